@@ -0,0 +1,190 @@
+use backend_comparison::persistence::save;
+use burn::record::{
+    FullPrecisionSettings, NamedMpkFileRecorder, PrecisionSettings, RecordCompression,
+};
+use burn::tensor::backend::Backend;
+use burn::tensor::Device;
+use burn::{config::Config, module::Module, nn};
+use burn_common::benchmark::{run_benchmark, Benchmark};
+use derive_new::new;
+
+#[derive(Module, Debug)]
+struct BenchmarkModule<B: Backend> {
+    linears: Vec<nn::Linear<B>>,
+}
+
+#[derive(Config, Debug)]
+struct BenchmarkConfig {
+    linear: nn::LinearConfig,
+    num_layers: usize,
+}
+
+impl BenchmarkConfig {
+    pub fn init<B: Backend>(&self, device: &B::Device) -> BenchmarkModule<B> {
+        BenchmarkModule {
+            linears: (0..self.num_layers)
+                .map(|_| self.linear.init(device))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+struct GzipSettings;
+
+impl PrecisionSettings for GzipSettings {
+    type FloatElem = f32;
+    type IntElem = i32;
+    const COMPRESSION: RecordCompression = RecordCompression::Gzip;
+}
+
+#[derive(Debug, Default, Clone)]
+struct ZstdSettings;
+
+impl PrecisionSettings for ZstdSettings {
+    type FloatElem = f32;
+    type IntElem = i32;
+    const COMPRESSION: RecordCompression = RecordCompression::Zstd;
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Operation {
+    Save,
+    Load,
+}
+
+#[derive(new)]
+struct CompressedRecordBenchmark<B: Backend> {
+    config: BenchmarkConfig,
+    device: Device<B>,
+    compression: RecordCompression,
+    operation: Operation,
+}
+
+impl<B: Backend> Benchmark for CompressedRecordBenchmark<B> {
+    type Args = BenchmarkModule<B>;
+
+    fn name(&self) -> String {
+        format!(
+            "compressed_record_{:?}_{:?}",
+            self.operation, self.compression
+        )
+        .to_lowercase()
+    }
+
+    /// The size of the saved record, to compare the codecs.
+    fn options(&self) -> Option<String> {
+        let size = std::fs::metadata(self.file().with_extension("mpk"))
+            .map(|metadata| metadata.len())
+            .ok()?;
+
+        Some(format!("{size} bytes"))
+    }
+
+    fn shapes(&self) -> Vec<Vec<usize>> {
+        vec![]
+    }
+
+    fn num_samples(&self) -> usize {
+        10
+    }
+
+    fn execute(&self, module: Self::Args) {
+        match self.operation {
+            Operation::Save => self.save(module),
+            Operation::Load => self.load(module),
+        }
+    }
+
+    fn prepare(&self) -> Self::Args {
+        let module = self.config.init(&self.device);
+
+        // The record is loaded from the file saved with the same codec.
+        if let Operation::Load = self.operation {
+            self.save(module.clone());
+        }
+
+        // Force sync.
+        module.clone()
+    }
+
+    fn sync(&self) {
+        B::sync(&self.device)
+    }
+}
+
+impl<B: Backend> CompressedRecordBenchmark<B> {
+    fn file(&self) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!(
+            "burn_bench_compressed_record_{:?}",
+            self.compression
+        ))
+    }
+
+    fn save(&self, module: BenchmarkModule<B>) {
+        match self.compression {
+            RecordCompression::None => self.save_with::<FullPrecisionSettings>(module),
+            RecordCompression::Gzip => self.save_with::<GzipSettings>(module),
+            RecordCompression::Zstd => self.save_with::<ZstdSettings>(module),
+        }
+    }
+
+    fn load(&self, module: BenchmarkModule<B>) {
+        match self.compression {
+            RecordCompression::None => self.load_with::<FullPrecisionSettings>(module),
+            RecordCompression::Gzip => self.load_with::<GzipSettings>(module),
+            RecordCompression::Zstd => self.load_with::<ZstdSettings>(module),
+        }
+    }
+
+    fn save_with<S: PrecisionSettings>(&self, module: BenchmarkModule<B>) {
+        module
+            .save_file(self.file(), &NamedMpkFileRecorder::<S>::new())
+            .unwrap();
+    }
+
+    fn load_with<S: PrecisionSettings>(&self, module: BenchmarkModule<B>) {
+        let _ = module
+            .load_file(self.file(), &NamedMpkFileRecorder::<S>::new(), &self.device)
+            .unwrap();
+    }
+}
+
+#[allow(dead_code)]
+fn bench<B: Backend>(
+    device: &B::Device,
+    feature_name: &str,
+    url: Option<&str>,
+    token: Option<&str>,
+) {
+    // About as many parameters as a ResNet-50.
+    let config = BenchmarkConfig::new(nn::LinearConfig::new(2048, 2048), 6);
+
+    for compression in [
+        RecordCompression::None,
+        RecordCompression::Gzip,
+        RecordCompression::Zstd,
+    ] {
+        for operation in [Operation::Save, Operation::Load] {
+            let benchmark = CompressedRecordBenchmark::<B>::new(
+                config.clone(),
+                device.clone(),
+                compression,
+                operation,
+            );
+
+            save::<B>(
+                vec![run_benchmark(benchmark)],
+                device,
+                feature_name,
+                url,
+                token,
+            )
+            .unwrap();
+        }
+    }
+}
+
+fn main() {
+    backend_comparison::bench_on_backend!();
+}
@@ -1,4 +1,5 @@
 use backend_comparison::persistence::save;
+use burn::record::{BinFileRecorder, FullPrecisionSettings, MmapFileRecorder};
 use burn::tensor::backend::Backend;
 use burn::tensor::Device;
 use burn::{config::Config, module::Module, nn};
@@ -43,6 +44,8 @@ enum Kind {
     Lazy,
     Sync,
     Manual,
+    BinFile,
+    MmapFile,
 }
 
 #[derive(new)]
@@ -82,13 +85,41 @@ impl<B: Backend> Benchmark for LoadRecordBenchmark<B> {
                 module.load_record(record)
             }
             Kind::Manual => self.config.init_with(record),
+            Kind::BinFile => self
+                .config
+                .init(&self.device)
+                .load_file(
+                    self.file(),
+                    &BinFileRecorder::<FullPrecisionSettings>::new(),
+                    &self.device,
+                )
+                .unwrap(),
+            Kind::MmapFile => self
+                .config
+                .init(&self.device)
+                .load_file(
+                    self.file(),
+                    &MmapFileRecorder::<FullPrecisionSettings>::new(),
+                    &self.device,
+                )
+                .unwrap(),
         };
     }
 
     fn prepare(&self) -> Self::Args {
         let module = self.config.init(&self.device);
-        // Force sync.
 
+        if let Kind::BinFile | Kind::MmapFile = self.kind {
+            module
+                .clone()
+                .save_file(
+                    self.file(),
+                    &BinFileRecorder::<FullPrecisionSettings>::new(),
+                )
+                .unwrap();
+        }
+
+        // Force sync.
         module.clone()
     }
 
@@ -97,6 +128,12 @@ impl<B: Backend> Benchmark for LoadRecordBenchmark<B> {
     }
 }
 
+impl<B: Backend> LoadRecordBenchmark<B> {
+    fn file(&self) -> std::path::PathBuf {
+        std::env::temp_dir().join("burn_bench_load_record")
+    }
+}
+
 #[allow(dead_code)]
 fn bench<B: Backend>(
     device: &B::Device,
@@ -110,6 +147,10 @@ fn bench<B: Backend>(
     let benchmark_sync = LoadRecordBenchmark::<B>::new(config.clone(), device.clone(), Kind::Sync);
     let benchmark_manual =
         LoadRecordBenchmark::<B>::new(config.clone(), device.clone(), Kind::Manual);
+    let benchmark_bin_file =
+        LoadRecordBenchmark::<B>::new(config.clone(), device.clone(), Kind::BinFile);
+    let benchmark_mmap_file =
+        LoadRecordBenchmark::<B>::new(config.clone(), device.clone(), Kind::MmapFile);
 
     save::<B>(
         vec![run_benchmark(benchmark_lazy)],
@@ -135,6 +176,22 @@ fn bench<B: Backend>(
         token,
     )
     .unwrap();
+    save::<B>(
+        vec![run_benchmark(benchmark_bin_file)],
+        device,
+        feature_name,
+        url,
+        token,
+    )
+    .unwrap();
+    save::<B>(
+        vec![run_benchmark(benchmark_mmap_file)],
+        device,
+        feature_name,
+        url,
+        token,
+    )
+    .unwrap();
 }
 
 fn main() {
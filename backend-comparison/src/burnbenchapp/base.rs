@@ -100,6 +100,8 @@ enum BenchmarkValues {
     MaxPool2d,
     #[strum(to_string = "load-record")]
     LoadRecord,
+    #[strum(to_string = "compressed-record")]
+    CompressedRecord,
     #[strum(to_string = "autodiff")]
     Autodiff,
     #[strum(to_string = "conv-transpose2d")]
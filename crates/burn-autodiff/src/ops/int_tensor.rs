@@ -310,6 +310,36 @@ impl<B: Backend, C: CheckpointStrategy> IntTensorOps<Self> for Autodiff<B, C> {
     fn int_abs<const D: usize>(tensor: B::IntTensorPrimitive<D>) -> B::IntTensorPrimitive<D> {
         B::int_abs(tensor)
     }
+    fn int_bitwise_and<const D: usize>(
+        lhs: B::IntTensorPrimitive<D>,
+        rhs: B::IntTensorPrimitive<D>,
+    ) -> B::IntTensorPrimitive<D> {
+        B::int_bitwise_and(lhs, rhs)
+    }
+    fn int_bitwise_or<const D: usize>(
+        lhs: B::IntTensorPrimitive<D>,
+        rhs: B::IntTensorPrimitive<D>,
+    ) -> B::IntTensorPrimitive<D> {
+        B::int_bitwise_or(lhs, rhs)
+    }
+    fn int_bitwise_xor<const D: usize>(
+        lhs: B::IntTensorPrimitive<D>,
+        rhs: B::IntTensorPrimitive<D>,
+    ) -> B::IntTensorPrimitive<D> {
+        B::int_bitwise_xor(lhs, rhs)
+    }
+    fn int_bitwise_left_shift<const D: usize>(
+        lhs: B::IntTensorPrimitive<D>,
+        rhs: B::IntTensorPrimitive<D>,
+    ) -> B::IntTensorPrimitive<D> {
+        B::int_bitwise_left_shift(lhs, rhs)
+    }
+    fn int_bitwise_right_shift<const D: usize>(
+        lhs: B::IntTensorPrimitive<D>,
+        rhs: B::IntTensorPrimitive<D>,
+    ) -> B::IntTensorPrimitive<D> {
+        B::int_bitwise_right_shift(lhs, rhs)
+    }
     fn int_into_float<const D: usize>(
         tensor: <Autodiff<B> as Backend>::IntTensorPrimitive<D>,
     ) -> <Autodiff<B> as Backend>::FloatTensorPrimitive<D> {
@@ -31,6 +31,14 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         AutodiffTensor::new(B::float_from_data(data, device))
     }
 
+    fn float_from_quantized<const D: usize>(
+        data: Data<i8, D>,
+        scale: f32,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        AutodiffTensor::new(B::float_from_quantized(data, scale, device))
+    }
+
     fn float_random<const D: usize>(
         shape: Shape<D>,
         distribution: burn_tensor::Distribution,
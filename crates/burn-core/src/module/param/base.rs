@@ -41,6 +41,9 @@ pub struct Param<T: Parameter> {
     /// when the lock is actually useful, waiting for the initialization to be completed before
     /// returning the value.
     initialization: Option<RwLock<Option<Uninitialized<T>>>>,
+    /// The gradient requirement restored from a record, which takes precedence over the one of
+    /// the module the record is loaded into.
+    pub(crate) recorded_require_grad: Option<bool>,
 }
 
 impl<T: Parameter> core::fmt::Display for Param<T> {
@@ -91,6 +94,7 @@ impl<T: Parameter> Param<T> {
             id,
             state: OnceCell::from(value),
             initialization: None,
+            recorded_require_grad: None,
         }
     }
 
@@ -107,6 +111,7 @@ impl<T: Parameter> Param<T> {
                 device,
                 is_require_grad,
             }))),
+            recorded_require_grad: None,
         }
     }
 
@@ -153,6 +158,7 @@ impl<T: Parameter> Param<T> {
             id,
             state: OnceCell::from(tensor),
             initialization: None,
+            recorded_require_grad: None,
         }
     }
 
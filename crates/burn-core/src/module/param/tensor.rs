@@ -100,10 +100,13 @@ impl<const D: usize, B: Backend> Module<B> for Param<Tensor<B, D>> {
     }
 
     fn load_record(self, record: Self::Record) -> Self {
+        let recorded_require_grad = record.recorded_require_grad;
         let (new_id, mut new_value) = record.consume();
 
         let expected_device = self.lazy_device();
-        let expected_require_grad = self.lazy_is_require_grad();
+        // The gradient requirement restored from a record wins over the one of the module.
+        let expected_require_grad =
+            recorded_require_grad.unwrap_or_else(|| self.lazy_is_require_grad());
 
         // Make sure we load the record into the same module device.
         if new_value.device() != expected_device {
@@ -155,8 +158,10 @@ impl<const D: usize, B: Backend> Module<B> for Param<Tensor<B, D, Int>> {
     }
 
     fn map<M: ModuleMapper<B>>(self, mapper: &mut M) -> Self {
-        let value = mapper.map_int(&self.id, self.val());
-        Self::initialized(self.id, value)
+        let (id, tensor) = self.consume();
+        let value = mapper.map_int(&id, tensor);
+
+        Self::initialized(id, value)
     }
 
     fn into_record(self) -> Self::Record {
@@ -206,8 +211,10 @@ impl<const D: usize, B: Backend> Module<B> for Param<Tensor<B, D, Bool>> {
     }
 
     fn map<M: ModuleMapper<B>>(self, mapper: &mut M) -> Self {
-        let value = mapper.map_bool(&self.id, self.val());
-        Self::initialized(self.id, value)
+        let (id, tensor) = self.consume();
+        let value = mapper.map_bool(&id, tensor);
+
+        Self::initialized(id, value)
     }
 
     fn into_record(self) -> Self::Record {
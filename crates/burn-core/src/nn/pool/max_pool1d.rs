@@ -6,6 +6,9 @@ use crate::nn::PaddingConfig1d;
 use crate::tensor::backend::Backend;
 use crate::tensor::Tensor;
 use burn_tensor::module::max_pool1d;
+use burn_tensor::ElementConversion;
+
+use super::max_pool2d::ceil_mode_padding;
 
 /// Configuration to create a [1D max pooling](MaxPool1d) layer.
 #[derive(Config, Debug)]
@@ -21,6 +24,10 @@ pub struct MaxPool1dConfig {
     /// The dilation.
     #[config(default = "1")]
     pub dilation: usize,
+    /// If true, the output size is rounded up instead of down, the last window going past the
+    /// end of the input as long as it starts inside the input or its left padding.
+    #[config(default = false)]
+    pub ceil_mode: bool,
 }
 
 /// Applies a 1D max pooling over input tensors.
@@ -30,6 +37,7 @@ pub struct MaxPool1d {
     kernel_size: usize,
     padding: PaddingConfig1d,
     dilation: usize,
+    ceil_mode: bool,
 }
 
 impl MaxPool1dConfig {
@@ -40,6 +48,7 @@ impl MaxPool1dConfig {
             kernel_size: self.kernel_size,
             padding: self.padding.clone(),
             dilation: self.dilation,
+            ceil_mode: self.ceil_mode,
         }
     }
 }
@@ -57,6 +66,19 @@ impl MaxPool1d {
             .padding
             .calculate_padding_1d(length, self.kernel_size, self.stride);
 
+        let input = if self.ceil_mode {
+            let right = ceil_mode_padding(
+                length,
+                self.kernel_size,
+                self.stride,
+                padding,
+                self.dilation,
+            );
+            input.pad((0, right, 0, 0), f32::NEG_INFINITY.elem())
+        } else {
+            input
+        };
+
         max_pool1d(input, self.kernel_size, self.stride, padding, self.dilation)
     }
 }
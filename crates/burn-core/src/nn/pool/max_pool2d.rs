@@ -4,8 +4,9 @@ use crate::config::Config;
 use crate::module::Module;
 use crate::nn::PaddingConfig2d;
 use crate::tensor::backend::Backend;
-use crate::tensor::Tensor;
-use burn_tensor::module::max_pool2d;
+use crate::tensor::{Int, Tensor};
+use burn_tensor::module::{max_pool2d, max_pool2d_with_indices};
+use burn_tensor::ElementConversion;
 
 /// Configuration to create an [2D max pooling](MaxPool2d) layer.
 #[derive(Debug, Config)]
@@ -21,6 +22,10 @@ pub struct MaxPool2dConfig {
     /// The dilation.
     #[config(default = "[1, 1]")]
     pub dilation: [usize; 2],
+    /// If true, the output size is rounded up instead of down, the last windows going past the
+    /// end of the input as long as they start inside the input or its left padding.
+    #[config(default = false)]
+    pub ceil_mode: bool,
 }
 
 /// Applies a 2D max pooling over input tensors.
@@ -30,6 +35,7 @@ pub struct MaxPool2d {
     kernel_size: [usize; 2],
     padding: PaddingConfig2d,
     dilation: [usize; 2],
+    ceil_mode: bool,
 }
 
 impl MaxPool2dConfig {
@@ -40,6 +46,7 @@ impl MaxPool2dConfig {
             kernel_size: self.kernel_size,
             padding: self.padding.clone(),
             dilation: self.dilation,
+            ceil_mode: self.ceil_mode,
         }
     }
 }
@@ -52,11 +59,102 @@ impl MaxPool2d {
     /// - input: [batch_size, channels, height_in, width_in],
     /// - output: [batch_size, channels, height_out, width_out],
     pub fn forward<B: Backend>(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let (input, padding) = self.pad(input);
+
+        max_pool2d(input, self.kernel_size, self.stride, padding, self.dilation)
+    }
+
+    /// Applies the forward pass on the input tensor, also returning the indices of the maximum
+    /// values in the flattened `height_in * width_in` plane of each channel.
+    ///
+    /// # Shapes
+    ///
+    /// - input: [batch_size, channels, height_in, width_in],
+    /// - output: [batch_size, channels, height_out, width_out],
+    /// - indices: [batch_size, channels, height_out, width_out],
+    pub fn forward_with_indices<B: Backend>(
+        &self,
+        input: Tensor<B, 4>,
+    ) -> (Tensor<B, 4>, Tensor<B, 4, Int>) {
+        let [_batch_size, _channels_in, _height_in, width_in] = input.dims();
+        let (input, padding) = self.pad(input);
+        let [_batch_size, _channels_in, _height_in, width_padded] = input.dims();
+
+        let (output, indices) =
+            max_pool2d_with_indices(input, self.kernel_size, self.stride, padding, self.dilation);
+
+        if width_padded == width_in {
+            return (output, indices);
+        }
+
+        // The indices are in the input widened by the ceil mode.
+        let rows = indices.clone().div_scalar(width_padded as i64);
+        let columns = indices - rows.clone().mul_scalar(width_padded as i64);
+
+        (output, rows.mul_scalar(width_in as i64) + columns)
+    }
+
+    /// Pad the input at the bottom and the right for the last windows of the ceil mode, returning
+    /// the padding applied on both sides by the pooling.
+    fn pad<B: Backend>(&self, input: Tensor<B, 4>) -> (Tensor<B, 4>, [usize; 2]) {
         let [_batch_size, _channels_in, height_in, width_in] = input.dims();
         let padding =
             self.padding
                 .calculate_padding_2d(height_in, width_in, &self.kernel_size, &self.stride);
 
-        max_pool2d(input, self.kernel_size, self.stride, padding, self.dilation)
+        if !self.ceil_mode {
+            return (input, padding);
+        }
+
+        let bottom = ceil_mode_padding(
+            height_in,
+            self.kernel_size[0],
+            self.stride[0],
+            padding[0],
+            self.dilation[0],
+        );
+        let right = ceil_mode_padding(
+            width_in,
+            self.kernel_size[1],
+            self.stride[1],
+            padding[1],
+            self.dilation[1],
+        );
+
+        if bottom == 0 && right == 0 {
+            return (input, padding);
+        }
+
+        let input = input.pad((0, right, 0, bottom), f32::NEG_INFINITY.elem());
+
+        (input, padding)
     }
 }
+
+/// The padding to add at the end of a dimension of `size` elements, already padded with `padding`
+/// on both sides, for the pooling output size to be rounded up.
+///
+/// The last window is dropped when it would start in the right padding, so that every window
+/// covers at least one element of the input.
+pub(crate) fn ceil_mode_padding(
+    size: usize,
+    kernel_size: usize,
+    stride: usize,
+    padding: usize,
+    dilation: usize,
+) -> usize {
+    let size_padded = size + 2 * padding;
+    let kernel_size = dilation * (kernel_size - 1) + 1;
+
+    if size_padded < kernel_size {
+        return 0;
+    }
+
+    let mut num_windows = (size_padded - kernel_size).div_ceil(stride) + 1;
+
+    if (num_windows - 1) * stride >= size + padding {
+        num_windows -= 1;
+    }
+
+    ((num_windows - 1) * stride + kernel_size).saturating_sub(size_padded)
+}
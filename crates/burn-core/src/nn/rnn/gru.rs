@@ -11,7 +11,7 @@ use burn_tensor::activation;
 use super::gate_controller::GateController;
 
 /// The configuration for a [gru](Gru) module.
-#[derive(Config)]
+#[derive(Config, Debug)]
 pub struct GruConfig {
     /// The size of the input features.
     pub d_input: usize,
@@ -19,6 +19,10 @@ pub struct GruConfig {
     pub d_hidden: usize,
     /// If a bias should be applied during the Gru transformation.
     pub bias: bool,
+    /// If the reset gate should be applied after the hidden transformation of the new gate,
+    /// as in PyTorch and ONNX with `linear_before_reset`, instead of before it.
+    #[config(default = false)]
+    pub reset_after: bool,
     /// Gru initializer
     #[config(default = "Initializer::XavierNormal{gain:1.0}")]
     pub initializer: Initializer,
@@ -31,6 +35,7 @@ pub struct Gru<B: Backend> {
     reset_gate: GateController<B>,
     new_gate: GateController<B>,
     d_hidden: usize,
+    reset_after: bool,
 }
 
 impl GruConfig {
@@ -65,6 +70,7 @@ impl GruConfig {
             reset_gate,
             new_gate,
             d_hidden: self.d_hidden,
+            reset_after: self.reset_after,
         }
     }
 }
@@ -75,33 +81,28 @@ impl<B: Backend> Gru<B> {
     ///
     /// Parameters:
     ///     batched_input: The input tensor of shape [batch_size, sequence_length, input_size].
-    ///     state: An optional tensor representing an initial cell state with the same dimensions
-    ///            as batched_input. If none is provided, one will be generated.
+    ///     state: An optional tensor representing the initial hidden state of shape
+    ///            [batch_size, hidden_size]. If none is provided, a zero state is used.
     ///
     /// Returns:
     ///     The resulting state tensor, with shape [batch_size, sequence_length, hidden_size].
     pub fn forward(
         &self,
         batched_input: Tensor<B, 3>,
-        state: Option<Tensor<B, 3>>,
+        state: Option<Tensor<B, 2>>,
     ) -> Tensor<B, 3> {
         let [batch_size, seq_length, _] = batched_input.shape().dims;
+        let device = batched_input.device();
 
-        let mut hidden_state = match state {
+        let mut batched_hidden_state =
+            Tensor::zeros([batch_size, seq_length, self.d_hidden], &device);
+        let mut hidden_t = match state {
             Some(state) => state,
-            None => Tensor::zeros(
-                [batch_size, seq_length, self.d_hidden],
-                &batched_input.device(),
-            ),
+            None => Tensor::zeros([batch_size, self.d_hidden], &device),
         };
 
-        for (t, (input_t, hidden_t)) in batched_input
-            .iter_dim(1)
-            .zip(hidden_state.clone().iter_dim(1))
-            .enumerate()
-        {
+        for (t, input_t) in batched_input.iter_dim(1).enumerate() {
             let input_t = input_t.squeeze(1);
-            let hidden_t = hidden_t.squeeze(1);
             // u(pdate)g(ate) tensors
             let biased_ug_input_sum = self.gate_product(&input_t, &hidden_t, &self.update_gate);
             let update_values = activation::sigmoid(biased_ug_input_sum); // Colloquially referred to as z(t)
@@ -109,29 +110,36 @@ impl<B: Backend> Gru<B> {
             // r(eset)g(ate) tensors
             let biased_rg_input_sum = self.gate_product(&input_t, &hidden_t, &self.reset_gate);
             let reset_values = activation::sigmoid(biased_rg_input_sum); // Colloquially referred to as r(t)
-            let reset_t = hidden_t.clone().mul(reset_values); // Passed as input to new_gate
 
             // n(ew)g(ate) tensor
-            let biased_ng_input_sum = self.gate_product(&input_t, &reset_t, &self.new_gate);
+            let biased_ng_input_sum = if self.reset_after {
+                // Wn*X + bn + r(t) * (Rn*H + bhn)
+                self.new_gate.input_transform.forward(input_t)
+                    + self
+                        .new_gate
+                        .hidden_transform
+                        .forward(hidden_t.clone())
+                        .mul(reset_values)
+            } else {
+                let reset_t = hidden_t.clone().mul(reset_values); // Passed as input to new_gate
+                self.gate_product(&input_t, &reset_t, &self.new_gate)
+            };
             let candidate_state = biased_ng_input_sum.tanh(); // Colloquially referred to as g(t)
 
             // calculate linear interpolation between previous hidden state and candidate state:
             // g(t) * (1 - z(t)) + z(t) * hidden_t
-            let state_vector = candidate_state
+            hidden_t = candidate_state
                 .clone()
                 .mul(update_values.clone().sub_scalar(1).mul_scalar(-1)) // (1 - z(t)) = -(z(t) - 1)
                 + update_values.clone().mul(hidden_t);
 
-            let current_shape = state_vector.shape().dims;
-            let unsqueezed_shape = [current_shape[0], 1, current_shape[1]];
-            let reshaped_state_vector = state_vector.reshape(unsqueezed_shape);
-            hidden_state = hidden_state.slice_assign(
+            batched_hidden_state = batched_hidden_state.slice_assign(
                 [0..batch_size, t..(t + 1), 0..self.d_hidden],
-                reshaped_state_vector,
+                hidden_t.clone().unsqueeze_dim(1),
             );
         }
 
-        hidden_state
+        batched_hidden_state
     }
 
     /// Helper function for performing weighted matrix product for a gate and adds
@@ -258,6 +266,44 @@ mod tests {
         output.to_data().assert_approx_eq(&Data::from([[0.034]]), 3);
     }
 
+    /// Test forward pass over two time steps, with the reset gate applied after the hidden
+    /// transformation of the new gate.
+    ///
+    /// h_1 = 0.1062 from the zero initial state, then h_2 = 0.2064 from h_1
+    #[test]
+    fn tests_forward_reset_after_uses_previous_hidden_state() {
+        let device = Default::default();
+        let config = GruConfig::new(1, 1, true).with_reset_after(true);
+        let mut gru = config.init::<TestBackend>(&device);
+
+        let create_gate_controller = |weights: f32, biases: f32| {
+            let record = || LinearRecord {
+                weight: Param::from_data(Data::from([[weights]]), &device),
+                bias: Some(Param::from_data(Data::from([biases]), &device)),
+            };
+            gate_controller::GateController::create_with_weights(
+                1,
+                1,
+                true,
+                Initializer::XavierNormal { gain: 1.0 },
+                record(),
+                record(),
+            )
+        };
+
+        gru.update_gate = create_gate_controller(0.5, 0.0);
+        gru.reset_gate = create_gate_controller(0.6, 0.0);
+        gru.new_gate = create_gate_controller(0.7, 0.1);
+
+        let input = Tensor::<TestBackend, 3>::from_data(Data::from([[[0.1], [0.2]]]), &device);
+
+        let state = gru.forward(input, None);
+
+        state
+            .to_data()
+            .assert_approx_eq(&Data::from([[[0.1062], [0.2064]]]), 3);
+    }
+
     #[test]
     fn test_batched_forward_pass() {
         let device = Default::default();
@@ -24,7 +24,7 @@ impl<B: Backend, const D: usize> LstmState<B, D> {
 }
 
 /// The configuration for a [lstm](Lstm) module.
-#[derive(Config)]
+#[derive(Config, Debug)]
 pub struct LstmConfig {
     /// The size of the input features.
     pub d_input: usize,
@@ -172,7 +172,7 @@ impl<B: Backend> Lstm<B> {
 }
 
 /// The configuration for a [Bidirectional LSTM](BiLstm) module.
-#[derive(Config)]
+#[derive(Config, Debug)]
 pub struct BiLstmConfig {
     /// The size of the input features.
     pub d_input: usize,
@@ -189,9 +189,9 @@ impl<B: Backend, const D: usize> AdaptiveMomentumState<B, D> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::module::{Module, Param};
+    use crate::module::{list_param_ids, Module, Param, ParamId};
     use crate::optim::{GradientsParams, Optimizer};
-    use crate::record::{BinFileRecorder, FullPrecisionSettings, Recorder};
+    use crate::record::{BinBytesRecorder, BinFileRecorder, FullPrecisionSettings, Recorder};
     use crate::tensor::{Data, Distribution, Tensor};
     use crate::{nn, TestAutodiffBackend, TestBackend};
 
@@ -221,6 +221,79 @@ mod tests {
 
         assert_eq!(state_optim_before.len(), state_optim_after.len());
     }
+
+    #[test]
+    fn test_adam_optimizer_resume_gives_identical_updates() {
+        let device = Default::default();
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 6], Distribution::Default, &device);
+        let mut optimizer = create_adam();
+        let linear = nn::LinearConfig::new(6, 6).init(&device);
+        let grads = GradientsParams::from_grads(linear.forward(x.clone()).backward(), &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let model_bytes = recorder.record(linear.clone().into_record(), ()).unwrap();
+        let optim_bytes = recorder.record(optimizer.to_record(), ()).unwrap();
+
+        // The training continues without interruption.
+        let grads = GradientsParams::from_grads(linear.forward(x.clone()).backward(), &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        // The training is resumed from the records with a new module and optimizer.
+        let resumed = nn::LinearConfig::new(6, 6)
+            .init(&device)
+            .load_record(recorder.load(model_bytes, &device).unwrap());
+        let mut optimizer = create_adam().load_record(recorder.load(optim_bytes, &device).unwrap());
+        let grads = GradientsParams::from_grads(resumed.forward(x).backward(), &resumed);
+        let resumed = optimizer.step(LEARNING_RATE, resumed, grads);
+
+        assert_eq!(linear.weight.to_data(), resumed.weight.to_data());
+        assert_eq!(
+            linear.bias.unwrap().to_data(),
+            resumed.bias.unwrap().to_data()
+        );
+    }
+
+    #[test]
+    fn test_adam_optimizer_resume_with_other_params() {
+        let device = Default::default();
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 6], Distribution::Default, &device);
+        let mut optimizer =
+            AdamConfig::new().init::<TestAutodiffBackend, Vec<nn::Linear<TestAutodiffBackend>>>();
+        let layers: Vec<nn::Linear<TestAutodiffBackend>> = vec![
+            nn::LinearConfig::new(6, 6).init(&device),
+            nn::LinearConfig::new(6, 6).init(&device),
+        ];
+        let loss = layers[1].forward(layers[0].forward(x.clone()));
+        let grads = GradientsParams::from_grads(loss.backward(), &layers);
+        let mut layers = optimizer.step(LEARNING_RATE, layers, grads);
+        let record = optimizer.to_record();
+
+        // The second layer is replaced by a new one.
+        layers[1] = nn::LinearConfig::new(6, 6).init(&device);
+        let mut optimizer = AdamConfig::new()
+            .init::<TestAutodiffBackend, Vec<nn::Linear<TestAutodiffBackend>>>()
+            .load_record(record);
+        let loss = layers[1].forward(layers[0].forward(x));
+        let grads = GradientsParams::from_grads(loss.backward(), &layers);
+        let layers = optimizer.step(LEARNING_RATE, layers, grads);
+        let record = optimizer.to_record();
+
+        let mut ids = record.keys().cloned().collect::<Vec<_>>();
+        let mut expected = list_param_ids(&layers);
+        ids.sort_by_key(|id| id.to_string());
+        expected.sort_by_key(|id| id.to_string());
+        assert_eq!(ids, expected);
+
+        // The state of the first layer is resumed, the new layer starts from scratch.
+        let time = |id: &ParamId| {
+            let state: AdamState<TestBackend, 2> = record[id].clone().into_state();
+            state.momentum.time
+        };
+        assert_eq!(time(&layers[0].weight.id), 2);
+        assert_eq!(time(&layers[1].weight.id), 1);
+    }
+
     const ASSERT_PRECISION: usize = 2;
 
     #[test]
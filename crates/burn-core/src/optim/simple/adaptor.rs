@@ -1,16 +1,22 @@
 use super::{record::AdaptorRecord, SimpleOptimizer};
 use crate::{
     grad_clipping::GradientClipping,
-    module::{AutodiffModule, ModuleMapper, ParamId},
+    module::{list_param_ids, AutodiffModule, ModuleMapper, ParamId},
     optim::{GradientsParams, Optimizer},
     LearningRate,
 };
 use burn_tensor::{backend::AutodiffBackend, Tensor};
 use core::marker::PhantomData;
-use hashbrown::HashMap;
+use hashbrown::{HashMap, HashSet};
 
 /// Wrapper struct that adapts any [simple optimizer](SimpleOptimizer) into
 /// an [optimizer](Optimizer).
+///
+/// The state of each parameter is recorded with its [id](ParamId), which is saved with the module
+/// record, so training can be resumed by loading both the module and the optimizer records.
+/// When the module has other parameters than the loaded record, e.g. after adding or removing
+/// layers, the states of the parameters missing from the module are dropped on the next step, and
+/// the parameters without a state start from a zero-initialized state, like on the first step.
 #[derive(Clone)]
 pub struct OptimizerAdaptor<O, M, B>
 where
@@ -22,6 +28,9 @@ where
     records: HashMap<ParamId, AdaptorRecord<O, B>>,
     module: PhantomData<M>,
     grad_clipping: Option<GradientClipping>,
+    /// Whether the records were loaded and may contain states of parameters missing from the
+    /// module.
+    prune_records: bool,
 }
 
 impl<O, B, M> From<O> for OptimizerAdaptor<O, M, B>
@@ -36,6 +45,7 @@ where
             records: HashMap::new(),
             module: PhantomData,
             grad_clipping: None,
+            prune_records: false,
         }
     }
 }
@@ -75,6 +85,12 @@ where
     type Record = HashMap<ParamId, AdaptorRecord<O, B>>;
 
     fn step(&mut self, lr: LearningRate, module: M, mut grads: GradientsParams) -> M {
+        if self.prune_records {
+            let ids = list_param_ids(&module).into_iter().collect::<HashSet<_>>();
+            self.records.retain(|id, _| ids.contains(id));
+            self.prune_records = false;
+        }
+
         let mut mapper = SimpleOptimizerMapper::<M, B, O>::new(
             &self.optim,
             &mut self.records,
@@ -91,6 +107,7 @@ where
 
     fn load_record(mut self, record: Self::Record) -> Self {
         self.records = record;
+        self.prune_records = true;
         self
     }
 }
@@ -0,0 +1,181 @@
+use super::{Record, RecordReader, Recorder, RecorderError, StreamFileRecorder, StreamRecorder};
+use burn_tensor::backend::Backend;
+use core::future::Future;
+use std::io::Cursor;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// [File recorder](StreamFileRecorder) loading records without blocking the async runtime.
+///
+/// The record is read with async I/O, then decoded and converted into the record on the
+/// [blocking pool](tokio::task::spawn_blocking) of the runtime, so a [tokio] runtime must be
+/// running when the returned future is polled.
+///
+/// Dropping the future cancels the load: a record that isn't decoded yet is never converted, so
+/// nothing is allocated on the device. When the conversion has already started, it runs to
+/// completion on the blocking pool and the record is dropped right away, releasing its tensors.
+pub trait AsyncFileRecorder<B: Backend>: StreamFileRecorder<B> + 'static {
+    /// Load an item from the given file, see [load](Recorder::load).
+    fn load_async<R>(
+        &self,
+        mut file: PathBuf,
+        device: &B::Device,
+    ) -> impl Future<Output = Result<R, RecorderError>> + Send
+    where
+        R: Record<B> + 'static,
+    {
+        let recorder = self.clone();
+        let device = device.clone();
+
+        async move {
+            file.set_extension(<Self as super::FileRecorder<B>>::file_extension());
+            let bytes = tokio::fs::read(&file).await.map_err(io_error)?;
+
+            load_bytes::<B, Self, R>(recorder, bytes, device).await
+        }
+    }
+
+    /// Load an item from the given reader, in the format of the recorder.
+    fn load_reader_async<R, Rd>(
+        &self,
+        mut reader: Rd,
+        device: &B::Device,
+    ) -> impl Future<Output = Result<R, RecorderError>> + Send
+    where
+        R: Record<B> + 'static,
+        Rd: AsyncRead + Unpin + Send,
+    {
+        let recorder = self.clone();
+        let device = device.clone();
+
+        async move {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await.map_err(io_error)?;
+
+            load_bytes::<B, Self, R>(recorder, bytes, device).await
+        }
+    }
+}
+
+impl<B: Backend, F: StreamFileRecorder<B> + 'static> AsyncFileRecorder<B> for F {}
+
+/// Decode the bytes of a record and convert it on the blocking pool.
+async fn load_bytes<B, F, R>(
+    recorder: F,
+    bytes: Vec<u8>,
+    device: B::Device,
+) -> Result<R, RecorderError>
+where
+    B: Backend,
+    F: StreamFileRecorder<B> + 'static,
+    R: Record<B> + 'static,
+{
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let _cancel = CancelOnDrop(cancelled.clone());
+
+    let task = tokio::task::spawn_blocking(move || {
+        check_cancelled(&cancelled)?;
+
+        let recorder = StreamRecorder::<F, std::io::Sink, Cursor<Vec<u8>>>::new(recorder);
+        let reader = RecordReader::new(Cursor::new(bytes))?;
+        let decoded = <_ as Recorder<B>>::decode::<R>(&recorder, reader)?;
+
+        // Last point where the load can be cancelled without allocating on the device.
+        check_cancelled(&cancelled)?;
+
+        decoded.into_record(&device)
+    });
+
+    task.await
+        .map_err(|err| RecorderError::Unknown(err.to_string()))?
+}
+
+/// Flag raised when the future of a load is dropped.
+struct CancelOnDrop(Arc<AtomicBool>);
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::Release);
+    }
+}
+
+fn check_cancelled(cancelled: &AtomicBool) -> Result<(), RecorderError> {
+    if cancelled.load(Ordering::Acquire) {
+        return Err(RecorderError::Unknown("The load was cancelled".to_string()));
+    }
+
+    Ok(())
+}
+
+fn io_error(err: std::io::Error) -> RecorderError {
+    match err.kind() {
+        std::io::ErrorKind::NotFound => RecorderError::FileNotFound(err.to_string()),
+        _ => RecorderError::Unknown(err.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::{FullPrecisionSettings, NamedMpkFileRecorder, RecordWriter};
+    use crate::TestBackend;
+    use burn_tensor::{Int, Tensor};
+
+    type TensorsRecord = (Tensor<TestBackend, 2>, Tensor<TestBackend, 1, Int>);
+
+    fn tensors() -> TensorsRecord {
+        let device = Default::default();
+
+        (
+            Tensor::from_floats([[1.0, -2.5], [3.25, 0.0]], &device),
+            Tensor::from_ints([1, -2, 3], &device),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_load_async_reads_the_saved_file() {
+        let device = Default::default();
+        let recorder = NamedMpkFileRecorder::<FullPrecisionSettings>::default();
+        let file = std::env::temp_dir().join("burn_test_async_load");
+        let tensors = tensors();
+        recorder.record(tensors.clone(), file.clone()).unwrap();
+
+        let loaded: TensorsRecord = recorder.load_async(file, &device).await.unwrap();
+
+        assert_eq!(tensors.0.into_data(), loaded.0.into_data());
+        assert_eq!(tensors.1.into_data(), loaded.1.into_data());
+    }
+
+    #[tokio::test]
+    async fn test_load_reader_async_reads_the_record_bytes() {
+        let device = Default::default();
+        let recorder = NamedMpkFileRecorder::<FullPrecisionSettings>::default();
+        let tensors = tensors();
+        let writer = RecordWriter::new(Vec::new());
+        StreamRecorder::<_, Vec<u8>, Cursor<Vec<u8>>>::new(recorder.clone())
+            .record(tensors.clone(), writer.clone())
+            .unwrap();
+        let bytes = writer.into_inner().unwrap();
+
+        let loaded: TensorsRecord = recorder
+            .load_reader_async(bytes.as_slice(), &device)
+            .await
+            .unwrap();
+
+        assert_eq!(tensors.0.into_data(), loaded.0.into_data());
+        assert_eq!(tensors.1.into_data(), loaded.1.into_data());
+    }
+
+    #[tokio::test]
+    async fn test_load_async_missing_file() {
+        let device = Default::default();
+        let recorder = NamedMpkFileRecorder::<FullPrecisionSettings>::default();
+        let file = std::env::temp_dir().join("burn_test_async_load_missing");
+
+        let result: Result<TensorsRecord, _> = recorder.load_async(file, &device).await;
+
+        assert!(matches!(result, Err(RecorderError::FileNotFound(_))));
+    }
+}
@@ -1,7 +1,8 @@
+use alloc::{format, string::String, string::ToString};
 pub use burn_derive::Record;
 use burn_tensor::backend::Backend;
 
-use super::PrecisionSettings;
+use super::{PrecisionSettings, RecorderError, ShapeCheck};
 use serde::{de::DeserializeOwned, Serialize};
 
 /// Trait to define a family of types which can be recorded using any [settings](PrecisionSettings).
@@ -13,5 +14,93 @@ pub trait Record<B: Backend>: Send {
     fn into_item<S: PrecisionSettings>(self) -> Self::Item<S>;
 
     /// Convert the given item into a record.
+    ///
+    /// # Notes
+    ///
+    /// The item is fully deserialized before being converted, so the data of all its tensors is
+    /// held on the host when the conversion starts, unless it's staged in a file by the
+    /// [memory budget](PrecisionSettings::MEMORY_BUDGET) of the settings.
+    ///
+    /// Implementations should consume the item one field at a time, so that the data of each
+    /// tensor is freed right after its tensor is created on the device, instead of once the whole
+    /// record is converted. This is what the [derive macro](burn_derive::Record) and the provided
+    /// implementations do: fields are converted in declaration order, collections in iteration
+    /// order.
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self;
+
+    /// Convert the given item into a record located at the given path of the parent record,
+    /// e.g. `layer1.conv.weight`.
+    ///
+    /// The path doesn't change the conversion, it's only used to report which tensor can't be
+    /// loaded. By default, it's ignored and the item is converted with
+    /// [from_item](Record::from_item).
+    fn from_item_with_path<S: PrecisionSettings>(
+        item: Self::Item<S>,
+        device: &B::Device,
+        _path: &str,
+    ) -> Self
+    where
+        Self: Sized,
+    {
+        Self::from_item(item, device)
+    }
+
+    /// Check that the integer values of the given item located at the given path can be
+    /// represented by the integer element of the backend.
+    ///
+    /// It's only called before converting the item when the settings enable
+    /// [CHECK_INT_OVERFLOW](PrecisionSettings::CHECK_INT_OVERFLOW). By default, the item is
+    /// considered to have no integer tensor.
+    fn check_int_overflow<S: PrecisionSettings>(
+        _item: &Self::Item<S>,
+        _path: &str,
+    ) -> Result<(), RecorderError> {
+        Ok(())
+    }
+
+    /// Check that the tensors of the given item located at the given path have the rank of the
+    /// record and as many values as their shape, reporting the path of the first one that
+    /// doesn't. The shapes of the tensors are also [collected or compared](ShapeCheck) with the
+    /// given shapes.
+    ///
+    /// It's called before converting a loaded item, so that a misshapen tensor produces an error
+    /// instead of a panic in [from_item](Record::from_item). By default, the item is considered
+    /// to have no tensor.
+    fn check_shapes<S: PrecisionSettings>(
+        _item: &Self::Item<S>,
+        _path: &str,
+        _shapes: &mut ShapeCheck,
+    ) -> Result<(), RecorderError> {
+        Ok(())
+    }
+
+    /// Check that the float values of the given item located at the given path aren't rejected
+    /// by the [CHECK_FINITE](PrecisionSettings::CHECK_FINITE) of the settings.
+    ///
+    /// It's only called before saving the item when the check is enabled. By default, the item
+    /// is considered to have no float tensor.
+    fn check_finite<S: PrecisionSettings>(
+        _item: &Self::Item<S>,
+        _path: &str,
+    ) -> Result<(), RecorderError> {
+        Ok(())
+    }
+
+    /// Add the given offset to the values of the index tensors of the given item located at the
+    /// given path, i.e. the integer tensors selected by the
+    /// [INDEX_FIELDS](PrecisionSettings::INDEX_FIELDS) of the settings.
+    ///
+    /// It's called with the [INDEX_OFFSET](PrecisionSettings::INDEX_OFFSET) of the settings
+    /// after converting a record into an item, and with its opposite before converting a loaded
+    /// item. By default, the item is considered to have no integer tensor.
+    fn offset_indices<S: PrecisionSettings>(_item: &mut Self::Item<S>, _path: &str, _offset: i64) {}
+}
+
+/// Get the path of a field in a record, given the path of the record.
+pub fn record_field_path(path: &str, field: &str) -> String {
+    if path.is_empty() {
+        field.to_string()
+    } else {
+        format!("{path}.{field}")
+    }
 }
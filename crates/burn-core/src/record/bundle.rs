@@ -0,0 +1,269 @@
+use super::checksum::{append_checksum, verify_checksum};
+use super::{bin_config, PrecisionSettings, Recorder, RecorderError};
+use burn_tensor::backend::Backend;
+use core::marker::PhantomData;
+use serde::{de::DeserializeOwned, Serialize};
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+
+/// Marker written at the start of each entry of a bundle.
+const ENTRY_MAGIC: &[u8; 4] = b"BNDL";
+/// Size of the entry header: the marker, the step and the length of the snapshot.
+const ENTRY_HEADER_SIZE: u64 = 20;
+
+/// File recorder appending successive snapshots of a record, e.g. the checkpoints of a
+/// training, into a single bundle file using the [bincode format](bincode).
+///
+/// Each snapshot is recorded with the step it belongs to, and any of them can be loaded without
+/// deserializing the others. A snapshot that was only partially written, e.g. when the process
+/// crashed during a save, is ignored when reading and discarded by the next save. Readers can
+/// load snapshots while another recorder appends to the bundle, the snapshot being written only
+/// becomes visible once complete.
+#[derive(new, Debug, Default, Clone)]
+pub struct BundleFileRecorder<S: PrecisionSettings> {
+    _settings: PhantomData<S>,
+}
+
+/// Location of a snapshot in a bundle file.
+#[derive(new, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BundleEntry {
+    /// Step of the snapshot.
+    pub step: usize,
+    /// Offset of the serialized snapshot in the file, in bytes.
+    pub offset: u64,
+    /// Length of the serialized snapshot, in bytes.
+    pub length: u64,
+}
+
+impl<S: PrecisionSettings> BundleFileRecorder<S> {
+    /// File extension of the bundle format.
+    pub fn file_extension() -> &'static str {
+        "bundle"
+    }
+
+    /// List the complete snapshots of the bundle in the order they were recorded.
+    pub fn entries(&self, mut file: PathBuf) -> Result<Vec<BundleEntry>, RecorderError> {
+        file.set_extension(Self::file_extension());
+        let mut reader = File::open(file).map_err(io_error)?;
+        let (entries, _) = read_entries(&mut reader)?;
+
+        Ok(entries)
+    }
+
+    /// List the steps of the complete snapshots of the bundle in the order they were recorded.
+    pub fn steps(&self, file: PathBuf) -> Result<Vec<usize>, RecorderError> {
+        let entries = self.entries(file)?;
+
+        Ok(entries.into_iter().map(|entry| entry.step).collect())
+    }
+}
+
+impl<S: PrecisionSettings, B: Backend> Recorder<B> for BundleFileRecorder<S> {
+    type Settings = S;
+    /// The bundle file and the step of the snapshot.
+    type RecordArgs = (PathBuf, usize);
+    type RecordOutput = ();
+    /// The bundle file and the step of the snapshot to load, the last snapshot recorded with
+    /// this step is loaded.
+    type LoadArgs = (PathBuf, usize);
+
+    fn save_item<I: Serialize>(
+        &self,
+        item: I,
+        (mut file, step): Self::RecordArgs,
+    ) -> Result<(), RecorderError> {
+        let mut payload = bincode::serde::encode_to_vec(&item, bin_config())
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+        append_checksum::<S>(&mut payload, 0);
+
+        file.set_extension(Self::file_extension());
+
+        // Add parent directories if they don't exist
+        if let Some(parent) = file.parent() {
+            std::fs::create_dir_all(parent).ok();
+        }
+
+        let mut writer = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&file)
+            .map_err(io_error)?;
+
+        let (_, end) = read_entries(&mut writer)?;
+        let size = writer.metadata().map_err(io_error)?.len();
+
+        if end < size {
+            log::warn!(
+                "Discarding {} bytes of a partially written snapshot at the end of {}",
+                size - end,
+                file.display()
+            );
+            writer.set_len(end).map_err(io_error)?;
+        }
+
+        // The header and the snapshot are written at once so that readers never see a header
+        // without the snapshot, unless the write is interrupted.
+        let mut entry = Vec::with_capacity(ENTRY_HEADER_SIZE as usize + payload.len());
+        entry.extend_from_slice(ENTRY_MAGIC);
+        entry.extend_from_slice(&(step as u64).to_le_bytes());
+        entry.extend_from_slice(&(payload.len() as u64).to_le_bytes());
+        entry.extend_from_slice(&payload);
+
+        writer.seek(SeekFrom::Start(end)).map_err(io_error)?;
+        writer.write_all(&entry).map_err(io_error)?;
+        writer.sync_data().map_err(io_error)?;
+
+        Ok(())
+    }
+
+    fn load_item<I: DeserializeOwned>(
+        &self,
+        (mut file, step): Self::LoadArgs,
+    ) -> Result<I, RecorderError> {
+        file.set_extension(Self::file_extension());
+        let mut reader = File::open(&file).map_err(io_error)?;
+        let (entries, _) = read_entries(&mut reader)?;
+
+        let entry = entries
+            .into_iter()
+            .rev()
+            .find(|entry| entry.step == step)
+            .ok_or_else(|| {
+                RecorderError::Unknown(format!(
+                    "No snapshot of step {step} in the bundle {}",
+                    file.display()
+                ))
+            })?;
+
+        let mut payload = vec![0; entry.length as usize];
+        reader
+            .seek(SeekFrom::Start(entry.offset))
+            .map_err(io_error)?;
+        reader.read_exact(&mut payload).map_err(io_error)?;
+
+        let payload = verify_checksum::<S>(&payload)?;
+        let (state, _) = bincode::serde::decode_from_slice(payload, bin_config())
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        Ok(state)
+    }
+}
+
+/// Read the headers of the complete entries of the bundle, skipping over the snapshots.
+///
+/// Returns the entries with the offset of the end of the last complete one, the remaining bytes
+/// being a partially written entry.
+fn read_entries(file: &mut File) -> Result<(Vec<BundleEntry>, u64), RecorderError> {
+    // The size is read once so that an entry appended concurrently is either complete or ignored.
+    let size = file.metadata().map_err(io_error)?.len();
+    let mut entries = Vec::new();
+    let mut end = 0;
+
+    while end + ENTRY_HEADER_SIZE <= size {
+        let mut header = [0; ENTRY_HEADER_SIZE as usize];
+        file.seek(SeekFrom::Start(end)).map_err(io_error)?;
+        file.read_exact(&mut header).map_err(io_error)?;
+
+        if &header[0..4] != ENTRY_MAGIC {
+            log::warn!("Invalid bundle entry at offset {end}, ignoring the rest of the file");
+            break;
+        }
+
+        let step = u64::from_le_bytes(header[4..12].try_into().unwrap()) as usize;
+        let length = u64::from_le_bytes(header[12..20].try_into().unwrap());
+        let offset = end + ENTRY_HEADER_SIZE;
+
+        if offset + length > size {
+            break;
+        }
+
+        entries.push(BundleEntry::new(step, offset, length));
+        end = offset + length;
+    }
+
+    Ok((entries, end))
+}
+
+fn io_error(err: std::io::Error) -> RecorderError {
+    match err.kind() {
+        std::io::ErrorKind::NotFound => RecorderError::FileNotFound(err.to_string()),
+        _ => RecorderError::Unknown(err.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{record::FullPrecisionSettings, tensor::Tensor, TestBackend};
+    use std::path::Path;
+
+    type TestRecorder = BundleFileRecorder<FullPrecisionSettings>;
+
+    fn bundle_path(name: &str) -> PathBuf {
+        let file = std::env::temp_dir().join(name);
+        std::fs::remove_file(file.with_extension(TestRecorder::file_extension())).ok();
+        file
+    }
+
+    fn record_steps(recorder: &TestRecorder, file: &Path, steps: &[usize]) {
+        let device = Default::default();
+
+        for &step in steps {
+            let tensor = Tensor::<TestBackend, 1>::from_floats([step as f32; 4], &device);
+            Recorder::<TestBackend>::record(recorder, tensor, (file.to_path_buf(), step)).unwrap();
+        }
+    }
+
+    fn load_step(recorder: &TestRecorder, file: &Path, step: usize) -> Vec<f32> {
+        let device = Default::default();
+        let tensor: Tensor<TestBackend, 1> =
+            Recorder::<TestBackend>::load(recorder, (file.to_path_buf(), step), &device).unwrap();
+
+        tensor.into_data().value
+    }
+
+    #[test]
+    fn test_bundle_loads_any_step() {
+        let recorder = TestRecorder::default();
+        let file = bundle_path("burn_test_bundle_steps");
+
+        record_steps(&recorder, &file, &[0, 10, 20]);
+
+        assert_eq!(recorder.steps(file.clone()).unwrap(), vec![0, 10, 20]);
+        assert_eq!(load_step(&recorder, &file, 10), vec![10.0; 4]);
+        assert_eq!(load_step(&recorder, &file, 20), vec![20.0; 4]);
+
+        let result: Result<Tensor<TestBackend, 1>, _> =
+            Recorder::<TestBackend>::load(&recorder, (file, 30), &Default::default());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_bundle_skips_truncated_snapshot() {
+        let recorder = TestRecorder::default();
+        let file = bundle_path("burn_test_bundle_truncated");
+
+        record_steps(&recorder, &file, &[0, 1]);
+
+        // Simulate a crash during the save of the last snapshot.
+        let path = file.with_extension(TestRecorder::file_extension());
+        let size = std::fs::metadata(&path).unwrap().len();
+        OpenOptions::new()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_len(size - 3)
+            .unwrap();
+
+        assert_eq!(recorder.steps(file.clone()).unwrap(), vec![0]);
+
+        // The partial snapshot is discarded by the next save.
+        record_steps(&recorder, &file, &[2]);
+
+        assert_eq!(recorder.steps(file.clone()).unwrap(), vec![0, 2]);
+        assert_eq!(load_step(&recorder, &file, 2), vec![2.0; 4]);
+    }
+}
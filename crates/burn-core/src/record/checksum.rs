@@ -0,0 +1,122 @@
+use super::{PrecisionSettings, RecorderError};
+use alloc::format;
+use alloc::vec::Vec;
+
+/// Marker ending the checksum trailer.
+const CHECKSUM_MAGIC: &[u8; 8] = b"BURNcs1\n";
+/// Size of the checksum trailer: the CRC32 of the payload in little endian, then the marker.
+pub(crate) const CHECKSUM_TRAILER_SIZE: usize = 12;
+
+/// The trailer appended after a payload with the given checksum.
+///
+/// The checksum is written after the serialized record, outside of its
+/// [metadata](super::BurnMetadata), so that the decoders stopping at the end of the record skip
+/// it, e.g. the recorders of the versions before it was introduced.
+pub(crate) fn checksum_trailer(checksum: u32) -> [u8; CHECKSUM_TRAILER_SIZE] {
+    let mut trailer = [0; CHECKSUM_TRAILER_SIZE];
+    trailer[..4].copy_from_slice(&checksum.to_le_bytes());
+    trailer[4..].copy_from_slice(CHECKSUM_MAGIC);
+
+    trailer
+}
+
+/// Append the trailer with the checksum of the payload, the bytes starting at the given offset,
+/// when the checksum is [enabled](PrecisionSettings::CHECKSUM) by the settings.
+pub(crate) fn append_checksum<S: PrecisionSettings>(bytes: &mut Vec<u8>, offset: usize) {
+    if S::CHECKSUM {
+        let trailer = checksum_trailer(crc32fast::hash(&bytes[offset..]));
+        bytes.extend_from_slice(&trailer);
+    }
+}
+
+/// The payload of the given bytes without its checksum trailer, verified against the checksum
+/// when it's [enabled](PrecisionSettings::CHECKSUM) by the settings.
+///
+/// The payloads without trailer, e.g. saved without checksum, are returned as is.
+pub(crate) fn verify_checksum<S: PrecisionSettings>(bytes: &[u8]) -> Result<&[u8], RecorderError> {
+    let Some((payload, expected)) = split_trailer(bytes) else {
+        return Ok(bytes);
+    };
+
+    if S::CHECKSUM {
+        let actual = crc32fast::hash(payload);
+
+        if actual != expected {
+            return Err(RecorderError::ChecksumMismatch(format!(
+                "Actual {actual:#010x}, Expected {expected:#010x}"
+            )));
+        }
+    }
+
+    Ok(payload)
+}
+
+/// Split the payload and the checksum of its trailer, `None` without trailer.
+fn split_trailer(bytes: &[u8]) -> Option<(&[u8], u32)> {
+    let (payload, trailer) = bytes.split_at(bytes.len().checked_sub(CHECKSUM_TRAILER_SIZE)?);
+
+    if &trailer[4..] != CHECKSUM_MAGIC {
+        return None;
+    }
+
+    let checksum = u32::from_le_bytes([trailer[0], trailer[1], trailer[2], trailer[3]]);
+
+    Some((payload, checksum))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::FullPrecisionSettings;
+    use alloc::vec;
+
+    #[derive(Debug, Default, Clone)]
+    struct ChecksumSettings;
+
+    impl PrecisionSettings for ChecksumSettings {
+        type FloatElem = f32;
+        type IntElem = i32;
+        const CHECKSUM: bool = true;
+    }
+
+    #[test]
+    fn checksum_covers_the_payload_after_the_offset() {
+        let mut bytes = vec![1, 2, 3, 4, 5];
+        append_checksum::<ChecksumSettings>(&mut bytes, 2);
+
+        assert_eq!(bytes.len(), 5 + CHECKSUM_TRAILER_SIZE);
+        assert_eq!(
+            verify_checksum::<ChecksumSettings>(&bytes[2..]).unwrap(),
+            &[3, 4, 5]
+        );
+    }
+
+    #[test]
+    fn checksum_is_only_appended_when_enabled() {
+        let mut bytes = vec![1, 2, 3];
+        append_checksum::<FullPrecisionSettings>(&mut bytes, 0);
+
+        assert_eq!(bytes, vec![1, 2, 3]);
+        assert_eq!(
+            verify_checksum::<ChecksumSettings>(&bytes).unwrap(),
+            &[1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn err_when_payload_is_corrupted() {
+        let mut bytes = vec![1, 2, 3];
+        append_checksum::<ChecksumSettings>(&mut bytes, 0);
+        bytes[1] ^= 0xFF;
+
+        assert!(matches!(
+            verify_checksum::<ChecksumSettings>(&bytes),
+            Err(RecorderError::ChecksumMismatch(_))
+        ));
+        // The trailer is still stripped when the checksum isn't verified.
+        assert_eq!(
+            verify_checksum::<FullPrecisionSettings>(&bytes).unwrap(),
+            &[1, 253, 3]
+        );
+    }
+}
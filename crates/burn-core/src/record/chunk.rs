@@ -0,0 +1,402 @@
+use super::compression::{deserialize_data, serialize_data};
+#[cfg(feature = "std")]
+use super::staging::{StagedData, StagingWriter};
+use super::{PrecisionSettings, RecordCompression};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use burn_tensor::DataSerialize;
+use core::marker::PhantomData;
+use serde::{
+    de::{DeserializeOwned, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/// Tensor data split in chunks of values, each chunk being compressed on its own.
+#[derive(Serialize)]
+struct ChunkedDataRef<'a, E: Serialize> {
+    shape: &'a [usize],
+    chunks: Vec<ChunkRef<'a, E>>,
+}
+
+struct ChunkRef<'a, E> {
+    values: &'a [E],
+    compression: RecordCompression,
+}
+
+impl<'a, E: Serialize> Serialize for ChunkRef<'a, E> {
+    fn serialize<Se: Serializer>(&self, serializer: Se) -> Result<Se::Ok, Se::Error> {
+        serialize_data(&self.values, self.compression, serializer)
+    }
+}
+
+/// Tensor data split in chunks, staged in a file when it exceeds the
+/// [memory budget](PrecisionSettings::MEMORY_BUDGET) and `STAGE` is set.
+#[derive(Deserialize)]
+#[serde(bound(deserialize = "E: Serialize + DeserializeOwned"))]
+struct ChunkedData<E, S: PrecisionSettings, const STAGE: bool> {
+    shape: Vec<usize>,
+    chunks: Chunks<E, S, STAGE>,
+}
+
+/// The values of all the chunks, appended as soon as each chunk is deserialized.
+struct Chunks<E, S, const STAGE: bool> {
+    values: ChunkValues<E>,
+    _settings: PhantomData<S>,
+}
+
+/// The values of the chunks, held in a single host buffer or staged in a file.
+enum ChunkValues<E> {
+    Host(Vec<E>),
+    #[cfg(feature = "std")]
+    Staged(StagingWriter<E>),
+}
+
+/// Data of a loaded tensor, held in a host buffer or staged in a file when it exceeds the
+/// [memory budget](PrecisionSettings::MEMORY_BUDGET).
+pub(crate) enum LoadedData<E> {
+    Host(DataSerialize<E>),
+    #[cfg(feature = "std")]
+    Staged(StagedData<E>),
+}
+
+/// The values of a single chunk.
+struct Chunk<E, S> {
+    values: Vec<E>,
+    _settings: PhantomData<S>,
+}
+
+impl<'de, E: DeserializeOwned, S: PrecisionSettings> Deserialize<'de> for Chunk<E, S> {
+    fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        Ok(Self {
+            values: deserialize_data(S::COMPRESSION, deserializer)?,
+            _settings: PhantomData,
+        })
+    }
+}
+
+impl<'de, E, S, const STAGE: bool> Deserialize<'de> for Chunks<E, S, STAGE>
+where
+    E: Serialize + DeserializeOwned,
+    S: PrecisionSettings,
+{
+    fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        struct ChunksVisitor<E, S, const STAGE: bool>(PhantomData<(E, S)>);
+
+        impl<'de, E, S, const STAGE: bool> Visitor<'de> for ChunksVisitor<E, S, STAGE>
+        where
+            E: Serialize + DeserializeOwned,
+            S: PrecisionSettings,
+        {
+            type Value = Chunks<E, S, STAGE>;
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                formatter.write_str("a sequence of tensor chunks")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut values = ChunkValues::Host(Vec::new());
+
+                while let Some(chunk) = seq.next_element::<Chunk<E, S>>()? {
+                    if let Some(budget) = S::MEMORY_BUDGET {
+                        // The chunk is the smallest buffer, it can't be staged in smaller ones.
+                        check_memory_budget::<E>(chunk.values.len(), budget)
+                            .map_err(serde::de::Error::custom)?;
+
+                        if let ChunkValues::Host(host) = &mut values {
+                            let num_values = host.len() + chunk.values.len();
+
+                            #[cfg(feature = "std")]
+                            if STAGE && check_memory_budget::<E>(num_values, budget).is_err() {
+                                let mut writer =
+                                    StagingWriter::create().map_err(serde::de::Error::custom)?;
+                                writer.push(host).map_err(serde::de::Error::custom)?;
+                                values = ChunkValues::Staged(writer);
+                            }
+
+                            if !STAGE {
+                                check_memory_budget::<E>(num_values, budget)
+                                    .map_err(serde::de::Error::custom)?;
+                            }
+                        }
+                    }
+
+                    match &mut values {
+                        ChunkValues::Host(host) => host.extend(chunk.values),
+                        #[cfg(feature = "std")]
+                        ChunkValues::Staged(writer) => writer
+                            .push(&chunk.values)
+                            .map_err(serde::de::Error::custom)?,
+                    }
+                }
+
+                Ok(Chunks {
+                    values,
+                    _settings: PhantomData,
+                })
+            }
+        }
+
+        deserializer.deserialize_seq(ChunksVisitor::<E, S, STAGE>(PhantomData))
+    }
+}
+
+/// Check that a host buffer of the given number of values fits in the memory budget.
+fn check_memory_budget<E>(num_values: usize, budget: usize) -> Result<(), String> {
+    let num_bytes = num_values * core::mem::size_of::<E>();
+
+    if num_bytes > budget {
+        return Err(format!(
+            "The tensor exceeds the memory budget: a buffer of {num_bytes} bytes is needed, the \
+            budget is {budget} bytes"
+        ));
+    }
+
+    Ok(())
+}
+
+/// Serialize the tensor data in chunks of at most `chunk_size` bytes of values.
+///
+/// A tensor smaller than the chunk size is still written as a single chunk, so the format only
+/// depends on the settings.
+fn serialize_chunks<E, S, Se>(
+    data: &DataSerialize<E>,
+    chunk_size: usize,
+    serializer: Se,
+) -> Result<Se::Ok, Se::Error>
+where
+    E: Serialize,
+    S: PrecisionSettings,
+    Se: Serializer,
+{
+    let values_per_chunk = usize::max(chunk_size / core::mem::size_of::<E>().max(1), 1);
+
+    ChunkedDataRef {
+        shape: &data.shape,
+        chunks: data
+            .value
+            .chunks(values_per_chunk)
+            .map(|values| ChunkRef {
+                values,
+                compression: S::COMPRESSION,
+            })
+            .collect(),
+    }
+    .serialize(serializer)
+}
+
+/// Deserialize the tensor data written in chunks, making sure the chunks have as many values as
+/// the shape of the tensor.
+fn deserialize_chunks<'de, E, S, De, const STAGE: bool>(
+    deserializer: De,
+) -> Result<LoadedData<E>, De::Error>
+where
+    E: Serialize + DeserializeOwned,
+    S: PrecisionSettings,
+    De: Deserializer<'de>,
+{
+    let data = ChunkedData::<E, S, STAGE>::deserialize(deserializer)?;
+    let num_elements = data.shape.iter().product::<usize>();
+    let num_values = match &data.chunks.values {
+        ChunkValues::Host(values) => values.len(),
+        #[cfg(feature = "std")]
+        ChunkValues::Staged(writer) => writer.num_values(),
+    };
+
+    if num_values != num_elements {
+        return Err(serde::de::Error::custom(format!(
+            "The chunks of the tensor have {num_values} values, expected {num_elements} for \
+            shape {:?}",
+            data.shape
+        )));
+    }
+
+    match data.chunks.values {
+        ChunkValues::Host(values) => Ok(LoadedData::Host(DataSerialize::new(values, data.shape))),
+        #[cfg(feature = "std")]
+        ChunkValues::Staged(writer) => {
+            let mut staged = writer.finish().map_err(serde::de::Error::custom)?;
+            staged.shape = data.shape;
+
+            Ok(LoadedData::Staged(staged))
+        }
+    }
+}
+
+/// Serialize the tensor data, in chunks when the settings have a
+/// [chunk size](PrecisionSettings::CHUNK_SIZE).
+pub(crate) fn serialize_tensor_data<E, S, Se>(
+    data: &DataSerialize<E>,
+    serializer: Se,
+) -> Result<Se::Ok, Se::Error>
+where
+    E: Serialize,
+    S: PrecisionSettings,
+    Se: Serializer,
+{
+    match S::CHUNK_SIZE {
+        Some(chunk_size) => serialize_chunks::<E, S, Se>(data, chunk_size, serializer),
+        None => serialize_data(data, S::COMPRESSION, serializer),
+    }
+}
+
+/// Deserialize the tensor data, from chunks when the settings have a
+/// [chunk size](PrecisionSettings::CHUNK_SIZE).
+///
+/// The data must fit in the [memory budget](PrecisionSettings::MEMORY_BUDGET) of the settings.
+pub(crate) fn deserialize_tensor_data<'de, E, S, De>(
+    deserializer: De,
+) -> Result<DataSerialize<E>, De::Error>
+where
+    E: Serialize + DeserializeOwned,
+    S: PrecisionSettings,
+    De: Deserializer<'de>,
+{
+    match deserialize_loaded_data::<E, S, De, false>(deserializer)? {
+        LoadedData::Host(data) => Ok(data),
+        #[cfg(feature = "std")]
+        LoadedData::Staged(_) => unreachable!("Only the data of float tensors is staged"),
+    }
+}
+
+/// Deserialize the tensor data as [deserialize_tensor_data], staging the chunks in a file when
+/// the data exceeds the [memory budget](PrecisionSettings::MEMORY_BUDGET) of the settings.
+#[cfg(feature = "std")]
+pub(crate) fn deserialize_staged_tensor_data<'de, E, S, De>(
+    deserializer: De,
+) -> Result<LoadedData<E>, De::Error>
+where
+    E: Serialize + DeserializeOwned,
+    S: PrecisionSettings,
+    De: Deserializer<'de>,
+{
+    deserialize_loaded_data::<E, S, De, true>(deserializer)
+}
+
+fn deserialize_loaded_data<'de, E, S, De, const STAGE: bool>(
+    deserializer: De,
+) -> Result<LoadedData<E>, De::Error>
+where
+    E: Serialize + DeserializeOwned,
+    S: PrecisionSettings,
+    De: Deserializer<'de>,
+{
+    if S::CHUNK_SIZE.is_some() {
+        return deserialize_chunks::<E, S, De, STAGE>(deserializer);
+    }
+
+    let data: DataSerialize<E> = deserialize_data(S::COMPRESSION, deserializer)?;
+
+    // Without chunks, the whole tensor is the smallest buffer.
+    if let Some(budget) = S::MEMORY_BUDGET {
+        check_memory_budget::<E>(data.value.len(), budget).map_err(serde::de::Error::custom)?;
+    }
+
+    Ok(LoadedData::Host(data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        record::{tensor::FloatTensorSerde, BinBytesRecorder, Recorder},
+        tensor::Tensor,
+        TestBackend,
+    };
+    use alloc::vec;
+
+    #[derive(Debug, Default, Clone)]
+    struct ChunkedSettings;
+
+    impl PrecisionSettings for ChunkedSettings {
+        type FloatElem = f32;
+        type IntElem = i32;
+        // Four values per chunk.
+        const CHUNK_SIZE: Option<usize> = Some(16);
+    }
+
+    #[test]
+    fn test_tensor_larger_than_chunk_size_round_trips_in_chunks() {
+        let device = Default::default();
+        let recorder = BinBytesRecorder::<ChunkedSettings>::default();
+        let tensor = Tensor::<TestBackend, 2>::from_floats(
+            [[0.0, 1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0, 9.0]],
+            &device,
+        );
+
+        let item = FloatTensorSerde::<ChunkedSettings>::new(tensor.to_data().serialize());
+        let value = serde_json::to_value(item).unwrap();
+        assert_eq!(value["shape"], serde_json::json!([2, 5]));
+        assert_eq!(
+            value["chunks"],
+            serde_json::json!([[0.0, 1.0, 2.0, 3.0], [4.0, 5.0, 6.0, 7.0], [8.0, 9.0]])
+        );
+
+        let bytes = recorder.record(tensor.clone(), ()).unwrap();
+        let tensor_after: Tensor<TestBackend, 2> = recorder.load(bytes, &device).unwrap();
+
+        assert_eq!(tensor.into_data(), tensor_after.into_data());
+    }
+
+    #[derive(Debug, Default, Clone)]
+    struct BudgetSettings<const BUDGET: usize>;
+
+    impl<const BUDGET: usize> PrecisionSettings for BudgetSettings<BUDGET> {
+        type FloatElem = f32;
+        type IntElem = i32;
+        // Four values per chunk.
+        const CHUNK_SIZE: Option<usize> = Some(16);
+        const MEMORY_BUDGET: Option<usize> = Some(BUDGET);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_tensor_larger_than_memory_budget_is_staged_in_chunks() {
+        // Two chunks fit in the budget, the tensor has three.
+        type Settings = BudgetSettings<32>;
+        let device = Default::default();
+        let recorder = BinBytesRecorder::<Settings>::default();
+        let tensor = Tensor::<TestBackend, 2>::from_floats(
+            [[0.0, 1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0, 9.0]],
+            &device,
+        );
+
+        let item = FloatTensorSerde::<Settings>::new(tensor.to_data().serialize());
+        let value = serde_json::to_value(item).unwrap();
+        let data = deserialize_staged_tensor_data::<f32, Settings, _>(value).unwrap();
+        assert!(matches!(data, LoadedData::Staged(_)));
+
+        let bytes = recorder.record(tensor.clone(), ()).unwrap();
+        let tensor_after: Tensor<TestBackend, 2> = recorder.load(bytes, &device).unwrap();
+
+        assert_eq!(tensor.into_data(), tensor_after.into_data());
+    }
+
+    #[test]
+    fn test_chunk_larger_than_memory_budget_is_rejected() {
+        // A chunk of four values doesn't fit in the budget.
+        type Settings = BudgetSettings<8>;
+        let value = serde_json::json!({ "shape": [4], "chunks": [[0.0, 1.0, 2.0, 3.0]] });
+
+        let err = serde_json::from_value::<FloatTensorSerde<Settings>>(value)
+            .err()
+            .unwrap();
+
+        assert!(err
+            .to_string()
+            .contains("The tensor exceeds the memory budget: a buffer of 16 bytes is needed"));
+    }
+
+    #[test]
+    fn test_chunks_with_missing_values_are_rejected() {
+        let value = serde_json::json!({ "shape": [2, 5], "chunks": [[0.0, 1.0, 2.0, 3.0]] });
+
+        let err = serde_json::from_value::<FloatTensorSerde<ChunkedSettings>>(value)
+            .err()
+            .unwrap();
+
+        assert!(err
+            .to_string()
+            .contains("have 4 values, expected 10 for shape [2, 5]"));
+    }
+}
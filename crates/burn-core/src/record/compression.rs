@@ -0,0 +1,148 @@
+use super::{bin_config, RecordCompression};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Tensor data compressed with a codec, prefixed by the codec identifier.
+#[derive(Serialize, Deserialize)]
+struct CompressedData {
+    codec: u8,
+    bytes: Vec<u8>,
+}
+
+impl RecordCompression {
+    fn id(&self) -> u8 {
+        match self {
+            RecordCompression::None => 0,
+            RecordCompression::Gzip => 1,
+            RecordCompression::Zstd => 2,
+        }
+    }
+
+    fn from_id(id: u8) -> Result<Self, String> {
+        match id {
+            0 => Ok(RecordCompression::None),
+            1 => Ok(RecordCompression::Gzip),
+            2 => Ok(RecordCompression::Zstd),
+            _ => Err(format!("Unknown compression codec id {id}")),
+        }
+    }
+
+    fn compress(&self, bytes: Vec<u8>) -> Result<Vec<u8>, String> {
+        match self {
+            RecordCompression::None => Ok(bytes),
+            #[cfg(feature = "std")]
+            RecordCompression::Gzip => {
+                use std::io::Write;
+
+                let mut encoder =
+                    flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+                encoder
+                    .write_all(&bytes)
+                    .map_err(|err| format!("{err:?}"))?;
+                encoder.finish().map_err(|err| format!("{err:?}"))
+            }
+            #[cfg(feature = "zstd")]
+            RecordCompression::Zstd => {
+                zstd::encode_all(bytes.as_slice(), 0).map_err(|err| format!("{err:?}"))
+            }
+            #[allow(unreachable_patterns)]
+            _ => Err(format!(
+                "{self:?} compression isn't supported with the enabled features"
+            )),
+        }
+    }
+
+    fn decompress(&self, bytes: Vec<u8>) -> Result<Vec<u8>, String> {
+        match self {
+            RecordCompression::None => Ok(bytes),
+            #[cfg(feature = "std")]
+            RecordCompression::Gzip => {
+                use std::io::Read;
+
+                let mut decoder = flate2::read::GzDecoder::new(bytes.as_slice());
+                let mut output = Vec::new();
+                decoder
+                    .read_to_end(&mut output)
+                    .map_err(|err| format!("{err:?}"))?;
+                Ok(output)
+            }
+            #[cfg(feature = "zstd")]
+            RecordCompression::Zstd => {
+                zstd::decode_all(bytes.as_slice()).map_err(|err| format!("{err:?}"))
+            }
+            #[allow(unreachable_patterns)]
+            _ => Err(format!(
+                "{self:?} decompression isn't supported with the enabled features"
+            )),
+        }
+    }
+}
+
+/// Serialize the tensor data, compressing it with the given codec.
+///
+/// Without compression, the data is serialized as is to stay compatible with existing records.
+pub(crate) fn serialize_data<T, Se>(
+    data: &T,
+    compression: RecordCompression,
+    serializer: Se,
+) -> Result<Se::Ok, Se::Error>
+where
+    T: Serialize,
+    Se: Serializer,
+{
+    if compression == RecordCompression::None {
+        return data.serialize(serializer);
+    }
+
+    let bytes = bincode::serde::encode_to_vec(data, bin_config())
+        .map_err(|err| serde::ser::Error::custom(format!("{err:?}")))?;
+    let bytes = compression
+        .compress(bytes)
+        .map_err(serde::ser::Error::custom)?;
+
+    CompressedData {
+        codec: compression.id(),
+        bytes,
+    }
+    .serialize(serializer)
+}
+
+/// Deserialize the tensor data.
+///
+/// When the settings use compression, the codec stored with the data is used to decompress it,
+/// whichever codec the settings use. The data written without a codec can't be told apart from
+/// compressed data in every format, so the settings tell whether the data is compressed.
+pub(crate) fn deserialize_data<'de, T, De>(
+    compression: RecordCompression,
+    deserializer: De,
+) -> Result<T, De::Error>
+where
+    T: DeserializeOwned,
+    De: Deserializer<'de>,
+{
+    use serde::de::Error;
+
+    if compression == RecordCompression::None {
+        return T::deserialize(deserializer).map_err(|err| {
+            De::Error::custom(format!(
+                "{err}, the tensor data may be compressed while the settings don't use compression"
+            ))
+        });
+    }
+
+    let compressed = CompressedData::deserialize(deserializer).map_err(|err| {
+        De::Error::custom(format!(
+            "{err}, the tensor data may not be compressed while the settings use {compression:?} \
+             compression"
+        ))
+    })?;
+    let bytes = RecordCompression::from_id(compressed.codec)
+        .and_then(|codec| codec.decompress(compressed.bytes))
+        .map_err(serde::de::Error::custom)?;
+    let (data, _) = bincode::serde::decode_from_slice(&bytes, bin_config())
+        .map_err(|err| serde::de::Error::custom(format!("{err:?}")))?;
+
+    Ok(data)
+}
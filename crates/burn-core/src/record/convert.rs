@@ -0,0 +1,107 @@
+use super::{Record, Recorder, RecorderError};
+use burn_tensor::backend::Backend;
+
+/// Load a record with a recorder and save it with another one, e.g. to change the format or the
+/// precision of a checkpoint without retraining.
+///
+/// The record is loaded into tensors on the given device, so the float, int and bool tensors keep
+/// their kind and their path, and the [tags](super::BurnMetadata::tags) of the loaded record are
+/// written in the converted one. The values are then converted to the elements of the
+/// [settings](Recorder::Settings) of `to`, which is lossy when they are narrower than the ones of
+/// `from`, e.g. `f32` floats saved as [half precision](super::HalfPrecisionSettings) `f16`, or
+/// `i64` integers that don't fit in `i32`.
+///
+/// Since records are typed, a conversion tool has to be built for a given module, e.g.
+///
+/// ```ignore
+/// let from = NamedMpkFileRecorder::<FullPrecisionSettings>::new();
+/// let to = BinGzFileRecorder::<HalfPrecisionSettings>::new();
+///
+/// convert_record::<B, ModelRecord<B>, _, _>(&from, &to, input.into(), output.into(), &device)?;
+/// ```
+pub fn convert_record<B, R, FR, TR>(
+    from: &FR,
+    to: &TR,
+    load_args: FR::LoadArgs,
+    record_args: TR::RecordArgs,
+    device: &B::Device,
+) -> Result<TR::RecordOutput, RecorderError>
+where
+    B: Backend,
+    R: Record<B>,
+    FR: Recorder<B>,
+    TR: Recorder<B>,
+{
+    let tags = from.load_metadata(load_args.clone())?.tags;
+    let record: R = from.load(load_args, device)?;
+
+    to.record_with_tags(record, tags, record_args)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{
+        record::{
+            BinGzFileRecorder, FullPrecisionSettings, HalfPrecisionSettings, NamedMpkFileRecorder,
+        },
+        tensor::{Bool, Int, Tensor},
+        TestBackend,
+    };
+    use alloc::collections::BTreeMap;
+
+    type TensorsRecord = (
+        Tensor<TestBackend, 2>,
+        Tensor<TestBackend, 2, Int>,
+        Tensor<TestBackend, 2, Bool>,
+    );
+
+    #[test]
+    fn test_convert_round_trip_keeps_tensors_and_tags() {
+        let device = Default::default();
+        let tensors: TensorsRecord = (
+            Tensor::from_floats([[1.0, -2.5], [1.2345, 1e-3]], &device),
+            Tensor::from_ints([[1, -2], [300, 4]], &device),
+            Tensor::from_bool([[true, false], [false, true]].into(), &device),
+        );
+        let tags = BTreeMap::from([("step".to_string(), "1000".to_string())]);
+        let mpk = NamedMpkFileRecorder::<FullPrecisionSettings>::new();
+        let bin = BinGzFileRecorder::<HalfPrecisionSettings>::new();
+        let file = std::env::temp_dir().join("burn_test_convert_record");
+
+        mpk.record_with_tags(tensors.clone(), tags.clone(), file.clone())
+            .unwrap();
+        convert_record::<TestBackend, TensorsRecord, _, _>(
+            &mpk,
+            &bin,
+            file.clone(),
+            file.clone(),
+            &device,
+        )
+        .unwrap();
+        convert_record::<TestBackend, TensorsRecord, _, _>(
+            &bin,
+            &mpk,
+            file.clone(),
+            file.clone(),
+            &device,
+        )
+        .unwrap();
+
+        let loaded: TensorsRecord = mpk.load(file.clone(), &device).unwrap();
+        assert_eq!(
+            Recorder::<TestBackend>::load_metadata(&mpk, file)
+                .unwrap()
+                .tags,
+            tags
+        );
+
+        // The floats went through f16.
+        tensors
+            .0
+            .to_data()
+            .assert_approx_eq(&loaded.0.into_data(), 2);
+        assert_eq!(tensors.1.to_data(), loaded.1.into_data());
+        assert_eq!(tensors.2.to_data(), loaded.2.into_data());
+    }
+}
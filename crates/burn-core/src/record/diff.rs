@@ -0,0 +1,313 @@
+use super::summary::TensorData;
+use super::{FullPrecisionSettings, Record, Recorder, RecorderError};
+use alloc::collections::BTreeMap;
+use burn_tensor::backend::Backend;
+use serde_json::Value;
+
+/// Difference between the tensors located at the same path of two records.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TensorDiff {
+    /// Path of the tensor in the records, e.g. `layers.0.weight`.
+    pub path: String,
+    /// Shape of the tensor in the first record.
+    pub lhs_shape: Vec<usize>,
+    /// Shape of the tensor in the second record.
+    pub rhs_shape: Vec<usize>,
+    /// Largest absolute difference between two elements, `None` when the shapes differ.
+    pub max_abs_diff: Option<f64>,
+    /// Norm of the element-wise difference divided by the norm of the first tensor, `None` when
+    /// the shapes differ.
+    pub relative_diff: Option<f64>,
+}
+
+impl TensorDiff {
+    /// Whether the tensor has the same shape in both records.
+    pub fn shapes_match(&self) -> bool {
+        self.lhs_shape == self.rhs_shape
+    }
+
+    /// Whether the tensors differ, in shape or in value.
+    pub fn is_changed(&self) -> bool {
+        self.max_abs_diff != Some(0.0)
+    }
+}
+
+/// Difference between the tensors of two records, see [diff_records].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RecordDiff {
+    /// Tensors present in both records, sorted by path.
+    pub tensors: Vec<TensorDiff>,
+    /// Paths of the tensors only present in the first record, sorted.
+    pub only_in_lhs: Vec<String>,
+    /// Paths of the tensors only present in the second record, sorted.
+    pub only_in_rhs: Vec<String>,
+}
+
+impl RecordDiff {
+    /// The tensors present in both records that differ, in shape or in value.
+    pub fn changed(&self) -> impl Iterator<Item = &TensorDiff> {
+        self.tensors.iter().filter(|tensor| tensor.is_changed())
+    }
+
+    /// Whether both records have the same tensors with the same values.
+    pub fn is_identical(&self) -> bool {
+        self.only_in_lhs.is_empty()
+            && self.only_in_rhs.is_empty()
+            && self.changed().next().is_none()
+    }
+}
+
+impl core::fmt::Display for RecordDiff {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for tensor in self.tensors.iter() {
+            match (tensor.max_abs_diff, tensor.relative_diff) {
+                (Some(max_abs_diff), Some(relative_diff)) => writeln!(
+                    f,
+                    "{}: max abs diff {max_abs_diff:e}, relative diff {relative_diff:e}",
+                    tensor.path
+                )?,
+                _ => writeln!(
+                    f,
+                    "{}: shape {:?} != {:?}",
+                    tensor.path, tensor.lhs_shape, tensor.rhs_shape
+                )?,
+            }
+        }
+
+        for path in self.only_in_lhs.iter() {
+            writeln!(f, "{path}: only in the first record")?;
+        }
+        for path in self.only_in_rhs.iter() {
+            writeln!(f, "{path}: only in the second record")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Load two records of the same type with the given recorder and compare their tensors path by
+/// path, see [diff_records].
+///
+/// The records are loaded as with [load](Recorder::load), which leaves them unchanged.
+pub fn load_and_diff_records<R, B, Rec>(
+    recorder: &Rec,
+    lhs: Rec::LoadArgs,
+    rhs: Rec::LoadArgs,
+    device: &B::Device,
+) -> Result<RecordDiff, RecorderError>
+where
+    R: Record<B>,
+    B: Backend,
+    Rec: Recorder<B>,
+{
+    let lhs: R = recorder.load(lhs, device)?;
+    let rhs: R = recorder.load(rhs, device)?;
+
+    diff_records(lhs, rhs)
+}
+
+/// Compare the tensors of two records path by path, e.g. to find where two training runs diverge.
+///
+/// The records are converted to [full precision](FullPrecisionSettings) items, so float, int and
+/// bool tensors are all compared as floats. Recorded records can be compared with
+/// [load_and_diff_records].
+pub fn diff_records<B: Backend, R: Record<B>>(lhs: R, rhs: R) -> Result<RecordDiff, RecorderError> {
+    let lhs = record_tensors(lhs)?;
+    let mut rhs = record_tensors(rhs)?;
+    let mut diff = RecordDiff::default();
+
+    for (path, lhs) in lhs {
+        match rhs.remove(&path) {
+            Some(rhs) => diff.tensors.push(tensor_diff(path, lhs, rhs)),
+            None => diff.only_in_lhs.push(path),
+        }
+    }
+    diff.only_in_rhs = rhs.into_keys().collect();
+
+    Ok(diff)
+}
+
+/// Shape and values of a serialized tensor.
+type TensorValues = (Vec<usize>, Vec<f64>);
+
+fn record_tensors<B: Backend, R: Record<B>>(
+    record: R,
+) -> Result<BTreeMap<String, TensorValues>, RecorderError> {
+    let value = serde_json::to_value(record.into_item::<FullPrecisionSettings>())
+        .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+    let mut tensors = BTreeMap::new();
+    collect_tensors(value, "", &mut tensors);
+
+    Ok(tensors)
+}
+
+fn collect_tensors(value: Value, path: &str, tensors: &mut BTreeMap<String, TensorValues>) {
+    match value {
+        Value::Object(mut map) => {
+            if let Some(data) = TensorData::from_map(&map) {
+                tensors.insert(path.to_string(), (data.shape(), data.values()));
+                return;
+            }
+
+            // Parameters wrap their tensor with their id.
+            if map.len() == 2 && map.contains_key("id") {
+                if let Some(param) = map.remove("param") {
+                    return collect_tensors(param, path, tensors);
+                }
+            }
+
+            for (field, value) in map {
+                collect_tensors(value, &join_path(path, &field), tensors);
+            }
+        }
+        Value::Array(values) => {
+            for (index, value) in values.into_iter().enumerate() {
+                collect_tensors(value, &join_path(path, &index.to_string()), tensors);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn join_path(path: &str, field: &str) -> String {
+    if path.is_empty() {
+        field.to_string()
+    } else {
+        format!("{path}.{field}")
+    }
+}
+
+fn tensor_diff(path: String, lhs: TensorValues, rhs: TensorValues) -> TensorDiff {
+    let (lhs_shape, lhs) = lhs;
+    let (rhs_shape, rhs) = rhs;
+
+    if lhs_shape != rhs_shape || lhs.len() != rhs.len() {
+        return TensorDiff {
+            path,
+            lhs_shape,
+            rhs_shape,
+            max_abs_diff: None,
+            relative_diff: None,
+        };
+    }
+
+    let mut max_abs_diff = 0.0;
+    let mut diff_norm = 0.0;
+    for (a, b) in lhs.iter().zip(rhs.iter()) {
+        // Non finite values at the same position are considered equal.
+        let diff = if a.is_nan() && b.is_nan() {
+            0.0
+        } else {
+            (a - b).abs()
+        };
+
+        if diff.is_nan() || diff > max_abs_diff {
+            max_abs_diff = diff;
+        }
+        diff_norm += diff * diff;
+    }
+
+    let norm = lhs
+        .iter()
+        .filter(|value| !value.is_nan())
+        .map(|value| value * value)
+        .sum::<f64>()
+        .sqrt();
+    let diff_norm = diff_norm.sqrt();
+    // A tensor of zeros that changed has an infinite relative difference.
+    let relative_diff = if diff_norm == 0.0 {
+        0.0
+    } else {
+        diff_norm / norm
+    };
+
+    TensorDiff {
+        path,
+        lhs_shape,
+        rhs_shape,
+        max_abs_diff: Some(max_abs_diff),
+        relative_diff: Some(relative_diff),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        module::Module,
+        nn::{Linear, LinearConfig, LinearRecord},
+        optim::{GradientsParams, Optimizer, SgdConfig},
+        record::BinBytesRecorder,
+        tensor::{Distribution, Tensor},
+        TestAutodiffBackend, TestBackend,
+    };
+
+    #[test]
+    fn test_only_updated_params_differ_after_optimizer_step() {
+        let device = Default::default();
+        let layers: Vec<Linear<TestAutodiffBackend>> = vec![
+            LinearConfig::new(4, 4).init(&device),
+            LinearConfig::new(4, 4).init(&device),
+        ];
+        let input =
+            Tensor::<TestAutodiffBackend, 2>::random([2, 4], Distribution::Default, &device);
+
+        // Only the first layer is used, the second one doesn't have gradients.
+        let loss = layers[0].forward(input).sum();
+        let grads = GradientsParams::from_grads(loss.backward(), &layers);
+        let mut optim = SgdConfig::new().init::<TestAutodiffBackend, Vec<Linear<_>>>();
+        let updated = optim.step(0.1, layers.clone(), grads);
+
+        let diff = diff_records(layers.into_record(), updated.into_record()).unwrap();
+
+        let changed = diff
+            .changed()
+            .map(|tensor| tensor.path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(changed, vec!["0.bias", "0.weight"]);
+        assert_eq!(diff.tensors.len(), 4);
+        assert!(diff
+            .changed()
+            .all(|tensor| tensor.relative_diff.unwrap() > 0.0));
+        assert!(diff.only_in_lhs.is_empty() && diff.only_in_rhs.is_empty());
+    }
+
+    #[test]
+    fn test_diff_reports_shape_mismatches_and_missing_paths() {
+        let device = Default::default();
+        let lhs: Vec<Linear<TestAutodiffBackend>> = vec![
+            LinearConfig::new(4, 2).init(&device),
+            LinearConfig::new(2, 2).init(&device),
+        ];
+        let rhs: Vec<Linear<TestAutodiffBackend>> = vec![LinearConfig::new(4, 3).init(&device)];
+
+        let diff = diff_records(lhs.into_record(), rhs.into_record()).unwrap();
+
+        assert!(diff.tensors.iter().all(|tensor| !tensor.shapes_match()));
+        assert_eq!(diff.tensors[0].path, "0.bias");
+        assert_eq!(diff.tensors[0].max_abs_diff, None);
+        assert_eq!(diff.only_in_lhs, vec!["1.bias", "1.weight"]);
+        assert!(diff.only_in_rhs.is_empty());
+        assert!(!diff.is_identical());
+    }
+
+    #[test]
+    fn test_load_and_diff_records_of_the_same_module_are_identical() {
+        let device = Default::default();
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::new();
+        let linear: Linear<TestBackend> = LinearConfig::new(4, 2).init(&device);
+        let bytes = Recorder::<TestBackend>::record(&recorder, linear.into_record(), ()).unwrap();
+
+        let diff = load_and_diff_records::<LinearRecord<TestBackend>, _, _>(
+            &recorder,
+            bytes.clone(),
+            bytes,
+            &device,
+        )
+        .unwrap();
+
+        assert_eq!(diff.tensors.len(), 2);
+        assert!(diff.is_identical());
+    }
+}
@@ -0,0 +1,171 @@
+use super::{Record, Recorder, RecorderError};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use burn_tensor::{backend::Backend, DType, Element};
+use core::cell::RefCell;
+
+/// Element type conversion of a tensor loaded by
+/// [load_with_dtype_report], from the element of the
+/// [settings](super::PrecisionSettings) of the record to the element of the backend.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DTypeConversion {
+    /// The path of the tensor in the record, e.g. `linear.weight`.
+    pub path: String,
+    /// The element type of the tensor in the record.
+    pub from: DType,
+    /// The element type of the loaded tensor.
+    pub to: DType,
+}
+
+impl DTypeConversion {
+    /// Whether some values of the record may not be represented exactly by the loaded tensor,
+    /// e.g. `f32` values loaded into `f16` tensors, while `f16` values loaded into `f32` tensors
+    /// are upcast without loss.
+    pub fn is_lossy(&self) -> bool {
+        use DType::*;
+
+        let lossless = match (self.from, self.to) {
+            (F16 | BF16, F32 | F64) | (F32, F64) => true,
+            (I8, I16 | I32 | I64) | (I16, I32 | I64) | (I32, I64) => true,
+            (U8, U32 | U64 | I16 | I32 | I64) | (U32, U64 | I64) => true,
+            (from, to) => from == to,
+        };
+
+        !lossless
+    }
+}
+
+/// Load a record with the given recorder as with [load](Recorder::load), also reporting the
+/// tensors whose element type was converted, e.g. the `f16` tensors of a
+/// [half precision](super::HalfPrecisionSettings) record loaded into a backend with `f32` floats,
+/// to know the native precision of a checkpoint.
+///
+/// The conversions are reported in the order the tensors are loaded, with the path of the tensor
+/// in the record. Tensors loaded with the element type of the record aren't converted and aren't
+/// reported, see [is_lossy](DTypeConversion::is_lossy) to tell the upcasts from the conversions
+/// losing precision.
+pub fn load_with_dtype_report<R, B, Rec>(
+    recorder: &Rec,
+    args: Rec::LoadArgs,
+    device: &B::Device,
+) -> Result<(R, Vec<DTypeConversion>), RecorderError>
+where
+    R: Record<B>,
+    B: Backend,
+    Rec: Recorder<B>,
+{
+    let scope = DTypeReportScope::start();
+    let record = recorder.load(args, device)?;
+
+    Ok((record, scope.finish()))
+}
+
+std::thread_local! {
+    static CONVERSIONS: RefCell<Option<Vec<DTypeConversion>>> = const { RefCell::new(None) };
+}
+
+/// Collect the element type conversions of the tensors loaded while the scope is alive.
+pub(crate) struct DTypeReportScope {
+    previous: Option<Vec<DTypeConversion>>,
+}
+
+impl DTypeReportScope {
+    pub(crate) fn start() -> Self {
+        let previous = CONVERSIONS.with(|state| state.replace(Some(Vec::new())));
+
+        Self { previous }
+    }
+
+    /// The conversions collected by the scope, in the order the tensors were loaded.
+    pub(crate) fn finish(self) -> Vec<DTypeConversion> {
+        // The previous conversions are restored when the scope is dropped.
+        CONVERSIONS
+            .with(|state| state.borrow_mut().take())
+            .unwrap_or_default()
+    }
+}
+
+impl Drop for DTypeReportScope {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        CONVERSIONS.with(|state| state.replace(previous));
+    }
+}
+
+/// Notify that the tensor at the given path was converted from the `From` to the `To` element.
+///
+/// Conversions to the same element type aren't conversions and are omitted.
+pub(crate) fn tensor_converted<From: Element, To: Element>(path: &str) {
+    if From::dtype() == To::dtype() {
+        return;
+    }
+
+    CONVERSIONS.with(|state| {
+        if let Some(conversions) = state.borrow_mut().as_mut() {
+            conversions.push(DTypeConversion {
+                path: path.to_string(),
+                from: From::dtype(),
+                to: To::dtype(),
+            });
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        module::Module,
+        nn::{Linear, LinearConfig, LinearRecord},
+        record::{BinBytesRecorder, FullPrecisionSettings, HalfPrecisionSettings, Recorder},
+        TestBackend,
+    };
+
+    #[test]
+    fn test_load_with_dtype_report_lists_upcast_tensors() {
+        let device = Default::default();
+        let recorder = BinBytesRecorder::<HalfPrecisionSettings>::new();
+        let linear: Linear<TestBackend> = LinearConfig::new(4, 2).init(&device);
+        let bytes = Recorder::<TestBackend>::record(&recorder, linear.into_record(), ()).unwrap();
+
+        let (_, conversions) =
+            load_with_dtype_report::<LinearRecord<TestBackend>, _, _>(&recorder, bytes, &device)
+                .unwrap();
+
+        let expected = ["weight", "bias"].map(|path| DTypeConversion {
+            path: path.to_string(),
+            from: DType::F16,
+            to: DType::F32,
+        });
+        assert_eq!(conversions, expected);
+        assert!(conversions.iter().all(|conversion| !conversion.is_lossy()));
+    }
+
+    #[test]
+    fn test_load_with_dtype_report_omits_tensors_with_the_same_dtype() {
+        let device = Default::default();
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::new();
+        let linear: Linear<TestBackend> = LinearConfig::new(4, 2).init(&device);
+        let bytes = Recorder::<TestBackend>::record(&recorder, linear.into_record(), ()).unwrap();
+
+        let (_, conversions) =
+            load_with_dtype_report::<LinearRecord<TestBackend>, _, _>(&recorder, bytes, &device)
+                .unwrap();
+
+        assert!(conversions.is_empty());
+    }
+
+    #[test]
+    fn test_dtype_conversion_is_lossy_when_narrowing() {
+        let conversion = |from, to| DTypeConversion {
+            path: "weight".to_string(),
+            from,
+            to,
+        };
+
+        assert!(conversion(DType::F32, DType::F16).is_lossy());
+        assert!(conversion(DType::F16, DType::BF16).is_lossy());
+        assert!(conversion(DType::I64, DType::I32).is_lossy());
+        assert!(!conversion(DType::I32, DType::I64).is_lossy());
+    }
+}
@@ -0,0 +1,218 @@
+use super::{
+    bin_config, file::AtomicFileWriter, FileRecorder, PrecisionSettings, Recorder, RecorderError,
+};
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use burn_tensor::backend::Backend;
+use core::marker::PhantomData;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{io::Write, path::PathBuf};
+
+/// Marker written at the start of an encrypted record.
+const MAGIC: &[u8; 4] = b"BENC";
+/// Size of the AES-GCM nonce written after the marker.
+const NONCE_SIZE: usize = 12;
+
+/// 256-bit key used to encrypt and decrypt records.
+///
+/// The key is never printed, its [Debug](core::fmt::Debug) implementation is redacted.
+#[derive(Clone, PartialEq, Eq)]
+pub struct RecordKey([u8; 32]);
+
+impl RecordKey {
+    /// Create a key from its bytes.
+    pub fn new(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl core::fmt::Debug for RecordKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("RecordKey(<redacted>)")
+    }
+}
+
+/// File recorder encrypting records in the [bincode format](bincode) with AES-256-GCM.
+///
+/// The serialized record is encrypted with a random nonce written in the header of the file.
+/// Tensors are compressed with the [compression](PrecisionSettings::COMPRESSION) of the settings
+/// before the record is encrypted. A record loaded with another key, or modified on disk, fails
+/// with [DecryptionFailed](RecorderError::DecryptionFailed) before any tensor is created.
+///
+/// The [default](Default) recorder has no key and fails to save and load records, use
+/// [with_key](EncryptedFileRecorder::with_key) instead.
+#[derive(Debug, Clone)]
+pub struct EncryptedFileRecorder<S: PrecisionSettings> {
+    key: Option<RecordKey>,
+    _settings: PhantomData<S>,
+}
+
+impl<S: PrecisionSettings> EncryptedFileRecorder<S> {
+    /// Create a recorder encrypting records with the given key.
+    pub fn with_key(key: RecordKey) -> Self {
+        Self {
+            key: Some(key),
+            _settings: PhantomData,
+        }
+    }
+
+    fn cipher(&self) -> Result<Aes256Gcm, RecorderError> {
+        let key = self.key.as_ref().ok_or_else(|| {
+            RecorderError::Unknown("The encrypted file recorder has no key".to_string())
+        })?;
+
+        Ok(Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key.0)))
+    }
+}
+
+impl<S: PrecisionSettings> Default for EncryptedFileRecorder<S> {
+    fn default() -> Self {
+        Self {
+            key: None,
+            _settings: PhantomData,
+        }
+    }
+}
+
+impl<S: PrecisionSettings, B: Backend> FileRecorder<B> for EncryptedFileRecorder<S> {
+    fn file_extension() -> &'static str {
+        "bin.enc"
+    }
+}
+
+impl<S: PrecisionSettings, B: Backend> Recorder<B> for EncryptedFileRecorder<S> {
+    type Settings = S;
+    type RecordArgs = PathBuf;
+    type RecordOutput = ();
+    type LoadArgs = PathBuf;
+
+    fn save_item<I: Serialize>(
+        &self,
+        item: I,
+        mut file: Self::RecordArgs,
+    ) -> Result<(), RecorderError> {
+        let cipher = self.cipher()?;
+        let payload = bincode::serde::encode_to_vec(&item, bin_config())
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = cipher
+            .encrypt(&nonce, payload.as_slice())
+            .map_err(|_| RecorderError::Unknown("Failed to encrypt the record".to_string()))?;
+
+        let mut bytes = Vec::with_capacity(MAGIC.len() + NONCE_SIZE + ciphertext.len());
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&nonce);
+        bytes.extend_from_slice(&ciphertext);
+
+        file.set_extension(<Self as FileRecorder<B>>::file_extension());
+
+        let mut writer = AtomicFileWriter::create(file)?;
+        writer.write_all(&bytes).map_err(io_error)?;
+        writer.commit()
+    }
+
+    fn load_item<I: DeserializeOwned>(&self, mut file: Self::LoadArgs) -> Result<I, RecorderError> {
+        let cipher = self.cipher()?;
+
+        file.set_extension(<Self as FileRecorder<B>>::file_extension());
+        let bytes = std::fs::read(&file).map_err(io_error)?;
+
+        if bytes.len() < MAGIC.len() + NONCE_SIZE || &bytes[..MAGIC.len()] != MAGIC {
+            return Err(RecorderError::DeserializeError(format!(
+                "{} isn't an encrypted record",
+                file.display()
+            )));
+        }
+
+        let (nonce, ciphertext) = bytes[MAGIC.len()..].split_at(NONCE_SIZE);
+        let payload = cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| {
+                RecorderError::DecryptionFailed(format!(
+                    "Wrong key or corrupted record for {}",
+                    file.display()
+                ))
+            })?;
+
+        let (state, _) = bincode::serde::decode_from_slice(&payload, bin_config())
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        Ok(state)
+    }
+}
+
+fn io_error(err: std::io::Error) -> RecorderError {
+    match err.kind() {
+        std::io::ErrorKind::NotFound => RecorderError::FileNotFound(err.to_string()),
+        _ => RecorderError::Unknown(err.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        module::Module,
+        nn::{Linear, LinearConfig},
+        record::{FullPrecisionSettings, PrecisionSettings, RecordCompression},
+        TestBackend,
+    };
+
+    #[derive(Debug, Default, Clone)]
+    struct GzipSettings;
+
+    impl PrecisionSettings for GzipSettings {
+        type FloatElem = f32;
+        type IntElem = i32;
+        const COMPRESSION: RecordCompression = RecordCompression::Gzip;
+    }
+
+    fn linear() -> Linear<TestBackend> {
+        LinearConfig::new(16, 8).init(&Default::default())
+    }
+
+    #[test]
+    fn test_encrypted_round_trip() {
+        let file = std::env::temp_dir().join("burn_test_encrypted_round_trip");
+        let device = Default::default();
+        let recorder = EncryptedFileRecorder::<GzipSettings>::with_key(RecordKey::new([7; 32]));
+        let expected = linear();
+
+        expected.clone().save_file(file.clone(), &recorder).unwrap();
+
+        let bytes = std::fs::read(file.with_extension("bin.enc")).unwrap();
+        assert_eq!(&bytes[..MAGIC.len()], MAGIC);
+
+        let loaded = linear().load_file(file, &recorder, &device).unwrap();
+
+        loaded
+            .weight
+            .to_data()
+            .assert_approx_eq(&expected.weight.to_data(), 6);
+    }
+
+    #[test]
+    fn err_when_wrong_key() {
+        let file = std::env::temp_dir().join("burn_test_encrypted_wrong_key");
+        let device = Default::default();
+        let recorder =
+            EncryptedFileRecorder::<FullPrecisionSettings>::with_key(RecordKey::new([7; 32]));
+        let wrong_recorder =
+            EncryptedFileRecorder::<FullPrecisionSettings>::with_key(RecordKey::new([8; 32]));
+
+        linear().save_file(file.clone(), &recorder).unwrap();
+
+        let result = linear().load_file(file, &wrong_recorder, &device);
+
+        assert!(matches!(result, Err(RecorderError::DecryptionFailed(_))));
+    }
+
+    #[test]
+    fn key_is_not_printed() {
+        let recorder =
+            EncryptedFileRecorder::<FullPrecisionSettings>::with_key(RecordKey::new([42; 32]));
+
+        assert!(!format!("{recorder:?}").contains("42"));
+    }
+}
@@ -0,0 +1,577 @@
+use super::tensor::float_tensor_from_value;
+use super::{
+    bin_config, migrate_tensors, record_field_path, select_field, PrecisionSettings,
+    RecordCompression, Recorder, RecorderError, SelfDescribingRecorder,
+};
+use burn_tensor::{backend::Backend, bf16, f16, DType, DataSerialize, Tensor};
+use core::cell::RefCell;
+use core::marker::PhantomData;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+/// Key of the objects replacing the tensor data in the manifest.
+const EXTERNAL_DATA_KEY: &str = "external_data";
+
+/// File recorder writing the structure of a record, with the paths, shapes and element types of
+/// its tensors, to a small json manifest, and the tensor data to separate blob files next to it,
+/// like the external data of ONNX models.
+///
+/// Each tensor of the manifest references the range of the blob holding its values, encoded
+/// with the [bincode format](bincode), so tools can list the tensors cheaply with
+/// [tensors](ExternalDataFileRecorder::tensors), and
+/// [load_tensor](SelfDescribingRecorder::load_tensor) only reads the blob range of the selected
+/// tensor. The tensors are written to a single blob, unless a
+/// [maximum blob size](ExternalDataFileRecorder::with_max_blob_size) is set. The blobs are
+/// named after the manifest, e.g. `model.0.blob` for the manifest `model.manifest.json`, and are
+/// written before it, so a manifest only references complete blobs.
+///
+/// Quantized tensors and the tensors of settings with compression or chunks stay in the
+/// manifest.
+#[derive(new, Debug, Default, Clone)]
+pub struct ExternalDataFileRecorder<S: PrecisionSettings> {
+    #[new(default)]
+    max_blob_size: Option<u64>,
+    _settings: PhantomData<S>,
+}
+
+/// Tensor of a manifest written by an [ExternalDataFileRecorder], with the range of the blob
+/// holding its values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExternalTensor {
+    /// Path of the tensor in the record, e.g. `layers.0.weight`.
+    pub path: String,
+    /// Shape of the tensor.
+    pub shape: Vec<usize>,
+    /// Element type of the values.
+    pub dtype: DType,
+    /// File name of the blob, in the directory of the manifest.
+    pub blob: String,
+    /// Offset of the encoded values in the blob, in bytes.
+    pub offset: u64,
+    /// Length of the encoded values, in bytes.
+    pub length: u64,
+}
+
+/// Reference written in the manifest in place of the tensor data.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ExternalData {
+    shape: Vec<usize>,
+    dtype: DType,
+    blob: String,
+    offset: u64,
+    length: u64,
+}
+
+#[derive(Serialize)]
+pub(crate) struct ExternalDataRef {
+    external_data: ExternalData,
+}
+
+impl ExternalData {
+    fn from_map(map: &Map<String, Value>) -> Option<Self> {
+        match map.get(EXTERNAL_DATA_KEY) {
+            Some(data) if map.len() == 1 => serde_json::from_value(data.clone()).ok(),
+            _ => None,
+        }
+    }
+}
+
+impl<S: PrecisionSettings> ExternalDataFileRecorder<S> {
+    /// File extension of the manifest.
+    pub fn file_extension() -> &'static str {
+        "manifest.json"
+    }
+
+    /// Start a new blob once the current one would exceed the given size, in bytes. A tensor
+    /// larger than the size is written alone in its blob.
+    pub fn with_max_blob_size(mut self, max_blob_size: u64) -> Self {
+        self.max_blob_size = Some(max_blob_size);
+        self
+    }
+
+    /// List the tensors referenced by the manifest, sorted by path, without reading the blobs.
+    pub fn tensors(&self, mut file: PathBuf) -> Result<Vec<ExternalTensor>, RecorderError> {
+        file.set_extension(Self::file_extension());
+        let manifest = read_manifest(&file)?;
+        let mut tensors = Vec::new();
+
+        if let Some(item) = manifest.get("item") {
+            collect_tensors(item, "", &mut tensors);
+        }
+        tensors.sort_by(|a, b| a.path.cmp(&b.path));
+
+        Ok(tensors)
+    }
+}
+
+impl<S: PrecisionSettings, B: Backend> Recorder<B> for ExternalDataFileRecorder<S> {
+    type Settings = S;
+    type RecordArgs = PathBuf;
+    type RecordOutput = ();
+    type LoadArgs = PathBuf;
+    const SELF_DESCRIBING: bool = true;
+
+    fn save_item<I: Serialize>(
+        &self,
+        item: I,
+        file: Self::RecordArgs,
+    ) -> Result<(), RecorderError> {
+        // Add parent directories if they don't exist
+        if let Some(parent) = file.parent() {
+            std::fs::create_dir_all(parent).ok();
+        }
+
+        let scope = ExternalScope::start(BlobWriter::new(file.clone(), self.max_blob_size));
+        let manifest = serde_json::to_value(item);
+        let blobs = scope.finish();
+        let manifest = manifest.map_err(|err| RecorderError::Unknown(err.to_string()))?;
+        let written = blobs.finish()?;
+
+        // The blobs of a previous record with more blobs aren't referenced anymore.
+        (written..)
+            .map(|index| blob_path(&file, index))
+            .take_while(|blob| blob.exists())
+            .try_for_each(std::fs::remove_file)
+            .map_err(io_error)?;
+
+        let manifest_file = file.with_extension(Self::file_extension());
+        let mut writer = BufWriter::new(File::create(manifest_file).map_err(io_error)?);
+        serde_json::to_writer_pretty(&mut writer, &manifest)
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+        writer.flush().map_err(io_error)?;
+
+        Ok(())
+    }
+
+    fn load_item<I: DeserializeOwned>(&self, mut file: Self::LoadArgs) -> Result<I, RecorderError> {
+        file.set_extension(Self::file_extension());
+        let mut manifest = read_manifest(&file)?;
+        let mut blobs = BlobReader::new(&file);
+
+        if let Some(item) = manifest.get_mut("item") {
+            resolve_external(item, "", &mut blobs)?;
+        }
+
+        serde_json::from_value(manifest)
+            .map_err(|err| RecorderError::DeserializeError(err.to_string()))
+    }
+}
+
+impl<S: PrecisionSettings, B: Backend> SelfDescribingRecorder<B> for ExternalDataFileRecorder<S> {
+    /// Load the float tensor located at the given path of the record, only reading the blob
+    /// range holding its values.
+    fn load_tensor<const D: usize>(
+        &self,
+        mut file: Self::LoadArgs,
+        path: &str,
+        device: &B::Device,
+    ) -> Result<Tensor<B, D>, RecorderError> {
+        file.set_extension(Self::file_extension());
+        let manifest = read_manifest(&file)?;
+        let (mut value, record_version) = select_field(manifest, path)?;
+        resolve_external(&mut value, path, &mut BlobReader::new(&file))?;
+
+        migrate_tensors::<S>(&mut value, record_version);
+
+        float_tensor_from_value::<B, D, S>(value, device, path)
+    }
+}
+
+std::thread_local! {
+    static BLOBS: RefCell<Option<BlobWriter>> = const { RefCell::new(None) };
+}
+
+/// Write the data of the tensors serialized while the scope is alive to blobs.
+struct ExternalScope {
+    previous: Option<BlobWriter>,
+}
+
+impl ExternalScope {
+    fn start(blobs: BlobWriter) -> Self {
+        let previous = BLOBS.with(|state| state.replace(Some(blobs)));
+
+        Self { previous }
+    }
+
+    /// The writer of the blobs, with the last blob still open.
+    fn finish(self) -> BlobWriter {
+        // The previous writer is restored when the scope is dropped.
+        BLOBS
+            .with(|state| state.borrow_mut().take())
+            .expect("The blob writer should be set while the scope is alive")
+    }
+}
+
+impl Drop for ExternalScope {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        BLOBS.with(|state| *state.borrow_mut() = previous);
+    }
+}
+
+/// Write the values of the tensor data to the current blob when a tensor is recorded by an
+/// [ExternalDataFileRecorder], returning the reference to serialize in place of the data.
+pub(crate) fn tensor_externalized<E: Serialize, S: PrecisionSettings>(
+    data: &DataSerialize<E>,
+    dtype: DType,
+) -> Result<Option<ExternalDataRef>, RecorderError> {
+    // Compressed and chunked data is only readable by the deserializer of the tensors.
+    if S::COMPRESSION != RecordCompression::None || S::CHUNK_SIZE.is_some() {
+        return Ok(None);
+    }
+
+    BLOBS.with(|state| {
+        let mut state = state.borrow_mut();
+        let blobs = match state.as_mut() {
+            Some(blobs) => blobs,
+            None => return Ok(None),
+        };
+
+        let payload = bincode::serde::encode_to_vec(&data.value, bin_config())
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+        let (blob, offset) = blobs.write(&payload)?;
+
+        Ok(Some(ExternalDataRef {
+            external_data: ExternalData {
+                shape: data.shape.clone(),
+                dtype,
+                blob,
+                offset,
+                length: payload.len() as u64,
+            },
+        }))
+    })
+}
+
+/// Writer of the blobs of a record, starting a new blob once the current one would exceed the
+/// maximum size.
+struct BlobWriter {
+    file: PathBuf,
+    max_size: Option<u64>,
+    index: usize,
+    current: Option<(String, BufWriter<File>)>,
+    size: u64,
+}
+
+impl BlobWriter {
+    fn new(file: PathBuf, max_size: Option<u64>) -> Self {
+        Self {
+            file,
+            max_size,
+            index: 0,
+            current: None,
+            size: 0,
+        }
+    }
+
+    /// Append the payload, returning the name of its blob and its offset in the blob.
+    fn write(&mut self, payload: &[u8]) -> Result<(String, u64), RecorderError> {
+        let length = payload.len() as u64;
+
+        if let Some(max_size) = self.max_size {
+            if self.current.is_some() && self.size > 0 && self.size + length > max_size {
+                self.close_blob()?;
+            }
+        }
+
+        if self.current.is_none() {
+            let blob = blob_path(&self.file, self.index);
+            let name = blob
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            let writer = BufWriter::new(File::create(&blob).map_err(io_error)?);
+
+            self.current = Some((name, writer));
+            self.index += 1;
+            self.size = 0;
+        }
+
+        let (name, writer) = self.current.as_mut().unwrap();
+        writer.write_all(payload).map_err(io_error)?;
+        let offset = self.size;
+        self.size += length;
+
+        Ok((name.clone(), offset))
+    }
+
+    fn close_blob(&mut self) -> Result<(), RecorderError> {
+        if let Some((_, writer)) = self.current.take() {
+            let writer = writer
+                .into_inner()
+                .map_err(|err| io_error(err.into_error()))?;
+            writer.sync_all().map_err(io_error)?;
+        }
+
+        Ok(())
+    }
+
+    /// Close the last blob, returning the number of blobs written.
+    fn finish(mut self) -> Result<usize, RecorderError> {
+        self.close_blob()?;
+
+        Ok(self.index)
+    }
+}
+
+/// Reader of the blobs referenced by a manifest, keeping each blob open once read.
+struct BlobReader {
+    directory: PathBuf,
+    blobs: BTreeMap<String, File>,
+}
+
+impl BlobReader {
+    fn new(manifest: &Path) -> Self {
+        Self {
+            directory: manifest.parent().map(Path::to_path_buf).unwrap_or_default(),
+            blobs: BTreeMap::new(),
+        }
+    }
+
+    /// Read the tensor data referenced by the manifest at the given path.
+    fn read(&mut self, data: ExternalData, path: &str) -> Result<Value, RecorderError> {
+        let blob = self.directory.join(&data.blob);
+
+        if !self.blobs.contains_key(&data.blob) {
+            let file = File::open(&blob).map_err(|err| match err.kind() {
+                std::io::ErrorKind::NotFound => RecorderError::FileNotFound(format!(
+                    "The blob {} of the tensor at path {path} is missing",
+                    blob.display()
+                )),
+                _ => io_error(err),
+            })?;
+            self.blobs.insert(data.blob.clone(), file);
+        }
+
+        let file = self.blobs.get_mut(&data.blob).unwrap();
+        let size = file.metadata().map_err(io_error)?.len();
+
+        if data.offset + data.length > size {
+            return Err(RecorderError::DeserializeError(format!(
+                "The blob {} of the tensor at path {path} is truncated",
+                blob.display()
+            )));
+        }
+
+        let mut payload = vec![0; data.length as usize];
+        file.seek(SeekFrom::Start(data.offset)).map_err(io_error)?;
+        file.read_exact(&mut payload).map_err(io_error)?;
+
+        let value = decode_values(data.dtype, &payload).map_err(|err| {
+            RecorderError::DeserializeError(format!(
+                "Invalid data of the tensor at path {path}: {err}"
+            ))
+        })?;
+
+        Ok(serde_json::json!({ "value": value, "shape": data.shape }))
+    }
+}
+
+/// Replace the references of the manifest with the tensor data read from the blobs.
+fn resolve_external(
+    value: &mut Value,
+    path: &str,
+    blobs: &mut BlobReader,
+) -> Result<(), RecorderError> {
+    match value {
+        Value::Object(map) => {
+            if let Some(data) = ExternalData::from_map(map) {
+                *value = blobs.read(data, path)?;
+                return Ok(());
+            }
+
+            let is_param = is_param(map);
+            for (field, value) in map.iter_mut() {
+                let path = field_path(path, field, is_param);
+                resolve_external(value, &path, blobs)?;
+            }
+        }
+        Value::Array(values) => {
+            for (index, value) in values.iter_mut().enumerate() {
+                resolve_external(value, &record_field_path(path, &index.to_string()), blobs)?;
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+fn collect_tensors(value: &Value, path: &str, tensors: &mut Vec<ExternalTensor>) {
+    match value {
+        Value::Object(map) => {
+            if let Some(data) = ExternalData::from_map(map) {
+                tensors.push(ExternalTensor {
+                    path: path.to_string(),
+                    shape: data.shape,
+                    dtype: data.dtype,
+                    blob: data.blob,
+                    offset: data.offset,
+                    length: data.length,
+                });
+                return;
+            }
+
+            let is_param = is_param(map);
+            for (field, value) in map.iter() {
+                collect_tensors(value, &field_path(path, field, is_param), tensors);
+            }
+        }
+        Value::Array(values) => {
+            for (index, value) in values.iter().enumerate() {
+                collect_tensors(value, &record_field_path(path, &index.to_string()), tensors);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Parameters wrap their tensor with their id.
+fn is_param(map: &Map<String, Value>) -> bool {
+    map.contains_key("id") && map.contains_key("param")
+}
+
+fn field_path(path: &str, field: &str, is_param: bool) -> String {
+    match is_param && field == "param" {
+        true => path.to_string(),
+        false => record_field_path(path, field),
+    }
+}
+
+fn decode_values(dtype: DType, payload: &[u8]) -> Result<Value, String> {
+    fn decode<E: Serialize + DeserializeOwned>(payload: &[u8]) -> Result<Value, String> {
+        let (values, _): (Vec<E>, _) = bincode::serde::decode_from_slice(payload, bin_config())
+            .map_err(|err| err.to_string())?;
+
+        serde_json::to_value(values).map_err(|err| err.to_string())
+    }
+
+    match dtype {
+        DType::F64 => decode::<f64>(payload),
+        DType::F32 => decode::<f32>(payload),
+        DType::F16 => decode::<f16>(payload),
+        DType::BF16 => decode::<bf16>(payload),
+        DType::I64 => decode::<i64>(payload),
+        DType::I32 => decode::<i32>(payload),
+        DType::I16 => decode::<i16>(payload),
+        DType::I8 => decode::<i8>(payload),
+        DType::U64 => decode::<u64>(payload),
+        DType::U32 => decode::<u32>(payload),
+        DType::U8 => decode::<u8>(payload),
+        DType::Bool => decode::<bool>(payload),
+    }
+}
+
+fn blob_path(file: &Path, index: usize) -> PathBuf {
+    file.with_extension(format!("{index}.blob"))
+}
+
+fn read_manifest(file: &Path) -> Result<Value, RecorderError> {
+    let reader = BufReader::new(File::open(file).map_err(io_error)?);
+
+    serde_json::from_reader(reader).map_err(|err| RecorderError::DeserializeError(err.to_string()))
+}
+
+fn io_error(err: std::io::Error) -> RecorderError {
+    match err.kind() {
+        std::io::ErrorKind::NotFound => RecorderError::FileNotFound(err.to_string()),
+        _ => RecorderError::Unknown(err.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        module::Module,
+        nn::{Linear, LinearConfig, LinearRecord},
+        record::FullPrecisionSettings,
+        TestBackend,
+    };
+
+    type TestRecorder = ExternalDataFileRecorder<FullPrecisionSettings>;
+
+    fn record_linear(recorder: &TestRecorder, name: &str) -> (PathBuf, Linear<TestBackend>) {
+        let device = Default::default();
+        let file = std::env::temp_dir().join(name);
+        let linear = LinearConfig::new(4, 2).init::<TestBackend>(&device);
+
+        Recorder::<TestBackend>::record(recorder, linear.clone().into_record(), file.clone())
+            .unwrap();
+
+        (file, linear)
+    }
+
+    #[test]
+    fn test_manifest_references_the_blob_of_each_tensor() {
+        let recorder = TestRecorder::default();
+        let (file, linear) = record_linear(&recorder, "burn_test_external_data");
+
+        let tensors = recorder.tensors(file.clone()).unwrap();
+        let paths: Vec<_> = tensors.iter().map(|tensor| tensor.path.as_str()).collect();
+        assert_eq!(paths, vec!["bias", "weight"]);
+        assert!(tensors
+            .iter()
+            .all(|tensor| tensor.blob == "burn_test_external_data.0.blob"));
+        assert_eq!(tensors[1].shape, vec![4, 2]);
+        assert_eq!(tensors[1].dtype, DType::F32);
+
+        // The manifest doesn't hold the values.
+        let manifest =
+            std::fs::read_to_string(file.with_extension(TestRecorder::file_extension())).unwrap();
+        assert!(!manifest.contains("\"value\""));
+
+        let record: LinearRecord<TestBackend> =
+            Recorder::<TestBackend>::load(&recorder, file, &Default::default()).unwrap();
+        record
+            .weight
+            .to_data()
+            .assert_approx_eq(&linear.weight.to_data(), 6);
+    }
+
+    #[test]
+    fn test_load_tensor_only_reads_its_blob() {
+        let recorder = TestRecorder::default().with_max_blob_size(1);
+        let (file, linear) = record_linear(&recorder, "burn_test_external_data_selective");
+
+        // Each tensor is alone in its blob.
+        let tensors = recorder.tensors(file.clone()).unwrap();
+        assert_eq!(tensors.len(), 2);
+        assert_ne!(tensors[0].blob, tensors[1].blob);
+
+        let bias = tensors.iter().find(|tensor| tensor.path == "bias").unwrap();
+        std::fs::remove_file(file.with_file_name(&bias.blob)).unwrap();
+
+        let weight: Tensor<TestBackend, 2> = recorder
+            .load_tensor(file.clone(), "weight", &Default::default())
+            .unwrap();
+        weight
+            .to_data()
+            .assert_approx_eq(&linear.weight.to_data(), 6);
+
+        let result: Result<Tensor<TestBackend, 1>, _> =
+            recorder.load_tensor(file, "bias", &Default::default());
+        assert!(
+            matches!(result, Err(RecorderError::FileNotFound(message)) if message.contains("bias"))
+        );
+    }
+
+    #[test]
+    fn test_err_when_blob_is_missing() {
+        let recorder = TestRecorder::default();
+        let (file, _) = record_linear(&recorder, "burn_test_external_data_missing");
+
+        std::fs::remove_file(blob_path(&file, 0)).unwrap();
+
+        let result: Result<LinearRecord<TestBackend>, _> =
+            Recorder::<TestBackend>::load(&recorder, file, &Default::default());
+        assert!(matches!(
+            result,
+            Err(RecorderError::FileNotFound(message)) if message.contains("is missing")
+        ));
+    }
+}
@@ -1,10 +1,17 @@
-use super::{bin_config, PrecisionSettings, Recorder, RecorderError};
+use super::checksum::{checksum_trailer, verify_checksum};
+use super::{
+    bin_config, decode_headerless_bin_record, summary::summarize_record, PrecisionSettings,
+    RecordFormat, Recorder, RecorderError, SelfDescribingRecorder, RECORD_HEADER_SIZE,
+};
 use burn_tensor::backend::Backend;
 use core::marker::PhantomData;
 use flate2::{read::GzDecoder, write::GzEncoder, Compression};
 use serde::{de::DeserializeOwned, Serialize};
-use std::io::{BufReader, BufWriter};
-use std::{fs::File, path::PathBuf};
+use std::io::{BufReader, BufWriter, Chain, Cursor, Read, Write};
+use std::{
+    fs::File,
+    path::{Path, PathBuf},
+};
 
 /// Recorder trait specialized to save and load data to and from files.
 pub trait FileRecorder<B: Backend>:
@@ -41,6 +48,17 @@ pub struct PrettyJsonFileRecorder<S: PrecisionSettings> {
     _settings: PhantomData<S>,
 }
 
+/// File recorder writing a [pretty json](serde_json) summary of the record for debugging.
+///
+/// Each tensor is replaced by its shape and its min, max and mean values, and the fields are
+/// sorted by name so that the summaries of two records can be diffed. The summary can't be
+/// loaded back, and settings with compression should be avoided since compressed tensors can't be
+/// summarized.
+#[derive(new, Debug, Default, Clone)]
+pub struct JsonSummaryFileRecorder<S: PrecisionSettings> {
+    _settings: PhantomData<S>,
+}
+
 /// File recorder using the [named msgpack](rmp_serde) format compressed with gzip.
 #[derive(new, Debug, Default, Clone)]
 pub struct NamedMpkGzFileRecorder<S: PrecisionSettings> {
@@ -74,6 +92,12 @@ impl<S: PrecisionSettings, B: Backend> FileRecorder<B> for PrettyJsonFileRecorde
     }
 }
 
+impl<S: PrecisionSettings, B: Backend> FileRecorder<B> for JsonSummaryFileRecorder<S> {
+    fn file_extension() -> &'static str {
+        "summary.json"
+    }
+}
+
 impl<S: PrecisionSettings, B: Backend> FileRecorder<B> for NamedMpkGzFileRecorder<S> {
     fn file_extension() -> &'static str {
         "mpk.gz"
@@ -86,6 +110,25 @@ impl<S: PrecisionSettings, B: Backend> FileRecorder<B> for NamedMpkFileRecorder<
     }
 }
 
+impl<S: PrecisionSettings, B: Backend> SelfDescribingRecorder<B> for JsonGzFileRecorder<S> {}
+impl<S: PrecisionSettings, B: Backend> SelfDescribingRecorder<B> for PrettyJsonFileRecorder<S> {}
+impl<S: PrecisionSettings, B: Backend> SelfDescribingRecorder<B> for NamedMpkGzFileRecorder<S> {}
+impl<S: PrecisionSettings, B: Backend> SelfDescribingRecorder<B> for NamedMpkFileRecorder<S> {}
+
+/// [File recorder](FileRecorder) whose format can be written to any [writer](Write) and read
+/// from any [reader](Read), e.g. to stream a record through a network upload or a compression
+/// pipe. See [StreamRecorder](super::StreamRecorder) to record and load with a stream.
+///
+/// The formats are written in a single pass, none of them seeking back to patch a header, so the
+/// writer doesn't have to be seekable.
+pub trait StreamFileRecorder<B: Backend>: FileRecorder<B> {
+    /// Write the item after the header of the format, if it has one, returning the writer.
+    fn write_item<I: Serialize, W: Write>(item: &I, writer: W) -> Result<W, RecorderError>;
+
+    /// Read the item after the header of the format, if it has one.
+    fn read_item<I: DeserializeOwned, R: Read>(reader: R) -> Result<I, RecorderError>;
+}
+
 macro_rules! str2reader {
     (
         $file:expr
@@ -98,7 +141,7 @@ macro_rules! str2reader {
                 std::io::ErrorKind::NotFound => RecorderError::FileNotFound(err.to_string()),
                 _ => RecorderError::Unknown(err.to_string()),
             })
-            .map(|file| BufReader::new(file))
+            .map(BufReader::new)
     }};
 }
 
@@ -107,25 +150,224 @@ macro_rules! str2writer {
         $file:expr
     ) => {{
         $file.set_extension(<Self as FileRecorder<B>>::file_extension());
-        let path = $file.as_path();
 
+        AtomicFileWriter::create($file)
+    }};
+}
+
+/// Write the header of the format to the writer, if the format has one.
+fn write_header<W: Write>(format: RecordFormat, mut writer: W) -> Result<W, RecorderError> {
+    if let Some(header) = format.header() {
+        writer.write_all(&header).map_err(io_error)?;
+    }
+
+    Ok(writer)
+}
+
+/// Read the header of a record saved in the given format, failing when it's the header of
+/// another format. The first bytes of a record without header are given back to the reader.
+fn read_header<R: Read>(
+    format: RecordFormat,
+    mut reader: R,
+) -> Result<Chain<Cursor<Vec<u8>>, R>, RecorderError> {
+    let mut header = read_start(&mut reader)?;
+    let size = format.check_header(&header)?;
+    header.drain(..size);
+
+    Ok(Cursor::new(header).chain(reader))
+}
+
+/// Whether the record given back by [read_header] has no header, i.e. its first bytes are given
+/// back.
+fn has_no_header<R>(reader: &Chain<Cursor<Vec<u8>>, R>) -> bool {
+    !reader.get_ref().0.get_ref().is_empty()
+}
+
+/// Writer of the payload after the header, whose [checksum](PrecisionSettings::CHECKSUM) is
+/// computed when it's enabled by the settings and appended in a trailer once finished.
+///
+/// The checksum covers the bytes as they're written, i.e. compressed for the gzip formats, whose
+/// trailer follows the gzip stream.
+struct ChecksumWriter<W> {
+    writer: W,
+    hasher: Option<crc32fast::Hasher>,
+}
+
+impl<W: Write> ChecksumWriter<W> {
+    fn new<S: PrecisionSettings>(writer: W) -> Self {
+        Self {
+            writer,
+            hasher: S::CHECKSUM.then(crc32fast::Hasher::new),
+        }
+    }
+
+    /// Write the trailer, if any, returning the writer.
+    fn finish(mut self) -> Result<W, RecorderError> {
+        if let Some(hasher) = self.hasher {
+            let trailer = checksum_trailer(hasher.finalize());
+            self.writer.write_all(&trailer).map_err(io_error)?;
+        }
+
+        Ok(self.writer)
+    }
+}
+
+impl<W: Write> Write for ChecksumWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let size = self.writer.write(buf)?;
+
+        if let Some(hasher) = &mut self.hasher {
+            hasher.update(&buf[..size]);
+        }
+
+        Ok(size)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// Decode the payload after the header. When the [checksum](PrecisionSettings::CHECKSUM) is
+/// enabled by the settings, the payload is read in memory and verified first, so that nothing
+/// is decoded from a corrupted record.
+fn read_payload<S: PrecisionSettings, R: Read, T>(
+    mut reader: R,
+    decode: impl FnOnce(&mut dyn Read) -> Result<T, RecorderError>,
+) -> Result<T, RecorderError> {
+    if !S::CHECKSUM {
+        return decode(&mut reader);
+    }
+
+    let bytes = read_to_end(reader)?;
+
+    decode(&mut verify_checksum::<S>(&bytes)?)
+}
+
+fn read_to_end<R: Read>(mut reader: R) -> Result<Vec<u8>, RecorderError> {
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes).map_err(io_error)?;
+
+    Ok(bytes)
+}
+
+/// Detect the format of a record file from its header, or from its content for the json
+/// formats which have no header, without reading the rest of the file, so that it can be loaded
+/// with the matching recorder.
+///
+/// Returns `None` for files whose format can't be identified, e.g. records saved before the
+/// headers were introduced.
+pub fn detect_format<P: AsRef<Path>>(path: P) -> Result<Option<RecordFormat>, RecorderError> {
+    let path = path.as_ref();
+    let start = read_start(File::open(path).map_err(io_error)?)?;
+
+    if let Some(format) = RecordFormat::from_content(&start) {
+        return Ok(Some(format));
+    }
+
+    // The gzip formats with a header start with it, so a gzip stream is compressed json.
+    if start.starts_with(&GZIP_MAGIC) {
+        let decoder = GzDecoder::new(File::open(path).map_err(io_error)?);
+
+        // A corrupted stream isn't json.
+        if let Ok(start) = read_start(decoder) {
+            if RecordFormat::from_content(&start) == Some(RecordFormat::PrettyJson) {
+                return Ok(Some(RecordFormat::JsonGz));
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+/// Marker of the gzip streams.
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Read the first bytes of a record, enough to hold its header.
+fn read_start<R: Read>(reader: R) -> Result<Vec<u8>, RecorderError> {
+    let mut start = Vec::with_capacity(RECORD_HEADER_SIZE);
+    reader
+        .take(RECORD_HEADER_SIZE as u64)
+        .read_to_end(&mut start)
+        .map_err(io_error)?;
+
+    Ok(start)
+}
+
+/// Writer saving a file atomically.
+///
+/// The data is written to a temporary file next to the target, which replaces it only once the
+/// writer is [committed](AtomicFileWriter::commit). A save interrupted before that, by an error
+/// or a crash, leaves an existing file intact.
+pub(crate) struct AtomicFileWriter {
+    writer: Option<BufWriter<File>>,
+    temp: PathBuf,
+    path: PathBuf,
+}
+
+impl AtomicFileWriter {
+    /// Create the temporary file of the target `path`.
+    pub(crate) fn create(path: PathBuf) -> Result<Self, RecorderError> {
         // Add parent directories if they don't exist
         if let Some(parent) = path.parent() {
             std::fs::create_dir_all(parent).ok();
         }
 
-        if path.exists() {
-            log::info!("File exists, replacing");
-            std::fs::remove_file(path).map_err(|err| RecorderError::Unknown(err.to_string()))?;
+        let mut name = path.file_name().unwrap_or_default().to_os_string();
+        name.push(format!(".{}.tmp", std::process::id()));
+        let temp = path.with_file_name(name);
+
+        let file = File::create(&temp).map_err(io_error)?;
+
+        Ok(Self {
+            writer: Some(BufWriter::new(file)),
+            temp,
+            path,
+        })
+    }
+
+    /// Flush the data to the disk and replace the target with it.
+    pub(crate) fn commit(mut self) -> Result<(), RecorderError> {
+        let writer = self.writer.take().expect("The writer is committed once");
+        let file = writer
+            .into_inner()
+            .map_err(|err| io_error(err.into_error()))?;
+        file.sync_all().map_err(io_error)?;
+        drop(file);
+
+        if std::fs::rename(&self.temp, &self.path).is_err() {
+            // Renaming fails when the target is on another filesystem than its directory, e.g. a
+            // mounted file, in which case the data is copied over it instead.
+            std::fs::copy(&self.temp, &self.path).map_err(io_error)?;
         }
 
-        File::create(path)
-            .map_err(|err| match err.kind() {
-                std::io::ErrorKind::NotFound => RecorderError::FileNotFound(err.to_string()),
-                _ => RecorderError::Unknown(err.to_string()),
-            })
-            .map(|file| BufWriter::new(file))
-    }};
+        Ok(())
+    }
+}
+
+impl Write for AtomicFileWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.writer.as_mut().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.writer.as_mut().unwrap().flush()
+    }
+}
+
+impl Drop for AtomicFileWriter {
+    fn drop(&mut self) {
+        // Nothing is left behind, whether the save was committed or not.
+        drop(self.writer.take());
+        std::fs::remove_file(&self.temp).ok();
+    }
+}
+
+fn io_error(err: std::io::Error) -> RecorderError {
+    match err.kind() {
+        std::io::ErrorKind::NotFound => RecorderError::FileNotFound(err.to_string()),
+        _ => RecorderError::Unknown(err.to_string()),
+    }
 }
 
 impl<S: PrecisionSettings, B: Backend> Recorder<B> for BinGzFileRecorder<S> {
@@ -139,23 +381,38 @@ impl<S: PrecisionSettings, B: Backend> Recorder<B> for BinGzFileRecorder<S> {
         item: I,
         mut file: Self::RecordArgs,
     ) -> Result<(), RecorderError> {
-        let config = bin_config();
         let writer = str2writer!(file)?;
+        <Self as StreamFileRecorder<B>>::write_item(&item, writer)?.commit()
+    }
+
+    fn load_item<I: DeserializeOwned>(&self, mut file: Self::LoadArgs) -> Result<I, RecorderError> {
+        let reader = str2reader!(file)?;
+        <Self as StreamFileRecorder<B>>::read_item(reader)
+    }
+}
+
+impl<S: PrecisionSettings, B: Backend> StreamFileRecorder<B> for BinGzFileRecorder<S> {
+    fn write_item<I: Serialize, W: Write>(item: &I, writer: W) -> Result<W, RecorderError> {
+        let writer = ChecksumWriter::new::<S>(write_header(RecordFormat::BinGz, writer)?);
         let mut writer = GzEncoder::new(writer, Compression::default());
 
-        bincode::serde::encode_into_std_write(&item, &mut writer, config)
+        bincode::serde::encode_into_std_write(item, &mut writer, bin_config())
             .map_err(|err| RecorderError::Unknown(err.to_string()))?;
 
-        Ok(())
+        writer.finish().map_err(io_error)?.finish()
     }
 
-    fn load_item<I: DeserializeOwned>(&self, mut file: Self::LoadArgs) -> Result<I, RecorderError> {
-        let reader = str2reader!(file)?;
-        let mut reader = GzDecoder::new(reader);
-        let state = bincode::serde::decode_from_std_read(&mut reader, bin_config())
-            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+    fn read_item<I: DeserializeOwned, R: Read>(reader: R) -> Result<I, RecorderError> {
+        let reader = read_header(RecordFormat::BinGz, reader)?;
 
-        Ok(state)
+        if has_no_header(&reader) {
+            return decode_headerless_bin_record(&read_to_end(GzDecoder::new(reader))?);
+        }
+
+        read_payload::<S, _, _>(reader, |reader| {
+            bincode::serde::decode_from_std_read(&mut GzDecoder::new(reader), bin_config())
+                .map_err(|err| RecorderError::Unknown(err.to_string()))
+        })
     }
 }
 
@@ -170,18 +427,36 @@ impl<S: PrecisionSettings, B: Backend> Recorder<B> for BinFileRecorder<S> {
         item: I,
         mut file: Self::RecordArgs,
     ) -> Result<(), RecorderError> {
-        let config = bin_config();
-        let mut writer = str2writer!(file)?;
-        bincode::serde::encode_into_std_write(&item, &mut writer, config)
-            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
-        Ok(())
+        let writer = str2writer!(file)?;
+        <Self as StreamFileRecorder<B>>::write_item(&item, writer)?.commit()
     }
 
     fn load_item<I: DeserializeOwned>(&self, mut file: Self::LoadArgs) -> Result<I, RecorderError> {
-        let mut reader = str2reader!(file)?;
-        let state = bincode::serde::decode_from_std_read(&mut reader, bin_config())
+        let reader = str2reader!(file)?;
+        <Self as StreamFileRecorder<B>>::read_item(reader)
+    }
+}
+
+impl<S: PrecisionSettings, B: Backend> StreamFileRecorder<B> for BinFileRecorder<S> {
+    fn write_item<I: Serialize, W: Write>(item: &I, writer: W) -> Result<W, RecorderError> {
+        let mut writer = ChecksumWriter::new::<S>(write_header(RecordFormat::Bin, writer)?);
+        bincode::serde::encode_into_std_write(item, &mut writer, bin_config())
             .map_err(|err| RecorderError::Unknown(err.to_string()))?;
-        Ok(state)
+
+        writer.finish()
+    }
+
+    fn read_item<I: DeserializeOwned, R: Read>(reader: R) -> Result<I, RecorderError> {
+        let reader = read_header(RecordFormat::Bin, reader)?;
+
+        if has_no_header(&reader) {
+            return decode_headerless_bin_record(&read_to_end(reader)?);
+        }
+
+        read_payload::<S, _, _>(reader, |mut reader| {
+            bincode::serde::decode_from_std_read(&mut reader, bin_config())
+                .map_err(|err| RecorderError::Unknown(err.to_string()))
+        })
     }
 }
 
@@ -190,6 +465,7 @@ impl<S: PrecisionSettings, B: Backend> Recorder<B> for JsonGzFileRecorder<S> {
     type RecordArgs = PathBuf;
     type RecordOutput = ();
     type LoadArgs = PathBuf;
+    const SELF_DESCRIBING: bool = true;
 
     fn save_item<I: Serialize>(
         &self,
@@ -197,20 +473,31 @@ impl<S: PrecisionSettings, B: Backend> Recorder<B> for JsonGzFileRecorder<S> {
         mut file: Self::RecordArgs,
     ) -> Result<(), RecorderError> {
         let writer = str2writer!(file)?;
-        let writer = GzEncoder::new(writer, Compression::default());
-        serde_json::to_writer(writer, &item)
-            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
-
-        Ok(())
+        <Self as StreamFileRecorder<B>>::write_item(&item, writer)?.commit()
     }
 
     fn load_item<I: DeserializeOwned>(&self, mut file: Self::LoadArgs) -> Result<I, RecorderError> {
         let reader = str2reader!(file)?;
-        let reader = GzDecoder::new(reader);
-        let state = serde_json::from_reader(reader)
+        <Self as StreamFileRecorder<B>>::read_item(reader)
+    }
+}
+
+impl<S: PrecisionSettings, B: Backend> StreamFileRecorder<B> for JsonGzFileRecorder<S> {
+    fn write_item<I: Serialize, W: Write>(item: &I, writer: W) -> Result<W, RecorderError> {
+        let mut writer = GzEncoder::new(ChecksumWriter::new::<S>(writer), Compression::default());
+        serde_json::to_writer(&mut writer, item)
             .map_err(|err| RecorderError::Unknown(err.to_string()))?;
 
-        Ok(state)
+        writer.finish().map_err(io_error)?.finish()
+    }
+
+    fn read_item<I: DeserializeOwned, R: Read>(reader: R) -> Result<I, RecorderError> {
+        let reader = read_header(RecordFormat::JsonGz, reader)?;
+
+        read_payload::<S, _, _>(reader, |reader| {
+            serde_json::from_reader(GzDecoder::new(reader))
+                .map_err(|err| RecorderError::Unknown(err.to_string()))
+        })
     }
 }
 
@@ -219,6 +506,7 @@ impl<S: PrecisionSettings, B: Backend> Recorder<B> for PrettyJsonFileRecorder<S>
     type RecordArgs = PathBuf;
     type RecordOutput = ();
     type LoadArgs = PathBuf;
+    const SELF_DESCRIBING: bool = true;
 
     fn save_item<I: Serialize>(
         &self,
@@ -226,13 +514,34 @@ impl<S: PrecisionSettings, B: Backend> Recorder<B> for PrettyJsonFileRecorder<S>
         mut file: Self::RecordArgs,
     ) -> Result<(), RecorderError> {
         let writer = str2writer!(file)?;
-        serde_json::to_writer_pretty(writer, &item)
-            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
-        Ok(())
+        <Self as StreamFileRecorder<B>>::write_item(&item, writer)?.commit()
     }
 
     fn load_item<I: DeserializeOwned>(&self, mut file: Self::LoadArgs) -> Result<I, RecorderError> {
         let reader = str2reader!(file)?;
+        <Self as StreamFileRecorder<B>>::read_item(reader)
+    }
+}
+
+impl<S: PrecisionSettings, B: Backend> StreamFileRecorder<B> for PrettyJsonFileRecorder<S> {
+    fn write_item<I: Serialize, W: Write>(item: &I, mut writer: W) -> Result<W, RecorderError> {
+        // A trailer would make the file invalid json.
+        if S::CHECKSUM {
+            return Err(RecorderError::Unknown(
+                "The checksum can't be written in a pretty json record, use the gzip json format \
+                instead"
+                    .to_string(),
+            ));
+        }
+
+        serde_json::to_writer_pretty(&mut writer, item)
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        Ok(writer)
+    }
+
+    fn read_item<I: DeserializeOwned, R: Read>(reader: R) -> Result<I, RecorderError> {
+        let reader = read_header(RecordFormat::PrettyJson, reader)?;
         let state = serde_json::from_reader(reader)
             .map_err(|err| RecorderError::Unknown(err.to_string()))?;
 
@@ -240,7 +549,7 @@ impl<S: PrecisionSettings, B: Backend> Recorder<B> for PrettyJsonFileRecorder<S>
     }
 }
 
-impl<S: PrecisionSettings, B: Backend> Recorder<B> for NamedMpkGzFileRecorder<S> {
+impl<S: PrecisionSettings, B: Backend> Recorder<B> for JsonSummaryFileRecorder<S> {
     type Settings = S;
     type RecordArgs = PathBuf;
     type RecordOutput = ();
@@ -251,21 +560,62 @@ impl<S: PrecisionSettings, B: Backend> Recorder<B> for NamedMpkGzFileRecorder<S>
         item: I,
         mut file: Self::RecordArgs,
     ) -> Result<(), RecorderError> {
-        let writer = str2writer!(file)?;
-        let mut writer = GzEncoder::new(writer, Compression::default());
-        rmp_serde::encode::write_named(&mut writer, &item)
+        let value =
+            serde_json::to_value(&item).map_err(|err| RecorderError::Unknown(err.to_string()))?;
+        // Summaries can't be loaded back and are written without header.
+        file.set_extension(<Self as FileRecorder<B>>::file_extension());
+        let mut writer = AtomicFileWriter::create(file)?;
+        serde_json::to_writer_pretty(&mut writer, &summarize_record(value))
             .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+        writer.commit()
+    }
 
-        Ok(())
+    fn load_item<I: DeserializeOwned>(&self, _file: Self::LoadArgs) -> Result<I, RecorderError> {
+        Err(RecorderError::Unknown(
+            "Json summaries don't contain the tensor data and can't be loaded".to_string(),
+        ))
+    }
+}
+
+impl<S: PrecisionSettings, B: Backend> Recorder<B> for NamedMpkGzFileRecorder<S> {
+    type Settings = S;
+    type RecordArgs = PathBuf;
+    type RecordOutput = ();
+    type LoadArgs = PathBuf;
+    const SELF_DESCRIBING: bool = true;
+
+    fn save_item<I: Serialize>(
+        &self,
+        item: I,
+        mut file: Self::RecordArgs,
+    ) -> Result<(), RecorderError> {
+        let writer = str2writer!(file)?;
+        <Self as StreamFileRecorder<B>>::write_item(&item, writer)?.commit()
     }
 
     fn load_item<I: DeserializeOwned>(&self, mut file: Self::LoadArgs) -> Result<I, RecorderError> {
         let reader = str2reader!(file)?;
-        let reader = GzDecoder::new(reader);
-        let state = rmp_serde::decode::from_read(reader)
+        <Self as StreamFileRecorder<B>>::read_item(reader)
+    }
+}
+
+impl<S: PrecisionSettings, B: Backend> StreamFileRecorder<B> for NamedMpkGzFileRecorder<S> {
+    fn write_item<I: Serialize, W: Write>(item: &I, writer: W) -> Result<W, RecorderError> {
+        let writer = ChecksumWriter::new::<S>(write_header(RecordFormat::NamedMpkGz, writer)?);
+        let mut writer = GzEncoder::new(writer, Compression::default());
+        rmp_serde::encode::write_named(&mut writer, item)
             .map_err(|err| RecorderError::Unknown(err.to_string()))?;
 
-        Ok(state)
+        writer.finish().map_err(io_error)?.finish()
+    }
+
+    fn read_item<I: DeserializeOwned, R: Read>(reader: R) -> Result<I, RecorderError> {
+        let reader = read_header(RecordFormat::NamedMpkGz, reader)?;
+
+        read_payload::<S, _, _>(reader, |reader| {
+            rmp_serde::decode::from_read(GzDecoder::new(reader))
+                .map_err(|err| RecorderError::Unknown(err.to_string()))
+        })
     }
 }
 
@@ -274,26 +624,39 @@ impl<S: PrecisionSettings, B: Backend> Recorder<B> for NamedMpkFileRecorder<S> {
     type RecordArgs = PathBuf;
     type RecordOutput = ();
     type LoadArgs = PathBuf;
+    const SELF_DESCRIBING: bool = true;
 
     fn save_item<I: Serialize>(
         &self,
         item: I,
         mut file: Self::RecordArgs,
     ) -> Result<(), RecorderError> {
-        let mut writer = str2writer!(file)?;
-
-        rmp_serde::encode::write_named(&mut writer, &item)
-            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
-
-        Ok(())
+        let writer = str2writer!(file)?;
+        <Self as StreamFileRecorder<B>>::write_item(&item, writer)?.commit()
     }
 
     fn load_item<I: DeserializeOwned>(&self, mut file: Self::LoadArgs) -> Result<I, RecorderError> {
         let reader = str2reader!(file)?;
-        let state = rmp_serde::decode::from_read(reader)
+        <Self as StreamFileRecorder<B>>::read_item(reader)
+    }
+}
+
+impl<S: PrecisionSettings, B: Backend> StreamFileRecorder<B> for NamedMpkFileRecorder<S> {
+    fn write_item<I: Serialize, W: Write>(item: &I, writer: W) -> Result<W, RecorderError> {
+        let mut writer = ChecksumWriter::new::<S>(write_header(RecordFormat::NamedMpk, writer)?);
+        rmp_serde::encode::write_named(&mut writer, item)
             .map_err(|err| RecorderError::Unknown(err.to_string()))?;
 
-        Ok(state)
+        writer.finish()
+    }
+
+    fn read_item<I: DeserializeOwned, R: Read>(reader: R) -> Result<I, RecorderError> {
+        let reader = read_header(RecordFormat::NamedMpk, reader)?;
+
+        read_payload::<S, _, _>(reader, |reader| {
+            rmp_serde::decode::from_read(reader)
+                .map_err(|err| RecorderError::Unknown(err.to_string()))
+        })
     }
 }
 
@@ -309,7 +672,8 @@ mod tests {
             conv::{Conv2d, Conv2dConfig},
             Linear, LinearConfig,
         },
-        record::{BinBytesRecorder, FullPrecisionSettings},
+        record::{BinBytesRecorder, BytesRecorder, FullPrecisionSettings, NamedMpkBytesRecorder},
+        tensor::{Bool, Int, Tensor},
         TestBackend,
     };
 
@@ -352,6 +716,48 @@ mod tests {
         test_can_save_and_load(NamedMpkFileRecorder::<FullPrecisionSettings>::default())
     }
 
+    #[test]
+    fn test_json_summary_replaces_tensors_with_stats() {
+        let device = Default::default();
+        let recorder = JsonSummaryFileRecorder::<FullPrecisionSettings>::default();
+        let file = std::env::temp_dir().join("burn_test_json_summary_tensor");
+
+        let tensor = Tensor::<TestBackend, 2>::from_floats([[1.0, -2.5], [3.25, 0.0]], &device);
+        recorder.record(tensor, file.clone()).unwrap();
+
+        let summary = std::fs::read_to_string(file.with_extension("summary.json")).unwrap();
+        let summary: serde_json::Value = serde_json::from_str(&summary).unwrap();
+
+        assert_eq!(
+            summary["item"],
+            serde_json::json!({
+                "max": 3.25,
+                "mean": 0.4375,
+                "min": -2.5,
+                "numel": 4,
+                "shape": [2, 2],
+            })
+        );
+    }
+
+    #[test]
+    fn test_json_summary_sorts_fields_and_cant_be_loaded() {
+        let device = Default::default();
+        let recorder = JsonSummaryFileRecorder::<FullPrecisionSettings>::default();
+        let file = std::env::temp_dir().join("burn_test_json_summary_linear");
+
+        let linear = LinearConfig::new(2, 2).init::<TestBackend>(&device);
+        recorder.record(linear.into_record(), file.clone()).unwrap();
+
+        let summary = std::fs::read_to_string(file.with_extension("summary.json")).unwrap();
+
+        // The linear record declares the weight before the bias.
+        assert!(summary.find("\"bias\"").unwrap() < summary.find("\"weight\"").unwrap());
+
+        let result: Result<crate::nn::LinearRecord<TestBackend>, _> = recorder.load(file, &device);
+        assert!(result.is_err());
+    }
+
     fn test_can_save_and_load<Recorder>(recorder: Recorder)
     where
         Recorder: FileRecorder<TestBackend>,
@@ -374,6 +780,255 @@ mod tests {
         assert_eq!(model_bytes_after, model_bytes_before);
     }
 
+    #[test]
+    fn test_interrupted_bin_save_keeps_existing_file() {
+        test_interrupted_save_keeps_existing_file(
+            BinFileRecorder::<FullPrecisionSettings>::default(),
+        )
+    }
+
+    #[test]
+    fn test_interrupted_mpkgz_save_keeps_existing_file() {
+        test_interrupted_save_keeps_existing_file(
+            NamedMpkGzFileRecorder::<FullPrecisionSettings>::default(),
+        )
+    }
+
+    #[test]
+    fn test_interrupted_pretty_json_save_keeps_existing_file() {
+        test_interrupted_save_keeps_existing_file(
+            PrettyJsonFileRecorder::<FullPrecisionSettings>::default(),
+        )
+    }
+
+    /// Item failing to serialize after part of it has been written.
+    struct InterruptedItem;
+
+    impl Serialize for InterruptedItem {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            use serde::ser::{Error, SerializeSeq};
+
+            let mut seq = serializer.serialize_seq(Some(2))?;
+            seq.serialize_element(&[1u8; 32])?;
+
+            Err(S::Error::custom("interrupted"))
+        }
+    }
+
+    fn test_interrupted_save_keeps_existing_file<Recorder>(recorder: Recorder)
+    where
+        Recorder: FileRecorder<TestBackend>,
+    {
+        let device = Default::default();
+        let file = std::env::temp_dir().join("burn_test_file_recorder_interrupted");
+        let path = file.with_extension(<Recorder as FileRecorder<TestBackend>>::file_extension());
+
+        let tensor = Tensor::<TestBackend, 2>::from_floats([[1.0, -2.5], [3.25, 0.0]], &device);
+        recorder.record(tensor.clone(), file.clone()).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+
+        assert!(recorder.save_item(InterruptedItem, file.clone()).is_err());
+
+        // The previous file is untouched and the temporary one is removed.
+        assert_eq!(std::fs::read(&path).unwrap(), bytes);
+        let loaded: Tensor<TestBackend, 2> = recorder.load(file.clone(), &device).unwrap();
+        assert_eq!(loaded.into_data(), tensor.into_data());
+
+        let temp_prefix = path.file_name().unwrap().to_str().unwrap().to_string() + ".";
+        let leftovers = std::fs::read_dir(std::env::temp_dir())
+            .unwrap()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| {
+                let name = entry.file_name();
+                let name = name.to_string_lossy();
+                name.starts_with(&temp_prefix) && name.ends_with(".tmp")
+            })
+            .count();
+        assert_eq!(leftovers, 0);
+    }
+
+    #[test]
+    fn test_bin_file_and_bytes_are_interchangeable() {
+        test_file_and_bytes_are_interchangeable(
+            BinFileRecorder::<FullPrecisionSettings>::default(),
+            BinBytesRecorder::<FullPrecisionSettings>::default(),
+        )
+    }
+
+    #[test]
+    fn test_mpk_file_and_bytes_are_interchangeable() {
+        test_file_and_bytes_are_interchangeable(
+            NamedMpkFileRecorder::<FullPrecisionSettings>::default(),
+            NamedMpkBytesRecorder::<FullPrecisionSettings>::default(),
+        )
+    }
+
+    fn test_file_and_bytes_are_interchangeable<FR, BR>(file_recorder: FR, bytes_recorder: BR)
+    where
+        FR: FileRecorder<TestBackend>,
+        BR: BytesRecorder<TestBackend>,
+    {
+        type TensorsRecord = (
+            Tensor<TestBackend, 2>,
+            Tensor<TestBackend, 2, Int>,
+            Tensor<TestBackend, 2, Bool>,
+        );
+
+        let device = Default::default();
+        let tensors: TensorsRecord = (
+            Tensor::from_floats([[1.0, -2.5], [3.25, 0.0]], &device),
+            Tensor::from_ints([[1, -2], [3, 4]], &device),
+            Tensor::from_bool([[true, false], [false, true]].into(), &device),
+        );
+        let mut file = file_path();
+        file.set_extension(<FR as FileRecorder<TestBackend>>::file_extension());
+
+        // File to bytes.
+        file_recorder.record(tensors.clone(), file.clone()).unwrap();
+        let loaded: TensorsRecord = bytes_recorder
+            .load(std::fs::read(&file).unwrap(), &device)
+            .unwrap();
+        assert_tensors_eq(&tensors, loaded);
+
+        // Bytes to file.
+        let bytes = bytes_recorder.record(tensors.clone(), ()).unwrap();
+        std::fs::write(&file, bytes).unwrap();
+        let loaded: TensorsRecord = file_recorder.load(file, &device).unwrap();
+        assert_tensors_eq(&tensors, loaded);
+    }
+
+    fn assert_tensors_eq(
+        expected: &(
+            Tensor<TestBackend, 2>,
+            Tensor<TestBackend, 2, Int>,
+            Tensor<TestBackend, 2, Bool>,
+        ),
+        actual: (
+            Tensor<TestBackend, 2>,
+            Tensor<TestBackend, 2, Int>,
+            Tensor<TestBackend, 2, Bool>,
+        ),
+    ) {
+        assert_eq!(expected.0.to_data(), actual.0.into_data());
+        assert_eq!(expected.1.to_data(), actual.1.into_data());
+        assert_eq!(expected.2.to_data(), actual.2.into_data());
+    }
+
+    #[test]
+    fn test_detect_format_of_each_recorder() {
+        type S = FullPrecisionSettings;
+
+        assert_eq!(
+            detect(BinFileRecorder::<S>::default()),
+            Some(RecordFormat::Bin)
+        );
+        assert_eq!(
+            detect(BinGzFileRecorder::<S>::default()),
+            Some(RecordFormat::BinGz)
+        );
+        assert_eq!(
+            detect(JsonGzFileRecorder::<S>::default()),
+            Some(RecordFormat::JsonGz)
+        );
+        assert_eq!(
+            detect(PrettyJsonFileRecorder::<S>::default()),
+            Some(RecordFormat::PrettyJson)
+        );
+        assert_eq!(
+            detect(NamedMpkFileRecorder::<S>::default()),
+            Some(RecordFormat::NamedMpk)
+        );
+        assert_eq!(
+            detect(NamedMpkGzFileRecorder::<S>::default()),
+            Some(RecordFormat::NamedMpkGz)
+        );
+    }
+
+    /// Save a record, detect its format and load it back.
+    fn detect<R: FileRecorder<TestBackend>>(recorder: R) -> Option<RecordFormat> {
+        let device = Default::default();
+        let file = std::env::temp_dir().join("burn_test_file_recorder_detect");
+        let path = file.with_extension(<R as FileRecorder<TestBackend>>::file_extension());
+
+        let tensor = Tensor::<TestBackend, 1>::from_floats([1.0, -2.5], &device);
+        recorder.record(tensor.clone(), file.clone()).unwrap();
+        let format = detect_format(path).unwrap();
+
+        let loaded: Tensor<TestBackend, 1> = recorder.load(file, &device).unwrap();
+        assert_eq!(loaded.into_data(), tensor.into_data());
+
+        format
+    }
+
+    #[test]
+    fn test_err_when_loading_with_another_format() {
+        let device = Default::default();
+        let file = std::env::temp_dir().join("burn_test_file_recorder_mismatch");
+        let tensor = Tensor::<TestBackend, 1>::from_floats([1.0, -2.5], &device);
+
+        NamedMpkFileRecorder::<FullPrecisionSettings>::default()
+            .record(tensor, file.clone())
+            .unwrap();
+        std::fs::rename(file.with_extension("mpk"), file.with_extension("bin")).unwrap();
+
+        let result: Result<Tensor<TestBackend, 1>, _> =
+            BinFileRecorder::<FullPrecisionSettings>::default().load(file, &device);
+
+        assert!(matches!(
+            result,
+            Err(RecorderError::FormatMismatch {
+                expected: RecordFormat::Bin,
+                found: RecordFormat::NamedMpk,
+            })
+        ));
+    }
+
+    #[test]
+    fn test_can_load_record_without_header() {
+        let device = Default::default();
+        let file = std::env::temp_dir().join("burn_test_file_recorder_no_header.bin");
+        let tensor = Tensor::<TestBackend, 1>::from_floats([1.0, -2.5], &device);
+
+        // Records saved before the headers were introduced start with the serialized record.
+        let bytes = BinBytesRecorder::<FullPrecisionSettings>::default()
+            .record(tensor.clone(), ())
+            .unwrap();
+        std::fs::write(&file, &bytes[RECORD_HEADER_SIZE..]).unwrap();
+        assert_eq!(detect_format(&file).unwrap(), None);
+
+        let loaded: Tensor<TestBackend, 1> = BinFileRecorder::<FullPrecisionSettings>::default()
+            .load(file, &device)
+            .unwrap();
+        assert_eq!(loaded.into_data(), tensor.into_data());
+    }
+
+    #[test]
+    fn test_json_records_are_valid_json() {
+        let device = Default::default();
+        let file = std::env::temp_dir().join("burn_test_file_recorder_valid_json");
+        let tensor = Tensor::<TestBackend, 1>::from_floats([1.0, -2.5], &device);
+
+        PrettyJsonFileRecorder::<FullPrecisionSettings>::default()
+            .record(tensor.clone(), file.clone())
+            .unwrap();
+        let content = std::fs::read_to_string(file.with_extension("json")).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&content).unwrap();
+        assert!(value["metadata"]["format"]
+            .as_str()
+            .unwrap()
+            .contains("PrettyJsonFileRecorder"));
+
+        JsonGzFileRecorder::<FullPrecisionSettings>::default()
+            .record(tensor, file.clone())
+            .unwrap();
+        let decoder = GzDecoder::new(File::open(file.with_extension("json.gz")).unwrap());
+        let value: serde_json::Value = serde_json::from_reader(decoder).unwrap();
+        assert!(value["metadata"]["format"]
+            .as_str()
+            .unwrap()
+            .contains("JsonGzFileRecorder"));
+    }
+
     #[derive(Module, Debug)]
     pub struct Model<B: Backend> {
         conv2d1: Conv2d<B>,
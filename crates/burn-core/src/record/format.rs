@@ -0,0 +1,193 @@
+use super::RecorderError;
+use alloc::format;
+
+/// Format of a record, identified by the header written at its start.
+///
+/// The recorders of the binary formats write the header of their format before the serialized
+/// record, so loading a record with the recorder of another format fails with
+/// [FormatMismatch](RecorderError::FormatMismatch) instead of a deserialization error. The json
+/// formats are written without header, so that the records stay valid json, and are detected from
+/// their content instead. Records saved before the headers were introduced don't have one and are
+/// loaded as before.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RecordFormat {
+    /// The [bincode format](bincode), written by the `BinFileRecorder` and the
+    /// [BinBytesRecorder](super::BinBytesRecorder).
+    Bin,
+    /// The [bincode format](bincode) compressed with gzip, written by the `BinGzFileRecorder`.
+    BinGz,
+    /// The json format compressed with gzip, written by the `JsonGzFileRecorder`.
+    JsonGz,
+    /// The pretty json format, written by the `PrettyJsonFileRecorder`.
+    PrettyJson,
+    /// The named msgpack format, written by the `NamedMpkFileRecorder` and the
+    /// `NamedMpkBytesRecorder`.
+    NamedMpk,
+    /// The named msgpack format compressed with gzip, written by the `NamedMpkGzFileRecorder`.
+    NamedMpkGz,
+}
+
+/// Marker written at the start of the header.
+const MAGIC: &[u8; 4] = b"BURN";
+/// Version of the header, increased when its layout changes.
+const HEADER_VERSION: u8 = b'1';
+/// Size of the header: the marker, the tag of the format, the version and a line feed.
+pub const RECORD_HEADER_SIZE: usize = 8;
+
+impl RecordFormat {
+    /// The formats written with a header.
+    const WITH_HEADER: [RecordFormat; 4] = [
+        RecordFormat::Bin,
+        RecordFormat::BinGz,
+        RecordFormat::NamedMpk,
+        RecordFormat::NamedMpkGz,
+    ];
+
+    fn tag(&self) -> Option<&'static [u8; 2]> {
+        match self {
+            RecordFormat::Bin => Some(b"bi"),
+            RecordFormat::BinGz => Some(b"bz"),
+            RecordFormat::NamedMpk => Some(b"mp"),
+            RecordFormat::NamedMpkGz => Some(b"mz"),
+            RecordFormat::JsonGz | RecordFormat::PrettyJson => None,
+        }
+    }
+
+    /// The header written at the start of the records of the format, `None` for the json
+    /// formats which are written without header.
+    pub fn header(&self) -> Option<[u8; RECORD_HEADER_SIZE]> {
+        let tag = self.tag()?;
+
+        Some([
+            MAGIC[0],
+            MAGIC[1],
+            MAGIC[2],
+            MAGIC[3],
+            tag[0],
+            tag[1],
+            HEADER_VERSION,
+            b'\n',
+        ])
+    }
+
+    /// The format of a record from its first bytes, `None` when they don't start with a
+    /// header.
+    pub fn from_header(bytes: &[u8]) -> Option<Self> {
+        let header = bytes.get(..RECORD_HEADER_SIZE)?;
+
+        Self::WITH_HEADER.into_iter().find(|format| {
+            format
+                .header()
+                .is_some_and(|known| known.as_slice() == header)
+        })
+    }
+
+    /// The format of a record from its first bytes, from its header or, for a record without
+    /// header, from its content when it's json. `None` when the format can't be identified, e.g.
+    /// for the records saved before the headers were introduced.
+    ///
+    /// The compressed json records can't be identified without decompressing them, see the
+    /// `detect_format` function of the file recorders.
+    pub fn from_content(bytes: &[u8]) -> Option<Self> {
+        Self::from_header(bytes).or_else(|| is_json(bytes).then_some(RecordFormat::PrettyJson))
+    }
+
+    /// Check that the record starting with the given bytes has the format, returning the size
+    /// of its header to skip.
+    pub(crate) fn check_header(&self, bytes: &[u8]) -> Result<usize, RecorderError> {
+        let found = match Self::from_header(bytes) {
+            Some(found) if found == *self => return Ok(RECORD_HEADER_SIZE),
+            Some(found) => found,
+            None if bytes.starts_with(MAGIC) => {
+                return Err(RecorderError::DeserializeError(format!(
+                    "Unsupported record header {:?}",
+                    &bytes[..bytes.len().min(RECORD_HEADER_SIZE)]
+                )))
+            }
+            // A json record loaded by the recorder of a binary format
+            None if self.tag().is_some() && is_json(bytes) => RecordFormat::PrettyJson,
+            // Json records and records saved before the headers were introduced
+            None => return Ok(0),
+        };
+
+        Err(RecorderError::FormatMismatch {
+            expected: *self,
+            found,
+        })
+    }
+}
+
+/// Whether the bytes start a json object, which the serialized records are.
+///
+/// None of the binary formats start with an opening brace: the bincode records start with the
+/// length of the name of the float type of their metadata, and the named msgpack ones with a map
+/// marker.
+fn is_json(bytes: &[u8]) -> bool {
+    bytes
+        .iter()
+        .find(|byte| !byte.is_ascii_whitespace())
+        .is_some_and(|byte| *byte == b'{')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn headers_identify_their_format() {
+        for format in RecordFormat::WITH_HEADER {
+            let header = format.header().unwrap();
+
+            assert_eq!(RecordFormat::from_header(&header), Some(format));
+            assert_eq!(format.check_header(&header).unwrap(), RECORD_HEADER_SIZE);
+        }
+    }
+
+    #[test]
+    fn json_formats_have_no_header() {
+        assert_eq!(RecordFormat::PrettyJson.header(), None);
+        assert_eq!(RecordFormat::JsonGz.header(), None);
+        assert_eq!(
+            RecordFormat::from_content(b"{\n  \"metadata\""),
+            Some(RecordFormat::PrettyJson)
+        );
+    }
+
+    #[test]
+    fn record_without_header_is_accepted() {
+        assert_eq!(RecordFormat::from_header(b"{\"item\": 1}"), None);
+        assert_eq!(
+            RecordFormat::PrettyJson
+                .check_header(b"{\"item\": 1}")
+                .unwrap(),
+            0
+        );
+        assert_eq!(RecordFormat::Bin.check_header(&[3, b'f', b'3']).unwrap(), 0);
+    }
+
+    #[test]
+    fn err_when_header_of_another_format() {
+        let result = RecordFormat::Bin.check_header(&RecordFormat::NamedMpk.header().unwrap());
+
+        assert!(matches!(
+            result,
+            Err(RecorderError::FormatMismatch {
+                expected: RecordFormat::Bin,
+                found: RecordFormat::NamedMpk,
+            })
+        ));
+    }
+
+    #[test]
+    fn err_when_json_record_loaded_as_binary_format() {
+        let result = RecordFormat::NamedMpk.check_header(b"{\"item\": 1}");
+
+        assert!(matches!(
+            result,
+            Err(RecorderError::FormatMismatch {
+                expected: RecordFormat::NamedMpk,
+                found: RecordFormat::PrettyJson,
+            })
+        ));
+    }
+}
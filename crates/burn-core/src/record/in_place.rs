@@ -0,0 +1,102 @@
+use crate::module::{Module, ModuleMapper, ModuleVisitor, ParamId};
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use burn_tensor::{backend::Backend, BasicOps, Bool, Int, Tensor};
+use core::any::Any;
+
+/// Write the tensors of the loaded module into the tensors of the module with the same shape,
+/// replacing the others, see [load_into](super::Recorder::load_into).
+///
+/// Both modules must have the same structure, e.g. the loaded module being the module with a
+/// loaded record, so that their parameters are visited in the same order.
+pub(crate) fn assign_in_place<B: Backend, M: Module<B>>(module: M, loaded: &M) -> M {
+    let mut collector = TensorCollector::default();
+    loaded.visit(&mut collector);
+
+    let mut assigner = TensorAssigner {
+        tensors: collector.tensors,
+    };
+    module.map(&mut assigner)
+}
+
+/// Collects the tensors of the visited parameters, in order.
+#[derive(Default)]
+struct TensorCollector {
+    tensors: VecDeque<Box<dyn Any>>,
+}
+
+impl<B: Backend> ModuleVisitor<B> for TensorCollector {
+    fn visit_float<const D: usize>(&mut self, _id: &ParamId, tensor: &Tensor<B, D>) {
+        self.tensors.push_back(Box::new(tensor.clone().detach()));
+    }
+
+    fn visit_int<const D: usize>(&mut self, _id: &ParamId, tensor: &Tensor<B, D, Int>) {
+        self.tensors.push_back(Box::new(tensor.clone()));
+    }
+
+    fn visit_bool<const D: usize>(&mut self, _id: &ParamId, tensor: &Tensor<B, D, Bool>) {
+        self.tensors.push_back(Box::new(tensor.clone()));
+    }
+}
+
+/// Assigns the collected tensors to the mapped parameters, in order.
+struct TensorAssigner {
+    tensors: VecDeque<Box<dyn Any>>,
+}
+
+impl TensorAssigner {
+    fn next<T: 'static>(&mut self) -> T {
+        let tensor = self
+            .tensors
+            .pop_front()
+            .expect("The loaded module should have as many parameters as the module");
+
+        *tensor
+            .downcast()
+            .expect("The loaded module should have the same parameters as the module")
+    }
+}
+
+impl<B: Backend> ModuleMapper<B> for TensorAssigner {
+    fn map_float<const D: usize>(&mut self, _id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let loaded: Tensor<B, D> = self.next();
+        let require_grad = tensor.is_require_grad();
+
+        // The parameter is detached so that the assignment isn't tracked by autodiff.
+        assign(tensor.detach(), loaded).set_require_grad(require_grad)
+    }
+
+    fn map_int<const D: usize>(
+        &mut self,
+        _id: &ParamId,
+        tensor: Tensor<B, D, Int>,
+    ) -> Tensor<B, D, Int> {
+        let loaded = self.next();
+        assign(tensor, loaded)
+    }
+
+    fn map_bool<const D: usize>(
+        &mut self,
+        _id: &ParamId,
+        tensor: Tensor<B, D, Bool>,
+    ) -> Tensor<B, D, Bool> {
+        let loaded = self.next();
+        assign(tensor, loaded)
+    }
+}
+
+/// Write the loaded values over the whole tensor when the shapes match, which backends reusing
+/// the buffers of uniquely owned tensors, e.g. NdArray, do in place. Otherwise, the tensor is
+/// replaced.
+fn assign<B, const D: usize, K>(tensor: Tensor<B, D, K>, loaded: Tensor<B, D, K>) -> Tensor<B, D, K>
+where
+    B: Backend,
+    K: BasicOps<B>,
+{
+    if tensor.shape() != loaded.shape() {
+        return loaded;
+    }
+
+    let ranges = tensor.dims().map(|dim| 0..dim);
+    tensor.slice_assign(ranges, loaded)
+}
@@ -0,0 +1,163 @@
+use super::tied::is_tensor;
+use super::value::is_non_finite;
+use super::{record_field_path, RecorderError};
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use serde_json::Value;
+
+/// How the paths of a loaded record are matched against the fields of a record, see
+/// [load_with_keys](super::SelfDescribingRecorder::load_with_keys).
+#[derive(Default)]
+pub struct KeyMatching {
+    lenient: bool,
+    #[allow(clippy::type_complexity)]
+    remap: Option<Box<dyn Fn(&str) -> String + Send + Sync>>,
+}
+
+impl KeyMatching {
+    /// Error on any loaded path without a field in the record and any field of the record without
+    /// a loaded value.
+    pub fn strict() -> Self {
+        Self::default()
+    }
+
+    /// Ignore the loaded paths without a field in the record and keep the value of the fields
+    /// without a loaded value, returning both instead of an error.
+    pub fn lenient() -> Self {
+        Self {
+            lenient: true,
+            remap: None,
+        }
+    }
+
+    /// Map each loaded path, e.g. `encoder.fc.weight`, to the path of the field it's loaded into
+    /// before matching, e.g. to follow a layer renamed between versions.
+    pub fn with_remap<F>(mut self, remap: F) -> Self
+    where
+        F: Fn(&str) -> String + Send + Sync + 'static,
+    {
+        self.remap = Some(Box::new(remap));
+        self
+    }
+
+    /// Match the leaves of the loaded item against the ones of the default item, returning the
+    /// default item with the matched values.
+    ///
+    /// The shapes of the matched tensors are compared on the converted item, whatever their
+    /// serialized form.
+    pub(crate) fn apply(
+        &self,
+        mut default: Value,
+        loaded: Value,
+    ) -> Result<(Value, KeyMismatch), RecorderError> {
+        let mut leaves = BTreeMap::new();
+        self.collect_leaves(loaded, String::new(), &mut leaves);
+
+        let mut missing = Vec::new();
+        fill_leaves(&mut default, "", &mut leaves, &mut missing);
+
+        let mismatch = KeyMismatch {
+            unexpected: leaves.into_keys().collect(),
+            missing,
+        };
+
+        if !self.lenient && !mismatch.is_empty() {
+            return Err(RecorderError::KeyMismatch(mismatch));
+        }
+
+        Ok((default, mismatch))
+    }
+
+    fn collect_leaves(&self, value: Value, path: String, leaves: &mut BTreeMap<String, Value>) {
+        if is_leaf(&value) {
+            let path = match &self.remap {
+                Some(remap) => remap(&path),
+                None => path,
+            };
+            leaves.insert(path, value);
+            return;
+        }
+
+        match value {
+            Value::Object(map) => map.into_iter().for_each(|(key, value)| {
+                self.collect_leaves(value, record_field_path(&path, &key), leaves)
+            }),
+            Value::Array(values) => values.into_iter().enumerate().for_each(|(index, value)| {
+                self.collect_leaves(value, record_field_path(&path, &index.to_string()), leaves)
+            }),
+            _ => unreachable!("Scalars are leaves"),
+        }
+    }
+}
+
+impl core::fmt::Debug for KeyMatching {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("KeyMatching")
+            .field("lenient", &self.lenient)
+            .field("remap", &self.remap.is_some())
+            .finish()
+    }
+}
+
+/// Paths that didn't match when loading a record with [KeyMatching].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KeyMismatch {
+    /// Loaded paths, after remapping, without a field in the record.
+    pub unexpected: Vec<String>,
+    /// Fields of the record without a loaded value.
+    pub missing: Vec<String>,
+}
+
+impl KeyMismatch {
+    /// Whether all the paths matched.
+    pub fn is_empty(&self) -> bool {
+        self.unexpected.is_empty() && self.missing.is_empty()
+    }
+}
+
+/// Whether the serialized value is matched as a whole: a tensor, a parameter or a primitive.
+fn is_leaf(value: &Value) -> bool {
+    match value {
+        // Parameters wrap their tensor with their id.
+        Value::Object(map) => {
+            is_tensor(map)
+                || is_non_finite(map)
+                || (map.contains_key("id") && map.contains_key("param"))
+        }
+        Value::Array(_) => false,
+        _ => true,
+    }
+}
+
+fn fill_leaves(
+    value: &mut Value,
+    path: &str,
+    leaves: &mut BTreeMap<String, Value>,
+    missing: &mut Vec<String>,
+) {
+    if is_leaf(value) {
+        match leaves.remove(path) {
+            Some(loaded) => *value = loaded,
+            None => missing.push(path.into()),
+        }
+
+        return;
+    }
+
+    match value {
+        Value::Object(map) => map.iter_mut().for_each(|(key, value)| {
+            fill_leaves(value, &record_field_path(path, key), leaves, missing)
+        }),
+        Value::Array(values) => values.iter_mut().enumerate().for_each(|(index, value)| {
+            fill_leaves(
+                value,
+                &record_field_path(path, &index.to_string()),
+                leaves,
+                missing,
+            )
+        }),
+        _ => {}
+    }
+}
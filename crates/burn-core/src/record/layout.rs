@@ -0,0 +1,102 @@
+use super::RecordLayout;
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_tensor::DataSerialize;
+
+impl RecordLayout {
+    /// Reorder the row-major values of the data, as returned by `to_data`, into the layout.
+    ///
+    /// The data must be compact, i.e. have exactly one value per element of its shape.
+    pub(crate) fn encode<'a, E: Clone>(
+        &self,
+        data: &'a DataSerialize<E>,
+    ) -> Result<Cow<'a, DataSerialize<E>>, String> {
+        check_compact(data)?;
+
+        match self {
+            RecordLayout::RowMajor => Ok(Cow::Borrowed(data)),
+            RecordLayout::ColumnMajor => Ok(Cow::Owned(DataSerialize::new(
+                reorder(&data.value, &data.shape, true),
+                data.shape.clone(),
+            ))),
+        }
+    }
+
+    /// Reorder the values of the data stored with the layout into row-major values.
+    ///
+    /// Row-major data is returned as is, its shape being checked when the tensor is created.
+    pub(crate) fn decode<E: Clone>(
+        &self,
+        data: DataSerialize<E>,
+    ) -> Result<DataSerialize<E>, String> {
+        match self {
+            RecordLayout::RowMajor => Ok(data),
+            RecordLayout::ColumnMajor => {
+                check_compact(&data)?;
+
+                Ok(DataSerialize::new(
+                    reorder(&data.value, &data.shape, false),
+                    data.shape,
+                ))
+            }
+        }
+    }
+}
+
+fn check_compact<E>(data: &DataSerialize<E>) -> Result<(), String> {
+    let num_elements = data.shape.iter().product::<usize>();
+
+    if data.value.len() != num_elements {
+        return Err(format!(
+            "Tensor data isn't compact: {} values for shape {:?}",
+            data.value.len(),
+            data.shape
+        ));
+    }
+
+    Ok(())
+}
+
+/// Move each value between its row-major and its column-major position.
+fn reorder<E: Clone>(values: &[E], shape: &[usize], to_column_major: bool) -> Vec<E> {
+    if shape.len() < 2 || values.is_empty() {
+        return values.to_vec();
+    }
+
+    // Strides of the column-major layout, the first dimension being contiguous.
+    let mut strides = vec![1; shape.len()];
+    for dim in 1..shape.len() {
+        strides[dim] = strides[dim - 1] * shape[dim - 1];
+    }
+
+    let mut output = values.to_vec();
+    let mut index = vec![0; shape.len()];
+
+    for row_major in 0..values.len() {
+        let column_major = index
+            .iter()
+            .zip(strides.iter())
+            .map(|(index, stride)| index * stride)
+            .sum::<usize>();
+
+        if to_column_major {
+            output[column_major] = values[row_major].clone();
+        } else {
+            output[row_major] = values[column_major].clone();
+        }
+
+        // Increment the row-major index, the last dimension being contiguous.
+        for dim in (0..shape.len()).rev() {
+            index[dim] += 1;
+            if index[dim] < shape[dim] {
+                break;
+            }
+            index[dim] = 0;
+        }
+    }
+
+    output
+}
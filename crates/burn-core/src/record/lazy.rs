@@ -0,0 +1,232 @@
+use super::progress::tensor_processed;
+use super::staging::staging_failed;
+use super::tensor::{check_data_shape, FloatTensorSerde};
+use super::{PrecisionSettings, Record, RecorderError, ShapeCheck};
+use burn_tensor::{backend::Backend, DataSerialize, ElementConversion, Shape, Tensor};
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// Float tensor record kept on the host until it's first accessed.
+///
+/// The record is serialized like a [Tensor], so a record can be loaded with lazy tensors in place
+/// of its tensors, e.g. to only upload the weights of a submodule once it's used. Loading the
+/// record keeps the data on the host, and the first call to [tensor](LazyTensor::tensor) creates
+/// the tensor on the device, after which the host data is freed.
+///
+/// Clones share the same tensor: it's uploaded once, even when several threads access it
+/// concurrently, the other threads waiting for the first upload to complete.
+pub struct LazyTensor<B: Backend, const D: usize> {
+    state: Arc<Mutex<LazyState<B, D>>>,
+    shape: Shape<D>,
+    device: B::Device,
+}
+
+struct LazyState<B: Backend, const D: usize> {
+    data: Option<DataSerialize<B::FloatElem>>,
+    tensor: Option<Tensor<B, D>>,
+}
+
+impl<B: Backend, const D: usize> LazyTensor<B, D> {
+    /// The tensor, created on the device on the first access.
+    pub fn tensor(&self) -> Tensor<B, D> {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+
+        if let Some(tensor) = &state.tensor {
+            return tensor.clone();
+        }
+
+        let data = state
+            .data
+            .take()
+            .expect("The data should be on the host until the tensor is created");
+        let tensor = Tensor::from_data(data, &self.device);
+        state.tensor = Some(tensor.clone());
+
+        tensor
+    }
+
+    /// Whether the tensor was created on the device.
+    pub fn is_materialized(&self) -> bool {
+        self.state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .tensor
+            .is_some()
+    }
+
+    /// The shape of the tensor, available without creating it.
+    pub fn shape(&self) -> Shape<D> {
+        self.shape.clone()
+    }
+
+    /// The device the tensor is created on.
+    pub fn device(&self) -> B::Device {
+        self.device.clone()
+    }
+}
+
+impl<B: Backend, const D: usize> From<Tensor<B, D>> for LazyTensor<B, D> {
+    fn from(tensor: Tensor<B, D>) -> Self {
+        Self {
+            shape: tensor.shape(),
+            device: tensor.device(),
+            state: Arc::new(Mutex::new(LazyState {
+                data: None,
+                tensor: Some(tensor),
+            })),
+        }
+    }
+}
+
+impl<B: Backend, const D: usize> Clone for LazyTensor<B, D> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            shape: self.shape.clone(),
+            device: self.device.clone(),
+        }
+    }
+}
+
+impl<B: Backend, const D: usize> core::fmt::Debug for LazyTensor<B, D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("LazyTensor")
+            .field("shape", &self.shape)
+            .field("device", &self.device)
+            .field("materialized", &self.is_materialized())
+            .finish()
+    }
+}
+
+impl<B: Backend, const D: usize> Record<B> for LazyTensor<B, D> {
+    type Item<S: PrecisionSettings> = FloatTensorSerde<S>;
+
+    fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
+        let state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+
+        match (&state.tensor, &state.data) {
+            (Some(tensor), _) => Record::<B>::into_item::<S>(tensor.clone()),
+            (None, Some(data)) => FloatTensorSerde::new(data.clone().convert()),
+            (None, None) => unreachable!("The lazy tensor has either data or a tensor"),
+        }
+    }
+
+    fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
+        Self::from_item_with_path(item, device, "")
+    }
+
+    fn from_item_with_path<S: PrecisionSettings>(
+        item: Self::Item<S>,
+        device: &B::Device,
+        path: &str,
+    ) -> Self {
+        let shape = item.shape().to_vec();
+        let data = item.into_data().unwrap_or_else(|err| {
+            staging_failed(err, path);
+            DataSerialize::new(
+                alloc::vec![S::FloatElem::from_elem(0); shape.iter().product()],
+                shape,
+            )
+        });
+        check_data_shape::<D>(&data.shape, data.value.len(), path)
+            .unwrap_or_else(|err| panic!("{err}"));
+
+        // The tensor is loaded, even though it's only moved to the device on first access.
+        tensor_processed();
+
+        Self {
+            shape: Shape::from(&data.shape),
+            device: device.clone(),
+            state: Arc::new(Mutex::new(LazyState {
+                data: Some(data.convert()),
+                tensor: None,
+            })),
+        }
+    }
+
+    fn check_shapes<S: PrecisionSettings>(
+        item: &Self::Item<S>,
+        path: &str,
+        shapes: &mut ShapeCheck,
+    ) -> Result<(), RecorderError> {
+        <Tensor<B, D> as Record<B>>::check_shapes::<S>(item, path, shapes)
+    }
+
+    fn check_finite<S: PrecisionSettings>(
+        item: &Self::Item<S>,
+        path: &str,
+    ) -> Result<(), RecorderError> {
+        <Tensor<B, D> as Record<B>>::check_finite::<S>(item, path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        record::{BinBytesRecorder, FullPrecisionSettings, Recorder},
+        tensor::Distribution,
+        TestBackend,
+    };
+
+    fn lazy_tensor() -> (Tensor<TestBackend, 2>, LazyTensor<TestBackend, 2>) {
+        let device = Default::default();
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let tensor = Tensor::<TestBackend, 2>::random([4, 8], Distribution::Default, &device);
+
+        let bytes = recorder.record(tensor.clone(), ()).unwrap();
+        let lazy: LazyTensor<TestBackend, 2> = recorder.load(bytes, &device).unwrap();
+
+        (tensor, lazy)
+    }
+
+    #[test]
+    fn test_tensor_is_created_on_first_access() {
+        let (tensor, lazy) = lazy_tensor();
+
+        assert!(!lazy.is_materialized());
+        assert_eq!(lazy.shape(), Shape::new([4, 8]));
+        assert!(!lazy.is_materialized());
+
+        lazy.tensor()
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 6);
+        assert!(lazy.is_materialized());
+        assert!(lazy.state.lock().unwrap().data.is_none());
+    }
+
+    #[test]
+    fn test_concurrent_accesses_create_the_tensor_once() {
+        let (tensor, lazy) = lazy_tensor();
+
+        let handles = (0..4)
+            .map(|_| {
+                let lazy = lazy.clone();
+                std::thread::spawn(move || lazy.tensor().into_data())
+            })
+            .collect::<Vec<_>>();
+
+        for handle in handles {
+            handle
+                .join()
+                .unwrap()
+                .assert_approx_eq(&tensor.clone().into_data(), 6);
+        }
+        assert!(lazy.is_materialized());
+    }
+
+    #[test]
+    fn test_lazy_tensor_is_recorded_like_a_tensor() {
+        let device = Default::default();
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let (tensor, lazy) = lazy_tensor();
+
+        // Recorded before and after being materialized.
+        let bytes = recorder.record(tensor.clone(), ()).unwrap();
+        assert_eq!(recorder.record(lazy.clone(), ()).unwrap(), bytes);
+        lazy.tensor();
+        assert_eq!(recorder.record(lazy, ()).unwrap(), bytes);
+
+        let loaded: Tensor<TestBackend, 2> = recorder.load(bytes, &device).unwrap();
+        loaded.into_data().assert_approx_eq(&tensor.into_data(), 6);
+    }
+}
@@ -1,10 +1,23 @@
-use super::{bin_config, PrecisionSettings, Recorder, RecorderError};
+use super::checksum::append_checksum;
+#[cfg(feature = "std")]
+use super::checksum::verify_checksum;
+use super::{
+    bin_config, decode_bin_record, PrecisionSettings, RecordFormat, Recorder, RecorderError,
+};
+use alloc::string::ToString;
 use alloc::vec::Vec;
 use burn_tensor::backend::Backend;
 use serde::{de::DeserializeOwned, Serialize};
 
+#[cfg(feature = "std")]
+use super::SelfDescribingRecorder;
+
 /// Recorder trait specialized to save and load data to and from bytes.
 ///
+/// The bytes have the same format as the equivalent [file recorder](crate::record::FileRecorder),
+/// e.g. a file written by the `BinFileRecorder` can be loaded from its bytes with the
+/// [BinBytesRecorder] and vice versa.
+///
 /// # Notes
 ///
 /// This is especially useful in no_std environment where weights are stored directly in
@@ -33,11 +46,20 @@ impl<S: PrecisionSettings, B: Backend> Recorder<B> for BinBytesRecorder<S> {
         item: I,
         _args: Self::RecordArgs,
     ) -> Result<Self::RecordOutput, RecorderError> {
-        Ok(bincode::serde::encode_to_vec(item, bin_config()).unwrap())
+        let payload = bincode::serde::encode_to_vec(item, bin_config())
+            .map_err(|e| RecorderError::Unknown(e.to_string()))?;
+
+        let mut bytes = RecordFormat::Bin
+            .header()
+            .map(Vec::from)
+            .unwrap_or_default();
+        let offset = bytes.len();
+        bytes.extend_from_slice(&payload);
+        append_checksum::<S>(&mut bytes, offset);
+        Ok(bytes)
     }
     fn load_item<I: DeserializeOwned>(&self, args: Self::LoadArgs) -> Result<I, RecorderError> {
-        let state = bincode::serde::decode_borrowed_from_slice(&args, bin_config()).unwrap();
-        Ok(state)
+        decode_bin_record::<S, _>(RecordFormat::Bin, &args)
     }
 }
 
@@ -51,22 +73,36 @@ pub struct NamedMpkBytesRecorder<S: PrecisionSettings> {
 #[cfg(feature = "std")]
 impl<S: PrecisionSettings, B: Backend> BytesRecorder<B> for NamedMpkBytesRecorder<S> {}
 
+#[cfg(feature = "std")]
+impl<S: PrecisionSettings, B: Backend> SelfDescribingRecorder<B> for NamedMpkBytesRecorder<S> {}
+
 #[cfg(feature = "std")]
 impl<S: PrecisionSettings, B: Backend> Recorder<B> for NamedMpkBytesRecorder<S> {
     type Settings = S;
     type RecordArgs = ();
     type RecordOutput = Vec<u8>;
     type LoadArgs = Vec<u8>;
+    const SELF_DESCRIBING: bool = true;
 
     fn save_item<I: Serialize>(
         &self,
         item: I,
         _args: Self::RecordArgs,
     ) -> Result<Self::RecordOutput, RecorderError> {
-        rmp_serde::encode::to_vec_named(&item).map_err(|e| RecorderError::Unknown(e.to_string()))
+        let mut bytes = RecordFormat::NamedMpk
+            .header()
+            .map(Vec::from)
+            .unwrap_or_default();
+        let offset = bytes.len();
+        rmp_serde::encode::write_named(&mut bytes, &item)
+            .map_err(|e| RecorderError::Unknown(e.to_string()))?;
+        append_checksum::<S>(&mut bytes, offset);
+        Ok(bytes)
     }
     fn load_item<I: DeserializeOwned>(&self, args: Self::LoadArgs) -> Result<I, RecorderError> {
-        rmp_serde::decode::from_slice(&args).map_err(|e| RecorderError::Unknown(e.to_string()))
+        let offset = RecordFormat::NamedMpk.check_header(&args)?;
+        rmp_serde::decode::from_slice(verify_checksum::<S>(&args[offset..])?)
+            .map_err(|e| RecorderError::Unknown(e.to_string()))
     }
 }
 
@@ -74,7 +110,15 @@ impl<S: PrecisionSettings, B: Backend> Recorder<B> for NamedMpkBytesRecorder<S>
 mod tests {
     use super::*;
     use crate::{
-        module::Module, nn, record::FullPrecisionSettings, tensor::backend::Backend, TestBackend,
+        module::Module,
+        nn,
+        record::{
+            tensor::FloatTensorSerde, BF16PrecisionSettings, FullPrecisionSettings,
+            HalfPrecisionSettings, Int8PrecisionSettings, PrecisionSettings, Record,
+            RecordCompression, RecordLayout,
+        },
+        tensor::{backend::Backend, Distribution, Tensor},
+        TestBackend,
     };
 
     #[test]
@@ -88,6 +132,262 @@ mod tests {
         test_can_save_and_load(NamedMpkBytesRecorder::<FullPrecisionSettings>::default())
     }
 
+    #[cfg(feature = "std")]
+    #[derive(Debug, Default, Clone)]
+    struct GzipSettings;
+
+    #[cfg(feature = "std")]
+    impl PrecisionSettings for GzipSettings {
+        type FloatElem = f32;
+        type IntElem = i32;
+        const COMPRESSION: RecordCompression = RecordCompression::Gzip;
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_can_save_and_load_compressed_bin_format() {
+        test_can_save_and_load(BinBytesRecorder::<GzipSettings>::default())
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_can_save_and_load_compressed_bool_tensor() {
+        use crate::tensor::Bool;
+
+        let device = Default::default();
+        let recorder = NamedMpkBytesRecorder::<GzipSettings>::default();
+        let tensor = Tensor::<TestBackend, 2, Bool>::from_bool(
+            [[true, false], [false, true]].into(),
+            &device,
+        );
+
+        let bytes = recorder.record(tensor.clone(), ()).unwrap();
+        let tensor_after: Tensor<TestBackend, 2, Bool> = recorder.load(bytes, &device).unwrap();
+
+        assert_eq!(tensor.into_data(), tensor_after.into_data());
+    }
+
+    #[cfg(feature = "zstd")]
+    #[derive(Debug, Default, Clone)]
+    struct ZstdSettings;
+
+    #[cfg(feature = "zstd")]
+    impl PrecisionSettings for ZstdSettings {
+        type FloatElem = f32;
+        type IntElem = i32;
+        const COMPRESSION: RecordCompression = RecordCompression::Zstd;
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn test_can_load_compressed_tensor_with_another_codec() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2>::random([4, 3], Distribution::Default, &device);
+
+        let bytes = NamedMpkBytesRecorder::<GzipSettings>::default()
+            .record(tensor.clone(), ())
+            .unwrap();
+        let tensor_after: Tensor<TestBackend, 2> = NamedMpkBytesRecorder::<ZstdSettings>::default()
+            .load(bytes, &device)
+            .unwrap();
+
+        tensor
+            .into_data()
+            .assert_approx_eq(&tensor_after.into_data(), 6);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_load_uncompressed_tensor_with_compression_errors() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2>::random([4, 3], Distribution::Default, &device);
+
+        let bytes = NamedMpkBytesRecorder::<FullPrecisionSettings>::default()
+            .record(tensor, ())
+            .unwrap();
+        let result: Result<Tensor<TestBackend, 2>, _> =
+            NamedMpkBytesRecorder::<GzipSettings>::default().load(bytes, &device);
+
+        assert!(matches!(
+            result,
+            Err(RecorderError::Unknown(message))
+                if message.contains("the settings use Gzip compression")
+        ));
+    }
+
+    #[test]
+    fn test_map_records_are_serialized_in_a_stable_order() {
+        use crate::module::ParamId;
+        use alloc::format;
+        use hashbrown::HashMap;
+
+        let device = Default::default();
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let entries = (0..32)
+            .map(|i| {
+                let tensor = Tensor::<TestBackend, 1>::from_floats([i as f32; 2], &device);
+                (ParamId::from(format!("param{i}")), tensor)
+            })
+            .collect::<Vec<_>>();
+
+        // The same entries inserted in a different order into maps with different capacities.
+        let map = entries.iter().cloned().collect::<HashMap<_, _>>();
+        let mut map_reversed = HashMap::with_capacity(256);
+        map_reversed.extend(entries.into_iter().rev());
+
+        let bytes = recorder.record(map, ()).unwrap();
+        let bytes_reversed = recorder.record(map_reversed, ()).unwrap();
+
+        assert_eq!(bytes, bytes_reversed);
+    }
+
+    #[derive(Debug, Default, Clone)]
+    struct ColumnMajorSettings;
+
+    impl PrecisionSettings for ColumnMajorSettings {
+        type FloatElem = f32;
+        type IntElem = i32;
+        const LAYOUT: RecordLayout = RecordLayout::ColumnMajor;
+    }
+
+    #[test]
+    fn test_can_save_and_load_column_major_layout() {
+        test_can_save_and_load(BinBytesRecorder::<ColumnMajorSettings>::default())
+    }
+
+    #[test]
+    fn test_column_major_layout_stores_first_dim_contiguous() {
+        let device = Default::default();
+        let tensor =
+            Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+
+        let bytes = BinBytesRecorder::<ColumnMajorSettings>::default()
+            .record(tensor, ())
+            .unwrap();
+        // Loaded as row-major values, the stored order is visible.
+        let tensor: Tensor<TestBackend, 2> = BinBytesRecorder::<FullPrecisionSettings>::default()
+            .load(bytes, &device)
+            .unwrap();
+
+        assert_eq!(tensor.into_data().value, vec![1.0, 4.0, 2.0, 5.0, 3.0, 6.0]);
+    }
+
+    #[test]
+    fn test_half_precision_round_trip_extreme_values() {
+        // Largest f16 values, a subnormal f16 value and a regular value.
+        test_round_trip_bounded_error::<HalfPrecisionSettings>(
+            [65504.0, -65504.0, 1.0e-6, 1.234_567],
+            1.0e-3,
+            6.0e-8,
+        );
+    }
+
+    #[test]
+    fn test_half_precision_keeps_int_values_outside_of_i16() {
+        use crate::tensor::Int;
+
+        let device = Default::default();
+        let recorder = BinBytesRecorder::<HalfPrecisionSettings>::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::from_ints([40_000, -40_000, 7], &device);
+
+        let bytes = recorder.record(tensor.clone(), ()).unwrap();
+        let tensor_after: Tensor<TestBackend, 1, Int> = recorder.load(bytes, &device).unwrap();
+
+        assert_eq!(tensor.into_data(), tensor_after.into_data());
+    }
+
+    #[test]
+    fn test_bf16_precision_round_trip_extreme_values() {
+        // Values outside of the f16 range, on both ends, and a regular value.
+        test_round_trip_bounded_error::<BF16PrecisionSettings>(
+            [1.0e38, -1.0e38, 1.0e-30, 1.234_567],
+            8.0e-3,
+            0.0,
+        );
+    }
+
+    #[test]
+    fn test_int8_quantization_round_trip_gaussian_tensor() {
+        let device = Default::default();
+        let recorder = BinBytesRecorder::<Int8PrecisionSettings>::default();
+        let tensor =
+            Tensor::<TestBackend, 2>::random([32, 32], Distribution::Normal(0.0, 1.0), &device);
+
+        let bytes = recorder.record(tensor.clone(), ()).unwrap();
+        let tensor_after: Tensor<TestBackend, 2> = recorder.load(bytes, &device).unwrap();
+
+        let max_abs = tensor.clone().abs().max().into_scalar();
+        let max_error = (tensor - tensor_after).abs().max().into_scalar();
+        // Each value is rounded to the nearest multiple of the scale.
+        assert!(max_error <= max_abs / 127.0 / 2.0 + 1.0e-6);
+    }
+
+    #[test]
+    fn test_int8_quantization_round_trip_zeros() {
+        let device = Default::default();
+        let recorder = BinBytesRecorder::<Int8PrecisionSettings>::default();
+        let tensor = Tensor::<TestBackend, 2>::zeros([4, 4], &device);
+
+        let bytes = recorder.record(tensor.clone(), ()).unwrap();
+        let tensor_after: Tensor<TestBackend, 2> = recorder.load(bytes, &device).unwrap();
+
+        assert_eq!(tensor.into_data(), tensor_after.into_data());
+    }
+
+    #[test]
+    fn test_int8_quantization_keeps_loaded_tensors_quantized() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1>::from_floats([1.0, -2.0, 0.5], &device);
+
+        let item = Record::<TestBackend>::into_item::<Int8PrecisionSettings>(tensor.clone());
+        let value = serde_json::to_value(&item).unwrap();
+        let quantized: FloatTensorSerde<Int8PrecisionSettings> =
+            serde_json::from_value(value).unwrap();
+        let item = Record::<TestBackend>::into_item::<FullPrecisionSettings>(tensor.clone());
+        let value = serde_json::to_value(&item).unwrap();
+        let float: FloatTensorSerde<FullPrecisionSettings> = serde_json::from_value(value).unwrap();
+
+        assert!(quantized.is_quantized());
+        assert!(!float.is_quantized());
+
+        // The backend doesn't store quantized tensors, so the values are dequantized.
+        let tensor_after: Tensor<TestBackend, 1> = Tensor::from_item(quantized, &device);
+        tensor
+            .into_data()
+            .assert_approx_eq(&tensor_after.into_data(), 1);
+    }
+
+    #[test]
+    fn test_int8_quantization_round_trip_outlier() {
+        // The outlier is kept, the small values are lost in the quantization error.
+        test_round_trip_bounded_error::<Int8PrecisionSettings>(
+            [1.0e6, 1.0, -2.0, 0.5],
+            1.0e-6,
+            1.0e6 / 127.0 / 2.0,
+        );
+    }
+
+    fn test_round_trip_bounded_error<S: PrecisionSettings>(
+        values: [f32; 4],
+        relative_error: f32,
+        absolute_error: f32,
+    ) {
+        let device = Default::default();
+        let recorder = BinBytesRecorder::<S>::default();
+        let tensor = Tensor::<TestBackend, 1>::from_floats(values, &device);
+
+        let bytes = recorder.record(tensor, ()).unwrap();
+        let tensor: Tensor<TestBackend, 1> = recorder.load(bytes, &device).unwrap();
+
+        for (expected, actual) in values.iter().zip(tensor.into_data().value) {
+            let error = (expected - actual).abs();
+            assert!(
+                error <= relative_error * expected.abs() + absolute_error,
+                "Expected {expected}, got {actual}"
+            );
+        }
+    }
+
     fn test_can_save_and_load<Recorder>(recorder: Recorder)
     where
         Recorder: BytesRecorder<TestBackend>,
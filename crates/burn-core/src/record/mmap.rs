@@ -0,0 +1,187 @@
+use super::{
+    bin_config, decode_bin_record, BinFileRecorder, FileRecorder, PrecisionSettings, RecordFormat,
+    Recorder, RecorderError,
+};
+use burn_tensor::backend::Backend;
+use core::marker::PhantomData;
+use memmap2::Mmap;
+use serde::{de::DeserializeOwned, Serialize};
+use std::fs::File;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+/// File recorder loading records in the [bincode format](bincode) from a memory-mapped file.
+///
+/// Records are saved the same way as with the [BinFileRecorder], but they are decoded straight
+/// from the mapped file instead of being read into memory first, which reduces the startup time
+/// when loading large models. Tensors recorded with the element types of the backend are moved
+/// into the backend without being converted, while the other ones, e.g. `f16` tensors loaded by
+/// a `f32` backend, are converted as with the other recorders.
+///
+/// # Safety
+///
+/// The file must not be modified while it is mapped, i.e. during the call to
+/// [load](Recorder::load). The recorders of Burn replace the files they save instead of writing
+/// into them, so saving a record to the same path doesn't affect a load in progress, but another
+/// process truncating the file in place can crash the program. The size and the modification
+/// time of the file are compared before and after decoding the record, and the load fails if the
+/// file was modified in between.
+#[derive(new, Debug, Default, Clone)]
+pub struct MmapFileRecorder<S: PrecisionSettings> {
+    _settings: PhantomData<S>,
+}
+
+impl<S: PrecisionSettings, B: Backend> FileRecorder<B> for MmapFileRecorder<S> {
+    fn file_extension() -> &'static str {
+        "bin"
+    }
+}
+
+impl<S: PrecisionSettings, B: Backend> Recorder<B> for MmapFileRecorder<S> {
+    type Settings = S;
+    type RecordArgs = PathBuf;
+    type RecordOutput = ();
+    type LoadArgs = PathBuf;
+
+    fn save_item<I: Serialize>(
+        &self,
+        item: I,
+        file: Self::RecordArgs,
+    ) -> Result<(), RecorderError> {
+        Recorder::<B>::save_item(&BinFileRecorder::<S>::new(), item, file)
+    }
+
+    fn load_item<I: DeserializeOwned>(&self, mut file: Self::LoadArgs) -> Result<I, RecorderError> {
+        file.set_extension(<Self as FileRecorder<B>>::file_extension());
+
+        let reader = File::open(&file).map_err(|err| match err.kind() {
+            std::io::ErrorKind::NotFound => RecorderError::FileNotFound(err.to_string()),
+            _ => RecorderError::Unknown(err.to_string()),
+        })?;
+        let before = FileState::new(&reader)?;
+
+        // SAFETY: The file isn't modified by Burn while it's mapped, and modifications by other
+        // processes are detected once the record is decoded.
+        let mmap =
+            unsafe { Mmap::map(&reader) }.map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        let state = decode_bin_record::<S, _>(RecordFormat::Bin, &mmap)?;
+
+        if FileState::new(&reader)? != before {
+            return Err(RecorderError::Unknown(format!(
+                "The file {} was modified while loading the record",
+                file.display()
+            )));
+        }
+
+        Ok(state)
+    }
+}
+
+/// Size and modification time of a file, used to detect modifications while it's mapped.
+#[derive(Debug, PartialEq, Eq)]
+struct FileState {
+    len: u64,
+    modified: Option<SystemTime>,
+}
+
+impl FileState {
+    fn new(file: &File) -> Result<Self, RecorderError> {
+        let metadata = file
+            .metadata()
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        Ok(Self {
+            len: metadata.len(),
+            modified: metadata.modified().ok(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        module::Module,
+        nn::{Linear, LinearConfig},
+        record::{FullPrecisionSettings, HalfPrecisionSettings},
+        TestBackend,
+    };
+
+    fn linear_file(name: &str) -> PathBuf {
+        let file = std::env::temp_dir().join(name);
+        let device = Default::default();
+        let linear: Linear<TestBackend> = LinearConfig::new(32, 16).init(&device);
+
+        linear
+            .save_file(
+                file.clone(),
+                &BinFileRecorder::<FullPrecisionSettings>::new(),
+            )
+            .unwrap();
+
+        file
+    }
+
+    #[test]
+    fn test_mmap_loads_bin_file() {
+        let file = linear_file("burn_test_mmap_bin");
+        let device = Default::default();
+        let expected: Linear<TestBackend> = LinearConfig::new(32, 16)
+            .init(&device)
+            .load_file(
+                file.clone(),
+                &BinFileRecorder::<FullPrecisionSettings>::new(),
+                &device,
+            )
+            .unwrap();
+
+        let linear: Linear<TestBackend> = LinearConfig::new(32, 16)
+            .init(&device)
+            .load_file(
+                file,
+                &MmapFileRecorder::<FullPrecisionSettings>::new(),
+                &device,
+            )
+            .unwrap();
+
+        linear
+            .weight
+            .to_data()
+            .assert_approx_eq(&expected.weight.to_data(), 6);
+    }
+
+    #[test]
+    fn test_mmap_converts_elements() {
+        let file = std::env::temp_dir().join("burn_test_mmap_half");
+        let device = Default::default();
+        let recorder = MmapFileRecorder::<HalfPrecisionSettings>::new();
+        let expected: Linear<TestBackend> = LinearConfig::new(32, 16).init(&device);
+
+        expected.clone().save_file(file.clone(), &recorder).unwrap();
+
+        let linear: Linear<TestBackend> = LinearConfig::new(32, 16)
+            .init(&device)
+            .load_file(file, &recorder, &device)
+            .unwrap();
+
+        linear
+            .weight
+            .to_data()
+            .assert_approx_eq(&expected.weight.to_data(), 2);
+    }
+
+    #[test]
+    fn err_when_file_is_missing() {
+        let device = Default::default();
+        let result = LinearConfig::new(32, 16)
+            .init::<TestBackend>(&device)
+            .load_file(
+                std::env::temp_dir().join("burn_test_mmap_missing"),
+                &MmapFileRecorder::<FullPrecisionSettings>::new(),
+                &device,
+            );
+
+        assert!(matches!(result, Err(RecorderError::FileNotFound(_))));
+    }
+}
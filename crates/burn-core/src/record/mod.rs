@@ -1,20 +1,85 @@
+mod checksum;
+mod chunk;
+mod compression;
+mod in_place;
+mod layout;
 mod primitive;
+mod quantization;
+mod require_grad;
+mod shapes;
+#[cfg(feature = "std")]
+mod staging;
 mod tensor;
+mod tied;
+mod value;
 
 mod base;
+mod convert;
+mod format;
+mod keys;
 mod memory;
 mod recorder;
 mod settings;
 
 pub use base::*;
+pub use convert::*;
+pub use format::*;
+pub use keys::*;
 pub use memory::*;
 pub use recorder::*;
 pub use settings::*;
+pub use shapes::ShapeCheck;
 
+#[cfg(feature = "std")]
+mod bundle;
+#[cfg(feature = "std")]
+pub use bundle::*;
+#[cfg(feature = "std")]
+mod tensor_file;
+#[cfg(feature = "std")]
+pub use tensor_file::*;
+#[cfg(feature = "std")]
+mod external;
+#[cfg(feature = "std")]
+pub use external::{ExternalDataFileRecorder, ExternalTensor};
 #[cfg(feature = "std")]
 mod file;
 #[cfg(feature = "std")]
 pub use file::*;
+#[cfg(feature = "std")]
+mod stream;
+#[cfg(feature = "std")]
+pub use stream::*;
+#[cfg(feature = "mmap")]
+mod mmap;
+#[cfg(feature = "mmap")]
+pub use mmap::*;
+#[cfg(feature = "encryption")]
+mod encryption;
+#[cfg(feature = "encryption")]
+pub use encryption::*;
+#[cfg(feature = "async-record")]
+mod async_load;
+#[cfg(feature = "async-record")]
+pub use async_load::*;
+#[cfg(feature = "std")]
+mod progress;
+#[cfg(feature = "std")]
+pub use progress::{ProgressCallback, ProgressRecorder};
+#[cfg(feature = "std")]
+mod dtype_report;
+#[cfg(feature = "std")]
+pub use dtype_report::{load_with_dtype_report, DTypeConversion};
+#[cfg(feature = "std")]
+mod diff;
+#[cfg(feature = "std")]
+mod lazy;
+#[cfg(feature = "std")]
+pub use lazy::LazyTensor;
+#[cfg(feature = "std")]
+mod summary;
+#[cfg(feature = "std")]
+pub use diff::*;
 
 pub use primitive::ParamSerde;
 
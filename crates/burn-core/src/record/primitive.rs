@@ -1,12 +1,15 @@
 use alloc::{
+    collections::BTreeMap,
     string::{String, ToString},
     vec,
     vec::Vec,
 };
 use core::{fmt, marker::PhantomData};
 
-use super::tensor::{BoolTensorSerde, FloatTensorSerde, IntTensorSerde};
-use super::{PrecisionSettings, Record};
+use super::require_grad::{record_require_grad, recorded_require_grad};
+use super::tensor::{check_finite_values, BoolTensorSerde, FloatTensorSerde, IntTensorSerde};
+use super::tied::tied_tensor;
+use super::{record_field_path, PrecisionSettings, Record, RecorderError, ShapeCheck};
 use crate::module::{Param, ParamId};
 
 use burn_tensor::{backend::Backend, Bool, DataSerialize, Element, Int, Tensor};
@@ -41,10 +44,66 @@ where
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
+        Self::from_item_with_path(item, device, "")
+    }
+
+    fn from_item_with_path<S: PrecisionSettings>(
+        item: Self::Item<S>,
+        device: &B::Device,
+        path: &str,
+    ) -> Self {
         item.into_iter()
-            .map(|i| Record::from_item(i, device))
+            .enumerate()
+            .map(|(index, i)| {
+                Record::from_item_with_path(i, device, &record_field_path(path, &index.to_string()))
+            })
             .collect()
     }
+
+    fn check_int_overflow<S: PrecisionSettings>(
+        item: &Self::Item<S>,
+        path: &str,
+    ) -> Result<(), RecorderError> {
+        item.iter().enumerate().try_for_each(|(index, i)| {
+            <T as Record<B>>::check_int_overflow::<S>(
+                i,
+                &record_field_path(path, &index.to_string()),
+            )
+        })
+    }
+
+    fn check_shapes<S: PrecisionSettings>(
+        item: &Self::Item<S>,
+        path: &str,
+        shapes: &mut ShapeCheck,
+    ) -> Result<(), RecorderError> {
+        item.iter().enumerate().try_for_each(|(index, i)| {
+            <T as Record<B>>::check_shapes::<S>(
+                i,
+                &record_field_path(path, &index.to_string()),
+                shapes,
+            )
+        })
+    }
+
+    fn check_finite<S: PrecisionSettings>(
+        item: &Self::Item<S>,
+        path: &str,
+    ) -> Result<(), RecorderError> {
+        item.iter().enumerate().try_for_each(|(index, i)| {
+            <T as Record<B>>::check_finite::<S>(i, &record_field_path(path, &index.to_string()))
+        })
+    }
+
+    fn offset_indices<S: PrecisionSettings>(item: &mut Self::Item<S>, path: &str, offset: i64) {
+        item.iter_mut().enumerate().for_each(|(index, i)| {
+            <T as Record<B>>::offset_indices::<S>(
+                i,
+                &record_field_path(path, &index.to_string()),
+                offset,
+            )
+        })
+    }
 }
 
 impl<T, B> Record<B> for Option<T>
@@ -59,7 +118,52 @@ where
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
-        item.map(|i| Record::from_item(i, device))
+        Self::from_item_with_path(item, device, "")
+    }
+
+    fn from_item_with_path<S: PrecisionSettings>(
+        item: Self::Item<S>,
+        device: &B::Device,
+        path: &str,
+    ) -> Self {
+        item.map(|i| Record::from_item_with_path(i, device, path))
+    }
+
+    fn check_int_overflow<S: PrecisionSettings>(
+        item: &Self::Item<S>,
+        path: &str,
+    ) -> Result<(), RecorderError> {
+        match item {
+            Some(i) => <T as Record<B>>::check_int_overflow::<S>(i, path),
+            None => Ok(()),
+        }
+    }
+
+    fn check_shapes<S: PrecisionSettings>(
+        item: &Self::Item<S>,
+        path: &str,
+        shapes: &mut ShapeCheck,
+    ) -> Result<(), RecorderError> {
+        match item {
+            Some(i) => <T as Record<B>>::check_shapes::<S>(i, path, shapes),
+            None => Ok(()),
+        }
+    }
+
+    fn check_finite<S: PrecisionSettings>(
+        item: &Self::Item<S>,
+        path: &str,
+    ) -> Result<(), RecorderError> {
+        match item {
+            Some(i) => <T as Record<B>>::check_finite::<S>(i, path),
+            None => Ok(()),
+        }
+    }
+
+    fn offset_indices<S: PrecisionSettings>(item: &mut Self::Item<S>, path: &str, offset: i64) {
+        if let Some(i) = item {
+            <T as Record<B>>::offset_indices::<S>(i, path, offset)
+        }
     }
 }
 
@@ -80,7 +184,69 @@ where
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
-        item.0.map(|i| Record::from_item(i, device))
+        Self::from_item_with_path(item, device, "")
+    }
+
+    fn from_item_with_path<S: PrecisionSettings>(
+        item: Self::Item<S>,
+        device: &B::Device,
+        path: &str,
+    ) -> Self {
+        let mut index = 0;
+        item.0.map(|i| {
+            let record = Record::from_item_with_path(
+                i,
+                device,
+                &record_field_path(path, &index.to_string()),
+            );
+            index += 1;
+            record
+        })
+    }
+
+    fn check_int_overflow<S: PrecisionSettings>(
+        item: &Self::Item<S>,
+        path: &str,
+    ) -> Result<(), RecorderError> {
+        item.0.iter().enumerate().try_for_each(|(index, i)| {
+            <T as Record<B>>::check_int_overflow::<S>(
+                i,
+                &record_field_path(path, &index.to_string()),
+            )
+        })
+    }
+
+    fn check_shapes<S: PrecisionSettings>(
+        item: &Self::Item<S>,
+        path: &str,
+        shapes: &mut ShapeCheck,
+    ) -> Result<(), RecorderError> {
+        item.0.iter().enumerate().try_for_each(|(index, i)| {
+            <T as Record<B>>::check_shapes::<S>(
+                i,
+                &record_field_path(path, &index.to_string()),
+                shapes,
+            )
+        })
+    }
+
+    fn check_finite<S: PrecisionSettings>(
+        item: &Self::Item<S>,
+        path: &str,
+    ) -> Result<(), RecorderError> {
+        item.0.iter().enumerate().try_for_each(|(index, i)| {
+            <T as Record<B>>::check_finite::<S>(i, &record_field_path(path, &index.to_string()))
+        })
+    }
+
+    fn offset_indices<S: PrecisionSettings>(item: &mut Self::Item<S>, path: &str, offset: i64) {
+        item.0.iter_mut().enumerate().for_each(|(index, i)| {
+            <T as Record<B>>::offset_indices::<S>(
+                i,
+                &record_field_path(path, &index.to_string()),
+                offset,
+            )
+        })
     }
 }
 
@@ -105,7 +271,48 @@ macro_rules! impl_record_tuple {
             }
 
             fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
-                ($(Record::from_item(item.$i, device),)*)
+                Self::from_item_with_path(item, device, "")
+            }
+
+            fn from_item_with_path<S: PrecisionSettings>(
+                item: Self::Item<S>,
+                device: &B::Device,
+                path: &str,
+            ) -> Self {
+                ($(Record::from_item_with_path(
+                    item.$i,
+                    device,
+                    &record_field_path(path, stringify!($i)),
+                ),)*)
+            }
+
+            fn check_int_overflow<S: PrecisionSettings>(
+                item: &Self::Item<S>,
+                path: &str,
+            ) -> Result<(), RecorderError> {
+                $(<$r as Record<B>>::check_int_overflow::<S>(&item.$i, &record_field_path(path, stringify!($i)))?;)*
+                Ok(())
+            }
+
+            fn check_shapes<S: PrecisionSettings>(
+                item: &Self::Item<S>,
+                path: &str,
+                shapes: &mut ShapeCheck,
+            ) -> Result<(), RecorderError> {
+                $(<$r as Record<B>>::check_shapes::<S>(&item.$i, &record_field_path(path, stringify!($i)), shapes)?;)*
+                Ok(())
+            }
+
+            fn check_finite<S: PrecisionSettings>(
+                item: &Self::Item<S>,
+                path: &str,
+            ) -> Result<(), RecorderError> {
+                $(<$r as Record<B>>::check_finite::<S>(&item.$i, &record_field_path(path, stringify!($i)))?;)*
+                Ok(())
+            }
+
+            fn offset_indices<S: PrecisionSettings>(item: &mut Self::Item<S>, path: &str, offset: i64) {
+                $(<$r as Record<B>>::offset_indices::<S>(&mut item.$i, &record_field_path(path, stringify!($i)), offset);)*
             }
         }
     };
@@ -126,23 +333,66 @@ where
     T: Record<B>,
     B: Backend,
 {
-    type Item<S: PrecisionSettings> = HashMap<String, T::Item<S>>;
+    /// The items are sorted by id, so that the same records are always serialized the same way
+    /// regardless of the iteration order of the map.
+    type Item<S: PrecisionSettings> = BTreeMap<String, T::Item<S>>;
 
     fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
-        let mut items = HashMap::with_capacity(self.len());
-        self.into_iter().for_each(|(id, record)| {
-            items.insert(id.to_string(), record.into_item());
-        });
-        items
+        self.into_iter()
+            .map(|(id, record)| (id.to_string(), record.into_item()))
+            .collect()
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
+        Self::from_item_with_path(item, device, "")
+    }
+
+    fn from_item_with_path<S: PrecisionSettings>(
+        item: Self::Item<S>,
+        device: &B::Device,
+        path: &str,
+    ) -> Self {
         let mut record = HashMap::with_capacity(item.len());
         item.into_iter().for_each(|(id, item)| {
-            record.insert(ParamId::from(id), T::from_item(item, device));
+            let value = T::from_item_with_path(item, device, &record_field_path(path, &id));
+            record.insert(ParamId::from(id), value);
         });
         record
     }
+
+    fn check_int_overflow<S: PrecisionSettings>(
+        item: &Self::Item<S>,
+        path: &str,
+    ) -> Result<(), RecorderError> {
+        item.iter().try_for_each(|(id, item)| {
+            <T as Record<B>>::check_int_overflow::<S>(item, &record_field_path(path, id))
+        })
+    }
+
+    fn check_shapes<S: PrecisionSettings>(
+        item: &Self::Item<S>,
+        path: &str,
+        shapes: &mut ShapeCheck,
+    ) -> Result<(), RecorderError> {
+        item.iter().try_for_each(|(id, item)| {
+            <T as Record<B>>::check_shapes::<S>(item, &record_field_path(path, id), shapes)
+        })
+    }
+
+    fn check_finite<S: PrecisionSettings>(
+        item: &Self::Item<S>,
+        path: &str,
+    ) -> Result<(), RecorderError> {
+        item.iter().try_for_each(|(id, item)| {
+            <T as Record<B>>::check_finite::<S>(item, &record_field_path(path, id))
+        })
+    }
+
+    fn offset_indices<S: PrecisionSettings>(item: &mut Self::Item<S>, path: &str, offset: i64) {
+        item.iter_mut().for_each(|(id, item)| {
+            <T as Record<B>>::offset_indices::<S>(item, &record_field_path(path, id), offset)
+        })
+    }
 }
 
 impl<E, B> Record<B> for DataSerialize<E>
@@ -159,6 +409,13 @@ where
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, _device: &B::Device) -> Self {
         item.convert()
     }
+
+    fn check_finite<S: PrecisionSettings>(
+        item: &Self::Item<S>,
+        path: &str,
+    ) -> Result<(), RecorderError> {
+        check_finite_values::<S>(item, path)
+    }
 }
 
 /// (De)serialize parameters into a clean format.
@@ -176,15 +433,48 @@ where
 
     fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
         let (id, tensor) = self.consume();
-        ParamSerde::new(id.into_string(), tensor.into_item())
+        let id = id.into_string();
+        record_require_grad(&id, tensor.is_require_grad());
+        ParamSerde::new(id, tensor.into_item())
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
-        Param::initialized(
-            ParamId::from(item.id),
-            Tensor::from_item(item.param, device).require_grad(), // Same behavior as when we create a new
-                                                                  // Param from a tensor.
-        )
+        Self::from_item_with_path(item, device, "")
+    }
+
+    fn from_item_with_path<S: PrecisionSettings>(
+        item: Self::Item<S>,
+        device: &B::Device,
+        path: &str,
+    ) -> Self {
+        // Same behavior as when we create a new Param from a tensor.
+        let tensor = tied_tensor(&item.id, || {
+            Tensor::from_item_with_path(item.param, device, path).require_grad()
+        });
+        let recorded_require_grad = recorded_require_grad(&item.id);
+        let tensor = match recorded_require_grad {
+            Some(require_grad) => tensor.set_require_grad(require_grad),
+            None => tensor,
+        };
+
+        let mut param = Param::initialized(ParamId::from(item.id), tensor);
+        param.recorded_require_grad = recorded_require_grad;
+        param
+    }
+
+    fn check_shapes<S: PrecisionSettings>(
+        item: &Self::Item<S>,
+        path: &str,
+        shapes: &mut ShapeCheck,
+    ) -> Result<(), RecorderError> {
+        <Tensor<B, D> as Record<B>>::check_shapes::<S>(&item.param, path, shapes)
+    }
+
+    fn check_finite<S: PrecisionSettings>(
+        item: &Self::Item<S>,
+        path: &str,
+    ) -> Result<(), RecorderError> {
+        <Tensor<B, D> as Record<B>>::check_finite::<S>(&item.param, path)
     }
 }
 
@@ -200,10 +490,38 @@ where
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
-        Param::initialized(
-            ParamId::from(item.id),
-            Tensor::from_item(item.param, device),
-        )
+        Self::from_item_with_path(item, device, "")
+    }
+
+    fn from_item_with_path<S: PrecisionSettings>(
+        item: Self::Item<S>,
+        device: &B::Device,
+        path: &str,
+    ) -> Self {
+        let tensor = tied_tensor(&item.id, || {
+            Tensor::from_item_with_path(item.param, device, path)
+        });
+
+        Param::initialized(ParamId::from(item.id), tensor)
+    }
+
+    fn check_int_overflow<S: PrecisionSettings>(
+        item: &Self::Item<S>,
+        path: &str,
+    ) -> Result<(), RecorderError> {
+        <Tensor<B, D, Int> as Record<B>>::check_int_overflow::<S>(&item.param, path)
+    }
+
+    fn check_shapes<S: PrecisionSettings>(
+        item: &Self::Item<S>,
+        path: &str,
+        shapes: &mut ShapeCheck,
+    ) -> Result<(), RecorderError> {
+        <Tensor<B, D, Int> as Record<B>>::check_shapes::<S>(&item.param, path, shapes)
+    }
+
+    fn offset_indices<S: PrecisionSettings>(item: &mut Self::Item<S>, path: &str, offset: i64) {
+        <Tensor<B, D, Int> as Record<B>>::offset_indices::<S>(&mut item.param, path, offset)
     }
 }
 
@@ -211,7 +529,7 @@ impl<B, const D: usize> Record<B> for Param<Tensor<B, D, Bool>>
 where
     B: Backend,
 {
-    type Item<S: PrecisionSettings> = ParamSerde<BoolTensorSerde>;
+    type Item<S: PrecisionSettings> = ParamSerde<BoolTensorSerde<S>>;
 
     fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
         let (id, tensor) = self.consume();
@@ -219,10 +537,27 @@ where
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
-        Param::initialized(
-            ParamId::from(item.id),
-            Tensor::from_item::<S>(item.param, device),
-        )
+        Self::from_item_with_path(item, device, "")
+    }
+
+    fn from_item_with_path<S: PrecisionSettings>(
+        item: Self::Item<S>,
+        device: &B::Device,
+        path: &str,
+    ) -> Self {
+        let tensor = tied_tensor(&item.id, || {
+            Tensor::from_item_with_path::<S>(item.param, device, path)
+        });
+
+        Param::initialized(ParamId::from(item.id), tensor)
+    }
+
+    fn check_shapes<S: PrecisionSettings>(
+        item: &Self::Item<S>,
+        path: &str,
+        shapes: &mut ShapeCheck,
+    ) -> Result<(), RecorderError> {
+        <Tensor<B, D, Bool> as Record<B>>::check_shapes::<S>(&item.param, path, shapes)
     }
 }
 
@@ -0,0 +1,235 @@
+use super::{
+    FileRecorder, PrecisionSettings, Record, Recorder, RecorderError, SelfDescribingRecorder,
+    ShapeCheck,
+};
+use burn_tensor::backend::Backend;
+use core::cell::RefCell;
+use serde::{de::DeserializeOwned, Serialize};
+use std::sync::Arc;
+
+/// Callback receiving the number of tensors processed and the total number of tensors of the
+/// record being saved or loaded.
+pub type ProgressCallback = Arc<dyn Fn(usize, usize) + Send + Sync>;
+
+/// Recorder reporting the progress of the records saved and loaded by another recorder.
+///
+/// The callback is invoked once with `(0, total)` before the first tensor is processed, then
+/// once per tensor with the number of tensors processed so far. When saving, a tensor is
+/// processed once it's serialized, and when loading, once it's moved to the device.
+///
+/// The [default](Default) recorder has no callback and behaves as the wrapped recorder, use
+/// [new](ProgressRecorder::new) instead.
+#[derive(Clone, Default)]
+pub struct ProgressRecorder<R> {
+    recorder: R,
+    callback: Option<ProgressCallback>,
+}
+
+impl<R> ProgressRecorder<R> {
+    /// Create a recorder reporting the progress of the given recorder to the callback.
+    pub fn new<F>(recorder: R, callback: F) -> Self
+    where
+        F: Fn(usize, usize) + Send + Sync + 'static,
+    {
+        Self {
+            recorder,
+            callback: Some(Arc::new(callback)),
+        }
+    }
+}
+
+impl<R: core::fmt::Debug> core::fmt::Debug for ProgressRecorder<R> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ProgressRecorder")
+            .field("recorder", &self.recorder)
+            .field("callback", &self.callback.is_some())
+            .finish()
+    }
+}
+
+impl<B: Backend, R: FileRecorder<B>> FileRecorder<B> for ProgressRecorder<R> {
+    fn file_extension() -> &'static str {
+        R::file_extension()
+    }
+}
+
+impl<B: Backend, R: Recorder<B>> Recorder<B> for ProgressRecorder<R> {
+    type Settings = R::Settings;
+    type RecordArgs = R::RecordArgs;
+    type RecordOutput = R::RecordOutput;
+    type LoadArgs = R::LoadArgs;
+    const SELF_DESCRIBING: bool = R::SELF_DESCRIBING;
+
+    fn progress(&self) -> Option<ProgressCallback> {
+        self.callback.clone()
+    }
+
+    fn save_item<I: Serialize>(
+        &self,
+        item: I,
+        args: Self::RecordArgs,
+    ) -> Result<Self::RecordOutput, RecorderError> {
+        self.recorder.save_item(item, args)
+    }
+
+    fn load_item<I: DeserializeOwned>(&self, args: Self::LoadArgs) -> Result<I, RecorderError> {
+        self.recorder.load_item(args)
+    }
+}
+
+impl<B: Backend, R: SelfDescribingRecorder<B>> SelfDescribingRecorder<B> for ProgressRecorder<R> {}
+
+/// State of the progress reported on the current thread.
+struct Progress {
+    callback: ProgressCallback,
+    current: usize,
+    total: usize,
+}
+
+std::thread_local! {
+    static PROGRESS: RefCell<Option<Progress>> = const { RefCell::new(None) };
+}
+
+/// Report the progress of the tensors processed while the scope is alive.
+pub(crate) struct ProgressScope {
+    previous: Option<Progress>,
+}
+
+impl ProgressScope {
+    /// Start reporting the progress of the given number of tensors to the callback.
+    ///
+    /// Without a callback, nothing is reported.
+    pub(crate) fn start(callback: Option<ProgressCallback>, total: usize) -> Option<Self> {
+        let callback = callback?;
+        let progress = Progress {
+            callback: callback.clone(),
+            current: 0,
+            total,
+        };
+        let previous = PROGRESS.with(|state| state.replace(Some(progress)));
+
+        callback(0, total);
+
+        Some(Self { previous })
+    }
+
+    /// Count the tensors of the item and start reporting its progress to the callback.
+    ///
+    /// Without a callback, nothing is counted nor reported.
+    pub(crate) fn start_counting<B, R, S>(
+        callback: Option<ProgressCallback>,
+        item: &R::Item<S>,
+    ) -> Result<Option<Self>, RecorderError>
+    where
+        B: Backend,
+        R: Record<B>,
+        S: PrecisionSettings,
+    {
+        match callback {
+            Some(callback) => Ok(Self::start(Some(callback), count_tensors::<B, R, S>(item)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+impl Drop for ProgressScope {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        PROGRESS.with(|state| state.replace(previous));
+    }
+}
+
+/// Count the tensors of the item, visited with [check_shapes](Record::check_shapes) without
+/// serializing their data.
+pub(crate) fn count_tensors<B, R, S>(item: &R::Item<S>) -> Result<usize, RecorderError>
+where
+    B: Backend,
+    R: Record<B>,
+    S: PrecisionSettings,
+{
+    let mut shapes = ShapeCheck::collect();
+    R::check_shapes::<S>(item, "", &mut shapes)?;
+
+    Ok(shapes.into_shapes().len())
+}
+
+/// Notify that a tensor was serialized or moved to the device.
+pub(crate) fn tensor_processed() {
+    let report = PROGRESS.with(|state| state.borrow_mut().as_mut().map(Progress::advance));
+
+    // The callback is invoked once the state is released, so that it can record other items.
+    if let Some((callback, current, total)) = report {
+        callback(current, total);
+    }
+}
+
+impl Progress {
+    fn advance(&mut self) -> (ProgressCallback, usize, usize) {
+        self.current += 1;
+
+        (self.callback.clone(), self.current, self.total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        module::Module,
+        nn::{Linear, LinearConfig, LinearRecord},
+        record::{BinBytesRecorder, FullPrecisionSettings},
+        TestBackend,
+    };
+    use std::sync::Mutex;
+
+    type Calls = Arc<Mutex<Vec<(usize, usize)>>>;
+
+    fn recorder(calls: &Calls) -> ProgressRecorder<BinBytesRecorder<FullPrecisionSettings>> {
+        let calls = calls.clone();
+
+        ProgressRecorder::new(BinBytesRecorder::new(), move |current, total| {
+            calls.lock().unwrap().push((current, total))
+        })
+    }
+
+    #[test]
+    fn test_progress_is_reported_once_per_tensor() {
+        let device = Default::default();
+        let calls = Calls::default();
+        let recorder = recorder(&calls);
+        // The weight and the bias.
+        let expected = vec![(0, 2), (1, 2), (2, 2)];
+
+        let linear: Linear<TestBackend> = LinearConfig::new(4, 2).init(&device);
+        let bytes =
+            Recorder::<TestBackend>::record(&recorder, linear.clone().into_record(), ()).unwrap();
+        assert_eq!(*calls.lock().unwrap(), expected);
+
+        calls.lock().unwrap().clear();
+        let record = Recorder::<TestBackend>::load(&recorder, bytes, &device).unwrap();
+        assert_eq!(*calls.lock().unwrap(), expected);
+
+        linear
+            .clone()
+            .load_record(record)
+            .weight
+            .to_data()
+            .assert_approx_eq(&linear.weight.to_data(), 6);
+    }
+
+    #[test]
+    fn test_progress_counts_only_the_present_tensors() {
+        let device = Default::default();
+        let calls = Calls::default();
+        let recorder = recorder(&calls);
+
+        let linear: Linear<TestBackend> = LinearConfig::new(4, 2).with_bias(false).init(&device);
+        let bytes = Recorder::<TestBackend>::record(&recorder, linear.into_record(), ()).unwrap();
+        assert_eq!(*calls.lock().unwrap(), vec![(0, 1), (1, 1)]);
+
+        calls.lock().unwrap().clear();
+        let _record: LinearRecord<TestBackend> =
+            Recorder::<TestBackend>::load(&recorder, bytes, &device).unwrap();
+        assert_eq!(*calls.lock().unwrap(), vec![(0, 1), (1, 1)]);
+    }
+}
@@ -0,0 +1,57 @@
+use alloc::vec::Vec;
+use burn_tensor::{DataSerialize, Element, ElementConversion};
+use num_traits::Float;
+use serde::{Deserialize, Serialize};
+
+/// Tensor data quantized to `i8` with a symmetric per-tensor scale.
+///
+/// The original values are approximated by `data * scale`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub(crate) struct QuantizedData {
+    pub(crate) scale: f32,
+    pub(crate) data: DataSerialize<i8>,
+}
+
+impl QuantizedData {
+    /// Quantize the tensor data, mapping its largest absolute value to `i8::MAX`.
+    pub(crate) fn quantize<E: Element>(data: &DataSerialize<E>) -> Self {
+        let max_abs = data
+            .value
+            .iter()
+            .map(|value| value.elem::<f32>().abs())
+            .fold(0.0, f32::max);
+
+        // An all-zero tensor would have a zero scale, any scale quantizes it exactly.
+        let scale = if max_abs > 0.0 && max_abs.is_finite() {
+            max_abs / i8::MAX as f32
+        } else {
+            1.0
+        };
+
+        let value = data
+            .value
+            .iter()
+            .map(|value| {
+                let max = i8::MAX as f32;
+                Float::round(value.elem::<f32>() / scale).clamp(-max, max) as i8
+            })
+            .collect::<Vec<_>>();
+
+        Self {
+            scale,
+            data: DataSerialize::new(value, data.shape.clone()),
+        }
+    }
+
+    /// Reconstruct the approximated tensor data.
+    pub(crate) fn dequantize<E: Element>(self) -> DataSerialize<E> {
+        let value = self
+            .data
+            .value
+            .into_iter()
+            .map(|value| (value as f32 * self.scale).elem::<E>())
+            .collect();
+
+        DataSerialize::new(value, self.data.shape)
+    }
+}
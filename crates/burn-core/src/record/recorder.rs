@@ -1,13 +1,30 @@
 use core::any::type_name;
 use core::marker::PhantomData;
 
+use alloc::collections::{BTreeMap, BTreeSet};
 use alloc::format;
 use alloc::string::{String, ToString};
-use burn_tensor::backend::Backend;
+use alloc::vec::Vec;
+use burn_tensor::{backend::Backend, DataSerialize, Tensor};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
-use super::{BinBytesRecorder, FullPrecisionSettings, PrecisionSettings, Record};
+use crate::module::Module;
 
+use super::checksum::verify_checksum;
+use super::in_place::assign_in_place;
+use super::require_grad::RequireGradScope;
+use super::tensor::float_tensor_from_value;
+use super::tied::{deduplicate_tied, is_tensor, resolve_tied, TiedScope};
+use super::value::{from_value, is_non_finite, to_value, RecordValue, RecordValueRef};
+use super::{
+    record_field_path, BinBytesRecorder, FullPrecisionSettings, KeyMatching, KeyMismatch,
+    PrecisionSettings, Record, RecordFiniteCheck, RecordFormat, ShapeCheck,
+};
+
+#[cfg(feature = "std")]
+use super::progress::{count_tensors, ProgressCallback, ProgressScope};
+#[cfg(feature = "std")]
+use super::staging::StagingErrorScope;
 #[cfg(feature = "std")]
 use super::{
     BinFileRecorder, BinGzFileRecorder, DefaultFileRecorder, HalfPrecisionSettings,
@@ -30,6 +47,14 @@ pub trait Recorder<B: Backend>:
     /// Arguments used to load recorded objects.
     type LoadArgs: Clone;
 
+    /// Whether the format records the field names of the items, e.g. named msgpack or json,
+    /// see [SelfDescribingRecorder].
+    ///
+    /// The settings rewriting the records, i.e. [SAVE_FILTER](PrecisionSettings::SAVE_FILTER),
+    /// [DEDUPLICATE_TIED](PrecisionSettings::DEDUPLICATE_TIED) and
+    /// [MIGRATE](PrecisionSettings::MIGRATE), produce an error with the other formats.
+    const SELF_DESCRIBING: bool = false;
+
     /// Records an item.
     ///
     /// # Arguments
@@ -48,60 +73,193 @@ pub trait Recorder<B: Backend>:
     where
         R: Record<B>,
     {
-        let item = record.into_item::<Self::Settings>();
-        let item = BurnRecord::new::<Self>(item);
+        self.record_with_tags(record, BTreeMap::new(), args)
+    }
+
+    /// Records an item with user key-value tags, e.g. the commit or the hyperparameters used to
+    /// train a model.
+    ///
+    /// The tags are written in the [metadata](BurnMetadata::tags) of the record, separately from
+    /// the item, and can be read back with [load_metadata](Recorder::load_metadata).
+    fn record_with_tags<R>(
+        &self,
+        record: R,
+        tags: BTreeMap<String, String>,
+        args: Self::RecordArgs,
+    ) -> Result<Self::RecordOutput, RecorderError>
+    where
+        R: Record<B>,
+    {
+        if Self::Settings::SAVE_FILTER.is_some() || Self::Settings::DEDUPLICATE_TIED {
+            check_self_describing::<Self, B>()?;
+        }
+
+        let require_grad =
+            Self::Settings::RECORD_REQUIRE_GRAD.then(|| RequireGradScope::start(BTreeMap::new()));
+        let mut item = record.into_item::<Self::Settings>();
+        let require_grad = require_grad.map(RequireGradScope::finish);
+        shift_indices::<B, R, Self::Settings>(&mut item, 1);
+
+        if Self::Settings::CHECK_FINITE != RecordFiniteCheck::None {
+            R::check_finite::<Self::Settings>(&item, "")?;
+        }
+
+        // The tensors are counted on the item before it's rewritten.
+        let tensors = progress_total::<B, R, Self>(self, &item)?;
+
+        if Self::Settings::SAVE_FILTER.is_some() || Self::Settings::DEDUPLICATE_TIED {
+            let item = RewrittenItem::<_, Self::Settings>::new(item);
+
+            return save_record(self, item, tags, require_grad, tensors, args);
+        }
 
-        self.save_item(item, args)
+        save_record(self, item, tags, require_grad, tensors, args)
     }
 
     /// Load an item from the given arguments.
+    ///
+    /// The whole item is deserialized first, then converted into the record with
+    /// [from_item](Record::from_item), which uploads each tensor to the device and frees its
+    /// serialized data before moving to the next one.
     fn load<R>(&self, args: Self::LoadArgs, device: &B::Device) -> Result<R, RecorderError>
     where
         R: Record<B>,
     {
-        let item: BurnRecord<R::Item<Self::Settings>, B> =
-            self.load_item(args.clone()).map_err(|err| {
-                if let Ok(record) = self.load_item::<BurnRecordNoItem>(args.clone()) {
-                    let mut message = "Unable to load record.".to_string();
-                    let metadata = recorder_metadata::<Self, B>();
-                    if metadata.float != record.metadata.float {
-                        message += format!(
-                            "\nMetadata has a different float type: Actual {:?}, Expected {:?}",
-                            record.metadata.float, metadata.float
-                        )
-                        .as_str();
-                    }
-                    if metadata.int != record.metadata.int {
-                        message += format!(
-                            "\nMetadata has a different int type: Actual {:?}, Expected {:?}",
-                            record.metadata.int, metadata.int
-                        )
-                        .as_str();
-                    }
-                    if metadata.format != record.metadata.format {
-                        message += format!(
-                            "\nMetadata has a different format: Actual {:?}, Expected {:?}",
-                            record.metadata.format, metadata.format
-                        )
-                        .as_str();
-                    }
-                    if metadata.version != record.metadata.version {
-                        message += format!(
-                            "\nMetadata has a different Burn version: Actual {:?}, Expected {:?}",
-                            record.metadata.version, metadata.version
-                        )
-                        .as_str();
-                    }
-
-                    message += format!("\nError: {:?}", err).as_str();
-
-                    return RecorderError::Unknown(message);
+        self.decode::<R>(args)?.into_record(device)
+    }
+
+    /// Load and deserialize an item from the given arguments, without converting it into the
+    /// record.
+    ///
+    /// This is the first half of [load](Recorder::load): the item only lives in host memory, and
+    /// nothing is allocated on a device until the [decoded record](DecodedRecord) is converted.
+    /// Both halves can run separately, e.g. the conversion can be skipped when the load is
+    /// cancelled in between.
+    fn decode<R>(
+        &self,
+        args: Self::LoadArgs,
+    ) -> Result<DecodedRecord<B, R, Self::Settings>, RecorderError>
+    where
+        R: Record<B>,
+    {
+        if Self::Settings::LOAD_FILTER.is_some() {
+            return Err(RecorderError::Unknown(
+                "The settings filter the loaded fields, use load_partial to keep the value of \
+                the excluded fields"
+                    .to_string(),
+            ));
+        }
+
+        // The record is rewritten before being converted into the item.
+        if Self::Settings::DEDUPLICATE_TIED || Self::Settings::MIGRATE.is_some() {
+            check_self_describing::<Self, B>()?;
+
+            return decode_value(self, args);
+        }
+
+        let item: Result<BurnRecord<R::Item<Self::Settings>, B>, RecorderError> =
+            self.load_item(args.clone());
+
+        let mut item = item.map_err(|err| {
+            if let Ok(record) = self.load_item::<BurnRecordNoItem>(args.clone()) {
+                if let Err(err) = check_record_version(record.metadata.record_version) {
+                    return err;
+                }
+
+                let mut message = "Unable to load record.".to_string();
+                let metadata = recorder_metadata::<Self, B>();
+                if metadata.float != record.metadata.float {
+                    message += format!(
+                        "\nMetadata has a different float type: Actual {:?}, Expected {:?}",
+                        record.metadata.float, metadata.float
+                    )
+                    .as_str();
+                }
+                if metadata.int != record.metadata.int {
+                    message += format!(
+                        "\nMetadata has a different int type: Actual {:?}, Expected {:?}",
+                        record.metadata.int, metadata.int
+                    )
+                    .as_str();
+                }
+                if metadata.format != record.metadata.format {
+                    message += format!(
+                        "\nMetadata has a different format: Actual {:?}, Expected {:?}",
+                        record.metadata.format, metadata.format
+                    )
+                    .as_str();
+                }
+                if metadata.version != record.metadata.version {
+                    message += format!(
+                        "\nMetadata has a different Burn version: Actual {:?}, Expected {:?}",
+                        record.metadata.version, metadata.version
+                    )
+                    .as_str();
                 }
 
-                err
-            })?;
+                message += format!("\nError: {:?}", err).as_str();
+
+                return RecorderError::Unknown(message);
+            }
+
+            err
+        })?;
+        check_record_version(item.metadata.record_version)?;
+
+        shift_indices::<B, R, Self::Settings>(&mut item.item, -1);
 
-        Ok(R::from_item(item.item, device))
+        if Self::Settings::CHECK_INT_OVERFLOW {
+            R::check_int_overflow::<Self::Settings>(&item.item, "")?;
+        }
+        R::check_shapes::<Self::Settings>(&item.item, "", &mut ShapeCheck::ignore())?;
+
+        Ok(DecodedRecord::new(self, item, None))
+    }
+
+    /// Load the metadata of the record from the given arguments, including its
+    /// [tags](BurnMetadata::tags), without converting the item.
+    fn load_metadata(&self, args: Self::LoadArgs) -> Result<BurnMetadata, RecorderError> {
+        let record: BurnRecordNoItem = self.load_item(args)?;
+
+        Ok(record.metadata)
+    }
+
+    /// Load a record into the given module, writing the loaded values into its tensors instead
+    /// of replacing them, e.g. to hot-swap the weights of a model served with low latency.
+    ///
+    /// The record is loaded with [load](Recorder::load), then each tensor of the module with the
+    /// same shape as the loaded one is overwritten with a full
+    /// [slice assign](Tensor::slice_assign), which backends reusing the buffers of uniquely owned
+    /// tensors, e.g. NdArray, perform in place. A tensor with a different shape is replaced by the
+    /// loaded one. Unlike [load_record](Module::load_record), the parameters keep their id, and
+    /// the module is left unchanged when the record can't be loaded.
+    fn load_into<M>(
+        &self,
+        args: Self::LoadArgs,
+        module: &mut M,
+        device: &B::Device,
+    ) -> Result<(), RecorderError>
+    where
+        M: Module<B>,
+    {
+        let record = self.load::<M::Record>(args, device)?;
+
+        // The clone of the module releases its tensors when loading the record, so that the
+        // tensors of the module are uniquely owned while they are assigned.
+        let loaded = module.clone().load_record(record);
+        let current = core::mem::replace(module, loaded);
+        *module = assign_in_place(current, module);
+
+        Ok(())
+    }
+
+    /// Callback reporting the progress of the tensors saved by [record](Recorder::record) and
+    /// loaded by [load](Recorder::load), see [ProgressRecorder](super::ProgressRecorder).
+    ///
+    /// By default, there is no callback and the tensors aren't counted.
+    #[cfg(feature = "std")]
+    fn progress(&self) -> Option<ProgressCallback> {
+        None
     }
 
     /// Saves an item.
@@ -138,6 +296,441 @@ pub trait Recorder<B: Backend>:
         I: DeserializeOwned;
 }
 
+/// [Recorder] of a self-describing format, e.g. named msgpack or json, which records the field
+/// names of the items, so that a record can be loaded as a value before being converted.
+///
+/// It's implemented by the recorders whose [SELF_DESCRIBING](Recorder::SELF_DESCRIBING) is
+/// `true`, and loads part of a record or matches its paths against the fields of the record
+/// loading it.
+pub trait SelfDescribingRecorder<B: Backend>: Recorder<B> {
+    /// Load an item from the given arguments, filling the missing fields from the given record.
+    ///
+    /// This is useful to load a checkpoint containing only part of a module, e.g. a pretrained
+    /// backbone without its head, where `record` comes from a freshly initialized module.
+    ///
+    /// A tensor present in the loaded record with a shape different from the one in `record`
+    /// produces an error instead of being skipped.
+    fn load_partial<R>(
+        &self,
+        args: Self::LoadArgs,
+        record: R,
+        device: &B::Device,
+    ) -> Result<R, RecorderError>
+    where
+        R: Record<B>,
+    {
+        let RecordValue(mut loaded) = self.load_item(args)?;
+        let record_version = loaded_record_version(&loaded);
+        check_record_version(record_version)?;
+        migrate_tensors::<Self::Settings>(&mut loaded, record_version);
+        let tied = resolve_tied(&mut loaded)?;
+
+        if let (Some(filter), Some(item)) = (Self::Settings::LOAD_FILTER, loaded.get_mut("item")) {
+            filter_fields(item, "", filter);
+        }
+
+        // The default item is shifted like a recorded one, so that the merged item is shifted
+        // back as a whole.
+        let mut default = record.into_item::<Self::Settings>();
+        let mut shapes = ShapeCheck::collect();
+        R::check_shapes::<Self::Settings>(&default, "", &mut shapes)?;
+        shift_indices::<B, R, Self::Settings>(&mut default, 1);
+        let default = to_value(&BurnRecord::<_, B>::new::<Self>(default))?;
+
+        // The shapes are compared on the merged item, whatever the serialized form of its
+        // tensors.
+        let merged = merge_partial(default, loaded);
+        let mut item: BurnRecord<R::Item<Self::Settings>, B> = from_value(&merged)?;
+        shift_indices::<B, R, Self::Settings>(&mut item.item, -1);
+        let mut shapes = ShapeCheck::expect(shapes.into_shapes());
+        R::check_shapes::<Self::Settings>(&item.item, "", &mut shapes)?;
+
+        #[cfg(feature = "std")]
+        let _progress =
+            ProgressScope::start_counting::<B, R, Self::Settings>(self.progress(), &item.item)?;
+        let _tied = TiedScope::start(tied);
+        #[cfg(feature = "std")]
+        let staging = StagingErrorScope::start();
+        let record = R::from_item(item.item, device);
+        #[cfg(feature = "std")]
+        staging.finish()?;
+
+        Ok(record)
+    }
+
+    /// Load an item from the given arguments, matching its paths against the fields of the given
+    /// record with the given [key matching](KeyMatching).
+    ///
+    /// The loaded paths can be renamed with a [remap](KeyMatching::with_remap) function, e.g.
+    /// when a layer is renamed between versions. In [strict](KeyMatching::strict) mode, any
+    /// mismatch produces a [KeyMismatch](RecorderError::KeyMismatch) error listing all of them.
+    /// In [lenient](KeyMatching::lenient) mode, the fields without a loaded value keep their
+    /// value from `record` and the mismatched paths are returned with the loaded record.
+    fn load_with_keys<R>(
+        &self,
+        args: Self::LoadArgs,
+        record: R,
+        keys: &KeyMatching,
+        device: &B::Device,
+    ) -> Result<(R, KeyMismatch), RecorderError>
+    where
+        R: Record<B>,
+    {
+        let RecordValue(mut loaded) = self.load_item(args)?;
+        let record_version = loaded_record_version(&loaded);
+        check_record_version(record_version)?;
+        migrate_tensors::<Self::Settings>(&mut loaded, record_version);
+        let tied = resolve_tied(&mut loaded)?;
+
+        let mut loaded = loaded
+            .get_mut("item")
+            .map(serde_json::Value::take)
+            .ok_or_else(|| RecorderError::DeserializeError("Missing record item".to_string()))?;
+
+        if let Some(filter) = Self::Settings::LOAD_FILTER {
+            filter_fields(&mut loaded, "", filter);
+        }
+
+        let mut default = record.into_item::<Self::Settings>();
+        let mut shapes = ShapeCheck::collect();
+        R::check_shapes::<Self::Settings>(&default, "", &mut shapes)?;
+        shift_indices::<B, R, Self::Settings>(&mut default, 1);
+        let mut default = to_value(&BurnRecord::<_, B>::new::<Self>(default))?;
+
+        let item = default
+            .get_mut("item")
+            .ok_or_else(|| RecorderError::Unknown("Missing record item".to_string()))?;
+        let (matched, mismatch) = keys.apply(item.take(), loaded)?;
+        *item = matched;
+
+        let mut item: BurnRecord<R::Item<Self::Settings>, B> = from_value(&default)?;
+        shift_indices::<B, R, Self::Settings>(&mut item.item, -1);
+        let mut shapes = ShapeCheck::expect(shapes.into_shapes());
+        R::check_shapes::<Self::Settings>(&item.item, "", &mut shapes)?;
+
+        #[cfg(feature = "std")]
+        let _progress =
+            ProgressScope::start_counting::<B, R, Self::Settings>(self.progress(), &item.item)?;
+        let _tied = TiedScope::start(tied);
+        #[cfg(feature = "std")]
+        let staging = StagingErrorScope::start();
+        let record = R::from_item(item.item, device);
+        #[cfg(feature = "std")]
+        staging.finish()?;
+
+        Ok((record, mismatch))
+    }
+
+    /// Load the float tensor located at the given path of the record, e.g. `embeddings.weight`,
+    /// without converting the rest of the record.
+    ///
+    /// Since the formats don't index their tensors, the whole item is still read, but only the
+    /// selected tensor is converted and moved to the device. A path that doesn't exist produces a
+    /// [TensorNotFound](RecorderError::TensorNotFound) error, and a tensor with a rank other
+    /// than `D` produces an error instead of a misshapen tensor.
+    fn load_tensor<const D: usize>(
+        &self,
+        args: Self::LoadArgs,
+        path: &str,
+        device: &B::Device,
+    ) -> Result<Tensor<B, D>, RecorderError> {
+        let loaded: serde_json::Value = self.load_item(args)?;
+        let (mut value, record_version) = select_field(loaded, path)?;
+
+        migrate_tensors::<Self::Settings>(&mut value, record_version);
+
+        float_tensor_from_value::<B, D, Self::Settings>(value, device, path)
+    }
+}
+
+/// Version of the record format written by the recorders.
+///
+/// It is increased when the serialized representation of the items changes. Records written
+/// with an older version are loaded as is, or migrated by the
+/// [MIGRATE](PrecisionSettings::MIGRATE) function of the settings, while records written with a
+/// newer version can't be loaded.
+pub const RECORD_VERSION: u32 = 1;
+
+fn check_record_version(record_version: u32) -> Result<(), RecorderError> {
+    if record_version > RECORD_VERSION {
+        return Err(RecorderError::UnsupportedRecordVersion {
+            found: record_version,
+            supported: RECORD_VERSION,
+        });
+    }
+
+    Ok(())
+}
+
+/// The record version of a loaded record, `0` for the records written before it was introduced.
+fn loaded_record_version(loaded: &serde_json::Value) -> u32 {
+    loaded
+        .pointer("/metadata/record_version")
+        .and_then(serde_json::Value::as_u64)
+        .unwrap_or(0) as u32
+}
+
+/// Load an item that is rewritten before its conversion: recorded with an older record version
+/// [migrated](PrecisionSettings::MIGRATE) by the settings, or recorded with
+/// [deduplicated](PrecisionSettings::DEDUPLICATE_TIED) tied parameters, whose references are
+/// replaced with the parameter they are tied to.
+///
+/// The record is read once, as a [value](RecordValue) keeping its non-finite floats.
+fn decode_value<R, B, Rec>(
+    recorder: &R,
+    args: R::LoadArgs,
+) -> Result<DecodedRecord<B, Rec, R::Settings>, RecorderError>
+where
+    R: Recorder<B>,
+    B: Backend,
+    Rec: Record<B>,
+{
+    let RecordValue(mut loaded) = recorder.load_item(args)?;
+    let record_version = loaded_record_version(&loaded);
+    check_record_version(record_version)?;
+    migrate_tensors::<R::Settings>(&mut loaded, record_version);
+    let tied = resolve_tied(&mut loaded)?;
+
+    let mut item: BurnRecord<Rec::Item<R::Settings>, B> = from_value(&loaded)?;
+
+    shift_indices::<B, Rec, R::Settings>(&mut item.item, -1);
+
+    if R::Settings::CHECK_INT_OVERFLOW {
+        Rec::check_int_overflow::<R::Settings>(&item.item, "")?;
+    }
+    Rec::check_shapes::<R::Settings>(&item.item, "", &mut ShapeCheck::ignore())?;
+
+    Ok(DecodedRecord::new(recorder, item, Some(tied)))
+}
+
+/// The number of tensors of the item whose progress is reported by the recorder, or zero when
+/// it reports no progress.
+fn progress_total<B, R, Rec>(
+    recorder: &Rec,
+    item: &R::Item<Rec::Settings>,
+) -> Result<usize, RecorderError>
+where
+    B: Backend,
+    R: Record<B>,
+    Rec: Recorder<B>,
+{
+    #[cfg(feature = "std")]
+    if recorder.progress().is_some() {
+        return count_tensors::<B, R, Rec::Settings>(item);
+    }
+
+    #[cfg(not(feature = "std"))]
+    let _ = (recorder, item);
+
+    Ok(0)
+}
+
+/// Save the item with the metadata of the recorder, the given tags and the recorded gradient
+/// requirements, reporting the progress of its given number of tensors.
+fn save_record<R, B, I>(
+    recorder: &R,
+    item: I,
+    tags: BTreeMap<String, String>,
+    require_grad: Option<BTreeMap<String, bool>>,
+    tensors: usize,
+    args: R::RecordArgs,
+) -> Result<R::RecordOutput, RecorderError>
+where
+    R: Recorder<B>,
+    B: Backend,
+    I: Serialize,
+{
+    let mut item = BurnRecord::new::<R>(item);
+    item.metadata.tags = tags;
+    item.metadata.require_grad = require_grad;
+
+    #[cfg(feature = "std")]
+    let _progress = ProgressScope::start(recorder.progress(), tensors);
+    #[cfg(not(feature = "std"))]
+    let _ = tensors;
+
+    recorder.save_item(item, args)
+}
+
+/// Item rewritten when it's serialized: the fields excluded by the
+/// [SAVE_FILTER](PrecisionSettings::SAVE_FILTER) of the settings are left out, and the
+/// [tied](PrecisionSettings::DEDUPLICATE_TIED) parameters are replaced with a reference.
+///
+/// The item is rewritten as a [value](RecordValue), which keeps its non-finite floats.
+#[derive(new)]
+struct RewrittenItem<I, S> {
+    item: I,
+    _settings: PhantomData<S>,
+}
+
+impl<I: Serialize, S: PrecisionSettings> Serialize for RewrittenItem<I, S> {
+    fn serialize<Se: serde::Serializer>(&self, serializer: Se) -> Result<Se::Ok, Se::Error> {
+        let mut value = to_value(&self.item).map_err(serde::ser::Error::custom)?;
+
+        if let Some(filter) = S::SAVE_FILTER {
+            filter_fields(&mut value, "", filter);
+        }
+        if S::DEDUPLICATE_TIED {
+            deduplicate_tied(&mut value);
+        }
+
+        RecordValueRef(&value).serialize(serializer)
+    }
+}
+
+/// Shift the index tensors of the item by the [index offset](PrecisionSettings::INDEX_OFFSET)
+/// of the settings in the given direction, `1` when recording and `-1` when loading.
+fn shift_indices<B, R, S>(item: &mut R::Item<S>, direction: i64)
+where
+    B: Backend,
+    R: Record<B>,
+    S: PrecisionSettings,
+{
+    if S::INDEX_FIELDS.is_some() && S::INDEX_OFFSET != 0 {
+        R::offset_indices::<S>(item, "", direction * S::INDEX_OFFSET);
+    }
+}
+
+/// Item [decoded](Recorder::decode) by a recorder, not yet converted into its record.
+///
+/// The item is held in host memory, so dropping it without calling
+/// [into_record](DecodedRecord::into_record) leaves nothing allocated on a device.
+pub struct DecodedRecord<B: Backend, R: Record<B>, S: PrecisionSettings> {
+    item: R::Item<S>,
+    tied: Option<BTreeSet<String>>,
+    require_grad: Option<BTreeMap<String, bool>>,
+    #[cfg(feature = "std")]
+    progress: Option<ProgressCallback>,
+    _backend: PhantomData<B>,
+}
+
+impl<B: Backend, R: Record<B>, S: PrecisionSettings> DecodedRecord<B, R, S> {
+    fn new<Rec: Recorder<B, Settings = S>>(
+        recorder: &Rec,
+        record: BurnRecord<R::Item<S>, B>,
+        tied: Option<BTreeSet<String>>,
+    ) -> Self {
+        #[cfg(not(feature = "std"))]
+        let _ = recorder;
+
+        Self {
+            item: record.item,
+            tied,
+            require_grad: record
+                .metadata
+                .require_grad
+                .filter(|_| S::RECORD_REQUIRE_GRAD),
+            #[cfg(feature = "std")]
+            progress: recorder.progress(),
+            _backend: PhantomData,
+        }
+    }
+
+    /// Convert the item into the record, uploading its tensors to the given device.
+    pub fn into_record(self, device: &B::Device) -> Result<R, RecorderError> {
+        #[cfg(feature = "std")]
+        let _progress = ProgressScope::start_counting::<B, R, S>(self.progress, &self.item)?;
+        let _tied = self.tied.map(TiedScope::start);
+        let _require_grad = self.require_grad.map(RequireGradScope::start);
+        // Staged tensors are read back while they are converted.
+        #[cfg(feature = "std")]
+        let staging = StagingErrorScope::start();
+        let record = R::from_item(self.item, device);
+        #[cfg(feature = "std")]
+        staging.finish()?;
+
+        Ok(record)
+    }
+}
+
+/// Select the field at the given path of a loaded record, unwrapping the parameter holding it,
+/// and return it with the version of the record.
+pub(crate) fn select_field(
+    mut loaded: serde_json::Value,
+    path: &str,
+) -> Result<(serde_json::Value, u32), RecorderError> {
+    let record_version = loaded_record_version(&loaded);
+    check_record_version(record_version)?;
+    resolve_tied(&mut loaded)?;
+
+    let mut value = loaded
+        .get_mut("item")
+        .map(serde_json::Value::take)
+        .ok_or_else(|| RecorderError::DeserializeError("Missing record item".to_string()))?;
+
+    for field in path.split('.').filter(|field| !field.is_empty()) {
+        value = match value {
+            serde_json::Value::Object(mut map) => map.remove(field),
+            serde_json::Value::Array(mut values) => field
+                .parse::<usize>()
+                .ok()
+                .filter(|index| *index < values.len())
+                .map(|index| values.swap_remove(index)),
+            _ => None,
+        }
+        .ok_or_else(|| RecorderError::TensorNotFound(path.to_string()))?;
+    }
+
+    // Parameters wrap their tensor with their id.
+    if let serde_json::Value::Object(map) = &mut value {
+        if map.contains_key("id") {
+            if let Some(param) = map.remove("param") {
+                value = param;
+            }
+        }
+    }
+
+    Ok((value, record_version))
+}
+
+/// Apply the [migration](PrecisionSettings::MIGRATE) chain of the settings from the given record
+/// version to every serialized tensor.
+pub(crate) fn migrate_tensors<S: PrecisionSettings>(
+    value: &mut serde_json::Value,
+    record_version: u32,
+) {
+    use serde_json::Value;
+
+    let migrate = match S::MIGRATE {
+        Some(migrate) if record_version < RECORD_VERSION => migrate,
+        _ => return,
+    };
+
+    match value {
+        Value::Object(map) if map.contains_key("value") && map.contains_key("shape") => {
+            if let Ok(mut data) =
+                serde_json::from_value::<DataSerialize<Value>>(Value::Object(core::mem::take(map)))
+            {
+                for version in record_version..RECORD_VERSION {
+                    data = migrate(version, data);
+                }
+                *value = serde_json::to_value(data).unwrap_or(Value::Null);
+            }
+        }
+        Value::Object(map) => map
+            .values_mut()
+            .for_each(|value| migrate_tensors::<S>(value, record_version)),
+        Value::Array(values) => values
+            .iter_mut()
+            .for_each(|value| migrate_tensors::<S>(value, record_version)),
+        _ => {}
+    }
+}
+
+/// Check that the recorder is [self-describing](Recorder::SELF_DESCRIBING), as needed by the
+/// settings rewriting the records.
+fn check_self_describing<R: Recorder<B>, B: Backend>() -> Result<(), RecorderError> {
+    if !R::SELF_DESCRIBING {
+        return Err(RecorderError::Unknown(format!(
+            "The settings rewrite the records, which needs a self-describing format, e.g. named \
+            msgpack or json, not {}",
+            type_name::<R>()
+        )));
+    }
+
+    Ok(())
+}
+
 fn recorder_metadata<R, B>() -> BurnMetadata
 where
     R: Recorder<B>,
@@ -149,9 +742,78 @@ where
         type_name::<R>().to_string(),
         env!("CARGO_PKG_VERSION").to_string(),
         format!("{:?}", R::Settings::default()),
+        RECORD_VERSION,
+        R::Settings::USER_VERSION.map(ToString::to_string),
+        BTreeMap::new(),
+        None,
     )
 }
 
+/// Remove the fields of the serialized item located at the given path whose path is rejected
+/// by the filter.
+///
+/// The serialized tensors aren't walked into, and parameters are filtered with the path of their
+/// tensor. Array elements are never removed, only their fields, so the indices stay the same.
+fn filter_fields(value: &mut serde_json::Value, path: &str, filter: fn(&str) -> bool) {
+    use serde_json::Value;
+
+    match value {
+        Value::Object(map) if is_tensor(map) => {}
+        // Parameters wrap their tensor with their id.
+        Value::Object(map) if map.contains_key("id") && map.contains_key("param") => {}
+        Value::Object(map) if is_non_finite(map) => {}
+        Value::Object(map) => map.retain(|key, value| {
+            let path = record_field_path(path, key);
+            let keep = filter(&path);
+
+            if keep {
+                filter_fields(value, &path, filter);
+            }
+
+            keep
+        }),
+        Value::Array(values) => values.iter_mut().enumerate().for_each(|(index, value)| {
+            filter_fields(value, &record_field_path(path, &index.to_string()), filter)
+        }),
+        _ => {}
+    }
+}
+
+/// Merge a loaded item over a default one, keeping the default values for the missing fields.
+///
+/// Tensors are replaced as a whole, whatever their serialized form.
+fn merge_partial(default: serde_json::Value, loaded: serde_json::Value) -> serde_json::Value {
+    use serde_json::Value;
+
+    match (default, loaded) {
+        (Value::Object(default), Value::Object(loaded))
+            if is_tensor(&default) || is_tensor(&loaded) =>
+        {
+            Value::Object(loaded)
+        }
+        (Value::Object(mut default), Value::Object(mut loaded)) => {
+            for (key, value) in default.iter_mut() {
+                if let Some(loaded) = loaded.remove(key) {
+                    *value = merge_partial(value.take(), loaded);
+                }
+            }
+
+            Value::Object(default)
+        }
+        (Value::Array(mut default), Value::Array(loaded)) => {
+            for (index, loaded) in loaded.into_iter().enumerate() {
+                match default.get_mut(index) {
+                    Some(value) => *value = merge_partial(value.take(), loaded),
+                    None => default.push(loaded),
+                }
+            }
+
+            Value::Array(default)
+        }
+        (_, loaded) => loaded,
+    }
+}
+
 /// Error that can occur when using a [Recorder](Recorder).
 #[derive(Debug)]
 pub enum RecorderError {
@@ -161,6 +823,48 @@ pub enum RecorderError {
     /// Failed to read file.
     DeserializeError(String),
 
+    /// The checksum of the loaded item doesn't match the one stored in the record.
+    ChecksumMismatch(String),
+
+    /// A loaded integer value can't be represented by the integer element of the backend.
+    IntegerOverflow(String),
+
+    /// A float tensor to save has values rejected by the
+    /// [finite check](PrecisionSettings::CHECK_FINITE) of the settings.
+    NonFiniteValues {
+        /// Path of the tensor in the record, e.g. `layers.0.weight`.
+        path: String,
+    },
+
+    /// No tensor exists at the given path of the record.
+    TensorNotFound(String),
+
+    /// A tensor already exists at the given path of the record.
+    DuplicateTensor(String),
+
+    /// The record couldn't be decrypted, e.g. because the key is wrong.
+    DecryptionFailed(String),
+
+    /// The record was saved in another format than the one of the recorder loading it.
+    FormatMismatch {
+        /// Format of the recorder.
+        expected: RecordFormat,
+        /// Format found in the header of the record.
+        found: RecordFormat,
+    },
+
+    /// The paths of the loaded record don't match the fields of the record loading it.
+    KeyMismatch(KeyMismatch),
+
+    /// The record was written with a newer [record version](RECORD_VERSION) than the one
+    /// supported.
+    UnsupportedRecordVersion {
+        /// Record version found in the metadata of the record.
+        found: u32,
+        /// Latest record version supported.
+        supported: u32,
+    },
+
     /// Other error.
     Unknown(String),
 }
@@ -196,6 +900,111 @@ pub struct BurnMetadata {
 
     /// Settings used to record the item.
     pub settings: String,
+
+    /// Record format version used to record the item.
+    ///
+    /// Records written before the version was introduced have version 0.
+    #[serde(default)]
+    pub record_version: u32,
+
+    /// Version of the recorded item provided by the [settings](PrecisionSettings::USER_VERSION).
+    #[serde(default)]
+    pub user_version: Option<String>,
+
+    /// User key-value tags given to [record_with_tags](Recorder::record_with_tags).
+    #[serde(default)]
+    pub tags: BTreeMap<String, String>,
+
+    /// Gradient requirement of each float parameter by [id](crate::module::ParamId), when
+    /// [enabled](PrecisionSettings::RECORD_REQUIRE_GRAD) by the settings.
+    #[serde(default)]
+    pub require_grad: Option<BTreeMap<String, bool>>,
+}
+
+/// Metadata of the records saved before the [record version](BurnMetadata::record_version) was
+/// introduced, which only had its first fields.
+#[derive(Deserialize)]
+pub(crate) struct LegacyMetadata {
+    float: String,
+    int: String,
+    format: String,
+    version: String,
+    settings: String,
+}
+
+impl LegacyMetadata {
+    /// The [bincode](bincode) encoding of the metadata in the current layout, with the record
+    /// version 0 of the records saved before it, `None` when it isn't the metadata of a record.
+    pub(crate) fn upgrade(self) -> Option<Vec<u8>> {
+        // The format is the type name of the recorder.
+        if !self.format.contains("Recorder") {
+            return None;
+        }
+
+        let metadata = BurnMetadata::new(
+            self.float,
+            self.int,
+            self.format,
+            self.version,
+            self.settings,
+            0,
+            None,
+            BTreeMap::new(),
+            None,
+        );
+
+        bincode::serde::encode_to_vec(metadata, bin_config()).ok()
+    }
+}
+
+/// Decode a record encoded with [bincode](bincode) after the header of the given format, whose
+/// [checksum](PrecisionSettings::CHECKSUM) is verified, or without header when it was saved
+/// before the headers were introduced.
+pub(crate) fn decode_bin_record<S: PrecisionSettings, I: DeserializeOwned>(
+    format: RecordFormat,
+    bytes: &[u8],
+) -> Result<I, RecorderError> {
+    match format.check_header(bytes)? {
+        0 => decode_headerless_bin_record(bytes),
+        offset => decode_bin(verify_checksum::<S>(&bytes[offset..])?),
+    }
+}
+
+/// Decode a record encoded with [bincode](bincode) without header.
+///
+/// Bincode isn't self-describing, so the fields added to [BurnMetadata] since the records
+/// without header were saved would be read from their item: their metadata is upgraded to the
+/// current layout first. Since a record in the other layout can still be decoded into garbage,
+/// the layout whose decoding consumes all the bytes is preferred.
+pub(crate) fn decode_headerless_bin_record<I: DeserializeOwned>(
+    bytes: &[u8],
+) -> Result<I, RecorderError> {
+    let upgraded = bincode::serde::decode_from_slice::<LegacyMetadata, _>(bytes, bin_config())
+        .ok()
+        .and_then(|(legacy, size)| {
+            let mut upgraded = legacy.upgrade()?;
+            upgraded.extend_from_slice(&bytes[size..]);
+
+            Some(upgraded)
+        });
+
+    let candidates = [Some(bytes.to_vec()), upgraded];
+    let decoded = candidates.iter().flatten().find_map(|bytes| {
+        bincode::serde::decode_from_slice::<I, _>(bytes, bin_config())
+            .ok()
+            .filter(|(_, size)| *size == bytes.len())
+    });
+
+    match decoded {
+        Some((item, _)) => Ok(item),
+        None => decode_bin(bytes),
+    }
+}
+
+fn decode_bin<I: DeserializeOwned>(bytes: &[u8]) -> Result<I, RecorderError> {
+    bincode::serde::decode_from_slice(bytes, bin_config())
+        .map(|(item, _)| item)
+        .map_err(|err| RecorderError::Unknown(err.to_string()))
 }
 
 /// Record that can be saved by a [Recorder](Recorder).
@@ -282,7 +1091,927 @@ mod tests {
     use crate::TestBackend;
 
     use super::*;
-    use burn_tensor::{Device, ElementConversion};
+    use crate as burn;
+    use crate::module::{Module, Param};
+    use crate::nn::{BatchNormConfig, BatchNormRecord, Linear, LinearConfig};
+    use crate::record::checksum::CHECKSUM_TRAILER_SIZE;
+    use crate::record::{NamedMpkBytesRecorder, NamedMpkGzFileRecorder, RecordCompression};
+    use crate::TestAutodiffBackend;
+    use burn_tensor::{Data, Device, Distribution, ElementConversion, Int, Tensor};
+
+    #[derive(Module, Debug)]
+    struct Backbone<B: Backend> {
+        linear1: Linear<B>,
+    }
+
+    #[derive(Module, Debug)]
+    struct Model<B: Backend> {
+        linear1: Linear<B>,
+        linear2: Linear<B>,
+    }
+
+    #[derive(Module, Debug)]
+    struct OptionalHeadModel<B: Backend> {
+        linear1: Linear<B>,
+        head: Option<Linear<B>>,
+    }
+
+    #[test]
+    fn load_partial_fills_missing_fields() {
+        let device: Device<TestBackend> = Default::default();
+        let recorder = NamedMpkBytesRecorder::<FullPrecisionSettings>::new();
+        let backbone = Backbone::<TestBackend> {
+            linear1: LinearConfig::new(4, 4).init(&device),
+        };
+        let model = Model::<TestBackend> {
+            linear1: LinearConfig::new(4, 4).init(&device),
+            linear2: LinearConfig::new(4, 2).init(&device),
+        };
+        let bytes = recorder.record(backbone.clone().into_record(), ()).unwrap();
+
+        let record = recorder
+            .load_partial(bytes, model.clone().into_record(), &device)
+            .unwrap();
+        let loaded = model.clone().load_record(record);
+
+        loaded
+            .linear1
+            .weight
+            .to_data()
+            .assert_approx_eq(&backbone.linear1.weight.to_data(), 6);
+        loaded
+            .linear2
+            .weight
+            .to_data()
+            .assert_approx_eq(&model.linear2.weight.to_data(), 6);
+    }
+
+    #[test]
+    fn optional_fields_round_trip() {
+        let device: Device<TestBackend> = Default::default();
+        let recorder = BinFileRecorder::<FullPrecisionSettings>::new();
+        let path = "/tmp/burn_test_record_optional";
+
+        for head in [Some(LinearConfig::new(4, 2).init(&device)), None] {
+            let model = OptionalHeadModel::<TestBackend> {
+                linear1: LinearConfig::new(4, 4).init(&device),
+                head,
+            };
+
+            recorder
+                .record(model.clone().into_record(), path.into())
+                .unwrap();
+            let record: OptionalHeadModelRecord<TestBackend> =
+                recorder.load(path.into(), &device).unwrap();
+
+            match (record.head, model.head) {
+                (Some(loaded), Some(head)) => {
+                    assert_eq!(loaded.weight.to_data(), head.weight.to_data())
+                }
+                (None, None) => {}
+                (loaded, _) => panic!("Expected the head to round trip, got {}", loaded.is_some()),
+            }
+        }
+    }
+
+    #[test]
+    fn err_when_optional_tensor_loaded_with_another_rank() {
+        let device: Device<TestBackend> = Default::default();
+        let recorder = NamedMpkBytesRecorder::<FullPrecisionSettings>::new();
+        let tensor = Some(Tensor::<TestBackend, 2>::zeros([2, 3], &device));
+        let bytes = recorder.record(tensor, ()).unwrap();
+
+        let result = recorder.load::<Option<Tensor<TestBackend, 1>>>(bytes, &device);
+
+        assert!(matches!(
+            result,
+            Err(RecorderError::DeserializeError(message))
+                if message == "Expected a tensor of rank 1 at path <root>, got shape [2, 3]"
+        ));
+    }
+
+    #[test]
+    fn record_with_tags_writes_them_in_the_metadata() {
+        let device: Device<TestBackend> = Default::default();
+        let path = "/tmp/burn_test_record_tags";
+        let recorder = BinFileRecorder::<FullPrecisionSettings>::new();
+        let model = Model::<TestBackend> {
+            linear1: LinearConfig::new(4, 4).init(&device),
+            linear2: LinearConfig::new(4, 2).init(&device),
+        };
+        let tags = BTreeMap::from([
+            ("commit".to_string(), "1a2b3c".to_string()),
+            ("lr".to_string(), "0.001".to_string()),
+        ]);
+
+        recorder
+            .record_with_tags(model.clone().into_record(), tags.clone(), path.into())
+            .unwrap();
+
+        let metadata = Recorder::<TestBackend>::load_metadata(&recorder, path.into()).unwrap();
+        assert_eq!(metadata.tags, tags);
+
+        let record = recorder.load(path.into(), &device).unwrap();
+        let loaded = model.clone().load_record(record);
+        loaded
+            .linear1
+            .weight
+            .to_data()
+            .assert_approx_eq(&model.linear1.weight.to_data(), 6);
+    }
+
+    #[derive(Debug, Default, Clone)]
+    struct NanCheckSettings;
+
+    impl PrecisionSettings for NanCheckSettings {
+        type FloatElem = f32;
+        type IntElem = i32;
+        const CHECK_FINITE: RecordFiniteCheck = RecordFiniteCheck::Nan;
+    }
+
+    #[derive(Debug, Default, Clone)]
+    struct NanOrInfCheckSettings;
+
+    impl PrecisionSettings for NanOrInfCheckSettings {
+        type FloatElem = f32;
+        type IntElem = i32;
+        const CHECK_FINITE: RecordFiniteCheck = RecordFiniteCheck::NanOrInf;
+    }
+
+    #[test]
+    fn err_when_saving_non_finite_values() {
+        let device: Device<TestBackend> = Default::default();
+        let model = Model::<TestBackend> {
+            linear1: LinearConfig::new(2, 2).init(&device),
+            linear2: LinearConfig::new(2, 2).init(&device),
+        };
+        let with_value = |value: f32| {
+            let mut record = model.clone().into_record();
+            record.linear2.weight =
+                Param::from_tensor(Tensor::from_floats([[1.0, value], [0.0, -1.0]], &device));
+            record
+        };
+        let nan_recorder = NamedMpkBytesRecorder::<NanCheckSettings>::new();
+        let inf_recorder = NamedMpkBytesRecorder::<NanOrInfCheckSettings>::new();
+
+        for result in [
+            nan_recorder.record(with_value(f32::NAN), ()),
+            inf_recorder.record(with_value(f32::NAN), ()),
+            inf_recorder.record(with_value(f32::NEG_INFINITY), ()),
+        ] {
+            match result {
+                Err(RecorderError::NonFiniteValues { path }) => assert_eq!(path, "linear2.weight"),
+                _ => panic!("Expected a non finite values error"),
+            }
+        }
+
+        // Infinite values are only rejected when asked for.
+        assert!(nan_recorder.record(with_value(f32::INFINITY), ()).is_ok());
+        assert!(inf_recorder.record(with_value(2.0), ()).is_ok());
+    }
+
+    #[derive(Debug, Default, Clone)]
+    struct TrainableSettings;
+
+    impl PrecisionSettings for TrainableSettings {
+        type FloatElem = f32;
+        type IntElem = i32;
+        const SAVE_FILTER: Option<fn(&str) -> bool> = Some(is_trainable);
+    }
+
+    fn is_trainable(path: &str) -> bool {
+        !path.ends_with("running_mean") && !path.ends_with("running_var")
+    }
+
+    #[test]
+    fn save_filter_skips_excluded_buffers() {
+        let device = Default::default();
+        let recorder = NamedMpkBytesRecorder::<TrainableSettings>::new();
+        let module = BatchNormConfig::new(3).init::<TestAutodiffBackend, 1>(&device);
+        // Update the running statistics.
+        let _output = module.forward(Tensor::<TestAutodiffBackend, 3>::random(
+            [2, 3, 4],
+            Distribution::Default,
+            &device,
+        ));
+        let bytes = recorder.record(module.clone().into_record(), ()).unwrap();
+
+        let result =
+            recorder.load::<BatchNormRecord<TestAutodiffBackend, 1>>(bytes.clone(), &device);
+        assert!(result.is_err());
+
+        let fresh = BatchNormConfig::new(3).init::<TestAutodiffBackend, 1>(&device);
+        let record = recorder
+            .load_partial(bytes, fresh.clone().into_record(), &device)
+            .unwrap();
+        let expected = fresh.into_record();
+
+        record
+            .running_mean
+            .val()
+            .to_data()
+            .assert_approx_eq(&expected.running_mean.val().to_data(), 6);
+        record
+            .running_var
+            .val()
+            .to_data()
+            .assert_approx_eq(&expected.running_var.val().to_data(), 6);
+        record
+            .gamma
+            .val()
+            .to_data()
+            .assert_approx_eq(&module.into_record().gamma.val().to_data(), 6);
+    }
+
+    #[test]
+    fn save_filter_keeps_non_finite_values() {
+        let device = Default::default();
+        let recorder = NamedMpkBytesRecorder::<TrainableSettings>::new();
+        let mut record = BatchNormConfig::new(3)
+            .init::<TestBackend, 1>(&device)
+            .into_record();
+        record.gamma =
+            Param::from_tensor(Tensor::from_floats([f32::NAN, f32::INFINITY, 1.0], &device));
+        let bytes = recorder.record(record, ()).unwrap();
+
+        let fresh = BatchNormConfig::new(3).init::<TestBackend, 1>(&device);
+        let record = recorder
+            .load_partial(bytes, fresh.into_record(), &device)
+            .unwrap();
+        let gamma = record.gamma.val().into_data().value;
+
+        assert!(gamma[0].is_nan());
+        assert_eq!(gamma[1..], [f32::INFINITY, 1.0]);
+    }
+
+    #[test]
+    fn err_when_save_filter_with_bin_recorder() {
+        let device: Device<TestBackend> = Default::default();
+        let recorder = BinBytesRecorder::<TrainableSettings>::new();
+
+        let result = recorder.record(Tensor::<TestBackend, 1>::ones([2], &device), ());
+
+        assert!(matches!(
+            result,
+            Err(RecorderError::Unknown(message)) if message.contains("self-describing format")
+        ));
+    }
+
+    #[derive(Debug, Default, Clone)]
+    struct DeduplicatedSettings;
+
+    impl PrecisionSettings for DeduplicatedSettings {
+        type FloatElem = f32;
+        type IntElem = i32;
+        const DEDUPLICATE_TIED: bool = true;
+    }
+
+    #[test]
+    fn tied_params_are_recorded_once() {
+        let device: Device<TestBackend> = Default::default();
+        let recorder = NamedMpkBytesRecorder::<DeduplicatedSettings>::new();
+        let linear = LinearConfig::new(4, 4).init(&device);
+        // Both fields share the ids of the parameters of the same layer.
+        let model = Model::<TestBackend> {
+            linear1: linear.clone(),
+            linear2: linear,
+        };
+        let bytes = recorder.record(model.clone().into_record(), ()).unwrap();
+
+        let stored: serde_json::Value =
+            Recorder::<TestBackend>::load_item(&recorder, bytes.clone()).unwrap();
+        assert!(stored.pointer("/item/linear1/weight/param").is_some());
+        assert!(stored.pointer("/item/linear2/weight/param").is_none());
+        assert_eq!(
+            stored.pointer("/item/linear2/weight/tied"),
+            Some(&serde_json::Value::from("linear1.weight"))
+        );
+
+        let record = recorder
+            .load::<ModelRecord<TestBackend>>(bytes, &device)
+            .unwrap();
+        let loaded = model.clone().load_record(record);
+
+        assert_eq!(loaded.linear1.weight.id, loaded.linear2.weight.id);
+        assert_eq!(
+            loaded.linear1.bias.as_ref().unwrap().id,
+            loaded.linear2.bias.as_ref().unwrap().id
+        );
+        loaded
+            .linear2
+            .weight
+            .to_data()
+            .assert_approx_eq(&model.linear1.weight.to_data(), 6);
+        loaded
+            .linear2
+            .bias
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&model.linear1.bias.unwrap().to_data(), 6);
+    }
+
+    #[test]
+    fn tied_params_keep_non_finite_values() {
+        let device: Device<TestBackend> = Default::default();
+        let recorder = NamedMpkBytesRecorder::<DeduplicatedSettings>::new();
+        let mut linear = LinearConfig::new(4, 4).init(&device);
+        linear.weight = linear.weight.map(|weight| weight.mul_scalar(f32::NAN));
+        let model = Model::<TestBackend> {
+            linear1: linear.clone(),
+            linear2: linear,
+        };
+        let bytes = recorder.record(model.clone().into_record(), ()).unwrap();
+
+        let record = recorder
+            .load::<ModelRecord<TestBackend>>(bytes, &device)
+            .unwrap();
+        let loaded = model.load_record(record);
+
+        assert!(loaded
+            .linear1
+            .weight
+            .val()
+            .into_data()
+            .value
+            .iter()
+            .all(|value| value.is_nan()));
+        assert!(loaded
+            .linear2
+            .weight
+            .val()
+            .into_data()
+            .value
+            .iter()
+            .all(|value| value.is_nan()));
+    }
+
+    #[test]
+    #[cfg(not(any(feature = "test-tch", feature = "test-wgpu")))]
+    fn load_into_reuses_tensors_with_the_same_shape() {
+        let device: Device<TestBackend> = Default::default();
+        let recorder = NamedMpkBytesRecorder::<FullPrecisionSettings>::new();
+        let saved = Model::<TestBackend> {
+            linear1: LinearConfig::new(4, 4).init(&device),
+            linear2: LinearConfig::new(4, 4).init(&device),
+        };
+        let bytes = recorder.record(saved.clone().into_record(), ()).unwrap();
+
+        // The weights of the second layer have another shape, so they are replaced.
+        let mut model = Model::<TestBackend> {
+            linear1: LinearConfig::new(4, 4).init(&device),
+            linear2: LinearConfig::new(4, 2).init(&device),
+        };
+        let buffer =
+            |linear: &Linear<TestBackend>| linear.weight.val().into_primitive().array.as_ptr();
+        let weight = buffer(&model.linear1);
+        let ids = [&model.linear1.weight.id, &model.linear2.weight.id].map(Clone::clone);
+
+        recorder.load_into(bytes, &mut model, &device).unwrap();
+
+        // The parameters keep their id and device, replaced or not.
+        assert_eq!(buffer(&model.linear1), weight);
+        assert_eq!(model.linear1.weight.id, ids[0]);
+        assert_eq!(model.linear2.weight.id, ids[1]);
+        assert_eq!(model.linear1.weight.device(), device);
+        model
+            .linear1
+            .weight
+            .to_data()
+            .assert_approx_eq(&saved.linear1.weight.to_data(), 6);
+        assert_eq!(model.linear2.weight.dims(), [4, 4]);
+        model
+            .linear2
+            .weight
+            .to_data()
+            .assert_approx_eq(&saved.linear2.weight.to_data(), 6);
+    }
+
+    #[derive(Record)]
+    struct VocabRecord<B: Backend> {
+        token_ids: Tensor<B, 1, Int>,
+        counts: Tensor<B, 1, Int>,
+    }
+
+    #[derive(Debug, Default, Clone)]
+    struct OneBasedSettings;
+
+    impl PrecisionSettings for OneBasedSettings {
+        type FloatElem = f32;
+        type IntElem = i32;
+        const INDEX_OFFSET: i64 = 1;
+        const INDEX_FIELDS: Option<fn(&str) -> bool> = Some(|path| path == "token_ids");
+    }
+
+    #[test]
+    fn index_offset_is_applied_to_index_tensors_only() {
+        let device: Device<TestBackend> = Default::default();
+        let recorder = NamedMpkBytesRecorder::<OneBasedSettings>::new();
+        let record = VocabRecord::<TestBackend> {
+            token_ids: Tensor::from_ints([0, 2, 5], &device),
+            counts: Tensor::from_ints([0, 2, 5], &device),
+        };
+        let bytes = recorder.record(record, ()).unwrap();
+
+        let stored: serde_json::Value =
+            Recorder::<TestBackend>::load_item(&recorder, bytes.clone()).unwrap();
+        assert_eq!(
+            stored.pointer("/item/token_ids/value"),
+            Some(&serde_json::json!([1, 3, 6]))
+        );
+        assert_eq!(
+            stored.pointer("/item/counts/value"),
+            Some(&serde_json::json!([0, 2, 5]))
+        );
+
+        let loaded = recorder
+            .load::<VocabRecord<TestBackend>>(bytes, &device)
+            .unwrap();
+
+        assert_eq!(loaded.token_ids.into_data().value, vec![0, 2, 5]);
+        assert_eq!(loaded.counts.into_data().value, vec![0, 2, 5]);
+    }
+
+    #[test]
+    fn load_tensor_reads_a_single_tensor() {
+        let device: Device<TestBackend> = Default::default();
+        let recorder = NamedMpkBytesRecorder::<FullPrecisionSettings>::new();
+        let model = Model::<TestBackend> {
+            linear1: LinearConfig::new(4, 4).init(&device),
+            linear2: LinearConfig::new(4, 2).init(&device),
+        };
+        let bytes = recorder.record(model.clone().into_record(), ()).unwrap();
+
+        let weight: Tensor<TestBackend, 2> = recorder
+            .load_tensor(bytes.clone(), "linear2.weight", &device)
+            .unwrap();
+        weight
+            .to_data()
+            .assert_approx_eq(&model.linear2.weight.to_data(), 6);
+
+        let result: Result<Tensor<TestBackend, 2>, _> =
+            recorder.load_tensor(bytes.clone(), "linear3.weight", &device);
+        assert!(
+            matches!(result, Err(RecorderError::TensorNotFound(path)) if path == "linear3.weight")
+        );
+
+        let result: Result<Tensor<TestBackend, 1>, _> =
+            recorder.load_tensor(bytes, "linear2.weight", &device);
+        assert!(matches!(result, Err(RecorderError::DeserializeError(_))));
+    }
+
+    #[test]
+    fn load_partial_errors_on_shape_mismatch() {
+        let device: Device<TestBackend> = Default::default();
+        let recorder = NamedMpkBytesRecorder::<FullPrecisionSettings>::new();
+        let backbone = Backbone::<TestBackend> {
+            linear1: LinearConfig::new(2, 2).init(&device),
+        };
+        let model = Model::<TestBackend> {
+            linear1: LinearConfig::new(4, 4).init(&device),
+            linear2: LinearConfig::new(4, 2).init(&device),
+        };
+        let bytes = recorder.record(backbone.into_record(), ()).unwrap();
+
+        let result = recorder.load_partial(bytes, model.into_record(), &device);
+
+        assert!(matches!(result, Err(RecorderError::DeserializeError(_))));
+    }
+
+    #[derive(Debug, Default, Clone)]
+    struct GzipSettings;
+
+    impl PrecisionSettings for GzipSettings {
+        type FloatElem = f32;
+        type IntElem = i32;
+        const COMPRESSION: RecordCompression = RecordCompression::Gzip;
+    }
+
+    #[test]
+    fn load_partial_errors_on_compressed_shape_mismatch() {
+        let device: Device<TestBackend> = Default::default();
+        let recorder = NamedMpkBytesRecorder::<GzipSettings>::new();
+        let backbone = Backbone::<TestBackend> {
+            linear1: LinearConfig::new(2, 2).init(&device),
+        };
+        let model = Model::<TestBackend> {
+            linear1: LinearConfig::new(4, 4).init(&device),
+            linear2: LinearConfig::new(4, 2).init(&device),
+        };
+        let bytes = recorder.record(backbone.into_record(), ()).unwrap();
+
+        let result = recorder.load_partial(bytes, model.into_record(), &device);
+
+        assert!(matches!(
+            result,
+            Err(RecorderError::DeserializeError(message))
+                if message.starts_with("Shape mismatch for tensor 'linear1.weight'")
+        ));
+    }
+
+    #[derive(Module, Debug)]
+    struct RenamedModel<B: Backend> {
+        linear1: Linear<B>,
+        output: Linear<B>,
+    }
+
+    #[test]
+    fn load_with_keys_remaps_renamed_layer() {
+        let device: Device<TestBackend> = Default::default();
+        let recorder = NamedMpkBytesRecorder::<FullPrecisionSettings>::new();
+        let model = Model::<TestBackend> {
+            linear1: LinearConfig::new(4, 4).init(&device),
+            linear2: LinearConfig::new(4, 2).init(&device),
+        };
+        let renamed = RenamedModel::<TestBackend> {
+            linear1: LinearConfig::new(4, 4).init(&device),
+            output: LinearConfig::new(4, 2).init(&device),
+        };
+        let bytes = recorder.record(model.clone().into_record(), ()).unwrap();
+        let keys = KeyMatching::strict().with_remap(|path| path.replace("linear2.", "output."));
+
+        let (record, mismatch) = recorder
+            .load_with_keys(bytes, renamed.clone().into_record(), &keys, &device)
+            .unwrap();
+        let loaded = renamed.load_record(record);
+
+        assert!(mismatch.is_empty());
+        loaded
+            .linear1
+            .weight
+            .to_data()
+            .assert_approx_eq(&model.linear1.weight.to_data(), 6);
+        loaded
+            .output
+            .weight
+            .to_data()
+            .assert_approx_eq(&model.linear2.weight.to_data(), 6);
+        loaded
+            .output
+            .bias
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&model.linear2.bias.unwrap().to_data(), 6);
+    }
+
+    #[test]
+    fn load_with_keys_reports_mismatched_paths() {
+        let device: Device<TestBackend> = Default::default();
+        let recorder = NamedMpkBytesRecorder::<FullPrecisionSettings>::new();
+        let model = Model::<TestBackend> {
+            linear1: LinearConfig::new(4, 4).init(&device),
+            linear2: LinearConfig::new(4, 2).init(&device),
+        };
+        let renamed = RenamedModel::<TestBackend> {
+            linear1: LinearConfig::new(4, 4).init(&device),
+            output: LinearConfig::new(4, 2).init(&device),
+        };
+        let bytes = recorder.record(model.clone().into_record(), ()).unwrap();
+        let expected = KeyMismatch {
+            unexpected: vec!["linear2.bias".to_string(), "linear2.weight".to_string()],
+            missing: vec!["output.bias".to_string(), "output.weight".to_string()],
+        };
+
+        let result = recorder.load_with_keys(
+            bytes.clone(),
+            renamed.clone().into_record(),
+            &KeyMatching::strict(),
+            &device,
+        );
+        assert!(
+            matches!(result, Err(RecorderError::KeyMismatch(mismatch)) if mismatch == expected)
+        );
+
+        let (record, mismatch) = recorder
+            .load_with_keys(
+                bytes,
+                renamed.clone().into_record(),
+                &KeyMatching::lenient(),
+                &device,
+            )
+            .unwrap();
+        let loaded = renamed.clone().load_record(record);
+
+        assert_eq!(mismatch, expected);
+        loaded
+            .linear1
+            .weight
+            .to_data()
+            .assert_approx_eq(&model.linear1.weight.to_data(), 6);
+        loaded
+            .output
+            .weight
+            .to_data()
+            .assert_approx_eq(&renamed.output.weight.to_data(), 6);
+    }
+
+    #[derive(Record)]
+    struct Rank3LinearRecord<B: Backend> {
+        weight: Param<Tensor<B, 3>>,
+        bias: Option<Param<Tensor<B, 1>>>,
+    }
+
+    #[derive(Record)]
+    struct Rank3BackboneRecord<B: Backend> {
+        linear1: Rank3LinearRecord<B>,
+    }
+
+    #[test]
+    fn load_reports_path_of_tensor_with_wrong_rank() {
+        let device: Device<TestBackend> = Default::default();
+        let recorder = NamedMpkBytesRecorder::<FullPrecisionSettings>::new();
+        let backbone = Backbone::<TestBackend> {
+            linear1: LinearConfig::new(4, 4).init(&device),
+        };
+        let bytes = recorder.record(backbone.into_record(), ()).unwrap();
+
+        let result = recorder.load::<Rank3BackboneRecord<TestBackend>>(bytes, &device);
+
+        assert!(matches!(
+            result,
+            Err(RecorderError::DeserializeError(message))
+                if message == "Expected a tensor of rank 3 at path linear1.weight, got shape [4, 4]"
+        ));
+    }
+
+    #[test]
+    fn load_reports_path_of_tensor_with_wrong_number_of_values() {
+        let device: Device<TestBackend> = Default::default();
+        let recorder = NamedMpkBytesRecorder::<FullPrecisionSettings>::new();
+        let backbone = Backbone::<TestBackend> {
+            linear1: LinearConfig::new(4, 4).init(&device),
+        };
+        let bytes = recorder.record(backbone.into_record(), ()).unwrap();
+        let mut loaded: serde_json::Value =
+            Recorder::<TestBackend>::load_item(&recorder, bytes).unwrap();
+        loaded["item"]["linear1"]["weight"]["param"]["shape"] = serde_json::json!([4, 3]);
+        let bytes = Recorder::<TestBackend>::save_item(&recorder, loaded, ()).unwrap();
+
+        let result = recorder.load::<BackboneRecord<TestBackend>>(bytes, &device);
+
+        assert!(matches!(
+            result,
+            Err(RecorderError::DeserializeError(message))
+                if message == "Expected 12 values for shape [4, 3] at path linear1.weight, got 16"
+        ));
+    }
+
+    #[derive(Debug, Default, Clone)]
+    struct DoubleOnMigrationSettings;
+
+    impl PrecisionSettings for DoubleOnMigrationSettings {
+        type FloatElem = f32;
+        type IntElem = i32;
+        const MIGRATE: Option<
+            fn(u32, DataSerialize<serde_json::Value>) -> DataSerialize<serde_json::Value>,
+        > = Some(double_values);
+    }
+
+    fn double_values(
+        _version: u32,
+        data: DataSerialize<serde_json::Value>,
+    ) -> DataSerialize<serde_json::Value> {
+        let value = data
+            .value
+            .into_iter()
+            .map(|value| serde_json::Value::from(value.as_f64().unwrap() * 2.0))
+            .collect();
+
+        DataSerialize::new(value, data.shape)
+    }
+
+    fn record_with_version(record_version: u32) -> Vec<u8> {
+        type Rec = NamedMpkBytesRecorder<DoubleOnMigrationSettings>;
+
+        let device: Device<TestBackend> = Default::default();
+        let tensor = Tensor::<TestBackend, 1>::from_floats([1.0, 2.0], &device);
+        let mut record = BurnRecord::<_, TestBackend>::new::<Rec>(
+            tensor.into_item::<DoubleOnMigrationSettings>(),
+        );
+        record.metadata.record_version = record_version;
+
+        Recorder::<TestBackend>::save_item(&Rec::new(), record, ()).unwrap()
+    }
+
+    #[test]
+    fn load_migrates_older_record_version() {
+        let device: Device<TestBackend> = Default::default();
+        let recorder = NamedMpkBytesRecorder::<DoubleOnMigrationSettings>::new();
+
+        let tensor: Tensor<TestBackend, 1> =
+            recorder.load(record_with_version(0), &device).unwrap();
+
+        tensor
+            .into_data()
+            .assert_approx_eq(&Data::from([2.0, 4.0]), 3);
+    }
+
+    #[test]
+    fn load_doesnt_migrate_current_record_version() {
+        let device: Device<TestBackend> = Default::default();
+        let recorder = NamedMpkBytesRecorder::<DoubleOnMigrationSettings>::new();
+
+        let tensor: Tensor<TestBackend, 1> = recorder
+            .load(record_with_version(RECORD_VERSION), &device)
+            .unwrap();
+
+        tensor
+            .into_data()
+            .assert_approx_eq(&Data::from([1.0, 2.0]), 3);
+    }
+
+    #[test]
+    fn load_without_migration_keeps_older_record_version() {
+        let device: Device<TestBackend> = Default::default();
+        let recorder = NamedMpkBytesRecorder::<FullPrecisionSettings>::new();
+
+        let tensor: Tensor<TestBackend, 1> =
+            recorder.load(record_with_version(0), &device).unwrap();
+
+        tensor
+            .into_data()
+            .assert_approx_eq(&Data::from([1.0, 2.0]), 3);
+    }
+
+    #[test]
+    fn err_when_newer_record_version() {
+        let device: Device<TestBackend> = Default::default();
+
+        let migrated =
+            NamedMpkBytesRecorder::<DoubleOnMigrationSettings>::new()
+                .load::<Tensor<TestBackend, 1>>(record_with_version(RECORD_VERSION + 1), &device);
+        let typed = NamedMpkBytesRecorder::<FullPrecisionSettings>::new()
+            .load::<Tensor<TestBackend, 1>>(record_with_version(RECORD_VERSION + 1), &device);
+
+        for result in [migrated, typed] {
+            assert!(matches!(
+                result,
+                Err(RecorderError::UnsupportedRecordVersion { found, supported })
+                    if found == RECORD_VERSION + 1 && supported == RECORD_VERSION
+            ));
+        }
+    }
+
+    #[derive(Debug, Default, Clone)]
+    struct ChecksumSettings;
+
+    impl PrecisionSettings for ChecksumSettings {
+        type FloatElem = f32;
+        type IntElem = i32;
+        const CHECKSUM: bool = true;
+    }
+
+    #[test]
+    fn load_verifies_checksum() {
+        let device: Device<TestBackend> = Default::default();
+        let recorder = BinBytesRecorder::<ChecksumSettings>::new();
+        let tensor = Tensor::<TestBackend, 1>::ones([16], &device);
+
+        let bytes = recorder.record(tensor.clone(), ()).unwrap();
+        let loaded: Tensor<TestBackend, 1> = recorder.load(bytes, &device).unwrap();
+
+        loaded.into_data().assert_approx_eq(&tensor.into_data(), 3);
+    }
+
+    #[test]
+    fn err_when_checksum_mismatch() {
+        let device: Device<TestBackend> = Default::default();
+        let recorder = BinBytesRecorder::<ChecksumSettings>::new();
+        let tensor = Tensor::<TestBackend, 1>::ones([16], &device);
+
+        let mut bytes = recorder.record(tensor, ()).unwrap();
+        // The last bytes of the payload encode the shape, corrupt the last float value instead.
+        let index = bytes.len() - CHECKSUM_TRAILER_SIZE - 5;
+        bytes[index] ^= 0xFF;
+
+        let result = recorder.load::<Tensor<TestBackend, 1>>(bytes, &device);
+
+        assert!(matches!(result, Err(RecorderError::ChecksumMismatch(_))));
+    }
+
+    #[test]
+    fn checksum_is_ignored_without_checksum_settings() {
+        let device: Device<TestBackend> = Default::default();
+        let tensor = Tensor::<TestBackend, 1>::ones([16], &device);
+
+        let bytes = BinBytesRecorder::<ChecksumSettings>::new()
+            .record(tensor.clone(), ())
+            .unwrap();
+        let loaded: Tensor<TestBackend, 1> = BinBytesRecorder::<FullPrecisionSettings>::new()
+            .load(bytes, &device)
+            .unwrap();
+
+        loaded.into_data().assert_approx_eq(&tensor.into_data(), 3);
+    }
+
+    #[test]
+    fn checksum_file_is_loaded_without_checksum_settings() {
+        let device: Device<TestBackend> = Default::default();
+        let tensor = Tensor::<TestBackend, 1>::ones([16], &device);
+        let file_path = format!("{FILE_PATH}-checksum");
+
+        NamedMpkGzFileRecorder::<ChecksumSettings>::new()
+            .record(tensor.clone(), file_path.clone().into())
+            .unwrap();
+
+        let checked: Tensor<TestBackend, 1> = NamedMpkGzFileRecorder::<ChecksumSettings>::new()
+            .load(file_path.clone().into(), &device)
+            .unwrap();
+        let unchecked: Tensor<TestBackend, 1> =
+            NamedMpkGzFileRecorder::<FullPrecisionSettings>::new()
+                .load(file_path.into(), &device)
+                .unwrap();
+
+        checked.into_data().assert_approx_eq(&tensor.to_data(), 3);
+        unchecked
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+    }
+
+    #[derive(Debug, Default, Clone)]
+    struct ChecksumTrainableSettings;
+
+    impl PrecisionSettings for ChecksumTrainableSettings {
+        type FloatElem = f32;
+        type IntElem = i32;
+        const CHECKSUM: bool = true;
+        const SAVE_FILTER: Option<fn(&str) -> bool> = Some(is_trainable);
+    }
+
+    #[test]
+    fn checksum_with_save_filter() {
+        let device: Device<TestBackend> = Default::default();
+        let recorder = NamedMpkBytesRecorder::<ChecksumTrainableSettings>::new();
+        let tensor = Tensor::<TestBackend, 1>::ones([16], &device);
+
+        let mut bytes = recorder.record(tensor.clone(), ()).unwrap();
+        let loaded: Tensor<TestBackend, 1> = recorder.load(bytes.clone(), &device).unwrap();
+        loaded.into_data().assert_approx_eq(&tensor.into_data(), 3);
+
+        let index = bytes.len() - CHECKSUM_TRAILER_SIZE - 5;
+        bytes[index] ^= 0xFF;
+        let result = recorder.load::<Tensor<TestBackend, 1>>(bytes, &device);
+
+        assert!(matches!(result, Err(RecorderError::ChecksumMismatch(_))));
+    }
+
+    #[derive(Debug, Default, Clone)]
+    struct RequireGradSettings;
+
+    impl PrecisionSettings for RequireGradSettings {
+        type FloatElem = f32;
+        type IntElem = i32;
+        const RECORD_REQUIRE_GRAD: bool = true;
+    }
+
+    #[test]
+    fn load_restores_recorded_require_grad() {
+        let device: Device<TestAutodiffBackend> = Default::default();
+        let config = LinearConfig::new(2, 2);
+        let recorder = BinBytesRecorder::<RequireGradSettings>::new();
+        let model_init = || Model::<TestAutodiffBackend> {
+            linear1: config.init(&device),
+            linear2: config.init(&device),
+        };
+
+        // The first layer is frozen during the fine-tuning.
+        let mut model = model_init();
+        model.linear1 = model.linear1.no_grad();
+        let bytes = recorder.record(model.into_record(), ()).unwrap();
+
+        // The recorded flags win over the module the record is loaded into.
+        let record = recorder.load(bytes.clone(), &device).unwrap();
+        let loaded = model_init().load_record(record);
+        assert!(!loaded.linear1.weight.is_require_grad());
+        assert!(!loaded.linear1.bias.unwrap().is_require_grad());
+        assert!(loaded.linear2.weight.is_require_grad());
+        assert!(loaded.linear2.bias.unwrap().is_require_grad());
+
+        // Settings not restoring the flags keep the gradient requirements of the module.
+        let record = BinBytesRecorder::<FullPrecisionSettings>::new()
+            .load(bytes, &device)
+            .unwrap();
+        let loaded = model_init().load_record(record);
+        assert!(loaded.linear1.weight.is_require_grad());
+        assert!(loaded.linear2.weight.is_require_grad());
+    }
+
+    #[test]
+    fn err_when_int_value_overflows_target_element() {
+        let data = DataSerialize::new(vec![1i64, 3_000_000_000, -2], vec![3]);
+
+        let result = crate::record::tensor::check_int_values::<i64, i32>(&data, "weight");
+
+        match result {
+            Err(RecorderError::IntegerOverflow(message)) => {
+                assert!(message.contains("3000000000"));
+                assert!(message.contains("weight"));
+            }
+            _ => panic!("Expected an integer overflow error"),
+        }
+
+        let data = DataSerialize::new(vec![1i64, i32::MAX as i64, i32::MIN as i64], vec![3]);
+        assert!(crate::record::tensor::check_int_values::<i64, i32>(&data, "weight").is_ok());
+    }
 
     #[test]
     #[should_panic]
@@ -0,0 +1,94 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+
+#[cfg(feature = "std")]
+use alloc::string::ToString;
+#[cfg(feature = "std")]
+use core::cell::RefCell;
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static REQUIRE_GRAD: RefCell<Option<BTreeMap<String, bool>>> = const { RefCell::new(None) };
+}
+
+/// Record the gradient requirement of the float parameters converted on the current thread
+/// while the scope is alive, or restore the recorded ones when they are loaded, by parameter
+/// id.
+///
+/// Without the `std` feature, nothing is recorded nor restored.
+pub(crate) struct RequireGradScope {
+    #[cfg(feature = "std")]
+    previous: Option<BTreeMap<String, bool>>,
+}
+
+impl RequireGradScope {
+    /// Start recording the gradient requirements, or restoring the given ones.
+    pub(crate) fn start(flags: BTreeMap<String, bool>) -> Self {
+        #[cfg(feature = "std")]
+        {
+            let previous = REQUIRE_GRAD.with(|state| state.replace(Some(flags)));
+
+            Self { previous }
+        }
+
+        #[cfg(not(feature = "std"))]
+        {
+            let _ = flags;
+            Self {}
+        }
+    }
+
+    /// Stop the scope, returning the gradient requirements recorded while it was alive.
+    pub(crate) fn finish(self) -> BTreeMap<String, bool> {
+        #[cfg(feature = "std")]
+        {
+            REQUIRE_GRAD
+                .with(|state| state.borrow_mut().take())
+                .unwrap_or_default()
+        }
+
+        #[cfg(not(feature = "std"))]
+        BTreeMap::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for RequireGradScope {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        REQUIRE_GRAD.with(|state| state.replace(previous));
+    }
+}
+
+/// Record the gradient requirement of the parameter with the given id, when a scope is alive.
+pub(crate) fn record_require_grad(id: &str, require_grad: bool) {
+    #[cfg(feature = "std")]
+    REQUIRE_GRAD.with(|state| {
+        if let Some(flags) = state.borrow_mut().as_mut() {
+            flags.insert(id.to_string(), require_grad);
+        }
+    });
+
+    #[cfg(not(feature = "std"))]
+    let _ = (id, require_grad);
+}
+
+/// The recorded gradient requirement of the parameter with the given id, `None` when no scope
+/// is alive or the parameter wasn't recorded with one.
+pub(crate) fn recorded_require_grad(id: &str) -> Option<bool> {
+    #[cfg(feature = "std")]
+    {
+        REQUIRE_GRAD.with(|state| {
+            state
+                .borrow()
+                .as_ref()
+                .and_then(|flags| flags.get(id).copied())
+        })
+    }
+
+    #[cfg(not(feature = "std"))]
+    {
+        let _ = id;
+        None
+    }
+}
@@ -1,4 +1,4 @@
-use burn_tensor::Element;
+use burn_tensor::{DataSerialize, Element};
 use serde::{de::DeserializeOwned, Serialize};
 
 /// Settings allowing to control the precision when (de)serializing items.
@@ -10,16 +10,228 @@ pub trait PrecisionSettings:
 
     /// Integer element type.
     type IntElem: Element + Serialize + DeserializeOwned;
+
+    /// Compression codec applied to the serialized tensor data.
+    ///
+    /// The codec is written alongside the compressed bytes, so any settings using compression
+    /// can load a tensor compressed with a different codec. Without compression, the data is
+    /// written as is, without a codec: a record saved without compression must be loaded by
+    /// settings without compression, and a compressed one by settings with compression, the
+    /// others producing an error.
+    const COMPRESSION: RecordCompression = RecordCompression::None;
+
+    /// Quantization applied to the float tensor data before it is serialized.
+    ///
+    /// Quantized tensors are stored with their scale and are dequantized to
+    /// [FloatElem](PrecisionSettings::FloatElem) when loaded.
+    const QUANTIZATION: RecordQuantization = RecordQuantization::None;
+
+    /// Maximum size in bytes of the values of a tensor written as a single chunk.
+    ///
+    /// When set, the tensors are written in chunks of at most this size, each chunk being
+    /// compressed on its own, and are reassembled on load, so that no serialized buffer is as
+    /// large as a big tensor, e.g. an embedding table. Quantized float tensors aren't chunked.
+    /// Records must be loaded with the same chunking as they were saved with.
+    const CHUNK_SIZE: Option<usize> = None;
+
+    /// Maximum size in bytes of the host buffer holding the values of a loaded tensor, e.g. on
+    /// memory-constrained devices.
+    ///
+    /// With a [chunk size](PrecisionSettings::CHUNK_SIZE), the values of a float tensor larger
+    /// than the budget are staged in a temporary file as their chunks are deserialized, then
+    /// uploaded to the device one chunk at a time. Loading fails when a single chunk, or a whole
+    /// tensor that isn't chunked, exceeds the budget, as do int and bool tensors larger than the
+    /// budget, which aren't staged. Since the tensors are uploaded once the whole record is
+    /// deserialized, the budget bounds the buffer of each tensor, not the memory of the record.
+    const MEMORY_BUDGET: Option<usize> = None;
+
+    /// Order of the tensor values on disk.
+    ///
+    /// The values are always loaded in the row-major order of [Data](burn_tensor::Data), whatever
+    /// the internal layout of the backend, so records are portable across backends. Records
+    /// must be loaded with the same layout as they were saved with.
+    const LAYOUT: RecordLayout = RecordLayout::RowMajor;
+
+    /// Whether a checksum of the record is written after it and verified on load, before the
+    /// record is decoded.
+    ///
+    /// This is useful for untrusted files, e.g. downloaded checkpoints. The checksum is the CRC32
+    /// (IEEE) of the bytes written after the header of the [format](crate::record::RecordFormat),
+    /// compressed for the gzip formats, followed by a marker. It's written after the record
+    /// rather than in its [metadata](crate::record::BurnMetadata), so the records can still be
+    /// loaded without verifying it, e.g. by the versions before it was introduced. The records
+    /// read with the checksum enabled are read in memory before being decoded.
+    ///
+    /// The pretty json format can't have a checksum, and the encrypted records are already
+    /// authenticated.
+    const CHECKSUM: bool = false;
+
+    /// Whether the loaded integer tensors are checked to fit in the integer element of the
+    /// backend, returning [IntegerOverflow](crate::record::RecorderError::IntegerOverflow)
+    /// instead of failing during the conversion.
+    ///
+    /// This is useful when loading a record saved with wider integers, e.g. `i64` indices into
+    /// an `i32` backend, at the cost of scanning the integer tensors once more.
+    const CHECK_INT_OVERFLOW: bool = false;
+
+    /// Which non-finite float values make [record](crate::record::Recorder::record) fail with
+    /// [NonFiniteValues](crate::record::RecorderError::NonFiniteValues) before anything is
+    /// written, e.g. to never save the checkpoint of a diverged training run.
+    ///
+    /// The check costs a pass over the float tensors, so it's disabled by default.
+    const CHECK_FINITE: RecordFiniteCheck = RecordFiniteCheck::None;
+
+    /// Offset added to the values of the index tensors when they are recorded and subtracted
+    /// when they are loaded, e.g. `1` to exchange 1-based indices with another framework while
+    /// Burn keeps using 0-based ones.
+    ///
+    /// Only the integer tensors selected by [INDEX_FIELDS](PrecisionSettings::INDEX_FIELDS) are
+    /// shifted, the other integer tensors holding plain values, e.g. counts.
+    const INDEX_OFFSET: i64 = 0;
+
+    /// Predicate over the path of each integer tensor of the record, e.g. `vocab.token_ids`,
+    /// selecting the tensors holding indices shifted by
+    /// [INDEX_OFFSET](PrecisionSettings::INDEX_OFFSET).
+    const INDEX_FIELDS: Option<fn(&str) -> bool> = None;
+
+    /// Optional version of the recorded items, written in the record metadata.
+    const USER_VERSION: Option<&'static str> = None;
+
+    /// Predicate over the path of each field of the recorded item, e.g. `norm.running_mean`,
+    /// selecting the fields written by [record](crate::record::Recorder::record).
+    ///
+    /// The excluded fields are left out of the record, which is then loaded with
+    /// [load_partial](crate::record::SelfDescribingRecorder::load_partial) so that they keep the
+    /// value of the given record, e.g. the running statistics of a freshly initialized batch
+    /// norm.
+    const SAVE_FILTER: Option<fn(&str) -> bool> = None;
+
+    /// Whether the parameters tied to a parameter already recorded, i.e. sharing its
+    /// [id](crate::module::ParamId), are written as a reference to its path instead of a copy of
+    /// its tensor.
+    ///
+    /// A record with tied parameters, e.g. a decoder reusing the embedding table of the encoder,
+    /// then stores their tensor once, and the fields tied together share the same loaded tensor.
+    const DEDUPLICATE_TIED: bool = false;
+
+    /// Whether the gradient requirement of each float parameter is written in the
+    /// [record metadata](crate::record::BurnMetadata::require_grad), e.g. so that the parameters
+    /// frozen during a fine-tuning stay frozen when it's resumed.
+    ///
+    /// The recorded gradient requirements are restored by [load](crate::record::Recorder::load)
+    /// and take precedence over the ones of the module the record is loaded into. Settings where
+    /// it's disabled ignore them when loading, each parameter keeping the gradient requirement
+    /// of its module.
+    const RECORD_REQUIRE_GRAD: bool = false;
+
+    /// Predicate over the path of each field of the loaded item, selecting the fields loaded by
+    /// [load_partial](crate::record::SelfDescribingRecorder::load_partial).
+    ///
+    /// The excluded fields keep the value of the given record even when they are present in the
+    /// loaded record. Since [load](crate::record::Recorder::load) has no value to keep, it
+    /// returns an error with a load filter.
+    const LOAD_FILTER: Option<fn(&str) -> bool> = None;
+
+    /// Migration of the serialized data of a tensor written with an older
+    /// [record version](crate::record::RECORD_VERSION).
+    ///
+    /// It's called once for each version step, from the recorded version up to the current one,
+    /// before the tensor is created. Without a migration, the records of older versions are loaded
+    /// as is.
+    #[allow(clippy::type_complexity)]
+    const MIGRATE: Option<
+        fn(u32, DataSerialize<serde_json::Value>) -> DataSerialize<serde_json::Value>,
+    > = None;
+}
+
+/// Compression codec used when serializing tensor data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RecordCompression {
+    /// The tensor data is serialized as is, without a codec, as in the records saved before
+    /// compression was introduced.
+    #[default]
+    None,
+    /// The tensor data is compressed with gzip.
+    Gzip,
+    /// The tensor data is compressed with zstd.
+    ///
+    /// Requires the `zstd` feature.
+    Zstd,
+}
+
+/// Quantization used when serializing float tensor data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RecordQuantization {
+    /// The float tensor data is serialized with the [float element](PrecisionSettings::FloatElem).
+    #[default]
+    None,
+    /// The float tensor data is quantized to `i8` with a symmetric per-tensor scale computed
+    /// from its largest absolute value.
+    ///
+    /// The quantization error of each value is at most half the scale, so a single large
+    /// outlier reduces the precision of the whole tensor.
+    Int8,
+}
+
+/// Order of the tensor values when serializing tensor data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RecordLayout {
+    /// The values are stored in row-major order, the last dimension being contiguous.
+    #[default]
+    RowMajor,
+    /// The values are stored in column-major order, the first dimension being contiguous, e.g.
+    /// to interchange tensors with tools using the Fortran order.
+    ColumnMajor,
+}
+
+/// Non-finite float values rejected when saving a record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RecordFiniteCheck {
+    /// The float values aren't checked.
+    #[default]
+    None,
+    /// NaN values are rejected, infinite values are saved as is, e.g. for attention masks.
+    Nan,
+    /// NaN and infinite values are rejected.
+    NanOrInf,
+}
+
+impl RecordFiniteCheck {
+    /// Whether the given value is rejected.
+    pub fn rejects(&self, value: f64) -> bool {
+        match self {
+            Self::None => false,
+            Self::Nan => value.is_nan(),
+            Self::NanOrInf => !value.is_finite(),
+        }
+    }
 }
 
 /// Default precision settings.
 #[derive(Debug, Default, Clone)]
 pub struct FullPrecisionSettings;
 
-/// Precision settings optimized for compactness.
+/// Precision settings optimized for compactness using the [f16](half::f16) float type.
+///
+/// Integers are kept in full precision, since index tensors, e.g. token ids, often exceed the
+/// range of `i16`.
 #[derive(Debug, Default, Clone)]
 pub struct HalfPrecisionSettings;
 
+/// Precision settings optimized for compactness using the [bf16](half::bf16) float type.
+///
+/// Compared to [half precision](HalfPrecisionSettings), it keeps the exponent range of `f32` at
+/// the cost of a lower mantissa precision, and integers are kept in full precision.
+#[derive(Debug, Default, Clone)]
+pub struct BF16PrecisionSettings;
+
+/// Precision settings optimized for compactness using [int8 quantization](RecordQuantization::Int8)
+/// of the float tensors.
+///
+/// Float tensors are loaded as `f32` approximations, integers are kept in full precision.
+#[derive(Debug, Default, Clone)]
+pub struct Int8PrecisionSettings;
+
 /// Precision settings optimized for precision.
 #[derive(Debug, Default, Clone)]
 pub struct DoublePrecisionSettings;
@@ -36,5 +248,16 @@ impl PrecisionSettings for DoublePrecisionSettings {
 
 impl PrecisionSettings for HalfPrecisionSettings {
     type FloatElem = half::f16;
-    type IntElem = i16;
+    type IntElem = i32;
+}
+
+impl PrecisionSettings for BF16PrecisionSettings {
+    type FloatElem = half::bf16;
+    type IntElem = i32;
+}
+
+impl PrecisionSettings for Int8PrecisionSettings {
+    type FloatElem = f32;
+    type IntElem = i32;
+    const QUANTIZATION: RecordQuantization = RecordQuantization::Int8;
 }
@@ -0,0 +1,113 @@
+use super::RecorderError;
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Shapes of the tensors whose [shapes are checked](super::Record::check_shapes), by path.
+///
+/// The shapes can be collected from a record, then compared with the ones of another record,
+/// e.g. to check that a partially loaded record has the shapes of the record it's merged with.
+/// They're compared on the items, so that the tensors are compared whatever their serialized
+/// form, e.g. compressed, quantized or chunked.
+#[derive(Clone, Debug, Default)]
+pub struct ShapeCheck {
+    mode: ShapeMode,
+}
+
+#[derive(Clone, Debug, Default)]
+enum ShapeMode {
+    /// The shapes are only checked against the items.
+    #[default]
+    Ignore,
+    /// The shapes are collected.
+    Collect(BTreeMap<String, Vec<usize>>),
+    /// The shapes are compared with the expected ones.
+    Expect(BTreeMap<String, Vec<usize>>),
+}
+
+impl ShapeCheck {
+    /// Only check the shapes against the items.
+    pub fn ignore() -> Self {
+        Self::default()
+    }
+
+    /// Collect the shapes of the checked tensors.
+    pub fn collect() -> Self {
+        Self {
+            mode: ShapeMode::Collect(BTreeMap::new()),
+        }
+    }
+
+    /// Compare the shapes of the checked tensors with the given ones, a tensor without an
+    /// expected shape being accepted as is.
+    pub fn expect(shapes: BTreeMap<String, Vec<usize>>) -> Self {
+        Self {
+            mode: ShapeMode::Expect(shapes),
+        }
+    }
+
+    /// The shapes collected so far, empty unless they're [collected](Self::collect).
+    pub fn into_shapes(self) -> BTreeMap<String, Vec<usize>> {
+        match self.mode {
+            ShapeMode::Collect(shapes) => shapes,
+            _ => BTreeMap::new(),
+        }
+    }
+
+    /// Collect the shape of the tensor located at the given path, or compare it with its
+    /// expected shape.
+    pub fn check(&mut self, path: &str, shape: &[usize]) -> Result<(), RecorderError> {
+        match &mut self.mode {
+            ShapeMode::Ignore => Ok(()),
+            ShapeMode::Collect(shapes) => {
+                shapes.insert(path.to_string(), shape.to_vec());
+                Ok(())
+            }
+            ShapeMode::Expect(shapes) => match shapes.get(path) {
+                Some(expected) if expected.as_slice() != shape => {
+                    Err(RecorderError::DeserializeError(format!(
+                        "Shape mismatch for tensor '{path}': Actual {shape:?}, Expected {expected:?}"
+                    )))
+                }
+                _ => Ok(()),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_collected_shapes_are_expected() {
+        let mut shapes = ShapeCheck::collect();
+        shapes.check("weight", &[2, 3]).unwrap();
+
+        let mut shapes = ShapeCheck::expect(shapes.into_shapes());
+
+        assert!(shapes.check("weight", &[2, 3]).is_ok());
+        assert!(shapes.check("bias", &[3]).is_ok());
+        assert!(matches!(
+            shapes.check("weight", &[3, 2]),
+            Err(RecorderError::DeserializeError(message))
+                if message == "Shape mismatch for tensor 'weight': Actual [3, 2], Expected [2, 3]"
+        ));
+    }
+
+    #[test]
+    fn test_ignored_shapes_are_not_collected() {
+        let mut shapes = ShapeCheck::ignore();
+        shapes.check("weight", &[2, 3]).unwrap();
+
+        assert_eq!(shapes.into_shapes(), BTreeMap::new());
+        assert_eq!(ShapeCheck::collect().into_shapes(), BTreeMap::new());
+        assert!(
+            ShapeCheck::expect(BTreeMap::from([("weight".to_string(), vec![2])]))
+                .check("weight", &[2])
+                .is_ok()
+        );
+    }
+}
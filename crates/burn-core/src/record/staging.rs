@@ -0,0 +1,233 @@
+use super::{bin_config, RecorderError};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use burn_tensor::DataSerialize;
+use core::cell::RefCell;
+use core::marker::PhantomData;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use serde::{de::DeserializeOwned, Serialize};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+use std::path::PathBuf;
+
+std::thread_local! {
+    static STAGING_ERROR: RefCell<Option<Option<String>>> = const { RefCell::new(None) };
+}
+
+/// Catch the errors reading back the staged tensors converted on the current thread while the
+/// scope is alive, so that the conversion of the record returns the first one instead of
+/// panicking.
+pub(crate) struct StagingErrorScope {
+    previous: Option<Option<String>>,
+}
+
+impl StagingErrorScope {
+    /// Start catching the errors.
+    pub(crate) fn start() -> Self {
+        let previous = STAGING_ERROR.with(|state| state.replace(Some(None)));
+
+        Self { previous }
+    }
+
+    /// Stop the scope, returning the first error caught while it was alive.
+    pub(crate) fn finish(self) -> Result<(), RecorderError> {
+        match STAGING_ERROR
+            .with(|state| state.borrow_mut().take())
+            .flatten()
+        {
+            Some(message) => Err(RecorderError::DeserializeError(message)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for StagingErrorScope {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        STAGING_ERROR.with(|state| state.replace(previous));
+    }
+}
+
+/// Report an error reading back the staged tensor at the given path, caught by the alive
+/// scope, or panic without one.
+pub(crate) fn staging_failed(err: String, path: &str) {
+    let path = if path.is_empty() { "<root>" } else { path };
+    let message = format!("{err} at path {path}");
+
+    let uncaught = STAGING_ERROR.with(|state| match state.borrow_mut().as_mut() {
+        Some(error) => {
+            error.get_or_insert(message);
+            None
+        }
+        None => Some(message),
+    });
+
+    if let Some(message) = uncaught {
+        panic!("{message}");
+    }
+}
+
+/// Values of a loaded tensor staged in a temporary file instead of a single host buffer, when
+/// they exceed the [memory budget](super::PrecisionSettings::MEMORY_BUDGET).
+///
+/// The values are written chunk by chunk as they are deserialized, and are read back one chunk
+/// at a time, so that a chunk is the largest buffer holding them.
+#[derive(Debug)]
+pub(crate) struct StagedData<E> {
+    pub(crate) shape: Vec<usize>,
+    file: Arc<StagingFile>,
+    chunks: Vec<usize>,
+    _elem: PhantomData<E>,
+}
+
+impl<E> Clone for StagedData<E> {
+    fn clone(&self) -> Self {
+        Self {
+            shape: self.shape.clone(),
+            file: self.file.clone(),
+            chunks: self.chunks.clone(),
+            _elem: PhantomData,
+        }
+    }
+}
+
+impl<E: DeserializeOwned> StagedData<E> {
+    /// The number of values of the tensor.
+    pub(crate) fn num_values(&self) -> usize {
+        self.chunks.iter().sum()
+    }
+
+    /// Read the staged chunks, in order.
+    pub(crate) fn chunks(&self) -> impl Iterator<Item = Result<Vec<E>, String>> + '_ {
+        let mut reader = File::open(&self.file.path)
+            .map(BufReader::new)
+            .map_err(|err| format!("Unable to read the staged tensor: {err}"));
+
+        self.chunks.iter().map(move |len| {
+            let reader = reader.as_mut().map_err(|err| err.clone())?;
+            let values: Vec<E> = bincode::serde::decode_from_std_read(reader, bin_config())
+                .map_err(|err| format!("Unable to read the staged tensor: {err}"))?;
+
+            if values.len() != *len {
+                return Err(format!(
+                    "The staged chunk has {} values, expected {len}",
+                    values.len()
+                ));
+            }
+
+            Ok(values)
+        })
+    }
+
+    /// Read all the staged values back into a single host buffer.
+    pub(crate) fn into_data(self) -> Result<DataSerialize<E>, String> {
+        let mut value = Vec::with_capacity(self.num_values());
+
+        for chunk in self.chunks() {
+            value.extend(chunk?);
+        }
+
+        Ok(DataSerialize::new(value, self.shape))
+    }
+}
+
+/// Writer staging the chunks of a tensor being deserialized.
+pub(crate) struct StagingWriter<E> {
+    writer: BufWriter<File>,
+    file: StagingFile,
+    chunks: Vec<usize>,
+    _elem: PhantomData<E>,
+}
+
+impl<E: Serialize> StagingWriter<E> {
+    /// Create a temporary file staging the values.
+    pub(crate) fn create() -> Result<Self, String> {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let path = std::env::temp_dir().join(format!(
+            "burn-staging-{}-{}.tmp",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let writer = File::create(&path)
+            .map(BufWriter::new)
+            .map_err(|err| format!("Unable to stage the tensor: {err}"))?;
+
+        Ok(Self {
+            writer,
+            file: StagingFile { path },
+            chunks: Vec::new(),
+            _elem: PhantomData,
+        })
+    }
+
+    /// Append a chunk of values.
+    pub(crate) fn push(&mut self, values: &[E]) -> Result<(), String> {
+        bincode::serde::encode_into_std_write(values, &mut self.writer, bin_config())
+            .map_err(|err| format!("Unable to stage the tensor: {err}"))?;
+        self.chunks.push(values.len());
+
+        Ok(())
+    }
+
+    /// The number of values staged so far.
+    pub(crate) fn num_values(&self) -> usize {
+        self.chunks.iter().sum()
+    }
+
+    /// Flush the staged values once the last chunk is deserialized, the shape of the tensor
+    /// being set by the caller.
+    pub(crate) fn finish(mut self) -> Result<StagedData<E>, String> {
+        self.writer
+            .flush()
+            .map_err(|err| format!("Unable to stage the tensor: {err}"))?;
+
+        Ok(StagedData {
+            shape: Vec::new(),
+            file: Arc::new(self.file),
+            chunks: self.chunks,
+            _elem: PhantomData,
+        })
+    }
+}
+
+/// Temporary file removed once the staged values are dropped.
+#[derive(Debug)]
+struct StagingFile {
+    path: PathBuf,
+}
+
+impl Drop for StagingFile {
+    fn drop(&mut self) {
+        std::fs::remove_file(&self.path).ok();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::{tensor::FloatTensorSerde, FullPrecisionSettings, Record};
+    use crate::TestBackend;
+    use burn_tensor::Tensor;
+
+    #[test]
+    fn test_unreadable_staged_tensor_is_caught_by_the_scope() {
+        let mut writer = StagingWriter::<f32>::create().unwrap();
+        writer.push(&[1.0, 2.0]).unwrap();
+        let mut data = writer.finish().unwrap();
+        data.shape = alloc::vec![2];
+        std::fs::remove_file(&data.file.path).unwrap();
+        let item = FloatTensorSerde::<FullPrecisionSettings>::from(data);
+
+        let scope = StagingErrorScope::start();
+        let _tensor: Tensor<TestBackend, 1> = Record::from_item(item, &Default::default());
+        let result = scope.finish();
+
+        assert!(matches!(
+            result,
+            Err(RecorderError::DeserializeError(message))
+                if message.starts_with("Unable to read the staged tensor")
+                    && message.ends_with("at path <root>")
+        ));
+    }
+}
@@ -0,0 +1,205 @@
+use super::{Recorder, RecorderError, SelfDescribingRecorder, StreamFileRecorder};
+use burn_tensor::backend::Backend;
+use core::marker::PhantomData;
+use serde::{de::DeserializeOwned, Serialize};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+/// Recorder writing records to any [writer](Write) and reading them from any seekable
+/// [reader](Read), in the format of the given [file recorder](StreamFileRecorder), e.g. to upload
+/// a checkpoint or to pipe it through a compressor without staging it on disk.
+///
+/// The records are written in a single pass, but loading a record can read it more than once,
+/// e.g. to report the metadata of a record that can't be loaded, so the reader must be seekable:
+/// it's rewound before each read to its position when the [handle](RecordReader) was created.
+pub struct StreamRecorder<F, W, R> {
+    recorder: F,
+    _stream: PhantomData<fn() -> (W, R)>,
+}
+
+impl<F, W, R> StreamRecorder<F, W, R> {
+    /// Create a recorder using the format of the given file recorder.
+    pub fn new(recorder: F) -> Self {
+        Self {
+            recorder,
+            _stream: PhantomData,
+        }
+    }
+}
+
+impl<F: Default, W, R> Default for StreamRecorder<F, W, R> {
+    fn default() -> Self {
+        Self::new(F::default())
+    }
+}
+
+impl<F: Clone, W, R> Clone for StreamRecorder<F, W, R> {
+    fn clone(&self) -> Self {
+        Self::new(self.recorder.clone())
+    }
+}
+
+impl<F: core::fmt::Debug, W, R> core::fmt::Debug for StreamRecorder<F, W, R> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("StreamRecorder")
+            .field("recorder", &self.recorder)
+            .finish()
+    }
+}
+
+/// Handle to the writer of a [StreamRecorder], shared by its clones so that the writer can be
+/// taken back once the record is written.
+#[derive(Debug)]
+pub struct RecordWriter<W> {
+    writer: Arc<Mutex<W>>,
+}
+
+impl<W> RecordWriter<W> {
+    /// Create a handle to the given writer.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer: Arc::new(Mutex::new(writer)),
+        }
+    }
+
+    /// Take the writer back, `None` when another clone of the handle is still alive.
+    pub fn into_inner(self) -> Option<W> {
+        Arc::try_unwrap(self.writer)
+            .ok()
+            .map(|writer| writer.into_inner().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    fn lock(&self) -> MutexGuard<'_, W> {
+        self.writer.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<W> Clone for RecordWriter<W> {
+    fn clone(&self) -> Self {
+        Self {
+            writer: self.writer.clone(),
+        }
+    }
+}
+
+/// Handle to the reader of a [StreamRecorder], shared by its clones.
+#[derive(Debug)]
+pub struct RecordReader<R> {
+    reader: Arc<Mutex<R>>,
+    start: u64,
+}
+
+impl<R: Seek> RecordReader<R> {
+    /// Create a handle to the given reader, the record starting at its current position.
+    pub fn new(mut reader: R) -> Result<Self, RecorderError> {
+        let start = reader
+            .stream_position()
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        Ok(Self {
+            reader: Arc::new(Mutex::new(reader)),
+            start,
+        })
+    }
+}
+
+impl<R> Clone for RecordReader<R> {
+    fn clone(&self) -> Self {
+        Self {
+            reader: self.reader.clone(),
+            start: self.start,
+        }
+    }
+}
+
+impl<F, W, R, B> Recorder<B> for StreamRecorder<F, W, R>
+where
+    F: StreamFileRecorder<B>,
+    W: Write + Send,
+    R: Read + Seek + Send,
+    B: Backend,
+{
+    type Settings = <F as Recorder<B>>::Settings;
+    type RecordArgs = RecordWriter<W>;
+    type RecordOutput = ();
+    type LoadArgs = RecordReader<R>;
+    const SELF_DESCRIBING: bool = <F as Recorder<B>>::SELF_DESCRIBING;
+
+    fn save_item<I: Serialize>(
+        &self,
+        item: I,
+        writer: Self::RecordArgs,
+    ) -> Result<(), RecorderError> {
+        let mut writer = writer.lock();
+
+        <F as StreamFileRecorder<B>>::write_item(&item, &mut *writer)?
+            .flush()
+            .map_err(|err| RecorderError::Unknown(err.to_string()))
+    }
+
+    fn load_item<I: DeserializeOwned>(&self, reader: Self::LoadArgs) -> Result<I, RecorderError> {
+        let start = reader.start;
+        let mut reader = reader.reader.lock().unwrap_or_else(PoisonError::into_inner);
+        reader
+            .seek(SeekFrom::Start(start))
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        <F as StreamFileRecorder<B>>::read_item(&mut *reader)
+    }
+}
+
+impl<F, W, R, B> SelfDescribingRecorder<B> for StreamRecorder<F, W, R>
+where
+    F: StreamFileRecorder<B> + SelfDescribingRecorder<B>,
+    W: Write + Send,
+    R: Read + Seek + Send,
+    B: Backend,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::{FileRecorder, FullPrecisionSettings, NamedMpkFileRecorder};
+    use crate::TestBackend;
+    use burn_tensor::{Int, Tensor};
+    use std::io::Cursor;
+
+    type TestRecorder =
+        StreamRecorder<NamedMpkFileRecorder<FullPrecisionSettings>, Vec<u8>, Cursor<Vec<u8>>>;
+    type TensorsRecord = (Tensor<TestBackend, 2>, Tensor<TestBackend, 1, Int>);
+
+    #[test]
+    fn test_stream_recorder_writes_and_reads_in_memory_buffers() {
+        let device = Default::default();
+        let recorder = TestRecorder::default();
+        let tensors: TensorsRecord = (
+            Tensor::from_floats([[1.0, -2.5], [3.25, 0.0]], &device),
+            Tensor::from_ints([1, -2, 3], &device),
+        );
+
+        // The buffer is prefixed with bytes that aren't part of the record.
+        let writer = RecordWriter::new(b"prefix".to_vec());
+        recorder.record(tensors.clone(), writer.clone()).unwrap();
+        let bytes = writer.into_inner().unwrap();
+
+        let mut reader = Cursor::new(bytes.clone());
+        reader.set_position(6);
+        let loaded: TensorsRecord = recorder
+            .load(RecordReader::new(reader).unwrap(), &device)
+            .unwrap();
+        assert_eq!(tensors.0.to_data(), loaded.0.into_data());
+        assert_eq!(tensors.1.to_data(), loaded.1.into_data());
+
+        // The stream has the content of a record file.
+        let mut file = std::env::temp_dir().join("burn_test_stream_recorder");
+        file.set_extension(
+            <NamedMpkFileRecorder<FullPrecisionSettings> as FileRecorder<TestBackend>>::file_extension(),
+        );
+        std::fs::write(&file, &bytes[6..]).unwrap();
+        let loaded: TensorsRecord = NamedMpkFileRecorder::<FullPrecisionSettings>::default()
+            .load(file, &device)
+            .unwrap();
+        assert_eq!(tensors.0.into_data(), loaded.0.into_data());
+    }
+}
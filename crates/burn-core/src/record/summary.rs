@@ -0,0 +1,98 @@
+use serde_json::{Map, Number, Value};
+
+/// Replace the tensor data of a serialized record with its shape and a statistical summary,
+/// sorting the fields by name so that the paths are stable between records.
+pub(crate) fn summarize_record(value: Value) -> Value {
+    match value {
+        Value::Object(map) => match TensorData::from_map(&map) {
+            Some(data) => data.summary(),
+            None => {
+                let mut fields: Vec<(String, Value)> = map.into_iter().collect();
+                fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+                Value::Object(
+                    fields
+                        .into_iter()
+                        .map(|(key, value)| (key, summarize_record(value)))
+                        .collect(),
+                )
+            }
+        },
+        Value::Array(values) => Value::Array(values.into_iter().map(summarize_record).collect()),
+        value => value,
+    }
+}
+
+/// Serialized tensor data, with the `value` and `shape` fields of
+/// [DataSerialize](burn_tensor::DataSerialize).
+pub(crate) struct TensorData<'a> {
+    values: &'a [Value],
+    shape: &'a [Value],
+}
+
+impl<'a> TensorData<'a> {
+    pub(crate) fn from_map(map: &'a Map<String, Value>) -> Option<Self> {
+        if map.len() != 2 {
+            return None;
+        }
+
+        match (map.get("value"), map.get("shape")) {
+            (Some(Value::Array(values)), Some(Value::Array(shape)))
+                if shape.iter().all(Value::is_u64) =>
+            {
+                Some(Self { values, shape })
+            }
+            _ => None,
+        }
+    }
+
+    /// The shape of the tensor.
+    pub(crate) fn shape(&self) -> Vec<usize> {
+        self.shape
+            .iter()
+            .filter_map(Value::as_u64)
+            .map(|dim| dim as usize)
+            .collect()
+    }
+
+    /// The values of the tensor as floats, booleans being read as 0 and 1.
+    ///
+    /// Non finite values, serialized as null, are read as NaN.
+    pub(crate) fn values(&self) -> Vec<f64> {
+        self.values
+            .iter()
+            .map(|value| match value {
+                Value::Bool(value) => *value as u8 as f64,
+                value => value.as_f64().unwrap_or(f64::NAN),
+            })
+            .collect()
+    }
+
+    fn summary(&self) -> Value {
+        // Non finite values are ignored.
+        let values: Vec<f64> = self
+            .values()
+            .into_iter()
+            .filter(|value| !value.is_nan())
+            .collect();
+
+        let stat = |value: Option<f64>| {
+            value
+                .and_then(Number::from_f64)
+                .map(Value::Number)
+                .unwrap_or(Value::Null)
+        };
+        let min = values.iter().copied().reduce(f64::min);
+        let max = values.iter().copied().reduce(f64::max);
+        let mean = (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64);
+
+        let mut summary = Map::new();
+        summary.insert("max".to_string(), stat(max));
+        summary.insert("mean".to_string(), stat(mean));
+        summary.insert("min".to_string(), stat(min));
+        summary.insert("numel".to_string(), Value::from(self.values.len()));
+        summary.insert("shape".to_string(), Value::Array(self.shape.to_vec()));
+
+        Value::Object(summary)
+    }
+}
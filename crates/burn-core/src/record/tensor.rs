@@ -1,12 +1,44 @@
-use super::{PrecisionSettings, Record};
-use burn_tensor::{backend::Backend, Bool, DataSerialize, Int, Tensor};
+use core::marker::PhantomData;
+
+#[cfg(feature = "std")]
+use super::chunk::{deserialize_staged_tensor_data, LoadedData};
+use super::chunk::{deserialize_tensor_data, serialize_tensor_data};
+use super::compression::{deserialize_data, serialize_data};
+use super::quantization::QuantizedData;
+#[cfg(feature = "std")]
+use super::staging::StagedData;
+use super::ShapeCheck;
+use super::{PrecisionSettings, Record, RecordQuantization, RecorderError};
+use alloc::{
+    borrow::Cow,
+    format,
+    string::{String, ToString},
+};
+use burn_tensor::{
+    backend::Backend, Bool, DType, DataSerialize, Element, ElementConversion, Int, Tensor,
+};
+use num_traits::ToPrimitive;
 use serde::{Deserialize, Serialize};
 
 /// This struct implements serde to lazily serialize and deserialize a float tensor
 /// using the given [record settings](RecordSettings).
-#[derive(new, Clone, Debug)]
+///
+/// Quantized tensors are kept quantized until the tensor is created, so that backends with
+/// [quantized storage](burn_tensor::ops::FloatTensorOps::float_from_quantized) receive the
+/// quantized values instead of float values.
+#[derive(Clone, Debug)]
 pub struct FloatTensorSerde<S: PrecisionSettings> {
-    data: DataSerialize<S::FloatElem>,
+    data: FloatTensorData<S::FloatElem>,
+}
+
+/// The data of a float tensor, stored on the host.
+#[derive(Clone, Debug)]
+enum FloatTensorData<E> {
+    Float(DataSerialize<E>),
+    Quantized(QuantizedData),
+    /// Staged in a file, exceeding the [memory budget](PrecisionSettings::MEMORY_BUDGET).
+    #[cfg(feature = "std")]
+    Staged(StagedData<E>),
 }
 
 /// This struct implements serde to lazily serialize and deserialize an int tensor
@@ -17,9 +49,68 @@ pub struct IntTensorSerde<S: PrecisionSettings> {
 }
 
 /// This struct implements serde to lazily serialize and deserialize an bool tensor.
+///
+/// The [record settings](RecordSettings) are only used to select the compression codec.
 #[derive(new, Clone, Debug)]
-pub struct BoolTensorSerde {
+pub struct BoolTensorSerde<S: PrecisionSettings> {
     data: DataSerialize<bool>,
+    _settings: PhantomData<S>,
+}
+
+impl<S: PrecisionSettings> FloatTensorSerde<S> {
+    /// Create the item of a tensor with the given float data.
+    pub fn new(data: DataSerialize<S::FloatElem>) -> Self {
+        Self {
+            data: FloatTensorData::Float(data),
+        }
+    }
+
+    /// The data of the tensor, stored on the host, dequantized if needed.
+    ///
+    /// Reading back the values of a staged tensor can fail.
+    pub(crate) fn into_data(self) -> Result<DataSerialize<S::FloatElem>, String> {
+        match self.data {
+            FloatTensorData::Float(data) => Ok(data),
+            FloatTensorData::Quantized(data) => Ok(data.dequantize()),
+            #[cfg(feature = "std")]
+            FloatTensorData::Staged(data) => data.into_data(),
+        }
+    }
+
+    /// The shape of the tensor.
+    pub(crate) fn shape(&self) -> &[usize] {
+        match &self.data {
+            FloatTensorData::Float(data) => &data.shape,
+            FloatTensorData::Quantized(data) => &data.data.shape,
+            #[cfg(feature = "std")]
+            FloatTensorData::Staged(data) => &data.shape,
+        }
+    }
+
+    /// The number of values of the tensor.
+    pub(crate) fn num_values(&self) -> usize {
+        match &self.data {
+            FloatTensorData::Float(data) => data.value.len(),
+            FloatTensorData::Quantized(data) => data.data.value.len(),
+            #[cfg(feature = "std")]
+            FloatTensorData::Staged(data) => data.num_values(),
+        }
+    }
+
+    /// Whether the tensor data is quantized.
+    #[cfg(test)]
+    pub(crate) fn is_quantized(&self) -> bool {
+        matches!(self.data, FloatTensorData::Quantized(_))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<S: PrecisionSettings> From<StagedData<S::FloatElem>> for FloatTensorSerde<S> {
+    fn from(data: StagedData<S::FloatElem>) -> Self {
+        Self {
+            data: FloatTensorData::Staged(data),
+        }
+    }
 }
 
 // --- SERDE IMPLEMENTATIONS --- //
@@ -29,7 +120,33 @@ impl<S: PrecisionSettings> Serialize for FloatTensorSerde<S> {
     where
         Se: serde::Serializer,
     {
-        self.data.serialize(serializer)
+        #[cfg(feature = "std")]
+        super::progress::tensor_processed();
+
+        // A loaded quantized tensor is quantized again to the same values when it's saved.
+        let data = match &self.data {
+            FloatTensorData::Float(data) => Cow::Borrowed(data),
+            FloatTensorData::Quantized(data) => Cow::Owned(data.clone().dequantize()),
+            // The staged values are read back, e.g. when a loaded record is saved again.
+            #[cfg(feature = "std")]
+            FloatTensorData::Staged(data) => Cow::Owned(
+                data.clone()
+                    .into_data()
+                    .map_err(serde::ser::Error::custom)?,
+            ),
+        };
+        let data = S::LAYOUT.encode(&data).map_err(serde::ser::Error::custom)?;
+
+        match S::QUANTIZATION {
+            RecordQuantization::None => {
+                serialize_values::<_, S, _>(data.as_ref(), S::FloatElem::dtype(), serializer)
+            }
+            RecordQuantization::Int8 => serialize_data(
+                &QuantizedData::quantize(data.as_ref()),
+                S::COMPRESSION,
+                serializer,
+            ),
+        }
     }
 }
 
@@ -38,9 +155,44 @@ impl<'de, S: PrecisionSettings> Deserialize<'de> for FloatTensorSerde<S> {
     where
         De: serde::Deserializer<'de>,
     {
-        let data = DataSerialize::<S::FloatElem>::deserialize(deserializer)?;
+        let data = match S::QUANTIZATION {
+            #[cfg(feature = "std")]
+            RecordQuantization::None => {
+                match deserialize_staged_tensor_data::<S::FloatElem, S, De>(deserializer)? {
+                    LoadedData::Host(data) => {
+                        let data = S::LAYOUT.decode(data).map_err(serde::de::Error::custom)?;
 
-        Ok(Self::new(data))
+                        FloatTensorData::Float(data)
+                    }
+                    // Reordering the values would need them all in memory.
+                    LoadedData::Staged(_) if S::LAYOUT != super::RecordLayout::RowMajor => {
+                        return Err(serde::de::Error::custom(
+                            "Only tensors with a row-major layout can be staged, the tensor \
+                            exceeds the memory budget",
+                        ));
+                    }
+                    LoadedData::Staged(data) => return Ok(data.into()),
+                }
+            }
+            #[cfg(not(feature = "std"))]
+            RecordQuantization::None => {
+                let data = deserialize_tensor_data::<S::FloatElem, S, De>(deserializer)?;
+                let data = S::LAYOUT.decode(data).map_err(serde::de::Error::custom)?;
+
+                FloatTensorData::Float(data)
+            }
+            RecordQuantization::Int8 => {
+                let mut quantized =
+                    deserialize_data::<QuantizedData, De>(S::COMPRESSION, deserializer)?;
+                quantized.data = S::LAYOUT
+                    .decode(quantized.data)
+                    .map_err(serde::de::Error::custom)?;
+
+                FloatTensorData::Quantized(quantized)
+            }
+        };
+
+        Ok(Self { data })
     }
 }
 
@@ -49,7 +201,14 @@ impl<S: PrecisionSettings> Serialize for IntTensorSerde<S> {
     where
         Se: serde::Serializer,
     {
-        self.data.serialize(serializer)
+        #[cfg(feature = "std")]
+        super::progress::tensor_processed();
+
+        let data = S::LAYOUT
+            .encode(&self.data)
+            .map_err(serde::ser::Error::custom)?;
+
+        serialize_values::<_, S, _>(data.as_ref(), S::IntElem::dtype(), serializer)
     }
 }
 
@@ -58,31 +217,64 @@ impl<'de, S: PrecisionSettings> Deserialize<'de> for IntTensorSerde<S> {
     where
         De: serde::Deserializer<'de>,
     {
-        let data = DataSerialize::<S::IntElem>::deserialize(deserializer)?;
+        let data = deserialize_tensor_data::<S::IntElem, S, De>(deserializer)?;
+        let data = S::LAYOUT.decode(data).map_err(serde::de::Error::custom)?;
+
         Ok(Self::new(data))
     }
 }
 
-impl Serialize for BoolTensorSerde {
+impl<S: PrecisionSettings> Serialize for BoolTensorSerde<S> {
     fn serialize<Se>(&self, serializer: Se) -> Result<Se::Ok, Se::Error>
     where
         Se: serde::Serializer,
     {
-        self.data.serialize(serializer)
+        #[cfg(feature = "std")]
+        super::progress::tensor_processed();
+
+        let data = S::LAYOUT
+            .encode(&self.data)
+            .map_err(serde::ser::Error::custom)?;
+
+        serialize_values::<_, S, _>(data.as_ref(), DType::Bool, serializer)
     }
 }
 
-impl<'de> Deserialize<'de> for BoolTensorSerde {
+impl<'de, S: PrecisionSettings> Deserialize<'de> for BoolTensorSerde<S> {
     fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
     where
         De: serde::Deserializer<'de>,
     {
-        let data = DataSerialize::<bool>::deserialize(deserializer)?;
+        let data = deserialize_tensor_data::<bool, S, De>(deserializer)?;
+        let data = S::LAYOUT.decode(data).map_err(serde::de::Error::custom)?;
 
         Ok(Self::new(data))
     }
 }
 
+/// Serialize the tensor data, writing its values to a blob instead when the tensor is recorded
+/// by an [external data recorder](super::ExternalDataFileRecorder).
+#[cfg_attr(not(feature = "std"), allow(unused_variables))]
+fn serialize_values<E, S, Se>(
+    data: &DataSerialize<E>,
+    dtype: DType,
+    serializer: Se,
+) -> Result<Se::Ok, Se::Error>
+where
+    E: Serialize,
+    S: PrecisionSettings,
+    Se: serde::Serializer,
+{
+    #[cfg(feature = "std")]
+    if let Some(reference) = super::external::tensor_externalized::<E, S>(data, dtype)
+        .map_err(serde::ser::Error::custom)?
+    {
+        return reference.serialize(serializer);
+    }
+
+    serialize_tensor_data::<_, S, _>(data, serializer)
+}
+
 // --- RECORD IMPLEMENTATIONS --- //
 
 impl<B: Backend, const D: usize> Record<B> for Tensor<B, D> {
@@ -97,7 +289,75 @@ impl<B: Backend, const D: usize> Record<B> for Tensor<B, D> {
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
-        Tensor::from_data(item.data.convert::<B::FloatElem>(), device)
+        Self::from_item_with_path(item, device, "")
+    }
+
+    fn from_item_with_path<S: PrecisionSettings>(
+        item: Self::Item<S>,
+        device: &B::Device,
+        path: &str,
+    ) -> Self {
+        // Each tensor is routed on its own, so a record can mix quantized and float tensors.
+        let tensor = match item.data {
+            FloatTensorData::Float(data) => {
+                check_data_shape::<D>(&data.shape, data.value.len(), path)
+                    .unwrap_or_else(|err| panic!("{err}"));
+                #[cfg(feature = "std")]
+                super::dtype_report::tensor_converted::<S::FloatElem, B::FloatElem>(path);
+
+                Tensor::from_data(data.convert::<B::FloatElem>(), device)
+            }
+            FloatTensorData::Quantized(QuantizedData { scale, data }) => {
+                check_data_shape::<D>(&data.shape, data.value.len(), path)
+                    .unwrap_or_else(|err| panic!("{err}"));
+                #[cfg(feature = "std")]
+                super::dtype_report::tensor_converted::<i8, B::FloatElem>(path);
+
+                Tensor::from_quantized(data.into(), scale, device)
+            }
+            #[cfg(feature = "std")]
+            FloatTensorData::Staged(data) => {
+                check_data_shape::<D>(&data.shape, data.num_values(), path)
+                    .unwrap_or_else(|err| panic!("{err}"));
+                super::dtype_report::tensor_converted::<S::FloatElem, B::FloatElem>(path);
+
+                upload_staged(data, device, path)
+            }
+        };
+
+        #[cfg(feature = "std")]
+        super::progress::tensor_processed();
+
+        tensor
+    }
+
+    fn check_shapes<S: PrecisionSettings>(
+        item: &Self::Item<S>,
+        path: &str,
+        shapes: &mut ShapeCheck,
+    ) -> Result<(), RecorderError> {
+        check_data_shape::<D>(item.shape(), item.num_values(), path)
+            .map_err(RecorderError::DeserializeError)?;
+
+        shapes.check(path, item.shape())
+    }
+
+    fn check_finite<S: PrecisionSettings>(
+        item: &Self::Item<S>,
+        path: &str,
+    ) -> Result<(), RecorderError> {
+        match &item.data {
+            FloatTensorData::Float(data) => check_finite_values::<S>(data, path),
+            // Quantized values are always finite.
+            FloatTensorData::Quantized(_) => Ok(()),
+            #[cfg(feature = "std")]
+            FloatTensorData::Staged(data) => data.chunks().try_for_each(|chunk| {
+                let chunk = chunk.map_err(RecorderError::Unknown)?;
+                let len = chunk.len();
+
+                check_finite_values::<S>(&DataSerialize::new(chunk, vec![len]), path)
+            }),
+        }
     }
 }
 
@@ -113,12 +373,62 @@ impl<B: Backend, const D: usize> Record<B> for Tensor<B, D, Int> {
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
-        Tensor::from_data(item.data.convert(), device)
+        Self::from_item_with_path(item, device, "")
+    }
+
+    fn from_item_with_path<S: PrecisionSettings>(
+        item: Self::Item<S>,
+        device: &B::Device,
+        path: &str,
+    ) -> Self {
+        check_data_shape::<D>(&item.data.shape, item.data.value.len(), path)
+            .unwrap_or_else(|err| panic!("{err}"));
+        #[cfg(feature = "std")]
+        super::dtype_report::tensor_converted::<S::IntElem, B::IntElem>(path);
+
+        let tensor = Tensor::from_data(item.data.convert(), device);
+
+        #[cfg(feature = "std")]
+        super::progress::tensor_processed();
+
+        tensor
+    }
+
+    fn check_int_overflow<S: PrecisionSettings>(
+        item: &Self::Item<S>,
+        path: &str,
+    ) -> Result<(), RecorderError> {
+        check_int_values::<S::IntElem, B::IntElem>(&item.data, path)
+    }
+
+    fn check_shapes<S: PrecisionSettings>(
+        item: &Self::Item<S>,
+        path: &str,
+        shapes: &mut ShapeCheck,
+    ) -> Result<(), RecorderError> {
+        check_data_shape::<D>(&item.data.shape, item.data.value.len(), path)
+            .map_err(RecorderError::DeserializeError)?;
+
+        shapes.check(path, &item.data.shape)
+    }
+
+    fn offset_indices<S: PrecisionSettings>(item: &mut Self::Item<S>, path: &str, offset: i64) {
+        if !S::INDEX_FIELDS.is_some_and(|is_index| is_index(path)) {
+            return;
+        }
+
+        item.data.value.iter_mut().for_each(|value| {
+            *value = value
+                .to_i64()
+                .unwrap_or_default()
+                .saturating_add(offset)
+                .elem();
+        });
     }
 }
 
 impl<B: Backend, const D: usize> Record<B> for Tensor<B, D, Bool> {
-    type Item<S: PrecisionSettings> = BoolTensorSerde;
+    type Item<S: PrecisionSettings> = BoolTensorSerde<S>;
 
     fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
         #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
@@ -129,6 +439,173 @@ impl<B: Backend, const D: usize> Record<B> for Tensor<B, D, Bool> {
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
-        Tensor::from_data(item.data, device)
+        Self::from_item_with_path(item, device, "")
     }
+
+    fn from_item_with_path<S: PrecisionSettings>(
+        item: Self::Item<S>,
+        device: &B::Device,
+        path: &str,
+    ) -> Self {
+        check_data_shape::<D>(&item.data.shape, item.data.value.len(), path)
+            .unwrap_or_else(|err| panic!("{err}"));
+        let tensor = Tensor::from_data(item.data, device);
+
+        #[cfg(feature = "std")]
+        super::progress::tensor_processed();
+
+        tensor
+    }
+
+    fn check_shapes<S: PrecisionSettings>(
+        item: &Self::Item<S>,
+        path: &str,
+        shapes: &mut ShapeCheck,
+    ) -> Result<(), RecorderError> {
+        check_data_shape::<D>(&item.data.shape, item.data.value.len(), path)
+            .map_err(RecorderError::DeserializeError)?;
+
+        shapes.check(path, &item.data.shape)
+    }
+}
+
+/// Make sure each integer value can be represented by the target element `T`, reporting the first
+/// value that can't with the path of the tensor.
+pub(crate) fn check_int_values<E: Element, T: Element>(
+    data: &DataSerialize<E>,
+    path: &str,
+) -> Result<(), RecorderError> {
+    let (min, max) = match T::dtype() {
+        DType::I64 => (i64::MIN as i128, i64::MAX as i128),
+        DType::I32 => (i32::MIN as i128, i32::MAX as i128),
+        DType::I16 => (i16::MIN as i128, i16::MAX as i128),
+        DType::I8 => (i8::MIN as i128, i8::MAX as i128),
+        DType::U64 => (0, u64::MAX as i128),
+        DType::U32 => (0, u32::MAX as i128),
+        DType::U8 => (0, u8::MAX as i128),
+        // Other elements aren't integers and are converted as is.
+        _ => return Ok(()),
+    };
+
+    let overflow = data
+        .value
+        .iter()
+        .find(|value| !matches!(value.to_i128(), Some(value) if (min..=max).contains(&value)));
+
+    match overflow {
+        Some(value) => {
+            let path = if path.is_empty() { "<root>" } else { path };
+
+            Err(RecorderError::IntegerOverflow(format!(
+                "The value {value} at path {path} can't be represented as {:?}",
+                T::dtype()
+            )))
+        }
+        None => Ok(()),
+    }
+}
+
+/// Make sure no float value is rejected by the [finite check](PrecisionSettings::CHECK_FINITE) of
+/// the settings, reporting the path of the tensor otherwise.
+pub(crate) fn check_finite_values<S: PrecisionSettings>(
+    data: &DataSerialize<S::FloatElem>,
+    path: &str,
+) -> Result<(), RecorderError> {
+    let rejected = data
+        .value
+        .iter()
+        .any(|value| S::CHECK_FINITE.rejects(value.to_f64().unwrap_or(f64::NAN)));
+
+    match rejected {
+        true => Err(RecorderError::NonFiniteValues {
+            path: match path.is_empty() {
+                true => "<root>".to_string(),
+                false => path.to_string(),
+            },
+        }),
+        false => Ok(()),
+    }
+}
+
+/// Deserialize a float tensor of rank `D` from its serialized value located at the given path.
+pub(crate) fn float_tensor_from_value<B: Backend, const D: usize, S: PrecisionSettings>(
+    value: serde_json::Value,
+    device: &B::Device,
+    path: &str,
+) -> Result<Tensor<B, D>, RecorderError> {
+    let item: FloatTensorSerde<S> = serde_json::from_value(value).map_err(|err| {
+        RecorderError::DeserializeError(format!("No float tensor at path {path}: {err}"))
+    })?;
+    <Tensor<B, D> as Record<B>>::check_shapes::<S>(&item, path, &mut ShapeCheck::ignore())?;
+
+    #[cfg(feature = "std")]
+    let staging = super::staging::StagingErrorScope::start();
+    let tensor = Tensor::from_item_with_path(item, device, path);
+    #[cfg(feature = "std")]
+    staging.finish()?;
+
+    Ok(tensor)
+}
+
+/// Upload the staged values to the device one chunk at a time, so that a chunk is the largest
+/// host buffer holding them.
+///
+/// A chunk that can't be read back is [reported](super::staging::staging_failed), the tensor
+/// being left with zeros.
+#[cfg(feature = "std")]
+#[allow(clippy::single_range_in_vec_init)]
+fn upload_staged<B: Backend, E: Element + serde::de::DeserializeOwned, const D: usize>(
+    data: StagedData<E>,
+    device: &B::Device,
+    path: &str,
+) -> Tensor<B, D> {
+    let mut tensor = Tensor::<B, 1>::empty([data.num_values()], device);
+    let mut start = 0;
+
+    let shape: [usize; D] = data.shape.clone().try_into().unwrap();
+
+    for chunk in data.chunks() {
+        let chunk = match chunk {
+            Ok(chunk) => chunk,
+            Err(err) => {
+                super::staging::staging_failed(err, path);
+                return Tensor::zeros(shape, device);
+            }
+        };
+        let end = start + chunk.len();
+        let values = Tensor::from_data(
+            DataSerialize::new(chunk, vec![end - start]).convert::<B::FloatElem>(),
+            device,
+        );
+
+        tensor = tensor.slice_assign([start..end], values);
+        start = end;
+    }
+
+    tensor.reshape(shape)
+}
+
+/// Make sure the tensor data can be loaded in a tensor of rank `D`, reporting the path of the
+/// tensor otherwise.
+pub(crate) fn check_data_shape<const D: usize>(
+    shape: &[usize],
+    num_values: usize,
+    path: &str,
+) -> Result<(), String> {
+    let path = if path.is_empty() { "<root>" } else { path };
+
+    if shape.len() != D {
+        return Err(format!(
+            "Expected a tensor of rank {D} at path {path}, got shape {shape:?}"
+        ));
+    }
+
+    let num_elements = shape.iter().product::<usize>();
+    if num_values != num_elements {
+        return Err(format!(
+            "Expected {num_elements} values for shape {shape:?} at path {path}, got {num_values}"
+        ));
+    }
+
+    Ok(())
 }
@@ -0,0 +1,533 @@
+use super::staging::StagingErrorScope;
+use super::tensor::FloatTensorSerde;
+use super::{bin_config, PrecisionSettings, Record, RecorderError, ShapeCheck};
+use burn_tensor::{backend::Backend, Tensor};
+use core::marker::PhantomData;
+use std::collections::BTreeMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+
+/// Marker written at the start of each entry of a tensor file.
+const ENTRY_MAGIC: &[u8; 4] = b"TNSR";
+/// Marker written at the start of the entries removing the tensor at their path.
+const REMOVED_MAGIC: &[u8; 4] = b"TDEL";
+/// Size of the entry header: the marker, the length of the path, the length of the tensor and its
+/// hash.
+const ENTRY_HEADER_SIZE: u64 = 24;
+
+/// File extension of the tensor files.
+const FILE_EXTENSION: &str = "tensors";
+
+/// Location of a tensor in a tensor file.
+#[derive(new, Debug, Clone, PartialEq, Eq)]
+pub struct TensorFileEntry {
+    /// Path of the tensor, e.g. `layers.0.keys`.
+    pub path: String,
+    /// Offset of the serialized tensor in the file, in bytes.
+    pub offset: u64,
+    /// Length of the serialized tensor, in bytes.
+    pub length: u64,
+    /// CRC32 hash of the serialized tensor.
+    ///
+    /// Tensors with different hashes are different, but different tensors can collide on the
+    /// same hash, so equal hashes only tell that the tensors are most likely identical, see
+    /// [changed_paths](TensorFileReader::changed_paths).
+    pub hash: u32,
+}
+
+/// Writer appending float tensors one at a time to a tensor file, e.g. the keys and values of
+/// an inference cache as they are computed, using the [bincode format](bincode).
+///
+/// Each tensor is written after the existing ones with its path, so appending a tensor never
+/// rewrites the file. The index of the paths is read from the entry headers when the file is
+/// opened, skipping over the tensors, and kept up to date by the writer. A tensor that was only
+/// partially written, e.g. when the process crashed during an append, is discarded when the file
+/// is opened.
+///
+/// Removing a tensor appends an entry marking it as removed, so the space of the removed tensors
+/// is only reclaimed by [compacting](compact_tensor_file) the file.
+#[derive(Debug)]
+pub struct TensorFileWriter<S: PrecisionSettings> {
+    writer: File,
+    index: BTreeMap<String, TensorFileEntry>,
+    end: u64,
+    _settings: PhantomData<S>,
+}
+
+impl<S: PrecisionSettings> TensorFileWriter<S> {
+    /// Open the tensor file to append tensors to, creating it if it doesn't exist.
+    pub fn open(mut file: PathBuf) -> Result<Self, RecorderError> {
+        file.set_extension(FILE_EXTENSION);
+
+        // Add parent directories if they don't exist
+        if let Some(parent) = file.parent() {
+            std::fs::create_dir_all(parent).ok();
+        }
+
+        let mut writer = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&file)
+            .map_err(io_error)?;
+
+        let (index, end) = read_index(&mut writer)?;
+        let size = writer.metadata().map_err(io_error)?.len();
+
+        if end < size {
+            log::warn!(
+                "Discarding {} bytes of a partially written tensor at the end of {}",
+                size - end,
+                file.display()
+            );
+            writer.set_len(end).map_err(io_error)?;
+        }
+
+        Ok(Self {
+            writer,
+            index,
+            end,
+            _settings: PhantomData,
+        })
+    }
+
+    /// Append the tensor at the given path.
+    ///
+    /// A path already in the file produces a [DuplicateTensor](RecorderError::DuplicateTensor)
+    /// error, leaving the file unchanged.
+    pub fn append_tensor<B: Backend, const D: usize>(
+        &mut self,
+        path: &str,
+        tensor: Tensor<B, D>,
+    ) -> Result<(), RecorderError> {
+        if self.index.contains_key(path) {
+            return Err(RecorderError::DuplicateTensor(path.to_string()));
+        }
+
+        let item = Record::<B>::into_item::<S>(tensor);
+        let payload = bincode::serde::encode_to_vec(&item, bin_config())
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        let offset = self.end + ENTRY_HEADER_SIZE + path.len() as u64;
+        let hash = crc32fast::hash(&payload);
+        self.write_entry(ENTRY_MAGIC, path, &payload, hash)?;
+        self.index.insert(
+            path.to_string(),
+            TensorFileEntry::new(path.to_string(), offset, payload.len() as u64, hash),
+        );
+
+        Ok(())
+    }
+
+    /// Remove the tensor at the given path, which can then be appended again.
+    ///
+    /// A path that isn't in the file produces a [TensorNotFound](RecorderError::TensorNotFound)
+    /// error, leaving the file unchanged.
+    pub fn remove_tensor(&mut self, path: &str) -> Result<(), RecorderError> {
+        if !self.index.contains_key(path) {
+            return Err(RecorderError::TensorNotFound(path.to_string()));
+        }
+
+        self.write_entry(REMOVED_MAGIC, path, &[], 0)?;
+        self.index.remove(path);
+
+        Ok(())
+    }
+
+    fn write_entry(
+        &mut self,
+        magic: &[u8; 4],
+        path: &str,
+        payload: &[u8],
+        hash: u32,
+    ) -> Result<(), RecorderError> {
+        // The header, the path and the tensor are written at once so that readers never see a
+        // header without its tensor, unless the write is interrupted.
+        let mut entry = entry_header(magic, path, payload.len() as u64, hash);
+        entry.extend_from_slice(payload);
+
+        self.writer
+            .seek(SeekFrom::Start(self.end))
+            .map_err(io_error)?;
+        self.writer.write_all(&entry).map_err(io_error)?;
+        self.writer.sync_data().map_err(io_error)?;
+        self.end += entry.len() as u64;
+
+        Ok(())
+    }
+
+    /// The paths of the tensors of the file, sorted.
+    pub fn paths(&self) -> Vec<String> {
+        self.index.keys().cloned().collect()
+    }
+}
+
+/// Reader of the tensors appended by a [TensorFileWriter], any of them being read without
+/// deserializing the others.
+#[derive(Debug)]
+pub struct TensorFileReader<S: PrecisionSettings> {
+    reader: File,
+    index: BTreeMap<String, TensorFileEntry>,
+    _settings: PhantomData<S>,
+}
+
+impl<S: PrecisionSettings> TensorFileReader<S> {
+    /// Open the tensor file and read its index.
+    pub fn open(mut file: PathBuf) -> Result<Self, RecorderError> {
+        file.set_extension(FILE_EXTENSION);
+        let mut reader = File::open(file).map_err(io_error)?;
+        let (index, _) = read_index(&mut reader)?;
+
+        Ok(Self {
+            reader,
+            index,
+            _settings: PhantomData,
+        })
+    }
+
+    /// The entries of the complete tensors of the file, sorted by path.
+    pub fn entries(&self) -> Vec<TensorFileEntry> {
+        self.index.values().cloned().collect()
+    }
+
+    /// Read the tensor at the given path.
+    ///
+    /// A path that isn't in the file produces a [TensorNotFound](RecorderError::TensorNotFound)
+    /// error, and a tensor with a rank other than `D` produces an error instead of a misshapen
+    /// tensor.
+    pub fn read_tensor<B: Backend, const D: usize>(
+        &mut self,
+        path: &str,
+        device: &B::Device,
+    ) -> Result<Tensor<B, D>, RecorderError> {
+        let entry = self
+            .index
+            .get(path)
+            .ok_or_else(|| RecorderError::TensorNotFound(path.to_string()))?;
+
+        let payload = read_payload(&mut self.reader, entry)?;
+        let (item, _): (FloatTensorSerde<S>, _) =
+            bincode::serde::decode_from_slice(&payload, bin_config())
+                .map_err(|err| RecorderError::DeserializeError(err.to_string()))?;
+        <Tensor<B, D> as Record<B>>::check_shapes::<S>(&item, path, &mut ShapeCheck::ignore())?;
+
+        let staging = StagingErrorScope::start();
+        let tensor = Tensor::from_item_with_path(item, device, path);
+        staging.finish()?;
+
+        Ok(tensor)
+    }
+
+    /// The paths of the tensors that differ between this file and the other one, sorted,
+    /// including the tensors present in one file only.
+    ///
+    /// Only the indices of the files are compared, so no tensor is read unless `verify` is set,
+    /// e.g. to find the few tensors changed by a fine-tuning between two large checkpoints. Two
+    /// different tensors colliding on the same [hash](TensorFileEntry::hash) are then considered
+    /// identical, which is unlikely but possible. With `verify`, the tensors with the same hash
+    /// are also compared byte for byte, reading both of them.
+    pub fn changed_paths(
+        &mut self,
+        other: &mut TensorFileReader<S>,
+        verify: bool,
+    ) -> Result<Vec<String>, RecorderError> {
+        let mut changed = Vec::new();
+
+        for (path, entry) in self.index.iter() {
+            let other_entry = match other.index.get(path) {
+                Some(other_entry) => other_entry,
+                None => {
+                    changed.push(path.clone());
+                    continue;
+                }
+            };
+
+            let same = entry.length == other_entry.length
+                && entry.hash == other_entry.hash
+                && (!verify
+                    || read_payload(&mut self.reader, entry)?
+                        == read_payload(&mut other.reader, other_entry)?);
+
+            if !same {
+                changed.push(path.clone());
+            }
+        }
+
+        changed.extend(
+            other
+                .index
+                .keys()
+                .filter(|path| !self.index.contains_key(*path))
+                .cloned(),
+        );
+        changed.sort();
+
+        Ok(changed)
+    }
+}
+
+/// Rewrite the tensor file with only the tensors that weren't removed, reclaiming the space of the
+/// removed tensors and of a partially written one.
+///
+/// The tensors are copied one at a time in their order of append to a temporary file next to the
+/// tensor file, which then replaces it with a rename, so the tensor file is left unchanged if the
+/// compaction fails. Compacting a file while it's open by a [writer](TensorFileWriter) or a
+/// [reader](TensorFileReader), e.g. in another process, isn't safe: they keep reading and
+/// writing the previous file, or the offsets of its index, and must be opened again.
+pub fn compact_tensor_file(mut file: PathBuf) -> Result<(), RecorderError> {
+    file.set_extension(FILE_EXTENSION);
+    let compacted = file.with_extension(format!("{FILE_EXTENSION}.tmp"));
+
+    let mut reader = File::open(&file).map_err(io_error)?;
+    let (index, _) = read_index(&mut reader)?;
+    let mut entries: Vec<_> = index.into_values().collect();
+    entries.sort_by_key(|entry| entry.offset);
+
+    let mut writer = BufWriter::new(File::create(&compacted).map_err(io_error)?);
+
+    for entry in entries {
+        writer
+            .write_all(&entry_header(
+                ENTRY_MAGIC,
+                &entry.path,
+                entry.length,
+                entry.hash,
+            ))
+            .map_err(io_error)?;
+
+        reader
+            .seek(SeekFrom::Start(entry.offset))
+            .map_err(io_error)?;
+        let copied =
+            std::io::copy(&mut (&mut reader).take(entry.length), &mut writer).map_err(io_error)?;
+
+        if copied != entry.length {
+            return Err(RecorderError::DeserializeError(format!(
+                "The tensor at path {} is truncated",
+                entry.path
+            )));
+        }
+    }
+
+    let writer = writer
+        .into_inner()
+        .map_err(|err| io_error(err.into_error()))?;
+    writer.sync_all().map_err(io_error)?;
+    std::fs::rename(&compacted, &file).map_err(io_error)?;
+
+    Ok(())
+}
+
+/// The header of an entry with the given marker, followed by its path.
+fn entry_header(magic: &[u8; 4], path: &str, length: u64, hash: u32) -> Vec<u8> {
+    let mut header = Vec::with_capacity(ENTRY_HEADER_SIZE as usize + path.len());
+    header.extend_from_slice(magic);
+    header.extend_from_slice(&(path.len() as u64).to_le_bytes());
+    header.extend_from_slice(&length.to_le_bytes());
+    header.extend_from_slice(&hash.to_le_bytes());
+    header.extend_from_slice(path.as_bytes());
+    header
+}
+
+/// Read the serialized tensor of the given entry.
+fn read_payload(file: &mut File, entry: &TensorFileEntry) -> Result<Vec<u8>, RecorderError> {
+    let mut payload = vec![0; entry.length as usize];
+    file.seek(SeekFrom::Start(entry.offset)).map_err(io_error)?;
+    file.read_exact(&mut payload).map_err(io_error)?;
+
+    Ok(payload)
+}
+
+/// Read the headers of the complete entries of the tensor file, skipping over the tensors.
+///
+/// Returns the index of the entries with the offset of the end of the last complete one, the
+/// remaining bytes being a partially written entry.
+fn read_index(file: &mut File) -> Result<(BTreeMap<String, TensorFileEntry>, u64), RecorderError> {
+    // The size is read once so that an entry appended concurrently is either complete or ignored.
+    let size = file.metadata().map_err(io_error)?.len();
+    let mut index = BTreeMap::new();
+    let mut end = 0;
+
+    while end + ENTRY_HEADER_SIZE <= size {
+        let mut header = [0; ENTRY_HEADER_SIZE as usize];
+        file.seek(SeekFrom::Start(end)).map_err(io_error)?;
+        file.read_exact(&mut header).map_err(io_error)?;
+
+        let removed = match &header[0..4] {
+            magic if magic == ENTRY_MAGIC => false,
+            magic if magic == REMOVED_MAGIC => true,
+            _ => {
+                log::warn!("Invalid tensor entry at offset {end}, ignoring the rest of the file");
+                break;
+            }
+        };
+
+        let path_length = u64::from_le_bytes(header[4..12].try_into().unwrap());
+        let length = u64::from_le_bytes(header[12..20].try_into().unwrap());
+        let hash = u32::from_le_bytes(header[20..24].try_into().unwrap());
+        let offset = end + ENTRY_HEADER_SIZE + path_length;
+
+        if offset + length > size {
+            break;
+        }
+
+        let mut path = vec![0; path_length as usize];
+        file.read_exact(&mut path).map_err(io_error)?;
+        let path = String::from_utf8(path)
+            .map_err(|err| RecorderError::DeserializeError(err.to_string()))?;
+
+        if removed {
+            index.remove(&path);
+        } else {
+            index.insert(
+                path.clone(),
+                TensorFileEntry::new(path, offset, length, hash),
+            );
+        }
+        end = offset + length;
+    }
+
+    Ok((index, end))
+}
+
+fn io_error(err: std::io::Error) -> RecorderError {
+    match err.kind() {
+        std::io::ErrorKind::NotFound => RecorderError::FileNotFound(err.to_string()),
+        _ => RecorderError::Unknown(err.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{record::FullPrecisionSettings, TestBackend};
+    use std::path::Path;
+
+    type TestWriter = TensorFileWriter<FullPrecisionSettings>;
+    type TestReader = TensorFileReader<FullPrecisionSettings>;
+
+    fn tensor_file_path(name: &str) -> PathBuf {
+        let file = std::env::temp_dir().join(name);
+        std::fs::remove_file(file.with_extension(FILE_EXTENSION)).ok();
+        file
+    }
+
+    fn append(file: &Path, path: &str, value: f32) -> Result<(), RecorderError> {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2>::from_floats([[value; 3]; 2], &device);
+
+        TestWriter::open(file.to_path_buf())?.append_tensor(path, tensor)
+    }
+
+    fn file_size(file: &Path) -> u64 {
+        std::fs::metadata(file.with_extension(FILE_EXTENSION))
+            .unwrap()
+            .len()
+    }
+
+    #[test]
+    fn test_tensor_file_reads_tensors_appended_separately() {
+        let device = Default::default();
+        let file = tensor_file_path("burn_test_tensor_file_append");
+
+        append(&file, "layers.0.keys", 0.0).unwrap();
+        append(&file, "layers.0.values", 1.0).unwrap();
+        append(&file, "layers.1.keys", 2.0).unwrap();
+
+        let mut reader = TestReader::open(file).unwrap();
+        let paths: Vec<_> = reader
+            .entries()
+            .into_iter()
+            .map(|entry| entry.path)
+            .collect();
+        assert_eq!(
+            paths,
+            vec!["layers.0.keys", "layers.0.values", "layers.1.keys"]
+        );
+
+        for (path, value) in paths.iter().zip([0.0, 1.0, 2.0]) {
+            let tensor: Tensor<TestBackend, 2> = reader.read_tensor(path, &device).unwrap();
+            assert_eq!(tensor.into_data().value, vec![value; 6]);
+        }
+    }
+
+    #[test]
+    fn test_tensor_file_rejects_duplicate_path() {
+        let device = Default::default();
+        let file = tensor_file_path("burn_test_tensor_file_duplicate");
+
+        append(&file, "keys", 0.0).unwrap();
+        let result = append(&file, "keys", 1.0);
+
+        assert!(matches!(result, Err(RecorderError::DuplicateTensor(path)) if path == "keys"));
+
+        let mut reader = TestReader::open(file).unwrap();
+        assert_eq!(reader.entries().len(), 1);
+        let tensor: Tensor<TestBackend, 2> = reader.read_tensor("keys", &device).unwrap();
+        assert_eq!(tensor.into_data().value, vec![0.0; 6]);
+    }
+
+    #[test]
+    fn test_tensor_file_compaction_keeps_live_tensors_only() {
+        let device = Default::default();
+        let file = tensor_file_path("burn_test_tensor_file_compact");
+
+        append(&file, "layers.0.keys", 0.0).unwrap();
+        append(&file, "layers.0.values", 1.0).unwrap();
+        append(&file, "layers.1.keys", 2.0).unwrap();
+
+        let mut writer = TestWriter::open(file.clone()).unwrap();
+        writer.remove_tensor("layers.0.values").unwrap();
+        writer.remove_tensor("layers.1.keys").unwrap();
+        drop(writer);
+        append(&file, "layers.1.keys", 3.0).unwrap();
+
+        let size = file_size(&file);
+        compact_tensor_file(file.clone()).unwrap();
+        assert!(file_size(&file) < size);
+
+        let mut reader = TestReader::open(file.clone()).unwrap();
+        let paths: Vec<_> = reader
+            .entries()
+            .into_iter()
+            .map(|entry| entry.path)
+            .collect();
+        assert_eq!(paths, vec!["layers.0.keys", "layers.1.keys"]);
+
+        for (path, value) in paths.iter().zip([0.0, 3.0]) {
+            let tensor: Tensor<TestBackend, 2> = reader.read_tensor(path, &device).unwrap();
+            assert_eq!(tensor.into_data().value, vec![value; 6]);
+        }
+
+        // The compacted file can be appended to.
+        append(&file, "layers.0.values", 4.0).unwrap();
+        assert_eq!(TestReader::open(file).unwrap().entries().len(), 3);
+    }
+
+    #[test]
+    fn test_tensor_file_hashes_detect_changed_tensors() {
+        let file = tensor_file_path("burn_test_tensor_file_hash");
+        let other_file = tensor_file_path("burn_test_tensor_file_hash_other");
+
+        append(&file, "layers.0.keys", 0.0).unwrap();
+        append(&file, "layers.0.values", 1.0).unwrap();
+        append(&file, "layers.1.keys", 2.0).unwrap();
+        append(&other_file, "layers.0.keys", 0.0).unwrap();
+        append(&other_file, "layers.0.values", 5.0).unwrap();
+        append(&other_file, "layers.2.keys", 2.0).unwrap();
+
+        let mut reader = TestReader::open(file).unwrap();
+        let mut other = TestReader::open(other_file).unwrap();
+        let entries = reader.entries();
+        let other_entries = other.entries();
+
+        assert_eq!(entries[0].hash, other_entries[0].hash);
+        assert_ne!(entries[1].hash, other_entries[1].hash);
+
+        let expected = vec!["layers.0.values", "layers.1.keys", "layers.2.keys"];
+        assert_eq!(reader.changed_paths(&mut other, false).unwrap(), expected);
+        assert_eq!(reader.changed_paths(&mut other, true).unwrap(), expected);
+    }
+}
@@ -0,0 +1,289 @@
+use super::{record_field_path, RecorderError};
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::format;
+use alloc::string::{String, ToString};
+use serde_json::{Map, Value};
+
+#[cfg(feature = "std")]
+use alloc::boxed::Box;
+#[cfg(feature = "std")]
+use core::{any::Any, cell::RefCell};
+
+/// Replace each parameter already serialized under another path with a reference to it, so
+/// that tied parameters are stored once.
+///
+/// Parameters are identified by their [id](crate::module::ParamId), which is shared by all the
+/// clones of a parameter, e.g. a decoder reusing the embedding table of the encoder. Two
+/// parameters with the same values but different ids are stored twice.
+pub(crate) fn deduplicate_tied(value: &mut Value) {
+    deduplicate(value, "", &mut BTreeMap::new());
+}
+
+fn deduplicate(value: &mut Value, path: &str, stored: &mut BTreeMap<String, String>) {
+    match value {
+        Value::Object(map) if is_tensor(map) => {}
+        Value::Object(map) if map.contains_key("id") && map.contains_key("param") => {
+            let id = match map.get("id").and_then(Value::as_str) {
+                Some(id) => id.to_string(),
+                None => return,
+            };
+
+            match stored.get(&id) {
+                Some(first) => {
+                    map.remove("param");
+                    map.insert("tied".to_string(), Value::String(first.clone()));
+                }
+                None => {
+                    stored.insert(id, path.to_string());
+                }
+            }
+        }
+        Value::Object(map) => map
+            .iter_mut()
+            .for_each(|(key, value)| deduplicate(value, &record_field_path(path, key), stored)),
+        Value::Array(values) => values.iter_mut().enumerate().for_each(|(index, value)| {
+            deduplicate(value, &record_field_path(path, &index.to_string()), stored)
+        }),
+        _ => {}
+    }
+}
+
+/// Replace the references written by [deduplicate_tied] with the parameter they reference,
+/// returning the ids of the tied parameters.
+pub(crate) fn resolve_tied(value: &mut Value) -> Result<BTreeSet<String>, RecorderError> {
+    let mut references = BTreeMap::new();
+    collect_references(value, "", &mut references);
+
+    if references.is_empty() {
+        return Ok(BTreeSet::new());
+    }
+
+    let mut params = BTreeMap::new();
+    collect_params(value, &references, &mut params);
+
+    if let Some((id, path)) = references
+        .iter()
+        .find(|(id, _)| !params.contains_key(id.as_str()))
+    {
+        return Err(RecorderError::DeserializeError(format!(
+            "Tied parameter '{path}' references the missing parameter {id}"
+        )));
+    }
+
+    replace_references(value, &params);
+
+    Ok(references.into_keys().collect())
+}
+
+fn collect_references(value: &Value, path: &str, references: &mut BTreeMap<String, String>) {
+    match value {
+        Value::Object(map) if is_tensor(map) => {}
+        Value::Object(map) if map.contains_key("tied") => {
+            if let Some(id) = map.get("id").and_then(Value::as_str) {
+                references.insert(id.to_string(), path.to_string());
+            }
+        }
+        Value::Object(map) => map.iter().for_each(|(key, value)| {
+            collect_references(value, &record_field_path(path, key), references)
+        }),
+        Value::Array(values) => values.iter().enumerate().for_each(|(index, value)| {
+            collect_references(
+                value,
+                &record_field_path(path, &index.to_string()),
+                references,
+            )
+        }),
+        _ => {}
+    }
+}
+
+fn collect_params(
+    value: &Value,
+    references: &BTreeMap<String, String>,
+    params: &mut BTreeMap<String, Value>,
+) {
+    match value {
+        Value::Object(map) if is_tensor(map) => {}
+        Value::Object(map) if map.contains_key("id") && map.contains_key("param") => {
+            if let Some(id) = map.get("id").and_then(Value::as_str) {
+                if references.contains_key(id) && !params.contains_key(id) {
+                    params.insert(id.to_string(), Value::Object(map.clone()));
+                }
+            }
+        }
+        Value::Object(map) => map
+            .values()
+            .for_each(|value| collect_params(value, references, params)),
+        Value::Array(values) => values
+            .iter()
+            .for_each(|value| collect_params(value, references, params)),
+        _ => {}
+    }
+}
+
+fn replace_references(value: &mut Value, params: &BTreeMap<String, Value>) {
+    match value {
+        Value::Object(map) if is_tensor(map) => {}
+        Value::Object(map) if map.contains_key("tied") => {
+            let param = map
+                .get("id")
+                .and_then(Value::as_str)
+                .and_then(|id| params.get(id))
+                .cloned();
+
+            if let Some(param) = param {
+                *value = param;
+            }
+        }
+        Value::Object(map) => map
+            .values_mut()
+            .for_each(|value| replace_references(value, params)),
+        Value::Array(values) => values
+            .iter_mut()
+            .for_each(|value| replace_references(value, params)),
+        _ => {}
+    }
+}
+
+/// Tensors are serialized with their values and shape, or compressed with their codec, or
+/// quantized with their scale, or chunked with their shape, or stored externally with their
+/// external data.
+pub(crate) fn is_tensor(map: &Map<String, Value>) -> bool {
+    let has = |first, second| map.contains_key(first) && map.contains_key(second);
+
+    has("value", "shape")
+        || has("codec", "bytes")
+        || has("scale", "data")
+        || has("shape", "chunks")
+        || (map.len() == 1 && map.contains_key("external_data"))
+}
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static TIED: RefCell<Option<TiedTensors>> = const { RefCell::new(None) };
+}
+
+/// Tensors of the tied parameters created on the current thread, by parameter id.
+#[cfg(feature = "std")]
+struct TiedTensors {
+    ids: BTreeSet<String>,
+    tensors: BTreeMap<String, Box<dyn Any>>,
+}
+
+/// Share the tensor of each tied parameter between all the fields referencing it while the
+/// scope is alive, instead of creating one tensor per field.
+pub(crate) struct TiedScope {
+    #[cfg(feature = "std")]
+    previous: Option<TiedTensors>,
+}
+
+impl TiedScope {
+    /// Start sharing the tensors of the parameters with the given ids.
+    pub(crate) fn start(ids: BTreeSet<String>) -> Self {
+        #[cfg(feature = "std")]
+        {
+            let tied = TiedTensors {
+                ids,
+                tensors: BTreeMap::new(),
+            };
+            let previous = TIED.with(|state| state.replace(Some(tied)));
+
+            Self { previous }
+        }
+
+        #[cfg(not(feature = "std"))]
+        {
+            let _ = ids;
+            Self {}
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for TiedScope {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        TIED.with(|state| state.replace(previous));
+    }
+}
+
+/// The tensor of the parameter with the given id, created the first time the parameter is
+/// loaded and cloned for the next fields tied to it.
+///
+/// Without the `std` feature, the tensor is created for each field.
+pub(crate) fn tied_tensor<T, F>(id: &str, create: F) -> T
+where
+    T: Clone + 'static,
+    F: FnOnce() -> T,
+{
+    #[cfg(feature = "std")]
+    {
+        let tied = TIED.with(|state| match state.borrow().as_ref() {
+            Some(tied) if tied.ids.contains(id) => Some(
+                tied.tensors
+                    .get(id)
+                    .and_then(|tensor| tensor.downcast_ref::<T>())
+                    .cloned(),
+            ),
+            _ => None,
+        });
+
+        match tied {
+            Some(Some(tensor)) => tensor,
+            Some(None) => {
+                let tensor = create();
+                TIED.with(|state| {
+                    if let Some(tied) = state.borrow_mut().as_mut() {
+                        tied.tensors
+                            .insert(id.to_string(), Box::new(tensor.clone()));
+                    }
+                });
+                tensor
+            }
+            None => create(),
+        }
+    }
+
+    #[cfg(not(feature = "std"))]
+    create()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn tied_params_are_stored_once() {
+        let param = json!({ "id": "a", "param": { "value": [1.0, 2.0], "shape": [2] } });
+        let mut item = json!({
+            "decoder": { "weight": param.clone() },
+            "encoder": { "weight": param.clone() },
+            "other": { "id": "b", "param": { "value": [1.0, 2.0], "shape": [2] } },
+        });
+
+        deduplicate_tied(&mut item);
+
+        assert_eq!(item["decoder"]["weight"], param);
+        assert_eq!(
+            item["encoder"]["weight"],
+            json!({ "id": "a", "tied": "decoder.weight" })
+        );
+        assert!(item["other"].get("param").is_some());
+
+        let ids = resolve_tied(&mut item).unwrap();
+
+        assert_eq!(item["encoder"]["weight"], param);
+        assert_eq!(ids.into_iter().collect::<alloc::vec::Vec<_>>(), ["a"]);
+    }
+
+    #[test]
+    fn err_when_tied_param_is_missing() {
+        let mut item = json!({ "encoder": { "weight": { "id": "a", "tied": "decoder.weight" } } });
+
+        assert!(matches!(
+            resolve_tied(&mut item),
+            Err(RecorderError::DeserializeError(_))
+        ));
+    }
+}
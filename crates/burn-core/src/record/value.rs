@@ -0,0 +1,221 @@
+use super::RecorderError;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use serde::de::{DeserializeOwned, MapAccess, SeqAccess, Visitor};
+use serde::ser::{SerializeMap, SerializeSeq};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Number, Value};
+
+/// Key of the objects holding the non-finite floats, which json values can't represent.
+const NON_FINITE: &str = "$non_finite";
+
+/// Serialized record loaded as a json [value](Value), e.g. to merge it with another record
+/// before converting it into an item.
+///
+/// Unlike a plain json value, the NaN and infinite floats aren't replaced by `null`: they are
+/// held in `{"$non_finite": "NaN"}` objects, which [from_value] converts back.
+pub(crate) struct RecordValue(pub(crate) Value);
+
+impl<'de> Deserialize<'de> for RecordValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(RecordValueVisitor).map(Self)
+    }
+}
+
+struct RecordValueVisitor;
+
+impl<'de> Visitor<'de> for RecordValueVisitor {
+    type Value = Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a self-describing record")
+    }
+
+    fn visit_bool<E>(self, value: bool) -> Result<Value, E> {
+        Ok(Value::Bool(value))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Value, E> {
+        Ok(Value::Number(value.into()))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Value, E> {
+        Ok(Value::Number(value.into()))
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Value, E> {
+        Ok(match Number::from_f64(value) {
+            Some(number) => Value::Number(number),
+            None => {
+                let mut map = Map::new();
+                map.insert(NON_FINITE.to_string(), Value::String(value.to_string()));
+                Value::Object(map)
+            }
+        })
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Value, E> {
+        Ok(Value::String(value.to_string()))
+    }
+
+    fn visit_string<E>(self, value: String) -> Result<Value, E> {
+        Ok(Value::String(value))
+    }
+
+    fn visit_bytes<E>(self, value: &[u8]) -> Result<Value, E> {
+        Ok(Value::Array(
+            value.iter().map(|byte| Value::from(*byte)).collect(),
+        ))
+    }
+
+    fn visit_none<E>(self) -> Result<Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_unit<E>(self) -> Result<Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Value, A::Error> {
+        let mut values = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+
+        while let Some(RecordValue(value)) = seq.next_element()? {
+            values.push(value);
+        }
+
+        Ok(Value::Array(values))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Value, A::Error> {
+        let mut values = Map::new();
+
+        // Keys are strings, except the variants of enums that may be written by their index.
+        while let Some((RecordValue(key), RecordValue(value))) = map.next_entry()? {
+            let key = match key {
+                Value::String(key) => key,
+                key => key.to_string(),
+            };
+            values.insert(key, value);
+        }
+
+        Ok(Value::Object(values))
+    }
+}
+
+/// Serialize the value, converting the objects holding non-finite floats back into floats.
+pub(crate) struct RecordValueRef<'a>(pub(crate) &'a Value);
+
+impl Serialize for RecordValueRef<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            Value::Null => serializer.serialize_unit(),
+            Value::Bool(value) => serializer.serialize_bool(*value),
+            Value::Number(number) => match (number.as_u64(), number.as_i64()) {
+                (Some(value), _) => serializer.serialize_u64(value),
+                (_, Some(value)) => serializer.serialize_i64(value),
+                _ => serializer.serialize_f64(number.as_f64().unwrap_or(f64::NAN)),
+            },
+            Value::String(value) => serializer.serialize_str(value),
+            Value::Array(values) => {
+                let mut seq = serializer.serialize_seq(Some(values.len()))?;
+                for value in values {
+                    seq.serialize_element(&RecordValueRef(value))?;
+                }
+                seq.end()
+            }
+            Value::Object(map) => {
+                if let Some(value) = non_finite(map) {
+                    return serializer.serialize_f64(value);
+                }
+
+                let mut entries = serializer.serialize_map(Some(map.len()))?;
+                for (key, value) in map {
+                    entries.serialize_entry(key, &RecordValueRef(value))?;
+                }
+                entries.end()
+            }
+        }
+    }
+}
+
+/// Whether the object holds a non-finite float, which is a leaf of the value.
+pub(crate) fn is_non_finite(map: &Map<String, Value>) -> bool {
+    non_finite(map).is_some()
+}
+
+/// The float held by an object written for a non-finite float.
+fn non_finite(map: &Map<String, Value>) -> Option<f64> {
+    match map.get(NON_FINITE) {
+        Some(Value::String(value)) if map.len() == 1 => value.parse().ok(),
+        _ => None,
+    }
+}
+
+/// Serialize the item into a [record value](RecordValue).
+///
+/// Without the `std` feature, the item is serialized into a plain json value, whose non-finite
+/// floats are replaced by `null`.
+pub(crate) fn to_value<T: Serialize>(item: &T) -> Result<Value, RecorderError> {
+    #[cfg(feature = "std")]
+    {
+        let bytes =
+            rmp_serde::to_vec_named(item).map_err(|err| RecorderError::Unknown(err.to_string()))?;
+        let RecordValue(value) =
+            rmp_serde::from_slice(&bytes).map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        Ok(value)
+    }
+
+    #[cfg(not(feature = "std"))]
+    serde_json::to_value(item).map_err(|err| RecorderError::Unknown(err.to_string()))
+}
+
+/// Deserialize an item from a [record value](RecordValue), restoring its non-finite floats.
+///
+/// The value goes through the [named msgpack](rmp_serde) format, which holds the same data as
+/// the value while keeping the floats that json can't represent.
+pub(crate) fn from_value<T: DeserializeOwned>(value: &Value) -> Result<T, RecorderError> {
+    #[cfg(feature = "std")]
+    {
+        let bytes = rmp_serde::to_vec_named(&RecordValueRef(value))
+            .map_err(|err| RecorderError::DeserializeError(err.to_string()))?;
+
+        rmp_serde::from_slice(&bytes)
+            .map_err(|err| RecorderError::DeserializeError(err.to_string()))
+    }
+
+    #[cfg(not(feature = "std"))]
+    serde_json::from_value(value.clone())
+        .map_err(|err| RecorderError::DeserializeError(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_tensor::DataSerialize;
+
+    #[test]
+    fn non_finite_floats_round_trip() {
+        let data = DataSerialize::new(vec![f32::NAN, f32::INFINITY, -f32::INFINITY, 1.5], vec![4]);
+
+        let value = to_value(&data).unwrap();
+        let loaded: DataSerialize<f32> = from_value(&value).unwrap();
+
+        assert_eq!(value["value"][0][NON_FINITE], "NaN");
+        assert!(loaded.value[0].is_nan());
+        assert_eq!(loaded.value[1..], data.value[1..]);
+        assert_eq!(loaded.shape, data.shape);
+    }
+}
@@ -15,3 +15,100 @@ pub struct TestWithBackendRecord<B: Backend> {
 pub struct TestWithoutBackendRecord {
     _tensor: usize,
 }
+
+// It compiles
+#[derive(Record)]
+pub enum TestUnitOnlyRecord {
+    First,
+    Second,
+}
+
+#[cfg(feature = "std")]
+mod tests {
+    use super::*;
+    use burn::record::{FullPrecisionSettings, NamedMpkBytesRecorder, Recorder, RecorderError};
+    use burn_tensor::Data;
+
+    type TestBackend = burn_ndarray::NdArray<f32>;
+
+    #[derive(Record)]
+    pub enum ActivationRecord<B: Backend> {
+        Relu,
+        LeakyRelu { slope: f32 },
+        Prelu(Tensor<B, 1>),
+        Clamp(f32, f32),
+    }
+
+    /// A newer version of [ActivationRecord] with an additional variant.
+    #[derive(Record)]
+    pub enum ActivationRecordV2<B: Backend> {
+        Relu,
+        LeakyRelu { slope: f32 },
+        Prelu(Tensor<B, 1>),
+        Clamp(f32, f32),
+        Gelu { approximate: bool },
+    }
+
+    fn round_trip<R: Record<TestBackend>>(record: R) -> Result<R, RecorderError> {
+        let recorder = NamedMpkBytesRecorder::<FullPrecisionSettings>::default();
+        let bytes = Recorder::<TestBackend>::record(&recorder, record, ())?;
+
+        Recorder::<TestBackend>::load(&recorder, bytes, &Default::default())
+    }
+
+    #[test]
+    fn enum_record_round_trips_each_variant() {
+        let device = Default::default();
+
+        assert!(matches!(
+            round_trip(ActivationRecord::<TestBackend>::Relu),
+            Ok(ActivationRecord::Relu)
+        ));
+        assert!(matches!(
+            round_trip(ActivationRecord::<TestBackend>::LeakyRelu { slope: 0.2 }),
+            Ok(ActivationRecord::LeakyRelu { slope }) if slope == 0.2
+        ));
+        assert!(matches!(
+            round_trip(ActivationRecord::<TestBackend>::Clamp(-1.0, 6.0)),
+            Ok(ActivationRecord::Clamp(min, max)) if min == -1.0 && max == 6.0
+        ));
+
+        let weight = Tensor::from_floats([0.25, 0.5], &device);
+        match round_trip(ActivationRecord::<TestBackend>::Prelu(weight)) {
+            Ok(ActivationRecord::Prelu(weight)) => {
+                assert_eq!(weight.into_data(), Data::from([0.25, 0.5]))
+            }
+            _ => panic!("Expected the Prelu variant"),
+        }
+    }
+
+    #[test]
+    fn unit_only_enum_record_round_trips() {
+        assert!(matches!(
+            round_trip(TestUnitOnlyRecord::Second),
+            Ok(TestUnitOnlyRecord::Second)
+        ));
+    }
+
+    #[test]
+    fn enum_record_with_unknown_variant_fails_to_load() {
+        let recorder = NamedMpkBytesRecorder::<FullPrecisionSettings>::default();
+        let bytes = Recorder::<TestBackend>::record(
+            &recorder,
+            ActivationRecordV2::<TestBackend>::Gelu { approximate: true },
+            (),
+        )
+        .unwrap();
+
+        let result: Result<ActivationRecord<TestBackend>, _> =
+            Recorder::<TestBackend>::load(&recorder, bytes, &Default::default());
+
+        match result {
+            Err(RecorderError::Unknown(message)) => {
+                assert!(message.contains("unknown variant `Gelu`"), "{message}")
+            }
+            Err(err) => panic!("Expected an unknown variant error, got {err:?}"),
+            Ok(_) => panic!("Expected the unknown variant to fail"),
+        }
+    }
+}
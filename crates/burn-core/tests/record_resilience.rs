@@ -1,16 +1,17 @@
 #[cfg(feature = "std")]
 mod tests {
     use burn::{
-        module::Module,
+        module::{Module, Param},
         nn,
         record::{
-            BinFileRecorder, DefaultFileRecorder, FileRecorder, FullPrecisionSettings,
-            PrettyJsonFileRecorder, RecorderError,
+            BinBytesRecorder, BinFileRecorder, DefaultFileRecorder, FileRecorder,
+            FullPrecisionSettings, NamedMpkBytesRecorder, PrettyJsonFileRecorder, Record, Recorder,
+            RecorderError,
         },
     };
     use burn_core as burn;
     use burn_ndarray::NdArrayDevice;
-    use burn_tensor::backend::Backend;
+    use burn_tensor::{backend::Backend, Int, Tensor};
     use std::path::PathBuf;
 
     type TestBackend = burn_ndarray::NdArray<f32>;
@@ -49,6 +50,17 @@ mod tests {
         linear1: nn::Linear<B>,
     }
 
+    /// Record saved by the recorders without header, before the record version and the other
+    /// metadata fields were added.
+    #[derive(Record)]
+    pub struct BaselineRecord<B: Backend> {
+        weight: Param<Tensor<B, 2>>,
+        indices: Tensor<B, 1, Int>,
+    }
+
+    const BASELINE_BIN: &[u8] = include_bytes!("fixtures/baseline.bin");
+    const BASELINE_MPK: &[u8] = include_bytes!("fixtures/baseline.mpk");
+
     #[test]
     fn deserialize_with_new_optional_field_works_with_default_file_recorder() {
         deserialize_with_new_optional_field(
@@ -176,6 +188,47 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn load_baseline_record_with_bin_bytes_recorder() {
+        let record = BinBytesRecorder::<FullPrecisionSettings>::new()
+            .load(BASELINE_BIN.to_vec(), &Default::default())
+            .unwrap();
+
+        assert_baseline_record(record);
+    }
+
+    #[test]
+    fn load_baseline_record_with_bin_file_recorder() {
+        let file_path = file_path("load_baseline_record.bin".to_string());
+        std::fs::write(&file_path, BASELINE_BIN).unwrap();
+
+        let result = BinFileRecorder::<FullPrecisionSettings>::new()
+            .load(file_path.clone(), &Default::default());
+        std::fs::remove_file(file_path).ok();
+
+        assert_baseline_record(result.unwrap());
+    }
+
+    #[test]
+    fn load_baseline_record_with_named_mpk_bytes_recorder() {
+        let record = NamedMpkBytesRecorder::<FullPrecisionSettings>::new()
+            .load(BASELINE_MPK.to_vec(), &Default::default())
+            .unwrap();
+
+        assert_baseline_record(record);
+    }
+
+    fn assert_baseline_record(record: BaselineRecord<TestBackend>) {
+        let weight = record.weight.val().into_data();
+        let indices = record.indices.into_data();
+
+        assert_eq!(weight.shape.dims, [2, 2]);
+        assert_eq!(weight.value[..2], [1.0, -2.5]);
+        assert!(weight.value[2].is_nan());
+        assert_eq!(weight.value[3], 0.5);
+        assert_eq!(indices.value, vec![0, 2, 5]);
+    }
+
     #[inline(always)]
     fn file_path(filename: String) -> PathBuf {
         std::env::temp_dir().join(filename)
@@ -142,6 +142,13 @@ pub(crate) fn codegen_binary(
                 burn_cube::frontend::bitand::expand(context, _lhs, _rhs)
             }
         },
+        syn::BinOp::BitOr(_) => quote::quote! {
+            {
+                let _lhs = #lhs;
+                let _rhs = #rhs;
+                burn_cube::frontend::bitor::expand(context, _lhs, _rhs)
+            }
+        },
         syn::BinOp::BitXor(_) => quote::quote! {
             {
                 let _lhs = #lhs;
@@ -203,6 +203,26 @@ pub mod or {
     }
 }
 
+pub mod bitor {
+    use super::*;
+
+    pub fn expand<L: Into<ExpandElement>, R: Into<ExpandElement>>(
+        context: &mut CubeContext,
+        lhs: L,
+        rhs: R,
+    ) -> ExpandElement {
+        binary_expand(context, lhs.into(), rhs.into(), Operator::BitwiseOr)
+    }
+
+    impl core::ops::BitOr for UInt {
+        type Output = UInt;
+
+        fn bitor(self, _rhs: Self) -> Self::Output {
+            unexpanded!()
+        }
+    }
+}
+
 pub mod bitxor {
     use super::*;
 
@@ -107,6 +107,16 @@ macro_rules! cpa {
             cpa!(binary $lhs, $rhs, $out)
         ));
     };
+    // out = lhs | rhs
+    ($scope:expr, $out: ident = $lhs:ident | $rhs:ident) => {
+        cpa!($scope, $out = bitwise_or($lhs, $rhs))
+    };
+    // out = bitwise_or(lhs, rhs)
+    ($scope:expr, $out:ident = bitwise_or($lhs:expr, $rhs:expr)) => {
+        $scope.register($crate::ir::Operator::BitwiseOr(
+            cpa!(binary $lhs, $rhs, $out)
+        ));
+    };
     // out = lhs ^ rhs
     ($scope:expr, $out: ident = $lhs:ident ^ $rhs:ident) => {
         cpa!($scope, $out = bitwise_xor($lhs, $rhs))
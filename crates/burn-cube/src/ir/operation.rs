@@ -60,6 +60,7 @@ pub enum Operator {
     Max(BinaryOperator),
     Min(BinaryOperator),
     BitwiseAnd(BinaryOperator),
+    BitwiseOr(BinaryOperator),
     BitwiseXor(BinaryOperator),
     ShiftLeft(BinaryOperator),
     ShiftRight(BinaryOperator),
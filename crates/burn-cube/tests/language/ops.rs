@@ -155,6 +155,11 @@ fn bitand_op(a: UInt, b: UInt) -> UInt {
     a & b
 }
 
+#[cube]
+fn bitor_op(a: UInt, b: UInt) -> UInt {
+    a | b
+}
+
 #[cube]
 fn bitxor_op(a: UInt, b: UInt) -> UInt {
     a ^ b
@@ -327,6 +332,7 @@ mod tests {
     binary_boolean_test!(cube_can_and, and_op_expand, "And");
     binary_boolean_test!(cube_can_or, or_op_expand, "Or");
     binary_uint_test!(cube_can_bitand, bitand_op_expand, "BitwiseAnd");
+    binary_uint_test!(cube_can_bitor, bitor_op_expand, "BitwiseOr");
     binary_uint_test!(cube_can_bitxor, bitxor_op_expand, "BitwiseXor");
     binary_uint_test!(cube_can_shl, shl_op_expand, "ShiftLeft");
     binary_uint_test!(cube_can_shr, shr_op_expand, "ShiftRight");
@@ -282,6 +282,7 @@ impl CudaCompiler {
             gpu::Operator::Min(op) => Instruction::Min(self.compile_binary(op)),
             gpu::Operator::NotEqual(op) => Instruction::NotEqual(self.compile_binary(op)),
             gpu::Operator::BitwiseAnd(op) => Instruction::BitwiseAnd(self.compile_binary(op)),
+            gpu::Operator::BitwiseOr(op) => Instruction::BitwiseOr(self.compile_binary(op)),
             gpu::Operator::BitwiseXor(op) => Instruction::BitwiseXor(self.compile_binary(op)),
             gpu::Operator::ShiftLeft(op) => Instruction::ShiftLeft(self.compile_binary(op)),
             gpu::Operator::ShiftRight(op) => Instruction::ShiftRight(self.compile_binary(op)),
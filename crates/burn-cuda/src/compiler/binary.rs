@@ -244,6 +244,7 @@ operator!(GreaterEqual, ">=");
 operator!(ShiftLeft, "<<");
 operator!(ShiftRight, ">>");
 operator!(BitwiseAnd, "&");
+operator!(BitwiseOr, "|");
 operator!(BitwiseXor, "^");
 operator!(Or, "||");
 operator!(And, "&&");
@@ -72,6 +72,7 @@ pub enum Instruction {
     GreaterEqual(BinaryInstruction),
     Erf(UnaryInstruction),
     BitwiseAnd(BinaryInstruction),
+    BitwiseOr(BinaryInstruction),
     BitwiseXor(BinaryInstruction),
     ShiftLeft(BinaryInstruction),
     ShiftRight(BinaryInstruction),
@@ -116,6 +117,7 @@ impl Display for Instruction {
             Instruction::Sub(it) => Sub::format(f, &it.lhs, &it.rhs, &it.out),
             Instruction::Modulo(inst) => Modulo::format(f, &inst.lhs, &inst.rhs, &inst.out),
             Instruction::BitwiseAnd(it) => BitwiseAnd::format(f, &it.lhs, &it.rhs, &it.out),
+            Instruction::BitwiseOr(it) => BitwiseOr::format(f, &it.lhs, &it.rhs, &it.out),
             Instruction::BitwiseXor(it) => BitwiseXor::format(f, &it.lhs, &it.rhs, &it.out),
             Instruction::ShiftLeft(it) => ShiftLeft::format(f, &it.lhs, &it.rhs, &it.out),
             Instruction::ShiftRight(it) => ShiftRight::format(f, &it.lhs, &it.rhs, &it.out),
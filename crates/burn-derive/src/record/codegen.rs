@@ -54,6 +54,10 @@ impl<G: RecordItemCodegen> RecordCodegen<G> {
         let name_item = &self.ty.item;
         let into_item_fn = self.gen.gen_into_item(name_item);
         let from_item_fn = self.gen.gen_from_item();
+        let check_int_overflow_fn = self.gen.gen_check_int_overflow();
+        let check_shapes_fn = self.gen.gen_check_shapes();
+        let check_finite_fn = self.gen.gen_check_finite();
+        let offset_indices_fn = self.gen.gen_offset_indices();
 
         // Return the generated stream of token trees (i.e., code to be generated)
         let name = &self.ty.name;
@@ -63,7 +67,10 @@ impl<G: RecordItemCodegen> RecordCodegen<G> {
 
                 #into_item_fn
                 #from_item_fn
-
+                #check_int_overflow_fn
+                #check_shapes_fn
+                #check_finite_fn
+                #offset_indices_fn
             }
         }
     }
@@ -16,4 +16,12 @@ pub(crate) trait RecordItemCodegen {
     fn gen_into_item(&self, item_name: &Ident) -> TokenStream;
     /// Generate the from item function.
     fn gen_from_item(&self) -> TokenStream;
+    /// Generate the check_int_overflow function.
+    fn gen_check_int_overflow(&self) -> TokenStream;
+    /// Generate the check_shapes function.
+    fn gen_check_shapes(&self) -> TokenStream;
+    /// Generate the check_finite function.
+    fn gen_check_finite(&self) -> TokenStream;
+    /// Generate the offset_indices function.
+    fn gen_offset_indices(&self) -> TokenStream;
 }
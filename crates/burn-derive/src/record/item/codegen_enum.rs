@@ -1,5 +1,4 @@
-use crate::shared::enum_variant::{parse_variants, EnumVariant};
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
 use syn::{parse_quote, Generics};
 
@@ -7,14 +6,135 @@ use super::codegen::RecordItemCodegen;
 
 pub(crate) struct EnumRecordItemCodegen {
     /// Enum variants.
-    variants: Vec<EnumVariant>,
+    variants: Vec<RecordVariant>,
 }
 
-impl RecordItemCodegen for EnumRecordItemCodegen {
-    fn from_ast(ast: &syn::DeriveInput) -> Self {
+/// An enum variant, which can be a unit, a tuple or a struct variant.
+struct RecordVariant {
+    ident: Ident,
+    fields: Vec<RecordVariantField>,
+    named: bool,
+}
+
+/// A field of an enum variant.
+struct RecordVariantField {
+    /// Name of the field, or `arg_{index}` for tuple variants.
+    binding: Ident,
+    ty: syn::Type,
+    /// Key of the field in the path of the record, the path of the enum itself for the single
+    /// field of a tuple variant.
+    key: Option<String>,
+}
+
+impl RecordVariant {
+    fn from_variant(variant: &syn::Variant) -> Self {
+        let named = matches!(variant.fields, syn::Fields::Named(_));
+        let single = variant.fields.len() == 1;
+
+        let fields = variant
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| match &field.ident {
+                Some(ident) => RecordVariantField {
+                    binding: ident.clone(),
+                    ty: field.ty.clone(),
+                    key: Some(ident.to_string()),
+                },
+                None => RecordVariantField {
+                    binding: Ident::new(&format!("arg_{index}"), Span::call_site()),
+                    ty: field.ty.clone(),
+                    key: (!single).then(|| index.to_string()),
+                },
+            })
+            .collect();
+
         Self {
-            variants: parse_variants(ast),
+            ident: variant.ident.clone(),
+            fields,
+            named,
+        }
+    }
+
+    /// The fields of the variant with the given tokens for each field, e.g. its type or the
+    /// binding of its value in a pattern.
+    fn fields<F>(&self, mut map: F) -> TokenStream
+    where
+        F: FnMut(&RecordVariantField) -> TokenStream,
+    {
+        if self.fields.is_empty() {
+            return quote! {};
+        }
+
+        let values = self.fields.iter().map(&mut map);
+
+        if self.named {
+            let names = self.fields.iter().map(|field| &field.binding);
+            quote! {{ #(#names: #values),* }}
+        } else {
+            quote! {( #(#values),* )}
+        }
+    }
+
+    /// The pattern binding the fields of the variant by their name.
+    fn pattern(&self) -> TokenStream {
+        if self.named {
+            let names = self.fields.iter().map(|field| &field.binding);
+            return quote! {{ #(#names),* }};
+        }
+
+        self.fields(|field| {
+            let binding = &field.binding;
+            quote! { #binding }
+        })
+    }
+}
+
+impl RecordVariantField {
+    /// The path of the field in a record at `path`.
+    fn path(&self) -> TokenStream {
+        match &self.key {
+            Some(key) => quote! { &burn::record::record_field_path(path, #key) },
+            None => quote! { path },
+        }
+    }
+}
+
+impl EnumRecordItemCodegen {
+    /// Whether no variant has a field, in which case the record item doesn't use its generics.
+    fn is_unit_only(&self) -> bool {
+        self.variants
+            .iter()
+            .all(|variant| variant.fields.is_empty())
+    }
+
+    /// The match arm of the variant only used to bind the generics of the record item, matched
+    /// by reference or by value.
+    fn phantom_arm(&self, by_ref: bool) -> TokenStream {
+        if !self.is_unit_only() {
+            return quote! {};
         }
+
+        if by_ref {
+            quote! { Self::Item::_Phantom(_, never) => match *never {}, }
+        } else {
+            quote! { Self::Item::_Phantom(_, never) => match never {}, }
+        }
+    }
+}
+
+impl RecordItemCodegen for EnumRecordItemCodegen {
+    fn from_ast(ast: &syn::DeriveInput) -> Self {
+        let variants = match &ast.data {
+            syn::Data::Enum(data) => data
+                .variants
+                .iter()
+                .map(RecordVariant::from_variant)
+                .collect(),
+            _ => panic!("Only enum can be derived"),
+        };
+
+        Self { variants }
     }
 
     fn gen_item_type(
@@ -28,21 +148,36 @@ impl RecordItemCodegen for EnumRecordItemCodegen {
 
         // Capture the Record enum variant types and names to transpose them in RecordItem
         for variant in self.variants.iter() {
-            let ty = &variant.ty;
             let name = &variant.ident;
+            let fields = variant.fields(|field| {
+                let ty = &field.ty;
+                quote! { <#ty as burn::record::Record<B>>::Item<S> }
+            });
 
             variants.extend(quote! {
                 /// Variant to be serialized.
-                #name(<#ty as burn::record::Record<B>>::Item<S>),
+                #name #fields,
             });
 
             // Item types must implement serialization/deserialization
-            bounds.extend(quote! {
-              <#ty as burn::record::Record<B>>::Item<S>: burn::serde::Serialize + burn::serde::de::DeserializeOwned,
-          });
+            for field in variant.fields.iter() {
+                let ty = &field.ty;
+                bounds.extend(quote! {
+                    <#ty as burn::record::Record<B>>::Item<S>: burn::serde::Serialize + burn::serde::de::DeserializeOwned,
+                });
+            }
         }
         let bound = bounds.to_string();
 
+        // The generics are otherwise unused when no variant has a field.
+        if self.is_unit_only() {
+            variants.extend(quote! {
+                #[doc(hidden)]
+                #[serde(skip)]
+                _Phantom(core::marker::PhantomData<(S, B)>, core::convert::Infallible),
+            });
+        }
+
         // Capture the type's generics and bounds in where clauses
         let (generics, generics_where) = if !has_backend {
             let mut generics = generics.clone();
@@ -73,9 +208,14 @@ impl RecordItemCodegen for EnumRecordItemCodegen {
 
         for variant in self.variants.iter() {
             let name = &variant.ident;
+            let pattern = variant.pattern();
+            let items = variant.fields(|field| {
+                let binding = &field.binding;
+                quote! { burn::record::Record::<B>::into_item::<S>(#binding) }
+            });
 
             into_item_match_arms.extend(quote! {
-                Self::#name(record) => Self::Item::#name(burn::record::Record::<B>::into_item::<S>(record)),
+                Self::#name #pattern => Self::Item::#name #items,
             });
         }
 
@@ -93,18 +233,149 @@ impl RecordItemCodegen for EnumRecordItemCodegen {
 
         for variant in self.variants.iter() {
             let name = &variant.ident;
+            let pattern = variant.pattern();
+            let records = variant.fields(|field| {
+                let binding = &field.binding;
+                let path = field.path();
+                quote! {
+                    burn::record::Record::<B>::from_item_with_path::<S>(#binding, device, #path)
+                }
+            });
 
             from_item_match_arms.extend(quote! {
-                Self::Item::#name(item) => Self::#name(burn::record::Record::<B>::from_item::<S>(item, device)),
+                Self::Item::#name #pattern => Self::#name #records,
             });
         }
+        let phantom_arm = self.phantom_arm(false);
 
         quote! {
             fn from_item<S: burn::record::PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
+                Self::from_item_with_path(item, device, "")
+            }
+
+            fn from_item_with_path<S: burn::record::PrecisionSettings>(
+                item: Self::Item<S>,
+                device: &B::Device,
+                path: &str,
+            ) -> Self {
                 match item {
                     #from_item_match_arms
+                    #phantom_arm
+                }
+            }
+        }
+    }
+
+    fn gen_check_int_overflow(&self) -> TokenStream {
+        let check_match_arms = self.gen_check_arms(quote! { check_int_overflow }, quote! {});
+
+        quote! {
+            fn check_int_overflow<S: burn::record::PrecisionSettings>(
+                item: &Self::Item<S>,
+                path: &str,
+            ) -> Result<(), burn::record::RecorderError> {
+                match item {
+                    #check_match_arms
+                }
+            }
+        }
+    }
+
+    fn gen_check_shapes(&self) -> TokenStream {
+        let check_match_arms = self.gen_check_arms(quote! { check_shapes }, quote! { , shapes });
+
+        quote! {
+            fn check_shapes<S: burn::record::PrecisionSettings>(
+                item: &Self::Item<S>,
+                path: &str,
+                shapes: &mut burn::record::ShapeCheck,
+            ) -> Result<(), burn::record::RecorderError> {
+                match item {
+                    #check_match_arms
+                }
+            }
+        }
+    }
+
+    fn gen_check_finite(&self) -> TokenStream {
+        let check_match_arms = self.gen_check_arms(quote! { check_finite }, quote! {});
+
+        quote! {
+            fn check_finite<S: burn::record::PrecisionSettings>(
+                item: &Self::Item<S>,
+                path: &str,
+            ) -> Result<(), burn::record::RecorderError> {
+                match item {
+                    #check_match_arms
+                }
+            }
+        }
+    }
+
+    fn gen_offset_indices(&self) -> TokenStream {
+        let mut offset_match_arms = quote! {};
+
+        for variant in self.variants.iter() {
+            let name = &variant.ident;
+            let pattern = variant.pattern();
+            let offsets = variant.fields.iter().map(|field| {
+                let ty = &field.ty;
+                let binding = &field.binding;
+                let path = field.path();
+                quote! {
+                    <#ty as burn::record::Record<B>>::offset_indices::<S>(#binding, #path, offset);
+                }
+            });
+
+            offset_match_arms.extend(quote! {
+                Self::Item::#name #pattern => {
+                    #(#offsets)*
+                }
+            });
+        }
+        offset_match_arms.extend(self.phantom_arm(true));
+
+        quote! {
+            fn offset_indices<S: burn::record::PrecisionSettings>(
+                item: &mut Self::Item<S>,
+                path: &str,
+                offset: i64,
+            ) {
+                match item {
+                    #offset_match_arms
                 }
             }
         }
     }
 }
+
+impl EnumRecordItemCodegen {
+    /// The match arms running a check of the [Record] trait on the fields of each variant, with
+    /// the given arguments after the path.
+    fn gen_check_arms(&self, check: TokenStream, args: TokenStream) -> TokenStream {
+        let mut check_match_arms = quote! {};
+
+        for variant in self.variants.iter() {
+            let name = &variant.ident;
+            let pattern = variant.pattern();
+            let checks = variant.fields.iter().map(|field| {
+                let ty = &field.ty;
+                let binding = &field.binding;
+                let path = field.path();
+                quote! {
+                    <#ty as burn::record::Record<B>>::#check::<S>(#binding, #path #args)?;
+                }
+            });
+
+            check_match_arms.extend(quote! {
+                Self::Item::#name #pattern => {
+                    #(#checks)*
+                    Ok(())
+                }
+            });
+        }
+
+        check_match_arms.extend(self.phantom_arm(true));
+        check_match_arms
+    }
+}
@@ -89,20 +89,146 @@ impl RecordItemCodegen for StructRecordItemCodegen {
     fn gen_from_item(&self) -> TokenStream {
         let mut body_from_item = quote! {};
 
+        // Each field is moved out of the item so its serialized data is dropped as soon as the
+        // field is converted, in declaration order.
         for field in self.fields.iter() {
             let name = &field.field.ident;
+            let key = field.ident().to_string();
 
             body_from_item.extend(quote! {
-                #name: burn::record::Record::<B>::from_item::<S>(item.#name, device),
+                #name: burn::record::Record::<B>::from_item_with_path::<S>(
+                    item.#name,
+                    device,
+                    &burn::record::record_field_path(path, #key),
+                ),
             });
         }
 
         quote! {
             fn from_item<S: burn::record::PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
+                Self::from_item_with_path(item, device, "")
+            }
+
+            fn from_item_with_path<S: burn::record::PrecisionSettings>(
+                item: Self::Item<S>,
+                device: &B::Device,
+                path: &str,
+            ) -> Self {
                 Self {
                     #body_from_item
                 }
             }
         }
     }
+
+    fn gen_check_int_overflow(&self) -> TokenStream {
+        let mut body_check = quote! {};
+
+        for field in self.fields.iter() {
+            let name = &field.field.ident;
+            let ty = &field.field.ty;
+            let key = field.ident().to_string();
+
+            body_check.extend(quote! {
+                <#ty as burn::record::Record<B>>::check_int_overflow::<S>(
+                    &item.#name,
+                    &burn::record::record_field_path(path, #key),
+                )?;
+            });
+        }
+
+        quote! {
+            fn check_int_overflow<S: burn::record::PrecisionSettings>(
+                item: &Self::Item<S>,
+                path: &str,
+            ) -> Result<(), burn::record::RecorderError> {
+                #body_check
+                Ok(())
+            }
+        }
+    }
+
+    fn gen_check_shapes(&self) -> TokenStream {
+        let mut body_check = quote! {};
+
+        for field in self.fields.iter() {
+            let name = &field.field.ident;
+            let ty = &field.field.ty;
+            let key = field.ident().to_string();
+
+            body_check.extend(quote! {
+                <#ty as burn::record::Record<B>>::check_shapes::<S>(
+                    &item.#name,
+                    &burn::record::record_field_path(path, #key),
+                    shapes,
+                )?;
+            });
+        }
+
+        quote! {
+            fn check_shapes<S: burn::record::PrecisionSettings>(
+                item: &Self::Item<S>,
+                path: &str,
+                shapes: &mut burn::record::ShapeCheck,
+            ) -> Result<(), burn::record::RecorderError> {
+                #body_check
+                Ok(())
+            }
+        }
+    }
+
+    fn gen_check_finite(&self) -> TokenStream {
+        let mut body_check = quote! {};
+
+        for field in self.fields.iter() {
+            let name = &field.field.ident;
+            let ty = &field.field.ty;
+            let key = field.ident().to_string();
+
+            body_check.extend(quote! {
+                <#ty as burn::record::Record<B>>::check_finite::<S>(
+                    &item.#name,
+                    &burn::record::record_field_path(path, #key),
+                )?;
+            });
+        }
+
+        quote! {
+            fn check_finite<S: burn::record::PrecisionSettings>(
+                item: &Self::Item<S>,
+                path: &str,
+            ) -> Result<(), burn::record::RecorderError> {
+                #body_check
+                Ok(())
+            }
+        }
+    }
+
+    fn gen_offset_indices(&self) -> TokenStream {
+        let mut body_offset = quote! {};
+
+        for field in self.fields.iter() {
+            let name = &field.field.ident;
+            let ty = &field.field.ty;
+            let key = field.ident().to_string();
+
+            body_offset.extend(quote! {
+                <#ty as burn::record::Record<B>>::offset_indices::<S>(
+                    &mut item.#name,
+                    &burn::record::record_field_path(path, #key),
+                    offset,
+                );
+            });
+        }
+
+        quote! {
+            fn offset_indices<S: burn::record::PrecisionSettings>(
+                item: &mut Self::Item<S>,
+                path: &str,
+                offset: i64,
+            ) {
+                #body_offset
+            }
+        }
+    }
 }
@@ -0,0 +1,27 @@
+//! Convert the record of a model between the recorder formats, e.g.
+//!
+//! ```sh
+//! cargo run --example convert_record -- model.mpk model.safetensors --half
+//! ```
+//!
+//! Since records are typed, replace `Model` with the module of the record to convert.
+
+#[cfg(feature = "safetensors")]
+use burn::{backend::NdArray, module::Module, nn::Linear, tensor::backend::Backend};
+
+// Only the record of the model is used, the model itself is never built.
+#[cfg(feature = "safetensors")]
+#[allow(dead_code)]
+#[derive(Module, Debug)]
+struct Model<B: Backend> {
+    fc1: Linear<B>,
+    fc2: Linear<B>,
+}
+
+fn main() {
+    #[cfg(feature = "safetensors")]
+    burn_import::convert::run_from_cli::<NdArray, ModelRecord<NdArray>>(&Default::default());
+
+    #[cfg(not(feature = "safetensors"))]
+    println!("Compiled without Safetensors feature.");
+}
@@ -11,38 +11,70 @@ fn main() {
         .input("tests/argmax/argmax.onnx")
         .input("tests/avg_pool1d/avg_pool1d.onnx")
         .input("tests/avg_pool2d/avg_pool2d.onnx")
+        .input("tests/bitshift/bitshift.onnx")
         .input("tests/batch_norm/batch_norm.onnx")
         .input("tests/cast/cast.onnx")
+        .input("tests/cast/cast_round_trip.onnx")
         .input("tests/clip/clip_opset16.onnx")
         .input("tests/clip/clip_opset7.onnx")
+        .input("tests/clip/clip_optional_inputs.onnx")
         .input("tests/concat/concat.onnx")
+        .input("tests/concat_negative_axis/concat_negative_axis.onnx")
+        .input("tests/constant_folding/constant_folding.onnx")
+        .input("tests/constant_forms/constant_forms.onnx")
         .input("tests/conv1d/conv1d.onnx")
         .input("tests/conv2d/conv2d.onnx")
+        .input("tests/conv2d/conv2d_same.onnx")
+        .input("tests/depth_to_space/depth_to_space_dcr.onnx")
+        .input("tests/depth_to_space/depth_to_space_crd.onnx")
+        .input("tests/conv_batch_norm/conv_batch_norm.onnx")
         .input("tests/cos/cos.onnx")
         .input("tests/div/div.onnx")
         .input("tests/dropout/dropout_opset16.onnx")
         .input("tests/dropout/dropout_opset7.onnx")
+        .input("tests/dropout/dropout_linear.onnx")
+        .input("tests/einsum/einsum.onnx")
         .input("tests/equal/equal.onnx")
         .input("tests/erf/erf.onnx")
         .input("tests/exp/exp.onnx")
         .input("tests/flatten/flatten.onnx")
+        .input("tests/flatten/flatten_axis.onnx")
         .input("tests/gather/gather.onnx")
+        .input("tests/gather/gather_scalar.onnx")
         .input("tests/gather_elements/gather_elements.onnx")
+        .input("tests/gather_nd/gather_nd.onnx")
         .input("tests/gelu/gelu.onnx")
+        .input("tests/gemm/gemm.onnx")
         .input("tests/global_avr_pool/global_avr_pool.onnx")
+        .input("tests/group_norm/group_norm.onnx")
+        .input("tests/global_pool/resnet_tail.onnx")
+        .input("tests/gru/gru.onnx")
+        .input("tests/instance_norm/instance_norm.onnx")
         .input("tests/layer_norm/layer_norm.onnx")
         .input("tests/linear/linear.onnx")
         .input("tests/log_softmax/log_softmax.onnx")
+        .input("tests/log_softmax_opset/log_softmax_opset11.onnx")
+        .input("tests/log_softmax_opset/log_softmax_opset13.onnx")
         .input("tests/log/log.onnx")
+        .input("tests/lstm/lstm.onnx")
         .input("tests/matmul/matmul.onnx")
+        .input("tests/matmul/matmul_broadcast.onnx")
         .input("tests/min/min.onnx")
         .input("tests/max/max.onnx")
         .input("tests/maxpool1d/maxpool1d.onnx")
         .input("tests/maxpool2d/maxpool2d.onnx")
+        .input("tests/maxpool2d_ceil_mode/maxpool2d_ceil_mode.onnx")
+        .input("tests/mean_variance_normalization/mean_variance_normalization.onnx")
+        .input("tests/modulo/modulo.onnx")
         .input("tests/mul/mul.onnx")
         .input("tests/neg/neg.onnx")
+        .input("tests/nonzero/nonzero.onnx")
         .input("tests/not/not.onnx")
+        .input("tests/one_hot/one_hot.onnx")
+        .input("tests/cum_sum/cum_sum.onnx")
+        .input("tests/unique/unique.onnx")
         .input("tests/expand/expand.onnx")
+        .input("tests/expand/expand_broadcast.onnx")
         .input("tests/greater/greater.onnx")
         .input("tests/greater_or_equal/greater_or_equal.onnx")
         .input("tests/less/less.onnx")
@@ -50,36 +82,64 @@ fn main() {
         .input("tests/recip/recip.onnx")
         .input("tests/relu/relu.onnx")
         .input("tests/leaky_relu/leaky_relu.onnx")
+        .input("tests/hard_sigmoid/hard_sigmoid.onnx")
+        .input("tests/hard_swish/hard_swish.onnx")
+        .input("tests/softplus/softplus.onnx")
+        .input("tests/softsign/softsign.onnx")
+        .input("tests/pad/pad.onnx")
         .input("tests/prelu/prelu.onnx")
+        .input("tests/prelu/prelu_per_channel.onnx")
         .input("tests/reduce_max/reduce_max.onnx")
         .input("tests/reduce_mean/reduce_mean.onnx")
+        .input("tests/reduce_min/reduce_min.onnx")
         .input("tests/reduce_sum/reduce_sum_opset13.onnx")
         .input("tests/reduce_sum/reduce_sum_opset11.onnx")
         .input("tests/reshape/reshape.onnx")
+        .input("tests/resize/resize.onnx")
+        .input("tests/scatter/scatter.onnx")
         .input("tests/shape/shape.onnx")
+        .input("tests/shape_slice/shape_slice.onnx")
         .input("tests/sigmoid/sigmoid.onnx")
         .input("tests/sign/sign.onnx")
         .input("tests/sin/sin.onnx")
+        .input("tests/slice/slice.onnx")
+        .input("tests/space_to_depth/space_to_depth.onnx")
+        .input("tests/split/split.onnx")
         .input("tests/softmax/softmax.onnx")
+        .input("tests/softmax_opset/softmax_opset11.onnx")
+        .input("tests/softmax_opset/softmax_opset13.onnx")
         .input("tests/sqrt/sqrt.onnx")
         .input("tests/sub/sub_int.onnx")
         .input("tests/sub/sub.onnx")
         .input("tests/tanh/tanh.onnx")
         .input("tests/transpose/transpose.onnx")
+        .input("tests/trilu/trilu.onnx")
+        .input("tests/unary_math/unary_math.onnx")
         .input("tests/conv_transpose2d/conv_transpose2d.onnx")
+        .input("tests/conv_transpose2d/conv_transpose2d_upsample.onnx")
         .input("tests/pow/pow.onnx")
         .input("tests/pow/pow_int.onnx")
         .input("tests/sum/sum.onnx")
         .input("tests/sum/sum_int.onnx")
+        .input("tests/top_k/top_k.onnx")
+        .input("tests/top_k/top_k_smallest.onnx")
         .input("tests/unsqueeze/unsqueeze.onnx")
         .input("tests/unsqueeze/unsqueeze_opset16.onnx")
         .input("tests/unsqueeze/unsqueeze_opset11.onnx")
+        .input("tests/unsqueeze/unsqueeze_axes_opset11.onnx")
+        .input("tests/unsqueeze/unsqueeze_axes_opset13.onnx")
         .input("tests/mask_where/mask_where.onnx")
+        .input("tests/mask_where/mask_where_scalar.onnx")
         .input("tests/squeeze/squeeze_opset16.onnx")
         .input("tests/squeeze/squeeze_opset13.onnx")
+        .input("tests/squeeze/squeeze_axes_opset11.onnx")
+        .input("tests/squeeze/squeeze_axes_opset13.onnx")
+        .input("tests/squeeze/squeeze_no_axes.onnx")
         .input("tests/random_uniform/random_uniform.onnx")
+        .input("tests/random_uniform_seeded/random_uniform_seeded.onnx")
         .input("tests/random_normal/random_normal.onnx")
         .input("tests/range/range.onnx")
+        .input("tests/range/range_constant.onnx")
         .out_dir("model/")
         .run_from_script();
 
@@ -0,0 +1,39 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/bitshift/bitshift.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # The shift amounts are broadcast over the rows of the input
+    node = helper.make_node(
+        "BitShift",
+        name="/BitShift",
+        inputs=["x", "y"],
+        outputs=["output"],
+        direction="LEFT",
+    )
+
+    graph_def = helper.make_graph(
+        nodes=[node],
+        name="BitShiftGraph",
+        inputs=[
+            helper.make_tensor_value_info("x", TensorProto.INT64, [2, 3]),
+            helper.make_tensor_value_info("y", TensorProto.INT64, [3]),
+        ],
+        outputs=[helper.make_tensor_value_info("output", TensorProto.INT64, [2, 3])],
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="bitshift",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    onnx.save(model_def, "bitshift.onnx")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,49 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/cast/cast_round_trip.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # Casting a float to an int truncates toward zero
+    to_int = helper.make_node(
+        "Cast",
+        name="/Cast",
+        inputs=["x"],
+        outputs=["x_int"],
+        to=TensorProto.INT64,
+    )
+    to_float = helper.make_node(
+        "Cast",
+        name="/Cast_1",
+        inputs=["x_int"],
+        outputs=["output"],
+        to=TensorProto.FLOAT,
+    )
+
+    # Create the graph
+    graph_def = helper.make_graph(
+        nodes=[to_int, to_float],
+        name="CastRoundTripGraph",
+        inputs=[helper.make_tensor_value_info("x", TensorProto.FLOAT, [2, 3])],
+        outputs=[
+            helper.make_tensor_value_info("x_int", TensorProto.INT64, [2, 3]),
+            helper.make_tensor_value_info("output", TensorProto.FLOAT, [2, 3]),
+        ],
+    )
+
+    # Create the model
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="cast_round_trip",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    # Save the model to a file
+    onnx.save(model_def, "cast_round_trip.onnx")
+
+
+if __name__ == "__main__":
+    main()
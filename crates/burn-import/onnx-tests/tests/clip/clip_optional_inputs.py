@@ -0,0 +1,50 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/clip/clip_optional_inputs.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # Scalar bounds given as initializers
+    min_value = helper.make_tensor("min", TensorProto.FLOAT, [], [0.3])
+    max_value = helper.make_tensor("max", TensorProto.FLOAT, [], [0.7])
+
+    # Without bounds the input is passed through
+    clip_none = helper.make_node("Clip", name="/Clip", inputs=["input"], outputs=["output1"])
+    # Only the max bound, the omitted min input is left empty
+    clip_max = helper.make_node(
+        "Clip", name="/Clip_1", inputs=["input", "", "max"], outputs=["output2"]
+    )
+    # Only the min bound, the max input is omitted
+    clip_min = helper.make_node(
+        "Clip", name="/Clip_2", inputs=["input", "min"], outputs=["output3"]
+    )
+
+    # Create the graph
+    graph_def = helper.make_graph(
+        nodes=[clip_none, clip_max, clip_min],
+        name="ClipGraph",
+        inputs=[helper.make_tensor_value_info("input", TensorProto.FLOAT, [6])],
+        outputs=[
+            helper.make_tensor_value_info("output1", TensorProto.FLOAT, [6]),
+            helper.make_tensor_value_info("output2", TensorProto.FLOAT, [6]),
+            helper.make_tensor_value_info("output3", TensorProto.FLOAT, [6]),
+        ],
+        initializer=[min_value, max_value],
+    )
+
+    # Create the model
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="clip",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    # Save the model to a file
+    onnx.save(model_def, "clip_optional_inputs.onnx")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,45 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/concat_negative_axis/concat_negative_axis.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # Concatenate three tensors of different sizes along the last dimension
+    concat = helper.make_node(
+        "Concat",
+        name="/Concat",
+        inputs=["input1", "input2", "input3"],
+        outputs=["output"],
+        axis=-1,
+    )
+
+    # Create the graph
+    graph_def = helper.make_graph(
+        nodes=[concat],
+        name="ConcatNegativeAxisGraph",
+        inputs=[
+            helper.make_tensor_value_info("input1", TensorProto.FLOAT, [1, 2, 2]),
+            helper.make_tensor_value_info("input2", TensorProto.FLOAT, [1, 2, 3]),
+            helper.make_tensor_value_info("input3", TensorProto.FLOAT, [1, 2, 1]),
+        ],
+        outputs=[
+            helper.make_tensor_value_info("output", TensorProto.FLOAT, [1, 2, 6]),
+        ],
+    )
+
+    # Create the model
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="concat_negative_axis",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    # Save the model to a file
+    onnx.save(model_def, "concat_negative_axis.onnx")
+
+
+if __name__ == "__main__":
+    main()
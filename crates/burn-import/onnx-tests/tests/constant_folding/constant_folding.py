@@ -0,0 +1,73 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/constant_folding/constant_folding.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # Compute the target shape [3, 2] of the reshape from constants only, the chain
+    # of Unsqueeze and Concat nodes is folded into a single constant during the import
+    dim0 = helper.make_node(
+        "Constant",
+        name="/Constant",
+        inputs=[],
+        outputs=["dim0"],
+        value=helper.make_tensor("dim0_value", TensorProto.INT64, [], [3]),
+    )
+    dim1 = helper.make_node(
+        "Constant",
+        name="/Constant_1",
+        inputs=[],
+        outputs=["dim1"],
+        value=helper.make_tensor("dim1_value", TensorProto.INT64, [1], [2]),
+    )
+    axes = helper.make_tensor("axes", TensorProto.INT64, [1], [0])
+
+    unsqueeze = helper.make_node(
+        "Unsqueeze",
+        name="/Unsqueeze",
+        inputs=["dim0", "axes"],
+        outputs=["dim0_unsqueezed"],
+    )
+    concat = helper.make_node(
+        "Concat",
+        name="/Concat",
+        inputs=["dim0_unsqueezed", "dim1"],
+        outputs=["shape"],
+        axis=0,
+    )
+    reshape = helper.make_node(
+        "Reshape",
+        name="/Reshape",
+        inputs=["input", "shape"],
+        outputs=["output"],
+    )
+
+    # Create the graph
+    graph_def = helper.make_graph(
+        nodes=[dim0, dim1, unsqueeze, concat, reshape],
+        name="ConstantFoldingGraph",
+        inputs=[
+            helper.make_tensor_value_info("input", TensorProto.FLOAT, [2, 3]),
+        ],
+        outputs=[
+            helper.make_tensor_value_info("output", TensorProto.FLOAT, [3, 2]),
+        ],
+        initializer=[axes],
+    )
+
+    # Create the model
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="constant_folding",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    # Save the model to a file
+    onnx.save(model_def, "constant_folding.onnx")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,79 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/constant_forms/constant_forms.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # One Constant node for each form of its value attribute
+    constants = [
+        helper.make_node(
+            "Constant",
+            inputs=[],
+            outputs=["c_tensor"],
+            value=helper.make_tensor("c_tensor", TensorProto.FLOAT, [2, 2], [1.0, 2.0, 3.0, 4.0]),
+        ),
+        helper.make_node(
+            "Constant",
+            inputs=[],
+            outputs=["c_int_tensor"],
+            value=helper.make_tensor("c_int_tensor", TensorProto.INT64, [2], [10, 20]),
+        ),
+        helper.make_node("Constant", inputs=[], outputs=["c_float"], value_float=2.0),
+        helper.make_node("Constant", inputs=[], outputs=["c_floats"], value_floats=[1.0, 2.0]),
+        helper.make_node("Constant", inputs=[], outputs=["c_int"], value_int=3),
+        helper.make_node("Constant", inputs=[], outputs=["c_ints"], value_ints=[1, 2]),
+        # The values 5 and 6 at the linearized indices 1 and 2 of a [2, 2] tensor
+        helper.make_node(
+            "Constant",
+            inputs=[],
+            outputs=["c_sparse"],
+            sparse_value=helper.make_sparse_tensor(
+                helper.make_tensor("c_sparse_values", TensorProto.FLOAT, [2], [5.0, 6.0]),
+                helper.make_tensor("c_sparse_indices", TensorProto.INT64, [2], [1, 2]),
+                [2, 2],
+            ),
+        ),
+    ]
+
+    nodes = constants + [
+        helper.make_node("Add", ["x", "c_tensor"], ["tensor_out"]),
+        helper.make_node("Add", ["i", "c_int_tensor"], ["int_tensor_out"]),
+        helper.make_node("Mul", ["x", "c_float"], ["float_out"]),
+        helper.make_node("Add", ["x", "c_floats"], ["floats_out"]),
+        helper.make_node("Add", ["i", "c_int"], ["int_out"]),
+        helper.make_node("Add", ["i", "c_ints"], ["ints_out"]),
+        helper.make_node("Add", ["x", "c_sparse"], ["sparse_out"]),
+    ]
+
+    graph_def = helper.make_graph(
+        nodes=nodes,
+        name="ConstantFormsGraph",
+        inputs=[
+            helper.make_tensor_value_info("x", TensorProto.FLOAT, [2, 2]),
+            helper.make_tensor_value_info("i", TensorProto.INT64, [2]),
+        ],
+        outputs=[
+            helper.make_tensor_value_info("tensor_out", TensorProto.FLOAT, [2, 2]),
+            helper.make_tensor_value_info("int_tensor_out", TensorProto.INT64, [2]),
+            helper.make_tensor_value_info("float_out", TensorProto.FLOAT, [2, 2]),
+            helper.make_tensor_value_info("floats_out", TensorProto.FLOAT, [2, 2]),
+            helper.make_tensor_value_info("int_out", TensorProto.INT64, [2]),
+            helper.make_tensor_value_info("ints_out", TensorProto.INT64, [2]),
+            helper.make_tensor_value_info("sparse_out", TensorProto.FLOAT, [2, 2]),
+        ],
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="constant_forms",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    onnx.save(model_def, "constant_forms.onnx")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,42 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/conv2d/conv2d_same.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # A conv with SAME_UPPER padding as exported from TensorFlow. With a stride of 2, the total
+    # padding of each spatial dimension is 1, which is added at the end.
+    weight = helper.make_tensor("weight", TensorProto.FLOAT, [1, 1, 3, 3], [1.0] * 9)
+
+    node = helper.make_node(
+        "Conv",
+        name="/Conv",
+        inputs=["input", "weight"],
+        outputs=["output"],
+        kernel_shape=[3, 3],
+        strides=[2, 2],
+        auto_pad="SAME_UPPER",
+    )
+
+    graph_def = helper.make_graph(
+        nodes=[node],
+        name="Conv2dSameGraph",
+        inputs=[helper.make_tensor_value_info("input", TensorProto.FLOAT, [1, 1, 6, 6])],
+        outputs=[helper.make_tensor_value_info("output", TensorProto.FLOAT, [1, 1, 3, 3])],
+        initializer=[weight],
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="conv2d_same",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    onnx.save(model_def, "conv2d_same.onnx")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,76 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/conv_batch_norm/conv_batch_norm.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # Two identical Conv -> BatchNormalization branches, the first one is folded into a single
+    # convolution during the import while the output of the second convolution is also a graph
+    # output, so both of its nodes are kept
+    weight = helper.make_tensor("weight", TensorProto.FLOAT, [2, 1, 1, 1], [2.0, -1.0])
+    bias = helper.make_tensor("bias", TensorProto.FLOAT, [2], [1.0, 0.0])
+    scale = helper.make_tensor("scale", TensorProto.FLOAT, [2], [1.0, 2.0])
+    beta = helper.make_tensor("beta", TensorProto.FLOAT, [2], [0.0, 1.0])
+    mean = helper.make_tensor("mean", TensorProto.FLOAT, [2], [1.0, 0.0])
+    var = helper.make_tensor("var", TensorProto.FLOAT, [2], [4.0, 1.0])
+
+    conv1 = helper.make_node(
+        "Conv",
+        name="/Conv",
+        inputs=["input", "weight", "bias"],
+        outputs=["conv1_out"],
+        kernel_shape=[1, 1],
+    )
+    batch_norm1 = helper.make_node(
+        "BatchNormalization",
+        name="/BatchNormalization",
+        inputs=["conv1_out", "scale", "beta", "mean", "var"],
+        outputs=["output1"],
+        epsilon=1e-5,
+    )
+    conv2 = helper.make_node(
+        "Conv",
+        name="/Conv_1",
+        inputs=["input", "weight", "bias"],
+        outputs=["output3"],
+        kernel_shape=[1, 1],
+    )
+    batch_norm2 = helper.make_node(
+        "BatchNormalization",
+        name="/BatchNormalization_1",
+        inputs=["output3", "scale", "beta", "mean", "var"],
+        outputs=["output2"],
+        epsilon=1e-5,
+    )
+
+    # Create the graph
+    graph_def = helper.make_graph(
+        nodes=[conv1, batch_norm1, conv2, batch_norm2],
+        name="ConvBatchNormGraph",
+        inputs=[
+            helper.make_tensor_value_info("input", TensorProto.FLOAT, [1, 1, 2, 2]),
+        ],
+        outputs=[
+            helper.make_tensor_value_info("output1", TensorProto.FLOAT, [1, 2, 2, 2]),
+            helper.make_tensor_value_info("output2", TensorProto.FLOAT, [1, 2, 2, 2]),
+            helper.make_tensor_value_info("output3", TensorProto.FLOAT, [1, 2, 2, 2]),
+        ],
+        initializer=[weight, bias, scale, beta, mean, var],
+    )
+
+    # Create the model
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="conv_batch_norm",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    # Save the model to a file
+    onnx.save(model_def, "conv_batch_norm.onnx")
+
+
+if __name__ == "__main__":
+    main()
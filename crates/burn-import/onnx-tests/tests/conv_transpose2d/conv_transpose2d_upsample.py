@@ -0,0 +1,50 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/conv_transpose2d/conv_transpose2d_upsample.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # Upsample each value into a 2x2 block with a kernel of ones, and crop the first row and
+    # column of the output with asymmetric pads
+    weight = helper.make_tensor("weight", TensorProto.FLOAT, [1, 2, 2, 2], [1.0] * 8)
+    bias = helper.make_tensor("bias", TensorProto.FLOAT, [2], [0.5, -0.5])
+
+    conv_transpose_node = helper.make_node(
+        "ConvTranspose",
+        name="/ConvTranspose",
+        inputs=["input", "weight", "bias"],
+        outputs=["output"],
+        kernel_shape=[2, 2],
+        strides=[2, 2],
+        pads=[1, 1, 0, 0],
+    )
+
+    # Create the graph
+    graph_def = helper.make_graph(
+        nodes=[conv_transpose_node],
+        name="ConvTranspose2dUpsampleGraph",
+        inputs=[
+            helper.make_tensor_value_info("input", TensorProto.FLOAT, [1, 1, 2, 2]),
+        ],
+        outputs=[
+            helper.make_tensor_value_info("output", TensorProto.FLOAT, [1, 2, 3, 3]),
+        ],
+        initializer=[weight, bias],
+    )
+
+    # Create the model
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="conv_transpose2d_upsample",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    # Save the model to a file
+    onnx.save(model_def, "conv_transpose2d_upsample.onnx")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,44 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/cum_sum/cum_sum.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # The same prefix sums along the last axis, with every combination of flags
+    nodes = [
+        helper.make_node(
+            "CumSum",
+            name=f"/CumSum{i}",
+            inputs=["input", "axis"],
+            outputs=[f"output{i}"],
+            exclusive=exclusive,
+            reverse=reverse,
+        )
+        for i, (exclusive, reverse) in enumerate([(0, 0), (1, 0), (0, 1), (1, 1)])
+    ]
+
+    graph_def = helper.make_graph(
+        nodes=nodes,
+        name="CumSumGraph",
+        inputs=[helper.make_tensor_value_info("input", TensorProto.FLOAT, [2, 3])],
+        outputs=[
+            helper.make_tensor_value_info(f"output{i}", TensorProto.FLOAT, [2, 3])
+            for i in range(4)
+        ],
+        initializer=[helper.make_tensor("axis", TensorProto.INT64, [], [-1])],
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="cum_sum",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    onnx.save(model_def, "cum_sum.onnx")
+
+
+if __name__ == "__main__":
+    main()
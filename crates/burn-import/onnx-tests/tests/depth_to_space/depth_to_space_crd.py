@@ -0,0 +1,36 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/depth_to_space/depth_to_space_crd.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    node = helper.make_node(
+        "DepthToSpace",
+        name="/DepthToSpace",
+        inputs=["input"],
+        outputs=["output"],
+        blocksize=2,
+        mode="CRD",
+    )
+
+    graph_def = helper.make_graph(
+        nodes=[node],
+        name="DepthToSpaceGraph",
+        inputs=[helper.make_tensor_value_info("input", TensorProto.FLOAT, [1, 8, 2, 3])],
+        outputs=[helper.make_tensor_value_info("output", TensorProto.FLOAT, [1, 2, 4, 6])],
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="depth_to_space_crd",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    onnx.save(model_def, "depth_to_space_crd.onnx")
+
+
+if __name__ == "__main__":
+    main()
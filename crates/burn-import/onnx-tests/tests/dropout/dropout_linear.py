@@ -0,0 +1,61 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/dropout/dropout_linear.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # Dropout followed by a linear layer, the mask output isn't used
+    nodes = [
+        helper.make_node(
+            "Dropout",
+            name="/dropout/Dropout",
+            inputs=["x", "ratio"],
+            outputs=["dropout_out", "mask"],
+        ),
+        helper.make_node(
+            "MatMul",
+            name="/linear/MatMul",
+            inputs=["dropout_out", "weight"],
+            outputs=["matmul_out"],
+        ),
+        helper.make_node(
+            "Add",
+            name="/linear/Add",
+            inputs=["matmul_out", "bias"],
+            outputs=["output"],
+        ),
+    ]
+
+    initializers = [
+        helper.make_tensor("ratio", TensorProto.FLOAT, [], [0.5]),
+        helper.make_tensor(
+            "weight",
+            TensorProto.FLOAT,
+            [4, 3],
+            [0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0, 1.1, 1.2],
+        ),
+        helper.make_tensor("bias", TensorProto.FLOAT, [3], [0.1, -0.1, 0.5]),
+    ]
+
+    graph_def = helper.make_graph(
+        nodes=nodes,
+        name="DropoutLinearGraph",
+        inputs=[helper.make_tensor_value_info("x", TensorProto.FLOAT, [2, 4])],
+        outputs=[helper.make_tensor_value_info("output", TensorProto.FLOAT, [2, 3])],
+        initializer=initializers,
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="dropout_linear",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    onnx.save(model_def, "dropout_linear.onnx")
+
+
+if __name__ == "__main__":
+    main()
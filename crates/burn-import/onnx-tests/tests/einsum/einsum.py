@@ -0,0 +1,63 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/einsum/einsum.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # One node per supported equation form: a batched matrix product with a transposed rhs,
+    # a permutation of the last two dimensions and a plain matrix product
+    batched_matmul = helper.make_node(
+        "Einsum",
+        name="/Einsum",
+        inputs=["x", "y"],
+        outputs=["output1"],
+        equation="bik,bjk->bij",
+    )
+    transpose = helper.make_node(
+        "Einsum",
+        name="/Einsum_1",
+        inputs=["x"],
+        outputs=["output2"],
+        equation="...ij->...ji",
+    )
+    matmul = helper.make_node(
+        "Einsum",
+        name="/Einsum_2",
+        inputs=["a", "b"],
+        outputs=["output3"],
+        equation="ij,jk->ik",
+    )
+
+    # Create the graph
+    graph_def = helper.make_graph(
+        nodes=[batched_matmul, transpose, matmul],
+        name="EinsumGraph",
+        inputs=[
+            helper.make_tensor_value_info("x", TensorProto.FLOAT, [2, 2, 3]),
+            helper.make_tensor_value_info("y", TensorProto.FLOAT, [2, 4, 3]),
+            helper.make_tensor_value_info("a", TensorProto.FLOAT, [2, 3]),
+            helper.make_tensor_value_info("b", TensorProto.FLOAT, [3, 2]),
+        ],
+        outputs=[
+            helper.make_tensor_value_info("output1", TensorProto.FLOAT, [2, 2, 4]),
+            helper.make_tensor_value_info("output2", TensorProto.FLOAT, [2, 3, 2]),
+            helper.make_tensor_value_info("output3", TensorProto.FLOAT, [2, 2]),
+        ],
+    )
+
+    # Create the model
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="einsum",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    # Save the model to a file
+    onnx.save(model_def, "einsum.onnx")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,42 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/expand/expand_broadcast.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # The dims of size 1 are expanded, a size of 1 in the shape keeps the input size
+    nodes = [
+        helper.make_node(
+            "Expand",
+            name="/Expand",
+            inputs=["input_tensor", "shape"],
+            outputs=["output"],
+        ),
+    ]
+
+    initializers = [
+        helper.make_tensor("shape", TensorProto.INT64, [3], [2, 1, 4]),
+    ]
+
+    graph_def = helper.make_graph(
+        nodes=nodes,
+        name="ExpandBroadcastGraph",
+        inputs=[helper.make_tensor_value_info("input_tensor", TensorProto.FLOAT, [1, 3, 1])],
+        outputs=[helper.make_tensor_value_info("output", TensorProto.FLOAT, [2, 3, 4])],
+        initializer=initializers,
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="expand_broadcast",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    onnx.save(model_def, "expand_broadcast.onnx")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,44 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/flatten/flatten_axis.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+# Axis of each Flatten node with the shape of its output for an input of shape [2, 3, 4, 5]
+AXES = [(1, [2, 60]), (0, [1, 120]), (-2, [6, 20])]
+
+
+def main() -> None:
+    nodes = [
+        helper.make_node(
+            "Flatten",
+            name=f"/Flatten_{i}",
+            inputs=["x"],
+            outputs=[f"output{i}"],
+            axis=axis,
+        )
+        for i, (axis, _) in enumerate(AXES)
+    ]
+
+    graph_def = helper.make_graph(
+        nodes=nodes,
+        name="FlattenAxisGraph",
+        inputs=[helper.make_tensor_value_info("x", TensorProto.FLOAT, [2, 3, 4, 5])],
+        outputs=[
+            helper.make_tensor_value_info(f"output{i}", TensorProto.FLOAT, shape)
+            for i, (_, shape) in enumerate(AXES)
+        ],
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="flatten_axis",
+        opset_imports=[helper.make_operatorsetid("", 13)],
+    )
+
+    onnx.save(model_def, "flatten_axis.onnx")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,59 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/gather/gather_scalar.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # Scalar index given by a constant node, which removes the gathered dimension
+    index_tensor = helper.make_tensor(
+        name="index",
+        data_type=TensorProto.INT64,
+        dims=[],
+        vals=[1],
+    )
+
+    index_node = helper.make_node(
+        "Constant",
+        name="index_constant",
+        inputs=[],
+        outputs=["index"],
+        value=index_tensor,
+    )
+
+    # Gather along the last dimension, i.e. `x[:, 1]`
+    gather_node = helper.make_node(
+        "Gather",
+        name="/Gather",
+        inputs=["input", "index"],
+        outputs=["output"],
+        axis=-1,
+    )
+
+    # Create the graph
+    graph_def = helper.make_graph(
+        nodes=[index_node, gather_node],
+        name="GatherScalarGraph",
+        inputs=[
+            helper.make_tensor_value_info("input", TensorProto.FLOAT, [2, 3]),
+        ],
+        outputs=[
+            helper.make_tensor_value_info("output", TensorProto.FLOAT, [2]),
+        ],
+    )
+
+    # Create the model
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="gather_scalar",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    # Save the model to a file
+    onnx.save(model_def, "gather_scalar.onnx")
+
+
+if __name__ == "__main__":
+    main()
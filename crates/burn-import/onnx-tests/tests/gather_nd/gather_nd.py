@@ -0,0 +1,38 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/gather_nd/gather_nd.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # Each pair of indices selects a row of the last axis
+    gather_nd = helper.make_node(
+        "GatherND",
+        name="/GatherND",
+        inputs=["data", "indices"],
+        outputs=["output"],
+    )
+
+    graph_def = helper.make_graph(
+        nodes=[gather_nd],
+        name="GatherNDGraph",
+        inputs=[
+            helper.make_tensor_value_info("data", TensorProto.FLOAT, [2, 2, 2]),
+            helper.make_tensor_value_info("indices", TensorProto.INT64, [3, 2]),
+        ],
+        outputs=[helper.make_tensor_value_info("output", TensorProto.FLOAT, [3, 2])],
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="gather_nd",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    onnx.save(model_def, "gather_nd.onnx")
+
+
+if __name__ == "__main__":
+    main()
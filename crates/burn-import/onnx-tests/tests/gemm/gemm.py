@@ -0,0 +1,58 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/gemm/gemm.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+WEIGHT = [1.0, 0.0, -1.0, 0.5, 0.5, 0.5, 2.0, 1.0, 0.0, 0.0, -1.0, 1.0]
+BIAS = [1.0, 2.0, 3.0, 4.0]
+
+
+def gemm(name: str, inputs: list, output: str) -> onnx.NodeProto:
+    return helper.make_node(
+        "Gemm",
+        name=name,
+        inputs=inputs,
+        outputs=[output],
+        alpha=0.5,
+        beta=2.0,
+        transB=1,
+    )
+
+
+def main() -> None:
+    # With constant weight and bias, the Gemm node is imported as a linear layer
+    linear = gemm("/Gemm", ["x", "weight", "bias"], "linear_output")
+    # With runtime inputs, the Gemm node is imported as a matrix multiplication
+    matmul = gemm("/Gemm_1", ["x", "y", "z"], "gemm_output")
+
+    graph_def = helper.make_graph(
+        nodes=[linear, matmul],
+        name="GemmGraph",
+        inputs=[
+            helper.make_tensor_value_info("x", TensorProto.FLOAT, [2, 3]),
+            helper.make_tensor_value_info("y", TensorProto.FLOAT, [4, 3]),
+            helper.make_tensor_value_info("z", TensorProto.FLOAT, [4]),
+        ],
+        outputs=[
+            helper.make_tensor_value_info("linear_output", TensorProto.FLOAT, [2, 4]),
+            helper.make_tensor_value_info("gemm_output", TensorProto.FLOAT, [2, 4]),
+        ],
+        initializer=[
+            helper.make_tensor("weight", TensorProto.FLOAT, [4, 3], WEIGHT),
+            helper.make_tensor("bias", TensorProto.FLOAT, [4], BIAS),
+        ],
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="gemm",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    onnx.save(model_def, "gemm.onnx")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,93 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/global_pool/resnet_tail.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # Tail of a classification backbone: conv -> global average pool -> flatten -> linear, with a
+    # global max pool of the conv output as a second output
+    nodes = [
+        helper.make_node(
+            "Conv",
+            name="/conv/Conv",
+            inputs=["x", "conv_weight", "conv_bias"],
+            outputs=["conv_out"],
+            kernel_shape=[1, 1],
+            strides=[1, 1],
+            pads=[0, 0, 0, 0],
+            dilations=[1, 1],
+            group=1,
+        ),
+        helper.make_node(
+            "GlobalAveragePool",
+            name="/avgpool/GlobalAveragePool",
+            inputs=["conv_out"],
+            outputs=["avgpool_out"],
+        ),
+        helper.make_node(
+            "Flatten",
+            name="/Flatten",
+            inputs=["avgpool_out"],
+            outputs=["flatten_out"],
+            axis=1,
+        ),
+        helper.make_node(
+            "MatMul",
+            name="/fc/MatMul",
+            inputs=["flatten_out", "fc_weight"],
+            outputs=["matmul_out"],
+        ),
+        helper.make_node(
+            "Add", name="/fc/Add", inputs=["matmul_out", "fc_bias"], outputs=["output1"]
+        ),
+        helper.make_node(
+            "GlobalMaxPool",
+            name="/maxpool/GlobalMaxPool",
+            inputs=["conv_out"],
+            outputs=["maxpool_out"],
+        ),
+        helper.make_node(
+            "Flatten",
+            name="/Flatten_1",
+            inputs=["maxpool_out"],
+            outputs=["output2"],
+            axis=1,
+        ),
+    ]
+
+    initializers = [
+        helper.make_tensor(
+            "conv_weight", TensorProto.FLOAT, [3, 2, 1, 1], [1.0, 0.0, 0.0, 1.0, 1.0, -1.0]
+        ),
+        helper.make_tensor("conv_bias", TensorProto.FLOAT, [3], [0.0, 1.0, -1.0]),
+        helper.make_tensor(
+            "fc_weight", TensorProto.FLOAT, [3, 2], [1.0, 0.0, 0.0, 1.0, 1.0, 1.0]
+        ),
+        helper.make_tensor("fc_bias", TensorProto.FLOAT, [2], [0.5, -0.5]),
+    ]
+
+    graph_def = helper.make_graph(
+        nodes=nodes,
+        name="ResnetTailGraph",
+        inputs=[helper.make_tensor_value_info("x", TensorProto.FLOAT, [1, 2, 2, 2])],
+        outputs=[
+            helper.make_tensor_value_info("output1", TensorProto.FLOAT, [1, 2]),
+            helper.make_tensor_value_info("output2", TensorProto.FLOAT, [1, 3]),
+        ],
+        initializer=initializers,
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="resnet_tail",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    onnx.save(model_def, "resnet_tail.onnx")
+
+
+if __name__ == "__main__":
+    main()
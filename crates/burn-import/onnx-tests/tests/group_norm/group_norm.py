@@ -0,0 +1,41 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/group_norm/group_norm.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # Opset 18 has a scale and a bias per group
+    group_norm = helper.make_node(
+        "GroupNormalization",
+        name="/GroupNormalization",
+        inputs=["input", "scale", "bias"],
+        outputs=["output"],
+        epsilon=1e-5,
+        num_groups=2,
+    )
+
+    graph_def = helper.make_graph(
+        nodes=[group_norm],
+        name="GroupNormGraph",
+        inputs=[helper.make_tensor_value_info("input", TensorProto.FLOAT, [2, 4, 2, 2])],
+        outputs=[helper.make_tensor_value_info("output", TensorProto.FLOAT, [2, 4, 2, 2])],
+        initializer=[
+            helper.make_tensor("scale", TensorProto.FLOAT, [2], [0.5, 2.0]),
+            helper.make_tensor("bias", TensorProto.FLOAT, [2], [0.1, -0.2]),
+        ],
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="group_norm",
+        opset_imports=[helper.make_operatorsetid("", 18)],
+    )
+
+    onnx.save(model_def, "group_norm.onnx")
+
+
+if __name__ == "__main__":
+    main()
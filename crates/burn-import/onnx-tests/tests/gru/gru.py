@@ -0,0 +1,52 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/gru/gru.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # A single feature GRU with the update, reset and hidden gates weights stacked as in ONNX,
+    # the reset gate is applied after the recurrence transformation (linear_before_reset=1)
+    weight = helper.make_tensor("W", TensorProto.FLOAT, [1, 3, 1], [0.5, 0.6, 0.7])
+    recurrence = helper.make_tensor("R", TensorProto.FLOAT, [1, 3, 1], [0.5, 0.6, 0.7])
+    bias = helper.make_tensor("B", TensorProto.FLOAT, [1, 6], [0.0, 0.0, 0.1, 0.0, 0.0, 0.1])
+
+    gru = helper.make_node(
+        "GRU",
+        name="/GRU",
+        inputs=["input", "W", "R", "B", "", "initial_h"],
+        outputs=["output", "hidden"],
+        hidden_size=1,
+        linear_before_reset=1,
+    )
+
+    # Create the graph
+    graph_def = helper.make_graph(
+        nodes=[gru],
+        name="GruGraph",
+        inputs=[
+            helper.make_tensor_value_info("input", TensorProto.FLOAT, [2, 1, 1]),
+            helper.make_tensor_value_info("initial_h", TensorProto.FLOAT, [1, 1, 1]),
+        ],
+        outputs=[
+            helper.make_tensor_value_info("output", TensorProto.FLOAT, [2, 1, 1, 1]),
+            helper.make_tensor_value_info("hidden", TensorProto.FLOAT, [1, 1, 1]),
+        ],
+        initializer=[weight, recurrence, bias],
+    )
+
+    # Create the model
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="gru",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    # Save the model to a file
+    onnx.save(model_def, "gru.onnx")
+
+
+if __name__ == "__main__":
+    main()
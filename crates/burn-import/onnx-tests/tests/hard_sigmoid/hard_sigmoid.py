@@ -0,0 +1,37 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/hard_sigmoid/hard_sigmoid.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # Non-default alpha and beta to check that the attributes are read
+    node = helper.make_node(
+        "HardSigmoid",
+        name="/HardSigmoid",
+        inputs=["input"],
+        outputs=["output"],
+        alpha=0.25,
+        beta=0.4,
+    )
+
+    graph_def = helper.make_graph(
+        nodes=[node],
+        name="HardSigmoidGraph",
+        inputs=[helper.make_tensor_value_info("input", TensorProto.FLOAT, [9])],
+        outputs=[helper.make_tensor_value_info("output", TensorProto.FLOAT, [9])],
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="hard_sigmoid",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    onnx.save(model_def, "hard_sigmoid.onnx")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,39 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/instance_norm/instance_norm.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    instance_norm = helper.make_node(
+        "InstanceNormalization",
+        name="/InstanceNormalization",
+        inputs=["input", "scale", "bias"],
+        outputs=["output"],
+        epsilon=1e-5,
+    )
+
+    graph_def = helper.make_graph(
+        nodes=[instance_norm],
+        name="InstanceNormGraph",
+        inputs=[helper.make_tensor_value_info("input", TensorProto.FLOAT, [2, 4, 2, 2])],
+        outputs=[helper.make_tensor_value_info("output", TensorProto.FLOAT, [2, 4, 2, 2])],
+        initializer=[
+            helper.make_tensor("scale", TensorProto.FLOAT, [4], [1.0, 0.5, 2.0, -1.0]),
+            helper.make_tensor("bias", TensorProto.FLOAT, [4], [0.0, 0.1, -0.1, 0.2]),
+        ],
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="instance_norm",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    onnx.save(model_def, "instance_norm.onnx")
+
+
+if __name__ == "__main__":
+    main()
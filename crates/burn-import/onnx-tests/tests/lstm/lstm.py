@@ -0,0 +1,91 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/lstm/lstm.onnx
+
+import math
+
+import onnx
+from onnx import helper, TensorProto
+
+# A bidirectional single feature LSTM, with the input, output, forget and cell gates weights
+# stacked as in ONNX for the forward then the reverse direction
+WEIGHT = [[0.5, 0.6, 0.7, 0.8], [0.1, 0.2, 0.3, 0.4]]
+RECURRENCE = [[0.2, 0.3, 0.4, 0.5], [0.6, 0.5, 0.4, 0.3]]
+# The input biases then the recurrence biases of each direction
+BIAS = [
+    [0.1, 0.0, 0.0, 0.0, 0.0, 0.0, 0.1, 0.0],
+    [0.0, 0.1, 0.0, 0.0, 0.0, 0.0, 0.0, 0.1],
+]
+
+
+def reference(inputs, initial_h, initial_c):
+    """ONNX reference LSTM with the default activations, printing the expected outputs."""
+    sigmoid = lambda x: 1 / (1 + math.exp(-x))  # noqa: E731
+    output = [[None, None] for _ in inputs]
+    states = []
+
+    for direction in range(2):
+        hidden, cell = initial_h[direction], initial_c[direction]
+        steps = range(len(inputs)) if direction == 0 else reversed(range(len(inputs)))
+
+        for step in steps:
+            w, r, b = WEIGHT[direction], RECURRENCE[direction], BIAS[direction]
+            gate = [w[k] * inputs[step] + r[k] * hidden + b[k] + b[4 + k] for k in range(4)]
+            i, o, f, c = sigmoid(gate[0]), sigmoid(gate[1]), sigmoid(gate[2]), math.tanh(gate[3])
+            cell = f * cell + i * c
+            hidden = o * math.tanh(cell)
+            output[step][direction] = hidden
+
+        states.append((hidden, cell))
+
+    print("Test output: {}".format(output))
+    print("Test hidden: {}".format([state[0] for state in states]))
+    print("Test cell: {}".format([state[1] for state in states]))
+
+
+def main() -> None:
+    weight = helper.make_tensor("W", TensorProto.FLOAT, [2, 4, 1], sum(WEIGHT, []))
+    recurrence = helper.make_tensor("R", TensorProto.FLOAT, [2, 4, 1], sum(RECURRENCE, []))
+    bias = helper.make_tensor("B", TensorProto.FLOAT, [2, 8], sum(BIAS, []))
+
+    lstm = helper.make_node(
+        "LSTM",
+        name="/LSTM",
+        inputs=["input", "W", "R", "B", "", "initial_h", "initial_c"],
+        outputs=["output", "hidden", "cell"],
+        hidden_size=1,
+        direction="bidirectional",
+    )
+
+    # Create the graph
+    graph_def = helper.make_graph(
+        nodes=[lstm],
+        name="LstmGraph",
+        inputs=[
+            helper.make_tensor_value_info("input", TensorProto.FLOAT, [2, 1, 1]),
+            helper.make_tensor_value_info("initial_h", TensorProto.FLOAT, [2, 1, 1]),
+            helper.make_tensor_value_info("initial_c", TensorProto.FLOAT, [2, 1, 1]),
+        ],
+        outputs=[
+            helper.make_tensor_value_info("output", TensorProto.FLOAT, [2, 2, 1, 1]),
+            helper.make_tensor_value_info("hidden", TensorProto.FLOAT, [2, 1, 1]),
+            helper.make_tensor_value_info("cell", TensorProto.FLOAT, [2, 1, 1]),
+        ],
+        initializer=[weight, recurrence, bias],
+    )
+
+    # Create the model
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="lstm",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    # Save the model to a file
+    onnx.save(model_def, "lstm.onnx")
+
+    reference([0.1, 0.2], [0.0, 0.1], [0.0, 0.2])
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,41 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/mask_where/mask_where_scalar.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # The scalar condition and the values of y are broadcast to the shape of x
+    nodes = [
+        helper.make_node(
+            "Where",
+            name="/Where",
+            inputs=["condition", "x", "y"],
+            outputs=["output"],
+        ),
+    ]
+
+    graph_def = helper.make_graph(
+        nodes=nodes,
+        name="MaskWhereScalarGraph",
+        inputs=[
+            helper.make_tensor_value_info("condition", TensorProto.BOOL, []),
+            helper.make_tensor_value_info("x", TensorProto.FLOAT, [2, 2]),
+            helper.make_tensor_value_info("y", TensorProto.FLOAT, [1]),
+        ],
+        outputs=[helper.make_tensor_value_info("output", TensorProto.FLOAT, [2, 2])],
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="mask_where_scalar",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    onnx.save(model_def, "mask_where_scalar.onnx")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,50 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/matmul/matmul_broadcast.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # Products between inputs of different ranks, broadcasting the batch dimensions
+    nodes = [
+        # 3D x 2D
+        helper.make_node("MatMul", name="/MatMul", inputs=["a", "w"], outputs=["output_3d"]),
+        # 4D x 2D
+        helper.make_node("MatMul", name="/MatMul_1", inputs=["b", "w"], outputs=["output_4d"]),
+        # 1D x 2D (vector-matrix)
+        helper.make_node("MatMul", name="/MatMul_2", inputs=["v", "w"], outputs=["output_vm"]),
+        # 2D x 1D (matrix-vector)
+        helper.make_node("MatMul", name="/MatMul_3", inputs=["m", "v"], outputs=["output_mv"]),
+    ]
+
+    graph_def = helper.make_graph(
+        nodes=nodes,
+        name="MatMulBroadcastGraph",
+        inputs=[
+            helper.make_tensor_value_info("a", TensorProto.FLOAT, [2, 3, 4]),
+            helper.make_tensor_value_info("b", TensorProto.FLOAT, [2, 1, 3, 4]),
+            helper.make_tensor_value_info("w", TensorProto.FLOAT, [4, 2]),
+            helper.make_tensor_value_info("v", TensorProto.FLOAT, [4]),
+            helper.make_tensor_value_info("m", TensorProto.FLOAT, [3, 4]),
+        ],
+        outputs=[
+            helper.make_tensor_value_info("output_3d", TensorProto.FLOAT, [2, 3, 2]),
+            helper.make_tensor_value_info("output_4d", TensorProto.FLOAT, [2, 1, 3, 2]),
+            helper.make_tensor_value_info("output_vm", TensorProto.FLOAT, [2]),
+            helper.make_tensor_value_info("output_mv", TensorProto.FLOAT, [3]),
+        ],
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="matmul_broadcast",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    onnx.save(model_def, "matmul_broadcast.onnx")
+
+
+if __name__ == "__main__":
+    main()
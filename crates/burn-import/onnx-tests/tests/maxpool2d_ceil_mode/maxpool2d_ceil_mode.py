@@ -0,0 +1,51 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/maxpool2d_ceil_mode/maxpool2d_ceil_mode.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # The same pooling on an odd-sized input, with the output size rounded down and up.
+    floor = helper.make_node(
+        "MaxPool",
+        name="/MaxPool_floor",
+        inputs=["input"],
+        outputs=["floor"],
+        kernel_shape=[2, 2],
+        strides=[2, 2],
+        ceil_mode=0,
+    )
+    ceil = helper.make_node(
+        "MaxPool",
+        name="/MaxPool_ceil",
+        inputs=["input"],
+        outputs=["ceil", "indices"],
+        kernel_shape=[2, 2],
+        strides=[2, 2],
+        ceil_mode=1,
+    )
+
+    graph_def = helper.make_graph(
+        nodes=[floor, ceil],
+        name="MaxPoolCeilModeGraph",
+        inputs=[helper.make_tensor_value_info("input", TensorProto.FLOAT, [1, 2, 5, 5])],
+        outputs=[
+            helper.make_tensor_value_info("floor", TensorProto.FLOAT, [1, 2, 2, 2]),
+            helper.make_tensor_value_info("ceil", TensorProto.FLOAT, [1, 2, 3, 3]),
+            helper.make_tensor_value_info("indices", TensorProto.INT64, [1, 2, 3, 3]),
+        ],
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="maxpool2d_ceil_mode",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    onnx.save(model_def, "maxpool2d_ceil_mode.onnx")
+
+
+if __name__ == "__main__":
+    main()
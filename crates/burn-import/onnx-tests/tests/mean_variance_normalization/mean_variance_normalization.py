@@ -0,0 +1,50 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/mean_variance_normalization/mean_variance_normalization.onnx
+
+import numpy as np
+import onnx
+from onnx import helper, TensorProto
+
+
+def reference(x, axes):
+    # Same formula as the ONNX function, the epsilon being added to the standard deviation
+    mean = np.mean(x, axis=axes, keepdims=True)
+    std = np.sqrt(np.mean(np.power(x, 2), axis=axes, keepdims=True) - np.power(mean, 2))
+    return (x - mean) / (std + 1e-9)
+
+
+def main() -> None:
+    nodes = [
+        # Default axes [0, 2, 3], normalizing each channel
+        helper.make_node("MeanVarianceNormalization", ["x"], ["channels"]),
+        helper.make_node("MeanVarianceNormalization", ["x"], ["last"], axes=[-1]),
+    ]
+
+    graph_def = helper.make_graph(
+        nodes=nodes,
+        name="MeanVarianceNormalizationGraph",
+        inputs=[helper.make_tensor_value_info("x", TensorProto.FLOAT, [2, 2, 1, 2])],
+        outputs=[
+            helper.make_tensor_value_info("channels", TensorProto.FLOAT, [2, 2, 1, 2]),
+            helper.make_tensor_value_info("last", TensorProto.FLOAT, [2, 2, 1, 2]),
+        ],
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="mean_variance_normalization",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    onnx.save(model_def, "mean_variance_normalization.onnx")
+
+    # The second channel is constant, its variance being zero
+    x = np.array([[[[1.0, 2.0]], [[3.0, 3.0]]], [[[3.0, 6.0]], [[3.0, 3.0]]]], dtype=np.float32)
+    print(f"Test input data: {x}")
+    print(f"Test output channels: {reference(x, (0, 2, 3))}")
+    print(f"Test output last: {reference(x, (3,))}")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,64 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/modulo/modulo.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # The integer remainder has the sign of the divisor, the divisor being broadcast
+    int_mod = helper.make_node(
+        "Mod",
+        name="/Mod",
+        inputs=["x", "y"],
+        outputs=["int_mod"],
+        fmod=0,
+    )
+
+    # The float remainder has the sign of the dividend
+    float_mod = helper.make_node(
+        "Mod",
+        name="/Mod_1",
+        inputs=["a", "b"],
+        outputs=["float_mod"],
+        fmod=1,
+    )
+
+    # The exponents are broadcast over the rows of the base
+    pow = helper.make_node(
+        "Pow",
+        name="/Pow",
+        inputs=["base", "exponent"],
+        outputs=["pow"],
+    )
+
+    graph_def = helper.make_graph(
+        nodes=[int_mod, float_mod, pow],
+        name="ModuloGraph",
+        inputs=[
+            helper.make_tensor_value_info("x", TensorProto.INT64, [2, 3]),
+            helper.make_tensor_value_info("y", TensorProto.INT64, [3]),
+            helper.make_tensor_value_info("a", TensorProto.FLOAT, [4]),
+            helper.make_tensor_value_info("b", TensorProto.FLOAT, [4]),
+            helper.make_tensor_value_info("base", TensorProto.FLOAT, [2, 3]),
+            helper.make_tensor_value_info("exponent", TensorProto.FLOAT, [3]),
+        ],
+        outputs=[
+            helper.make_tensor_value_info("int_mod", TensorProto.INT64, [2, 3]),
+            helper.make_tensor_value_info("float_mod", TensorProto.FLOAT, [4]),
+            helper.make_tensor_value_info("pow", TensorProto.FLOAT, [2, 3]),
+        ],
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="modulo",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    onnx.save(model_def, "modulo.onnx")
+
+
+if __name__ == "__main__":
+    main()
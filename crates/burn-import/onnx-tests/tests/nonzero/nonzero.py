@@ -0,0 +1,63 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/nonzero/nonzero.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # The coordinates of the true elements of the mask, one column per element
+    coordinates = helper.make_node(
+        "NonZero",
+        name="/NonZero",
+        inputs=["mask"],
+        outputs=["coordinates"],
+    )
+
+    # The non-zero values are gathered with their indices, a common mask-to-index pattern
+    indices = helper.make_node(
+        "NonZero",
+        name="/NonZero_1",
+        inputs=["values"],
+        outputs=["indices_2d"],
+    )
+    squeeze = helper.make_node(
+        "Squeeze",
+        name="/Squeeze",
+        inputs=["indices_2d", "axes"],
+        outputs=["indices"],
+    )
+    gather = helper.make_node(
+        "Gather",
+        name="/Gather",
+        inputs=["values", "indices"],
+        outputs=["gathered"],
+        axis=0,
+    )
+
+    graph_def = helper.make_graph(
+        nodes=[coordinates, indices, squeeze, gather],
+        name="NonZeroGraph",
+        inputs=[
+            helper.make_tensor_value_info("mask", TensorProto.BOOL, [2, 3]),
+            helper.make_tensor_value_info("values", TensorProto.FLOAT, [5]),
+        ],
+        outputs=[
+            helper.make_tensor_value_info("coordinates", TensorProto.INT64, [2, "n"]),
+            helper.make_tensor_value_info("gathered", TensorProto.FLOAT, ["m"]),
+        ],
+        initializer=[helper.make_tensor("axes", TensorProto.INT64, [1], [0])],
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="nonzero",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    onnx.save(model_def, "nonzero.onnx")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,40 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/one_hot/one_hot.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # The classes are encoded along a new first axis
+    one_hot = helper.make_node(
+        "OneHot",
+        name="/OneHot",
+        inputs=["indices", "depth", "values"],
+        outputs=["output"],
+        axis=0,
+    )
+
+    graph_def = helper.make_graph(
+        nodes=[one_hot],
+        name="OneHotGraph",
+        inputs=[helper.make_tensor_value_info("indices", TensorProto.INT64, [2, 2])],
+        outputs=[helper.make_tensor_value_info("output", TensorProto.FLOAT, [3, 2, 2])],
+        initializer=[
+            helper.make_tensor("depth", TensorProto.INT64, [], [3]),
+            helper.make_tensor("values", TensorProto.FLOAT, [2], [-1.0, 3.0]),
+        ],
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="one_hot",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    onnx.save(model_def, "one_hot.onnx")
+
+
+if __name__ == "__main__":
+    main()
@@ -1,5 +1,7 @@
 #![no_std]
 
+extern crate alloc;
+
 /// Include generated models in the `model` directory in the target directory.
 macro_rules! include_models {
     ($($model:ident),*) => {
@@ -19,75 +21,135 @@ include_models!(
     add,
     argmax,
     avg_pool2d,
+    bitshift,
     avg_pool1d,
     batch_norm,
     cast,
+    cast_round_trip,
     clip_opset16,
     clip_opset7,
+    clip_optional_inputs,
     concat,
+    concat_negative_axis,
+    constant_folding,
+    constant_forms,
     conv1d,
     conv2d,
+    conv2d_same,
+    depth_to_space_dcr,
+    depth_to_space_crd,
+    conv_batch_norm,
     cos,
     div,
     dropout_opset16,
     dropout_opset7,
+    dropout_linear,
+    einsum,
     equal,
     erf,
     exp,
     expand,
+    expand_broadcast,
     flatten,
+    flatten_axis,
     gather,
+    gather_scalar,
     gather_elements,
+    gather_nd,
     gelu,
+    gemm,
     global_avr_pool,
+    group_norm,
+    gru,
+    hard_sigmoid,
+    hard_swish,
+    instance_norm,
     layer_norm,
     leaky_relu,
     linear,
     log_softmax,
+    log_softmax_opset11,
+    log_softmax_opset13,
     log,
+    lstm,
     mask_where,
+    mask_where_scalar,
     matmul,
+    matmul_broadcast,
     min,
     max,
     maxpool1d,
     maxpool2d,
+    maxpool2d_ceil_mode,
+    mean_variance_normalization,
+    modulo,
     mul,
     neg,
+    nonzero,
     not,
+    one_hot,
+    cum_sum,
+    unique,
     greater,
     greater_or_equal,
     less,
     less_or_equal,
+    pad,
     prelu,
+    prelu_per_channel,
     range,
+    range_constant,
     recip,
     reduce_max,
     reduce_mean,
+    reduce_min,
     reduce_sum_opset13,
     reduce_sum_opset11,
     relu,
     reshape,
+    resize,
+    resnet_tail,
+    scatter,
     shape,
+    shape_slice,
     sigmoid,
     sign,
     sin,
+    slice,
+    space_to_depth,
+    split,
     softmax,
+    softmax_opset11,
+    softmax_opset13,
+    softplus,
+    softsign,
     sqrt,
     sub_int,
     sub,
     sum,
     sum_int,
+    top_k,
+    top_k_smallest,
     tanh,
     transpose,
+    trilu,
+    unary_math,
     conv_transpose2d,
+    conv_transpose2d_upsample,
     pow,
     pow_int,
     unsqueeze,
     unsqueeze_opset16,
     unsqueeze_opset11,
+    unsqueeze_axes_opset11,
+    unsqueeze_axes_opset13,
     squeeze_opset16,
     squeeze_opset13,
+    squeeze_axes_opset11,
+    squeeze_axes_opset13,
+    squeeze_no_axes,
     random_uniform,
+    random_uniform_seeded,
     random_normal
 );
 
@@ -97,6 +159,7 @@ mod tests {
 
     use super::*;
 
+    use alloc::vec::Vec;
     use burn::tensor::{Bool, Data, Int, Shape, Tensor};
 
     use float_cmp::ApproxEq;
@@ -279,6 +342,48 @@ mod tests {
         assert_eq!(output_mv.to_data(), expected_mv);
     }
 
+    #[test]
+    fn matmul_broadcast() {
+        let device = Default::default();
+        let model: matmul_broadcast::Model<Backend> = matmul_broadcast::Model::new(&device);
+
+        let a = Tensor::<Backend, 1, Int>::arange(0..24, &device)
+            .reshape([2, 3, 4])
+            .float();
+        let b = Tensor::<Backend, 1, Int>::arange(0..24, &device)
+            .reshape([2, 1, 3, 4])
+            .float();
+        let w = Tensor::<Backend, 1, Int>::arange(0..8, &device)
+            .reshape([4, 2])
+            .float();
+        let v = Tensor::<Backend, 1, Int>::arange(0..4, &device).float();
+        let m = Tensor::<Backend, 1, Int>::arange(0..12, &device)
+            .reshape([3, 4])
+            .float();
+
+        let (output_3d, output_4d, output_vm, output_mv) = model.forward(a, b, w, v, m);
+
+        // `a @ w` where the rhs matrix is broadcast over the batch
+        let expected_3d = Data::from([
+            [[28., 34.], [76., 98.], [124., 162.]],
+            [[172., 226.], [220., 290.], [268., 354.]],
+        ]);
+        // `b @ w` with two leading batch dimensions
+        let expected_4d = Data::from([
+            [[[28., 34.], [76., 98.], [124., 162.]]],
+            [[[172., 226.], [220., 290.], [268., 354.]]],
+        ]);
+        // `v @ w` where the lhs vector is a row vector
+        let expected_vm = Data::from([28., 34.]);
+        // `m @ v` where the rhs vector is a column vector
+        let expected_mv = Data::from([14., 38., 62.]);
+
+        assert_eq!(output_3d.to_data(), expected_3d);
+        assert_eq!(output_4d.to_data(), expected_4d);
+        assert_eq!(output_vm.to_data(), expected_vm);
+        assert_eq!(output_mv.to_data(), expected_mv);
+    }
+
     #[test]
     fn concat_tensors() {
         // Initialize the model
@@ -295,6 +400,63 @@ mod tests {
         assert_eq!(output.shape(), expected);
     }
 
+    #[test]
+    fn concat_tensors_negative_axis() {
+        // Initialize the model
+        let device = Default::default();
+        let model: concat_negative_axis::Model<Backend> = concat_negative_axis::Model::new(&device);
+
+        // Run the model
+        let input1 = Tensor::<Backend, 3>::from_floats([[[1., 2.], [3., 4.]]], &device);
+        let input2 = Tensor::<Backend, 3>::from_floats([[[5., 6., 7.], [8., 9., 10.]]], &device);
+        let input3 = Tensor::<Backend, 3>::from_floats([[[11.], [12.]]], &device);
+
+        let output = model.forward(input1, input2, input3);
+
+        // The axis -1 is the last dimension
+        let expected = Data::from([[[1., 2., 5., 6., 7., 11.], [3., 4., 8., 9., 10., 12.]]]);
+
+        assert_eq!(output.to_data(), expected);
+    }
+
+    #[test]
+    fn constant_folding() {
+        // The target shape of the reshape is computed from constants during the import
+        let device = Default::default();
+        let model: constant_folding::Model<Backend> = constant_folding::Model::new(&device);
+
+        let input = Tensor::<Backend, 2>::from_floats([[1., 2., 3.], [4., 5., 6.]], &device);
+
+        let output = model.forward(input);
+        let expected = Data::from([[1., 2.], [3., 4.], [5., 6.]]);
+
+        assert_eq!(output.to_data(), expected);
+    }
+
+    #[test]
+    fn constant_forms() {
+        // The constants are loaded from the record
+        let device = Default::default();
+        let model: constant_forms::Model<Backend> = constant_forms::Model::default();
+
+        let x = Tensor::<Backend, 2>::from_floats([[1., 1.], [1., 1.]], &device);
+        let i = Tensor::<Backend, 1, Int>::from_ints([1, 2], &device);
+
+        let (tensor_out, int_tensor_out, float_out, floats_out, int_out, ints_out, sparse_out) =
+            model.forward(x, i);
+
+        // A tensor value
+        assert_eq!(tensor_out.to_data(), Data::from([[2., 3.], [4., 5.]]));
+        assert_eq!(int_tensor_out.to_data(), Data::from([11, 22]));
+        // A scalar value_float and value_int, and a rank 1 value_floats and value_ints
+        assert_eq!(float_out.to_data(), Data::from([[2., 2.], [2., 2.]]));
+        assert_eq!(floats_out.to_data(), Data::from([[2., 3.], [2., 3.]]));
+        assert_eq!(int_out.to_data(), Data::from([4, 5]));
+        assert_eq!(ints_out.to_data(), Data::from([2, 4]));
+        // A sparse value, densified with zeros
+        assert_eq!(sparse_out.to_data(), Data::from([[1., 6.], [7., 1.]]));
+    }
+
     #[test]
     fn conv1d() {
         // Initialize the model with weights (loaded from the exported file)
@@ -338,6 +500,92 @@ mod tests {
         assert!(expected_sum.approx_eq(output_sum, (1.0e-4, 2)));
     }
 
+    #[test]
+    fn conv2d_same() {
+        // Initialize the model with weights (loaded from the exported file)
+        let model: conv2d_same::Model<Backend> = conv2d_same::Model::default();
+
+        // With a kernel of ones, each output counts the input values of its window
+        let input = Tensor::<Backend, 4>::ones([1, 1, 6, 6], &Default::default());
+
+        let output = model.forward(input);
+
+        // The output has ceil(6 / 2) values per dimension, and the extra padding is at the end.
+        assert_eq!(output.shape(), Shape::from([1, 1, 3, 3]));
+        let expected = Data::from([[[[9.0, 9.0, 6.0], [9.0, 9.0, 6.0], [6.0, 6.0, 4.0]]]]);
+        output.to_data().assert_approx_eq(&expected, 4);
+    }
+
+    /// Index of the value of an input of shape [1, 8, 2, 3] moved to the given position of an
+    /// output of shape [1, 2, 4, 6] by a DepthToSpace with a block size of 2.
+    fn depth_to_space_index(c: usize, h: usize, w: usize, crd: bool) -> usize {
+        let (i, j) = (h % 2, w % 2);
+        let channel = match crd {
+            true => c * 4 + i * 2 + j,
+            false => (i * 2 + j) * 2 + c,
+        };
+
+        (channel * 2 + h / 2) * 3 + w / 2
+    }
+
+    fn depth_to_space_expected(crd: bool) -> Vec<f32> {
+        let mut expected = Vec::new();
+        for c in 0..2 {
+            for h in 0..4 {
+                for w in 0..6 {
+                    expected.push(depth_to_space_index(c, h, w, crd) as f32);
+                }
+            }
+        }
+
+        expected
+    }
+
+    #[test]
+    fn depth_to_space_dcr() {
+        let device = Default::default();
+        let model: depth_to_space_dcr::Model<Backend> = depth_to_space_dcr::Model::new(&device);
+
+        // Each value is its index, so the output is the index of the value moved to each pixel
+        let input = Tensor::<Backend, 1, Int>::arange(0..48, &device)
+            .float()
+            .reshape([1, 8, 2, 3]);
+        let output = model.forward(input);
+
+        assert_eq!(output.shape(), Shape::from([1, 2, 4, 6]));
+        assert_eq!(output.into_data().value, depth_to_space_expected(false));
+    }
+
+    #[test]
+    fn depth_to_space_crd() {
+        let device = Default::default();
+        let model: depth_to_space_crd::Model<Backend> = depth_to_space_crd::Model::new(&device);
+
+        let input = Tensor::<Backend, 1, Int>::arange(0..48, &device)
+            .float()
+            .reshape([1, 8, 2, 3]);
+        let output = model.forward(input);
+
+        assert_eq!(output.shape(), Shape::from([1, 2, 4, 6]));
+        assert_eq!(output.into_data().value, depth_to_space_expected(true));
+    }
+
+    #[test]
+    fn space_to_depth() {
+        let device = Default::default();
+        let model: space_to_depth::Model<Backend> = space_to_depth::Model::new(&device);
+
+        // SpaceToDepth is the inverse of DepthToSpace in DCR mode
+        let input =
+            Tensor::<Backend, 1>::from_floats(depth_to_space_expected(false).as_slice(), &device)
+                .reshape([1, 2, 4, 6]);
+        let output = model.forward(input);
+
+        let expected: Vec<f32> = (0..48).map(|index| index as f32).collect();
+        assert_eq!(output.shape(), Shape::from([1, 8, 2, 3]));
+        assert_eq!(output.into_data().value, expected);
+    }
+
     #[test]
     fn dropout_opset16() {
         let model: dropout_opset16::Model<Backend> = dropout_opset16::Model::default();
@@ -376,6 +624,58 @@ mod tests {
         assert!(expected_sum.approx_eq(output_sum, (1.0e-4, 2)));
     }
 
+    #[test]
+    fn dropout_linear() {
+        let device = Default::default();
+        let model: dropout_linear::Model<Backend> = dropout_linear::Model::default();
+
+        let input = Tensor::<Backend, 2>::from_floats(
+            [[1.0, 2.0, 3.0, 4.0], [-1.0, 0.0, 1.0, 2.0]],
+            &device,
+        );
+
+        let output = model.forward(input);
+
+        // The dropout is an identity, the output is the one of the linear layer only.
+        let expected =
+            Tensor::<Backend, 2>::from_floats([[7.1, 7.9, 9.5], [2.7, 2.7, 3.5]], &device);
+        output.to_data().assert_approx_eq(&expected.to_data(), 4);
+    }
+
+    #[test]
+    fn einsum() {
+        let device = Default::default();
+        let model: einsum::Model<Backend> = einsum::Model::new(&device);
+
+        let x = Tensor::<Backend, 3>::from_floats(
+            [
+                [[1., 2., 3.], [4., 5., 6.]],
+                [[-1., 0., 1.], [2., -2., 0.5]],
+            ],
+            &device,
+        );
+        let y = Tensor::<Backend, 3>::from_floats(
+            [
+                [[1., 0., 0.], [0., 1., 0.], [0., 0., 1.], [1., 1., 1.]],
+                [[2., 1., 0.], [0., -1., 3.], [1., 2., 3.], [0.5, 0., -1.]],
+            ],
+            &device,
+        );
+        let a = Tensor::<Backend, 2>::from_floats([[1., 2., 3.], [-1., 0., 2.]], &device);
+        let b = Tensor::<Backend, 2>::from_floats([[1., 0.], [0., 1.], [2., -1.]], &device);
+
+        let (output1, output2, output3) = model.forward(x.clone(), y.clone(), a.clone(), b.clone());
+
+        // Compare against the equivalent Burn operations
+        output1
+            .to_data()
+            .assert_approx_eq(&x.clone().matmul(y.transpose()).to_data(), 3);
+        assert_eq!(output2.to_data(), x.swap_dims(1, 2).to_data());
+        output3
+            .to_data()
+            .assert_approx_eq(&a.matmul(b).to_data(), 3);
+    }
+
     #[test]
     fn erf() {
         let model: erf::Model<Backend> = erf::Model::default();
@@ -403,6 +703,19 @@ mod tests {
         assert_eq!(output.to_data(), expected);
     }
 
+    #[test]
+    fn gather_scalar() {
+        let model: gather_scalar::Model<Backend> = gather_scalar::Model::default();
+
+        let device = Default::default();
+
+        let input = Tensor::<Backend, 2>::from_floats([[1., 2., 3.], [4., 5., 6.]], &device);
+        let output = model.forward(input);
+        let expected = Data::from([2., 5.]);
+
+        assert_eq!(output.to_data(), expected);
+    }
+
     #[test]
     fn gather_elements() {
         // Initialize the model with weights (loaded from the exported file)
@@ -418,6 +731,78 @@ mod tests {
         assert_eq!(output.to_data(), expected);
     }
 
+    #[test]
+    fn gather_nd() {
+        let device = Default::default();
+        let model: gather_nd::Model<Backend> = gather_nd::Model::new(&device);
+
+        let data = Tensor::<Backend, 3>::from_floats(
+            [[[1., 2.], [3., 4.]], [[5., 6.], [7., 8.]]],
+            &device,
+        );
+        let indices = Tensor::<Backend, 2, Int>::from_ints([[0, 1], [1, 0], [1, 1]], &device);
+
+        let output = model.forward(data, indices);
+
+        let expected = Data::from([[3., 4.], [5., 6.], [7., 8.]]);
+        assert_eq!(output.to_data(), expected);
+    }
+
+    #[test]
+    fn gemm() {
+        let device = Default::default();
+        // Initialize the model with weights (loaded from the exported file)
+        let model: gemm::Model<Backend> = gemm::Model::default();
+
+        let x = Tensor::<Backend, 2>::from_floats([[1., 2., 3.], [4., 5., 6.]], &device);
+        let weight = Tensor::<Backend, 2>::from_floats(
+            [[1., 0., -1.], [0.5, 0.5, 0.5], [2., 1., 0.], [0., -1., 1.]],
+            &device,
+        );
+        let bias = Tensor::<Backend, 1>::from_floats([1., 2., 3., 4.], &device);
+        let (linear_output, gemm_output) = model.forward(x, weight, bias);
+
+        // Expected values computed with the ONNX reference Gemm (alpha=0.5, beta=2, transB=1)
+        let expected = Data::from([[1., 5.5, 8., 8.5], [1., 7.75, 12.5, 8.5]]);
+
+        linear_output.to_data().assert_approx_eq(&expected, 3);
+        gemm_output.to_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn top_k() {
+        let device = Default::default();
+        let model: top_k::Model<Backend> = top_k::Model::new(&device);
+
+        let input = Tensor::<Backend, 2>::from_floats(
+            [[1.0, 5.0, 3.0, 4.0, 2.0], [-1.0, -5.0, 0.0, 2.0, -3.0]],
+            &device,
+        );
+        let (values, indices, gathered) = model.forward(input);
+
+        let expected = Data::from([[5.0, 4.0, 3.0], [2.0, 0.0, -1.0]]);
+        assert_eq!(values.to_data(), expected);
+        assert_eq!(indices.to_data(), Data::from([[1, 3, 2], [3, 2, 0]]));
+        assert_eq!(gathered.to_data(), expected);
+    }
+
+    #[test]
+    fn top_k_smallest() {
+        let device = Default::default();
+        let model: top_k_smallest::Model<Backend> = top_k_smallest::Model::new(&device);
+
+        let input = Tensor::<Backend, 2>::from_floats(
+            [[1.0, 5.0, 3.0, 4.0, 2.0], [-1.0, -5.0, 0.0, 2.0, -3.0]],
+            &device,
+        );
+        let (values, indices, gathered) = model.forward(input);
+
+        let expected = Data::from([[1.0, 2.0, 3.0], [-5.0, -3.0, -1.0]]);
+        assert_eq!(values.to_data(), expected);
+        assert_eq!(indices.to_data(), Data::from([[0, 4, 2], [1, 4, 0]]));
+        assert_eq!(gathered.to_data(), expected);
+    }
+
     #[test]
     fn argmax() {
         // Initialize the model with weights (loaded from the exported file)
@@ -459,6 +844,26 @@ mod tests {
         assert!(expected_sum_2d.approx_eq(output_sum_2d, (1.0e-4, 2)));
     }
 
+    #[test]
+    fn resnet_tail() {
+        // Conv -> GlobalAveragePool -> Flatten -> Linear, and GlobalMaxPool on the conv output
+        let device = Default::default();
+        let model: resnet_tail::Model<Backend> = resnet_tail::Model::default();
+
+        let input = Tensor::<Backend, 4>::from_floats(
+            [[[[1.0, 2.0], [3.0, 4.0]], [[-1.0, 0.0], [1.0, 2.0]]]],
+            &device,
+        );
+        let (output1, output2) = model.forward(input);
+
+        // The conv channels have means of [2.5, 1.5, 1.0] and maxima of [4.0, 3.0, 1.0]
+        let expected1 = Data::from([[4.0, 2.0]]);
+        let expected2 = Data::from([[4.0, 3.0, 1.0]]);
+
+        output1.to_data().assert_approx_eq(&expected1, 4);
+        output2.to_data().assert_approx_eq(&expected2, 4);
+    }
+
     #[test]
     fn softmax() {
         // Initialize the model without weights (because the exported file does not contain them)
@@ -482,6 +887,48 @@ mod tests {
         assert_eq!(output.to_data(), expected);
     }
 
+    #[test]
+    fn softmax_opset11() {
+        let device = Default::default();
+        let model: softmax_opset11::Model<Backend> = softmax_opset11::Model::new(&device);
+
+        // Run the model
+        let input = Tensor::<Backend, 3>::from_floats(
+            [[[1.0, 2.0], [3.0, 0.5]], [[-1.0, 0.0], [2.0, 4.0]]],
+            &device,
+        );
+        let output = model.forward(input);
+
+        // The softmax is computed over all the dimensions from the axis.
+        let expected = Data::from([
+            [[0.08536889, 0.2320567], [0.63079554, 0.05177885]],
+            [[0.00580663, 0.01578405], [0.11662925, 0.86178007]],
+        ]);
+
+        output.to_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn softmax_opset13() {
+        let device = Default::default();
+        let model: softmax_opset13::Model<Backend> = softmax_opset13::Model::new(&device);
+
+        // Run the model
+        let input = Tensor::<Backend, 3>::from_floats(
+            [[[1.0, 2.0], [3.0, 0.5]], [[-1.0, 0.0], [2.0, 4.0]]],
+            &device,
+        );
+        let output = model.forward(input);
+
+        // The softmax is computed along the axis only.
+        let expected = Data::from([
+            [[0.11920292, 0.8175745], [0.8807971, 0.18242552]],
+            [[0.04742587, 0.01798621], [0.95257413, 0.98201379]],
+        ]);
+
+        output.to_data().assert_approx_eq(&expected, 5);
+    }
+
     #[test]
     fn log_softmax() {
         // Initialize the model without weights (because the exported file does not contain them)
@@ -505,6 +952,48 @@ mod tests {
         assert_eq!(output.to_data(), expected);
     }
 
+    #[test]
+    fn log_softmax_opset11() {
+        let device = Default::default();
+        let model: log_softmax_opset11::Model<Backend> = log_softmax_opset11::Model::new(&device);
+
+        // Run the model, the large values overflow the exponential of a naive log softmax
+        let input = Tensor::<Backend, 3>::from_floats(
+            [[[1000.0, 2.0], [3.0, -1000.0]], [[-1.0, 0.0], [2.0, 4.0]]],
+            &device,
+        );
+        let output = model.forward(input);
+
+        // The log softmax is computed over all the dimensions from the axis.
+        let expected = Data::from([
+            [[0.0, -998.0], [-997.0, -2000.0]],
+            [[-5.148755, -4.148755], [-2.1487552, -0.1487552]],
+        ]);
+
+        output.to_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn log_softmax_opset13() {
+        let device = Default::default();
+        let model: log_softmax_opset13::Model<Backend> = log_softmax_opset13::Model::new(&device);
+
+        // Run the model, the large values overflow the exponential of a naive log softmax
+        let input = Tensor::<Backend, 3>::from_floats(
+            [[[1000.0, 2.0], [3.0, -1000.0]], [[-1.0, 0.0], [2.0, 4.0]]],
+            &device,
+        );
+        let output = model.forward(input);
+
+        // The log softmax is computed along the axis only.
+        let expected = Data::from([
+            [[0.0, 0.0], [-997.0, -1002.0]],
+            [[-3.0485874, -4.01815], [-0.0485874, -0.0181499]],
+        ]);
+
+        output.to_data().assert_approx_eq(&expected, 4);
+    }
+
     #[test]
     fn sqrt() {
         let device = Default::default();
@@ -549,6 +1038,30 @@ mod tests {
         assert_eq!(output.to_data(), expected);
     }
 
+    #[test]
+    fn mean_variance_normalization() {
+        let device = Default::default();
+        let model: mean_variance_normalization::Model<Backend> =
+            mean_variance_normalization::Model::new(&device);
+
+        // The second channel is constant, its variance being zero
+        let input = Tensor::<Backend, 4>::from_floats(
+            [[[[1.0, 2.0]], [[3.0, 3.0]]], [[[3.0, 6.0]], [[3.0, 3.0]]]],
+            &device,
+        );
+        let (channels, last) = model.forward(input);
+
+        // The constant values are normalized to zeros thanks to the epsilon
+        let expected = Data::from([
+            [[[-1.069045, -0.534522]], [[0.0, 0.0]]],
+            [[[0.0, 1.603567]], [[0.0, 0.0]]],
+        ]);
+        channels.to_data().assert_approx_eq(&expected, 4);
+
+        let expected = Data::from([[[[-1.0, 1.0]], [[0.0, 0.0]]], [[[-1.0, 1.0]], [[0.0, 0.0]]]]);
+        last.to_data().assert_approx_eq(&expected, 4);
+    }
+
     #[test]
     fn maxpool1d() {
         let device = Default::default();
@@ -602,6 +1115,34 @@ mod tests {
         assert_eq!(output.to_data(), expected);
     }
 
+    #[test]
+    fn maxpool2d_ceil_mode() {
+        let device = Default::default();
+        let model: maxpool2d_ceil_mode::Model<Backend> = maxpool2d_ceil_mode::Model::new(&device);
+
+        let input = Tensor::<Backend, 1, Int>::arange(0..50, &device)
+            .float()
+            .reshape([1, 2, 5, 5]);
+        let (floor, ceil, indices) = model.forward(input);
+
+        // The 2x2 windows with a stride of 2 don't cover the last row and column of the 5x5
+        // input, unless the output size is rounded up.
+        assert_eq!(floor.shape(), Shape::from([1, 2, 2, 2]));
+        assert_eq!(ceil.shape(), Shape::from([1, 2, 3, 3]));
+
+        assert_eq!(
+            floor.to_data(),
+            Data::from([[[[6., 8.], [16., 18.]], [[31., 33.], [41., 43.]]]])
+        );
+        let expected = [[
+            [[6, 8, 9], [16, 18, 19], [21, 23, 24]],
+            [[31, 33, 34], [41, 43, 44], [46, 48, 49]],
+        ]];
+        assert_eq!(ceil.to_data(), Data::<i64, 4>::from(expected).convert());
+        // The input is a range, so the flattened indices of the maximums are their values.
+        assert_eq!(indices.to_data(), Data::<i64, 4>::from(expected));
+    }
+
     #[test]
     fn avg_pool1d() {
         // Initialize the model without weights (because the exported file does not contain them)
@@ -724,6 +1265,26 @@ mod tests {
         assert_eq!(output_value.to_data(), expected);
     }
 
+    #[test]
+    fn reduce_min() {
+        let device = Default::default();
+        let model: reduce_min::Model<Backend> = reduce_min::Model::new(&device);
+
+        // Run the model
+        let input =
+            Tensor::<Backend, 3>::from_floats([[[2.0, 5.0, 1.0], [4.0, 0.5, 6.0]]], &device);
+        let (output_dim, output_keepdims, output_dims, output_scalar) = model.forward(input);
+        let expected_dim = Data::from([[[2.0, 0.5, 1.0]]]);
+        let expected_keepdims = Data::from([[[0.5]]]);
+        let expected_dims = Data::from([1.0, 0.5]);
+        let expected_scalar = Data::from([0.5]);
+
+        assert_eq!(output_dim.to_data(), expected_dim);
+        assert_eq!(output_keepdims.to_data(), expected_keepdims);
+        assert_eq!(output_dims.to_data(), expected_dims);
+        assert_eq!(output_scalar.to_data(), expected_scalar);
+    }
+
     #[test]
     fn reduce_sum_opset11() {
         let device = Default::default();
@@ -771,7 +1332,34 @@ mod tests {
     }
 
     #[test]
-    fn shape() {
+    fn resize() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let device = Default::default();
+        let model: resize::Model<Backend> = resize::Model::new(&device);
+
+        // Run the model
+        let input = Tensor::<Backend, 4>::from_floats([[[[1., 2.], [3., 4.]]]], &device);
+        let scales = Tensor::<Backend, 1>::from_floats([1., 1., 1.5, 1.5], &device);
+        let (output_scales, output_sizes, output_runtime) = model.forward(input, scales);
+
+        let expected_scales = Data::from([[[
+            [1., 1., 2., 2.],
+            [1., 1., 2., 2.],
+            [3., 3., 4., 4.],
+            [3., 3., 4., 4.],
+        ]]]);
+        // The half pixel coordinates of the corners are clamped to the input, so they coincide
+        // with the aligned corners of Burn's bilinear interpolation.
+        let expected_sizes = Data::from([[[[1., 1.5, 2.], [2., 2.5, 3.], [3., 3.5, 4.]]]]);
+        let expected_runtime = Data::from([[[[1., 1., 2.], [1., 1., 2.], [3., 3., 4.]]]]);
+
+        assert_eq!(output_scales.to_data(), expected_scales);
+        output_sizes.to_data().assert_approx_eq(&expected_sizes, 3);
+        assert_eq!(output_runtime.to_data(), expected_runtime);
+    }
+
+    #[test]
+    fn shape() {
         let device = Default::default();
         let model: shape::Model<Backend> = shape::Model::new(&device);
 
@@ -783,6 +1371,19 @@ mod tests {
         assert_eq!(output.to_data(), expected);
     }
 
+    #[test]
+    fn shape_slice() {
+        let device = Default::default();
+        let model: shape_slice::Model<Backend> = shape_slice::Model::new(&device);
+
+        // Run the model
+        let input = Tensor::<Backend, 4>::ones([2, 3, 4, 5], &device);
+        let (middle, last) = model.forward(input);
+
+        assert_eq!(middle.to_data(), Data::from([3, 4]));
+        assert_eq!(last.to_data(), Data::from([3, 4, 5]));
+    }
+
     #[test]
     fn flatten() {
         // Initialize the model without weights (because the exported file does not contain them)
@@ -797,6 +1398,26 @@ mod tests {
         assert_eq!(expected_shape, output.shape());
     }
 
+    #[test]
+    fn flatten_axis() {
+        let device = Default::default();
+        let model: flatten_axis::Model<Backend> = flatten_axis::Model::new(&device);
+
+        let input = Tensor::<Backend, 1, Int>::arange(0..120, &device)
+            .reshape([2, 3, 4, 5])
+            .float();
+        let (output_axis1, output_axis0, output_neg) = model.forward(input.clone());
+
+        assert_eq!(Shape::from([2, 60]), output_axis1.shape());
+        // An axis of 0 produces a single row
+        assert_eq!(Shape::from([1, 120]), output_axis0.shape());
+        assert_eq!(Shape::from([6, 20]), output_neg.shape());
+
+        // The values keep their row-major order
+        let expected = input.reshape([6, 20]).into_data();
+        assert_eq!(output_neg.into_data(), expected);
+    }
+
     #[test]
     fn batch_norm() {
         let model: batch_norm::Model<Backend> = batch_norm::Model::default();
@@ -847,6 +1468,95 @@ mod tests {
         output.to_data().assert_approx_eq(&expected, 4);
     }
 
+    /// Input of the normalization tests, with a different mean and variance in each channel.
+    fn norm_input() -> Vec<f32> {
+        (0..32)
+            .map(|index| ((index * 7) % 11) as f32 * (index / 4 + 1) as f32 - 4.0)
+            .collect()
+    }
+
+    /// Reference normalization of a `[batch, channels, height, width]` input over the channels and
+    /// spatial dimensions of each group, scaled and shifted per channel.
+    fn group_norm_reference(
+        input: &[f32],
+        [batch_size, channels, height, width]: [usize; 4],
+        num_groups: usize,
+        scale: &[f32],
+        bias: &[f32],
+    ) -> Vec<f32> {
+        let epsilon = 1e-5;
+        let group_size = channels / num_groups * height * width;
+        let mut output = Vec::with_capacity(input.len());
+
+        for group in input.chunks(group_size) {
+            let mean = group.iter().map(|&x| x as f64).sum::<f64>() / group_size as f64;
+            let var = group
+                .iter()
+                .map(|&x| (x as f64 - mean).powi(2))
+                .sum::<f64>()
+                / group_size as f64;
+
+            output.extend(
+                group
+                    .iter()
+                    .map(|&x| (x as f64 - mean) / (var + epsilon).sqrt()),
+            );
+        }
+
+        for (index, value) in output.iter_mut().enumerate() {
+            let channel = index / (height * width) % channels;
+            *value = *value * scale[channel] as f64 + bias[channel] as f64;
+        }
+
+        assert_eq!(output.len(), batch_size * channels * height * width);
+        output.into_iter().map(|value| value as f32).collect()
+    }
+
+    #[test]
+    fn group_norm() {
+        let device = Default::default();
+        let model: group_norm::Model<Backend> = group_norm::Model::default();
+
+        let input = norm_input();
+        let output = model.forward(
+            Tensor::<Backend, 1>::from_floats(input.as_slice(), &device).reshape([2, 4, 2, 2]),
+        );
+
+        // The scale and bias of each group apply to its two channels
+        let expected = group_norm_reference(
+            &input,
+            [2, 4, 2, 2],
+            2,
+            &[0.5, 0.5, 2.0, 2.0],
+            &[0.1, 0.1, -0.2, -0.2],
+        );
+        output
+            .to_data()
+            .assert_approx_eq(&Data::new(expected, Shape::new([2, 4, 2, 2])), 3);
+    }
+
+    #[test]
+    fn instance_norm() {
+        let device = Default::default();
+        let model: instance_norm::Model<Backend> = instance_norm::Model::default();
+
+        let input = norm_input();
+        let output = model.forward(
+            Tensor::<Backend, 1>::from_floats(input.as_slice(), &device).reshape([2, 4, 2, 2]),
+        );
+
+        let expected = group_norm_reference(
+            &input,
+            [2, 4, 2, 2],
+            4,
+            &[1.0, 0.5, 2.0, -1.0],
+            &[0.0, 0.1, -0.1, 0.2],
+        );
+        output
+            .to_data()
+            .assert_approx_eq(&Data::new(expected, Shape::new([2, 4, 2, 2])), 3);
+    }
+
     #[test]
     fn leaky_relu() {
         // Initialize the model without weights (because the exported file does not contain them)
@@ -870,6 +1580,144 @@ mod tests {
         assert_eq!(output.to_data(), expected);
     }
 
+    #[test]
+    fn hard_sigmoid() {
+        let device = Default::default();
+        let model: hard_sigmoid::Model<Backend> = hard_sigmoid::Model::new(&device);
+
+        // A ramp going through both clamped regions
+        let ramp = [-4.0, -3.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0, 4.0];
+        let input = Tensor::<Backend, 1>::from_floats(ramp, &device);
+        let output = model.forward(input);
+        let expected = ramp.map(|x: f32| (0.25 * x + 0.4).clamp(0.0, 1.0));
+
+        output.to_data().assert_approx_eq(&Data::from(expected), 4);
+    }
+
+    #[test]
+    fn hard_swish() {
+        let device = Default::default();
+        let model: hard_swish::Model<Backend> = hard_swish::Model::new(&device);
+
+        // A ramp going through both clamped regions
+        let ramp = [-4.0, -3.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0, 4.0];
+        let input = Tensor::<Backend, 1>::from_floats(ramp, &device);
+        let output = model.forward(input);
+        let expected = ramp.map(|x: f32| x * (x / 6.0 + 0.5).clamp(0.0, 1.0));
+
+        output.to_data().assert_approx_eq(&Data::from(expected), 4);
+    }
+
+    #[test]
+    fn softplus() {
+        let device = Default::default();
+        let model: softplus::Model<Backend> = softplus::Model::new(&device);
+
+        let ramp = [-4.0, -3.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0, 4.0];
+        let input = Tensor::<Backend, 1>::from_floats(ramp, &device);
+        let output = model.forward(input);
+        let expected = ramp.map(|x: f32| x.exp().ln_1p());
+
+        output.to_data().assert_approx_eq(&Data::from(expected), 4);
+    }
+
+    #[test]
+    fn softsign() {
+        let device = Default::default();
+        let model: softsign::Model<Backend> = softsign::Model::new(&device);
+
+        let ramp = [-4.0, -3.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0, 4.0];
+        let input = Tensor::<Backend, 1>::from_floats(ramp, &device);
+        let output = model.forward(input);
+        let expected = ramp.map(|x: f32| x / (1.0 + x.abs()));
+
+        output.to_data().assert_approx_eq(&Data::from(expected), 4);
+    }
+
+    #[test]
+    fn bitshift() {
+        let device = Default::default();
+        let model: bitshift::Model<Backend> = bitshift::Model::new(&device);
+
+        let input = Tensor::<Backend, 2, Int>::from_ints([[1, 2, 3], [5, 7, 255]], &device);
+        let shift = Tensor::<Backend, 1, Int>::from_ints([0, 1, 4], &device);
+        let output = model.forward(input, shift);
+        let expected = Data::from([[1, 4, 48], [5, 14, 4080]]);
+
+        assert_eq!(output.to_data(), expected);
+    }
+
+    #[test]
+    fn scatter() {
+        let device = Default::default();
+        let model: scatter::Model<Backend> = scatter::Model::new(&device);
+
+        let indices = Tensor::<Backend, 2, Int>::from_ints([[1, 0, 2], [0, 2, 1]], &device);
+        let updates = Tensor::<Backend, 2>::from_floats([[1., 2., 3.], [4., 5., 6.]], &device);
+        let rows = Tensor::<Backend, 2, Int>::from_ints([[2], [0]], &device);
+
+        let data = Tensor::<Backend, 2>::zeros([3, 3], &device);
+        let (elements, nd) = model.forward(data, indices.clone(), updates.clone(), rows.clone());
+
+        assert_eq!(
+            elements.to_data(),
+            Data::from([[4., 2., 0.], [1., 0., 6.], [0., 5., 3.]])
+        );
+        assert_eq!(
+            nd.to_data(),
+            Data::from([[4., 5., 6.], [0., 0., 0.], [1., 2., 3.]])
+        );
+
+        // The updates replace the scattered values but are added to the scattered rows
+        let data = Tensor::<Backend, 2>::ones([3, 3], &device);
+        let (elements, nd) = model.forward(data, indices, updates, rows);
+
+        assert_eq!(
+            elements.to_data(),
+            Data::from([[4., 2., 1.], [1., 1., 6.], [1., 5., 3.]])
+        );
+        assert_eq!(
+            nd.to_data(),
+            Data::from([[5., 6., 7.], [1., 1., 1.], [2., 3., 4.]])
+        );
+    }
+
+    #[test]
+    fn unary_math() {
+        let device = Default::default();
+        let model: unary_math::Model<Backend> = unary_math::Model::new(&device);
+
+        let ramp = [-2.5, -1.5, -0.4, 0.5, 1.2, 1.5, 2.5, 3.7];
+        let input = Tensor::<Backend, 1>::from_floats(ramp, &device);
+        let (abs, neg, sign, floor, ceil, round, recip, sqrt) = model.forward(input);
+
+        // Halfway cases are rounded to the nearest even integer
+        let round_half_even = |x: f32| {
+            if (x - x.trunc()).abs() == 0.5 {
+                2.0 * (x / 2.0).round()
+            } else {
+                x.round()
+            }
+        };
+        #[allow(clippy::type_complexity)]
+        let outputs: [(Tensor<Backend, 1>, fn(f32) -> f32); 8] = [
+            (abs, f32::abs),
+            (neg, |x| -x),
+            (sign, f32::signum),
+            (floor, f32::floor),
+            (ceil, f32::ceil),
+            (round, round_half_even),
+            (recip, f32::recip),
+            (sqrt, |x| x.abs().sqrt()),
+        ];
+
+        for (output, reference) in outputs {
+            let expected = ramp.map(reference);
+
+            output.to_data().assert_approx_eq(&Data::from(expected), 4);
+        }
+    }
+
     #[test]
     fn prelu() {
         // Initialize the model without weights (because the exported file does not contain them)
@@ -893,6 +1741,41 @@ mod tests {
         assert_eq!(output.to_data(), expected);
     }
 
+    #[test]
+    fn prelu_per_channel() {
+        let device = Default::default();
+        let model: prelu_per_channel::Model<Backend> = prelu_per_channel::Model::default();
+
+        let input = Tensor::<Backend, 3>::from_floats(
+            [
+                [[1.0, -1.0], [-2.0, 2.0], [-3.0, 3.0]],
+                [[-1.0, -1.0], [2.0, -2.0], [0.0, -4.0]],
+            ],
+            &device,
+        );
+        let (output1, output2) = model.forward(input);
+
+        // One slope per channel
+        let expected1 = Tensor::<Backend, 3>::from_floats(
+            [
+                [[1.0, -0.1], [-0.4, 2.0], [-0.9, 3.0]],
+                [[-0.1, -0.1], [2.0, -0.4], [0.0, -1.2]],
+            ],
+            &device,
+        );
+        // A single slope of 0.2
+        let expected2 = Tensor::<Backend, 3>::from_floats(
+            [
+                [[1.0, -0.2], [-0.4, 2.0], [-0.6, 3.0]],
+                [[-0.2, -0.2], [2.0, -0.4], [0.0, -0.8]],
+            ],
+            &device,
+        );
+
+        output1.to_data().assert_approx_eq(&expected1.to_data(), 4);
+        output2.to_data().assert_approx_eq(&expected2.to_data(), 4);
+    }
+
     #[test]
     fn relu() {
         // Initialize the model without weights (because the exported file does not contain them)
@@ -1068,6 +1951,24 @@ mod tests {
         assert_eq!(output3.to_data(), expected3);
     }
 
+    #[test]
+    fn clip_optional_inputs() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let device = Default::default();
+        let model: clip_optional_inputs::Model<Backend> = clip_optional_inputs::Model::new(&device);
+
+        // Run the model
+        let input = Tensor::<Backend, 1>::from_floats([0.1, 0.2, 0.4, 0.6, 0.8, 0.9], &device);
+        let (output1, output2, output3) = model.forward(input);
+        let expected1 = Data::from([0.1, 0.2, 0.4, 0.6, 0.8, 0.9]);
+        let expected2 = Data::from([0.1, 0.2, 0.4, 0.6, 0.7, 0.7]);
+        let expected3 = Data::from([0.3, 0.3, 0.4, 0.6, 0.8, 0.9]);
+
+        assert_eq!(output1.to_data(), expected1);
+        assert_eq!(output2.to_data(), expected2);
+        assert_eq!(output3.to_data(), expected3);
+    }
+
     #[test]
     fn linear() {
         let device = Default::default();
@@ -1134,6 +2035,19 @@ mod tests {
         assert_eq!(output.to_data(), expected);
     }
 
+    #[test]
+    fn range_constant() {
+        let device = Default::default();
+        let model: range_constant::Model<Backend> = range_constant::Model::new(&device);
+
+        let (output, output_desc) = model.forward();
+
+        assert_eq!(output.dims(), [5]);
+        assert_eq!(output.to_data(), Data::from([0, 2, 4, 6, 8]));
+        // A negative delta gives decreasing values
+        assert_eq!(output_desc.to_data(), Data::from([10, 7, 4, 1]));
+    }
+
     #[test]
     fn recip() {
         // Initialize the model
@@ -1170,6 +2084,90 @@ mod tests {
         assert!(expected_sum.approx_eq(output_sum, (1.0e-4, 2)));
     }
 
+    #[test]
+    fn conv_transpose2d_upsample() {
+        // Initialize the model with weights (loaded from the exported file)
+        let model: conv_transpose2d_upsample::Model<Backend> =
+            conv_transpose2d_upsample::Model::default();
+
+        let input =
+            Tensor::<Backend, 4>::from_floats([[[[1., 2.], [3., 4.]]]], &Default::default());
+
+        let output = model.forward(input);
+
+        // The 2x2 blocks of each value are cropped by one row and column at the beginning
+        let expected = Data::from([[
+            [[1.5, 2.5, 2.5], [3.5, 4.5, 4.5], [3.5, 4.5, 4.5]],
+            [[0.5, 1.5, 1.5], [2.5, 3.5, 3.5], [2.5, 3.5, 3.5]],
+        ]]);
+
+        assert_eq!(output.to_data(), expected);
+    }
+
+    #[test]
+    fn conv_batch_norm() {
+        // Initialize the model with weights (loaded from the exported file)
+        let model: conv_batch_norm::Model<Backend> = conv_batch_norm::Model::default();
+
+        let input =
+            Tensor::<Backend, 4>::from_floats([[[[1., 2.], [3., 4.]]]], &Default::default());
+
+        // The first batch normalization is folded into its convolution, the second one isn't
+        // since the convolution output is also returned
+        let (output1, output2, output3) = model.forward(input);
+        let expected = Data::from([[[[1., 2.], [3., 4.]], [[-1., -3.], [-5., -7.]]]]);
+        let expected_conv = Data::from([[[[3., 5.], [7., 9.]], [[-1., -2.], [-3., -4.]]]]);
+
+        output1.to_data().assert_approx_eq(&expected, 3);
+        output2.to_data().assert_approx_eq(&expected, 3);
+        assert_eq!(output3.to_data(), expected_conv);
+    }
+
+    #[test]
+    fn gru() {
+        // Initialize the model with weights (loaded from the exported file)
+        let model: gru::Model<Backend> = gru::Model::default();
+        let device = Default::default();
+
+        // Input with the shape [seq_length, batch_size, input_size]
+        let input = Tensor::<Backend, 3>::from_floats([[[0.1]], [[0.2]]], &device);
+        let initial_h = Tensor::<Backend, 3>::zeros([1, 1, 1], &device);
+
+        let (output, hidden) = model.forward(input, initial_h);
+
+        // Expected values computed with the ONNX reference GRU (linear_before_reset=1)
+        let expected_output = Data::from([[[[0.10625]]], [[[0.20642]]]]);
+        let expected_hidden = Data::from([[[0.20642]]]);
+
+        output.to_data().assert_approx_eq(&expected_output, 3);
+        hidden.to_data().assert_approx_eq(&expected_hidden, 3);
+    }
+
+    #[test]
+    fn lstm() {
+        // Initialize the model with weights (loaded from the exported file)
+        let model: lstm::Model<Backend> = lstm::Model::default();
+        let device = Default::default();
+
+        // Input with the shape [seq_length, batch_size, input_size], the initial states with
+        // the shape [num_directions, batch_size, hidden_size]
+        let input = Tensor::<Backend, 3>::from_floats([[[0.1]], [[0.2]]], &device);
+        let initial_h = Tensor::<Backend, 3>::from_floats([[[0.0]], [[0.1]]], &device);
+        let initial_c = Tensor::<Backend, 3>::from_floats([[[0.0]], [[0.2]]], &device);
+
+        let (output, hidden, cell) = model.forward(input, initial_h, initial_c);
+
+        // Expected values computed with the ONNX reference LSTM of the script, the output has
+        // the shape [seq_length, num_directions, batch_size, hidden_size]
+        let expected_output = Data::from([[[[0.02208]], [[0.10745]]], [[[0.06214]], [[0.11465]]]]);
+        let expected_hidden = Data::from([[[0.06214]], [[0.10745]]]);
+        let expected_cell = Data::from([[[0.11743]], [[0.20006]]]);
+
+        output.to_data().assert_approx_eq(&expected_output, 3);
+        hidden.to_data().assert_approx_eq(&expected_hidden, 3);
+        cell.to_data().assert_approx_eq(&expected_cell, 3);
+    }
+
     #[test]
     fn cos() {
         let device = Default::default();
@@ -1210,6 +2208,72 @@ mod tests {
         assert_eq!(output.shape(), expected_shape);
     }
 
+    #[test]
+    fn expand_broadcast() {
+        let device = Default::default();
+        let model: expand_broadcast::Model<Backend> = expand_broadcast::Model::new(&device);
+
+        let input = Tensor::<Backend, 3>::from_floats([[[1.0], [2.0], [3.0]]], &device);
+
+        let output = model.forward(input);
+        let expected = Data::from(
+            [[
+                [1.0, 1.0, 1.0, 1.0],
+                [2.0, 2.0, 2.0, 2.0],
+                [3.0, 3.0, 3.0, 3.0],
+            ]; 2],
+        );
+
+        assert_eq!(output.to_data(), expected);
+    }
+
+    #[test]
+    fn slice() {
+        let device = Default::default();
+        let model: slice::Model<Backend> = slice::Model::new(&device);
+
+        let input = Tensor::<Backend, 2>::from_floats(
+            [[1., 2., 3., 4., 5.], [6., 7., 8., 9., 10.]],
+            &device,
+        );
+
+        let output = model.forward(input);
+        let expected = Data::from([[2., 3., 4.], [7., 8., 9.]]);
+
+        assert_eq!(output.to_data(), expected);
+    }
+
+    #[test]
+    fn pad() {
+        let device = Default::default();
+        let model: pad::Model<Backend> = pad::Model::new(&device);
+
+        let input = Tensor::<Backend, 2>::from_floats([[1., 2., 3.], [4., 5., 6.]], &device);
+
+        let output = model.forward(input);
+        let expected = Data::from([[3., 2., 1., 2., 3., 2.], [6., 5., 4., 5., 6., 5.]]);
+
+        assert_eq!(output.to_data(), expected);
+    }
+
+    #[test]
+    fn split() {
+        let device = Default::default();
+        let model: split::Model<Backend> = split::Model::new(&device);
+
+        let input = Tensor::<Backend, 2>::from_floats(
+            [[1., 2., 3., 4., 5.], [6., 7., 8., 9., 10.]],
+            &device,
+        );
+
+        let (output1, output2) = model.forward(input);
+        let expected1 = Data::from([[1., 2.], [6., 7.]]);
+        let expected2 = Data::from([[3., 4., 5.], [8., 9., 10.]]);
+
+        assert_eq!(output1.to_data(), expected1);
+        assert_eq!(output2.to_data(), expected2);
+    }
+
     #[test]
     fn gelu() {
         let device = Default::default();
@@ -1369,6 +2433,137 @@ mod tests {
         assert_eq!(output.to_data(), expected);
     }
 
+    #[test]
+    fn modulo() {
+        let device = Default::default();
+        let model: modulo::Model<Backend> = modulo::Model::new(&device);
+
+        let x = Tensor::<Backend, 2, Int>::from_ints([[7, -7, 7], [-7, 0, 9]], &device);
+        let y = Tensor::<Backend, 1, Int>::from_ints([3, 3, -3], &device);
+        let a = Tensor::<Backend, 1>::from_floats([5.5, -5.5, 5.5, -5.5], &device);
+        let b = Tensor::<Backend, 1>::from_floats([2., 2., -2., -2.], &device);
+        let base = Tensor::<Backend, 2>::from_floats([[1., 2., 3.], [4., 5., 6.]], &device);
+        let exponent = Tensor::<Backend, 1>::from_floats([2., 0.5, 1.], &device);
+
+        let (int_mod, float_mod, pow) = model.forward(x, y, a, b, base, exponent);
+
+        // The integer remainder has the sign of the divisor, the float one the sign of the dividend
+        assert_eq!(int_mod.to_data(), Data::from([[1, 2, -2], [2, 0, 0]]));
+        float_mod
+            .to_data()
+            .assert_approx_eq(&Data::from([1.5, -1.5, 1.5, -1.5]), 4);
+        pow.to_data().assert_approx_eq(
+            &Data::from([[1., 2f32.sqrt(), 3.], [16., 5f32.sqrt(), 6.]]),
+            4,
+        );
+    }
+
+    #[test]
+    fn nonzero() {
+        let device = Default::default();
+        let model: nonzero::Model<Backend> = nonzero::Model::new(&device);
+
+        let mask = Tensor::<Backend, 2, Bool>::from_bool(
+            Data::from([[true, false, true], [false, false, true]]),
+            &device,
+        );
+        let values = Tensor::<Backend, 1>::from_floats([0., 2., 0., -1., 3.], &device);
+
+        let (coordinates, gathered) = model.forward(mask, values);
+
+        // One column per true element, with its row then its column
+        assert_eq!(coordinates.to_data(), Data::from([[0, 0, 1], [0, 2, 2]]));
+        gathered
+            .to_data()
+            .assert_approx_eq(&Data::from([2., -1., 3.]), 4);
+    }
+
+    #[test]
+    fn one_hot() {
+        let device = Default::default();
+        let model: one_hot::Model<Backend> = one_hot::Model::new(&device);
+
+        // -1 is the last class and 5 is out of range, so it's only encoded with the off value
+        let indices = Tensor::<Backend, 2, Int>::from_ints([[0, 2], [-1, 5]], &device);
+
+        let output = model.forward(indices);
+
+        let expected = Data::from([
+            [[3., -1.], [-1., -1.]],
+            [[-1., -1.], [-1., -1.]],
+            [[-1., 3.], [3., -1.]],
+        ]);
+        output.to_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn cum_sum() {
+        let device = Default::default();
+        let model: cum_sum::Model<Backend> = cum_sum::Model::new(&device);
+
+        let input = Tensor::<Backend, 2>::from_floats([[1., 2., 3.], [4., 5., 6.]], &device);
+
+        let (output, exclusive, reverse, exclusive_reverse) = model.forward(input);
+
+        let expected = Data::from([[1., 3., 6.], [4., 9., 15.]]);
+        output.to_data().assert_approx_eq(&expected, 4);
+        let expected = Data::from([[0., 1., 3.], [0., 4., 9.]]);
+        exclusive.to_data().assert_approx_eq(&expected, 4);
+        let expected = Data::from([[6., 5., 3.], [15., 11., 6.]]);
+        reverse.to_data().assert_approx_eq(&expected, 4);
+        let expected = Data::from([[5., 3., 0.], [11., 6., 0.]]);
+        exclusive_reverse.to_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn trilu() {
+        let device = Default::default();
+        let model: trilu::Model<Backend> = trilu::Model::new(&device);
+
+        // Run the model
+        let input = Tensor::<Backend, 3>::from_floats(
+            [
+                [[1., 2., 3., 4.], [5., 6., 7., 8.], [9., 10., 11., 12.]],
+                [
+                    [13., 14., 15., 16.],
+                    [17., 18., 19., 20.],
+                    [21., 22., 23., 24.],
+                ],
+            ],
+            &device,
+        );
+        let (upper, lower) = model.forward(input);
+
+        // The upper part is kept from the diagonal above the main one, and the lower part from
+        // the diagonal below it.
+        let expected_upper = Data::from([
+            [[0., 2., 3., 4.], [0., 0., 7., 8.], [0., 0., 0., 12.]],
+            [[0., 14., 15., 16.], [0., 0., 19., 20.], [0., 0., 0., 24.]],
+        ]);
+        let expected_lower = Data::from([
+            [[0., 0., 0., 0.], [5., 0., 0., 0.], [9., 10., 0., 0.]],
+            [[0., 0., 0., 0.], [17., 0., 0., 0.], [21., 22., 0., 0.]],
+        ]);
+
+        assert_eq!(upper.to_data(), expected_upper);
+        assert_eq!(lower.to_data(), expected_lower);
+    }
+
+    #[test]
+    fn unique() {
+        let device = Default::default();
+        let model: unique::Model<Backend> = unique::Model::new(&device);
+
+        let input = Tensor::<Backend, 2>::from_floats([[2., 1., 2.], [3., 1., 2.]], &device);
+
+        let (values, indices, inverse_indices, counts) = model.forward(input);
+
+        assert_eq!(values.to_data(), Data::from([1., 2., 3.]));
+        assert_eq!(indices.to_data(), Data::from([1, 0, 3]));
+        assert_eq!(inverse_indices.to_data(), Data::from([1, 0, 1, 2, 0, 1]));
+        assert_eq!(counts.to_data(), Data::from([2, 3, 1]));
+    }
+
     #[test]
     fn unsqueeze() {
         let device = Default::default();
@@ -1404,6 +2599,25 @@ mod tests {
         assert_eq!(Shape::from([1]), output.1.shape());
     }
 
+    #[test]
+    fn unsqueeze_axes_opset11() {
+        let device = Default::default();
+        let model = unsqueeze_axes_opset11::Model::<Backend>::new(&device);
+        let input = Tensor::<Backend, 2>::ones([3, 4], &device);
+        let output = model.forward(input);
+        // The axes [-1, 1, 0] are positions in the output.
+        assert_eq!(Shape::from([1, 1, 3, 4, 1]), output.shape());
+    }
+
+    #[test]
+    fn unsqueeze_axes_opset13() {
+        let device = Default::default();
+        let model = unsqueeze_axes_opset13::Model::<Backend>::new(&device);
+        let input = Tensor::<Backend, 2>::ones([3, 4], &device);
+        let output = model.forward(input);
+        assert_eq!(Shape::from([1, 1, 3, 4, 1]), output.shape());
+    }
+
     #[test]
     fn cast() {
         let device = Default::default();
@@ -1452,6 +2666,23 @@ mod tests {
         assert_eq!(output_scalar, expected_scalar);
     }
 
+    #[test]
+    fn cast_round_trip() {
+        let device = Default::default();
+        let model: cast_round_trip::Model<Backend> = cast_round_trip::Model::new(&device);
+
+        let input =
+            Tensor::<Backend, 2>::from_floats([[1.7, -1.7, 2.5], [-0.5, 3.0, -3.9]], &device);
+        let (output_int, output) = model.forward(input);
+
+        // The values are truncated toward zero when casted to int
+        let expected_int = Data::from([[1, -1, 2], [0, 3, -3]]);
+        let expected = Data::from([[1., -1., 2.], [0., 3., -3.]]);
+
+        assert_eq!(output_int.to_data(), expected_int);
+        assert_eq!(output.to_data(), expected);
+    }
+
     #[test]
     fn mask_where() {
         let device = Default::default();
@@ -1470,6 +2701,22 @@ mod tests {
         assert_eq!(output_broadcasted.to_data(), expected);
     }
 
+    #[test]
+    fn mask_where_scalar() {
+        let device = Default::default();
+        let model: mask_where_scalar::Model<Backend> = mask_where_scalar::Model::new(&device);
+
+        let x = Tensor::<Backend, 2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+        let y = Tensor::<Backend, 1>::from_floats([-1.0], &device);
+
+        let output = model.forward(true, x.clone(), y.clone());
+        assert_eq!(output.to_data(), Data::from([[1.0, 2.0], [3.0, 4.0]]));
+
+        // The single value of y is broadcast to the shape of x
+        let output = model.forward(false, x, y);
+        assert_eq!(output.to_data(), Data::from([[-1.0, -1.0], [-1.0, -1.0]]));
+    }
+
     #[test]
     fn sign() {
         let device = Default::default();
@@ -1505,6 +2752,33 @@ mod tests {
         assert_eq!(expected_shape, output.shape());
     }
 
+    #[test]
+    fn squeeze_axes_opset11() {
+        let device = Default::default();
+        let model = squeeze_axes_opset11::Model::<Backend>::new(&device);
+        let input = Tensor::<Backend, 4>::ones([3, 1, 4, 1], &device);
+        let output = model.forward(input);
+        assert_eq!(Shape::from([3, 4]), output.shape());
+    }
+
+    #[test]
+    fn squeeze_axes_opset13() {
+        let device = Default::default();
+        let model = squeeze_axes_opset13::Model::<Backend>::new(&device);
+        let input = Tensor::<Backend, 4>::ones([3, 1, 4, 1], &device);
+        let output = model.forward(input);
+        assert_eq!(Shape::from([3, 4]), output.shape());
+    }
+
+    #[test]
+    fn squeeze_no_axes() {
+        let device = Default::default();
+        let model = squeeze_no_axes::Model::<Backend>::new(&device);
+        let input = Tensor::<Backend, 4>::ones([1, 3, 1, 4], &device);
+        let output = model.forward(input);
+        assert_eq!(Shape::from([3, 4]), output.shape());
+    }
+
     #[test]
     fn random_uniform() {
         let device = Default::default();
@@ -1514,6 +2788,27 @@ mod tests {
         assert_eq!(expected_shape, output.shape());
     }
 
+    #[test]
+    fn random_uniform_seeded() {
+        let device = Default::default();
+        let model = random_uniform_seeded::Model::<Backend>::new(&device);
+        let output = model.forward();
+        assert_eq!(Shape::from([2, 3]), output.shape());
+
+        // The values are generated when importing the model, every forward pass and every
+        // instance of the model give the same tensor.
+        let other = random_uniform_seeded::Model::<Backend>::new(&device);
+        output
+            .to_data()
+            .assert_approx_eq(&model.forward().into_data(), 6);
+        output
+            .to_data()
+            .assert_approx_eq(&other.forward().into_data(), 6);
+
+        let values = output.into_data().value;
+        assert!(values.iter().all(|value| (-1.0..1.0).contains(value)));
+    }
+
     #[test]
     fn random_normal() {
         let device = Default::default();
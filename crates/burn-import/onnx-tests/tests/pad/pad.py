@@ -0,0 +1,47 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/pad/pad.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # Reflect the last dimension with two values at the beginning and one at the end,
+    # the pads list the beginning of all the axes, then their end
+    pads = helper.make_tensor("pads", TensorProto.INT64, [4], [0, 2, 0, 1])
+
+    pad_node = helper.make_node(
+        "Pad",
+        name="/Pad",
+        inputs=["input", "pads"],
+        outputs=["output"],
+        mode="reflect",
+    )
+
+    # Create the graph
+    graph_def = helper.make_graph(
+        nodes=[pad_node],
+        name="PadGraph",
+        inputs=[
+            helper.make_tensor_value_info("input", TensorProto.FLOAT, [2, 3]),
+        ],
+        outputs=[
+            helper.make_tensor_value_info("output", TensorProto.FLOAT, [2, 6]),
+        ],
+        initializer=[pads],
+    )
+
+    # Create the model
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="pad",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    # Save the model to a file
+    onnx.save(model_def, "pad.onnx")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,48 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/prelu/prelu_per_channel.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # PRelu with one slope per channel, broadcast from the last dimension, and LeakyRelu with a
+    # scalar slope applied to the same input
+    nodes = [
+        helper.make_node(
+            "PRelu", name="/prelu/PRelu", inputs=["x", "slope"], outputs=["output1"]
+        ),
+        helper.make_node(
+            "LeakyRelu",
+            name="/leaky_relu/LeakyRelu",
+            inputs=["x"],
+            outputs=["output2"],
+            alpha=0.2,
+        ),
+    ]
+
+    graph_def = helper.make_graph(
+        nodes=nodes,
+        name="PReluPerChannelGraph",
+        inputs=[helper.make_tensor_value_info("x", TensorProto.FLOAT, [2, 3, 2])],
+        outputs=[
+            helper.make_tensor_value_info("output1", TensorProto.FLOAT, [2, 3, 2]),
+            helper.make_tensor_value_info("output2", TensorProto.FLOAT, [2, 3, 2]),
+        ],
+        initializer=[
+            helper.make_tensor("slope", TensorProto.FLOAT, [3, 1], [0.1, 0.2, 0.3])
+        ],
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="prelu_per_channel",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    onnx.save(model_def, "prelu_per_channel.onnx")
+
+
+if __name__ == "__main__":
+    main()
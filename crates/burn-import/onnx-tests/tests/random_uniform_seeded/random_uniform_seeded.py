@@ -0,0 +1,39 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/random_uniform_seeded/random_uniform_seeded.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # A seeded generator, whose values are generated when importing the model
+    random_uniform = helper.make_node(
+        "RandomUniform",
+        name="/RandomUniform",
+        inputs=[],
+        outputs=["output"],
+        shape=[2, 3],
+        low=-1.0,
+        high=1.0,
+        seed=42.0,
+    )
+
+    graph_def = helper.make_graph(
+        nodes=[random_uniform],
+        name="RandomUniformSeededGraph",
+        inputs=[],
+        outputs=[helper.make_tensor_value_info("output", TensorProto.FLOAT, [2, 3])],
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="random_uniform_seeded",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    onnx.save(model_def, "random_uniform_seeded.onnx")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,62 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/range/range_constant.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def constant(name, value):
+    return helper.make_node(
+        "Constant",
+        name=f"{name}_constant",
+        inputs=[],
+        outputs=[name],
+        value=helper.make_tensor(name, TensorProto.INT64, [], [value]),
+    )
+
+
+def main() -> None:
+    # Ranges with constant inputs, of increasing and decreasing values
+    nodes = [
+        constant("start", 0),
+        constant("limit", 10),
+        constant("delta", 2),
+        helper.make_node(
+            "Range",
+            name="/Range",
+            inputs=["start", "limit", "delta"],
+            outputs=["output1"],
+        ),
+        constant("start_desc", 10),
+        constant("limit_desc", 0),
+        constant("delta_desc", -3),
+        helper.make_node(
+            "Range",
+            name="/Range_1",
+            inputs=["start_desc", "limit_desc", "delta_desc"],
+            outputs=["output2"],
+        ),
+    ]
+
+    graph_def = helper.make_graph(
+        nodes=nodes,
+        name="RangeConstantGraph",
+        inputs=[],
+        outputs=[
+            helper.make_tensor_value_info("output1", TensorProto.INT64, [5]),
+            helper.make_tensor_value_info("output2", TensorProto.INT64, [4]),
+        ],
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="range_constant",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    onnx.save(model_def, "range_constant.onnx")
+
+
+if __name__ == "__main__":
+    main()
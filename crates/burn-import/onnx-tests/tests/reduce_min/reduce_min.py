@@ -0,0 +1,73 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/reduce_min/reduce_min.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # Since opset 18, the axes are provided as an input
+    axes_single = helper.make_tensor("axes_single", TensorProto.INT64, [1], [1])
+    axes_multiple = helper.make_tensor("axes_multiple", TensorProto.INT64, [2], [0, -1])
+
+    # ReduceMin, keepdims=1, axes=[1]
+    keepdims_single = helper.make_node(
+        "ReduceMin",
+        name="/ReduceMin",
+        inputs=["x", "axes_single"],
+        outputs=["output1"],
+        keepdims=1,
+    )
+    # ReduceMin, keepdims=1, axes=None
+    keepdims_all = helper.make_node(
+        "ReduceMin",
+        name="/ReduceMin_1",
+        inputs=["x"],
+        outputs=["output2"],
+        keepdims=1,
+    )
+    # ReduceMin, keepdims=0, axes=[0, -1]
+    multiple = helper.make_node(
+        "ReduceMin",
+        name="/ReduceMin_2",
+        inputs=["x", "axes_multiple"],
+        outputs=["output3"],
+        keepdims=0,
+    )
+    # ReduceMin, keepdims=0, axes=None
+    reduce_all = helper.make_node(
+        "ReduceMin",
+        name="/ReduceMin_3",
+        inputs=["x"],
+        outputs=["output4"],
+        keepdims=0,
+    )
+
+    # Create the graph
+    graph_def = helper.make_graph(
+        nodes=[keepdims_single, keepdims_all, multiple, reduce_all],
+        name="ReduceMinGraph",
+        inputs=[helper.make_tensor_value_info("x", TensorProto.FLOAT, [1, 2, 3])],
+        outputs=[
+            helper.make_tensor_value_info("output1", TensorProto.FLOAT, [1, 1, 3]),
+            helper.make_tensor_value_info("output2", TensorProto.FLOAT, [1, 1, 1]),
+            helper.make_tensor_value_info("output3", TensorProto.FLOAT, [2]),
+            helper.make_tensor_value_info("output4", TensorProto.FLOAT, []),
+        ],
+        initializer=[axes_single, axes_multiple],
+    )
+
+    # Create the model
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="reduce_min",
+        opset_imports=[helper.make_operatorsetid("", 18)],
+    )
+
+    # Save the model to a file
+    onnx.save(model_def, "reduce_min.onnx")
+
+
+if __name__ == "__main__":
+    main()
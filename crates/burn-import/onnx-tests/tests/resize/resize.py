@@ -0,0 +1,75 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/resize/resize.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # The roi is only used by the tf_crop_and_resize mode, it's given as an empty initializer
+    roi = helper.make_tensor("roi", TensorProto.FLOAT, [0], [])
+    scales = helper.make_tensor("scales_const", TensorProto.FLOAT, [4], [1.0, 1.0, 2.0, 2.0])
+    sizes = helper.make_tensor("sizes_const", TensorProto.INT64, [4], [1, 1, 3, 3])
+
+    # Nearest upsampling by the scales of an initializer
+    resize_scales = helper.make_node(
+        "Resize",
+        name="/Resize",
+        inputs=["input", "roi", "scales_const"],
+        outputs=["output_scales"],
+        mode="nearest",
+        coordinate_transformation_mode="asymmetric",
+        nearest_mode="floor",
+    )
+
+    # Linear resize to the sizes of an initializer, the omitted roi and scales are left empty
+    resize_sizes = helper.make_node(
+        "Resize",
+        name="/Resize_1",
+        inputs=["input", "", "", "sizes_const"],
+        outputs=["output_sizes"],
+        mode="linear",
+        coordinate_transformation_mode="half_pixel",
+    )
+
+    # Nearest resize by the scales given at runtime
+    resize_runtime = helper.make_node(
+        "Resize",
+        name="/Resize_2",
+        inputs=["input", "", "scales"],
+        outputs=["output_runtime"],
+        mode="nearest",
+        coordinate_transformation_mode="asymmetric",
+        nearest_mode="floor",
+    )
+
+    # Create the graph
+    graph_def = helper.make_graph(
+        nodes=[resize_scales, resize_sizes, resize_runtime],
+        name="ResizeGraph",
+        inputs=[
+            helper.make_tensor_value_info("input", TensorProto.FLOAT, [1, 1, 2, 2]),
+            helper.make_tensor_value_info("scales", TensorProto.FLOAT, [4]),
+        ],
+        outputs=[
+            helper.make_tensor_value_info("output_scales", TensorProto.FLOAT, [1, 1, 4, 4]),
+            helper.make_tensor_value_info("output_sizes", TensorProto.FLOAT, [1, 1, 3, 3]),
+            helper.make_tensor_value_info("output_runtime", TensorProto.FLOAT, None),
+        ],
+        initializer=[roi, scales, sizes],
+    )
+
+    # Create the model
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="resize",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    # Save the model to a file
+    onnx.save(model_def, "resize.onnx")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,53 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/scatter/scatter.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # The updates replace the values of the data along the first axis
+    scatter_elements = helper.make_node(
+        "ScatterElements",
+        name="/ScatterElements",
+        inputs=["data", "indices", "updates"],
+        outputs=["elements"],
+        axis=0,
+    )
+
+    # The updates are added to the rows of the data selected by the index tuples
+    scatter_nd = helper.make_node(
+        "ScatterND",
+        name="/ScatterND",
+        inputs=["data", "rows", "updates"],
+        outputs=["nd"],
+        reduction="add",
+    )
+
+    graph_def = helper.make_graph(
+        nodes=[scatter_elements, scatter_nd],
+        name="ScatterGraph",
+        inputs=[
+            helper.make_tensor_value_info("data", TensorProto.FLOAT, [3, 3]),
+            helper.make_tensor_value_info("indices", TensorProto.INT64, [2, 3]),
+            helper.make_tensor_value_info("updates", TensorProto.FLOAT, [2, 3]),
+            helper.make_tensor_value_info("rows", TensorProto.INT64, [2, 1]),
+        ],
+        outputs=[
+            helper.make_tensor_value_info("elements", TensorProto.FLOAT, [3, 3]),
+            helper.make_tensor_value_info("nd", TensorProto.FLOAT, [3, 3]),
+        ],
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="scatter",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    onnx.save(model_def, "scatter.onnx")
+
+
+if __name__ == "__main__":
+    main()
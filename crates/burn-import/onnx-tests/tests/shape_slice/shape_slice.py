@@ -0,0 +1,37 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/shape_slice/shape_slice.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    nodes = [
+        # The dimensions 1 and 2, with a negative end
+        helper.make_node("Shape", ["x"], ["middle"], start=1, end=-1),
+        # The last three dimensions, the end being clamped to the rank
+        helper.make_node("Shape", ["x"], ["last"], start=-3, end=10),
+    ]
+
+    graph_def = helper.make_graph(
+        nodes=nodes,
+        name="ShapeSliceGraph",
+        inputs=[helper.make_tensor_value_info("x", TensorProto.FLOAT, [2, 3, 4, 5])],
+        outputs=[
+            helper.make_tensor_value_info("middle", TensorProto.INT64, [2]),
+            helper.make_tensor_value_info("last", TensorProto.INT64, [3]),
+        ],
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="shape_slice",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    onnx.save(model_def, "shape_slice.onnx")
+
+
+if __name__ == "__main__":
+    main()
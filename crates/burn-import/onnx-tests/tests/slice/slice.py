@@ -0,0 +1,48 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/slice/slice.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # Slice the whole first dimension and crop one element on both sides of the
+    # second one, in the negative-end form `x[:, 1:-1]`
+    starts = helper.make_tensor("starts", TensorProto.INT64, [2], [0, 1])
+    ends = helper.make_tensor("ends", TensorProto.INT64, [2], [9223372036854775807, -1])
+    axes = helper.make_tensor("axes", TensorProto.INT64, [2], [0, 1])
+
+    slice_node = helper.make_node(
+        "Slice",
+        name="/Slice",
+        inputs=["input", "starts", "ends", "axes"],
+        outputs=["output"],
+    )
+
+    # Create the graph
+    graph_def = helper.make_graph(
+        nodes=[slice_node],
+        name="SliceGraph",
+        inputs=[
+            helper.make_tensor_value_info("input", TensorProto.FLOAT, [2, 5]),
+        ],
+        outputs=[
+            helper.make_tensor_value_info("output", TensorProto.FLOAT, [2, 3]),
+        ],
+        initializer=[starts, ends, axes],
+    )
+
+    # Create the model
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="slice",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    # Save the model to a file
+    onnx.save(model_def, "slice.onnx")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,47 @@
+#!/usr/bin/env python3
+
+# used to generate models:
+#   onnx-tests/tests/softmax_opset/softmax_opset11.onnx
+#   onnx-tests/tests/softmax_opset/softmax_opset13.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def export(opset: int) -> None:
+    # Before opset 13, the input is coerced to 2D with the dimensions from the axis flattened,
+    # since opset 13 the softmax is computed along the axis only
+    softmax = helper.make_node(
+        "Softmax",
+        name="/Softmax",
+        inputs=["x"],
+        outputs=["output"],
+        axis=1,
+    )
+
+    # Create the graph
+    graph_def = helper.make_graph(
+        nodes=[softmax],
+        name="SoftmaxGraph",
+        inputs=[helper.make_tensor_value_info("x", TensorProto.FLOAT, [2, 2, 2])],
+        outputs=[helper.make_tensor_value_info("output", TensorProto.FLOAT, [2, 2, 2])],
+    )
+
+    # Create the model
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="softmax_opset",
+        opset_imports=[helper.make_operatorsetid("", opset)],
+    )
+
+    # Save the model to a file
+    onnx.save(model_def, f"softmax_opset{opset}.onnx")
+
+
+def main() -> None:
+    export(11)
+    export(13)
+
+
+if __name__ == "__main__":
+    main()
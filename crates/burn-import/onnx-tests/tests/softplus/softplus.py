@@ -0,0 +1,34 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/softplus/softplus.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    node = helper.make_node(
+        "Softplus",
+        name="/Softplus",
+        inputs=["input"],
+        outputs=["output"],
+    )
+
+    graph_def = helper.make_graph(
+        nodes=[node],
+        name="SoftplusGraph",
+        inputs=[helper.make_tensor_value_info("input", TensorProto.FLOAT, [9])],
+        outputs=[helper.make_tensor_value_info("output", TensorProto.FLOAT, [9])],
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="softplus",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    onnx.save(model_def, "softplus.onnx")
+
+
+if __name__ == "__main__":
+    main()
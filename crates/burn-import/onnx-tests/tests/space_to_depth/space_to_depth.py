@@ -0,0 +1,35 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/space_to_depth/space_to_depth.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    node = helper.make_node(
+        "SpaceToDepth",
+        name="/SpaceToDepth",
+        inputs=["input"],
+        outputs=["output"],
+        blocksize=2,
+    )
+
+    graph_def = helper.make_graph(
+        nodes=[node],
+        name="SpaceToDepthGraph",
+        inputs=[helper.make_tensor_value_info("input", TensorProto.FLOAT, [1, 2, 4, 6])],
+        outputs=[helper.make_tensor_value_info("output", TensorProto.FLOAT, [1, 8, 2, 3])],
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="space_to_depth",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    onnx.save(model_def, "space_to_depth.onnx")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,47 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/split/split.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # Split the second dimension in two outputs of uneven sizes
+    split = helper.make_tensor("split", TensorProto.INT64, [2], [2, 3])
+
+    split_node = helper.make_node(
+        "Split",
+        name="/Split",
+        inputs=["input", "split"],
+        outputs=["output1", "output2"],
+        axis=1,
+    )
+
+    # Create the graph
+    graph_def = helper.make_graph(
+        nodes=[split_node],
+        name="SplitGraph",
+        inputs=[
+            helper.make_tensor_value_info("input", TensorProto.FLOAT, [2, 5]),
+        ],
+        outputs=[
+            helper.make_tensor_value_info("output1", TensorProto.FLOAT, [2, 2]),
+            helper.make_tensor_value_info("output2", TensorProto.FLOAT, [2, 3]),
+        ],
+        initializer=[split],
+    )
+
+    # Create the model
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="split",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    # Save the model to a file
+    onnx.save(model_def, "split.onnx")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,71 @@
+#!/usr/bin/env python3
+
+# used to generate models:
+#   onnx-tests/tests/squeeze/squeeze_axes_opset11.onnx
+#   onnx-tests/tests/squeeze/squeeze_axes_opset13.onnx
+#   onnx-tests/tests/squeeze/squeeze_no_axes.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+AXES = [1, -1]
+
+
+def export(opset: int) -> None:
+    # The axes are an attribute before opset 13 and an input since
+    if opset < 13:
+        squeeze = helper.make_node(
+            "Squeeze", name="/Squeeze", inputs=["x"], outputs=["output"], axes=AXES
+        )
+        initializers = []
+    else:
+        squeeze = helper.make_node(
+            "Squeeze", name="/Squeeze", inputs=["x", "axes"], outputs=["output"]
+        )
+        initializers = [helper.make_tensor("axes", TensorProto.INT64, [len(AXES)], AXES)]
+
+    graph_def = helper.make_graph(
+        nodes=[squeeze],
+        name="SqueezeAxesGraph",
+        inputs=[helper.make_tensor_value_info("x", TensorProto.FLOAT, [3, 1, 4, 1])],
+        outputs=[helper.make_tensor_value_info("output", TensorProto.FLOAT, [3, 4])],
+        initializer=initializers,
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="squeeze_axes",
+        opset_imports=[helper.make_operatorsetid("", opset)],
+    )
+
+    onnx.save(model_def, f"squeeze_axes_opset{opset}.onnx")
+
+
+def export_no_axes() -> None:
+    # Without axes, all the dimensions of size 1 are squeezed
+    squeeze = helper.make_node("Squeeze", name="/Squeeze", inputs=["x"], outputs=["output"])
+
+    graph_def = helper.make_graph(
+        nodes=[squeeze],
+        name="SqueezeNoAxesGraph",
+        inputs=[helper.make_tensor_value_info("x", TensorProto.FLOAT, [1, 3, 1, 4])],
+        outputs=[helper.make_tensor_value_info("output", TensorProto.FLOAT, [3, 4])],
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="squeeze_no_axes",
+        opset_imports=[helper.make_operatorsetid("", 13)],
+    )
+
+    onnx.save(model_def, "squeeze_no_axes.onnx")
+
+
+def main() -> None:
+    export(11)
+    export(13)
+    export_no_axes()
+
+
+if __name__ == "__main__":
+    main()
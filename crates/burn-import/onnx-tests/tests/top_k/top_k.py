@@ -0,0 +1,52 @@
+#!/usr/bin/env python3
+
+# used to generate models: onnx-tests/tests/top_k/top_k.onnx and top_k_smallest.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def top_k_model(name: str, largest: int) -> onnx.ModelProto:
+    top_k = helper.make_node(
+        "TopK",
+        name="/TopK",
+        inputs=["input", "k"],
+        outputs=["values", "indices"],
+        axis=1,
+        largest=largest,
+    )
+    # Gathering the input by the indices gives back the values
+    gather = helper.make_node(
+        "GatherElements",
+        name="/GatherElements",
+        inputs=["input", "indices"],
+        outputs=["gathered"],
+        axis=1,
+    )
+
+    graph_def = helper.make_graph(
+        nodes=[top_k, gather],
+        name="TopKGraph",
+        inputs=[helper.make_tensor_value_info("input", TensorProto.FLOAT, [2, 5])],
+        outputs=[
+            helper.make_tensor_value_info("values", TensorProto.FLOAT, [2, 3]),
+            helper.make_tensor_value_info("indices", TensorProto.INT64, [2, 3]),
+            helper.make_tensor_value_info("gathered", TensorProto.FLOAT, [2, 3]),
+        ],
+        initializer=[helper.make_tensor("k", TensorProto.INT64, [1], [3])],
+    )
+
+    return helper.make_model(
+        graph_def,
+        producer_name=name,
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+
+def main() -> None:
+    onnx.save(top_k_model("top_k", 1), "top_k.onnx")
+    onnx.save(top_k_model("top_k_smallest", 0), "top_k_smallest.onnx")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,51 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/trilu/trilu.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # The upper part from the diagonal above the main one, and the lower part from the diagonal
+    # below it
+    upper = helper.make_node(
+        "Trilu",
+        name="/TriluUpper",
+        inputs=["x", "k_upper"],
+        outputs=["upper"],
+        upper=1,
+    )
+    lower = helper.make_node(
+        "Trilu",
+        name="/TriluLower",
+        inputs=["x", "k_lower"],
+        outputs=["lower"],
+        upper=0,
+    )
+
+    graph_def = helper.make_graph(
+        nodes=[upper, lower],
+        name="TriluGraph",
+        inputs=[helper.make_tensor_value_info("x", TensorProto.FLOAT, [2, 3, 4])],
+        outputs=[
+            helper.make_tensor_value_info("upper", TensorProto.FLOAT, [2, 3, 4]),
+            helper.make_tensor_value_info("lower", TensorProto.FLOAT, [2, 3, 4]),
+        ],
+        initializer=[
+            helper.make_tensor("k_upper", TensorProto.INT64, [], [1]),
+            helper.make_tensor("k_lower", TensorProto.INT64, [], [-1]),
+        ],
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="trilu",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    onnx.save(model_def, "trilu.onnx")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,45 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/unary_math/unary_math.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+# Unary math operators applied to the input, the square root being taken of the absolute value
+OPS = ["Abs", "Neg", "Sign", "Floor", "Ceil", "Round", "Reciprocal"]
+
+
+def main() -> None:
+    nodes = [
+        helper.make_node(op, name=f"/{op}", inputs=["x"], outputs=[f"{op.lower()}_output"])
+        for op in OPS
+    ]
+    nodes.append(
+        helper.make_node(
+            "Sqrt", name="/Sqrt", inputs=["abs_output"], outputs=["sqrt_output"]
+        )
+    )
+
+    outputs = [f"{op.lower()}_output" for op in OPS] + ["sqrt_output"]
+
+    graph_def = helper.make_graph(
+        nodes=nodes,
+        name="UnaryMathGraph",
+        inputs=[helper.make_tensor_value_info("x", TensorProto.FLOAT, [8])],
+        outputs=[
+            helper.make_tensor_value_info(output, TensorProto.FLOAT, [8])
+            for output in outputs
+        ],
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="unary_math",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    onnx.save(model_def, "unary_math.onnx")
+
+
+if __name__ == "__main__":
+    main()
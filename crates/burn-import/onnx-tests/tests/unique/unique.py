@@ -0,0 +1,41 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/unique/unique.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # The sorted unique values of the flattened input, with all the optional outputs
+    unique = helper.make_node(
+        "Unique",
+        name="/Unique",
+        inputs=["input"],
+        outputs=["values", "indices", "inverse_indices", "counts"],
+        sorted=1,
+    )
+
+    graph_def = helper.make_graph(
+        nodes=[unique],
+        name="UniqueGraph",
+        inputs=[helper.make_tensor_value_info("input", TensorProto.FLOAT, [2, 3])],
+        outputs=[
+            helper.make_tensor_value_info("values", TensorProto.FLOAT, ["n"]),
+            helper.make_tensor_value_info("indices", TensorProto.INT64, ["n"]),
+            helper.make_tensor_value_info("inverse_indices", TensorProto.INT64, ["n"]),
+            helper.make_tensor_value_info("counts", TensorProto.INT64, ["n"]),
+        ],
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="unique",
+        opset_imports=[helper.make_operatorsetid("", 16)],
+    )
+
+    onnx.save(model_def, "unique.onnx")
+
+
+if __name__ == "__main__":
+    main()
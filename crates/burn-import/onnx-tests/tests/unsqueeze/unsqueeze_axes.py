@@ -0,0 +1,50 @@
+#!/usr/bin/env python3
+
+# used to generate models:
+#   onnx-tests/tests/unsqueeze/unsqueeze_axes_opset11.onnx
+#   onnx-tests/tests/unsqueeze/unsqueeze_axes_opset13.onnx
+
+import onnx
+from onnx import helper, TensorProto
+
+# The axes are positions in the output, in any order and possibly negative
+AXES = [-1, 1, 0]
+
+
+def export(opset: int) -> None:
+    # The axes are an attribute before opset 13 and an input since
+    if opset < 13:
+        unsqueeze = helper.make_node(
+            "Unsqueeze", name="/Unsqueeze", inputs=["x"], outputs=["output"], axes=AXES
+        )
+        initializers = []
+    else:
+        unsqueeze = helper.make_node(
+            "Unsqueeze", name="/Unsqueeze", inputs=["x", "axes"], outputs=["output"]
+        )
+        initializers = [helper.make_tensor("axes", TensorProto.INT64, [len(AXES)], AXES)]
+
+    graph_def = helper.make_graph(
+        nodes=[unsqueeze],
+        name="UnsqueezeAxesGraph",
+        inputs=[helper.make_tensor_value_info("x", TensorProto.FLOAT, [3, 4])],
+        outputs=[helper.make_tensor_value_info("output", TensorProto.FLOAT, [1, 1, 3, 4, 1])],
+        initializer=initializers,
+    )
+
+    model_def = helper.make_model(
+        graph_def,
+        producer_name="unsqueeze_axes",
+        opset_imports=[helper.make_operatorsetid("", opset)],
+    )
+
+    onnx.save(model_def, f"unsqueeze_axes_opset{opset}.onnx")
+
+
+def main() -> None:
+    export(11)
+    export(13)
+
+
+if __name__ == "__main__":
+    main()
@@ -17,4 +17,5 @@ mod layer_norm;
 mod linear;
 mod missing_module_field;
 mod non_contiguous_indexes;
+mod safetensors;
 mod top_level_key;
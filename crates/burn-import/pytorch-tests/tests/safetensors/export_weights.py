@@ -0,0 +1,42 @@
+#!/usr/bin/env python3
+
+import torch
+import torch.nn as nn
+from safetensors.torch import save_file
+
+
+class Model(nn.Module):
+    def __init__(self):
+        super(Model, self).__init__()
+        self.fc1 = nn.Linear(2, 3)
+
+    def forward(self, x):
+        x = self.fc1(x)
+
+        return x
+
+
+def main():
+
+    model = Model().to(torch.device("cpu"))
+
+    # Use known weights so that the values can be checked from Rust.
+    with torch.no_grad():
+        model.fc1.weight.copy_(torch.tensor([[0.1, 0.2], [0.3, 0.4], [0.5, 0.6]]))
+        model.fc1.bias.copy_(torch.tensor([0.01, 0.02, 0.03]))
+
+    save_file(model.state_dict(), "linear.safetensors")
+    save_file(
+        {key: value.half() for key, value in model.state_dict().items()},
+        "linear_f16.safetensors",
+    )
+
+    input = torch.tensor([[1.0, 2.0]])
+    print("Input: {}", input)
+
+    output = model(input)
+    print("Output: {}", output)
+
+
+if __name__ == '__main__':
+    main()
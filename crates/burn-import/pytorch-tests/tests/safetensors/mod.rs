@@ -0,0 +1,120 @@
+use burn::{
+    module::Module,
+    nn::{Linear, LinearConfig},
+    tensor::{backend::Backend, Tensor},
+};
+
+#[derive(Module, Debug)]
+pub struct Net<B: Backend> {
+    fc1: Linear<B>,
+}
+
+impl<B: Backend> Net<B> {
+    /// Create a new model.
+    pub fn init(device: &B::Device) -> Self {
+        let fc1 = LinearConfig::new(2, 3).init(device);
+
+        Self { fc1 }
+    }
+
+    /// Forward pass of the model.
+    pub fn forward(&self, x: Tensor<B, 2>) -> Tensor<B, 2> {
+        self.fc1.forward(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    type Backend = burn_ndarray::NdArray<f32>;
+
+    use burn::{
+        record::{FullPrecisionSettings, HalfPrecisionSettings, Recorder},
+        tensor::Data,
+    };
+
+    use burn_import::safetensors::{AdapterType, LoadArgs, SafetensorsFileRecorder};
+
+    use super::*;
+
+    fn linear_test(record: NetRecord<Backend>, precision: usize) {
+        let device = Default::default();
+        let model = Net::<Backend>::init(&device).load_record(record);
+
+        // PyTorch stores the weight as [d_output, d_input].
+        let expected_weight = Data::from([[0.1, 0.3, 0.5], [0.2, 0.4, 0.6]]);
+        let expected_bias = Data::from([0.01, 0.02, 0.03]);
+
+        model
+            .fc1
+            .weight
+            .to_data()
+            .assert_approx_eq(&expected_weight, precision);
+        model
+            .fc1
+            .bias
+            .as_ref()
+            .expect("Should have a bias")
+            .to_data()
+            .assert_approx_eq(&expected_bias, precision);
+
+        let input = Tensor::<Backend, 2>::from_data([[1.0, 2.0]], &device);
+        let output = model.forward(input);
+        let expected = Data::from([[0.51, 1.12, 1.73]]);
+
+        output.to_data().assert_approx_eq(&expected, precision);
+    }
+
+    #[test]
+    fn safetensors_full_precision() {
+        let device = Default::default();
+        let record = SafetensorsFileRecorder::<FullPrecisionSettings>::default()
+            .load("tests/safetensors/linear.safetensors".into(), &device)
+            .expect("Should decode state successfully");
+
+        linear_test(record, 6);
+    }
+
+    #[test]
+    fn safetensors_half_precision() {
+        let device = Default::default();
+        let record = SafetensorsFileRecorder::<HalfPrecisionSettings>::default()
+            .load("tests/safetensors/linear.safetensors".into(), &device)
+            .expect("Should decode state successfully");
+
+        linear_test(record, 3);
+    }
+
+    #[test]
+    fn safetensors_f16_file() {
+        let device = Default::default();
+        let record = SafetensorsFileRecorder::<FullPrecisionSettings>::default()
+            .load("tests/safetensors/linear_f16.safetensors".into(), &device)
+            .expect("Should decode state successfully");
+
+        linear_test(record, 2);
+    }
+
+    #[test]
+    fn safetensors_save_and_load() {
+        let device = Default::default();
+        let recorder = SafetensorsFileRecorder::<FullPrecisionSettings>::default();
+        let record = recorder
+            .load("tests/safetensors/linear.safetensors".into(), &device)
+            .expect("Should decode state successfully");
+        let model = Net::<Backend>::init(&device).load_record(record);
+
+        let file = std::env::temp_dir().join("burn_safetensors_save_and_load.safetensors");
+        recorder
+            .record(model.into_record(), file.clone())
+            .expect("Should save the record successfully");
+
+        let record = recorder
+            .load(
+                LoadArgs::new(file).with_adapter_type(AdapterType::NoAdapter),
+                &device,
+            )
+            .expect("Should decode state successfully");
+
+        linear_test(record, 6);
+    }
+}
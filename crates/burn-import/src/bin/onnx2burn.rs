@@ -10,12 +10,17 @@ fn main() {
         .expect("No output directory provided");
 
     // Generate the model code from the ONNX file.
-    ModelGen::new()
+    let result = ModelGen::new()
         .input(onnx_file.as_str())
         .development(true)
         .record_type(RecordType::PrettyJson)
         .out_dir(output_dir.as_str())
         .run_from_cli();
+
+    if let Err(err) = result {
+        eprintln!("{err}");
+        std::process::exit(1);
+    }
 }
 
 #[cfg(not(feature = "onnx"))]
@@ -1,4 +1,4 @@
-use super::{BurnImports, Scope, Type};
+use super::{BurnImports, Scope, ScopeError, Type};
 use crate::burn::{
     node::{Node, NodeCodegen},
     TensorKind, TensorType,
@@ -199,15 +199,26 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
     }
 
     /// Generate tokens reprensenting the graph with Burn modules and tensor operations.
-    pub fn codegen(mut self) -> TokenStream {
-        self.build_scope();
+    ///
+    /// # Panics
+    ///
+    /// If a node uses a variable that isn't declared before it, see [try_codegen](BurnGraph::try_codegen).
+    pub fn codegen(self) -> TokenStream {
+        self.try_codegen().unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Generate tokens reprensenting the graph with Burn modules and tensor operations.
+    ///
+    /// Returns an error if a node uses a variable that isn't declared before it.
+    pub fn try_codegen(mut self) -> Result<TokenStream, ScopeError> {
+        self.build_scope()?;
 
         self.register_imports();
 
         let codegen_imports = self.imports.codegen();
         let codegen_struct = self.codegen_struct();
         let codegen_new = self.codegen_new();
-        let codegen_forward = self.codegen_forward();
+        let codegen_forward = self.codegen_forward()?;
 
         let maybe_blank = match self.blank_spaces {
             true => quote! {
@@ -230,7 +241,7 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
             None => quote! {},
         };
 
-        quote! {
+        Ok(quote! {
             #maybe_top_file_comment
             #codegen_imports
             #maybe_blank
@@ -248,7 +259,7 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
 
                 #codegen_forward
             }
-        }
+        })
     }
 
     fn register_imports(&mut self) {
@@ -283,7 +294,7 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
         }
     }
     /// Build the scope state to make sure tensor clones are added where needed.
-    fn build_scope(&mut self) {
+    fn build_scope(&mut self) -> Result<(), ScopeError> {
         log::debug!("Building the scope nodes len => '{}'", self.nodes.len());
 
         fn to_tensor(ty: Type) -> Option<TensorType> {
@@ -316,18 +327,26 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
                     })
             });
 
-        self.nodes
-            .iter()
-            .enumerate()
-            .for_each(|(node_position, node)| {
-                node.input_types()
-                    .into_iter()
-                    .flat_map(to_tensor)
-                    .for_each(|tensor| {
-                        self.scope
-                            .tensor_register_future_use(&tensor, node_position)
-                    })
-            });
+        for (node_position, node) in self.nodes.iter().enumerate() {
+            for tensor in node.input_types().into_iter().flat_map(to_tensor) {
+                self.scope
+                    .tensor_register_future_use(&tensor, node_position)?;
+            }
+        }
+
+        // The graph outputs are used when returned, after the last node
+        let output_position = self.nodes.len();
+        for tensor in self
+            .graph_output_types
+            .clone()
+            .into_iter()
+            .flat_map(to_tensor)
+        {
+            self.scope
+                .tensor_register_future_use(&tensor, output_position)?;
+        }
+
+        Ok(())
     }
 
     fn register_record_file(&mut self, file: PathBuf, recorder_str: &str) {
@@ -454,7 +473,7 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
         }
     }
 
-    fn codegen_forward(&mut self) -> TokenStream {
+    fn codegen_forward(&mut self) -> Result<TokenStream, ScopeError> {
         let mut input_def = quote! {};
         let mut output_type_def = quote! {};
         let mut output_return_def = quote! {};
@@ -469,10 +488,22 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
             })
         });
 
-        let multiple_output = self.graph_output_types.len() > 1;
+        let mut body = quote! {};
+        for (index, node) in self.nodes.iter().enumerate() {
+            body.extend(node.forward(&mut self.scope, index)?);
+        }
 
-        self.graph_output_types.iter().for_each(|output| {
-            let name = output.name();
+        let multiple_output = self.graph_output_types.len() > 1;
+        let output_position = self.nodes.len();
+
+        for output in self.graph_output_types.iter() {
+            let name = match output {
+                Type::Tensor(tensor) => self.scope.tensor_use_owned(tensor, output_position)?,
+                _ => {
+                    let name = output.name();
+                    quote! { #name }
+                }
+            };
             let ty = output.ty();
 
             if multiple_output {
@@ -490,7 +521,7 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
                     #name
                 });
             }
-        });
+        }
 
         if multiple_output {
             output_return_def = quote! {
@@ -501,24 +532,21 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
             };
         }
 
-        let mut body = quote! {};
-        self.nodes
-            .iter()
-            .enumerate()
-            .map(|(index, node)| node.forward(&mut self.scope, index))
-            .for_each(|code| body.extend(code));
+        for warning in self.scope.validate() {
+            log::warn!("{warning}");
+        }
 
         // TODO Return the result without a `let` binding from a block,
         // otherwise let_and_return error will be triggered by clippy.
         // For now, we just disable the warning.
-        quote! {
+        Ok(quote! {
             #[allow(clippy::let_and_return, clippy::approx_constant)]
             pub fn forward(&self, #input_def) -> #output_type_def {
                 #body
 
                 #output_return_def
             }
-        }
+        })
     }
 
     /// Register the input and output types of the graph using the passed in names.
@@ -26,16 +26,16 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for ArgMaxNode {
         &self,
         scope: &mut crate::burn::Scope,
         node_position: usize,
-    ) -> proc_macro2::TokenStream {
+    ) -> Result<proc_macro2::TokenStream, crate::burn::ScopeError> {
         //NOTE: select_last_index and keep_dims are not supported
         let axis = self.axis.to_tokens();
 
-        let input = scope.tensor_use_owned(&self.input, node_position);
+        let input = scope.tensor_use_owned(&self.input, node_position)?;
         let output = &self.output.name;
 
-        quote! {
+        Ok(quote! {
             let #output = #input.argmax(#axis);
-        }
+        })
     }
 
     fn into_node(self) -> super::Node<PS> {
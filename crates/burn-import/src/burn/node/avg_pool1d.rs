@@ -4,7 +4,7 @@ use quote::quote;
 use burn::{nn::pool::AvgPool1dConfig, record::PrecisionSettings};
 
 use super::{Node, NodeCodegen};
-use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
+use crate::burn::{BurnImports, OtherType, Scope, ScopeError, TensorType, ToTokens, Type};
 
 #[derive(Debug, Clone)]
 pub struct AvgPool1dNode {
@@ -64,14 +64,14 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for AvgPool1dNode {
         Some(tokens)
     }
 
-    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
-        let input = scope.tensor_use_owned(&self.input, node_position);
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
+        let input = scope.tensor_use_owned(&self.input, node_position)?;
         let output = &self.output.name;
         let field = &self.field.name;
 
-        quote! {
+        Ok(quote! {
             let #output = self.#field.forward(#input);
-        }
+        })
     }
 
     fn register_imports(&self, imports: &mut BurnImports) {
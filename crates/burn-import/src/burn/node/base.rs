@@ -2,15 +2,20 @@ use super::{
     argmax::ArgMaxNode, avg_pool1d::AvgPool1dNode, avg_pool2d::AvgPool2dNode,
     batch_norm::BatchNormNode, binary::BinaryNode, clip::ClipNode, concat::ConcatNode,
     constant::ConstantNode, conv1d::Conv1dNode, conv2d::Conv2dNode,
-    conv_transpose_2d::ConvTranspose2dNode, dropout::DropoutNode, expand::ExpandNode,
-    gather::GatherNode, gather_elements::GatherElementsNode, global_avg_pool::GlobalAvgPoolNode,
-    layer_norm::LayerNormNode, linear::LinearNode, mask_where::WhereNode, matmul::MatmulNode,
-    max_pool1d::MaxPool1dNode, max_pool2d::MaxPool2dNode, prelu::PReluNode,
+    conv_transpose_2d::ConvTranspose2dNode, cum_sum::CumSumNode, depth_to_space::DepthToSpaceNode,
+    dropout::DropoutNode, einsum::EinsumNode, expand::ExpandNode, gather::GatherNode,
+    gather_elements::GatherElementsNode, gather_nd::GatherNDNode, gemm::GemmNode,
+    global_avg_pool::GlobalAvgPoolNode, global_max_pool::GlobalMaxPoolNode,
+    group_norm::GroupNormNode, gru::GruNode, layer_norm::LayerNormNode, linear::LinearNode,
+    lstm::LstmNode, mask_where::WhereNode, matmul::MatmulNode, max_pool1d::MaxPool1dNode,
+    max_pool2d::MaxPool2dNode, one_hot::OneHotNode, pad::PadNode, prelu::PReluNode,
     random_normal::RandomNormalNode, random_uniform::RandomUniformNode, range::RangeNode,
-    reshape::ReshapeNode, squeeze::SqueezeNode, sum::SumNode, unary::UnaryNode,
-    unsqueeze::UnsqueezeNode,
+    reshape::ReshapeNode, resize::ResizeNode, scatter_elements::ScatterElementsNode,
+    scatter_nd::ScatterNDNode, slice::SliceNode, space_to_depth::SpaceToDepthNode,
+    split::SplitNode, squeeze::SqueezeNode, sum::SumNode, top_k::TopKNode, trilu::TriluNode,
+    unary::UnaryNode, unique::UniqueNode, unsqueeze::UnsqueezeNode,
 };
-use crate::burn::{BurnImports, Scope, Type};
+use crate::burn::{BurnImports, Scope, ScopeError, Type};
 use burn::backend::NdArray;
 use burn::record::PrecisionSettings;
 use proc_macro2::TokenStream;
@@ -38,7 +43,9 @@ pub trait NodeCodegen<PS: PrecisionSettings>: std::fmt::Debug {
     /// The [Scope](Scope) struct should be used for [input tensor type](Type::Tensor) access.
     /// The method [use_owned_tensor](Scope::use_owned_tensor) keeps track of tensor reference
     /// count and insert `clone` with necessary.
-    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream;
+    ///
+    /// Returns an error if the node uses a variable that isn't declared before it.
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError>;
 
     /// Convert the node implementation into a [node entry](Node).
     fn into_node(self) -> Node<PS>;
@@ -89,22 +96,42 @@ pub enum Node<PS: PrecisionSettings> {
     Conv1d(Conv1dNode<PS>),
     Conv2d(Conv2dNode<PS>),
     ConvTranspose2d(ConvTranspose2dNode<PS>),
+    CumSum(CumSumNode),
+    DepthToSpace(DepthToSpaceNode),
     PRelu(PReluNode<PS>),
     Dropout(DropoutNode),
+    Einsum(EinsumNode),
     Expand(ExpandNode),
     Gather(GatherNode),
     GatherElements(GatherElementsNode),
+    GatherND(GatherNDNode),
+    Gemm(GemmNode),
     GlobalAvgPool(GlobalAvgPoolNode),
+    GlobalMaxPool(GlobalMaxPoolNode),
+    GroupNorm(GroupNormNode<PS>),
+    Gru(GruNode<PS>),
     LayerNorm(LayerNormNode<PS>),
     Linear(LinearNode<PS>),
+    Lstm(LstmNode<PS>),
     Matmul(MatmulNode),
     MaxPool1d(MaxPool1dNode),
     MaxPool2d(MaxPool2dNode),
+    OneHot(OneHotNode),
+    Pad(PadNode),
     Range(RangeNode),
     Reshape(ReshapeNode),
+    Resize(ResizeNode),
+    ScatterElements(ScatterElementsNode),
+    ScatterND(ScatterNDNode),
+    Slice(SliceNode),
+    SpaceToDepth(SpaceToDepthNode),
+    Split(SplitNode),
     Squeeze(SqueezeNode),
     Sum(SumNode),
+    TopK(TopKNode),
+    Trilu(TriluNode),
     Unary(UnaryNode),
+    Unique(UniqueNode),
     Unsqueeze(UnsqueezeNode),
     Where(WhereNode),
     RandomUniform(RandomUniformNode),
@@ -126,22 +153,42 @@ macro_rules! match_all {
             Node::Conv1d(node) => $func(node),
             Node::Conv2d(node) => $func(node),
             Node::ConvTranspose2d(node) => $func(node),
+            Node::CumSum(node) => $func(node),
+            Node::DepthToSpace(node) => $func(node),
             Node::PRelu(node) => $func(node),
             Node::Dropout(node) => $func(node),
+            Node::Einsum(node) => $func(node),
             Node::Expand(node) => $func(node),
             Node::Gather(node) => $func(node),
             Node::GatherElements(node) => $func(node),
+            Node::GatherND(node) => $func(node),
+            Node::Gemm(node) => $func(node),
             Node::GlobalAvgPool(node) => $func(node),
+            Node::GlobalMaxPool(node) => $func(node),
+            Node::GroupNorm(node) => $func(node),
+            Node::Gru(node) => $func(node),
             Node::LayerNorm(node) => $func(node),
             Node::Linear(node) => $func(node),
+            Node::Lstm(node) => $func(node),
             Node::Matmul(node) => $func(node),
             Node::MaxPool1d(node) => $func(node),
             Node::MaxPool2d(node) => $func(node),
+            Node::OneHot(node) => $func(node),
+            Node::Pad(node) => $func(node),
             Node::Range(node) => $func(node),
             Node::Reshape(node) => $func(node),
+            Node::Resize(node) => $func(node),
+            Node::ScatterElements(node) => $func(node),
+            Node::ScatterND(node) => $func(node),
+            Node::Slice(node) => $func(node),
+            Node::SpaceToDepth(node) => $func(node),
+            Node::Split(node) => $func(node),
             Node::Squeeze(node) => $func(node),
             Node::Sum(node) => $func(node),
+            Node::TopK(node) => $func(node),
+            Node::Trilu(node) => $func(node),
             Node::Unary(node) => $func(node),
+            Node::Unique(node) => $func(node),
             Node::Unsqueeze(node) => $func(node),
             Node::Where(node) => $func(node),
             Node::RandomNormal(node) => $func(node),
@@ -173,22 +220,42 @@ impl<PS: PrecisionSettings> Node<PS> {
             Node::Conv1d(_) => "conv1d",
             Node::Conv2d(_) => "conv2d",
             Node::ConvTranspose2d(_) => "conv_transpose2d",
+            Node::CumSum(_) => "cum_sum",
+            Node::DepthToSpace(_) => "depth_to_space",
             Node::PRelu(_) => "prelu",
             Node::Dropout(_) => "dropout",
+            Node::Einsum(_) => "einsum",
             Node::Expand(_) => "expand",
             Node::Gather(_) => "gather",
             Node::GatherElements(_) => "gather_elements",
+            Node::GatherND(_) => "gather_nd",
+            Node::Gemm(_) => "gemm",
             Node::GlobalAvgPool(_) => "global_avg_pool",
+            Node::GlobalMaxPool(_) => "global_max_pool",
+            Node::GroupNorm(_) => "group_norm",
+            Node::Gru(_) => "gru",
             Node::LayerNorm(_) => "layer_norm",
             Node::Linear(_) => "linear",
+            Node::Lstm(_) => "lstm",
             Node::Matmul(_) => "matmul",
             Node::MaxPool1d(_) => "max_pool1d",
             Node::MaxPool2d(_) => "max_pool2d",
+            Node::OneHot(_) => "one_hot",
+            Node::Pad(_) => "pad",
             Node::Range(_) => "range",
             Node::Reshape(_) => "reshape",
+            Node::Resize(_) => "resize",
+            Node::ScatterElements(_) => "scatter_elements",
+            Node::ScatterND(_) => "scatter_nd",
+            Node::Slice(_) => "slice",
+            Node::SpaceToDepth(_) => "space_to_depth",
+            Node::Split(_) => "split",
             Node::Squeeze(_) => "squeeze",
             Node::Sum(_) => "add",
+            Node::TopK(_) => "top_k",
+            Node::Trilu(_) => "trilu",
             Node::Unary(unary) => unary.kind.as_str(),
+            Node::Unique(_) => "unique",
             Node::Unsqueeze(_) => "unsqueeze",
             Node::Where(_) => "where",
             Node::RandomNormal(_) => "random_normal",
@@ -206,7 +273,7 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for Node<PS> {
         match_all!(self, NodeCodegen::<PS>::input_types)
     }
 
-    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
         match_all!(self, |node| NodeCodegen::<PS>::forward(
             node,
             scope,
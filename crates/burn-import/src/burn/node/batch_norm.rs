@@ -1,5 +1,5 @@
 use super::{Node, NodeCodegen, SerializationBackend};
-use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
+use crate::burn::{BurnImports, OtherType, Scope, ScopeError, TensorType, ToTokens, Type};
 use burn::{
     module::{ConstantRecord, Param, ParamId},
     nn::{BatchNormConfig, BatchNormRecord},
@@ -133,14 +133,14 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for BatchNormNode<PS> {
         batch_norm_serialize!(self, serializer)
     }
 
-    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
-        let input = scope.tensor_use_owned(&self.input, node_position);
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
+        let input = scope.tensor_use_owned(&self.input, node_position)?;
         let output = &self.output.name;
         let field = &self.field.name;
 
-        quote! {
+        Ok(quote! {
             let #output = self.#field.forward(#input);
-        }
+        })
     }
     fn register_imports(&self, imports: &mut BurnImports) {
         imports.register("burn::nn::BatchNorm");
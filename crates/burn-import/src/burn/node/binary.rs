@@ -1,7 +1,7 @@
 use super::{Node, NodeCodegen};
-use crate::burn::{Scope, Type};
+use crate::burn::{Scope, ScopeError, TensorKind, Type};
 use burn::record::PrecisionSettings;
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, Literal, Span, TokenStream};
 use quote::quote;
 use std::sync::Arc;
 
@@ -14,12 +14,18 @@ pub enum BinaryType {
     Equal,
     Powf,
     Powi,
+    Mod,
     Min,
     Max,
     Greater,
     GreaterOrEqual,
     Less,
     LessOrEqual,
+    BitwiseAnd,
+    BitwiseOr,
+    BitwiseXor,
+    BitShiftLeft,
+    BitShiftRight,
 }
 
 impl BinaryType {
@@ -32,16 +38,29 @@ impl BinaryType {
             BinaryType::Equal => "equal",
             BinaryType::Powi => "powi",
             BinaryType::Powf => "powf",
+            BinaryType::Mod => "remainder",
             BinaryType::Min => "min_pair",
             BinaryType::Max => "max_pair",
             BinaryType::Greater => "greater",
             BinaryType::GreaterOrEqual => "greater_equal",
             BinaryType::Less => "lower",
             BinaryType::LessOrEqual => "lower_equal",
+            BinaryType::BitwiseAnd => "bitwise_and",
+            BinaryType::BitwiseOr => "bitwise_or",
+            BinaryType::BitwiseXor => "bitwise_xor",
+            BinaryType::BitShiftLeft => "bitwise_left_shift",
+            BinaryType::BitShiftRight => "bitwise_right_shift",
         }
     }
 }
 
+/// Direction of the bits shifted by the BitShift operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitShiftDirection {
+    Left,
+    Right,
+}
+
 // Simple fn pointer that receive input as a token stream and return function call.
 type FnPointer = Arc<dyn Fn(TokenStream, TokenStream) -> TokenStream>;
 
@@ -79,11 +98,14 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for BinaryNode {
         vec![self.lhs.clone(), self.rhs.clone()]
     }
 
-    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
         // Get the lhs name in the form of token stream.
-        let lhs = match &self.lhs {
-            Type::Tensor(tensor) => scope.tensor_use_owned(tensor, node_position),
-            Type::Scalar(scalar) => {
+        let lhs = match (&self.lhs, &self.output) {
+            (Type::Tensor(tensor), Type::Tensor(output)) => {
+                scope.tensor_use_inplace(tensor, output, node_position)?
+            }
+            (Type::Tensor(tensor), _) => scope.tensor_use_owned(tensor, node_position)?,
+            (Type::Scalar(scalar), _) => {
                 let name = scalar.name.clone();
                 quote! { #name }
             }
@@ -92,7 +114,7 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for BinaryNode {
 
         // Get the rhs name in the form of token stream
         let rhs = match &self.rhs {
-            Type::Tensor(tensor) => scope.tensor_use_owned(tensor, node_position),
+            Type::Tensor(tensor) => scope.tensor_use_owned(tensor, node_position)?,
             Type::Scalar(scalar) => {
                 let name = scalar.name.clone();
                 quote! { #name }
@@ -103,9 +125,9 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for BinaryNode {
         let output = &self.output.name();
         let function = (self.function)(lhs, rhs);
 
-        quote! {
+        Ok(quote! {
             let #output = #function;
-        }
+        })
     }
 
     fn into_node(self) -> Node<PS> {
@@ -115,49 +137,89 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for BinaryNode {
 
 impl BinaryNode {
     pub(crate) fn add(lhs: Type, rhs: Type, output: Type) -> Self {
-        let function = match (&lhs, &rhs) {
-            (Type::Tensor(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #lhs.add(#rhs) },
-            (Type::Tensor(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs.add_scalar(#rhs) },
-            (Type::Scalar(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #rhs.add_scalar(#lhs) },
-            (Type::Scalar(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs + #rhs },
+        let function: FnPointer = match (&lhs, &rhs) {
+            (Type::Tensor(lhs), Type::Tensor(rhs)) => {
+                let (lhs_dim, rhs_dim) = (lhs.dim, rhs.dim);
+
+                Arc::new(move |lhs, rhs| {
+                    let (lhs, rhs) = broadcast_ranks(lhs, lhs_dim, rhs, rhs_dim);
+                    quote! { #lhs.add(#rhs) }
+                })
+            }
+            (Type::Tensor(_), Type::Scalar(_)) => {
+                Arc::new(move |lhs, rhs| quote! { #lhs.add_scalar(#rhs) })
+            }
+            (Type::Scalar(_), Type::Tensor(_)) => {
+                Arc::new(move |lhs, rhs| quote! { #rhs.add_scalar(#lhs) })
+            }
+            (Type::Scalar(_), Type::Scalar(_)) => Arc::new(move |lhs, rhs| quote! { #lhs + #rhs }),
             _ => panic!("Addition is supported for tensor and scalar only"),
         };
 
-        Self::new(lhs, rhs, output, BinaryType::Add, Arc::new(function))
+        Self::new(lhs, rhs, output, BinaryType::Add, function)
     }
 
     pub(crate) fn sub(lhs: Type, rhs: Type, output: Type) -> Self {
-        let function = match (&lhs, &rhs) {
-            (Type::Tensor(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #lhs.sub(#rhs) },
-            (Type::Tensor(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs.sub_scalar(#rhs) },
-            (Type::Scalar(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs - #rhs },
+        let function: FnPointer = match (&lhs, &rhs) {
+            (Type::Tensor(lhs), Type::Tensor(rhs)) => {
+                let (lhs_dim, rhs_dim) = (lhs.dim, rhs.dim);
+
+                Arc::new(move |lhs, rhs| {
+                    let (lhs, rhs) = broadcast_ranks(lhs, lhs_dim, rhs, rhs_dim);
+                    quote! { #lhs.sub(#rhs) }
+                })
+            }
+            (Type::Tensor(_), Type::Scalar(_)) => {
+                Arc::new(move |lhs, rhs| quote! { #lhs.sub_scalar(#rhs) })
+            }
+            (Type::Scalar(_), Type::Scalar(_)) => Arc::new(move |lhs, rhs| quote! { #lhs - #rhs }),
             _ => panic!("Subtraction is supported for tensor and scalar only"),
         };
 
-        Self::new(lhs, rhs, output, BinaryType::Sub, Arc::new(function))
+        Self::new(lhs, rhs, output, BinaryType::Sub, function)
     }
 
     pub(crate) fn mul(lhs: Type, rhs: Type, output: Type) -> Self {
-        let function = match (&lhs, &rhs) {
-            (Type::Tensor(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #lhs.mul(#rhs) },
-            (Type::Tensor(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs.mul_scalar(#rhs) },
-            (Type::Scalar(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #rhs.mul_scalar(#lhs) },
-            (Type::Scalar(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs * #rhs },
+        let function: FnPointer = match (&lhs, &rhs) {
+            (Type::Tensor(lhs), Type::Tensor(rhs)) => {
+                let (lhs_dim, rhs_dim) = (lhs.dim, rhs.dim);
+
+                Arc::new(move |lhs, rhs| {
+                    let (lhs, rhs) = broadcast_ranks(lhs, lhs_dim, rhs, rhs_dim);
+                    quote! { #lhs.mul(#rhs) }
+                })
+            }
+            (Type::Tensor(_), Type::Scalar(_)) => {
+                Arc::new(move |lhs, rhs| quote! { #lhs.mul_scalar(#rhs) })
+            }
+            (Type::Scalar(_), Type::Tensor(_)) => {
+                Arc::new(move |lhs, rhs| quote! { #rhs.mul_scalar(#lhs) })
+            }
+            (Type::Scalar(_), Type::Scalar(_)) => Arc::new(move |lhs, rhs| quote! { #lhs * #rhs }),
             _ => panic!("Multiplication is supported for tensor and scalar only"),
         };
 
-        Self::new(lhs, rhs, output, BinaryType::Mul, Arc::new(function))
+        Self::new(lhs, rhs, output, BinaryType::Mul, function)
     }
 
     pub(crate) fn div(lhs: Type, rhs: Type, output: Type) -> Self {
-        let function = match (&lhs, &rhs) {
-            (Type::Tensor(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #lhs.div(#rhs) },
-            (Type::Tensor(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs.div_scalar(#rhs) },
-            (Type::Scalar(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs / #rhs },
+        let function: FnPointer = match (&lhs, &rhs) {
+            (Type::Tensor(lhs), Type::Tensor(rhs)) => {
+                let (lhs_dim, rhs_dim) = (lhs.dim, rhs.dim);
+
+                Arc::new(move |lhs, rhs| {
+                    let (lhs, rhs) = broadcast_ranks(lhs, lhs_dim, rhs, rhs_dim);
+                    quote! { #lhs.div(#rhs) }
+                })
+            }
+            (Type::Tensor(_), Type::Scalar(_)) => {
+                Arc::new(move |lhs, rhs| quote! { #lhs.div_scalar(#rhs) })
+            }
+            (Type::Scalar(_), Type::Scalar(_)) => Arc::new(move |lhs, rhs| quote! { #lhs / #rhs }),
             _ => panic!("Division is supported for tensor and scalar only"),
         };
 
-        Self::new(lhs, rhs, output, BinaryType::Div, Arc::new(function))
+        Self::new(lhs, rhs, output, BinaryType::Div, function)
     }
 
     pub(crate) fn equal(lhs: Type, rhs: Type, output: Type) -> Self {
@@ -170,20 +232,100 @@ impl BinaryNode {
         Self::new(lhs, rhs, output, BinaryType::Equal, Arc::new(function))
     }
     pub(crate) fn powf(lhs: Type, rhs: Type, output: Type) -> Self {
-        let function = match (&lhs, &rhs) {
-            (Type::Tensor(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #lhs.powf(#rhs) },
-            (Type::Tensor(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs.powf_scalar(#rhs) },
+        let function: FnPointer = match (&lhs, &rhs) {
+            (Type::Tensor(lhs), Type::Tensor(rhs)) => {
+                let (lhs_dim, rhs_dim) = (lhs.dim, rhs.dim);
+
+                Arc::new(move |lhs, rhs| {
+                    let (lhs, rhs) = broadcast_ranks(lhs, lhs_dim, rhs, rhs_dim);
+                    quote! { #lhs.powf(#rhs) }
+                })
+            }
+            (Type::Tensor(_), Type::Scalar(_)) => {
+                Arc::new(move |lhs, rhs| quote! { #lhs.powf_scalar(#rhs) })
+            }
             _ => panic!("pow is supported for tensor only"),
         };
-        Self::new(lhs, rhs, output, BinaryType::Powf, Arc::new(function))
+        Self::new(lhs, rhs, output, BinaryType::Powf, function)
     }
     pub(crate) fn powi(lhs: Type, rhs: Type, output: Type) -> Self {
-        let function = match (&lhs, &rhs) {
-            (Type::Tensor(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #lhs.powi(#rhs) },
-            (Type::Tensor(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs.powi_scalar(#rhs) },
+        let function: FnPointer = match (&lhs, &rhs) {
+            (Type::Tensor(lhs), Type::Tensor(rhs)) => {
+                let (lhs_dim, rhs_dim) = (lhs.dim, rhs.dim);
+
+                Arc::new(move |lhs, rhs| {
+                    let (lhs, rhs) = broadcast_ranks(lhs, lhs_dim, rhs, rhs_dim);
+                    quote! { #lhs.powi(#rhs) }
+                })
+            }
+            (Type::Tensor(_), Type::Scalar(_)) => {
+                Arc::new(move |lhs, rhs| quote! { #lhs.powi_scalar(#rhs) })
+            }
             _ => panic!("pow is supported for tensor only"),
         };
-        Self::new(lhs, rhs, output, BinaryType::Powi, Arc::new(function))
+        Self::new(lhs, rhs, output, BinaryType::Powi, function)
+    }
+
+    /// Remainder of the division of the operands, with the sign of the dividend when `fmod` is
+    /// set, like the C `fmod`, or with the sign of the divisor otherwise, like the Python `%`.
+    ///
+    /// Burn has no remainder between two tensors, so it's computed from the quotient, which is
+    /// truncated towards zero by the division of int tensors.
+    pub(crate) fn modulo(lhs: Type, rhs: Type, output: Type, fmod: bool) -> Self {
+        let (kind, lhs_dim, rhs_dim) = match (&lhs, &rhs) {
+            (Type::Tensor(lhs), Type::Tensor(rhs)) => (lhs.kind, lhs.dim, Some(rhs.dim)),
+            (Type::Tensor(lhs), Type::Scalar(_)) => (lhs.kind, lhs.dim, None),
+            _ => panic!("mod is supported for tensor only"),
+        };
+        let function = move |lhs, rhs| {
+            let (div, mul, add) = match rhs_dim {
+                Some(_) => (quote! { div }, quote! { mul }, quote! { add }),
+                None => (
+                    quote! { div_scalar },
+                    quote! { mul_scalar },
+                    quote! { add_scalar },
+                ),
+            };
+            let (lhs, rhs) = match rhs_dim {
+                Some(rhs_dim) => broadcast_ranks(lhs, lhs_dim, rhs, rhs_dim),
+                None => (lhs, rhs),
+            };
+
+            match (kind, fmod) {
+                (TensorKind::Float, _) => {
+                    let quotient = if fmod {
+                        quote! { quotient.clone().sign().mul(quotient.abs().floor()) }
+                    } else {
+                        quote! { quotient.floor() }
+                    };
+
+                    quote! {
+                        {
+                            let (lhs, rhs) = (#lhs, #rhs);
+                            let quotient = lhs.clone().#div(rhs.clone());
+                            lhs.sub(#quotient.#mul(rhs))
+                        }
+                    }
+                }
+                (TensorKind::Int, true) => quote! {
+                    {
+                        let (lhs, rhs) = (#lhs, #rhs);
+                        lhs.clone().sub(lhs.#div(rhs.clone()).#mul(rhs))
+                    }
+                },
+                (TensorKind::Int, false) => quote! {
+                    {
+                        let (lhs, rhs) = (#lhs, #rhs);
+                        let remainder = lhs.clone().sub(lhs.#div(rhs.clone()).#mul(rhs.clone()));
+                        let opposite = remainder.clone().#mul(rhs.clone()).lower_elem(0);
+                        remainder.clone().mask_where(opposite, remainder.#add(rhs))
+                    }
+                },
+                (TensorKind::Bool, _) => panic!("mod is supported for int and float tensors only"),
+            }
+        };
+
+        Self::new(lhs, rhs, output, BinaryType::Mod, Arc::new(function))
     }
 
     pub(crate) fn min_pair(lhs: Type, rhs: Type, output: Type) -> Self {
@@ -247,6 +389,64 @@ impl BinaryNode {
             Arc::new(function),
         )
     }
+
+    pub(crate) fn bitwise_and(lhs: Type, rhs: Type, output: Type) -> Self {
+        Self::bitwise(lhs, rhs, output, BinaryType::BitwiseAnd)
+    }
+
+    pub(crate) fn bitwise_or(lhs: Type, rhs: Type, output: Type) -> Self {
+        Self::bitwise(lhs, rhs, output, BinaryType::BitwiseOr)
+    }
+
+    pub(crate) fn bitwise_xor(lhs: Type, rhs: Type, output: Type) -> Self {
+        Self::bitwise(lhs, rhs, output, BinaryType::BitwiseXor)
+    }
+
+    pub(crate) fn bitwise_left_shift(lhs: Type, rhs: Type, output: Type) -> Self {
+        Self::bitwise(lhs, rhs, output, BinaryType::BitShiftLeft)
+    }
+
+    pub(crate) fn bitwise_right_shift(lhs: Type, rhs: Type, output: Type) -> Self {
+        Self::bitwise(lhs, rhs, output, BinaryType::BitShiftRight)
+    }
+
+    /// Bitwise operators on int tensors, the operand of lower rank being broadcast.
+    fn bitwise(lhs: Type, rhs: Type, output: Type, binary_type: BinaryType) -> Self {
+        let (lhs_dim, rhs_dim) = match (&lhs, &rhs) {
+            (Type::Tensor(lhs), Type::Tensor(rhs)) => (lhs.dim, rhs.dim),
+            _ => panic!("{} is supported for tensor only", binary_type.as_str()),
+        };
+        let method = binary_type.as_str().to_string();
+
+        let function = move |lhs, rhs| {
+            let method = Ident::new(&method, Span::call_site());
+            let (lhs, rhs) = broadcast_ranks(lhs, lhs_dim, rhs, rhs_dim);
+
+            quote! { #lhs.#method(#rhs) }
+        };
+
+        Self::new(lhs, rhs, output, binary_type, Arc::new(function))
+    }
+}
+
+/// Broadcast the operand of lower rank by adding leading dimensions of size one.
+fn broadcast_ranks(
+    lhs: TokenStream,
+    lhs_dim: usize,
+    rhs: TokenStream,
+    rhs_dim: usize,
+) -> (TokenStream, TokenStream) {
+    let dim = lhs_dim.max(rhs_dim);
+    let broadcast = |tensor: TokenStream, tensor_dim: usize| {
+        if tensor_dim < dim {
+            let dim = Literal::usize_unsuffixed(dim);
+            quote! { #tensor.unsqueeze::<#dim>() }
+        } else {
+            tensor
+        }
+    };
+
+    (broadcast(lhs, lhs_dim), broadcast(rhs, rhs_dim))
 }
 
 #[cfg(test)]
@@ -328,6 +528,27 @@ mod tests {
         test_binary_operator_on_tensors!(add);
     }
 
+    #[test]
+    fn test_binary_codegen_add_inplace_same_input() {
+        // The input is consumed twice by the node shadowing it, so it's cloned once.
+        one_node_graph(
+            BinaryNode::add(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor1 = tensor1.clone().add(tensor1);
+
+                    tensor1
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor1".to_string()],
+        );
+    }
+
     #[test]
     fn test_binary_codegen_add_scalar() {
         test_binary_operator_on_tensor_and_scalar!(add, add_scalar);
@@ -490,4 +711,161 @@ mod tests {
     fn test_binary_codegen_equal_scalars() {
         test_binary_operator_on_scalar_and_scalar!(equal, ==);
     }
+
+    fn int_graph(node_gen: BinaryNode, forward: TokenStream) {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(node_gen);
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+            vec!["tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                #forward
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_binary_codegen_bitwise_and() {
+        int_graph(
+            BinaryNode::bitwise_and(
+                Type::Tensor(TensorType::new_int("tensor1", 2)),
+                Type::Tensor(TensorType::new_int("tensor2", 2)),
+                Type::Tensor(TensorType::new_int("tensor3", 2)),
+            ),
+            quote! {
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 2, Int>,
+                    tensor2: Tensor<B, 2, Int>
+                ) -> Tensor<B, 2, Int> {
+                    let tensor3 = tensor1.bitwise_and(tensor2);
+
+                    tensor3
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_binary_codegen_bitwise_left_shift_broadcast() {
+        int_graph(
+            BinaryNode::bitwise_left_shift(
+                Type::Tensor(TensorType::new_int("tensor1", 3)),
+                Type::Tensor(TensorType::new_int("tensor2", 1)),
+                Type::Tensor(TensorType::new_int("tensor3", 3)),
+            ),
+            quote! {
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 3, Int>,
+                    tensor2: Tensor<B, 1, Int>
+                ) -> Tensor<B, 3, Int> {
+                    let tensor3 = tensor1.bitwise_left_shift(tensor2.unsqueeze::<3>());
+
+                    tensor3
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_binary_codegen_mod_int() {
+        int_graph(
+            BinaryNode::modulo(
+                Type::Tensor(TensorType::new_int("tensor1", 2)),
+                Type::Tensor(TensorType::new_int("tensor2", 1)),
+                Type::Tensor(TensorType::new_int("tensor3", 2)),
+                false,
+            ),
+            quote! {
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 2, Int>,
+                    tensor2: Tensor<B, 1, Int>
+                ) -> Tensor<B, 2, Int> {
+                    let tensor3 = {
+                        let (lhs, rhs) = (tensor1, tensor2.unsqueeze::<2>());
+                        let remainder = lhs.clone().sub(lhs.div(rhs.clone()).mul(rhs.clone()));
+                        let opposite = remainder.clone().mul(rhs.clone()).lower_elem(0);
+                        remainder.clone().mask_where(opposite, remainder.add(rhs))
+                    };
+
+                    tensor3
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_binary_codegen_fmod_float() {
+        one_node_graph(
+            BinaryNode::modulo(
+                Type::Tensor(TensorType::new_float("tensor1", 2)),
+                Type::Tensor(TensorType::new_float("tensor2", 2)),
+                Type::Tensor(TensorType::new_float("tensor3", 2)),
+                true,
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 2>, tensor2: Tensor<B, 2>) -> Tensor<B, 2> {
+                    let tensor3 = {
+                        let (lhs, rhs) = (tensor1, tensor2);
+                        let quotient = lhs.clone().div(rhs.clone());
+                        lhs.sub(quotient.clone().sign().mul(quotient.abs().floor()).mul(rhs))
+                    };
+
+                    tensor3
+                }
+            },
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+            vec!["tensor3".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_binary_codegen_powf_broadcast() {
+        one_node_graph(
+            BinaryNode::powf(
+                Type::Tensor(TensorType::new_float("tensor1", 3)),
+                Type::Tensor(TensorType::new_float("tensor2", 1)),
+                Type::Tensor(TensorType::new_float("tensor3", 3)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 3>, tensor2: Tensor<B, 1>) -> Tensor<B, 3> {
+                    let tensor3 = tensor1.powf(tensor2.unsqueeze::<3>());
+
+                    tensor3
+                }
+            },
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+            vec!["tensor3".to_string()],
+        );
+    }
 }
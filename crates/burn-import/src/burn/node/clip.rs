@@ -1,5 +1,5 @@
 use super::{Node, NodeCodegen};
-use crate::burn::{Scope, TensorType, Type};
+use crate::burn::{Scope, ScopeError, TensorType, Type};
 use burn::record::PrecisionSettings;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -21,11 +21,11 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for ClipNode {
         vec![Type::Tensor(self.input.clone())]
     }
 
-    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
-        let input = scope.tensor_use_owned(&self.input, node_position);
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
+        let input = scope.tensor_use_owned(&self.input, node_position)?;
         let output = &self.output.name;
 
-        if let Some(min) = self.min {
+        Ok(if let Some(min) = self.min {
             if let Some(max) = self.max {
                 quote! {
                     let #output = #input.clamp(#min, #max);
@@ -36,12 +36,15 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for ClipNode {
                 }
             }
         } else if let Some(max) = self.max {
-            return quote! {
+            quote! {
                 let #output = #input.clamp_max(#max);
-            };
+            }
         } else {
-            panic!("Clip node must have at least one min or max value");
-        }
+            // Without bounds, the input is passed through
+            quote! {
+                let #output = #input;
+            }
+        })
     }
 
     fn into_node(self) -> Node<PS> {
@@ -190,4 +193,49 @@ mod tests {
 
         assert_tokens(graph.codegen(), expected);
     }
+
+    #[test]
+    fn codegen_nodes_passthrough() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ClipNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 4),
+            None,
+            None,
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1;
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
 }
@@ -1,5 +1,5 @@
 use super::{Node, NodeCodegen};
-use crate::burn::{Scope, TensorType, ToTokens, Type};
+use crate::burn::{Scope, ScopeError, TensorType, ToTokens, Type};
 
 use burn::record::PrecisionSettings;
 use proc_macro2::TokenStream;
@@ -24,18 +24,19 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for ConcatNode {
             .collect()
     }
 
-    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
         let dim = self.dim.to_tokens();
         let inputs = self
             .inputs
             .iter()
-            .map(|t| scope.tensor_use_owned(t, node_position));
+            .map(|t| scope.tensor_use_owned(t, node_position))
+            .collect::<Result<Vec<_>, _>>()?;
 
         let output = &self.output.name;
 
-        quote! {
+        Ok(quote! {
             let #output = burn::tensor::Tensor::cat([#(#inputs),*].into(), #dim);
-        }
+        })
     }
 
     fn into_node(self) -> Node<PS> {
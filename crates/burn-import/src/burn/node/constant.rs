@@ -1,5 +1,7 @@
 use super::{Node, NodeCodegen};
-use crate::burn::{ScalarKind, ScalarType, Scope, TensorType, ToTokens, Type};
+use crate::burn::{
+    BurnImports, ScalarKind, ScalarType, Scope, ScopeError, TensorKind, TensorType, ToTokens, Type,
+};
 use burn::{
     module::ParamId,
     record::{ParamSerde, PrecisionSettings},
@@ -128,19 +130,36 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for ConstantNode<PS> {
                 let name = Ident::new(self.name.as_ref(), Span::call_site());
                 let shape = tensor_type.clone().shape.unwrap().to_tokens();
 
-                Some(quote! {
-                    let #name: burn::module::Param<#ty> = burn::nn::Initializer::Zeros.init(#shape, device).set_require_grad(false);
-                })
+                // The initializer only creates float tensors.
+                match tensor_type.kind {
+                    TensorKind::Int => {
+                        let dim = tensor_type.dim.to_tokens();
+
+                        Some(quote! {
+                            let #name: burn::module::Param<#ty> = burn::module::Param::initialized(
+                                burn::module::ParamId::new(),
+                                Tensor::<B, #dim, Int>::zeros(#shape, device),
+                            );
+                        })
+                    }
+                    _ => Some(quote! {
+                        let #name: burn::module::Param<#ty> = burn::nn::Initializer::Zeros.init(#shape, device).set_require_grad(false);
+                    }),
+                }
             }
             _ => None,
         }
     }
 
-    fn forward(&self, _scope: &mut Scope, _node_position: usize) -> TokenStream {
+    fn forward(
+        &self,
+        _scope: &mut Scope,
+        _node_position: usize,
+    ) -> Result<TokenStream, ScopeError> {
         let name = Ident::new(self.name.as_ref(), Span::call_site());
         let output = self.output.name();
 
-        match &self.value {
+        Ok(match &self.value {
             ConstantValue::Tensor(_, _) => {
                 quote! {
                     let #output = self.#name.val();
@@ -154,6 +173,19 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for ConstantNode<PS> {
                     let #output: #ty = #val;
                 }
             }
+        })
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        if let ConstantValue::Tensor(
+            TensorType {
+                kind: TensorKind::Int,
+                ..
+            },
+            _,
+        ) = &self.value
+        {
+            imports.register("burn::tensor::Int");
         }
     }
 
@@ -163,16 +195,76 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for ConstantNode<PS> {
 
     fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         if let ConstantValue::Tensor(_, ds) = &self.value {
-            let data: DataSerialize<PS::FloatElem> = match ds {
-                TensorValue::Float(data) => data.clone().convert(),
-                TensorValue::Int(data) => data.clone().convert(),
+            // Int tensors are recorded with the int element, to be loaded in an int parameter.
+            let id = ParamId::new().into_string();
+            return match ds {
+                TensorValue::Float(data) => ParamSerde::new(id, data.clone()).serialize(serializer),
+                TensorValue::Int(data) => ParamSerde::new(id, data.clone()).serialize(serializer),
             };
-            let data = ParamSerde::new(ParamId::new().into_string(), data);
-            return data.serialize(serializer);
         }
 
         S::serialize_none(serializer)
     }
 }
 
-// TODO add test missing for constant node (@antimora 8/2/2023)
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens};
+    use burn::{record::FullPrecisionSettings, tensor::Data};
+
+    #[test]
+    fn test_codegen_int_tensor() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ConstantNode::new(
+            "constant1".to_string(),
+            ConstantValue::Tensor(
+                TensorType::new("constant1", 1, TensorKind::Int, Some(vec![3])),
+                TensorValue::Int(Data::from([1, 2, 3]).serialize()),
+            ),
+            Type::Tensor(TensorType::new_int("output", 1)),
+        ));
+
+        graph.register_input_output(vec![], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                constant1: burn::module::Param<Tensor<B, 1, Int>>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let constant1: burn::module::Param<Tensor<B, 1, Int>> = burn::module::Param::initialized(
+                        burn::module::ParamId::new(),
+                        Tensor::<B, 1, Int>::zeros([3], device),
+                    );
+
+                    Self {
+                        constant1,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self,) -> Tensor<B, 1, Int> {
+                    let output = self.constant1.val();
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -1,5 +1,5 @@
 use super::{Node, NodeCodegen, SerializationBackend};
-use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
+use crate::burn::{BurnImports, OtherType, Scope, ScopeError, TensorType, ToTokens, Type};
 use burn::{
     module::{ConstantRecord, Param, ParamId},
     nn::conv::{Conv1dConfig, Conv1dRecord},
@@ -104,14 +104,14 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for Conv1dNode<PS> {
         item.serialize(serializer)
     }
 
-    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
-        let input = scope.tensor_use_owned(&self.input, node_position);
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
+        let input = scope.tensor_use_owned(&self.input, node_position)?;
         let output = &self.output.name;
         let field = &self.field.name;
 
-        quote! {
+        Ok(quote! {
             let #output = self.#field.forward(#input);
-        }
+        })
     }
     fn register_imports(&self, imports: &mut BurnImports) {
         imports.register("burn::nn::PaddingConfig1d");
@@ -1,5 +1,5 @@
 use super::{Node, NodeCodegen, SerializationBackend};
-use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
+use crate::burn::{BurnImports, OtherType, Scope, ScopeError, TensorType, ToTokens, Type};
 use burn::{
     module::{ConstantRecord, Param, ParamId},
     nn::conv::{ConvTranspose2dConfig, ConvTranspose2dRecord},
@@ -64,12 +64,14 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for ConvTranspose2dNode<PS> {
         let dilation = self.config.dilation.to_tokens();
         let groups = self.config.groups.to_tokens();
         let padding = self.config.padding.to_tokens();
+        let padding_out = self.config.padding_out.to_tokens();
         let bias = self.config.bias;
 
         let tokens = quote! {
             let #name = ConvTranspose2dConfig::new(#channels, #kernel_size)
                 .with_stride(#stride)
                 .with_padding(#padding)
+                .with_padding_out(#padding_out)
                 .with_dilation(#dilation)
                 .with_groups(#groups)
                 .with_bias(#bias)
@@ -104,14 +106,14 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for ConvTranspose2dNode<PS> {
         item.serialize(serializer)
     }
 
-    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
-        let input = scope.tensor_use_owned(&self.input, node_position);
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
+        let input = scope.tensor_use_owned(&self.input, node_position)?;
         let output = &self.output.name;
         let field = &self.field.name;
 
-        quote! {
+        Ok(quote! {
             let #output = self.#field.forward(#input);
-        }
+        })
     }
     fn register_imports(&self, imports: &mut BurnImports) {
         imports.register("burn::nn::conv::ConvTranspose2d");
@@ -169,6 +171,7 @@ mod tests {
                     let conv_transpose_2d = ConvTranspose2dConfig::new([3, 3], [3, 3])
                         .with_stride([1, 1])
                         .with_padding([0, 0])
+                        .with_padding_out([0, 0])
                         .with_dilation([1, 1])
                         .with_groups(1)
                         .with_bias(true)
@@ -0,0 +1,165 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{BurnImports, Scope, ScopeError, TensorKind, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// The cumulative sum of the input along an axis.
+///
+/// The exclusive sum of an element leaves the element out, the first sum being zero, and the
+/// reverse sum is computed from the end of the axis.
+#[derive(Debug, Clone, new)]
+pub struct CumSumNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub axis: usize,
+    pub exclusive: bool,
+    pub reverse: bool,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for CumSumNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
+        let input = scope.tensor_use_owned(&self.input, node_position)?;
+        let output = &self.output.name;
+        let axis = self.axis.to_tokens();
+        let dim = self.input.dim.to_tokens();
+
+        let (input, flip) = if self.reverse {
+            (quote! { #input.flip([#axis]) }, quote! { .flip([#axis]) })
+        } else {
+            (input, quote! {})
+        };
+
+        // The sum of the first element, and the element added to the sum at each index
+        let (first, previous) = if self.exclusive {
+            let zeros = match self.input.kind {
+                TensorKind::Int => quote! { Tensor::<B, #dim, Int>::zeros },
+                TensorKind::Float => quote! { Tensor::<B, #dim>::zeros },
+                TensorKind::Bool => panic!("CumSum: bool input is not supported"),
+            };
+            (
+                quote! {
+                    let mut shape = input.dims();
+                    shape[#axis] = 1;
+                    let mut sum = #zeros(shape, &input.device());
+                },
+                quote! { index - 1..index },
+            )
+        } else {
+            (
+                quote! {
+                    ranges[#axis] = 0..1;
+                    let mut sum = input.clone().slice(ranges.clone());
+                },
+                quote! { index..index + 1 },
+            )
+        };
+
+        Ok(quote! {
+            let #output = {
+                let input = #input;
+                let mut ranges = input.dims().map(|dim| 0..dim);
+                let len = ranges[#axis].end;
+                #first
+                let mut sums = Vec::with_capacity(len);
+                sums.push(sum.clone());
+
+                for index in 1..len {
+                    ranges[#axis] = #previous;
+                    sum = sum + input.clone().slice(ranges.clone());
+                    sums.push(sum.clone());
+                }
+
+                Tensor::cat(sums, #axis)#flip
+            };
+        })
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("alloc::vec::Vec");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::CumSum(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+
+    #[test]
+    fn test_codegen_cum_sum_exclusive_reverse() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(CumSumNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_float("tensor2", 2),
+            1,
+            true,
+            true,
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use alloc::vec::Vec;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>) -> Tensor<B, 2> {
+                    let tensor2 = {
+                        let input = tensor1.flip([1]);
+                        let mut ranges = input.dims().map(|dim| 0..dim);
+                        let len = ranges[1].end;
+                        let mut shape = input.dims();
+                        shape[1] = 1;
+                        let mut sum = Tensor::<B, 2>::zeros(shape, &input.device());
+                        let mut sums = Vec::with_capacity(len);
+                        sums.push(sum.clone());
+
+                        for index in 1..len {
+                            ranges[1] = index - 1..index;
+                            sum = sum + input.clone().slice(ranges.clone());
+                            sums.push(sum.clone());
+                        }
+
+                        Tensor::cat(sums, 1).flip([1])
+                    };
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
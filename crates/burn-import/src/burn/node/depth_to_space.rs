@@ -0,0 +1,162 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, ScopeError, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Order of the channels rearranged by the ONNX `DepthToSpace` operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DepthToSpaceMode {
+    /// Depth-column-row order (`mode=DCR`), the blocks being the outer dimension of the channels.
+    Dcr,
+    /// Column-row-depth order (`mode=CRD`), the blocks being the inner dimension of the channels,
+    /// as in PyTorch's `pixel_shuffle`.
+    Crd,
+}
+
+/// Rearrange blocks of channels into spatial blocks of `block_size x block_size` pixels.
+#[derive(Debug, Clone, new)]
+pub struct DepthToSpaceNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub block_size: usize,
+    pub mode: DepthToSpaceMode,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for DepthToSpaceNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
+        let input = scope.tensor_use_owned(&self.input, node_position)?;
+        let output = &self.output.name;
+        let block_size = self.block_size.to_tokens();
+        let num_blocks = (self.block_size * self.block_size).to_tokens();
+
+        let (blocks, permutation) = match self.mode {
+            DepthToSpaceMode::Dcr => (
+                quote! { #block_size, #block_size, channels / #num_blocks },
+                quote! { [0, 3, 4, 1, 5, 2] },
+            ),
+            DepthToSpaceMode::Crd => (
+                quote! { channels / #num_blocks, #block_size, #block_size },
+                quote! { [0, 1, 4, 2, 5, 3] },
+            ),
+        };
+
+        Ok(quote! {
+            let #output = {
+                let input = #input;
+                let [batch_size, channels, height, width] = input.dims();
+
+                input
+                    .reshape([batch_size, #blocks, height, width])
+                    .permute(#permutation)
+                    .reshape([
+                        batch_size,
+                        channels / #num_blocks,
+                        height * #block_size,
+                        width * #block_size,
+                    ])
+            };
+        })
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::DepthToSpace(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+
+    fn depth_to_space_forward(mode: DepthToSpaceMode) -> TokenStream {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(DepthToSpaceNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 4),
+            2,
+            mode,
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        graph.codegen()
+    }
+
+    fn expected(blocks: TokenStream, permutation: TokenStream) -> TokenStream {
+        quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = {
+                        let input = tensor1;
+                        let [batch_size, channels, height, width] = input.dims();
+
+                        input
+                            .reshape([batch_size, #blocks, height, width])
+                            .permute(#permutation)
+                            .reshape([
+                                batch_size,
+                                channels / 4,
+                                height * 2,
+                                width * 2,
+                            ])
+                    };
+
+                    tensor2
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_codegen_depth_to_space_dcr() {
+        assert_tokens(
+            depth_to_space_forward(DepthToSpaceMode::Dcr),
+            expected(
+                quote! { 2, 2, channels / 4 },
+                quote! { [0, 3, 4, 1, 5, 2] },
+            ),
+        );
+    }
+
+    #[test]
+    fn test_codegen_depth_to_space_crd() {
+        assert_tokens(
+            depth_to_space_forward(DepthToSpaceMode::Crd),
+            expected(
+                quote! { channels / 4, 2, 2 },
+                quote! { [0, 1, 4, 2, 5, 3] },
+            ),
+        );
+    }
+}
@@ -0,0 +1,199 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, ScopeError, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Operation of the ONNX `Einsum` operator, only the equations that map to a permutation or a
+/// (batched) matrix product are supported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EinsumOp {
+    /// Permute the dimensions of the single input, e.g. `...ij->...ji`.
+    Permute(Vec<usize>),
+    /// Matrix product over the last two dimensions, with the leading dimensions as batch
+    /// dimensions, e.g. `bik,bjk->bij`.
+    ///
+    /// Each flag transposes the last two dimensions of the corresponding operand or output.
+    Matmul {
+        transpose_lhs: bool,
+        transpose_rhs: bool,
+        transpose_output: bool,
+    },
+}
+
+#[derive(Debug, Clone, new)]
+pub struct EinsumNode {
+    pub inputs: Vec<TensorType>,
+    pub output: TensorType,
+    pub op: EinsumOp,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for EinsumNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        self.inputs
+            .iter()
+            .map(|input| Type::Tensor(input.clone()))
+            .collect()
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
+        let inputs: Vec<_> = self
+            .inputs
+            .iter()
+            .map(|input| scope.tensor_use_owned(input, node_position))
+            .collect::<Result<_, _>>()?;
+        let output = &self.output.name;
+
+        let transpose = |tensor: TokenStream, transpose: bool| match transpose {
+            true => quote! { #tensor.transpose() },
+            false => tensor,
+        };
+
+        let value = match &self.op {
+            EinsumOp::Permute(perm) => {
+                let input = &inputs[0];
+                let perm = perm.to_tokens();
+
+                quote! { #input.permute(#perm) }
+            }
+            EinsumOp::Matmul {
+                transpose_lhs,
+                transpose_rhs,
+                transpose_output,
+            } => {
+                let lhs = transpose(inputs[0].clone(), *transpose_lhs);
+                let rhs = transpose(inputs[1].clone(), *transpose_rhs);
+
+                transpose(quote! { #lhs.matmul(#rhs) }, *transpose_output)
+            }
+        };
+
+        Ok(quote! {
+            let #output = #value;
+        })
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Einsum(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{einsum::EinsumNode, test::assert_tokens},
+        TensorType,
+    };
+
+    /// Generate the model of a single Einsum node with the given inputs and forward pass.
+    fn codegen_einsum(inputs: &[&str], op: EinsumOp) -> TokenStream {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(EinsumNode::new(
+            inputs
+                .iter()
+                .map(|name| TensorType::new_float(*name, 3))
+                .collect(),
+            TensorType::new_float("output", 3),
+            op,
+        ));
+
+        graph.register_input_output(
+            inputs.iter().map(|name| name.to_string()).collect(),
+            vec!["output".to_string()],
+        );
+
+        graph.codegen()
+    }
+
+    fn expected_model(forward: TokenStream) -> TokenStream {
+        quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                #forward
+            }
+        }
+    }
+
+    #[test]
+    fn test_codegen_einsum_batched_matmul_transposed_rhs() {
+        // bik,bjk->bij
+        let op = EinsumOp::Matmul {
+            transpose_lhs: false,
+            transpose_rhs: true,
+            transpose_output: false,
+        };
+
+        let expected = expected_model(quote! {
+            pub fn forward(&self, lhs: Tensor<B, 3>, rhs: Tensor<B, 3>) -> Tensor<B, 3> {
+                let output = lhs.matmul(rhs.transpose());
+
+                output
+            }
+        });
+
+        assert_tokens(codegen_einsum(&["lhs", "rhs"], op), expected);
+    }
+
+    #[test]
+    fn test_codegen_einsum_batched_matmul_transposed_output() {
+        // bki,bjk->bji
+        let op = EinsumOp::Matmul {
+            transpose_lhs: true,
+            transpose_rhs: false,
+            transpose_output: true,
+        };
+
+        let expected = expected_model(quote! {
+            pub fn forward(&self, lhs: Tensor<B, 3>, rhs: Tensor<B, 3>) -> Tensor<B, 3> {
+                let output = lhs.transpose().matmul(rhs).transpose();
+
+                output
+            }
+        });
+
+        assert_tokens(codegen_einsum(&["lhs", "rhs"], op), expected);
+    }
+
+    #[test]
+    fn test_codegen_einsum_permute() {
+        // ...ij->...ji
+        let op = EinsumOp::Permute(vec![0, 2, 1]);
+
+        let expected = expected_model(quote! {
+            pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+                let output = input.permute([0, 2, 1]);
+
+                output
+            }
+        });
+
+        assert_tokens(codegen_einsum(&["input"], op), expected);
+    }
+}
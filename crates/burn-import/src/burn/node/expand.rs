@@ -1,5 +1,5 @@
 use super::{Node, NodeCodegen};
-use crate::burn::{Scope, TensorType, ToTokens, Type};
+use crate::burn::{Scope, ScopeError, TensorType, ToTokens, Type};
 use burn::record::PrecisionSettings;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -8,6 +8,7 @@ use quote::quote;
 pub struct ExpandNode {
     pub input: TensorType,
     pub output: TensorType,
+    /// Broadcast arguments of `expand`, where -1 keeps the size of the input dimension.
     pub shape: Vec<i64>,
 }
 
@@ -20,14 +21,14 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for ExpandNode {
         vec![Type::Tensor(self.input.clone())]
     }
 
-    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
-        let input = scope.tensor_use_owned(&self.input, node_position);
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
+        let input = scope.tensor_use_owned(&self.input, node_position)?;
         let shape = &self.shape.to_tokens();
         let output = &self.output.name;
 
-        quote! {
+        Ok(quote! {
             let #output = #input.expand(#shape);
-        }
+        })
     }
 
     fn into_node(self) -> Node<PS> {
@@ -1,13 +1,26 @@
 use super::{Node, NodeCodegen};
-use crate::burn::{TensorType, ToTokens, Type};
+use crate::burn::{BurnImports, ScalarType, TensorType, ToTokens, Type};
 
 use burn::record::PrecisionSettings;
 use quote::quote;
 
+/// Indices of the ONNX `Gather` operator.
+#[derive(Debug, Clone)]
+pub enum GatherIndices {
+    /// Indices given at runtime by a 1D int tensor.
+    Tensor(TensorType),
+    /// Index given at runtime by an int scalar, the gathered dimension is removed.
+    Scalar(ScalarType),
+    /// Constant indices.
+    Constant(Vec<i64>),
+    /// Constant scalar index, the gathered dimension is removed.
+    ConstantScalar(i64),
+}
+
 #[derive(Debug, Clone, new)]
 pub struct GatherNode {
     pub input: TensorType,
-    pub index: TensorType,
+    pub indices: GatherIndices,
     pub output: TensorType,
     pub dim: usize,
 }
@@ -18,24 +31,61 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for GatherNode {
     }
 
     fn input_types(&self) -> Vec<crate::burn::Type> {
-        vec![
-            Type::Tensor(self.input.clone()),
-            Type::Tensor(self.index.clone()),
-        ]
+        match &self.indices {
+            GatherIndices::Tensor(index) => vec![
+                Type::Tensor(self.input.clone()),
+                Type::Tensor(index.clone()),
+            ],
+            GatherIndices::Scalar(index) => vec![
+                Type::Tensor(self.input.clone()),
+                Type::Scalar(index.clone()),
+            ],
+            _ => vec![Type::Tensor(self.input.clone())],
+        }
     }
 
     fn forward(
         &self,
         scope: &mut crate::burn::Scope,
         node_position: usize,
-    ) -> proc_macro2::TokenStream {
+    ) -> Result<proc_macro2::TokenStream, crate::burn::ScopeError> {
         let dim = self.dim.to_tokens();
-        let input = scope.tensor_use_owned(&self.input, node_position);
-        let index = scope.tensor_use_owned(&self.index, node_position);
+        let input = scope.tensor_use_owned(&self.input, node_position)?;
         let output = &self.output.name;
 
-        quote! {
-            let #output = #input.select(#dim, #index);
+        let index = match &self.indices {
+            GatherIndices::Tensor(index) => scope.tensor_use_owned(index, node_position)?,
+            GatherIndices::Scalar(index) => {
+                let name = &index.name;
+                quote! { Tensor::<B, 1, Int>::from_ints([#name as i32], &*self.device) }
+            }
+            GatherIndices::Constant(indices) => {
+                let indices = indices.iter().map(|&index| index as i32);
+                quote! { Tensor::<B, 1, Int>::from_ints([#(#indices),*], &*self.device) }
+            }
+            GatherIndices::ConstantScalar(index) => {
+                let index = *index as i32;
+                quote! { Tensor::<B, 1, Int>::from_ints([#index], &*self.device) }
+            }
+        };
+
+        Ok(match &self.indices {
+            GatherIndices::Scalar(_) | GatherIndices::ConstantScalar(_) => {
+                let output_dim = self.output.dim.to_tokens();
+
+                quote! {
+                    let #output = #input.select(#dim, #index).squeeze::<#output_dim>(#dim);
+                }
+            }
+            _ => quote! {
+                let #output = #input.select(#dim, #index);
+            },
+        })
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        if !matches!(self.indices, GatherIndices::Tensor(_)) {
+            imports.register("burn::tensor::Int");
         }
     }
 
@@ -62,7 +112,7 @@ mod tests {
 
         graph.register(GatherNode::new(
             TensorType::new_float("tensor1", 2),
-            TensorType::new_int("tensor2", 1),
+            GatherIndices::Tensor(TensorType::new_int("tensor2", 1)),
             TensorType::new_float("tensor3", 2),
             0,
         ));
@@ -109,4 +159,53 @@ mod tests {
 
         assert_tokens(graph.codegen(), expected);
     }
+
+    #[test]
+    fn test_codegen_gather_constant_scalar() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(GatherNode::new(
+            TensorType::new_float("tensor1", 3),
+            GatherIndices::ConstantScalar(1),
+            TensorType::new_float("tensor2", 2),
+            2,
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 3>) -> Tensor<B, 2> {
+                    let tensor2 = tensor1
+                        .select(2, Tensor::<B, 1, Int>::from_ints([1i32], &*self.device))
+                        .squeeze::<2>(2);
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
 }
@@ -28,15 +28,15 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for GatherElementsNode {
         &self,
         scope: &mut crate::burn::Scope,
         node_position: usize,
-    ) -> proc_macro2::TokenStream {
+    ) -> Result<proc_macro2::TokenStream, crate::burn::ScopeError> {
         let dim = self.dim.to_tokens();
-        let input = scope.tensor_use_owned(&self.input, node_position);
-        let index = scope.tensor_use_owned(&self.index, node_position);
+        let input = scope.tensor_use_owned(&self.input, node_position)?;
+        let index = scope.tensor_use_owned(&self.index, node_position)?;
         let output = &self.output.name;
 
-        quote! {
+        Ok(quote! {
             let #output = #input.gather(#dim, #index);
-        }
+        })
     }
 
     fn into_node(self) -> super::Node<PS> {
@@ -0,0 +1,167 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, ScopeError, TensorType, ToTokens, Type};
+
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Gather the slices of the data selected by the index tuples of the last axis of the indices.
+///
+/// The data is flattened to rows of the slices selected by the index tuples, so the output is
+/// made of the rows at the linear offsets of the tuples. Negative indices aren't supported.
+#[derive(Debug, Clone, new)]
+pub struct GatherNDNode {
+    pub data: TensorType,
+    pub indices: TensorType,
+    pub output: TensorType,
+    /// Length of the index tuples, i.e. the size of the last axis of the indices.
+    pub tuple_size: usize,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for GatherNDNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![
+            Type::Tensor(self.data.clone()),
+            Type::Tensor(self.indices.clone()),
+        ]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
+        let batch = (self.indices.dim - 1).to_tokens();
+        let data_name = &self.data.name;
+        let indices_name = &self.indices.name;
+        let data = scope.tensor_use_owned(&self.data, node_position)?;
+        let indices = scope.tensor_use_owned(&self.indices, node_position)?;
+        let output = &self.output.name;
+        let output_dim = self.output.dim.to_tokens();
+
+        // Linear offset of the index tuples, computed with the Horner scheme.
+        let offsets = (1..self.tuple_size).map(|axis| {
+            let index = axis.to_tokens();
+            let end = (axis + 1).to_tokens();
+
+            quote! {
+                let offsets = offsets
+                    .mul_scalar(shape[#index] as i64)
+                    .add(tuples.clone().slice([0..count, #index..#end]));
+            }
+        });
+        let tuple_size = self.tuple_size.to_tokens();
+
+        Ok(quote! {
+            let #output = {
+                let shape = #data_name.dims();
+                let indices_shape = #indices_name.dims();
+                let rows = shape[..#tuple_size].iter().product::<usize>();
+                let row_size = shape[#tuple_size..].iter().product::<usize>();
+                let count = indices_shape[..#batch].iter().product::<usize>();
+
+                let tuples = #indices.reshape([count, #tuple_size]);
+                let offsets = tuples.clone().slice([0..count, 0..1]);
+                #(#offsets)*
+                let offsets = offsets.reshape([count]);
+
+                // The batch axes of the indices followed by the axes of the gathered slices
+                let mut output_shape = [0; #output_dim];
+                output_shape[..#batch].copy_from_slice(&indices_shape[..#batch]);
+                output_shape[#batch..].copy_from_slice(&shape[#tuple_size..]);
+
+                #data
+                    .reshape([rows, row_size])
+                    .select(0, offsets)
+                    .reshape(output_shape)
+            };
+        })
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::GatherND(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+
+    #[test]
+    fn test_codegen_gather_nd() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(GatherNDNode::new(
+            TensorType::new_float("tensor1", 3),
+            TensorType::new_int("tensor2", 2),
+            TensorType::new_float("tensor3", 2),
+            2,
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+            vec!["tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 3>,
+                    tensor2: Tensor<B, 2, Int>
+                ) -> Tensor<B, 2> {
+                    let tensor3 = {
+                        let shape = tensor1.dims();
+                        let indices_shape = tensor2.dims();
+                        let rows = shape[..2].iter().product::<usize>();
+                        let row_size = shape[2..].iter().product::<usize>();
+                        let count = indices_shape[..1].iter().product::<usize>();
+
+                        let tuples = tensor2.reshape([count, 2]);
+                        let offsets = tuples.clone().slice([0..count, 0..1]);
+                        let offsets = offsets
+                            .mul_scalar(shape[1] as i64)
+                            .add(tuples.clone().slice([0..count, 1..2]));
+                        let offsets = offsets.reshape([count]);
+
+                        let mut output_shape = [0; 2];
+                        output_shape[..1].copy_from_slice(&indices_shape[..1]);
+                        output_shape[1..].copy_from_slice(&shape[2..]);
+
+                        tensor1
+                            .reshape([rows, row_size])
+                            .select(0, offsets)
+                            .reshape(output_shape)
+                    };
+
+                    tensor3
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
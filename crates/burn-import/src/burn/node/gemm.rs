@@ -0,0 +1,192 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, ScopeError, TensorType, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// General matrix multiplication `alpha * A' * B' + beta * C`, where `A'` and `B'` are the
+/// inputs transposed or not depending on the flags.
+///
+/// Gemm nodes with constant weights and bias are imported as [linear](super::linear) layers
+/// instead, this node computing the products of runtime inputs.
+#[allow(clippy::too_many_arguments)]
+#[derive(Debug, Clone, new)]
+pub struct GemmNode {
+    pub a: TensorType,
+    pub b: TensorType,
+    pub c: Option<TensorType>,
+    pub output: TensorType,
+    pub alpha: f32,
+    pub beta: f32,
+    pub trans_a: bool,
+    pub trans_b: bool,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for GemmNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        let mut inputs = vec![Type::Tensor(self.a.clone()), Type::Tensor(self.b.clone())];
+        inputs.extend(self.c.clone().map(Type::Tensor));
+
+        inputs
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
+        let output = &self.output.name;
+        let matrix = |tensor: TokenStream, transpose: bool| {
+            if transpose {
+                quote! { #tensor.transpose() }
+            } else {
+                tensor
+            }
+        };
+        let scale = |tensor: TokenStream, factor: f32| {
+            if factor == 1.0 {
+                tensor
+            } else {
+                quote! { #tensor.mul_scalar(#factor) }
+            }
+        };
+
+        let a = matrix(
+            scope.tensor_use_owned(&self.a, node_position)?,
+            self.trans_a,
+        );
+        let b = matrix(
+            scope.tensor_use_owned(&self.b, node_position)?,
+            self.trans_b,
+        );
+        let product = scale(quote! { #a.matmul(#b) }, self.alpha);
+
+        // C is broadcast to the [M, N] output, a vector being a row
+        let result = match &self.c {
+            Some(c) => {
+                let tensor = scope.tensor_use_owned(c, node_position)?;
+                let tensor = if c.dim == 1 {
+                    quote! { #tensor.unsqueeze::<2>() }
+                } else {
+                    tensor
+                };
+                let bias = scale(tensor, self.beta);
+
+                quote! { #product.add(#bias) }
+            }
+            None => product,
+        };
+
+        Ok(quote! {
+            let #output = #result;
+        })
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Gemm(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+
+    fn expected(inputs: TokenStream, body: TokenStream) -> TokenStream {
+        quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, #inputs) -> Tensor<B, 2> {
+                    #body
+
+                    output
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_codegen_gemm() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(GemmNode::new(
+            TensorType::new_float("a", 2),
+            TensorType::new_float("b", 2),
+            Some(TensorType::new_float("c", 1)),
+            TensorType::new_float("output", 2),
+            0.5,
+            2.0,
+            false,
+            true,
+        ));
+
+        graph.register_input_output(
+            vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            vec!["output".to_string()],
+        );
+
+        assert_tokens(
+            graph.codegen(),
+            expected(
+                quote! { a: Tensor<B, 2>, b: Tensor<B, 2>, c: Tensor<B, 1> },
+                quote! {
+                    let output = a
+                        .matmul(b.transpose())
+                        .mul_scalar(0.5f32)
+                        .add(c.unsqueeze::<2>().mul_scalar(2f32));
+                },
+            ),
+        );
+    }
+
+    #[test]
+    fn test_codegen_gemm_without_c() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(GemmNode::new(
+            TensorType::new_float("a", 2),
+            TensorType::new_float("b", 2),
+            None,
+            TensorType::new_float("output", 2),
+            1.0,
+            3.0,
+            true,
+            false,
+        ));
+
+        graph.register_input_output(
+            vec!["a".to_string(), "b".to_string()],
+            vec!["output".to_string()],
+        );
+
+        assert_tokens(
+            graph.codegen(),
+            expected(
+                quote! { a: Tensor<B, 2>, b: Tensor<B, 2> },
+                quote! {
+                    let output = a.transpose().matmul(b);
+                },
+            ),
+        );
+    }
+}
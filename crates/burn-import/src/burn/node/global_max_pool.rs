@@ -0,0 +1,98 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use burn::record::PrecisionSettings;
+
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, ScopeError, TensorType, ToTokens, Type};
+
+/// GlobalMaxPoolNode is a node that performs a global max pooling operation.
+///
+/// The node reduces each spatial dimension with `max_dim`, which keeps the reduced dimensions
+/// with a size of 1, so the output has the same rank as the input.
+#[derive(Debug, Clone, new)]
+pub struct GlobalMaxPoolNode {
+    pub input: TensorType,
+    pub output: TensorType,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for GlobalMaxPoolNode {
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
+        if self.input.dim < 3 {
+            panic!(
+                "Unsupported input dim ({}) for GlobalMaxPoolNode",
+                self.input.dim
+            );
+        }
+
+        let input = scope.tensor_use_owned(&self.input, node_position)?;
+        let output = &self.output.name;
+        // The dimensions after the batch and the channels are spatial.
+        let dims = (2..self.input.dim).map(|dim| dim.to_tokens());
+
+        Ok(quote! {
+            let #output = #input #(.max_dim(#dims))*;
+        })
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::GlobalMaxPool(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+    use burn::record::FullPrecisionSettings;
+
+    #[test]
+    fn test_codegen_2d() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(GlobalMaxPoolNode::new(
+            TensorType::new_float("input", 4),
+            TensorType::new_float("output", 4),
+        ));
+
+        graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let output = input.max_dim(2).max_dim(3);
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,252 @@
+use super::{Node, NodeCodegen, SerializationBackend};
+use crate::burn::{BurnImports, OtherType, Scope, ScopeError, TensorType, ToTokens, Type};
+use burn::{
+    module::{ConstantRecord, Param, ParamId},
+    nn::{GroupNormConfig, GroupNormRecord, InstanceNormRecord},
+    record::{PrecisionSettings, Record},
+    tensor::{DataSerialize, Tensor},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde::Serialize;
+
+/// Group normalization, emitted as an `InstanceNorm` module when each channel is its own group.
+#[derive(Debug, Clone)]
+pub struct GroupNormNode<PS: PrecisionSettings> {
+    pub field: OtherType,
+    pub input: TensorType,
+    pub output: TensorType,
+    pub gamma: DataSerialize<PS::FloatElem>, // Scale
+    pub beta: DataSerialize<PS::FloatElem>,  // Bias (B)
+    pub config: GroupNormConfig,
+}
+
+impl<PS: PrecisionSettings> GroupNormNode<PS> {
+    pub fn new<S: AsRef<str>>(
+        name: S,
+        input: TensorType,
+        output: TensorType,
+        gamma: DataSerialize<PS::FloatElem>,
+        beta: DataSerialize<PS::FloatElem>,
+        config: GroupNormConfig,
+    ) -> Self {
+        let ty = if is_instance_norm(&config) {
+            quote! { InstanceNorm<B> }
+        } else {
+            quote! { GroupNorm<B> }
+        };
+
+        Self {
+            field: OtherType::new(name, ty),
+            input,
+            output,
+            gamma,
+            beta,
+            config,
+        }
+    }
+
+    fn group_norm_record(&self) -> GroupNormRecord<SerializationBackend> {
+        let device = Default::default();
+
+        GroupNormRecord {
+            num_groups: ConstantRecord::new(),
+            num_channels: ConstantRecord::new(),
+            gamma: Some(Param::initialized(
+                ParamId::new(),
+                Tensor::from_data(self.gamma.clone().convert(), &device),
+            )),
+            beta: Some(Param::initialized(
+                ParamId::new(),
+                Tensor::from_data(self.beta.clone().convert(), &device),
+            )),
+            epsilon: ConstantRecord::new(),
+            affine: ConstantRecord::new(),
+        }
+    }
+}
+
+/// Group norm with one channel per group is instance norm.
+///
+/// A single group isn't routed to `LayerNorm`, which only normalizes over the last dimension.
+fn is_instance_norm(config: &GroupNormConfig) -> bool {
+    config.num_groups == config.num_channels
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for GroupNormNode<PS> {
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Other(self.field.clone()))
+    }
+
+    fn field_init(&self) -> Option<TokenStream> {
+        let name = &self.field.name;
+        let num_groups = self.config.num_groups.to_tokens();
+        let num_channels = self.config.num_channels.to_tokens();
+        let epsilon = self.config.epsilon;
+
+        let tokens = if is_instance_norm(&self.config) {
+            quote! {
+                let #name = InstanceNormConfig::new(#num_channels)
+                    .with_epsilon(#epsilon)
+                    .init(device);
+            }
+        } else {
+            quote! {
+                let #name = GroupNormConfig::new(#num_groups, #num_channels)
+                    .with_epsilon(#epsilon)
+                    .init(device);
+            }
+        };
+
+        Some(tokens)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let group_norm = self.group_norm_record();
+
+        if is_instance_norm(&self.config) {
+            let record = InstanceNormRecord::<SerializationBackend> { group_norm };
+
+            Record::into_item::<PS>(record).serialize(serializer)
+        } else {
+            Record::into_item::<PS>(group_norm).serialize(serializer)
+        }
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
+        let input = scope.tensor_use_owned(&self.input, node_position)?;
+        let output = &self.output.name;
+        let field = &self.field.name;
+
+        Ok(quote! {
+            let #output = self.#field.forward(#input);
+        })
+    }
+    fn register_imports(&self, imports: &mut BurnImports) {
+        if is_instance_norm(&self.config) {
+            imports.register("burn::nn::InstanceNorm");
+            imports.register("burn::nn::InstanceNormConfig");
+        } else {
+            imports.register("burn::nn::GroupNorm");
+            imports.register("burn::nn::GroupNormConfig");
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::GroupNorm(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+    use burn::{record::FullPrecisionSettings, tensor::Data};
+
+    fn group_norm_graph(num_groups: usize) -> BurnGraph<FullPrecisionSettings> {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(GroupNormNode::new(
+            "norm",
+            TensorType::new_float("input", 4),
+            TensorType::new_float("output", 4),
+            Data::from([2., 2., 2., 2.]).serialize(),
+            Data::from([1., 1., 1., 1.]).serialize(),
+            GroupNormConfig::new(num_groups, 4),
+        ));
+
+        graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
+
+        graph
+    }
+
+    #[test]
+    fn test_codegen_group_norm() {
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::GroupNorm;
+            use burn::nn::GroupNormConfig;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                norm: GroupNorm<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let norm = GroupNormConfig::new(2, 4)
+                        .with_epsilon(0.00001f64)
+                        .init(device);
+
+                    Self {
+                        norm,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let output = self.norm.forward(input);
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(group_norm_graph(2).codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_group_norm_one_channel_per_group() {
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::InstanceNorm;
+            use burn::nn::InstanceNormConfig;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                norm: InstanceNorm<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let norm = InstanceNormConfig::new(4)
+                        .with_epsilon(0.00001f64)
+                        .init(device);
+
+                    Self {
+                        norm,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let output = self.norm.forward(input);
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(group_norm_graph(4).codegen(), expected);
+    }
+}
@@ -0,0 +1,354 @@
+use super::{lstm::gate_record, lstm::LstmGateData, Node, NodeCodegen, SerializationBackend};
+use crate::burn::{BurnImports, OtherType, Scope, ScopeError, TensorType, ToTokens, Type};
+use burn::{
+    module::ConstantRecord,
+    nn::gru::{GruConfig, GruRecord},
+    record::{PrecisionSettings, Record},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde::Serialize;
+
+/// Direction of the ONNX `GRU` operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GruDirection {
+    Forward,
+    Reverse,
+}
+
+/// Weights of the gates of a GRU, with the same layout as the LSTM gates.
+#[derive(Debug, Clone, new)]
+pub struct GruGatesData<E> {
+    pub update_gate: LstmGateData<E>,
+    pub reset_gate: LstmGateData<E>,
+    pub new_gate: LstmGateData<E>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GruNode<PS: PrecisionSettings> {
+    pub field: OtherType,
+    pub input: TensorType,
+    /// The initial hidden state.
+    pub initial_hidden: Option<TensorType>,
+    pub output: Option<TensorType>,
+    pub output_hidden: Option<TensorType>,
+    pub config: GruConfig,
+    pub direction: GruDirection,
+    pub data: GruGatesData<PS::FloatElem>,
+}
+
+impl<PS: PrecisionSettings> GruNode<PS> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<S: AsRef<str>>(
+        name: S,
+        input: TensorType,
+        initial_hidden: Option<TensorType>,
+        output: Option<TensorType>,
+        output_hidden: Option<TensorType>,
+        config: GruConfig,
+        direction: GruDirection,
+        data: GruGatesData<PS::FloatElem>,
+    ) -> Self {
+        Self {
+            field: OtherType::new(name, quote! { Gru<B> }),
+            input,
+            initial_hidden,
+            output,
+            output_hidden,
+            config,
+            direction,
+            data,
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for GruNode<PS> {
+    fn input_types(&self) -> Vec<Type> {
+        let mut types = vec![Type::Tensor(self.input.clone())];
+
+        if let Some(hidden) = &self.initial_hidden {
+            types.push(Type::Tensor(hidden.clone()));
+        }
+
+        types
+    }
+
+    fn output_types(&self) -> Vec<Type> {
+        [&self.output, &self.output_hidden]
+            .into_iter()
+            .flatten()
+            .map(|output| Type::Tensor(output.clone()))
+            .collect()
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Other(self.field.clone()))
+    }
+
+    fn field_init(&self) -> Option<TokenStream> {
+        let name = &self.field.name;
+        let d_input = self.config.d_input.to_tokens();
+        let d_hidden = self.config.d_hidden.to_tokens();
+        let bias = self.config.bias;
+        let reset_after = self.config.reset_after;
+
+        let tokens = quote! {
+            let #name = GruConfig::new(#d_input, #d_hidden, #bias)
+                .with_reset_after(#reset_after)
+                .init(device);
+        };
+
+        Some(tokens)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let record = GruRecord::<SerializationBackend> {
+            update_gate: gate_record::<PS>(&self.data.update_gate),
+            reset_gate: gate_record::<PS>(&self.data.reset_gate),
+            new_gate: gate_record::<PS>(&self.data.new_gate),
+            d_hidden: ConstantRecord::new(),
+            reset_after: ConstantRecord::new(),
+        };
+
+        Record::into_item::<PS>(record).serialize(serializer)
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
+        let input = scope.tensor_use_owned(&self.input, node_position)?;
+        let field = &self.field.name;
+
+        // The ONNX initial state has the shape [num_directions, batch_size, hidden_size].
+        let state = match &self.initial_hidden {
+            Some(hidden) => {
+                let hidden = scope.tensor_use_owned(hidden, node_position)?;
+                quote! { Some(#hidden.squeeze::<2>(0)) }
+            }
+            None => quote! { None },
+        };
+
+        // The ONNX input has the shape [seq_length, batch_size, input_size] while Burn expects
+        // the batch first.
+        let gru_forward = match self.direction {
+            GruDirection::Forward => quote! {
+                let input = #input.swap_dims(0, 1);
+                let output = self.#field.forward(input, #state);
+            },
+            GruDirection::Reverse => quote! {
+                let input = #input.swap_dims(0, 1).flip([1]);
+                let output = self.#field.forward(input, #state).flip([1]);
+            },
+        };
+
+        // The last hidden state is the one of the last processed time step.
+        let hidden = self.output_hidden.as_ref().map(|_| {
+            let output = match self.output {
+                Some(_) => quote! { output.clone() },
+                None => quote! { output },
+            };
+            let step = match self.direction {
+                GruDirection::Forward => quote! { output.dims()[1] - 1 },
+                GruDirection::Reverse => quote! { 0 },
+            };
+
+            quote! {
+                let hidden = #output.narrow(1, #step, 1).swap_dims(0, 1);
+            }
+        });
+
+        // The ONNX output has the shape [seq_length, num_directions, batch_size, hidden_size].
+        let (names, values): (Vec<_>, Vec<_>) = [
+            (
+                &self.output,
+                quote! { output.swap_dims(0, 1).unsqueeze_dim::<4>(1) },
+            ),
+            (&self.output_hidden, quote! { hidden }),
+        ]
+        .into_iter()
+        .filter_map(|(tensor, value)| tensor.as_ref().map(|tensor| (&tensor.name, value)))
+        .unzip();
+
+        Ok(if names.len() == 1 {
+            let name = names[0];
+            let value = &values[0];
+
+            quote! {
+                let #name = {
+                    #gru_forward
+                    #hidden
+
+                    #value
+                };
+            }
+        } else {
+            quote! {
+                let (#(#names),*) = {
+                    #gru_forward
+                    #hidden
+
+                    (#(#values),*)
+                };
+            }
+        })
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::nn::gru::Gru");
+        imports.register("burn::nn::gru::GruConfig");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Gru(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+    use burn::{record::FullPrecisionSettings, tensor::Data};
+
+    fn gates_data(d_input: usize, d_hidden: usize) -> GruGatesData<f32> {
+        let gate = || {
+            LstmGateData::new(
+                Data::<f32, 2>::zeros([d_input, d_hidden]).serialize(),
+                None,
+                Data::<f32, 2>::zeros([d_hidden, d_hidden]).serialize(),
+                None,
+            )
+        };
+
+        GruGatesData::new(gate(), gate(), gate())
+    }
+
+    #[test]
+    fn test_codegen_forward() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(GruNode::new(
+            "gru",
+            TensorType::new_float("input", 3),
+            None,
+            Some(TensorType::new_float("output", 4)),
+            Some(TensorType::new_float("hidden", 3)),
+            GruConfig::new(4, 8, false).with_reset_after(true),
+            GruDirection::Forward,
+            gates_data(4, 8),
+        ));
+
+        graph.register_input_output(
+            vec!["input".to_string()],
+            vec!["output".to_string(), "hidden".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::gru::Gru;
+            use burn::nn::gru::GruConfig;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                gru: Gru<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let gru = GruConfig::new(4, 8, false)
+                        .with_reset_after(true)
+                        .init(device);
+
+                    Self {
+                        gru,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 3>) -> (Tensor<B, 4>, Tensor<B, 3>) {
+                    let (output, hidden) = {
+                        let input = input.swap_dims(0, 1);
+                        let output = self.gru.forward(input, None);
+                        let hidden = output.clone().narrow(1, output.dims()[1] - 1, 1).swap_dims(0, 1);
+
+                        (output.swap_dims(0, 1).unsqueeze_dim::<4>(1), hidden)
+                    };
+
+                    (output, hidden)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_reverse_with_initial_state() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(GruNode::new(
+            "gru",
+            TensorType::new_float("input", 3),
+            Some(TensorType::new_float("initial_h", 3)),
+            None,
+            Some(TensorType::new_float("hidden", 3)),
+            GruConfig::new(4, 8, true),
+            GruDirection::Reverse,
+            gates_data(4, 8),
+        ));
+
+        graph.register_input_output(
+            vec!["input".to_string(), "initial_h".to_string()],
+            vec!["hidden".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::gru::Gru;
+            use burn::nn::gru::GruConfig;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                gru: Gru<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let gru = GruConfig::new(4, 8, true)
+                        .with_reset_after(false)
+                        .init(device);
+
+                    Self {
+                        gru,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 3>, initial_h: Tensor<B, 3>) -> Tensor<B, 3> {
+                    let hidden = {
+                        let input = input.swap_dims(0, 1).flip([1]);
+                        let output = self.gru.forward(input, Some(initial_h.squeeze::<2>(0))).flip([1]);
+                        let hidden = output.narrow(1, 0, 1).swap_dims(0, 1);
+
+                        hidden
+                    };
+
+                    hidden
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
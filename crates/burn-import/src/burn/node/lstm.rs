@@ -0,0 +1,450 @@
+use super::{Node, NodeCodegen, SerializationBackend};
+use crate::burn::{BurnImports, OtherType, Scope, ScopeError, TensorType, ToTokens, Type};
+use burn::{
+    module::{ConstantRecord, Param, ParamId},
+    nn::{BiLstmRecord, GateControllerRecord, LinearRecord, LstmConfig, LstmRecord},
+    record::{PrecisionSettings, Record},
+    tensor::{DataSerialize, Tensor},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde::Serialize;
+
+/// Direction of the ONNX `LSTM` operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LstmDirection {
+    Forward,
+    Reverse,
+    Bidirectional,
+}
+
+/// Weights of an LSTM gate, with the linear weights in the Burn `[d_input, d_output]` layout.
+#[derive(Debug, Clone, new)]
+pub struct LstmGateData<E> {
+    pub input_weight: DataSerialize<E>,
+    pub input_bias: Option<DataSerialize<E>>,
+    pub hidden_weight: DataSerialize<E>,
+    pub hidden_bias: Option<DataSerialize<E>>,
+}
+
+/// Weights of the gates of one LSTM direction.
+#[derive(Debug, Clone, new)]
+pub struct LstmGatesData<E> {
+    pub input_gate: LstmGateData<E>,
+    pub forget_gate: LstmGateData<E>,
+    pub output_gate: LstmGateData<E>,
+    pub cell_gate: LstmGateData<E>,
+}
+
+#[derive(Debug, Clone)]
+pub struct LstmNode<PS: PrecisionSettings> {
+    pub field: OtherType,
+    pub input: TensorType,
+    /// The initial hidden and cell states.
+    pub initial_state: Option<(TensorType, TensorType)>,
+    pub output: Option<TensorType>,
+    pub output_hidden: Option<TensorType>,
+    pub output_cell: Option<TensorType>,
+    pub config: LstmConfig,
+    pub direction: LstmDirection,
+    /// The weights of each direction, the reverse direction comes last.
+    pub data: Vec<LstmGatesData<PS::FloatElem>>,
+}
+
+impl<PS: PrecisionSettings> LstmNode<PS> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<S: AsRef<str>>(
+        name: S,
+        input: TensorType,
+        initial_state: Option<(TensorType, TensorType)>,
+        output: Option<TensorType>,
+        output_hidden: Option<TensorType>,
+        output_cell: Option<TensorType>,
+        config: LstmConfig,
+        direction: LstmDirection,
+        data: Vec<LstmGatesData<PS::FloatElem>>,
+    ) -> Self {
+        let ty = match direction {
+            LstmDirection::Bidirectional => quote! { BiLstm<B> },
+            _ => quote! { Lstm<B> },
+        };
+
+        Self {
+            field: OtherType::new(name, ty),
+            input,
+            initial_state,
+            output,
+            output_hidden,
+            output_cell,
+            config,
+            direction,
+            data,
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for LstmNode<PS> {
+    fn input_types(&self) -> Vec<Type> {
+        let mut types = vec![Type::Tensor(self.input.clone())];
+
+        if let Some((hidden, cell)) = &self.initial_state {
+            types.push(Type::Tensor(hidden.clone()));
+            types.push(Type::Tensor(cell.clone()));
+        }
+
+        types
+    }
+
+    fn output_types(&self) -> Vec<Type> {
+        [&self.output, &self.output_hidden, &self.output_cell]
+            .into_iter()
+            .flatten()
+            .map(|output| Type::Tensor(output.clone()))
+            .collect()
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Other(self.field.clone()))
+    }
+
+    fn field_init(&self) -> Option<TokenStream> {
+        let name = &self.field.name;
+        let d_input = self.config.d_input.to_tokens();
+        let d_hidden = self.config.d_hidden.to_tokens();
+        let bias = self.config.bias;
+
+        let config = match self.direction {
+            LstmDirection::Bidirectional => quote! { BiLstmConfig },
+            _ => quote! { LstmConfig },
+        };
+
+        let tokens = quote! {
+            let #name = #config::new(#d_input, #d_hidden, #bias)
+                .init(device);
+        };
+
+        Some(tokens)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.direction {
+            LstmDirection::Bidirectional => {
+                let record = BiLstmRecord::<SerializationBackend> {
+                    forward: lstm_record::<PS>(&self.data[0]),
+                    reverse: lstm_record::<PS>(&self.data[1]),
+                    d_hidden: ConstantRecord::new(),
+                };
+
+                Record::into_item::<PS>(record).serialize(serializer)
+            }
+            _ => Record::into_item::<PS>(lstm_record::<PS>(&self.data[0])).serialize(serializer),
+        }
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
+        let input = scope.tensor_use_owned(&self.input, node_position)?;
+        let field = &self.field.name;
+
+        // ONNX states have the shape [num_directions, batch_size, hidden_size].
+        let state = match &self.initial_state {
+            Some((hidden, cell)) => {
+                let hidden = scope.tensor_use_owned(hidden, node_position)?;
+                let cell = scope.tensor_use_owned(cell, node_position)?;
+
+                match self.direction {
+                    LstmDirection::Bidirectional => quote! { Some(LstmState::new(#cell, #hidden)) },
+                    _ => quote! {
+                        Some(LstmState::new(#cell.squeeze::<2>(0), #hidden.squeeze::<2>(0)))
+                    },
+                }
+            }
+            None => quote! { None },
+        };
+
+        // The ONNX input has the shape [seq_length, batch_size, input_size] while Burn expects
+        // the batch first.
+        let lstm_forward = match self.direction {
+            LstmDirection::Forward | LstmDirection::Bidirectional => quote! {
+                let input = #input.swap_dims(0, 1);
+                let (output, state) = self.#field.forward(input, #state);
+            },
+            LstmDirection::Reverse => quote! {
+                let input = #input.swap_dims(0, 1).flip([1]);
+                let (output, state) = self.#field.forward(input, #state);
+                let output = output.flip([1]);
+            },
+        };
+
+        // The ONNX output has the shape [seq_length, num_directions, batch_size, hidden_size].
+        let (output, hidden, cell) = match self.direction {
+            LstmDirection::Bidirectional => (
+                quote! { output.reshape([0, 0, 2, -1]).permute([1, 2, 0, 3]) },
+                quote! { state.hidden },
+                quote! { state.cell },
+            ),
+            _ => (
+                quote! { output.swap_dims(0, 1).unsqueeze_dim::<4>(1) },
+                quote! { state.hidden.unsqueeze_dim::<3>(0) },
+                quote! { state.cell.unsqueeze_dim::<3>(0) },
+            ),
+        };
+
+        let (names, values): (Vec<_>, Vec<_>) = [
+            (&self.output, output),
+            (&self.output_hidden, hidden),
+            (&self.output_cell, cell),
+        ]
+        .into_iter()
+        .filter_map(|(tensor, value)| tensor.as_ref().map(|tensor| (&tensor.name, value)))
+        .unzip();
+
+        Ok(if names.len() == 1 {
+            let name = names[0];
+            let value = &values[0];
+
+            quote! {
+                let #name = {
+                    #lstm_forward
+
+                    #value
+                };
+            }
+        } else {
+            quote! {
+                let (#(#names),*) = {
+                    #lstm_forward
+
+                    (#(#values),*)
+                };
+            }
+        })
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        match self.direction {
+            LstmDirection::Bidirectional => {
+                imports.register("burn::nn::BiLstm");
+                imports.register("burn::nn::BiLstmConfig");
+            }
+            _ => {
+                imports.register("burn::nn::Lstm");
+                imports.register("burn::nn::LstmConfig");
+            }
+        }
+
+        if self.initial_state.is_some() {
+            imports.register("burn::nn::LstmState");
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Lstm(self)
+    }
+}
+
+fn lstm_record<PS: PrecisionSettings>(
+    data: &LstmGatesData<PS::FloatElem>,
+) -> LstmRecord<SerializationBackend> {
+    LstmRecord {
+        input_gate: gate_record::<PS>(&data.input_gate),
+        forget_gate: gate_record::<PS>(&data.forget_gate),
+        output_gate: gate_record::<PS>(&data.output_gate),
+        cell_gate: gate_record::<PS>(&data.cell_gate),
+        d_hidden: ConstantRecord::new(),
+    }
+}
+
+pub(crate) fn gate_record<PS: PrecisionSettings>(
+    data: &LstmGateData<PS::FloatElem>,
+) -> GateControllerRecord<SerializationBackend> {
+    GateControllerRecord {
+        input_transform: linear_record::<PS>(&data.input_weight, data.input_bias.as_ref()),
+        hidden_transform: linear_record::<PS>(&data.hidden_weight, data.hidden_bias.as_ref()),
+    }
+}
+
+fn linear_record<PS: PrecisionSettings>(
+    weight: &DataSerialize<PS::FloatElem>,
+    bias: Option<&DataSerialize<PS::FloatElem>>,
+) -> LinearRecord<SerializationBackend> {
+    let device = Default::default();
+
+    LinearRecord {
+        weight: Param::initialized(
+            ParamId::new(),
+            Tensor::from_data(weight.clone().convert(), &device),
+        ),
+        bias: bias.map(|bias| {
+            Param::initialized(
+                ParamId::new(),
+                Tensor::from_data(bias.clone().convert(), &device),
+            )
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+    use burn::{record::FullPrecisionSettings, tensor::Data};
+
+    fn gates_data(d_input: usize, d_hidden: usize) -> LstmGatesData<f32> {
+        let gate = || {
+            LstmGateData::new(
+                Data::<f32, 2>::zeros([d_input, d_hidden]).serialize(),
+                None,
+                Data::<f32, 2>::zeros([d_hidden, d_hidden]).serialize(),
+                None,
+            )
+        };
+
+        LstmGatesData::new(gate(), gate(), gate(), gate())
+    }
+
+    #[test]
+    fn test_codegen_forward() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(LstmNode::new(
+            "lstm",
+            TensorType::new_float("input", 3),
+            None,
+            Some(TensorType::new_float("output", 4)),
+            Some(TensorType::new_float("hidden", 3)),
+            None,
+            LstmConfig::new(4, 8, false),
+            LstmDirection::Forward,
+            vec![gates_data(4, 8)],
+        ));
+
+        graph.register_input_output(
+            vec!["input".to_string()],
+            vec!["output".to_string(), "hidden".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::Lstm;
+            use burn::nn::LstmConfig;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                lstm: Lstm<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let lstm = LstmConfig::new(4, 8, false)
+                        .init(device);
+
+                    Self {
+                        lstm,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 3>) -> (Tensor<B, 4>, Tensor<B, 3>) {
+                    let (output, hidden) = {
+                        let input = input.swap_dims(0, 1);
+                        let (output, state) = self.lstm.forward(input, None);
+
+                        (
+                            output.swap_dims(0, 1).unsqueeze_dim::<4>(1),
+                            state.hidden.unsqueeze_dim::<3>(0)
+                        )
+                    };
+
+                    (output, hidden)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_bidirectional_with_initial_state() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(LstmNode::new(
+            "lstm",
+            TensorType::new_float("input", 3),
+            Some((
+                TensorType::new_float("hidden", 3),
+                TensorType::new_float("cell", 3),
+            )),
+            Some(TensorType::new_float("output", 4)),
+            None,
+            None,
+            LstmConfig::new(4, 8, true),
+            LstmDirection::Bidirectional,
+            vec![gates_data(4, 8), gates_data(4, 8)],
+        ));
+
+        graph.register_input_output(
+            vec![
+                "input".to_string(),
+                "hidden".to_string(),
+                "cell".to_string(),
+            ],
+            vec!["output".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::BiLstm;
+            use burn::nn::BiLstmConfig;
+            use burn::nn::LstmState;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                lstm: BiLstm<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let lstm = BiLstmConfig::new(4, 8, true)
+                        .init(device);
+
+                    Self {
+                        lstm,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    input: Tensor<B, 3>,
+                    hidden: Tensor<B, 3>,
+                    cell: Tensor<B, 3>
+                ) -> Tensor<B, 4> {
+                    let output = {
+                        let input = input.swap_dims(0, 1);
+                        let (output, state) = self.lstm.forward(input, Some(LstmState::new(cell, hidden)));
+
+                        output.reshape([0, 0, 2, -1]).permute([1, 2, 0, 3])
+                    };
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -1,15 +1,19 @@
-use core::cmp::max;
-
 use super::{Node, NodeCodegen};
 use crate::burn::{BurnImports, TensorType, ToTokens, Type};
 
 use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
 use quote::quote;
 
+/// WhereNode selects the values of two tensors depending on a condition, following the numpy
+/// broadcasting rules for the three inputs.
+///
+/// The inputs of lower rank are unsqueezed to the output rank, and when the output shape is known,
+/// they are also expanded to it so their dimensions of size 1 are broadcast.
 #[derive(Debug, Clone, new)]
 pub struct WhereNode {
-    /// Bool tensor. When True (nonzero), yield X, otherwise yield Y.
-    pub condition: TensorType,
+    /// Bool tensor or scalar. When True (nonzero), yield X, otherwise yield Y.
+    pub condition: Type,
     /// Values selected at indices where condition is True.
     pub x: TensorType,
     /// Values selected at indices where condition is False.
@@ -17,6 +21,24 @@ pub struct WhereNode {
     pub output: TensorType,
 }
 
+impl WhereNode {
+    /// Broadcast the tensor to the rank and the shape of the output.
+    fn broadcast(&self, tensor: &TensorType, mut tokens: TokenStream) -> TokenStream {
+        if tensor.dim < self.output.dim {
+            let dim = self.output.dim.to_tokens();
+            tokens = quote! { #tokens.unsqueeze::<#dim>() };
+        }
+
+        match &self.output.shape {
+            Some(shape) if tensor.shape.as_ref() != Some(shape) => {
+                let shape = shape.to_tokens();
+                quote! { #tokens.expand(#shape) }
+            }
+            _ => tokens,
+        }
+    }
+}
+
 impl<PS: PrecisionSettings> NodeCodegen<PS> for WhereNode {
     fn output_types(&self) -> Vec<Type> {
         vec![Type::Tensor(self.output.clone())]
@@ -24,7 +46,7 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for WhereNode {
 
     fn input_types(&self) -> Vec<crate::burn::Type> {
         vec![
-            Type::Tensor(self.condition.clone()),
+            self.condition.clone(),
             Type::Tensor(self.x.clone()),
             Type::Tensor(self.y.clone()),
         ]
@@ -34,35 +56,37 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for WhereNode {
         &self,
         scope: &mut crate::burn::Scope,
         node_position: usize,
-    ) -> proc_macro2::TokenStream {
-        let mut mask = scope.tensor_use_owned(&self.condition, node_position);
-        let mut x = scope.tensor_use_owned(&self.x, node_position);
-        let mut y = scope.tensor_use_owned(&self.y, node_position);
+    ) -> Result<proc_macro2::TokenStream, crate::burn::ScopeError> {
+        let x = scope.tensor_use_owned(&self.x, node_position)?;
+        let x = self.broadcast(&self.x, x);
+        let y = scope.tensor_use_owned(&self.y, node_position)?;
+        let y = self.broadcast(&self.y, y);
         let output = &self.output.name;
 
-        // x, y and condition need to be broadcastable
-        let broadcasted_dim = max(max(self.x.dim, self.y.dim), self.condition.dim);
-        let unsqueeze_dims = broadcasted_dim.to_tokens();
+        Ok(match &self.condition {
+            Type::Tensor(condition) => {
+                let mask = scope.tensor_use_owned(condition, node_position)?;
+                let mask = self.broadcast(condition, mask);
 
-        if self.condition.dim < broadcasted_dim {
-            mask = quote! { #mask.unsqueeze::<#unsqueeze_dims>()};
-        }
-
-        if self.x.dim < broadcasted_dim {
-            x = quote! { #x.unsqueeze::<#unsqueeze_dims>()};
-        }
-
-        if self.y.dim < broadcasted_dim {
-            y = quote! { #y.unsqueeze::<#unsqueeze_dims>()};
-        }
+                quote! {
+                    let #output = #y.mask_where(#mask, #x);
+                }
+            }
+            Type::Scalar(condition) => {
+                let condition = &condition.name;
 
-        quote! {
-            let #output = #y.mask_where(#mask, #x);
-        }
+                quote! {
+                    let #output = if #condition { #x } else { #y };
+                }
+            }
+            _ => panic!("Where: the condition must be a tensor or a scalar"),
+        })
     }
 
     fn register_imports(&self, imports: &mut BurnImports) {
-        imports.register("burn::tensor::Bool");
+        if let Type::Tensor(_) = &self.condition {
+            imports.register("burn::tensor::Bool");
+        }
     }
 
     fn into_node(self) -> super::Node<PS> {
@@ -79,7 +103,7 @@ mod tests {
     use crate::burn::{
         graph::BurnGraph,
         node::{mask_where::WhereNode, test::assert_tokens},
-        TensorType,
+        ScalarKind, ScalarType, TensorKind, TensorType,
     };
 
     #[test]
@@ -87,7 +111,7 @@ mod tests {
         let mut graph = BurnGraph::<FullPrecisionSettings>::default();
 
         graph.register(WhereNode::new(
-            TensorType::new_bool("tensor1", 2),
+            Type::Tensor(TensorType::new_bool("tensor1", 2)),
             TensorType::new_float("tensor2", 2),
             TensorType::new_float("tensor3", 2),
             TensorType::new_float("tensor4", 2),
@@ -146,7 +170,7 @@ mod tests {
         let mut graph = BurnGraph::<FullPrecisionSettings>::default();
 
         graph.register(WhereNode::new(
-            TensorType::new_bool("tensor1", 4),
+            Type::Tensor(TensorType::new_bool("tensor1", 4)),
             TensorType::new_float("tensor2", 2),
             TensorType::new_float("tensor3", 3),
             TensorType::new_float("tensor4", 4),
@@ -201,4 +225,67 @@ mod tests {
 
         assert_tokens(graph.codegen(), expected);
     }
+
+    #[test]
+    fn test_codegen_where_scalar_condition() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        // The dims of size 1 are expanded to the known output shape
+        graph.register(WhereNode::new(
+            Type::Scalar(ScalarType::new("scalar1", ScalarKind::Bool)),
+            TensorType::new("tensor1", 2, TensorKind::Float, Some(vec![2, 3])),
+            TensorType::new("tensor2", 1, TensorKind::Float, Some(vec![1])),
+            TensorType::new("tensor3", 2, TensorKind::Float, Some(vec![2, 3])),
+        ));
+
+        graph.register_input_output(
+            vec![
+                "scalar1".to_string(),
+                "tensor1".to_string(),
+                "tensor2".to_string(),
+            ],
+            vec!["tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    scalar1: bool,
+                    tensor1: Tensor<B, 2>,
+                    tensor2: Tensor<B, 1>
+                ) -> Tensor<B, 2> {
+                    let tensor3 = if scalar1 {
+                        tensor1
+                    } else {
+                        tensor2.unsqueeze::<2>().expand([2, 3])
+                    };
+
+                    tensor3
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
 }
@@ -1,7 +1,5 @@
-use core::cmp::Ordering;
-
 use super::{Node, NodeCodegen};
-use crate::burn::{Scope, TensorKind, TensorType, ToTokens, Type};
+use crate::burn::{Scope, ScopeError, TensorKind, TensorType, ToTokens, Type};
 use burn::record::PrecisionSettings;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -34,55 +32,60 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for MatmulNode {
         ]
     }
 
-    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
-        let lhs = scope.tensor_use_owned(&self.lhs, node_position);
-        let rhs = scope.tensor_use_owned(&self.rhs, node_position);
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
+        let lhs = scope.tensor_use_owned(&self.lhs, node_position)?;
+        let rhs = scope.tensor_use_owned(&self.rhs, node_position)?;
         let output = &self.output.name;
 
         let lhs_dim = self.lhs.dim;
         let rhs_dim = self.rhs.dim;
 
-        // Support broadcasting for missing dimensions
-        match lhs_dim.cmp(&rhs_dim) {
-            Ordering::Greater => {
-                // Alternate unsqueeze(0) -> unsqueeze(-1) -> unsqueeze(0) -> ...
-                let axes = (0..lhs_dim - rhs_dim)
-                    .map(|i| if i % 2 == 0 { 0 } else { -1 })
-                    .collect::<Vec<i64>>();
+        // Vector-vector product: the sum of the element-wise product
+        if lhs_dim == 1 && rhs_dim == 1 {
+            return Ok(quote! {
+                let #output = #lhs.mul(#rhs).sum();
+            });
+        }
+
+        // Both inputs are promoted to the same rank, the batch dimensions being broadcast.
+        let rank = lhs_dim.max(rhs_dim);
+        let unsqueeze = |input: TokenStream, axes: Vec<i64>| {
+            if axes.is_empty() {
+                input
+            } else {
                 let axes = axes.to_tokens();
+                quote! { #input.unsqueeze_dims(&#axes) }
+            }
+        };
 
-                if rhs_dim == 1 {
-                    // Matrix-vector product: squeeze(-1)
-                    let squeeze_dim = lhs_dim - 1;
-                    quote! {
-                        let #output = #lhs.matmul(#rhs.unsqueeze_dims(&#axes)).squeeze(#squeeze_dim);
-                    }
-                } else {
-                    quote! {
-                        let #output = #lhs.matmul(#rhs.unsqueeze_dims(&#axes));
-                    }
-                }
+        // Leading dimensions are prepended to the matrices, a vector lhs becomes a row [1, k]
+        // and a vector rhs becomes a column [k, 1].
+        let lhs = unsqueeze(lhs, vec![0; rank - lhs_dim]);
+        let rhs = if rhs_dim == 1 {
+            let mut axes = vec![0; rank - 2];
+            axes.push(-1);
+            unsqueeze(rhs, axes)
+        } else {
+            unsqueeze(rhs, vec![0; rank - rhs_dim])
+        };
+
+        Ok(if lhs_dim == 1 {
+            // Vector-matrix product: squeeze the added row
+            let squeeze_dim = rank - 2;
+            quote! {
+                let #output = #lhs.matmul(#rhs).squeeze(#squeeze_dim);
             }
-            Ordering::Less => {
-                // Always unsqueeze(0)
-                let axes = [0i64].repeat(rhs_dim - lhs_dim).to_tokens();
-
-                if lhs_dim == 1 {
-                    // Vector-matrix product: squeeze(-2)
-                    let squeeze_dim = rhs_dim - 2;
-                    quote! {
-                        let #output = #lhs.unsqueeze_dims(&#axes).matmul(#rhs).squeeze(#squeeze_dim);
-                    }
-                } else {
-                    quote! {
-                        let #output = #lhs.unsqueeze_dims(&#axes).matmul(#rhs);
-                    }
-                }
+        } else if rhs_dim == 1 {
+            // Matrix-vector product: squeeze the added column
+            let squeeze_dim = rank - 1;
+            quote! {
+                let #output = #lhs.matmul(#rhs).squeeze(#squeeze_dim);
             }
-            Ordering::Equal => quote! {
+        } else {
+            quote! {
                 let #output = #lhs.matmul(#rhs);
-            },
-        }
+            }
+        })
     }
 
     fn into_node(self) -> Node<PS> {
@@ -196,7 +199,7 @@ mod tests {
                     tensor1: Tensor<B, 4>,
                     tensor2: Tensor<B, 1>
                 ) -> Tensor<B, 3> {
-                    let tensor3 = tensor1.matmul(tensor2.unsqueeze_dims(&[0, -1, 0])).squeeze(3usize);
+                    let tensor3 = tensor1.matmul(tensor2.unsqueeze_dims(&[0, 0, -1])).squeeze(3usize);
 
                     tensor3
                 }
@@ -257,4 +260,160 @@ mod tests {
 
         assert_tokens(graph.codegen(), expected);
     }
+
+    #[test]
+    fn test_codegen_matmul_broadcast_matrix() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(MatmulNode::new(
+            TensorType::new_float("tensor1", 3),
+            TensorType::new_float("tensor2", 2),
+            TensorType::new_float("tensor3", 3),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+            vec!["tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 3>,
+                    tensor2: Tensor<B, 2>
+                ) -> Tensor<B, 3> {
+                    let tensor3 = tensor1.matmul(tensor2.unsqueeze_dims(&[0]));
+
+                    tensor3
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_matmul_vector_matrix_2d() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(MatmulNode::new(
+            TensorType::new_float("tensor1", 1),
+            TensorType::new_float("tensor2", 2),
+            TensorType::new_float("tensor3", 1),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+            vec!["tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 1>,
+                    tensor2: Tensor<B, 2>
+                ) -> Tensor<B, 1> {
+                    let tensor3 = tensor1.unsqueeze_dims(&[0]).matmul(tensor2).squeeze(0usize);
+
+                    tensor3
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_matmul_matrix_vector_2d() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(MatmulNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_float("tensor2", 1),
+            TensorType::new_float("tensor3", 1),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+            vec!["tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 2>,
+                    tensor2: Tensor<B, 1>
+                ) -> Tensor<B, 1> {
+                    let tensor3 = tensor1.matmul(tensor2.unsqueeze_dims(&[-1])).squeeze(1usize);
+
+                    tensor3
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
 }
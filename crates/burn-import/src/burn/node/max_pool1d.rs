@@ -4,7 +4,7 @@ use quote::quote;
 use burn::{nn::pool::MaxPool1dConfig, record::PrecisionSettings};
 
 use super::{Node, NodeCodegen};
-use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
+use crate::burn::{BurnImports, OtherType, Scope, ScopeError, TensorType, ToTokens, Type};
 
 #[derive(Debug, Clone)]
 pub struct MaxPool1dNode {
@@ -52,25 +52,27 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for MaxPool1dNode {
         let strides = self.config.stride.to_tokens();
         let padding = self.config.padding.to_tokens();
         let dilation = self.config.dilation.to_tokens();
+        let ceil_mode = self.config.ceil_mode;
         let tokens = quote! {
             let #name = MaxPool1dConfig::new(#kernel_size)
                 .with_stride(#strides)
                 .with_padding(#padding)
                 .with_dilation(#dilation)
+                .with_ceil_mode(#ceil_mode)
                 .init();
         };
 
         Some(tokens)
     }
 
-    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
-        let input = scope.tensor_use_owned(&self.input, node_position);
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
+        let input = scope.tensor_use_owned(&self.input, node_position)?;
         let output = &self.output.name;
         let field = &self.field.name;
 
-        quote! {
+        Ok(quote! {
             let #output = self.#field.forward(#input);
-        }
+        })
     }
 
     fn register_imports(&self, imports: &mut BurnImports) {
@@ -136,6 +138,7 @@ mod tests {
                         .with_stride(1)
                         .with_padding(PaddingConfig1d::Valid)
                         .with_dilation(1)
+                        .with_ceil_mode(false)
                         .init();
 
                     Self {
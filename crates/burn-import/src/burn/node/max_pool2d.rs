@@ -4,13 +4,16 @@ use quote::quote;
 use burn::{nn::pool::MaxPool2dConfig, record::PrecisionSettings};
 
 use super::{Node, NodeCodegen};
-use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
+use crate::burn::{BurnImports, OtherType, Scope, ScopeError, TensorType, ToTokens, Type};
 
 #[derive(Debug, Clone)]
 pub struct MaxPool2dNode {
     pub field: OtherType,
     pub input: TensorType,
     pub output: TensorType,
+    /// The flattened indices of the maximum values in the input tensor, the optional `Indices`
+    /// output of ONNX.
+    pub indices: Option<TensorType>,
     pub config: MaxPool2dConfig,
 }
 
@@ -19,6 +22,7 @@ impl MaxPool2dNode {
         name: S,
         input: TensorType,
         output: TensorType,
+        indices: Option<TensorType>,
         config: MaxPool2dConfig,
     ) -> Self {
         Self {
@@ -30,6 +34,7 @@ impl MaxPool2dNode {
             ),
             input,
             output,
+            indices,
             config,
         }
     }
@@ -40,7 +45,13 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for MaxPool2dNode {
         vec![Type::Tensor(self.input.clone())]
     }
     fn output_types(&self) -> Vec<Type> {
-        vec![Type::Tensor(self.output.clone())]
+        let mut outputs = vec![Type::Tensor(self.output.clone())];
+
+        if let Some(indices) = &self.indices {
+            outputs.push(Type::Tensor(indices.clone()));
+        }
+
+        outputs
     }
     fn field_type(&self) -> Option<Type> {
         Some(Type::Other(self.field.clone()))
@@ -52,28 +63,54 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for MaxPool2dNode {
         let strides = self.config.strides.to_tokens();
         let padding = self.config.padding.to_tokens();
         let dilation = self.config.dilation.to_tokens();
+        let ceil_mode = self.config.ceil_mode;
         let tokens = quote! {
             let #name = MaxPool2dConfig::new(#kernel_size)
                 .with_strides(#strides)
                 .with_padding(#padding)
                 .with_dilation(#dilation)
+                .with_ceil_mode(#ceil_mode)
                 .init();
         };
 
         Some(tokens)
     }
 
-    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
-        let input = scope.tensor_use_owned(&self.input, node_position);
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
+        let input = scope.tensor_use_owned(&self.input, node_position)?;
         let output = &self.output.name;
         let field = &self.field.name;
 
-        quote! {
-            let #output = self.#field.forward(#input);
-        }
+        let Some(indices) = &self.indices else {
+            return Ok(quote! {
+                let #output = self.#field.forward(#input);
+            });
+        };
+        let indices = &indices.name;
+
+        Ok(quote! {
+            let (#output, #indices) = {
+                let input = #input;
+                let [batch_size, channels, height, width] = input.dims();
+                let (output, indices) = self.#field.forward_with_indices(input);
+
+                // The indices of ONNX are in the whole input, not in the plane of each channel.
+                let offsets = Tensor::<B, 1, Int>::arange(
+                    0..(batch_size * channels) as i64,
+                    &indices.device(),
+                )
+                .reshape([batch_size, channels, 1, 1])
+                .mul_scalar((height * width) as i64);
+
+                (output, indices + offsets)
+            };
+        })
     }
 
     fn register_imports(&self, imports: &mut BurnImports) {
+        if self.indices.is_some() {
+            imports.register("burn::tensor::Int");
+        }
         imports.register("burn::nn::PaddingConfig2d");
         imports.register("burn::nn::pool::MaxPool2d");
         imports.register("burn::nn::pool::MaxPool2dConfig");
@@ -106,6 +143,7 @@ mod tests {
             "max_pool2d",
             TensorType::new_float("input", 4),
             TensorType::new_float("output", 4),
+            None,
             MaxPool2dConfig::new([3, 3])
                 .with_strides([1, 1])
                 .with_padding(PaddingConfig2d::Valid)
@@ -137,6 +175,7 @@ mod tests {
                         .with_strides([1, 1])
                         .with_padding(PaddingConfig2d::Valid)
                         .with_dilation([1, 1])
+                        .with_ceil_mode(false)
                         .init();
 
                     Self {
@@ -156,4 +195,81 @@ mod tests {
 
         assert_tokens(graph.codegen(), expected);
     }
+
+    #[test]
+    fn test_codegen_with_indices() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(MaxPool2dNode::new(
+            "max_pool2d",
+            TensorType::new_float("input", 4),
+            TensorType::new_float("output", 4),
+            Some(TensorType::new_int("indices", 4)),
+            MaxPool2dConfig::new([2, 2])
+                .with_strides([2, 2])
+                .with_ceil_mode(true),
+        ));
+
+        graph.register_input_output(
+            vec!["input".to_string()],
+            vec!["output".to_string(), "indices".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::PaddingConfig2d;
+            use burn::nn::pool::MaxPool2d;
+            use burn::nn::pool::MaxPool2dConfig;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                max_pool2d: MaxPool2d,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let max_pool2d = MaxPool2dConfig::new([2, 2])
+                        .with_strides([2, 2])
+                        .with_padding(PaddingConfig2d::Valid)
+                        .with_dilation([1, 1])
+                        .with_ceil_mode(true)
+                        .init();
+
+                    Self {
+                        max_pool2d,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 4>) -> (Tensor<B, 4>, Tensor<B, 4, Int>) {
+                    let (output, indices) = {
+                        let input = input;
+                        let [batch_size, channels, height, width] = input.dims();
+                        let (output, indices) = self.max_pool2d.forward_with_indices(input);
+
+                        let offsets = Tensor::<B, 1, Int>::arange(
+                            0..(batch_size * channels) as i64,
+                            &indices.device(),
+                        )
+                        .reshape([batch_size, channels, 1, 1])
+                        .mul_scalar((height * width) as i64);
+
+                        (output, indices + offsets)
+                    };
+
+                    (output, indices)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
 }
@@ -11,25 +11,45 @@ pub(crate) mod constant;
 pub(crate) mod conv1d;
 pub(crate) mod conv2d;
 pub(crate) mod conv_transpose_2d;
+pub(crate) mod cum_sum;
+pub(crate) mod depth_to_space;
 pub(crate) mod dropout;
+pub(crate) mod einsum;
 pub(crate) mod expand;
 pub(crate) mod gather;
 pub(crate) mod gather_elements;
+pub(crate) mod gather_nd;
+pub(crate) mod gemm;
 pub(crate) mod global_avg_pool;
+pub(crate) mod global_max_pool;
+pub(crate) mod group_norm;
+pub(crate) mod gru;
 pub(crate) mod layer_norm;
 pub(crate) mod linear;
+pub(crate) mod lstm;
 pub(crate) mod mask_where;
 pub(crate) mod matmul;
 pub(crate) mod max_pool1d;
 pub(crate) mod max_pool2d;
+pub(crate) mod one_hot;
+pub(crate) mod pad;
 pub(crate) mod prelu;
 pub(crate) mod random_normal;
 pub(crate) mod random_uniform;
 pub(crate) mod range;
 pub(crate) mod reshape;
+pub(crate) mod resize;
+pub(crate) mod scatter_elements;
+pub(crate) mod scatter_nd;
+pub(crate) mod slice;
+pub(crate) mod space_to_depth;
+pub(crate) mod split;
 pub(crate) mod squeeze;
 pub(crate) mod sum;
+pub(crate) mod top_k;
+pub(crate) mod trilu;
 pub(crate) mod unary;
+pub(crate) mod unique;
 pub(crate) mod unsqueeze;
 pub(crate) use base::*;
 
@@ -0,0 +1,175 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{TensorKind, TensorType, ToTokens, Type};
+
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// One-hot encode the indices along a new axis of size `depth`, the encoded positions having the
+/// on value and the others the off value.
+///
+/// Negative indices count from the end of the depth, and indices out of `[-depth, depth)` are
+/// encoded with the off value only.
+#[derive(Debug, Clone, new)]
+pub struct OneHotNode {
+    pub indices: TensorType,
+    pub output: TensorType,
+    pub depth: usize,
+    /// The off and on values.
+    pub values: [f64; 2],
+    pub axis: usize,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for OneHotNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.indices.clone())]
+    }
+
+    fn forward(
+        &self,
+        scope: &mut crate::burn::Scope,
+        node_position: usize,
+    ) -> Result<TokenStream, crate::burn::ScopeError> {
+        let indices_name = &self.indices.name;
+        let indices = scope.tensor_use_owned(&self.indices, node_position)?;
+        let output = &self.output.name;
+        let dim = self.output.dim.to_tokens();
+        let axis = self.axis.to_tokens();
+        let depth = self.depth as i64;
+        let last = depth - 1;
+
+        // The shape of the output, with the depth inserted at the axis.
+        let shape = (0..self.output.dim).map(|axis| match axis.cmp(&self.axis) {
+            std::cmp::Ordering::Less => {
+                let axis = axis.to_tokens();
+                quote! { dims[#axis] }
+            }
+            std::cmp::Ordering::Equal => self.depth.to_tokens(),
+            std::cmp::Ordering::Greater => {
+                let axis = (axis - 1).to_tokens();
+                quote! { dims[#axis] }
+            }
+        });
+
+        let [off, on] = self.values;
+        let (hot, values) = match self.output.kind {
+            TensorKind::Float => {
+                let scale = on - off;
+                let values = if off == 0.0 && on == 1.0 {
+                    quote! {}
+                } else {
+                    quote! { .mul_scalar(#scale).add_scalar(#off) }
+                };
+                (quote! { Tensor::<B, #dim> }, values)
+            }
+            TensorKind::Int => {
+                let (off, on) = (off as i64, on as i64);
+                let scale = on - off;
+                let values = if off == 0 && on == 1 {
+                    quote! {}
+                } else {
+                    quote! { .mul_scalar(#scale).add_scalar(#off) }
+                };
+                (quote! { Tensor::<B, #dim, Int> }, values)
+            }
+            TensorKind::Bool => panic!("OneHot: bool values are not supported"),
+        };
+        let valid = match self.output.kind {
+            TensorKind::Int => quote! { valid.int() },
+            _ => quote! { valid.float() },
+        };
+
+        Ok(quote! {
+            let #output = {
+                let dims = #indices_name.dims();
+                let device = #indices_name.device();
+                let indices = #indices.unsqueeze_dim::<#dim>(#axis);
+                // Negative indices count from the end of the depth
+                let indices = indices
+                    .clone()
+                    .mask_where(indices.clone().lower_elem(0), indices.add_scalar(#depth));
+                let valid = indices.clone().equal(indices.clone().clamp(0, #last));
+
+                #hot::zeros([#(#shape),*], &device)
+                    .scatter(#axis, indices.clamp(0, #last), #valid)
+                    #values
+            };
+        })
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::OneHot(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+
+    #[test]
+    fn test_codegen_one_hot() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(OneHotNode::new(
+            TensorType::new_int("indices", 2),
+            TensorType::new_float("output", 3),
+            4,
+            [-1.0, 1.0],
+            1,
+        ));
+
+        graph.register_input_output(vec!["indices".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, indices: Tensor<B, 2, Int>) -> Tensor<B, 3> {
+                    let output = {
+                        let dims = indices.dims();
+                        let device = indices.device();
+                        let indices = indices.unsqueeze_dim::<3>(1);
+                        let indices = indices
+                            .clone()
+                            .mask_where(indices.clone().lower_elem(0), indices.add_scalar(4i64));
+                        let valid = indices.clone().equal(indices.clone().clamp(0, 3i64));
+
+                        Tensor::<B, 3>::zeros([dims[0], 4, dims[1]], &device)
+                            .scatter(1, indices.clamp(0, 3i64), valid.float())
+                            .mul_scalar(2f64)
+                            .add_scalar(-1f64)
+                    };
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,237 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{BurnImports, Scope, ScopeError, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Padding mode of the ONNX `Pad` operator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PadMode {
+    /// Pad with a constant value (`mode=constant`).
+    Constant(f64),
+    /// Pad with the reflection of the values, excluding the edge (`mode=reflect`).
+    Reflect,
+    /// Pad by repeating the edge values (`mode=edge`).
+    Edge,
+}
+
+#[derive(Debug, Clone, new)]
+pub struct PadNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    /// The `(left, right, top, bottom)` padding of the last two dimensions, as in
+    /// [Tensor::pad](burn::tensor::Tensor::pad).
+    pub pads: [usize; 4],
+    pub mode: PadMode,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for PadNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
+        let input = scope.tensor_use_owned(&self.input, node_position)?;
+        let output = &self.output.name;
+        let [left, right, top, bottom] = self.pads;
+
+        let value = match self.mode {
+            PadMode::Constant(value) => value,
+            PadMode::Reflect | PadMode::Edge => {
+                // Burn only pads with a constant, so the padded parts are concatenated instead.
+                let height = pad_dim_tokens(self.mode, self.input.dim - 2, top, bottom);
+                let width = pad_dim_tokens(self.mode, self.input.dim - 1, left, right);
+
+                return Ok(quote! {
+                    let #output = {
+                        let input = #input;
+                        #height
+                        #width
+
+                        input
+                    };
+                });
+            }
+        };
+
+        let padding = quote! { (#left, #right, #top, #bottom) };
+
+        Ok(quote! {
+            let #output = #input.pad(#padding, #value.elem());
+        })
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        if let PadMode::Constant(_) = self.mode {
+            imports.register("burn::tensor::ElementConversion");
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Pad(self)
+    }
+}
+
+/// Generate the padding of the `input` variable in the given dimension with its reflected or
+/// repeated values.
+fn pad_dim_tokens(mode: PadMode, dim: usize, begin: usize, end: usize) -> TokenStream {
+    if begin == 0 && end == 0 {
+        return quote! {};
+    }
+
+    let dim = dim.to_tokens();
+
+    let begin_part = |size: TokenStream| match mode {
+        PadMode::Reflect => quote! { input.clone().narrow(#dim, 1, #size).flip([#dim]) },
+        _ => quote! { input.clone().narrow(#dim, 0, 1).repeat(#dim, #size) },
+    };
+    let end_part = |size: usize| {
+        let offset = (size + 1).to_tokens();
+        let size = size.to_tokens();
+
+        match mode {
+            PadMode::Reflect => quote! {
+                input.clone().narrow(#dim, size - #offset, #size).flip([#dim])
+            },
+            _ => quote! { input.clone().narrow(#dim, size - 1, 1).repeat(#dim, #size) },
+        }
+    };
+
+    let mut parts = Vec::new();
+    let mut statements = Vec::new();
+
+    if begin > 0 {
+        let part = begin_part(begin.to_tokens());
+        statements.push(quote! { let begin = #part; });
+        parts.push(quote! { begin });
+    }
+    parts.push(quote! { input });
+    if end > 0 {
+        let part = end_part(end);
+        statements.push(quote! {
+            let size = input.dims()[#dim];
+            let end = #part;
+        });
+        parts.push(quote! { end });
+    }
+
+    quote! {
+        #(#statements)*
+        let input = Tensor::cat([#(#parts),*].into(), #dim);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{pad::PadNode, test::assert_tokens},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_pad_constant() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(PadNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 4),
+            [1, 2, 3, 4],
+            PadMode::Constant(0.5),
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::tensor::ElementConversion;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.pad((1usize, 2usize, 3usize, 4usize), 0.5f64.elem());
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_pad_reflect() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(PadNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 4),
+            [0, 0, 1, 2],
+            PadMode::Reflect,
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = {
+                        let input = tensor1;
+                        let begin = input.clone().narrow(2, 1, 1).flip([2]);
+                        let size = input.dims()[2];
+                        let end = input.clone().narrow(2, size - 3, 2).flip([2]);
+                        let input = Tensor::cat([begin, input, end].into(), 2);
+
+                        input
+                    };
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
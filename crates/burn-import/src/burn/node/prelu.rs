@@ -1,8 +1,8 @@
 use super::{Node, NodeCodegen, SerializationBackend};
-use crate::burn::{BurnImports, OtherType, Scope, TensorType, Type};
+use crate::burn::{BurnImports, OtherType, Scope, ScopeError, TensorType, ToTokens, Type};
 use burn::{
     module::{Param, ParamId},
-    nn::{PReluConfig, PReluRecord},
+    nn::PReluRecord,
     record::{PrecisionSettings, Record},
     tensor::{DataSerialize, Tensor},
 };
@@ -10,13 +10,21 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use serde::Serialize;
 
+/// Slope of the ONNX `PRelu` operator, a single value or one value per channel.
+#[derive(Clone, Debug)]
+pub enum PReluSlope<PS: PrecisionSettings> {
+    /// Constant slope, loaded in a [PRelu](burn::nn::PRelu) module.
+    Constant(DataSerialize<PS::FloatElem>),
+    /// Slope given at runtime, flattened before being applied.
+    Tensor(TensorType),
+}
+
 #[derive(Clone, Debug)]
 pub struct PReluNode<PS: PrecisionSettings> {
     pub field: OtherType,
     pub input: TensorType,
     pub output: TensorType,
-    pub alpha: DataSerialize<PS::FloatElem>,
-    pub config: PReluConfig,
+    pub slope: PReluSlope<PS>,
 }
 
 impl<PS: PrecisionSettings> PReluNode<PS> {
@@ -24,8 +32,7 @@ impl<PS: PrecisionSettings> PReluNode<PS> {
         name: S,
         input: TensorType,
         output: TensorType,
-        alpha: DataSerialize<PS::FloatElem>,
-        config: PReluConfig,
+        slope: PReluSlope<PS>,
     ) -> Self {
         Self {
             field: OtherType::new(
@@ -36,27 +43,41 @@ impl<PS: PrecisionSettings> PReluNode<PS> {
             ),
             input,
             output,
-            alpha,
-            config,
+            slope,
         }
     }
 }
 
 impl<PS: PrecisionSettings> NodeCodegen<PS> for PReluNode<PS> {
     fn input_types(&self) -> Vec<Type> {
-        vec![Type::Tensor(self.input.clone())]
+        match &self.slope {
+            PReluSlope::Constant(_) => vec![Type::Tensor(self.input.clone())],
+            PReluSlope::Tensor(slope) => vec![
+                Type::Tensor(self.input.clone()),
+                Type::Tensor(slope.clone()),
+            ],
+        }
     }
     fn output_types(&self) -> Vec<Type> {
         vec![Type::Tensor(self.output.clone())]
     }
     fn field_type(&self) -> Option<Type> {
-        Some(Type::Other(self.field.clone()))
+        match &self.slope {
+            PReluSlope::Constant(_) => Some(Type::Other(self.field.clone())),
+            PReluSlope::Tensor(_) => None,
+        }
     }
 
     fn field_init(&self) -> Option<TokenStream> {
+        let alpha = match &self.slope {
+            PReluSlope::Constant(alpha) => alpha,
+            PReluSlope::Tensor(_) => return None,
+        };
         let name = &self.field.name;
+        let num_parameters = alpha.value.len().to_tokens();
         let tokens = quote! {
             let #name = PReluConfig::new()
+                .with_num_parameters(#num_parameters)
                 .init(device);
         };
 
@@ -64,11 +85,15 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for PReluNode<PS> {
     }
 
     fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let alpha = match &self.slope {
+            PReluSlope::Constant(alpha) => alpha,
+            PReluSlope::Tensor(_) => panic!("PRelu: a runtime slope isn't serialized"),
+        };
         let device = Default::default();
         let record = PReluRecord::<SerializationBackend> {
             alpha: Param::initialized(
                 ParamId::new(),
-                Tensor::from_data(self.alpha.clone().convert(), &device),
+                Tensor::from_data(alpha.clone().convert(), &device),
             ),
         };
 
@@ -76,18 +101,40 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for PReluNode<PS> {
         item.serialize(serializer)
     }
 
-    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
-        let input = scope.tensor_use_owned(&self.input, node_position);
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
+        let input = scope.tensor_use_owned(&self.input, node_position)?;
         let output = &self.output.name;
-        let field = &self.field.name;
 
-        quote! {
-            let #output = self.#field.forward(#input);
-        }
+        Ok(match &self.slope {
+            PReluSlope::Constant(_) => {
+                let field = &self.field.name;
+
+                quote! {
+                    let #output = self.#field.forward(#input);
+                }
+            }
+            PReluSlope::Tensor(slope_type) => {
+                let slope = scope.tensor_use_owned(slope_type, node_position)?;
+                // The slope broadcasts against the channels, its other dimensions are of size 1.
+                let slope = match slope_type.dim {
+                    1 => slope,
+                    dim => {
+                        let last = (dim - 1).to_tokens();
+                        quote! { #slope.flatten::<1>(0, #last) }
+                    }
+                };
+
+                quote! {
+                    let #output = burn::tensor::activation::prelu(#input, #slope);
+                }
+            }
+        })
     }
     fn register_imports(&self, imports: &mut BurnImports) {
-        imports.register("burn::nn::PRelu");
-        imports.register("burn::nn::PReluConfig");
+        if let PReluSlope::Constant(_) = self.slope {
+            imports.register("burn::nn::PRelu");
+            imports.register("burn::nn::PReluConfig");
+        }
     }
 
     fn into_node(self) -> Node<PS> {
@@ -109,8 +156,7 @@ mod tests {
             "prelu",
             TensorType::new_float("input", 4),
             TensorType::new_float("output", 4),
-            Data::from([2.]).serialize(),
-            PReluConfig::new(),
+            PReluSlope::Constant(Data::from([2.]).serialize()),
         ));
 
         graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
@@ -131,7 +177,7 @@ mod tests {
         impl<B: Backend> Model<B> {
             #[allow(unused_variables)]
             pub fn new(device: &B::Device) -> Self {
-                let prelu = PReluConfig::new().init(device);
+                let prelu = PReluConfig::new().with_num_parameters(1).init(device);
                 Self {
                     prelu,
                     phantom: core::marker::PhantomData,
@@ -148,4 +194,96 @@ mod tests {
 
         assert_tokens(graph.codegen(), expected);
     }
+
+    #[test]
+    fn test_codegen_per_channel() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(PReluNode::new(
+            "prelu",
+            TensorType::new_float("input", 4),
+            TensorType::new_float("output", 4),
+            PReluSlope::Constant(Data::from([0.1, 0.2, 0.3]).serialize()),
+        ));
+
+        graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::nn::PRelu;
+            use burn::nn::PReluConfig;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                prelu: PRelu<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+            impl<B: Backend> Model<B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let prelu = PReluConfig::new().with_num_parameters(3).init(device);
+                    Self {
+                        prelu,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let output = self.prelu.forward(input);
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_slope_tensor() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(PReluNode::<FullPrecisionSettings>::new(
+            "prelu",
+            TensorType::new_float("input", 4),
+            TensorType::new_float("output", 4),
+            PReluSlope::Tensor(TensorType::new_float("slope", 3)),
+        ));
+
+        graph.register_input_output(
+            vec!["input".to_string(), "slope".to_string()],
+            vec!["output".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+            impl<B: Backend> Model<B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 4>, slope: Tensor<B, 3>) -> Tensor<B, 4> {
+                    let output = burn::tensor::activation::prelu(input, slope.flatten::<1>(0, 2));
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
 }
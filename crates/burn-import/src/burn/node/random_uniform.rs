@@ -1,5 +1,5 @@
 use super::{Node, NodeCodegen};
-use crate::burn::{Scope, TensorType, Type};
+use crate::burn::{Scope, ScopeError, TensorType, Type};
 use burn::record::PrecisionSettings;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -46,13 +46,17 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for RandomUniformNode {
         vec![Type::Tensor(self.output_ty.clone())]
     }
 
-    fn forward(&self, _scope: &mut Scope, _node_position: usize) -> TokenStream {
+    fn forward(
+        &self,
+        _scope: &mut Scope,
+        _node_position: usize,
+    ) -> Result<TokenStream, ScopeError> {
         let output = &self.output_ty.name;
         let shape = self.get_output_shape();
         let dist = self.get_distribution();
-        quote! {
+        Ok(quote! {
             let #output = Tensor::random(#shape, #dist, &*self.device);
-        }
+        })
     }
 
     fn into_node(self) -> Node<PS> {
@@ -1,14 +1,39 @@
 use super::{Node, NodeCodegen};
-use crate::burn::{ScalarType, Scope, TensorType, Type};
+use crate::burn::{ScalarType, Scope, ScopeError, TensorType, ToTokens, Type};
 use burn::record::PrecisionSettings;
 use proc_macro2::TokenStream;
 use quote::quote;
 
+/// Start, limit or delta of a range.
+#[derive(Debug, Clone)]
+pub enum RangeValue {
+    /// Value known at import time.
+    Constant(i64),
+    /// Scalar input of the model.
+    Scalar(ScalarType),
+}
+
+impl RangeValue {
+    fn tokens(&self) -> TokenStream {
+        match self {
+            RangeValue::Constant(value) => value.to_tokens(),
+            RangeValue::Scalar(scalar) => {
+                let name = &scalar.name;
+                quote! { #name }
+            }
+        }
+    }
+}
+
+/// RangeNode generates the values from `start` up to `end` excluded, by increments of `step`.
+///
+/// A negative step is only supported when the three values are constants, the length of the
+/// output being known, otherwise the step must be positive at runtime.
 #[derive(Debug, Clone, new)]
 pub struct RangeNode {
-    pub start: ScalarType,
-    pub end: ScalarType,
-    pub step: ScalarType,
+    pub start: RangeValue,
+    pub end: RangeValue,
+    pub step: RangeValue,
     pub output: TensorType,
 }
 
@@ -18,23 +43,56 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for RangeNode {
     }
 
     fn input_types(&self) -> Vec<Type> {
-        vec![
-            Type::Scalar(self.start.clone()),
-            Type::Scalar(self.end.clone()),
-            Type::Scalar(self.step.clone()),
-        ]
+        [&self.start, &self.end, &self.step]
+            .into_iter()
+            .filter_map(|value| match value {
+                RangeValue::Constant(_) => None,
+                RangeValue::Scalar(scalar) => Some(Type::Scalar(scalar.clone())),
+            })
+            .collect()
     }
 
-    fn forward(&self, _scope: &mut Scope, _node_position: usize) -> TokenStream {
+    fn forward(
+        &self,
+        _scope: &mut Scope,
+        _node_position: usize,
+    ) -> Result<TokenStream, ScopeError> {
         let output = &self.output.name;
 
-        let start = &self.start.name;
-        let end = &self.end.name;
-        let step = &self.step.name;
+        let start = self.start.tokens();
+        let end = self.end.tokens();
 
-        quote! {
-            let #output = Tensor::arange_step(#start..#end, #step as usize, &*self.device);
-        }
+        let range = match (&self.start, &self.end, &self.step) {
+            (_, _, RangeValue::Constant(step)) if *step > 0 => {
+                let step = (*step as usize).to_tokens();
+                quote! { Tensor::arange_step(#start..#end, #step, &*self.device) }
+            }
+            (RangeValue::Constant(start), RangeValue::Constant(_), RangeValue::Constant(step)) => {
+                // The values are decreasing, computed from their indices
+                let len = match self.output.shape.as_deref() {
+                    Some([len]) => len.to_tokens(),
+                    _ => panic!("Range: the length of the output must be known"),
+                };
+                let start = start.to_tokens();
+                let step = step.to_tokens();
+                quote! {
+                    Tensor::arange(0..#len, &*self.device)
+                        .mul_scalar(#step)
+                        .add_scalar(#start)
+                }
+            }
+            (_, _, RangeValue::Constant(step)) => {
+                panic!("Range: a step of {step} requires a constant start and end")
+            }
+            (_, _, RangeValue::Scalar(step)) => {
+                let step = &step.name;
+                quote! { Tensor::arange_step(#start..#end, #step as usize, &*self.device) }
+            }
+        };
+
+        Ok(quote! {
+            let #output = #range;
+        })
     }
     fn into_node(self) -> Node<PS> {
         Node::Range(self)
@@ -46,6 +104,7 @@ mod tests {
     use super::*;
     use crate::burn::graph::BurnGraph;
     use crate::burn::node::test::assert_tokens;
+    use crate::burn::TensorKind;
     use crate::burn::{ScalarKind, ScalarType};
     use burn::record::FullPrecisionSettings;
 
@@ -55,9 +114,9 @@ mod tests {
 
         graph.register(
             RangeNode::new(
-                ScalarType::new("start", ScalarKind::Int64),
-                ScalarType::new("end", ScalarKind::Int64),
-                ScalarType::new("step", ScalarKind::Int64),
+                RangeValue::Scalar(ScalarType::new("start", ScalarKind::Int64)),
+                RangeValue::Scalar(ScalarType::new("end", ScalarKind::Int64)),
+                RangeValue::Scalar(ScalarType::new("step", ScalarKind::Int64)),
                 TensorType::new_int("output", 1),
             )
             .into_node(),
@@ -99,4 +158,54 @@ mod tests {
 
         assert_tokens(graph.codegen(), expected);
     }
+
+    #[test]
+    fn codegen_nodes_range_constant_negative_step() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(
+            RangeNode::new(
+                RangeValue::Constant(10),
+                RangeValue::Constant(0),
+                RangeValue::Constant(-3),
+                TensorType::new("output", 1, TensorKind::Int, Some(vec![4])),
+            )
+            .into_node(),
+        );
+        graph.register_input_output(vec![], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self) -> Tensor<B, 1, Int> {
+                    let output = Tensor::arange(0..4, &*self.device)
+                        .mul_scalar(-3)
+                        .add_scalar(10);
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
 }
@@ -1,5 +1,5 @@
 use super::{Node, NodeCodegen};
-use crate::burn::{Scope, TensorType, ToTokens, Type};
+use crate::burn::{Scope, ScopeError, TensorType, ToTokens, Type};
 use burn::record::PrecisionSettings;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -20,14 +20,14 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for ReshapeNode {
         vec![Type::Tensor(self.input.clone())]
     }
 
-    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
-        let input = scope.tensor_use_owned(&self.input, node_position);
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
+        let input = scope.tensor_use_owned(&self.input, node_position)?;
         let output = &self.output.name;
         let shape_values = &self.shape.to_tokens();
 
-        quote! {
+        Ok(quote! {
             let #output = #input.reshape(#shape_values);
-        }
+        })
     }
 
     fn into_node(self) -> Node<PS> {
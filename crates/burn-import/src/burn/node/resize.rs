@@ -0,0 +1,250 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, ScopeError, TensorType, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Interpolation algorithm of the ONNX `Resize` operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResizeMode {
+    /// Nearest-neighbor interpolation (`mode=nearest`).
+    Nearest,
+    /// Bilinear interpolation (`mode=linear`).
+    Linear,
+}
+
+/// Output size of the resized tensor.
+///
+/// The ONNX `scales` and `sizes` inputs cover the 4 dimensions of the input, but only the spatial
+/// dimensions are kept since the batch and channel dimensions can't be resized.
+#[derive(Debug, Clone)]
+pub enum ResizeSize {
+    /// Output height and width known during the conversion.
+    Sizes([usize; 2]),
+    /// Scale factors of the height and width known during the conversion.
+    Scales([f32; 2]),
+    /// Output sizes given by a 1D int tensor at runtime.
+    RuntimeSizes(TensorType),
+    /// Scale factors given by a 1D float tensor at runtime.
+    RuntimeScales(TensorType),
+}
+
+#[derive(Debug, Clone, new)]
+pub struct ResizeNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub mode: ResizeMode,
+    pub size: ResizeSize,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for ResizeNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        match &self.size {
+            ResizeSize::RuntimeSizes(tensor) | ResizeSize::RuntimeScales(tensor) => vec![
+                Type::Tensor(self.input.clone()),
+                Type::Tensor(tensor.clone()),
+            ],
+            _ => vec![Type::Tensor(self.input.clone())],
+        }
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
+        let input_name = &self.input.name;
+        let output = &self.output.name;
+
+        let mode = match self.mode {
+            ResizeMode::Nearest => quote! { burn::tensor::ops::InterpolateMode::Nearest },
+            ResizeMode::Linear => quote! { burn::tensor::ops::InterpolateMode::Bilinear },
+        };
+
+        // The runtime sizes or scales are read before the input is moved.
+        let (output_size, size_values) = match &self.size {
+            ResizeSize::Sizes([height, width]) => (quote! { [#height, #width] }, quote! {}),
+            ResizeSize::Scales([scale_height, scale_width]) => (
+                quote! {
+                    [
+                        (height as f32 * #scale_height) as usize,
+                        (width as f32 * #scale_width) as usize,
+                    ]
+                },
+                quote! {},
+            ),
+            ResizeSize::RuntimeSizes(sizes) => {
+                let sizes = scope.tensor_use_owned(sizes, node_position)?;
+                (
+                    quote! { [sizes[2] as usize, sizes[3] as usize] },
+                    quote! { let sizes = #sizes.into_data().convert::<i64>().value; },
+                )
+            }
+            ResizeSize::RuntimeScales(scales) => {
+                let scales = scope.tensor_use_owned(scales, node_position)?;
+                (
+                    quote! {
+                        [
+                            (height as f32 * scales[2]) as usize,
+                            (width as f32 * scales[3]) as usize,
+                        ]
+                    },
+                    quote! { let scales = #scales.into_data().convert::<f32>().value; },
+                )
+            }
+        };
+        let input = scope.tensor_use_owned(&self.input, node_position)?;
+
+        // The scales are applied to the spatial dimensions of the input.
+        let dims = match &self.size {
+            ResizeSize::Scales(_) | ResizeSize::RuntimeScales(_) => {
+                quote! { let [_, _, height, width] = #input_name.dims(); }
+            }
+            _ => quote! {},
+        };
+
+        Ok(quote! {
+            let #output = {
+                #dims
+                #size_values
+
+                burn::tensor::module::interpolate(
+                    #input,
+                    #output_size,
+                    burn::tensor::ops::InterpolateOptions::new(#mode),
+                )
+            };
+        })
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Resize(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{resize::ResizeNode, test::assert_tokens},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_resize_sizes() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ResizeNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 4),
+            ResizeMode::Nearest,
+            ResizeSize::Sizes([8, 16]),
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = {
+                        burn::tensor::module::interpolate(
+                            tensor1,
+                            [8usize, 16usize],
+                            burn::tensor::ops::InterpolateOptions::new(
+                                burn::tensor::ops::InterpolateMode::Nearest
+                            ),
+                        )
+                    };
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_resize_runtime_scales() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ResizeNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor3", 4),
+            ResizeMode::Linear,
+            ResizeSize::RuntimeScales(TensorType::new_float("tensor2", 1)),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+            vec!["tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>, tensor2: Tensor<B, 1>) -> Tensor<B, 4> {
+                    let tensor3 = {
+                        let [_, _, height, width] = tensor1.dims();
+                        let scales = tensor2.into_data().convert::<f32>().value;
+
+                        burn::tensor::module::interpolate(
+                            tensor1,
+                            [
+                                (height as f32 * scales[2]) as usize,
+                                (width as f32 * scales[3]) as usize,
+                            ],
+                            burn::tensor::ops::InterpolateOptions::new(
+                                burn::tensor::ops::InterpolateMode::Bilinear
+                            ),
+                        )
+                    };
+
+                    tensor3
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
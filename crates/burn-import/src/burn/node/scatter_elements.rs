@@ -0,0 +1,174 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, ScopeError, TensorType, ToTokens, Type};
+
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// How the updates are combined with the values they are scattered into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScatterReduction {
+    /// The updates replace the values.
+    None,
+    /// The updates are added to the values.
+    Add,
+}
+
+/// Scatter the updates into a copy of the data along an axis, at the positions given by the
+/// indices.
+#[derive(Debug, Clone, new)]
+pub struct ScatterElementsNode {
+    pub data: TensorType,
+    pub indices: TensorType,
+    pub updates: TensorType,
+    pub output: TensorType,
+    pub axis: usize,
+    pub reduction: ScatterReduction,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for ScatterElementsNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![
+            Type::Tensor(self.data.clone()),
+            Type::Tensor(self.indices.clone()),
+            Type::Tensor(self.updates.clone()),
+        ]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
+        let axis = self.axis.to_tokens();
+        let data = scope.tensor_use_owned(&self.data, node_position)?;
+        let indices = scope.tensor_use_owned(&self.indices, node_position)?;
+        let updates = scope.tensor_use_owned(&self.updates, node_position)?;
+        let output = &self.output.name;
+
+        Ok(match self.reduction {
+            ScatterReduction::Add => quote! {
+                let #output = #data.scatter(#axis, #indices, #updates);
+            },
+            // Burn scatters with a sum, so the scattered positions are cleared first.
+            ScatterReduction::None => {
+                let dim = self.data.dim.to_tokens();
+                let data_name = &self.data.name;
+                let indices_name = &self.indices.name;
+
+                quote! {
+                    let #output = {
+                        let scattered = Tensor::<B, #dim>::zeros(#data_name.shape(), &#data_name.device())
+                            .scatter(
+                                #axis,
+                                #indices_name.clone(),
+                                Tensor::ones(#indices_name.shape(), &#data_name.device()),
+                            )
+                            .greater_elem(0.0);
+
+                        #data.mask_fill(scattered, 0).scatter(#axis, #indices, #updates)
+                    };
+                }
+            }
+        })
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::ScatterElements(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+
+    fn expected(body: TokenStream) -> TokenStream {
+        quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    data: Tensor<B, 2>,
+                    indices: Tensor<B, 2, Int>,
+                    updates: Tensor<B, 2>
+                ) -> Tensor<B, 2> {
+                    #body
+
+                    output
+                }
+            }
+        }
+    }
+
+    fn graph(reduction: ScatterReduction) -> BurnGraph<FullPrecisionSettings> {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ScatterElementsNode::new(
+            TensorType::new_float("data", 2),
+            TensorType::new_int("indices", 2),
+            TensorType::new_float("updates", 2),
+            TensorType::new_float("output", 2),
+            1,
+            reduction,
+        ));
+
+        graph.register_input_output(
+            vec![
+                "data".to_string(),
+                "indices".to_string(),
+                "updates".to_string(),
+            ],
+            vec!["output".to_string()],
+        );
+
+        graph
+    }
+
+    #[test]
+    fn test_codegen_scatter_elements() {
+        assert_tokens(
+            graph(ScatterReduction::None).codegen(),
+            expected(quote! {
+                let output = {
+                    let scattered = Tensor::<B, 2>::zeros(data.shape(), &data.device())
+                        .scatter(1, indices.clone(), Tensor::ones(indices.shape(), &data.device()))
+                        .greater_elem(0.0);
+
+                    data.mask_fill(scattered, 0).scatter(1, indices, updates)
+                };
+            }),
+        );
+    }
+
+    #[test]
+    fn test_codegen_scatter_elements_add() {
+        assert_tokens(
+            graph(ScatterReduction::Add).codegen(),
+            expected(quote! {
+                let output = data.scatter(1, indices, updates);
+            }),
+        );
+    }
+}
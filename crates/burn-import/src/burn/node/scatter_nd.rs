@@ -0,0 +1,193 @@
+use super::{scatter_elements::ScatterReduction, Node, NodeCodegen};
+use crate::burn::{Scope, ScopeError, TensorType, ToTokens, Type};
+
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Scatter the updates into a copy of the data, each index tuple of the last axis of the indices
+/// selecting the slice of the data replaced by the corresponding update.
+///
+/// The data is flattened to rows of the slices selected by the index tuples, so the updates are
+/// assigned to the rows at the linear offsets of the tuples.
+#[derive(Debug, Clone, new)]
+pub struct ScatterNDNode {
+    pub data: TensorType,
+    pub indices: TensorType,
+    pub updates: TensorType,
+    pub output: TensorType,
+    pub reduction: ScatterReduction,
+}
+
+impl ScatterNDNode {
+    /// Length of the index tuples, from the ranks of the inputs.
+    fn tuple_size(&self) -> usize {
+        (self.data.dim + self.indices.dim)
+            .checked_sub(self.updates.dim + 1)
+            .filter(|size| (1..=self.data.dim).contains(size))
+            .unwrap_or_else(|| {
+                panic!(
+                    "ScatterND: updates of rank {} don't match data of rank {} and indices of rank {}",
+                    self.updates.dim, self.data.dim, self.indices.dim
+                )
+            })
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for ScatterNDNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![
+            Type::Tensor(self.data.clone()),
+            Type::Tensor(self.indices.clone()),
+            Type::Tensor(self.updates.clone()),
+        ]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
+        let tuple_size = self.tuple_size();
+        let batch = (self.indices.dim - 1).to_tokens();
+        let data_name = &self.data.name;
+        let indices_name = &self.indices.name;
+        let data = scope.tensor_use_owned(&self.data, node_position)?;
+        let indices = scope.tensor_use_owned(&self.indices, node_position)?;
+        let updates = scope.tensor_use_owned(&self.updates, node_position)?;
+        let output = &self.output.name;
+
+        // Linear offset of the index tuples, computed with the Horner scheme.
+        let offsets = (1..tuple_size).map(|axis| {
+            let index = axis.to_tokens();
+            let end = (axis + 1).to_tokens();
+
+            quote! {
+                let offsets = offsets
+                    .mul_scalar(shape[#index] as i64)
+                    .add(tuples.clone().slice([0..count, #index..#end]));
+            }
+        });
+        let tuple_size = tuple_size.to_tokens();
+
+        // Burn assigns with a sum, so the assigned rows are cleared first unless they are added to.
+        let clear = match self.reduction {
+            ScatterReduction::Add => quote! {},
+            ScatterReduction::None => quote! {
+                let device = data.device();
+                let scattered = Tensor::<B, 2>::zeros([rows, row_size], &device)
+                    .select_assign(0, offsets.clone(), Tensor::ones([count, row_size], &device))
+                    .greater_elem(0.0);
+                let data = data.mask_fill(scattered, 0);
+            },
+        };
+
+        Ok(quote! {
+            let #output = {
+                let shape = #data_name.dims();
+                let rows = shape[..#tuple_size].iter().product::<usize>();
+                let row_size = shape[#tuple_size..].iter().product::<usize>();
+                let count = #indices_name.dims()[..#batch].iter().product::<usize>();
+
+                let tuples = #indices.reshape([count, #tuple_size]);
+                let offsets = tuples.clone().slice([0..count, 0..1]);
+                #(#offsets)*
+                let offsets = offsets.reshape([count]);
+
+                let data = #data.reshape([rows, row_size]);
+                let updates = #updates.reshape([count, row_size]);
+                #clear
+
+                data.select_assign(0, offsets, updates).reshape(shape)
+            };
+        })
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::ScatterND(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+
+    #[test]
+    fn test_codegen_scatter_nd() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ScatterNDNode::new(
+            TensorType::new_float("data", 3),
+            TensorType::new_int("indices", 2),
+            TensorType::new_float("updates", 2),
+            TensorType::new_float("output", 3),
+            ScatterReduction::Add,
+        ));
+
+        graph.register_input_output(
+            vec![
+                "data".to_string(),
+                "indices".to_string(),
+                "updates".to_string(),
+            ],
+            vec!["output".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    data: Tensor<B, 3>,
+                    indices: Tensor<B, 2, Int>,
+                    updates: Tensor<B, 2>
+                ) -> Tensor<B, 3> {
+                    let output = {
+                        let shape = data.dims();
+                        let rows = shape[..2].iter().product::<usize>();
+                        let row_size = shape[2..].iter().product::<usize>();
+                        let count = indices.dims()[..1].iter().product::<usize>();
+
+                        let tuples = indices.reshape([count, 2]);
+                        let offsets = tuples.clone().slice([0..count, 0..1]);
+                        let offsets = offsets
+                            .mul_scalar(shape[1] as i64)
+                            .add(tuples.clone().slice([0..count, 1..2]));
+                        let offsets = offsets.reshape([count]);
+
+                        let data = data.reshape([rows, row_size]);
+                        let updates = updates.reshape([count, row_size]);
+
+                        data.select_assign(0, offsets, updates).reshape(shape)
+                    };
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
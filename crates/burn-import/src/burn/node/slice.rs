@@ -0,0 +1,234 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, ScopeError, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Debug, Clone, new)]
+pub struct SliceNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    /// The `(start, end)` indices of the leading dimensions, `None` keeps the whole dimension.
+    ///
+    /// Negative indices are counted from the end of the dimension and indices out of bounds
+    /// are clamped, as in ONNX.
+    pub ranges: Vec<Option<(i64, i64)>>,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for SliceNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
+        let input_name = &self.input.name;
+        let input = scope.tensor_use_owned(&self.input, node_position)?;
+        let output = &self.output.name;
+
+        let ranges = self.ranges.iter().enumerate().map(|(dim, range)| {
+            let dim = dim.to_tokens();
+
+            match range {
+                Some((start, end)) => {
+                    let start = index_tokens(*start, &dim);
+                    let end = index_tokens(*end, &dim);
+
+                    quote! { #start..#end }
+                }
+                None => quote! { 0..dims[#dim] },
+            }
+        });
+
+        Ok(quote! {
+            let #output = {
+                let dims = #input_name.dims();
+
+                #input.slice([#(#ranges),*])
+            };
+        })
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Slice(self)
+    }
+}
+
+/// Whether the range `start..end` is empty whatever the size of the dimension, the indices being
+/// interpreted as the [ranges](SliceNode::ranges) of the node.
+pub(crate) fn is_empty_range(start: i64, end: i64) -> bool {
+    match (Index::new(start), Index::new(end)) {
+        (Index::FromStart(start), Index::FromStart(end)) => start >= end,
+        (Index::FromEnd(start), Index::FromEnd(end)) => start <= end,
+        // The start is clamped to the size of the dimension.
+        (Index::FromEnd(0), Index::FromStart(_)) => true,
+        (Index::FromEnd(_), Index::FromStart(end)) => end == 0,
+        (Index::FromStart(_), Index::FromEnd(_)) => false,
+    }
+}
+
+/// A slice index, counted from the start or from the end of the dimension.
+enum Index {
+    FromStart(u64),
+    FromEnd(u64),
+}
+
+impl Index {
+    fn new(index: i64) -> Self {
+        match index {
+            // Large values, e.g. `i64::MAX`, are used to slice until the end.
+            index if index >= i32::MAX as i64 => Index::FromEnd(0),
+            // `unsigned_abs` doesn't overflow for `i64::MIN`.
+            index if index < 0 => Index::FromEnd(index.unsigned_abs()),
+            index => Index::FromStart(index as u64),
+        }
+    }
+}
+
+/// Generate the index in the given dimension, clamped to its size.
+fn index_tokens(index: i64, dim: &TokenStream) -> TokenStream {
+    match Index::new(index) {
+        Index::FromStart(0) => quote! { 0 },
+        Index::FromEnd(0) => quote! { dims[#dim] },
+        Index::FromEnd(offset) => {
+            let offset = (offset as usize).to_tokens();
+            quote! { dims[#dim].saturating_sub(#offset) }
+        }
+        Index::FromStart(index) => {
+            let index = (index as usize).to_tokens();
+            quote! { dims[#dim].min(#index) }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{slice::SliceNode, test::assert_tokens},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_slice() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(SliceNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 4),
+            vec![None, Some((1, -1)), Some((0, i64::MAX))],
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = {
+                        let dims = tensor1.dims();
+
+                        tensor1.slice([
+                            0..dims[0],
+                            dims[1].min(1)..dims[1].saturating_sub(1),
+                            0..dims[2]
+                        ])
+                    };
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_slice_with_i64_min() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(SliceNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_float("tensor2", 2),
+            vec![Some((i64::MIN, -1))],
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>) -> Tensor<B, 2> {
+                    let tensor2 = {
+                        let dims = tensor1.dims();
+
+                        tensor1.slice([
+                            dims[0].saturating_sub(9223372036854775808)
+                                ..dims[0].saturating_sub(1)
+                        ])
+                    };
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_is_empty_range() {
+        assert!(is_empty_range(2, 2));
+        assert!(is_empty_range(3, 1));
+        assert!(is_empty_range(-1, -2));
+        assert!(is_empty_range(i64::MAX, 2));
+        assert!(is_empty_range(-2, 0));
+        assert!(is_empty_range(i64::MIN, i64::MIN));
+        assert!(!is_empty_range(1, -1));
+        assert!(!is_empty_range(0, i64::MAX));
+        assert!(!is_empty_range(i64::MIN, -1));
+        assert!(!is_empty_range(-2, 1));
+    }
+}
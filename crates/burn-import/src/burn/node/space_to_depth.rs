@@ -0,0 +1,131 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, ScopeError, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Rearrange spatial blocks of `block_size x block_size` pixels into channels, the inverse of
+/// [DepthToSpaceNode](super::depth_to_space::DepthToSpaceNode) in `DCR` mode.
+#[derive(Debug, Clone, new)]
+pub struct SpaceToDepthNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub block_size: usize,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for SpaceToDepthNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
+        let input = scope.tensor_use_owned(&self.input, node_position)?;
+        let output = &self.output.name;
+        let block_size = self.block_size.to_tokens();
+        let num_blocks = (self.block_size * self.block_size).to_tokens();
+
+        Ok(quote! {
+            let #output = {
+                let input = #input;
+                let [batch_size, channels, height, width] = input.dims();
+
+                input
+                    .reshape([
+                        batch_size,
+                        channels,
+                        height / #block_size,
+                        #block_size,
+                        width / #block_size,
+                        #block_size,
+                    ])
+                    .permute([0, 3, 5, 1, 2, 4])
+                    .reshape([
+                        batch_size,
+                        channels * #num_blocks,
+                        height / #block_size,
+                        width / #block_size,
+                    ])
+            };
+        })
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::SpaceToDepth(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+
+    #[test]
+    fn test_codegen_space_to_depth() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(SpaceToDepthNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 4),
+            2,
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = {
+                        let input = tensor1;
+                        let [batch_size, channels, height, width] = input.dims();
+
+                        input
+                            .reshape([
+                                batch_size,
+                                channels,
+                                height / 2,
+                                2,
+                                width / 2,
+                                2,
+                            ])
+                            .permute([0, 3, 5, 1, 2, 4])
+                            .reshape([
+                                batch_size,
+                                channels * 4,
+                                height / 2,
+                                width / 2,
+                            ])
+                    };
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,313 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, ScopeError, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Debug, Clone, new)]
+pub struct SplitNode {
+    pub input: TensorType,
+    pub outputs: Vec<TensorType>,
+    pub axis: usize,
+    /// The size of each output along the axis, `None` splits the input in chunks of equal size
+    /// with a smaller last chunk when the size isn't divisible.
+    pub sizes: Option<Vec<usize>>,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for SplitNode {
+    fn output_types(&self) -> Vec<Type> {
+        self.outputs
+            .iter()
+            .map(|output| Type::Tensor(output.clone()))
+            .collect()
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
+        let input = scope.tensor_use_owned(&self.input, node_position)?;
+        let axis = self.axis.to_tokens();
+        let outputs = self.outputs.iter().map(|output| &output.name);
+        let outputs = match self.outputs.len() {
+            1 => quote! { #(#outputs)* },
+            _ => quote! { (#(#outputs),*) },
+        };
+
+        let (split, values): (TokenStream, Vec<TokenStream>) = match &self.sizes {
+            Some(sizes) => {
+                let mut start = 0;
+                let values = sizes
+                    .iter()
+                    .enumerate()
+                    .map(|(index, &size)| {
+                        // The last output takes the input without cloning it.
+                        let input = if index == sizes.len() - 1 {
+                            quote! { input }
+                        } else {
+                            quote! { input.clone() }
+                        };
+                        let value = quote! { #input.narrow(#axis, #start, #size) };
+                        start += size;
+                        value
+                    })
+                    .collect();
+
+                (quote! { let input = #input; }, values)
+            }
+            None => {
+                let num_outputs = self.outputs.len();
+                let values = (0..num_outputs)
+                    .map(|index| {
+                        let start = match index {
+                            0 => quote! { 0 },
+                            1 => quote! { chunk_size },
+                            index => {
+                                let index = index.to_tokens();
+                                quote! { #index * chunk_size }
+                            }
+                        };
+
+                        // The last output takes the rest of the input without cloning it.
+                        if index == 0 && num_outputs == 1 {
+                            quote! { input.narrow(#axis, 0, size) }
+                        } else if index == num_outputs - 1 {
+                            quote! { input.narrow(#axis, #start, size.saturating_sub(#start)) }
+                        } else {
+                            quote! { input.clone().narrow(#axis, #start, chunk_size) }
+                        }
+                    })
+                    .collect();
+                let num_outputs = num_outputs.to_tokens();
+
+                // The sizes are computed explicitly since `chunk` returns fewer chunks when the
+                // size isn't large enough.
+                (
+                    quote! {
+                        let input = #input;
+                        let size = input.dims()[#axis];
+                        let chunk_size = size.div_ceil(#num_outputs);
+                    },
+                    values,
+                )
+            }
+        };
+
+        let values = match values.len() {
+            1 => quote! { #(#values)* },
+            _ => quote! { (#(#values),*) },
+        };
+
+        Ok(quote! {
+            let #outputs = {
+                #split
+
+                #values
+            };
+        })
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Split(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{split::SplitNode, test::assert_tokens},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_split_sizes() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(SplitNode::new(
+            TensorType::new_float("tensor1", 2),
+            vec![
+                TensorType::new_float("tensor2", 2),
+                TensorType::new_float("tensor3", 2),
+            ],
+            1,
+            Some(vec![2, 3]),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string(), "tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>) -> (Tensor<B, 2>, Tensor<B, 2>) {
+                    let (tensor2, tensor3) = {
+                        let input = tensor1;
+
+                        (
+                            input.clone().narrow(1, 0usize, 2usize),
+                            input.narrow(1, 2usize, 3usize)
+                        )
+                    };
+
+                    (tensor2, tensor3)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_split_chunks() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(SplitNode::new(
+            TensorType::new_float("tensor1", 2),
+            vec![
+                TensorType::new_float("tensor2", 2),
+                TensorType::new_float("tensor3", 2),
+            ],
+            0,
+            None,
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string(), "tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>) -> (Tensor<B, 2>, Tensor<B, 2>) {
+                    let (tensor2, tensor3) = {
+                        let input = tensor1;
+                        let size = input.dims()[0];
+                        let chunk_size = size.div_ceil(2);
+
+                        (
+                            input.clone().narrow(0, 0, chunk_size),
+                            input.narrow(0, chunk_size, size.saturating_sub(chunk_size))
+                        )
+                    };
+
+                    (tensor2, tensor3)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_split_uneven_chunks() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(SplitNode::new(
+            TensorType::new_float("tensor1", 2),
+            vec![
+                TensorType::new_float("tensor2", 2),
+                TensorType::new_float("tensor3", 2),
+                TensorType::new_float("tensor4", 2),
+            ],
+            1,
+            None,
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string()],
+            vec![
+                "tensor2".to_string(),
+                "tensor3".to_string(),
+                "tensor4".to_string(),
+            ],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 2>
+                ) -> (Tensor<B, 2>, Tensor<B, 2>, Tensor<B, 2>) {
+                    let (tensor2, tensor3, tensor4) = {
+                        let input = tensor1;
+                        let size = input.dims()[1];
+                        let chunk_size = size.div_ceil(3);
+
+                        (
+                            input.clone().narrow(1, 0, chunk_size),
+                            input.clone().narrow(1, chunk_size, chunk_size),
+                            input.narrow(1, 2 * chunk_size, size.saturating_sub(2 * chunk_size))
+                        )
+                    };
+
+                    (tensor2, tensor3, tensor4)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
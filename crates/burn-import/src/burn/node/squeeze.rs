@@ -1,5 +1,5 @@
 use super::{Node, NodeCodegen};
-use crate::burn::{Scope, TensorType, ToTokens, Type};
+use crate::burn::{Scope, ScopeError, TensorType, ToTokens, Type};
 use burn::record::PrecisionSettings;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -20,15 +20,15 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for SqueezeNode {
         vec![Type::Tensor(self.input.clone())]
     }
 
-    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
-        let input = scope.tensor_use_owned(&self.input, node_position);
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
+        let input = scope.tensor_use_owned(&self.input, node_position)?;
         let output = &self.output.name;
 
         let axes_arg = &self.axes.to_tokens();
 
-        quote! {
+        Ok(quote! {
             let #output = #input.squeeze_dims(&#axes_arg);
-        }
+        })
     }
 
     fn into_node(self) -> Node<PS> {
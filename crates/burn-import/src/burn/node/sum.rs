@@ -1,5 +1,5 @@
 use super::{Node, NodeCodegen};
-use crate::burn::{Scope, TensorType, Type};
+use crate::burn::{Scope, ScopeError, TensorType, Type};
 
 use burn::record::PrecisionSettings;
 use proc_macro2::TokenStream;
@@ -23,17 +23,18 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for SumNode {
             .collect()
     }
 
-    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
         let inputs = self
             .inputs
             .iter()
-            .map(|t| scope.tensor_use_owned(t, node_position));
+            .map(|t| scope.tensor_use_owned(t, node_position))
+            .collect::<Result<Vec<_>, _>>()?;
 
         let output = &self.output.name;
 
-        quote! {
+        Ok(quote! {
             let #output = #(#inputs)+*;
-        }
+        })
     }
 
     fn into_node(self) -> Node<PS> {
@@ -0,0 +1,101 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, ScopeError, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// The upper or lower triangular part of the last two dimensions of the input, the other
+/// elements being zero.
+///
+/// The diagonal offset selects the diagonal bounding the kept part, the positive offsets being
+/// above the main diagonal and the negative ones below it.
+#[derive(Debug, Clone, new)]
+pub struct TriluNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub upper: bool,
+    pub diagonal: i64,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for TriluNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
+        let input = scope.tensor_use_owned(&self.input, node_position)?;
+        let output = &self.output.name;
+        let diagonal = self.diagonal.to_tokens();
+
+        Ok(if self.upper {
+            quote! {
+                let #output = #input.triu(#diagonal);
+            }
+        } else {
+            quote! {
+                let #output = #input.tril(#diagonal);
+            }
+        })
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Trilu(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+
+    #[test]
+    fn test_codegen_trilu_lower() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(TriluNode::new(
+            TensorType::new_float("tensor1", 3),
+            TensorType::new_float("tensor2", 3),
+            false,
+            -1,
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 3>) -> Tensor<B, 3> {
+                    let tensor2 = tensor1.tril(-1);
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
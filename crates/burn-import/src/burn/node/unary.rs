@@ -1,5 +1,5 @@
 use super::{Node, NodeCodegen};
-use crate::burn::{BurnImports, Scope, TensorKind, ToTokens, Type};
+use crate::burn::{BurnImports, ScalarKind, Scope, ScopeError, TensorKind, ToTokens, Type};
 use burn::record::PrecisionSettings;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -8,6 +8,29 @@ use std::rc::Rc;
 // Simple fn pointer that receive input as a token stream and return function call.
 type FnPointer = Rc<dyn Fn(TokenStream) -> TokenStream>;
 
+/// Apply the activation along the given dimension, optionally coercing the input to 2D, see
+/// [softmax](UnaryNode::softmax).
+fn along_dim(activation: TokenStream, dim: usize, coerce_2d: bool) -> FnPointer {
+    let dim = dim.to_tokens();
+
+    if coerce_2d {
+        Rc::new(move |input| {
+            quote! {
+                {
+                    let input = #input;
+                    let dims = input.dims();
+                    let outer = dims[..#dim].iter().product::<usize>();
+                    let inner = dims[#dim..].iter().product::<usize>();
+
+                    #activation(input.reshape([outer, inner]), 1).reshape(dims)
+                }
+            }
+        })
+    } else {
+        Rc::new(move |input| quote! { #activation(#input, #dim) })
+    }
+}
+
 /// Node for all unary operators.
 #[derive(Clone, new)]
 pub struct UnaryNode {
@@ -21,26 +44,38 @@ pub struct UnaryNode {
 #[derive(Clone)]
 pub enum UnaryNodeKind {
     // Input and output tensor types (required for codegen imports)
+    Abs,
     Cast(Option<TensorKind>, Option<TensorKind>),
+    Ceil,
     Cos,
     Erf,
     Exp,
     Flatten,
+    Floor,
     Gelu,
+    HardSigmoid,
+    HardSwish,
+    Identity,
     LeakyRelu,
     Log,
     LogSoftmax,
+    MeanVarianceNormalization,
     Neg,
+    NonZero,
     Not,
     ReduceMax,
     ReduceMean,
+    ReduceMin,
     ReduceSum,
     Reciprocal,
     Relu,
+    Round,
     Shape,
     Sigmoid,
     Sin,
     Softmax,
+    Softplus,
+    Softsign,
     Sqrt,
     Tanh,
     Transpose,
@@ -50,26 +85,38 @@ pub enum UnaryNodeKind {
 impl UnaryNodeKind {
     pub fn as_str(&self) -> &str {
         match self {
+            Self::Abs => "abs",
             Self::Cast(..) => "cast",
+            Self::Ceil => "ceil",
             Self::Cos => "cos",
             Self::Erf => "erf",
             Self::Exp => "exp",
             Self::Flatten => "flatten",
+            Self::Floor => "floor",
             Self::Gelu => "gelu",
+            Self::HardSigmoid => "hard_sigmoid",
+            Self::HardSwish => "hard_swish",
+            Self::Identity => "identity",
             Self::LeakyRelu => "leaky_relu",
             Self::Log => "log",
             Self::LogSoftmax => "log_softmax",
+            Self::MeanVarianceNormalization => "mean_variance_normalization",
             Self::Neg => "neg",
+            Self::NonZero => "nonzero",
             Self::Not => "not",
             Self::ReduceMax => "reduce_max",
             Self::ReduceMean => "reduce_mean",
+            Self::ReduceMin => "reduce_min",
             Self::ReduceSum => "reduce_sum",
             Self::Reciprocal => "reciprocal",
             Self::Relu => "relu",
+            Self::Round => "round",
             Self::Shape => "shape",
             Self::Sigmoid => "sigmoid",
             Self::Sin => "sin",
             Self::Softmax => "softmax",
+            Self::Softplus => "softplus",
+            Self::Softsign => "softsign",
             Self::Sqrt => "sqrt",
             Self::Tanh => "tanh",
             Self::Transpose => "transpose",
@@ -101,11 +148,14 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for UnaryNode {
         vec![self.input.clone()]
     }
 
-    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
         // Get the lhs name in the form of token stream.
-        let input = match &self.input {
-            Type::Tensor(tensor) => scope.tensor_use_owned(tensor, node_position),
-            Type::Scalar(scalar) => {
+        let input = match (&self.input, &self.output) {
+            (Type::Tensor(tensor), Type::Tensor(output)) => {
+                scope.tensor_use_inplace(tensor, output, node_position)?
+            }
+            (Type::Tensor(tensor), _) => scope.tensor_use_owned(tensor, node_position)?,
+            (Type::Scalar(scalar), _) => {
                 let name = scalar.name.clone();
                 quote! { #name }
             }
@@ -116,9 +166,9 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for UnaryNode {
         let output = &self.output.name();
         let function = (self.function)(input);
 
-        quote! {
+        Ok(quote! {
             let #output = #function;
-        }
+        })
     }
 
     fn into_node(self) -> Node<PS> {
@@ -137,6 +187,12 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for UnaryNode {
             UnaryNodeKind::Not => {
                 imports.register("burn::tensor::Bool");
             }
+            UnaryNodeKind::NonZero => {
+                if matches!(&self.input, Type::Tensor(tensor) if tensor.kind == TensorKind::Bool) {
+                    imports.register("burn::tensor::Bool");
+                }
+                imports.register("burn::tensor::Int");
+            }
             UnaryNodeKind::Cast(Some(input_kind), Some(output_kind)) => {
                 if input_kind == TensorKind::Bool || output_kind == TensorKind::Bool {
                     imports.register("burn::tensor::Bool");
@@ -156,12 +212,40 @@ impl UnaryNode {
         Self::new(input, output, UnaryNodeKind::Erf, Rc::new(function))
     }
 
-    pub(crate) fn flatten(input: Type, output: Type, start_dim: usize, end_dim: usize) -> Self {
-        let start_dim = start_dim.to_tokens();
-        let end_dim = end_dim.to_tokens();
-        let function = move |input| quote! { #input.flatten(#start_dim, #end_dim) };
+    /// Flatten the input into a matrix, the dimensions before `axis` being collapsed into the
+    /// rows and the others into the columns. An axis of 0 produces a single row and an axis
+    /// equal to the rank a single column.
+    pub(crate) fn flatten(input: Type, output: Type, axis: usize) -> Self {
+        let rank = match &input {
+            Type::Tensor(tensor) => tensor.dim,
+            _ => panic!("Flatten: the input must be a tensor"),
+        };
+        let last = (rank - 1).to_tokens();
+
+        let function: FnPointer = if axis == 0 {
+            Rc::new(move |input| quote! { #input.flatten::<1>(0, #last).unsqueeze::<2>() })
+        } else if axis == rank {
+            Rc::new(move |input| quote! { #input.flatten::<1>(0, #last).unsqueeze_dim::<2>(1) })
+        } else if axis == 1 {
+            Rc::new(move |input| quote! { #input.flatten::<2>(1, #last) })
+        } else {
+            // The rows are collapsed first, leaving the columns from the second dimension
+            let rows_end = (axis - 1).to_tokens();
+            let rows_rank = (rank - axis + 1).to_tokens();
+            let cols_end = (rank - axis).to_tokens();
+
+            Rc::new(move |input| {
+                quote! { #input.flatten::<#rows_rank>(0, #rows_end).flatten::<2>(1, #cols_end) }
+            })
+        };
 
-        Self::new(input, output, UnaryNodeKind::Flatten, Rc::new(function))
+        Self::new(input, output, UnaryNodeKind::Flatten, function)
+    }
+
+    /// Forward the input as is, e.g. a Dropout node imported for inference.
+    pub(crate) fn identity(input: Type, output: Type) -> Self {
+        let function = |input: TokenStream| input;
+        Self::new(input, output, UnaryNodeKind::Identity, Rc::new(function))
     }
 
     pub(crate) fn relu(input: Type, output: Type) -> Self {
@@ -172,7 +256,34 @@ impl UnaryNode {
     pub(crate) fn leaky_relu(input: Type, output: Type, alpha: f64) -> Self {
         let alpha = alpha.to_tokens();
         let function = move |input| quote! { burn::tensor::activation::leaky_relu(#input, #alpha) };
-        Self::new(input, output, UnaryNodeKind::Relu, Rc::new(function))
+        Self::new(input, output, UnaryNodeKind::LeakyRelu, Rc::new(function))
+    }
+
+    /// Piecewise linear approximation of the sigmoid, `max(0, min(1, alpha * x + beta))`.
+    pub(crate) fn hard_sigmoid(input: Type, output: Type, alpha: f64, beta: f64) -> Self {
+        let alpha = alpha.to_tokens();
+        let beta = beta.to_tokens();
+        let function = move |input| {
+            quote! { #input.mul_scalar(#alpha).add_scalar(#beta).clamp(0.0, 1.0) }
+        };
+        Self::new(input, output, UnaryNodeKind::HardSigmoid, Rc::new(function))
+    }
+
+    /// `x * hard_sigmoid(x)` with `alpha = 1/6` and `beta = 0.5`.
+    ///
+    /// The input is bound once in a block since it's used twice.
+    pub(crate) fn hard_swish(input: Type, output: Type) -> Self {
+        let alpha = (1.0f64 / 6.0).to_tokens();
+        let function = move |input| {
+            quote! {
+                {
+                    let input = #input;
+
+                    input.clone().mul(input.mul_scalar(#alpha).add_scalar(0.5).clamp(0.0, 1.0))
+                }
+            }
+        };
+        Self::new(input, output, UnaryNodeKind::HardSwish, Rc::new(function))
     }
 
     pub(crate) fn sigmoid(input: Type, output: Type) -> Self {
@@ -180,16 +291,80 @@ impl UnaryNode {
         Self::new(input, output, UnaryNodeKind::Sigmoid, Rc::new(function))
     }
 
-    pub(crate) fn log_softmax(input: Type, output: Type, dim: usize) -> Self {
-        let dim = dim.to_tokens();
-        let function = move |input| quote! { burn::tensor::activation::log_softmax(#input, #dim) };
-        Self::new(input, output, UnaryNodeKind::LogSoftmax, Rc::new(function))
+    /// `log(exp(x) + 1)`, i.e. the softplus with a `beta` of 1.
+    pub(crate) fn softplus(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { burn::tensor::activation::softplus(#input, 1.0) };
+        Self::new(input, output, UnaryNodeKind::Softplus, Rc::new(function))
+    }
+
+    /// `x / (1 + |x|)`.
+    ///
+    /// The input is bound once in a block since it's used twice.
+    pub(crate) fn softsign(input: Type, output: Type) -> Self {
+        let function = move |input| {
+            quote! {
+                {
+                    let input = #input;
+
+                    input.clone().div(input.abs().add_scalar(1.0))
+                }
+            }
+        };
+        Self::new(input, output, UnaryNodeKind::Softsign, Rc::new(function))
     }
 
-    pub(crate) fn softmax(input: Type, output: Type, dim: usize) -> Self {
-        let dim = dim.to_tokens();
-        let function = move |input| quote! { burn::tensor::activation::softmax(#input, #dim) };
-        Self::new(input, output, UnaryNodeKind::Softmax, Rc::new(function))
+    /// Log softmax along the given dimension, coerced to 2D as [softmax](UnaryNode::softmax).
+    ///
+    /// Burn's log softmax subtracts the maximum before the exponential, which doesn't overflow
+    /// for large inputs, unlike the log of the softmax.
+    pub(crate) fn log_softmax(input: Type, output: Type, dim: usize, coerce_2d: bool) -> Self {
+        let function = along_dim(
+            quote! { burn::tensor::activation::log_softmax },
+            dim,
+            coerce_2d,
+        );
+        Self::new(input, output, UnaryNodeKind::LogSoftmax, function)
+    }
+
+    /// Normalize the input to zero mean and unit variance over the given dimensions.
+    ///
+    /// As in ONNX, the epsilon is added to the standard deviation rather than to the variance, so
+    /// a constant slice is normalized to zeros.
+    pub(crate) fn mean_variance_normalization(input: Type, output: Type, dims: Vec<usize>) -> Self {
+        let mean = dims
+            .iter()
+            .map(|dim| {
+                let dim = dim.to_tokens();
+                quote! { .mean_dim(#dim) }
+            })
+            .collect::<Vec<_>>();
+        let function = move |input| {
+            quote! {
+                {
+                    let input = #input;
+                    let centered = input.clone() - input #(#mean)*;
+                    let std = centered.clone().powf_scalar(2.0) #(#mean)* .sqrt();
+
+                    centered / std.add_scalar(1e-9)
+                }
+            }
+        };
+        Self::new(
+            input,
+            output,
+            UnaryNodeKind::MeanVarianceNormalization,
+            Rc::new(function),
+        )
+    }
+
+    /// Softmax along the given dimension.
+    ///
+    /// When `coerce_2d` is set, the input is reshaped to 2D with the dimensions before `dim`
+    /// flattened into the first one and the others into the second one, the softmax is computed
+    /// along the second one and the result is reshaped back, as for ONNX models before opset 13.
+    pub(crate) fn softmax(input: Type, output: Type, dim: usize, coerce_2d: bool) -> Self {
+        let function = along_dim(quote! { burn::tensor::activation::softmax }, dim, coerce_2d);
+        Self::new(input, output, UnaryNodeKind::Softmax, function)
     }
 
     pub(crate) fn sqrt(input: Type, output: Type) -> Self {
@@ -202,9 +377,17 @@ impl UnaryNode {
         Self::new(input, output, UnaryNodeKind::Tanh, Rc::new(function))
     }
 
-    pub(crate) fn transpose(input: Type, output: Type, perm: Vec<i64>) -> Self {
+    pub(crate) fn transpose(input: Type, output: Type, perm: Vec<usize>) -> Self {
+        // The identity permutation is elided to avoid a no-op permute.
+        let identity = perm.iter().enumerate().all(|(dim, &axis)| dim == axis);
         let perm = perm.to_tokens();
-        let function = move |input| quote! { #input.permute(#perm) };
+        let function = move |input| {
+            if identity {
+                input
+            } else {
+                quote! { #input.permute(#perm) }
+            }
+        };
         Self::new(input, output, UnaryNodeKind::Transpose, Rc::new(function))
     }
 
@@ -249,6 +432,21 @@ impl UnaryNode {
         Self::new(input, output, UnaryNodeKind::Not, Rc::new(function))
     }
 
+    /// Coordinates of the non-zero elements of the input, one column per element like the
+    /// `NonZero` ONNX operator. The number of columns is only known at runtime.
+    pub(crate) fn nonzero(input: Type, output: Type) -> Self {
+        let function: FnPointer = match &input {
+            Type::Tensor(tensor) if tensor.kind == TensorKind::Bool => {
+                Rc::new(move |input| quote! { #input.argwhere().transpose() })
+            }
+            Type::Tensor(_) => {
+                Rc::new(move |input| quote! { #input.not_equal_elem(0).argwhere().transpose() })
+            }
+            _ => panic!("NonZero: the input must be a tensor"),
+        };
+        Self::new(input, output, UnaryNodeKind::NonZero, function)
+    }
+
     /// Casts the input to the output type.
     pub(crate) fn cast(input: Type, output: Type) -> Self {
         match (input.clone(), output.clone()) {
@@ -264,12 +462,20 @@ impl UnaryNode {
                 } else {
                     // If the input and output types are different, we need to cast.
                     let ty = output_scalar.ty();
-                    Self::new(
-                        input,
-                        output,
-                        UnaryNodeKind::Cast(None, None),
-                        Rc::new(move |input| quote! { #input as #ty }),
-                    )
+                    let input_ty = input_scalar.ty();
+                    // Primitive casts from bool are only defined for integers, and there are no
+                    // casts to bool.
+                    let function: FnPointer = match (&input_scalar.kind, &output_scalar.kind) {
+                        (_, ScalarKind::Bool) => {
+                            Rc::new(move |input| quote! { #input != 0 as #input_ty })
+                        }
+                        (ScalarKind::Bool, ScalarKind::Float32 | ScalarKind::Float64) => {
+                            Rc::new(move |input| quote! { #input as u8 as #ty })
+                        }
+                        _ => Rc::new(move |input| quote! { #input as #ty }),
+                    };
+
+                    Self::new(input, output, UnaryNodeKind::Cast(None, None), function)
                 }
             }
             (Type::Tensor(input_tensor), Type::Tensor(output_tensor)) => {
@@ -301,90 +507,72 @@ impl UnaryNode {
         }
     }
 
-    pub(crate) fn reduce_max(input: Type, output: Type, dim: Option<usize>) -> Self {
-        if let Type::Tensor(ref tensor) = output {
-            if let Some(dim) = dim {
-                if tensor.kind == TensorKind::Bool {
-                    // Max is only implemented on numeric tensors
-                    panic!("ReduceMax is not supported for boolean");
-                }
+    pub(crate) fn reduce_max(input: Type, output: Type, dims: Option<Vec<usize>>) -> Self {
+        Self::reduce(input, output, UnaryNodeKind::ReduceMax, dims)
+    }
 
-                // ReduceMax, keepdims=1, axes=[dim]
-                let dim = dim.to_tokens();
-                Self::new(
-                    input,
-                    output,
-                    UnaryNodeKind::ReduceMax,
-                    Rc::new(move |input| quote! { #input.max_dim(#dim) }),
-                )
-            } else {
-                // ReduceMax, keepdims=0, axes=None
-                Self::new(
-                    input,
-                    output,
-                    UnaryNodeKind::ReduceMax,
-                    Rc::new(move |input| quote! { #input.max() }),
-                )
-            }
-        } else {
-            panic!("ReduceMax only supports tensor output");
-        }
+    pub(crate) fn reduce_mean(input: Type, output: Type, dims: Option<Vec<usize>>) -> Self {
+        Self::reduce(input, output, UnaryNodeKind::ReduceMean, dims)
     }
 
-    pub(crate) fn reduce_mean(input: Type, output: Type, dim: Option<usize>) -> Self {
-        // ReduceMean is constrained to numeric tensors, so no need to check for bool.
-        if let Type::Tensor(_) = output {
-            if let Some(dim) = dim {
-                // ReduceMean, keepdims=1, axes=[dim]
-                let dim = dim.to_tokens();
-                Self::new(
-                    input,
-                    output,
-                    UnaryNodeKind::ReduceMean,
-                    Rc::new(move |input| quote! { #input.mean_dim(#dim) }),
-                )
-            } else {
-                // ReduceMean, keepdims=0, axes=None
-                Self::new(
-                    input,
-                    output,
-                    UnaryNodeKind::ReduceMean,
-                    Rc::new(move |input| quote! { #input.mean() }),
-                )
-            }
-        } else {
-            panic!("ReduceMean only supports tensor output");
-        }
+    pub(crate) fn reduce_min(input: Type, output: Type, dims: Option<Vec<usize>>) -> Self {
+        Self::reduce(input, output, UnaryNodeKind::ReduceMin, dims)
     }
 
-    pub(crate) fn reduce_sum(input: Type, output: Type, dim: Option<usize>) -> Self {
-        if let Type::Tensor(ref tensor) = output {
-            if let Some(dim) = dim {
-                if tensor.kind == TensorKind::Bool {
-                    // Sum is only implemented on numeric tensors
-                    panic!("ReduceSum is not supported for boolean");
+    pub(crate) fn reduce_sum(input: Type, output: Type, dims: Option<Vec<usize>>) -> Self {
+        Self::reduce(input, output, UnaryNodeKind::ReduceSum, dims)
+    }
+
+    /// Reduce the input over the given dimensions, or over all of them with `None`.
+    ///
+    /// The reduced dimensions are removed when the output has a lower rank than the input
+    /// (`keepdims=0`). Reducing over all dimensions returns a tensor of rank 1 since Burn doesn't
+    /// have 0-dim tensor.
+    fn reduce(input: Type, output: Type, kind: UnaryNodeKind, dims: Option<Vec<usize>>) -> Self {
+        let (input_dim, output_dim) = match (&input, &output) {
+            (Type::Tensor(input), Type::Tensor(output)) => {
+                if output.kind == TensorKind::Bool {
+                    // Reductions are only implemented on numeric tensors
+                    panic!("{} is not supported for boolean", kind.as_str());
                 }
+                (input.dim, output.dim)
+            }
+            _ => panic!("{} only supports tensor input and output", kind.as_str()),
+        };
 
-                // ReduceSum, keepdims=1, axes=[dim]
-                let dim = dim.to_tokens();
-                Self::new(
-                    input,
-                    output,
-                    UnaryNodeKind::ReduceSum,
-                    Rc::new(move |input| quote! { #input.sum_dim(#dim) }),
-                )
-            } else {
-                // ReduceSum, keepdims=0, axes=None
-                Self::new(
-                    input,
-                    output,
-                    UnaryNodeKind::ReduceSum,
-                    Rc::new(move |input| quote! { #input.sum() }),
-                )
+        let (method, method_dim) = match kind {
+            UnaryNodeKind::ReduceMax => (quote! { max }, quote! { max_dim }),
+            UnaryNodeKind::ReduceMean => (quote! { mean }, quote! { mean_dim }),
+            UnaryNodeKind::ReduceMin => (quote! { min }, quote! { min_dim }),
+            UnaryNodeKind::ReduceSum => (quote! { sum }, quote! { sum_dim }),
+            _ => panic!("{} isn't a reduce operation", kind.as_str()),
+        };
+
+        let reduction = match dims {
+            Some(dims) => {
+                let reduced = dims.iter().map(|dim| {
+                    let dim = dim.to_tokens();
+                    quote! { .#method_dim(#dim) }
+                });
+                let squeeze = if output_dim < input_dim {
+                    let dims = dims.iter().map(|dim| dim.to_tokens());
+                    let output_dim = output_dim.to_tokens();
+                    quote! { .squeeze_dims::<#output_dim>(&[#(#dims),*]) }
+                } else {
+                    quote! {}
+                };
+
+                quote! { #(#reduced)* #squeeze }
             }
-        } else {
-            panic!("ReduceSum only supports tensor output");
-        }
+            None => quote! { .#method() },
+        };
+
+        Self::new(
+            input,
+            output,
+            kind,
+            Rc::new(move |input| quote! { #input #reduction }),
+        )
     }
 
     pub(crate) fn shape(input: Type, output: Type, start_dim: usize, end_dim: usize) -> Self {
@@ -407,6 +595,27 @@ impl UnaryNode {
         let function = move |input| quote! { #input.sign()};
         Self::new(input, output, UnaryNodeKind::Sign, Rc::new(function))
     }
+
+    pub(crate) fn abs(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { #input.abs()};
+        Self::new(input, output, UnaryNodeKind::Abs, Rc::new(function))
+    }
+
+    pub(crate) fn floor(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { #input.floor()};
+        Self::new(input, output, UnaryNodeKind::Floor, Rc::new(function))
+    }
+
+    pub(crate) fn ceil(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { #input.ceil()};
+        Self::new(input, output, UnaryNodeKind::Ceil, Rc::new(function))
+    }
+
+    /// Rounds halfway cases to the nearest even integer, like the `Round` ONNX operator.
+    pub(crate) fn round(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { #input.round()};
+        Self::new(input, output, UnaryNodeKind::Round, Rc::new(function))
+    }
 }
 
 #[cfg(test)]
@@ -420,13 +629,48 @@ mod tests {
         one_node_graph(
             UnaryNode::flatten(
                 Type::Tensor(TensorType::new_float("tensor1", 4)),
-                Type::Tensor(TensorType::new_float("tensor2", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 2)),
                 1,
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 2> {
+                    let tensor2 = tensor1.flatten::<2>(1, 3);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+        one_node_graph(
+            UnaryNode::flatten(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 2)),
                 2,
             ),
             quote! {
-                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
-                    let tensor2 = tensor1.flatten(1, 2);
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 2> {
+                    let tensor2 = tensor1.flatten::<3>(0, 1).flatten::<2>(1, 2);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_flatten_axis_0() {
+        one_node_graph(
+            UnaryNode::flatten(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 2)),
+                0,
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 2> {
+                    let tensor2 = tensor1.flatten::<1>(0, 3).unsqueeze::<2>();
 
                     tensor2
                 }
@@ -474,6 +718,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_unary_codegen_identity() {
+        one_node_graph(
+            UnaryNode::identity(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1;
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
     #[test]
     fn test_unary_codegen_leaky_relu() {
         one_node_graph(
@@ -494,6 +757,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_unary_codegen_hard_sigmoid() {
+        one_node_graph(
+            UnaryNode::hard_sigmoid(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+                0.2,
+                0.5,
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.mul_scalar(0.2).add_scalar(0.5).clamp(0.0, 1.0);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_hard_swish() {
+        one_node_graph(
+            UnaryNode::hard_swish(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = {
+                        let input = tensor1;
+
+                        input
+                            .clone()
+                            .mul(input.mul_scalar(0.16666666666666666).add_scalar(0.5).clamp(0.0, 1.0))
+                    };
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
     #[test]
     fn test_unary_codegen_sigmoid() {
         one_node_graph(
@@ -513,6 +822,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_unary_codegen_softplus() {
+        one_node_graph(
+            UnaryNode::softplus(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = burn::tensor::activation::softplus(tensor1, 1.0);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_softsign() {
+        one_node_graph(
+            UnaryNode::softsign(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = {
+                        let input = tensor1;
+
+                        input.clone().div(input.abs().add_scalar(1.0))
+                    };
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
     #[test]
     fn test_unary_codegen_log_softmax() {
         one_node_graph(
@@ -520,6 +871,7 @@ mod tests {
                 Type::Tensor(TensorType::new_float("tensor1", 4)),
                 Type::Tensor(TensorType::new_float("tensor2", 4)),
                 1,
+                false,
             ),
             quote! {
                 pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
@@ -533,6 +885,68 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_unary_codegen_mean_variance_normalization() {
+        one_node_graph(
+            UnaryNode::mean_variance_normalization(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+                vec![0, 2, 3],
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = {
+                        let input = tensor1;
+                        let centered =
+                            input.clone() - input.mean_dim(0).mean_dim(2).mean_dim(3);
+                        let std = centered
+                            .clone()
+                            .powf_scalar(2.0)
+                            .mean_dim(0)
+                            .mean_dim(2)
+                            .mean_dim(3)
+                            .sqrt();
+
+                        centered / std.add_scalar(1e-9)
+                    };
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_log_softmax_coerce_2d() {
+        one_node_graph(
+            UnaryNode::log_softmax(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+                2,
+                true,
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = {
+                        let input = tensor1;
+                        let dims = input.dims();
+                        let outer = dims[..2].iter().product::<usize>();
+                        let inner = dims[2..].iter().product::<usize>();
+
+                        burn::tensor::activation::log_softmax(input.reshape([outer, inner]), 1)
+                            .reshape(dims)
+                    };
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
     #[test]
     fn test_unary_codegen_softmax() {
         one_node_graph(
@@ -540,6 +954,7 @@ mod tests {
                 Type::Tensor(TensorType::new_float("tensor1", 4)),
                 Type::Tensor(TensorType::new_float("tensor2", 4)),
                 1,
+                false,
             ),
             quote! {
                 pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
@@ -553,6 +968,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_unary_codegen_softmax_coerce_2d() {
+        one_node_graph(
+            UnaryNode::softmax(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+                2,
+                true,
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = {
+                        let input = tensor1;
+                        let dims = input.dims();
+                        let outer = dims[..2].iter().product::<usize>();
+                        let inner = dims[2..].iter().product::<usize>();
+
+                        burn::tensor::activation::softmax(input.reshape([outer, inner]), 1)
+                            .reshape(dims)
+                    };
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
     #[test]
     fn test_unary_codegen_tanh() {
         one_node_graph(
@@ -592,13 +1036,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_unary_codegen_transpose_identity() {
+        one_node_graph(
+            UnaryNode::transpose(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+                vec![0, 1, 2, 3],
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1;
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
     #[test]
     fn test_unary_codegen_reduce_max() {
         one_node_graph(
             UnaryNode::reduce_max(
                 Type::Tensor(TensorType::new_float("tensor1", 4)),
                 Type::Tensor(TensorType::new_float("tensor2", 4)),
-                Some(1),
+                Some(vec![1]),
             ),
             quote! {
                 pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
@@ -635,7 +1099,7 @@ mod tests {
             UnaryNode::reduce_mean(
                 Type::Tensor(TensorType::new_float("tensor1", 4)),
                 Type::Tensor(TensorType::new_float("tensor2", 4)),
-                Some(1),
+                Some(vec![1]),
             ),
             quote! {
                 pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
@@ -666,13 +1130,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_unary_codegen_reduce_min() {
+        one_node_graph(
+            UnaryNode::reduce_min(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 2)),
+                Some(vec![1, 3]),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 2> {
+                    let tensor2 = tensor1.min_dim(1).min_dim(3).squeeze_dims::<2>(&[1, 3]);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+
+        one_node_graph(
+            UnaryNode::reduce_min(
+                Type::Tensor(TensorType::new_float("tensor1", 2)),
+                Type::Tensor(TensorType::new_float("tensor2", 2)),
+                Some(vec![0, 1]),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 2>) -> Tensor<B, 2> {
+                    let tensor2 = tensor1.min_dim(0).min_dim(1);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
     #[test]
     fn test_unary_codegen_reduce_sum() {
         one_node_graph(
             UnaryNode::reduce_sum(
                 Type::Tensor(TensorType::new_float("tensor1", 4)),
                 Type::Tensor(TensorType::new_float("tensor2", 4)),
-                Some(1),
+                Some(vec![1]),
             ),
             quote! {
                 pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
@@ -801,6 +1302,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_unary_codegen_cast_scalar_bool() {
+        one_node_graph(
+            UnaryNode::cast(
+                Type::Scalar(ScalarType::new("scalar1", ScalarKind::Float32)),
+                Type::Scalar(ScalarType::new("scalar2", ScalarKind::Bool)),
+            ),
+            quote! {
+                pub fn forward(&self, scalar1: f32) -> bool {
+                    let scalar2 = scalar1 != 0 as f32;
+
+                    scalar2
+                }
+            },
+            vec!["scalar1".to_string()],
+            vec!["scalar2".to_string()],
+        );
+        one_node_graph(
+            UnaryNode::cast(
+                Type::Scalar(ScalarType::new("scalar1", ScalarKind::Bool)),
+                Type::Scalar(ScalarType::new("scalar2", ScalarKind::Float64)),
+            ),
+            quote! {
+                pub fn forward(&self, scalar1: bool) -> f64 {
+                    let scalar2 = scalar1 as u8 as f64;
+
+                    scalar2
+                }
+            },
+            vec!["scalar1".to_string()],
+            vec!["scalar2".to_string()],
+        );
+    }
+
     #[test]
     fn test_unary_codegen_cos() {
         one_node_graph(
@@ -953,6 +1488,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_unary_codegen_nonzero() {
+        one_node_graph(
+            UnaryNode::nonzero(
+                Type::Tensor(TensorType::new_bool("tensor1", 3)),
+                Type::Tensor(TensorType::new_int("tensor2", 2)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 3, Bool>) -> Tensor<B, 2, Int> {
+                    let tensor2 = tensor1.argwhere().transpose();
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+        one_node_graph(
+            UnaryNode::nonzero(
+                Type::Tensor(TensorType::new_float("tensor1", 3)),
+                Type::Tensor(TensorType::new_int("tensor2", 2)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 3>) -> Tensor<B, 2, Int> {
+                    let tensor2 = tensor1.not_equal_elem(0).argwhere().transpose();
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
     #[test]
     fn test_unary_codegen_shape() {
         one_node_graph(
@@ -997,4 +1566,80 @@ mod tests {
             vec!["tensor2".to_string()],
         );
     }
+
+    #[test]
+    fn test_unary_codegen_abs() {
+        one_node_graph(
+            UnaryNode::abs(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.abs();
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_floor() {
+        one_node_graph(
+            UnaryNode::floor(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.floor();
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_ceil() {
+        one_node_graph(
+            UnaryNode::ceil(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.ceil();
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_round() {
+        one_node_graph(
+            UnaryNode::round(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.round();
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
 }
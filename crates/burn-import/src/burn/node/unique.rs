@@ -0,0 +1,241 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{BurnImports, Scope, ScopeError, TensorKind, TensorType, Type};
+
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// The unique values of the flattened input, with the optional outputs of their first index in
+/// the input, of the index of each input value among the unique values and of their counts.
+///
+/// The outputs are sized by the number of unique values, known at runtime only, so the values are
+/// read back from the device and compared as `f64`.
+#[derive(Debug, Clone, new)]
+pub struct UniqueNode {
+    pub input: TensorType,
+    pub values: TensorType,
+    pub indices: Option<TensorType>,
+    pub inverse_indices: Option<TensorType>,
+    pub counts: Option<TensorType>,
+    /// Whether the unique values are sorted, instead of being in the order of their first
+    /// occurrence.
+    pub sorted: bool,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for UniqueNode {
+    fn output_types(&self) -> Vec<Type> {
+        [
+            Some(&self.values),
+            self.indices.as_ref(),
+            self.inverse_indices.as_ref(),
+            self.counts.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|output| Type::Tensor(output.clone()))
+        .collect()
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
+        let input_name = &self.input.name;
+        let input = scope.tensor_use_owned(&self.input, node_position)?;
+        let values = match self.values.kind {
+            TensorKind::Float => quote! { Tensor::<B, 1> },
+            TensorKind::Int => quote! { Tensor::<B, 1, Int> },
+            TensorKind::Bool => panic!("Unique: bool input is not supported"),
+        };
+
+        // The groups of equal values are in sorted order, they are reordered by first occurrence.
+        let order = match self.sorted {
+            true => quote! {},
+            false => {
+                let inverse = self.inverse_indices.as_ref().map(|_| {
+                    quote! {
+                        let mut ranks = vec![0; groups.len()];
+                        groups
+                            .iter()
+                            .enumerate()
+                            .for_each(|(rank, group)| ranks[*group] = rank as i64);
+                        let inverse = inverse
+                            .into_iter()
+                            .map(|group| ranks[group as usize])
+                            .collect::<Vec<_>>();
+                    }
+                });
+                let counts = self.counts.as_ref().map(|_| {
+                    quote! {
+                        let counts = groups.iter().map(|group| counts[*group]).collect::<Vec<_>>();
+                    }
+                });
+
+                quote! {
+                    let mut groups = (0..first.len()).collect::<Vec<_>>();
+                    groups.sort_by_key(|group| first[*group]);
+                    #inverse
+                    #counts
+                    let first = groups.iter().map(|group| first[*group]).collect::<Vec<_>>();
+                }
+            }
+        };
+
+        let first = quote! {
+            first.iter().map(|position| data[*position]).collect::<Vec<_>>()
+        };
+        let mut names = vec![&self.values.name];
+        let mut results = vec![quote! {
+            #values::from_data(Data::from(#first.as_slice()).convert(), &device)
+        }];
+        let int_outputs = [
+            (
+                &self.indices,
+                quote! { first.iter().map(|position| *position as i64).collect::<Vec<_>>() },
+            ),
+            (&self.inverse_indices, quote! { inverse }),
+            (&self.counts, quote! { counts }),
+        ];
+        for (output, value) in int_outputs {
+            if let Some(output) = output {
+                names.push(&output.name);
+                results.push(quote! {
+                    Tensor::<B, 1, Int>::from_data(Data::from(#value.as_slice()).convert(), &device)
+                });
+            }
+        }
+
+        let (names, results) = match names.len() {
+            1 => (quote! { #(#names)* }, quote! { #(#results)* }),
+            _ => (quote! { (#(#names),*) }, quote! { (#(#results),*) }),
+        };
+
+        Ok(quote! {
+            let #names = {
+                let device = #input_name.device();
+                let data = #input.into_data().convert::<f64>().value;
+
+                // The positions of the values sorted by value, equal values by position
+                let mut order = (0..data.len()).collect::<Vec<_>>();
+                order.sort_by(|a, b| data[*a].total_cmp(&data[*b]).then(a.cmp(b)));
+
+                // The first position and the count of each group of equal values
+                let mut first = Vec::new();
+                let mut counts = Vec::new();
+                let mut inverse = vec![0i64; data.len()];
+                for (index, position) in order.iter().enumerate() {
+                    if index == 0 || data[order[index - 1]].total_cmp(&data[*position]).is_ne() {
+                        first.push(*position);
+                        counts.push(0i64);
+                    }
+                    inverse[*position] = first.len() as i64 - 1;
+                    *counts.last_mut().unwrap() += 1;
+                }
+                #order
+
+                #results
+            };
+        })
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("alloc::vec");
+        imports.register("alloc::vec::Vec");
+        imports.register("burn::tensor::Data");
+        imports.register("burn::tensor::Int");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Unique(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+
+    #[test]
+    fn test_codegen_unique_counts() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(UniqueNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_float("tensor2", 1),
+            None,
+            None,
+            Some(TensorType::new_int("tensor3", 1)),
+            true,
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string(), "tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use alloc::vec;
+            use alloc::vec::Vec;
+            use burn::tensor::Data;
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>) -> (Tensor<B, 1>, Tensor<B, 1, Int>) {
+                    let (tensor2, tensor3) = {
+                        let device = tensor1.device();
+                        let data = tensor1.into_data().convert::<f64>().value;
+
+                        let mut order = (0..data.len()).collect::<Vec<_>>();
+                        order.sort_by(|a, b| data[*a].total_cmp(&data[*b]).then(a.cmp(b)));
+
+                        let mut first = Vec::new();
+                        let mut counts = Vec::new();
+                        let mut inverse = vec![0i64; data.len()];
+                        for (index, position) in order.iter().enumerate() {
+                            if index == 0 || data[order[index - 1]].total_cmp(&data[*position]).is_ne() {
+                                first.push(*position);
+                                counts.push(0i64);
+                            }
+                            inverse[*position] = first.len() as i64 - 1;
+                            *counts.last_mut().unwrap() += 1;
+                        }
+
+                        (
+                            Tensor::<B, 1>::from_data(
+                                Data::from(first.iter().map(|position| data[*position]).collect::<Vec<_>>().as_slice())
+                                    .convert(),
+                                &device
+                            ),
+                            Tensor::<B, 1, Int>::from_data(Data::from(counts.as_slice()).convert(), &device)
+                        )
+                    };
+
+                    (tensor2, tensor3)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
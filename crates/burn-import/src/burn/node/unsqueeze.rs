@@ -1,5 +1,5 @@
 use super::{Node, NodeCodegen};
-use crate::burn::{BurnImports, Scope, TensorType, ToTokens, Type};
+use crate::burn::{BurnImports, Scope, ScopeError, TensorType, ToTokens, Type};
 use burn::record::PrecisionSettings;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -20,14 +20,14 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for UnsqueezeNode {
         vec![self.input.clone()]
     }
 
-    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> Result<TokenStream, ScopeError> {
         let output = &self.output.name;
         let shape_values = &self.axes.to_tokens();
         let new_dims = self.output.dim.to_tokens();
 
-        match &self.input {
+        Ok(match &self.input {
             Type::Tensor(tensor) => {
-                let input = scope.tensor_use_owned(tensor, node_position);
+                let input = scope.tensor_use_owned(tensor, node_position)?;
                 quote! {
                     let #output: Tensor<B, #new_dims> = #input.unsqueeze_dims(&#shape_values);
                 }
@@ -39,7 +39,7 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for UnsqueezeNode {
                 }
             }
             _ => panic!("Unsupported input type"),
-        }
+        })
     }
 
     fn into_node(self) -> Node<PS> {
@@ -14,6 +14,9 @@ pub struct Scope {
 struct TensorVariable {
     references: usize,
     node_position: usize,
+    /// Number of registered uses, kept to detect variables that are never used.
+    #[new(default)]
+    uses: usize,
 }
 
 impl Scope {
@@ -41,44 +44,361 @@ impl Scope {
     /// # Notes
     ///
     /// We need to know all futures use of a variable in advance.
-    pub fn tensor_register_future_use(&mut self, tensor: &TensorType, node_position: usize) {
-        if let Some(variables) = self.variables.get_mut(&tensor.name) {
-            for variable in variables.iter_mut().rev() {
-                if node_position >= variable.node_position {
-                    variable.references += 1;
-                    break;
-                }
-            }
-        } else {
-            panic!("No variable with name {}", tensor.name);
-        }
+    pub fn tensor_register_future_use(
+        &mut self,
+        tensor: &TensorType,
+        node_position: usize,
+    ) -> Result<(), ScopeError> {
+        let variable = self.variable_mut(tensor, node_position)?;
+        variable.references += 1;
+        variable.uses += 1;
+
+        Ok(())
     }
 
     /// Use a tensor variable, cloning it if it was registered multiple times and the tensor will still be used afterward.
-    pub fn tensor_use_owned(&mut self, tensor: &TensorType, node_position: usize) -> TokenStream {
-        if let Some(variables) = self.variables.get_mut(&tensor.name) {
-            let mut count = 0;
-            let name = &tensor.name;
-
-            for variable in variables.iter_mut().rev() {
-                if node_position >= variable.node_position {
-                    variable.references -= 1;
-                    count = variable.references;
-                    break;
-                }
-            }
+    ///
+    /// An output reusing the name of an input, e.g. `let x = x.relu();`, is declared as another
+    /// variable at the next node position, so the last use of the input is moved even when the
+    /// name is used afterward.
+    ///
+    /// Returns an error if the variable wasn't registered.
+    pub fn tensor_use_owned(
+        &mut self,
+        tensor: &TensorType,
+        node_position: usize,
+    ) -> Result<TokenStream, ScopeError> {
+        let variable = self.variable_mut(tensor, node_position)?;
+        variable.references -= 1;
 
-            if count > 0 {
-                quote! {
-                    #name.clone()
-                }
-            } else {
-                quote! {
-                    #name
-                }
+        Ok(use_variable(&tensor.name, variable))
+    }
+
+    /// Use a tensor variable for an operation consuming its input and returning its output under
+    /// the same name, e.g. `let x = x.relu();`.
+    ///
+    /// The input is moved when the node is its last consumer, since the output shadows the input
+    /// variable. When the node also uses the input for another operand, e.g. `let x = x.add(x);`,
+    /// the use evaluated first is cloned so that the input is only moved once.
+    ///
+    /// If the output doesn't reuse the name of the input, this is the same as
+    /// [tensor_use_owned](Scope::tensor_use_owned).
+    ///
+    /// Returns an error if the variable wasn't registered.
+    pub fn tensor_use_inplace(
+        &mut self,
+        input: &TensorType,
+        output: &TensorType,
+        node_position: usize,
+    ) -> Result<TokenStream, ScopeError> {
+        if input.name != output.name {
+            return self.tensor_use_owned(input, node_position);
+        }
+
+        // The output is declared at the next node position, so only the input variable is
+        // visible from the node, and its remaining references are the other uses of the node.
+        let variable = self.variable_mut(input, node_position)?;
+        variable.references -= 1;
+
+        Ok(use_variable(&input.name, variable))
+    }
+
+    /// Check the variables once all of them have been used, returning a warning for each variable
+    /// that is never used or whose registered uses weren't all consumed.
+    ///
+    /// Both usually signal a bug in a node, e.g. a clone that is never moved.
+    pub fn validate(&self) -> Vec<ScopeWarning> {
+        let mut warnings = self
+            .variables
+            .iter()
+            .flat_map(|(name, variables)| {
+                variables.iter().filter_map(|variable| {
+                    let name = name.to_string();
+                    let node_position = variable.node_position;
+
+                    if variable.uses == 0 {
+                        Some(ScopeWarning::UnusedVariable {
+                            name,
+                            node_position,
+                        })
+                    } else if variable.references > 0 {
+                        Some(ScopeWarning::LeakedReferences {
+                            name,
+                            node_position,
+                            references: variable.references,
+                        })
+                    } else {
+                        None
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // The variables are stored in a map, so the warnings are sorted to be deterministic.
+        warnings.sort_by_key(|warning| match warning {
+            ScopeWarning::UnusedVariable {
+                name,
+                node_position,
             }
-        } else {
-            panic!("No variable with name {}", &tensor.name);
+            | ScopeWarning::LeakedReferences {
+                name,
+                node_position,
+                ..
+            } => (*node_position, name.clone()),
+        });
+
+        warnings
+    }
+
+    /// Find the variable visible from the given node position.
+    fn variable_mut(
+        &mut self,
+        tensor: &TensorType,
+        node_position: usize,
+    ) -> Result<&mut TensorVariable, ScopeError> {
+        self.variables
+            .get_mut(&tensor.name)
+            .and_then(|variables| {
+                variables
+                    .iter_mut()
+                    .rev()
+                    .find(|variable| node_position >= variable.node_position)
+            })
+            .ok_or_else(|| ScopeError::UnknownVariable {
+                name: tensor.name.to_string(),
+                node_position,
+            })
+    }
+}
+
+/// Use the variable, cloning it when it has remaining references.
+fn use_variable(name: &Ident, variable: &TensorVariable) -> TokenStream {
+    if variable.references > 0 {
+        quote! {
+            #name.clone()
+        }
+    } else {
+        quote! {
+            #name
+        }
+    }
+}
+
+/// Error that can occur when tracking variables in a [scope](Scope).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScopeError {
+    /// The variable isn't declared before the node using it.
+    UnknownVariable {
+        /// Name of the variable.
+        name: String,
+        /// Position of the node using the variable.
+        node_position: usize,
+    },
+}
+
+impl core::fmt::Display for ScopeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ScopeError::UnknownVariable {
+                name,
+                node_position,
+            } => write!(
+                f,
+                "No variable with name {name} declared before node {node_position}"
+            ),
         }
     }
 }
+
+impl std::error::Error for ScopeError {}
+
+/// Warning reported when [validating](Scope::validate) a [scope](Scope).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScopeWarning {
+    /// The variable is declared but never used.
+    UnusedVariable {
+        /// Name of the variable.
+        name: String,
+        /// Position of the node declaring the variable.
+        node_position: usize,
+    },
+    /// Some registered uses of the variable were never consumed.
+    LeakedReferences {
+        /// Name of the variable.
+        name: String,
+        /// Position of the node declaring the variable.
+        node_position: usize,
+        /// Number of uses left.
+        references: usize,
+    },
+}
+
+impl core::fmt::Display for ScopeWarning {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ScopeWarning::UnusedVariable {
+                name,
+                node_position,
+            } => write!(
+                f,
+                "Variable {name} declared at node {node_position} is never used"
+            ),
+            ScopeWarning::LeakedReferences {
+                name,
+                node_position,
+                references,
+            } => write!(
+                f,
+                "Variable {name} declared at node {node_position} has {references} unconsumed uses"
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn err_when_unknown_variable() {
+        let mut scope = Scope::default();
+        let tensor = TensorType::new_float("tensor", 4);
+
+        assert_eq!(
+            scope.tensor_register_future_use(&tensor, 2),
+            Err(ScopeError::UnknownVariable {
+                name: "tensor".to_string(),
+                node_position: 2
+            })
+        );
+        assert!(scope.tensor_use_owned(&tensor, 2).is_err());
+    }
+
+    #[test]
+    fn last_use_of_shadowed_variable_is_not_cloned() {
+        let mut scope = Scope::default();
+        let tensor = TensorType::new_float("tensor", 4);
+
+        // `let tensor = tensor.relu();` at node 0, then `tensor` is used by node 1.
+        scope.tensor_register_variable(&tensor, 0);
+        scope.tensor_register_variable(&tensor, 1);
+        scope.tensor_register_future_use(&tensor, 0).unwrap();
+        scope.tensor_register_future_use(&tensor, 1).unwrap();
+
+        let input = scope.tensor_use_owned(&tensor, 0).unwrap();
+        assert_eq!(input.to_string(), quote! { tensor }.to_string());
+
+        let input = scope.tensor_use_owned(&tensor, 1).unwrap();
+        assert_eq!(input.to_string(), quote! { tensor }.to_string());
+        assert!(scope.validate().is_empty());
+    }
+
+    #[test]
+    fn shadowed_variable_used_twice_by_the_same_node_is_cloned_once() {
+        let mut scope = Scope::default();
+        let tensor = TensorType::new_float("tensor", 4);
+
+        scope.tensor_register_variable(&tensor, 0);
+        scope.tensor_register_variable(&tensor, 1);
+        scope.tensor_register_future_use(&tensor, 0).unwrap();
+        scope.tensor_register_future_use(&tensor, 0).unwrap();
+
+        let other = scope.tensor_use_owned(&tensor, 0).unwrap();
+        assert_eq!(other.to_string(), quote! { tensor.clone() }.to_string());
+
+        let input = scope.tensor_use_owned(&tensor, 0).unwrap();
+        assert_eq!(input.to_string(), quote! { tensor }.to_string());
+    }
+
+    #[test]
+    fn inplace_last_use_is_not_cloned() {
+        let mut scope = Scope::default();
+        let tensor = TensorType::new_float("tensor", 4);
+
+        scope.tensor_register_variable(&tensor, 0);
+        scope.tensor_register_variable(&tensor, 1);
+        scope.tensor_register_future_use(&tensor, 0).unwrap();
+        scope.tensor_register_future_use(&tensor, 1).unwrap();
+
+        let input = scope.tensor_use_inplace(&tensor, &tensor, 0).unwrap();
+        assert_eq!(input.to_string(), quote! { tensor }.to_string());
+
+        let input = scope.tensor_use_owned(&tensor, 1).unwrap();
+        assert_eq!(input.to_string(), quote! { tensor }.to_string());
+        assert!(scope.validate().is_empty());
+    }
+
+    #[test]
+    fn inplace_input_consumed_twice_by_the_same_node_is_cloned_once() {
+        let tensor = TensorType::new_float("tensor", 4);
+        let node_scope = || {
+            let mut scope = Scope::default();
+            scope.tensor_register_variable(&tensor, 0);
+            scope.tensor_register_variable(&tensor, 1);
+            scope.tensor_register_future_use(&tensor, 0).unwrap();
+            scope.tensor_register_future_use(&tensor, 0).unwrap();
+            scope
+        };
+
+        // `let tensor = tensor.clone().add(tensor);`, the in-place operand being evaluated first
+        let mut scope = node_scope();
+        let lhs = scope.tensor_use_inplace(&tensor, &tensor, 0).unwrap();
+        let rhs = scope.tensor_use_owned(&tensor, 0).unwrap();
+        assert_eq!(lhs.to_string(), quote! { tensor.clone() }.to_string());
+        assert_eq!(rhs.to_string(), quote! { tensor }.to_string());
+
+        // The other operand evaluated first
+        let mut scope = node_scope();
+        let other = scope.tensor_use_owned(&tensor, 0).unwrap();
+        let input = scope.tensor_use_inplace(&tensor, &tensor, 0).unwrap();
+        assert_eq!(other.to_string(), quote! { tensor.clone() }.to_string());
+        assert_eq!(input.to_string(), quote! { tensor }.to_string());
+    }
+
+    #[test]
+    fn inplace_with_another_output_name_is_cloned_when_used_later() {
+        let mut scope = Scope::default();
+        let tensor = TensorType::new_float("tensor", 4);
+        let output = TensorType::new_float("output", 4);
+
+        scope.tensor_register_variable(&tensor, 0);
+        scope.tensor_register_future_use(&tensor, 0).unwrap();
+        scope.tensor_register_future_use(&tensor, 1).unwrap();
+
+        let input = scope.tensor_use_inplace(&tensor, &output, 0).unwrap();
+        assert_eq!(input.to_string(), quote! { tensor.clone() }.to_string());
+    }
+
+    #[test]
+    fn validate_reports_unused_and_leaked_variables() {
+        let mut scope = Scope::default();
+        let tensor = TensorType::new_float("tensor", 4);
+        let leaked = TensorType::new_float("leaked", 4);
+        let unused = TensorType::new_float("unused", 4);
+
+        scope.tensor_register_variable(&tensor, 0);
+        scope.tensor_register_future_use(&tensor, 0).unwrap();
+        scope.tensor_register_variable(&leaked, 1);
+        scope.tensor_register_future_use(&leaked, 1).unwrap();
+        scope.tensor_register_future_use(&leaked, 2).unwrap();
+        scope.tensor_register_variable(&unused, 2);
+
+        scope.tensor_use_owned(&tensor, 0).unwrap();
+        scope.tensor_use_owned(&leaked, 1).unwrap();
+
+        assert_eq!(
+            scope.validate(),
+            vec![
+                ScopeWarning::LeakedReferences {
+                    name: "leaked".to_string(),
+                    node_position: 1,
+                    references: 1,
+                },
+                ScopeWarning::UnusedVariable {
+                    name: "unused".to_string(),
+                    node_position: 2,
+                },
+            ]
+        );
+    }
+}
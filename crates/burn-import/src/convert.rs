@@ -0,0 +1,420 @@
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use burn::{
+    record::{
+        convert_record, BinFileRecorder, BinGzFileRecorder, FullPrecisionSettings,
+        HalfPrecisionSettings, JsonGzFileRecorder, NamedMpkFileRecorder, NamedMpkGzFileRecorder,
+        PrettyJsonFileRecorder, Record, RecorderError,
+    },
+    tensor::backend::Backend,
+};
+
+use crate::safetensors::{AdapterType, LoadArgs, SafetensorsFileRecorder};
+
+/// Usage of the record conversion command line.
+pub const CONVERT_USAGE: &str = "Usage: <input> <output> [--from <format>] [--to <format>] \
+[--half] [--pytorch]
+
+Formats: mpk, mpk.gz, bin, bin.gz, json, json.gz, safetensors. Without --from or --to, the format \
+is inferred from the extension of the file.
+
+  --half     Save the floats with half precision, which is lossy.
+  --pytorch  Load a safetensors input saved with the PyTorch layout, e.g. from HuggingFace.";
+
+/// The file format of a record, see [convert].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordFileFormat {
+    /// Named MessagePack, see [NamedMpkFileRecorder].
+    NamedMpk,
+    /// Compressed named MessagePack, see [NamedMpkGzFileRecorder].
+    NamedMpkGz,
+    /// Bincode, see [BinFileRecorder].
+    Bin,
+    /// Compressed bincode, see [BinGzFileRecorder].
+    BinGz,
+    /// Pretty JSON, see [PrettyJsonFileRecorder].
+    PrettyJson,
+    /// Compressed JSON, see [JsonGzFileRecorder].
+    JsonGz,
+    /// Safetensors, see [SafetensorsFileRecorder].
+    Safetensors,
+}
+
+impl RecordFileFormat {
+    const ALL: [Self; 7] = [
+        Self::NamedMpk,
+        Self::NamedMpkGz,
+        Self::Bin,
+        Self::BinGz,
+        Self::PrettyJson,
+        Self::JsonGz,
+        Self::Safetensors,
+    ];
+
+    /// The extension of the files of the format, also used to name it on the command line.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::NamedMpk => "mpk",
+            Self::NamedMpkGz => "mpk.gz",
+            Self::Bin => "bin",
+            Self::BinGz => "bin.gz",
+            Self::PrettyJson => "json",
+            Self::JsonGz => "json.gz",
+            Self::Safetensors => "safetensors",
+        }
+    }
+
+    /// The format of the given file, inferred from its extension.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_str()?;
+
+        // The longest extension wins, so that `model.mpk.gz` isn't taken for a `.gz` file.
+        Self::ALL
+            .into_iter()
+            .filter(|format| name.ends_with(&format!(".{}", format.extension())))
+            .max_by_key(|format| format.extension().len())
+    }
+
+    /// The path given to the file recorders, which add the extension of the format themselves.
+    fn recorder_path(&self, path: &Path) -> PathBuf {
+        let extension = format!(".{}", self.extension());
+
+        match path.to_str().and_then(|path| path.strip_suffix(&extension)) {
+            Some(path) => PathBuf::from(path),
+            None => path.to_path_buf(),
+        }
+    }
+}
+
+impl FromStr for RecordFileFormat {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|format| format.extension() == name)
+            .ok_or_else(|| format!("Unknown record format '{name}'"))
+    }
+}
+
+/// The arguments of a record conversion.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConvertArgs {
+    /// The file of the record to convert.
+    pub input: PathBuf,
+    /// The format of the input file.
+    pub input_format: RecordFileFormat,
+    /// The file of the converted record.
+    pub output: PathBuf,
+    /// The format of the output file.
+    pub output_format: RecordFileFormat,
+    /// Whether the floats are saved with half precision, which is lossy.
+    pub half_precision: bool,
+    /// Whether a safetensors input has the PyTorch layout, e.g. transposed linear weights.
+    pub pytorch_layout: bool,
+}
+
+impl ConvertArgs {
+    /// Parse the arguments of the command line, without the name of the program, see
+    /// [CONVERT_USAGE].
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut paths = Vec::new();
+        let mut input_format = None;
+        let mut output_format = None;
+        let mut half_precision = false;
+        let mut pytorch_layout = false;
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--from" | "--to" => {
+                    let format = args
+                        .next()
+                        .ok_or_else(|| format!("Missing the format after {arg}"))?
+                        .parse()?;
+                    match arg.as_str() {
+                        "--from" => input_format = Some(format),
+                        _ => output_format = Some(format),
+                    }
+                }
+                "--half" => half_precision = true,
+                "--pytorch" => pytorch_layout = true,
+                _ if arg.starts_with("--") => return Err(format!("Unknown option {arg}")),
+                _ => paths.push(PathBuf::from(arg)),
+            }
+        }
+
+        let [input, output]: [PathBuf; 2] = paths
+            .try_into()
+            .map_err(|_| "Expected an input and an output file".to_string())?;
+        let format = |format: Option<RecordFileFormat>, path: &Path| {
+            format
+                .or_else(|| RecordFileFormat::from_path(path))
+                .ok_or_else(|| format!("Unknown record format of {}", path.display()))
+        };
+
+        Ok(Self {
+            input_format: format(input_format, &input)?,
+            output_format: format(output_format, &output)?,
+            input,
+            output,
+            half_precision,
+            pytorch_layout,
+        })
+    }
+}
+
+/// Bind the recorder of the given format and its load arguments for the given path.
+macro_rules! with_recorder {
+    ($format:expr, $settings:ty, $path:expr, $pytorch_layout:expr, |$recorder:ident, $args:ident| $body:expr) => {
+        match $format {
+            RecordFileFormat::NamedMpk => {
+                let $recorder = NamedMpkFileRecorder::<$settings>::new();
+                let $args = $format.recorder_path($path);
+                $body
+            }
+            RecordFileFormat::NamedMpkGz => {
+                let $recorder = NamedMpkGzFileRecorder::<$settings>::new();
+                let $args = $format.recorder_path($path);
+                $body
+            }
+            RecordFileFormat::Bin => {
+                let $recorder = BinFileRecorder::<$settings>::new();
+                let $args = $format.recorder_path($path);
+                $body
+            }
+            RecordFileFormat::BinGz => {
+                let $recorder = BinGzFileRecorder::<$settings>::new();
+                let $args = $format.recorder_path($path);
+                $body
+            }
+            RecordFileFormat::PrettyJson => {
+                let $recorder = PrettyJsonFileRecorder::<$settings>::new();
+                let $args = $format.recorder_path($path);
+                $body
+            }
+            RecordFileFormat::JsonGz => {
+                let $recorder = JsonGzFileRecorder::<$settings>::new();
+                let $args = $format.recorder_path($path);
+                $body
+            }
+            RecordFileFormat::Safetensors => {
+                let $recorder = SafetensorsFileRecorder::<$settings>::new();
+                let adapter_type = match $pytorch_layout {
+                    true => AdapterType::PyTorch,
+                    false => AdapterType::NoAdapter,
+                };
+                let $args =
+                    LoadArgs::new($format.recorder_path($path).with_extension("safetensors"))
+                        .with_adapter_type(adapter_type);
+                $body
+            }
+        }
+    };
+}
+
+/// Convert the record of type `R` described by the given arguments with [convert_record].
+///
+/// The input is loaded with full precision and the output is saved with full or half precision.
+/// Safetensors files only contain the tensors, so the tags of a record saved as safetensors are
+/// lost.
+pub fn convert<B, R>(args: &ConvertArgs, device: &B::Device) -> Result<(), RecorderError>
+where
+    B: Backend,
+    R: Record<B>,
+{
+    let output = args.output_format.recorder_path(&args.output);
+
+    with_recorder!(
+        args.input_format,
+        FullPrecisionSettings,
+        &args.input,
+        args.pytorch_layout,
+        |from, load_args| match args.half_precision {
+            true => with_recorder!(
+                args.output_format,
+                HalfPrecisionSettings,
+                &args.output,
+                false,
+                |to, _load_args| convert_record::<B, R, _, _>(
+                    &from, &to, load_args, output, device
+                )
+            ),
+            false => with_recorder!(
+                args.output_format,
+                FullPrecisionSettings,
+                &args.output,
+                false,
+                |to, _load_args| convert_record::<B, R, _, _>(
+                    &from, &to, load_args, output, device
+                )
+            ),
+        }
+    )
+}
+
+/// Convert the record of type `R` described by the arguments of the command line, printing the
+/// [usage](CONVERT_USAGE) when they're invalid.
+///
+/// Since records are typed, the conversion tool is a binary built for a given module, e.g.
+///
+/// ```ignore
+/// fn main() {
+///     let device = Default::default();
+///     burn_import::convert::run_from_cli::<NdArray, ModelRecord<NdArray>>(&device);
+/// }
+/// ```
+pub fn run_from_cli<B, R>(device: &B::Device)
+where
+    B: Backend,
+    R: Record<B>,
+{
+    let args = match ConvertArgs::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("{err}\n\n{CONVERT_USAGE}");
+            std::process::exit(2);
+        }
+    };
+
+    if let Err(err) = convert::<B, R>(&args, device) {
+        eprintln!("{err}");
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn::{
+        backend::NdArray,
+        module::{Module, Param, ParamId},
+        nn::{Linear, LinearConfig},
+        record::Recorder,
+        tensor::{Int, Tensor},
+    };
+
+    type TestBackend = NdArray<f32>;
+
+    #[derive(Module, Debug)]
+    struct Model<B: Backend> {
+        linear: Linear<B>,
+        indices: Param<Tensor<B, 1, Int>>,
+    }
+
+    fn model(device: &<TestBackend as Backend>::Device) -> Model<TestBackend> {
+        Model {
+            linear: LinearConfig::new(3, 2).init(device),
+            indices: Param::initialized(ParamId::new(), Tensor::from_ints([3, -1, 7], device)),
+        }
+    }
+
+    fn args(args: &[&str]) -> Result<ConvertArgs, String> {
+        ConvertArgs::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    fn assert_same_model(
+        model: &Model<TestBackend>,
+        loaded: &Model<TestBackend>,
+        precision: usize,
+    ) {
+        let weight = model.linear.weight.val().into_data();
+        weight.assert_approx_eq(&loaded.linear.weight.val().into_data(), precision);
+        let bias = model.linear.bias.as_ref().unwrap().val().into_data();
+        bias.assert_approx_eq(
+            &loaded.linear.bias.as_ref().unwrap().val().into_data(),
+            precision,
+        );
+        assert_eq!(
+            model.indices.val().to_data(),
+            loaded.indices.val().to_data()
+        );
+    }
+
+    #[test]
+    fn formats_are_inferred_from_the_extensions() {
+        let args = args(&["model.mpk.gz", "out/model.safetensors", "--half"]).unwrap();
+
+        assert_eq!(args.input_format, RecordFileFormat::NamedMpkGz);
+        assert_eq!(args.output_format, RecordFileFormat::Safetensors);
+        assert!(args.half_precision);
+        assert!(!args.pytorch_layout);
+    }
+
+    #[test]
+    fn given_formats_override_the_extensions() {
+        let args = args(&["--from", "bin", "model", "--to", "json.gz", "model.out"]).unwrap();
+
+        assert_eq!(args.input, PathBuf::from("model"));
+        assert_eq!(args.input_format, RecordFileFormat::Bin);
+        assert_eq!(args.output, PathBuf::from("model.out"));
+        assert_eq!(args.output_format, RecordFileFormat::JsonGz);
+    }
+
+    #[test]
+    fn invalid_args_are_rejected() {
+        assert_eq!(
+            args(&["model.mpk"]),
+            Err("Expected an input and an output file".to_string())
+        );
+        assert_eq!(
+            args(&["model", "model.mpk"]),
+            Err("Unknown record format of model".to_string())
+        );
+        assert_eq!(
+            args(&["model.mpk", "model.bin", "--to", "onnx"]),
+            Err("Unknown record format 'onnx'".to_string())
+        );
+        assert_eq!(
+            args(&["model.mpk", "model.bin", "--fast"]),
+            Err("Unknown option --fast".to_string())
+        );
+    }
+
+    #[test]
+    fn mpk_to_safetensors_to_mpk_keeps_the_tensors() {
+        let device = Default::default();
+        let model = model(&device);
+        let dir = std::env::temp_dir().join("burn_import_convert_round_trip");
+        let mpk = dir.join("model.mpk");
+        let safetensors = dir.join("model.safetensors");
+        let converted = dir.join("converted.mpk");
+        NamedMpkFileRecorder::<FullPrecisionSettings>::new()
+            .record(model.clone().into_record(), dir.join("model"))
+            .unwrap();
+
+        for (input, output) in [(&mpk, &safetensors), (&safetensors, &converted)] {
+            let args = args(&[input.to_str().unwrap(), output.to_str().unwrap()]).unwrap();
+            convert::<TestBackend, ModelRecord<TestBackend>>(&args, &device).unwrap();
+        }
+
+        let record = NamedMpkFileRecorder::<FullPrecisionSettings>::new()
+            .load(dir.join("converted"), &device)
+            .unwrap();
+        assert_same_model(&model, &model.clone().load_record(record), 6);
+    }
+
+    #[test]
+    fn half_precision_conversion_is_lossy() {
+        let device = Default::default();
+        let model = model(&device);
+        let dir = std::env::temp_dir().join("burn_import_convert_half");
+        NamedMpkFileRecorder::<FullPrecisionSettings>::new()
+            .record(model.clone().into_record(), dir.join("model"))
+            .unwrap();
+
+        let input = dir.join("model.mpk");
+        let output = dir.join("model.safetensors");
+        let args = args(&[input.to_str().unwrap(), output.to_str().unwrap(), "--half"]).unwrap();
+        convert::<TestBackend, ModelRecord<TestBackend>>(&args, &device).unwrap();
+
+        let record = SafetensorsFileRecorder::<HalfPrecisionSettings>::new()
+            .load(
+                LoadArgs::new(output).with_adapter_type(AdapterType::NoAdapter),
+                &device,
+            )
+            .unwrap();
+        assert_same_model(&model, &model.clone().load_record(record), 2);
+    }
+}
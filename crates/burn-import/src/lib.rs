@@ -29,5 +29,13 @@ pub mod burn;
 #[cfg(feature = "pytorch")]
 pub mod pytorch;
 
+/// The Safetensors module for recorder.
+#[cfg(feature = "safetensors")]
+pub mod safetensors;
+
+/// The module converting records between the recorder formats.
+#[cfg(feature = "safetensors")]
+pub mod convert;
+
 mod formatter;
 pub use formatter::*;
@@ -1,8 +1,11 @@
-use std::{iter::Peekable, slice::Iter};
+use std::{collections::HashSet, iter::Peekable, slice::Iter};
+
+use half::f16;
 
 use super::{
     from_onnx::OnnxGraphIO,
-    ir::{AttributeValue, Node, NodeType},
+    ir::{Argument, AttributeValue, Node, NodeType},
+    op_configuration::gemm_config,
     proto_conversion::convert_node_proto,
     protos::NodeProto,
 };
@@ -23,78 +26,117 @@ pub fn coalesce(
     }
 }
 
-/// This function converts a Gemm node into a Linear node
+/// This function converts a Gemm node into a Linear node when possible.
 ///
-/// PyTorch and other frameworks use Gemm node to represent Linear layer.
+/// PyTorch and other frameworks use Gemm node to represent Linear layer. The node is converted
+/// when `A` isn't transposed and `B` and `C` are constants, `alpha` and `beta` being folded into
+/// the weight and the bias. The other Gemm nodes are left unchanged.
 pub(crate) fn convert_gemm_to_linear(node: &mut Node) {
     if node.outputs.len() != 1 {
         panic!("Gemm node must have 1 output");
     }
-    let straight_linear = match (
-        node.attrs.get("alpha"),
-        node.attrs.get("beta"),
-        node.attrs.get("transB"),
-    ) {
+    let (alpha, beta, trans_a, trans_b) = gemm_config(node);
+    if trans_a {
+        return;
+    }
+
+    let weight_input = match node.inputs.get(1) {
+        Some(input) => input.clone(),
+        None => return,
+    };
+    let (weight_data, elem_type, rows, cols) = match (&weight_input.value, &weight_input.ty) {
         (
-            Some(AttributeValue::Float32(alpha)),
-            Some(AttributeValue::Float32(beta)),
-            Some(AttributeValue::Int64(trans_b)),
-        ) => *alpha == 1.0 && *beta == 1.0 && *trans_b == 1,
-        _ => false,
+            Some(data),
+            ArgType::Tensor(TensorType {
+                elem_type,
+                dim: 2,
+                shape: Some(shape),
+            }),
+        ) => (data, elem_type.clone(), shape[0], shape[1]),
+        _ => return,
+    };
+    let weight = match float_values(weight_data) {
+        Some(weight) => weight,
+        None => return,
     };
 
-    if straight_linear {
-        node.node_type = NodeType::Linear;
-        node.attrs.remove("alpha");
-        node.attrs.remove("beta");
-        node.attrs.remove("transB");
-
-        // Transpose the weights
-        transpose_linear_node_weights(node);
+    // The weight is stored as [d_input, d_output], B being [d_output, d_input] with transB
+    let (weight, d_input, d_output) = if trans_b {
+        (transpose_flattened(weight, rows, cols), cols, rows)
     } else {
-        panic!("Full Gemm node not supported yet.");
-    }
-}
+        (weight, rows, cols)
+    };
 
-// Transpose linear weights (required for Gemm -> Linear conversion)
-fn transpose_linear_node_weights(node: &mut Node) {
-    assert!(
-        node.inputs.len() > 1,
-        "Linear node must have at least 2 input"
-    );
+    // C is omitted, or has an empty name, when there is no bias
+    let bias = match node.inputs.get(2).filter(|input| !input.name.is_empty()) {
+        Some(input) => match gemm_bias(input, d_output, beta) {
+            Some(bias) => Some(bias),
+            None => return,
+        },
+        None => None,
+    };
 
-    assert!(node.inputs[1].value.is_some(), "Input must have a value");
+    let weight = weight
+        .into_iter()
+        .map(|value| value * alpha as f64)
+        .collect();
+    node.inputs[1].value = Some(float_data_like(weight_data, weight));
+    node.inputs[1].ty = ArgType::Tensor(TensorType {
+        elem_type,
+        dim: 2,
+        shape: Some(vec![d_input, d_output]),
+    });
 
-    let weight = node.inputs[1]
-        .clone()
-        .into_tensor()
-        .expect("Tensor input is expected");
+    node.inputs.truncate(2);
+    node.inputs.extend(bias);
 
-    assert_eq!(weight.dim, 2, "Weight must be a 2D tensor");
+    node.node_type = NodeType::Linear;
+    for attr in ["alpha", "beta", "transA", "transB"] {
+        node.attrs.remove(attr);
+    }
+}
 
-    let shape = weight.shape.unwrap();
+/// The bias of a Gemm node converted into a Linear node, scaled by `beta`.
+///
+/// `None` when `C` isn't a constant broadcast along the rows of the output, i.e. a scalar, a
+/// vector or a single row.
+fn gemm_bias(input: &Argument, d_output: usize, beta: f32) -> Option<Argument> {
+    let (data, elem_type) = match (&input.value, &input.ty) {
+        (Some(data), ArgType::Tensor(tensor)) => {
+            let is_row = match tensor.shape.as_deref() {
+                Some([]) | Some([_]) | Some([1, _]) => true,
+                _ => tensor.dim <= 1,
+            };
+            if !is_row {
+                return None;
+            }
 
-    match weight.data.expect("Tensor must have data") {
-        Data::Float32s(data) => {
-            let data_t = transpose_flattened(data, shape[0], shape[1]);
-            node.inputs[1].value = Some(Data::Float32s(data_t));
-        }
-        Data::Float64s(data) => {
-            let data_t = transpose_flattened(data, shape[0], shape[1]);
-            node.inputs[1].value = Some(Data::Float64s(data_t));
-        }
-        Data::Float16s(data) => {
-            let data_t = transpose_flattened(data, shape[0], shape[1]);
-            node.inputs[1].value = Some(Data::Float16s(data_t));
+            (data, tensor.elem_type.clone())
         }
-        _ => panic!("Only float types are supported for Linear node"),
-    }
-    let shape = Some(vec![shape[1], shape[0]]); // Transpose the shape
-    node.inputs[1].ty = ArgType::Tensor(TensorType {
-        shape,
-        elem_type: weight.elem_type,
-        dim: 2,
-    });
+        _ => return None,
+    };
+
+    let values = float_values(data)?;
+    let values = match values.len() {
+        len if len == d_output => values,
+        1 => vec![values[0]; d_output],
+        _ => return None,
+    };
+    let values = values
+        .into_iter()
+        .map(|value| value * beta as f64)
+        .collect();
+
+    Some(Argument {
+        name: input.name.clone(),
+        ty: ArgType::Tensor(TensorType {
+            elem_type,
+            dim: 1,
+            shape: Some(vec![d_output]),
+        }),
+        value: Some(float_data_like(data, values)),
+        passed: input.passed,
+    })
 }
 
 fn transpose_flattened<T: Copy>(matrix: Vec<T>, rows: usize, cols: usize) -> Vec<T> {
@@ -143,7 +185,7 @@ pub(crate) fn convert_matmul_to_linear(
 
     // Check the next node for potential conversion
     if let Some(peek_node) = iter_mut.peek() {
-        let peek_node = convert_node_proto(peek_node, graph_io).clone();
+        let peek_node = convert_node_proto(peek_node, graph_io, node.opset_version).clone();
         if is_add_node_with_bias(&peek_node, node) {
             convert_and_remove_add_node(&peek_node, node);
 
@@ -177,3 +219,168 @@ fn convert_and_remove_add_node(bias_node: &Node, current_node: &mut Node) {
         .name
         .clone_from(&bias_node.outputs[0].name);
 }
+
+/// Fold the batch normalizations following a convolution into the weight and bias of the
+/// convolution, returning the indices of the folded batch normalization nodes to remove.
+///
+/// A batch normalization is only folded when the convolution output isn't used by any other
+/// node or as a graph output, otherwise both nodes are kept.
+pub(crate) fn fold_batch_norms(
+    nodes: &mut [Node],
+    graph_io: &OnnxGraphIO,
+    nodes_to_remove: &HashSet<usize>,
+) -> Vec<usize> {
+    let mut folded = Vec::new();
+
+    for bn_idx in 0..nodes.len() {
+        if nodes[bn_idx].node_type != NodeType::BatchNormalization
+            || nodes_to_remove.contains(&bn_idx)
+        {
+            continue;
+        }
+
+        let input_name = nodes[bn_idx].inputs[0].name.clone();
+        let conv_idx = nodes[..bn_idx].iter().position(|node| {
+            matches!(node.node_type, NodeType::Conv1d | NodeType::Conv2d)
+                && node.outputs.len() == 1
+                && node.outputs[0].name == input_name
+        });
+        let conv_idx = match conv_idx {
+            Some(conv_idx) if !nodes_to_remove.contains(&conv_idx) => conv_idx,
+            _ => continue,
+        };
+
+        let consumers = nodes
+            .iter()
+            .flat_map(|node| node.inputs.iter())
+            .chain(graph_io.outputs.iter())
+            .filter(|argument| argument.name == input_name)
+            .count();
+        if consumers != 1 {
+            log::debug!(
+                "not folding {} into {}: its input has {consumers} consumers",
+                nodes[bn_idx].name,
+                nodes[conv_idx].name
+            );
+            continue;
+        }
+
+        let (previous, next) = nodes.split_at_mut(bn_idx);
+        if fold_batch_norm(&mut previous[conv_idx], &next[0]) {
+            log::debug!("folding {} into {}", next[0].name, previous[conv_idx].name);
+            folded.push(bn_idx);
+        }
+    }
+
+    folded
+}
+
+/// Fold the batch normalization into the convolution producing its input, returning `false`
+/// without changing the convolution when the parameters aren't all constants.
+fn fold_batch_norm(conv: &mut Node, batch_norm: &Node) -> bool {
+    if batch_norm.inputs.len() != 5 || batch_norm.outputs.len() != 1 {
+        return false;
+    }
+    if let Some(AttributeValue::Int64(1)) = batch_norm.attrs.get("training_mode") {
+        return false;
+    }
+
+    let params = batch_norm.inputs[1..]
+        .iter()
+        .map(|input| input.value.as_ref().and_then(float_values))
+        .collect::<Option<Vec<_>>>();
+    let (gamma, beta, mean, var) = match params.as_deref() {
+        Some([gamma, beta, mean, var]) => (gamma, beta, mean, var),
+        _ => return false,
+    };
+    let epsilon = match batch_norm.attrs.get("epsilon") {
+        Some(AttributeValue::Float32(epsilon)) => *epsilon as f64,
+        _ => 1e-5,
+    };
+
+    let (weight_data, weight_elem) = match (&conv.inputs[1].value, &conv.inputs[1].ty) {
+        (Some(data), ArgType::Tensor(tensor)) => (data, tensor.elem_type.clone()),
+        _ => return false,
+    };
+    let weight = match float_values(weight_data) {
+        Some(weight) => weight,
+        None => return false,
+    };
+
+    let channels = gamma.len();
+    if [beta, mean, var]
+        .iter()
+        .any(|param| param.len() != channels)
+        || channels == 0
+        || weight.len() % channels != 0
+    {
+        return false;
+    }
+
+    let bias = match conv.inputs.get(2) {
+        Some(bias) => match bias.value.as_ref().and_then(float_values) {
+            Some(bias) if bias.len() == channels => bias,
+            _ => return false,
+        },
+        None => vec![0.0; channels],
+    };
+
+    // W' = W * gamma / sqrt(var + epsilon) for each output channel
+    // b' = (b - mean) * gamma / sqrt(var + epsilon) + beta
+    let scale = gamma
+        .iter()
+        .zip(var)
+        .map(|(gamma, var)| gamma / (var + epsilon).sqrt())
+        .collect::<Vec<_>>();
+    let channel_size = weight.len() / channels;
+    let weight = weight
+        .iter()
+        .enumerate()
+        .map(|(i, weight)| weight * scale[i / channel_size])
+        .collect();
+    let bias = (0..channels)
+        .map(|c| (bias[c] - mean[c]) * scale[c] + beta[c])
+        .collect();
+
+    let weight = float_data_like(weight_data, weight);
+    let bias = float_data_like(weight_data, bias);
+    conv.inputs[1].value = Some(weight);
+
+    match conv.inputs.get_mut(2) {
+        Some(input) => input.value = Some(bias),
+        None => conv.inputs.push(Argument {
+            name: format!("{}_bias", conv.name),
+            ty: ArgType::Tensor(TensorType {
+                elem_type: weight_elem,
+                dim: 1,
+                shape: Some(vec![channels]),
+            }),
+            value: Some(bias),
+            passed: false,
+        }),
+    }
+
+    // The convolution now produces the output of the batch normalization
+    conv.outputs[0] = batch_norm.outputs[0].clone();
+
+    true
+}
+
+/// The values of a float tensor, `None` for the other data types.
+fn float_values(data: &Data) -> Option<Vec<f64>> {
+    match data {
+        Data::Float16s(values) => Some(values.iter().map(|value| value.to_f64()).collect()),
+        Data::Float32s(values) => Some(values.iter().map(|value| *value as f64).collect()),
+        Data::Float64s(values) => Some(values.clone()),
+        _ => None,
+    }
+}
+
+/// Convert the values to the float type of the given data.
+fn float_data_like(data: &Data, values: Vec<f64>) -> Data {
+    match data {
+        Data::Float16s(_) => Data::Float16s(values.into_iter().map(f16::from_f64).collect()),
+        Data::Float32s(_) => Data::Float32s(values.into_iter().map(|value| value as f32).collect()),
+        _ => Data::Float64s(values),
+    }
+}
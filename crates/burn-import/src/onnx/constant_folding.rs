@@ -0,0 +1,654 @@
+use half::f16;
+
+use super::{
+    dim_inference::try_broadcast_shape,
+    ir::{ArgType, Argument, AttributeValue, Data, ElementType, Node, NodeType, Tensor},
+    protos::tensor_proto::DataType,
+};
+
+use protobuf::Enum;
+
+/// Values of a constant tensor in row-major order.
+///
+/// The floats are computed in double precision, so that the double constants aren't rounded, and
+/// rounded to the precision of their element type when the output is created.
+#[derive(Debug, Clone)]
+enum Values {
+    Int(Vec<i64>),
+    Float(Vec<f64>),
+}
+
+/// A constant tensor evaluated during the folding, a scalar having an empty shape.
+#[derive(Debug, Clone)]
+struct Constant {
+    elem_type: ElementType,
+    shape: Vec<usize>,
+    values: Values,
+}
+
+/// Evaluate the node when it only computes constants, e.g. the static shapes of `Shape`,
+/// `Gather`, `Unsqueeze` and `Concat` chains.
+///
+/// # Arguments
+///
+/// * `node` - The node to evaluate.
+/// * `inputs` - The constant values of the node inputs, `None` for omitted optional inputs.
+///
+/// # Returns
+///
+/// * The output value of the node, or `None` if the node type or its inputs can't be evaluated,
+///   in which case the node is kept as is.
+pub(crate) fn fold_constant(node: &Node, inputs: &[Option<Argument>]) -> Option<Tensor> {
+    let inputs = inputs
+        .iter()
+        .map(|input| match input {
+            Some(input) => Constant::from_argument(input).map(Some),
+            None => Some(None),
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let output = match node.node_type {
+        NodeType::Add => binary(&inputs, i64::checked_add, |a, b| a + b),
+        NodeType::Sub => binary(&inputs, i64::checked_sub, |a, b| a - b),
+        NodeType::Mul => binary(&inputs, i64::checked_mul, |a, b| a * b),
+        NodeType::Div => binary(&inputs, i64::checked_div, |a, b| a / b),
+        NodeType::Cast => cast(node, &inputs),
+        NodeType::Concat => concat(node, &inputs),
+        NodeType::ConstantOfShape => constant_of_shape(node, &inputs),
+        NodeType::Gather => gather(node, &inputs),
+        NodeType::Reshape => reshape(&inputs),
+        NodeType::Shape => shape(node, &inputs),
+        NodeType::Slice => slice(&inputs),
+        NodeType::Squeeze => squeeze(node, &inputs),
+        NodeType::Unsqueeze => unsqueeze(node, &inputs),
+        _ => None,
+    }?;
+
+    output.into_tensor()
+}
+
+impl Constant {
+    fn from_argument(arg: &Argument) -> Option<Self> {
+        let (elem_type, shape) = match &arg.ty {
+            ArgType::Scalar(elem_type) => (elem_type.clone(), Vec::new()),
+            ArgType::Tensor(tensor) => (tensor.elem_type.clone(), tensor.shape.clone()?),
+            ArgType::Shape(_) => return None,
+        };
+
+        let values = match arg.value.clone()? {
+            Data::Int64(value) => Values::Int(vec![value]),
+            Data::Int64s(values) => Values::Int(values),
+            Data::Int32(value) => Values::Int(vec![value as i64]),
+            Data::Int32s(values) => Values::Int(values.into_iter().map(|v| v as i64).collect()),
+            Data::Float16(value) => Values::Float(vec![f64::from(value)]),
+            Data::Float16s(values) => Values::Float(values.into_iter().map(f64::from).collect()),
+            Data::Float32(value) => Values::Float(vec![value as f64]),
+            Data::Float32s(values) => Values::Float(values.into_iter().map(|v| v as f64).collect()),
+            Data::Float64(value) => Values::Float(vec![value]),
+            Data::Float64s(values) => Values::Float(values),
+            _ => return None,
+        };
+
+        let constant = Self {
+            elem_type,
+            shape,
+            values,
+        };
+
+        (constant.values.len() == constant.numel()).then_some(constant)
+    }
+
+    /// The output tensor, `None` when an int32 value overflows, in which case the node isn't
+    /// folded.
+    fn into_tensor(self) -> Option<Tensor> {
+        let data = match (self.values, &self.elem_type) {
+            (Values::Int(values), ElementType::Int32) => Data::Int32s(
+                values
+                    .into_iter()
+                    .map(|v| i32::try_from(v).ok())
+                    .collect::<Option<_>>()?,
+            ),
+            (Values::Int(values), _) => Data::Int64s(values),
+            (Values::Float(values), ElementType::Float64) => Data::Float64s(values),
+            (Values::Float(values), ElementType::Float16) => {
+                Data::Float16s(values.into_iter().map(f16::from_f64).collect())
+            }
+            (Values::Float(values), _) => {
+                Data::Float32s(values.into_iter().map(|v| v as f32).collect())
+            }
+        };
+
+        Some(Tensor {
+            elem_type: self.elem_type,
+            dim: self.shape.len(),
+            data: Some(data),
+            shape: Some(self.shape),
+        })
+    }
+
+    fn int(shape: Vec<usize>, values: Vec<i64>) -> Self {
+        Self {
+            elem_type: ElementType::Int64,
+            shape,
+            values: Values::Int(values),
+        }
+    }
+
+    fn ints(&self) -> Option<&[i64]> {
+        match &self.values {
+            Values::Int(values) => Some(values),
+            Values::Float(_) => None,
+        }
+    }
+
+    fn numel(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    fn with_shape(self, shape: Vec<usize>) -> Self {
+        Self { shape, ..self }
+    }
+}
+
+impl Values {
+    fn len(&self) -> usize {
+        match self {
+            Values::Int(values) => values.len(),
+            Values::Float(values) => values.len(),
+        }
+    }
+
+    /// Append the other values, which must be of the same kind.
+    fn extend(&mut self, other: Values) -> Option<()> {
+        match (self, other) {
+            (Values::Int(values), Values::Int(other)) => values.extend(other),
+            (Values::Float(values), Values::Float(other)) => values.extend(other),
+            _ => return None,
+        }
+
+        Some(())
+    }
+
+    /// Select the values at the given flat indices.
+    fn select(&self, indices: &[usize]) -> Self {
+        match self {
+            Values::Int(values) => Values::Int(indices.iter().map(|&i| values[i]).collect()),
+            Values::Float(values) => Values::Float(indices.iter().map(|&i| values[i]).collect()),
+        }
+    }
+}
+
+/// The input at the given index, `None` when it is omitted.
+fn input(inputs: &[Option<Constant>], index: usize) -> Option<&Constant> {
+    inputs.get(index).and_then(Option::as_ref)
+}
+
+/// The integer values of an attribute, or of the input at the given index since the axes became
+/// inputs in later opsets.
+fn axes(node: &Node, inputs: &[Option<Constant>], index: usize) -> Option<Vec<i64>> {
+    match node.attrs.get("axes") {
+        Some(AttributeValue::Int64s(axes)) => Some(axes.clone()),
+        _ => input(inputs, index).and_then(|axes| axes.ints().map(<[i64]>::to_vec)),
+    }
+}
+
+/// Normalize a possibly negative axis of the given rank.
+fn normalize_axis(axis: i64, rank: usize) -> Option<usize> {
+    let axis = if axis < 0 { axis + rank as i64 } else { axis };
+
+    (0..rank as i64).contains(&axis).then_some(axis as usize)
+}
+
+/// The flat indices, in row-major order, of the cartesian product of the indices of each axis.
+fn flat_indices(shape: &[usize], axes: &[Vec<usize>]) -> Vec<usize> {
+    let mut indices = vec![0];
+
+    for (dim, axis) in shape.iter().zip(axes) {
+        indices = indices
+            .iter()
+            .flat_map(|&index| axis.iter().map(move |&i| index * dim + i))
+            .collect();
+    }
+
+    indices
+}
+
+fn binary(
+    inputs: &[Option<Constant>],
+    int_op: impl Fn(i64, i64) -> Option<i64>,
+    float_op: impl Fn(f64, f64) -> f64,
+) -> Option<Constant> {
+    let (lhs, rhs) = (input(inputs, 0)?, input(inputs, 1)?);
+
+    // The node isn't folded when the shapes can't be broadcast together
+    let shape = try_broadcast_shape(&[&lhs.shape, &rhs.shape])?;
+    let (lhs_indices, rhs_indices) = (
+        broadcast_indices(&lhs.shape, &shape),
+        broadcast_indices(&rhs.shape, &shape),
+    );
+    let indices = lhs_indices.into_iter().zip(rhs_indices);
+
+    let values = match (&lhs.values, &rhs.values) {
+        (Values::Int(a), Values::Int(b)) => Values::Int(
+            indices
+                .map(|(i, j)| int_op(a[i], b[j]))
+                .collect::<Option<_>>()?,
+        ),
+        (Values::Float(a), Values::Float(b)) => {
+            Values::Float(indices.map(|(i, j)| float_op(a[i], b[j])).collect())
+        }
+        _ => return None,
+    };
+
+    Some(Constant {
+        elem_type: lhs.elem_type.clone(),
+        shape,
+        values,
+    })
+}
+
+/// The flat index in the values of the given shape of each element of the broadcast shape, in
+/// row-major order.
+fn broadcast_indices(shape: &[usize], broadcast: &[usize]) -> Vec<usize> {
+    // The shapes are aligned on the right, and the dimensions of size one repeat their index 0
+    let offset = broadcast.len() - shape.len();
+    let axes = broadcast
+        .iter()
+        .enumerate()
+        .map(|(axis, &dim)| match axis.checked_sub(offset) {
+            Some(axis) if shape[axis] == dim => (0..dim).collect(),
+            _ => vec![0; dim],
+        })
+        .collect::<Vec<_>>();
+    let padded = (0..broadcast.len())
+        .map(|axis| axis.checked_sub(offset).map_or(1, |axis| shape[axis]))
+        .collect::<Vec<_>>();
+
+    flat_indices(&padded, &axes)
+}
+
+fn cast(node: &Node, inputs: &[Option<Constant>]) -> Option<Constant> {
+    let input = input(inputs, 0)?.clone();
+    let elem_type = match node.attrs.get("to") {
+        Some(AttributeValue::Int64(to)) => match DataType::from_i32(*to as i32)? {
+            DataType::INT32 => ElementType::Int32,
+            DataType::INT64 => ElementType::Int64,
+            DataType::FLOAT => ElementType::Float32,
+            DataType::DOUBLE => ElementType::Float64,
+            DataType::FLOAT16 => ElementType::Float16,
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    // Floats are truncated toward zero when cast to integers
+    let values = match (&elem_type, input.values) {
+        (ElementType::Int32 | ElementType::Int64, Values::Float(values)) => {
+            Values::Int(values.into_iter().map(|v| v as i64).collect())
+        }
+        (
+            ElementType::Float32 | ElementType::Float64 | ElementType::Float16,
+            Values::Int(values),
+        ) => Values::Float(values.into_iter().map(|v| v as f64).collect()),
+        (_, values) => values,
+    };
+
+    // The floats are rounded to the precision they are cast to
+    let values = match (&elem_type, values) {
+        (ElementType::Float32, Values::Float(values)) => {
+            Values::Float(values.into_iter().map(|v| v as f32 as f64).collect())
+        }
+        (ElementType::Float16, Values::Float(values)) => Values::Float(
+            values
+                .into_iter()
+                .map(|v| f16::from_f64(v).to_f64())
+                .collect(),
+        ),
+        (_, values) => values,
+    };
+
+    Some(Constant {
+        elem_type,
+        shape: input.shape,
+        values,
+    })
+}
+
+fn concat(node: &Node, inputs: &[Option<Constant>]) -> Option<Constant> {
+    let inputs = inputs.iter().flatten().collect::<Vec<_>>();
+    let first = inputs.first()?;
+    let rank = first.shape.len();
+    let axis = match node.attrs.get("axis") {
+        Some(AttributeValue::Int64(axis)) => normalize_axis(*axis, rank)?,
+        _ => return None,
+    };
+
+    // The inputs must have the same shape except for the concatenated axis
+    let same_shape = |input: &Constant| {
+        input.shape.len() == rank
+            && (0..rank).all(|d| d == axis || input.shape[d] == first.shape[d])
+    };
+    if !inputs.iter().all(|input| same_shape(input)) {
+        return None;
+    }
+
+    let outer = first.shape[..axis].iter().product::<usize>();
+    let inner = first.shape[axis + 1..].iter().product::<usize>();
+
+    let mut shape = first.shape.clone();
+    shape[axis] = inputs.iter().map(|input| input.shape[axis]).sum();
+
+    // Each input contributes a chunk of its values for each index of the outer dimensions
+    let mut values = first.values.select(&[]);
+    for o in 0..outer {
+        for input in inputs.iter() {
+            let size = input.shape[axis] * inner;
+            let chunk = (o * size..(o + 1) * size).collect::<Vec<_>>();
+            values.extend(input.values.select(&chunk))?;
+        }
+    }
+
+    Some(Constant {
+        elem_type: first.elem_type.clone(),
+        shape,
+        values,
+    })
+}
+
+fn constant_of_shape(node: &Node, inputs: &[Option<Constant>]) -> Option<Constant> {
+    let shape = input(inputs, 0)?
+        .ints()?
+        .iter()
+        .map(|&dim| usize::try_from(dim).ok())
+        .collect::<Option<Vec<_>>>()?;
+
+    // The value defaults to a float zero
+    let value = match node.attrs.get("value") {
+        Some(value @ AttributeValue::Tensor(_)) => {
+            Constant::from_argument(&Argument::from(value.clone()))?
+        }
+        None => Constant {
+            elem_type: ElementType::Float32,
+            shape: Vec::new(),
+            values: Values::Float(vec![0.0]),
+        },
+        _ => return None,
+    };
+
+    let numel = shape.iter().product::<usize>();
+
+    Some(Constant {
+        elem_type: value.elem_type,
+        shape,
+        values: value.values.select(&vec![0; numel]),
+    })
+}
+
+fn gather(node: &Node, inputs: &[Option<Constant>]) -> Option<Constant> {
+    let (data, indices) = (input(inputs, 0)?, input(inputs, 1)?);
+    let rank = data.shape.len();
+    let axis = match node.attrs.get("axis") {
+        Some(AttributeValue::Int64(axis)) => normalize_axis(*axis, rank)?,
+        _ => normalize_axis(0, rank)?,
+    };
+
+    let selected = indices
+        .ints()?
+        .iter()
+        .map(|&index| normalize_axis(index, data.shape[axis]))
+        .collect::<Option<Vec<_>>>()?;
+
+    let axes = data
+        .shape
+        .iter()
+        .enumerate()
+        .map(|(d, &dim)| {
+            if d == axis {
+                selected.clone()
+            } else {
+                (0..dim).collect()
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // The gathered axis is replaced by the shape of the indices
+    let shape = [
+        &data.shape[..axis],
+        &indices.shape[..],
+        &data.shape[axis + 1..],
+    ]
+    .concat();
+
+    Some(Constant {
+        elem_type: data.elem_type.clone(),
+        shape,
+        values: data.values.select(&flat_indices(&data.shape, &axes)),
+    })
+}
+
+fn reshape(inputs: &[Option<Constant>]) -> Option<Constant> {
+    let (data, shape) = (input(inputs, 0)?, input(inputs, 1)?);
+    let shape = shape.ints()?;
+
+    // A zero copies the dimension of the input, and the -1 dimension is inferred
+    let mut dims = shape
+        .iter()
+        .enumerate()
+        .map(|(d, &dim)| match dim {
+            0 => data.shape.get(d).copied(),
+            -1 => Some(1),
+            dim => usize::try_from(dim).ok(),
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    if let Some(inferred) = shape.iter().position(|&dim| dim == -1) {
+        let known = dims.iter().product::<usize>();
+        if known == 0 || data.numel() % known != 0 {
+            return None;
+        }
+        dims[inferred] = data.numel() / known;
+    }
+
+    (dims.iter().product::<usize>() == data.numel()).then(|| data.clone().with_shape(dims))
+}
+
+fn shape(node: &Node, inputs: &[Option<Constant>]) -> Option<Constant> {
+    let shape = input(inputs, 0)?
+        .shape
+        .iter()
+        .map(|&dim| dim as i64)
+        .collect::<Vec<_>>();
+    let rank = shape.len() as i64;
+
+    // Since opset 15, a slice of the shape can be selected
+    let clamp = |index: i64| {
+        let index = if index < 0 { index + rank } else { index };
+        index.clamp(0, rank) as usize
+    };
+    let start = match node.attrs.get("start") {
+        Some(AttributeValue::Int64(start)) => clamp(*start),
+        _ => 0,
+    };
+    let end = match node.attrs.get("end") {
+        Some(AttributeValue::Int64(end)) => clamp(*end),
+        _ => rank as usize,
+    };
+
+    let values = shape[start..end.max(start)].to_vec();
+
+    Some(Constant::int(vec![values.len()], values))
+}
+
+fn slice(inputs: &[Option<Constant>]) -> Option<Constant> {
+    let data = input(inputs, 0)?;
+    let rank = data.shape.len();
+    let starts = input(inputs, 1)?.ints()?;
+    let ends = input(inputs, 2)?.ints()?;
+    let axes = match input(inputs, 3) {
+        Some(axes) => axes.ints()?.to_vec(),
+        None => (0..starts.len() as i64).collect(),
+    };
+    let steps = match input(inputs, 4) {
+        Some(steps) => steps.ints()?.to_vec(),
+        None => vec![1; starts.len()],
+    };
+
+    if ends.len() != starts.len() || axes.len() != starts.len() || steps.len() != starts.len() {
+        return None;
+    }
+
+    let mut ranges = data
+        .shape
+        .iter()
+        .map(|&dim| (0..dim).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    for (((&start, &end), &axis), &step) in starts.iter().zip(ends).zip(&axes).zip(&steps) {
+        let axis = normalize_axis(axis, rank)?;
+        let dim = data.shape[axis] as i64;
+
+        // Negative indices are counted from the end, and the indices are clamped
+        let clamp = |index: i64, (min, max): (i64, i64)| {
+            let index = if index < 0 { index + dim } else { index };
+            index.clamp(min, max)
+        };
+
+        ranges[axis] = match step {
+            step if step > 0 => {
+                let (start, end) = (clamp(start, (0, dim)), clamp(end, (0, dim)));
+                (start..end.max(start))
+                    .step_by(step as usize)
+                    .map(|i| i as usize)
+                    .collect()
+            }
+            step if step < 0 => {
+                let (start, end) = (clamp(start, (0, dim - 1)), clamp(end, (-1, dim - 1)));
+                let mut indices = Vec::new();
+                let mut i = start;
+                while i > end {
+                    indices.push(i as usize);
+                    i += step;
+                }
+                indices
+            }
+            _ => return None,
+        };
+    }
+
+    let shape = ranges.iter().map(Vec::len).collect();
+
+    Some(Constant {
+        elem_type: data.elem_type.clone(),
+        values: data.values.select(&flat_indices(&data.shape, &ranges)),
+        shape,
+    })
+}
+
+fn squeeze(node: &Node, inputs: &[Option<Constant>]) -> Option<Constant> {
+    let data = input(inputs, 0)?;
+    let rank = data.shape.len();
+
+    // Without axes, all the dimensions of size 1 are removed
+    let axes = match axes(node, inputs, 1) {
+        Some(axes) => axes
+            .into_iter()
+            .map(|axis| normalize_axis(axis, rank))
+            .collect::<Option<Vec<_>>>()?,
+        None => (0..rank).filter(|&d| data.shape[d] == 1).collect(),
+    };
+
+    if axes.iter().any(|&axis| data.shape[axis] != 1) {
+        return None;
+    }
+
+    let shape = (0..rank)
+        .filter(|d| !axes.contains(d))
+        .map(|d| data.shape[d])
+        .collect();
+
+    Some(data.clone().with_shape(shape))
+}
+
+fn unsqueeze(node: &Node, inputs: &[Option<Constant>]) -> Option<Constant> {
+    let data = input(inputs, 0)?;
+    let axes = axes(node, inputs, 1)?;
+    let rank = data.shape.len() + axes.len();
+
+    let axes = axes
+        .into_iter()
+        .map(|axis| normalize_axis(axis, rank))
+        .collect::<Option<Vec<_>>>()?;
+
+    // The new dimensions of size 1 are inserted at the axes of the output
+    let mut dims = data.shape.iter();
+    let shape = (0..rank)
+        .map(|d| {
+            if axes.contains(&d) {
+                Some(1)
+            } else {
+                dims.next().copied()
+            }
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(data.clone().with_shape(shape))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn float(elem_type: ElementType, shape: Vec<usize>, values: Vec<f64>) -> Option<Constant> {
+        Some(Constant {
+            elem_type,
+            shape,
+            values: Values::Float(values),
+        })
+    }
+
+    #[test]
+    fn binary_broadcasts_numpy_shapes() {
+        let inputs = [
+            Some(Constant::int(vec![2, 1], vec![10, 20])),
+            Some(Constant::int(vec![3], vec![1, 2, 3])),
+        ];
+
+        let output = binary(&inputs, i64::checked_add, |a, b| a + b).unwrap();
+
+        assert_eq!(output.shape, vec![2, 3]);
+        assert_eq!(output.ints().unwrap(), [11, 12, 13, 21, 22, 23]);
+    }
+
+    #[test]
+    fn binary_isnt_folded_when_shapes_cant_be_broadcast() {
+        let inputs = [
+            Some(Constant::int(vec![2], vec![1, 2])),
+            Some(Constant::int(vec![3], vec![1, 2, 3])),
+        ];
+
+        assert!(binary(&inputs, i64::checked_add, |a, b| a + b).is_none());
+    }
+
+    #[test]
+    fn int32_overflow_isnt_folded() {
+        let mut constant = Constant::int(vec![1], vec![i32::MAX as i64 + 1]);
+        constant.elem_type = ElementType::Int32;
+
+        assert!(constant.into_tensor().is_none());
+    }
+
+    #[test]
+    fn doubles_are_computed_in_double_precision() {
+        let inputs = [
+            float(ElementType::Float64, vec![], vec![0.1]),
+            float(ElementType::Float64, vec![], vec![0.2]),
+        ];
+
+        let output = binary(&inputs, i64::checked_add, |a, b| a + b).unwrap();
+
+        assert!(matches!(
+            output.into_tensor().unwrap().data,
+            Some(Data::Float64s(values)) if values == [0.1 + 0.2]
+        ));
+    }
+}
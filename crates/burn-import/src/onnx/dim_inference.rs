@@ -1,53 +1,80 @@
 use core::cmp::max;
-use core::panic;
 
+use burn::nn::{PaddingConfig1d, PaddingConfig2d};
 use protobuf::Enum;
 
 use super::{
     from_onnx::OnnxGraphIO,
     ir::{ArgType, AttributeValue, Data, ElementType, Node, NodeType, TensorType},
-    op_configuration::flatten_config,
+    op_configuration::{
+        concat_config, conv1d_config, conv2d_config, conv_transpose2d_config,
+        depth_to_space_config, flatten_config, gather_nd_config, gemm_config, max_pool1d_config,
+        max_pool2d_config, one_hot_config, pool_output_size, range_config, range_len,
+        reduce_config, shape_config, space_to_depth_config, split_config, squeeze_config,
+        top_k_config, transpose_config, unsqueeze_config,
+    },
     protos::tensor_proto::DataType,
 };
 
 /// Infer the dimension of each output tensor and update them.
 pub fn dim_inference(node: &mut Node, graph_io: &mut OnnxGraphIO) {
     match node.node_type {
+        NodeType::Abs => same_as_input(node),
         NodeType::Add => same_as_input(node),
         NodeType::ArgMax => argmax_update_outputs(node),
         NodeType::AveragePool1d => same_as_input(node),
         NodeType::AveragePool2d => same_as_input(node),
         NodeType::BatchNormalization => same_as_input(node),
+        NodeType::BitShift => broadcast_update_outputs(node),
+        NodeType::BitwiseAnd => broadcast_update_outputs(node),
+        NodeType::BitwiseOr => broadcast_update_outputs(node),
+        NodeType::BitwiseXor => broadcast_update_outputs(node),
         NodeType::Cast => cast_update_outputs(node),
+        NodeType::Ceil => same_as_input(node),
         NodeType::Clip => same_as_input(node),
         NodeType::Concat => concat_update_outputs(node),
         NodeType::Constant => constant_update_outputs(node),
         NodeType::Conv1d => conv1d_update_outputs(node),
         NodeType::Conv2d => conv2d_update_outputs(node),
         NodeType::Cos => same_as_input(node),
+        NodeType::CumSum => same_as_input(node),
         NodeType::Div => same_as_input(node),
         NodeType::Dropout => same_as_input(node),
+        NodeType::Einsum => einsum_update_outputs(node),
         NodeType::Equal => equal_update_outputs(node),
         NodeType::Erf => same_as_input(node),
         NodeType::Exp => same_as_input(node),
         NodeType::Expand => expand_update_outputs(node),
         NodeType::Flatten => flatten_update_outputs(node),
+        NodeType::Floor => same_as_input(node),
         NodeType::Gelu => same_as_input(node),
+        NodeType::Gemm => gemm_update_outputs(node),
         NodeType::Gather => gather_update_outputs(node),
-        NodeType::GatherElements => same_as_input(node),
-        NodeType::GlobalAveragePool => same_as_input(node),
+        NodeType::GatherElements => gather_elements_update_outputs(node),
+        NodeType::GatherND => gather_nd_update_outputs(node),
+        NodeType::GlobalAveragePool => global_pool_update_outputs(node),
+        NodeType::GlobalMaxPool => global_pool_update_outputs(node),
+        NodeType::GroupNormalization => same_as_input(node),
+        NodeType::HardSigmoid => same_as_input(node),
+        NodeType::HardSwish => same_as_input(node),
         NodeType::ConvTranspose2d => conv_transpose2d_update_outputs(node),
+        NodeType::InstanceNormalization => same_as_input(node),
         NodeType::LayerNormalization => same_as_input(node),
         NodeType::Linear => linear_update_outputs(node),
+        NodeType::GRU => gru_update_outputs(node),
+        NodeType::LSTM => lstm_update_outputs(node),
         NodeType::Log => same_as_input(node),
         NodeType::LogSoftmax => same_as_input(node),
         NodeType::MatMul => matmul_update_outputs(node),
+        NodeType::MeanVarianceNormalization => same_as_input(node),
         NodeType::Min => same_as_input(node),
         NodeType::Max => same_as_input(node),
-        NodeType::MaxPool1d => same_as_input(node),
-        NodeType::MaxPool2d => same_as_input(node),
+        NodeType::MaxPool1d => max_pool1d_update_outputs(node),
+        NodeType::MaxPool2d => max_pool2d_update_outputs(node),
+        NodeType::Mod => broadcast_update_outputs(node),
         NodeType::Mul => same_as_input(node),
         NodeType::Neg => same_as_input(node),
+        NodeType::NonZero => nonzero_update_outputs(node),
         NodeType::Not => same_as_input(node),
         NodeType::Greater => greater_update_outputs(node),
         NodeType::GreaterOrEqual => greater_or_equal_update_outputs(node),
@@ -55,25 +82,41 @@ pub fn dim_inference(node: &mut Node, graph_io: &mut OnnxGraphIO) {
         NodeType::LessOrEqual => less_or_equal_update_outputs(node),
         NodeType::Range => range_update_outputs(node),
         NodeType::Reciprocal => same_as_input(node),
-        NodeType::ReduceMax => reduce_max_update_outputs(node),
-        NodeType::ReduceMean => reduce_mean_update_outputs(node),
-        NodeType::ReduceSum => reduce_sum_update_outputs(node),
+        NodeType::ReduceMax => reduce_update_outputs(node),
+        NodeType::ReduceMean => reduce_update_outputs(node),
+        NodeType::ReduceMin => reduce_update_outputs(node),
+        NodeType::ReduceSum => reduce_update_outputs(node),
         NodeType::Relu => same_as_input(node),
         NodeType::Reshape => reshape_update_outputs(node),
+        NodeType::Resize => resize_update_outputs(node),
+        NodeType::Round => same_as_input(node),
+        NodeType::ScatterElements => same_as_input(node),
+        NodeType::ScatterND => same_as_input(node),
         NodeType::Shape => shape_update_outputs(node),
         NodeType::Sigmoid => same_as_input(node),
         NodeType::Sign => same_as_input(node),
         NodeType::Sin => same_as_input(node),
+        NodeType::Slice => slice_update_outputs(node),
+        NodeType::Split => split_update_outputs(node),
+        NodeType::TopK => top_k_update_outputs(node),
+        NodeType::OneHot => one_hot_update_outputs(node),
         NodeType::Softmax => same_as_input(node),
+        NodeType::Softplus => same_as_input(node),
+        NodeType::Softsign => same_as_input(node),
         NodeType::Sqrt => same_as_input(node),
         NodeType::Sub => same_as_input(node),
         NodeType::Sum => same_as_input(node),
         NodeType::Tanh => same_as_input(node),
-        NodeType::Transpose => same_as_input(node),
+        NodeType::Transpose => transpose_update_outputs(node),
+        NodeType::Trilu => same_as_input(node),
+        NodeType::Unique => unique_update_outputs(node),
         NodeType::Unsqueeze => unsqueeze_update_output(node),
-        NodeType::Pow => same_as_input(node),
+        NodeType::Pow => broadcast_update_outputs(node),
         NodeType::LeakyRelu => same_as_input(node),
-        NodeType::PRelu => same_as_input(node),
+        NodeType::Pad => pad_update_outputs(node),
+        NodeType::DepthToSpace => depth_to_space_update_outputs(node),
+        NodeType::SpaceToDepth => space_to_depth_update_outputs(node),
+        NodeType::PRelu => prelu_update_outputs(node),
         NodeType::Where => where_update_outputs(node),
         NodeType::Squeeze => squeeze_update_output(node),
         NodeType::RandomUniform => random_update_output(node),
@@ -181,7 +224,8 @@ fn linear_update_outputs(node: &mut Node) {
         if let Some(mut shape) = tensor.shape.clone() {
             if let ArgType::Tensor(weight_tensor) = weight.clone().ty {
                 let last = shape.last_mut().unwrap();
-                *last = *weight_tensor.shape.unwrap().first().unwrap();
+                // The weight is stored as [d_input, d_output]
+                *last = *weight_tensor.shape.unwrap().last().unwrap();
             } else {
                 panic!("Weight must be a tensor");
             }
@@ -206,17 +250,19 @@ fn cast_update_outputs(node: &mut Node) {
     // Extract cast type and update the output tensor
     let elem_type = match node.attrs.get("to") {
         Some(value) => match &value {
-            AttributeValue::Int64(type_id) => match DataType::from_i32(*type_id as i32).unwrap() {
-                DataType::FLOAT => ElementType::Float32,
-                DataType::INT32 => ElementType::Int32,
-                DataType::INT64 => ElementType::Int64,
-                DataType::DOUBLE => ElementType::Float64,
-                DataType::BOOL => ElementType::Bool,
-                _ => panic!("Cast: unsupported type"),
+            AttributeValue::Int64(type_id) => match DataType::from_i32(*type_id as i32) {
+                Some(DataType::FLOAT) => ElementType::Float32,
+                Some(DataType::FLOAT16) => ElementType::Float16,
+                Some(DataType::INT32) => ElementType::Int32,
+                Some(DataType::INT64) => ElementType::Int64,
+                Some(DataType::DOUBLE) => ElementType::Float64,
+                Some(DataType::BOOL) => ElementType::Bool,
+                Some(data_type) => panic!("Cast: unsupported target type {data_type:?}"),
+                None => panic!("Cast: unknown target type {type_id}"),
             },
             _ => panic!("'to' attribute must be an Int64"),
         },
-        None => panic!("Constant node must have a value attribute"),
+        None => panic!("Cast node must have a 'to' attribute"),
     };
 
     match input.ty.clone() {
@@ -248,16 +294,37 @@ fn cast_update_outputs(node: &mut Node) {
 }
 
 fn concat_update_outputs(node: &mut Node) {
-    let tensor = node
+    let tensors: Vec<TensorType> = node
         .inputs
         .iter()
-        .find_map(|input| match &input.ty {
-            ArgType::Tensor(tensor) => Some(tensor),
+        .filter_map(|input| match &input.ty {
+            ArgType::Tensor(tensor) => Some(tensor.clone()),
             _ => None,
         })
-        .unwrap();
+        .collect();
+
+    // The concatenated dimension is the sum of the input dimensions when all the shapes are known
+    let shape = match tensors.len() == node.inputs.len() {
+        true => {
+            let axis = concat_config(node);
+
+            tensors
+                .iter()
+                .map(|tensor| tensor.shape.clone())
+                .collect::<Option<Vec<_>>>()
+                .map(|shapes| {
+                    let mut shape = shapes[0].clone();
+                    shape[axis] = shapes.iter().map(|shape| shape[axis]).sum();
+                    shape
+                })
+        }
+        false => tensors[0].shape.clone(),
+    };
 
-    node.outputs[0].ty = ArgType::Tensor(tensor.clone());
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        shape,
+        ..tensors[0].clone()
+    });
 }
 
 fn reshape_update_outputs(node: &mut Node) {
@@ -287,6 +354,240 @@ fn reshape_update_outputs(node: &mut Node) {
     }
 }
 
+/// The supported Einsum equations, permutations and matrix products, keep the rank of the
+/// first input.
+fn einsum_update_outputs(node: &mut Node) {
+    match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => {
+            node.outputs[0].ty = ArgType::Tensor(TensorType {
+                shape: None, // shape is calculated at runtime
+                ..tensor.clone()
+            });
+        }
+        _ => panic!("Einsum: invalid input types"),
+    }
+}
+
+/// Infers the shape of the GRU outputs `Y` and `Y_h`.
+fn gru_update_outputs(node: &mut Node) {
+    let input = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("GRU: invalid input types"),
+    };
+
+    for (index, output) in node.outputs.iter_mut().enumerate() {
+        output.ty = ArgType::Tensor(TensorType {
+            dim: if index == 0 { 4 } else { 3 },
+            shape: None, // shape is calculated at runtime
+            ..input.clone()
+        });
+    }
+}
+
+/// Infers the shape of the LSTM outputs `Y`, `Y_h` and `Y_c`.
+fn lstm_update_outputs(node: &mut Node) {
+    let input = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("LSTM: invalid input types"),
+    };
+
+    for (index, output) in node.outputs.iter_mut().enumerate() {
+        output.ty = ArgType::Tensor(TensorType {
+            dim: if index == 0 { 4 } else { 3 },
+            shape: None, // shape is calculated at runtime
+            ..input.clone()
+        });
+    }
+}
+
+fn slice_update_outputs(node: &mut Node) {
+    match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => {
+            node.outputs[0].ty = ArgType::Tensor(TensorType {
+                shape: None, // shape is calculated at runtime
+                ..tensor.clone()
+            });
+        }
+        _ => panic!("Slice: invalid input types"),
+    }
+}
+
+fn pad_update_outputs(node: &mut Node) {
+    match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => {
+            node.outputs[0].ty = ArgType::Tensor(TensorType {
+                shape: None, // shape is calculated at runtime
+                ..tensor.clone()
+            });
+        }
+        _ => panic!("Pad: invalid input types"),
+    }
+}
+
+/// The channels are divided by the number of pixels of a block and the spatial dimensions
+/// multiplied by the block size.
+fn depth_to_space_update_outputs(node: &mut Node) {
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) if tensor.dim == 4 => tensor.clone(),
+        ty => panic!("DepthToSpace: expected a tensor of rank 4, got {ty:?}"),
+    };
+    let (block_size, _) = depth_to_space_config(node);
+
+    let shape = tensor.shape.as_ref().map(|shape| {
+        if shape[1] % (block_size * block_size) != 0 {
+            panic!(
+                "DepthToSpace: {} channels can't be divided in blocks of size {block_size}",
+                shape[1]
+            );
+        }
+
+        vec![
+            shape[0],
+            shape[1] / (block_size * block_size),
+            shape[2] * block_size,
+            shape[3] * block_size,
+        ]
+    });
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType { shape, ..tensor });
+}
+
+/// The inverse of [DepthToSpace](depth_to_space_update_outputs).
+fn space_to_depth_update_outputs(node: &mut Node) {
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) if tensor.dim == 4 => tensor.clone(),
+        ty => panic!("SpaceToDepth: expected a tensor of rank 4, got {ty:?}"),
+    };
+    let block_size = space_to_depth_config(node);
+
+    let shape = tensor.shape.as_ref().map(|shape| {
+        if shape[2] % block_size != 0 || shape[3] % block_size != 0 {
+            panic!(
+                "SpaceToDepth: spatial shape {:?} can't be divided in blocks of size {block_size}",
+                &shape[2..]
+            );
+        }
+
+        vec![
+            shape[0],
+            shape[1] * block_size * block_size,
+            shape[2] / block_size,
+            shape[3] / block_size,
+        ]
+    });
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType { shape, ..tensor });
+}
+
+/// The values keep the type of the input and the indices are int64, both with `k` elements along
+/// the axis.
+/// Infers the shape of a OneHot node, the depth being inserted at the axis of the indices shape.
+///
+/// The output has the element type of the values.
+fn one_hot_update_outputs(node: &mut Node) {
+    let indices = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("OneHot: invalid input types"),
+    };
+    let elem_type = match &node.inputs[2].ty {
+        ArgType::Tensor(values) => values.elem_type.clone(),
+        _ => panic!("OneHot: the values must be a tensor"),
+    };
+    let (depth, _, axis) = one_hot_config(node);
+
+    let shape = indices.shape.map(|mut shape| {
+        shape.insert(axis, depth);
+        shape
+    });
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type,
+        dim: indices.dim + 1,
+        shape,
+    });
+}
+
+fn top_k_update_outputs(node: &mut Node) {
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("TopK: invalid input types"),
+    };
+    let (k, axis, _) = top_k_config(node);
+
+    let shape = tensor.shape.as_ref().map(|shape| {
+        let mut shape = shape.clone();
+        if k > shape[axis] {
+            panic!(
+                "TopK: k={k} is larger than the size {} of the axis",
+                shape[axis]
+            );
+        }
+        shape[axis] = k;
+        shape
+    });
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        shape: shape.clone(),
+        ..tensor.clone()
+    });
+    node.outputs[1].ty = ArgType::Tensor(TensorType {
+        elem_type: ElementType::Int64,
+        shape,
+        ..tensor
+    });
+}
+
+fn split_update_outputs(node: &mut Node) {
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("Split: invalid input types"),
+    };
+    let (axis, sizes) = split_config(node);
+    let num_outputs = node.outputs.len();
+
+    // The shapes are known when the input shape is, the last chunk being smaller when the
+    // dimension isn't divisible by the number of outputs.
+    let shapes: Vec<Option<Vec<usize>>> = match &tensor.shape {
+        Some(shape) => {
+            let sizes = sizes.unwrap_or_else(|| {
+                let chunk_size = shape[axis].div_ceil(num_outputs);
+                (0..num_outputs)
+                    .map(|i| chunk_size.min(shape[axis].saturating_sub(i * chunk_size)))
+                    .collect()
+            });
+
+            sizes
+                .into_iter()
+                .map(|size| {
+                    let mut shape = shape.clone();
+                    shape[axis] = size;
+                    Some(shape)
+                })
+                .collect()
+        }
+        None => vec![None; num_outputs],
+    };
+
+    for (output, shape) in node.outputs.iter_mut().zip(shapes) {
+        output.ty = ArgType::Tensor(TensorType {
+            shape,
+            ..tensor.clone()
+        });
+    }
+}
+
+fn resize_update_outputs(node: &mut Node) {
+    match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => {
+            node.outputs[0].ty = ArgType::Tensor(TensorType {
+                shape: None, // shape is calculated at runtime
+                ..tensor.clone()
+            });
+        }
+        _ => panic!("Resize: invalid input types"),
+    }
+}
+
 fn greater_update_outputs(node: &mut Node) {
     match &node.inputs[0].ty {
         ArgType::Tensor(tensor) => {
@@ -335,36 +636,66 @@ fn less_or_equal_update_outputs(node: &mut Node) {
     }
 }
 
-fn reduce_mean_update_outputs(node: &mut Node) {
-    if node.inputs.len() != 1 {
-        panic!("Mean: multiple inputs are not supported");
-    }
-
-    let node_input = &mut node.inputs[0];
-    let tensor = match node_input.clone().ty {
+/// Infers the shape of a ReduceMax, ReduceMean, ReduceMin or ReduceSum node and replaces the
+/// shape of the output tensor.
+fn reduce_update_outputs(node: &mut Node) {
+    let tensor = match node.inputs[0].clone().ty {
         ArgType::Tensor(tensor) => tensor,
         _ => panic!("Only tensor input is valid"),
     };
 
-    let dim_only = match node.attrs.get("axes") {
-        Some(value) => match &value {
-            AttributeValue::Int64(_) => true,
-            AttributeValue::Int64s(ints) => ints.len() == 1,
-            _ => false,
+    let (dims, keepdims) = reduce_config(node);
+
+    node.outputs[0].ty = ArgType::Tensor(match dims {
+        // NOTE: Reducing over all dims w/o keepdims reduces to a scalar value, but Burn doesn't
+        // have 0-dim tensor so we can't track or perform other ops on that value if we call
+        // `.into_scalar()` on the result of `tensor.sum()`
+        // Instead, we return a tensor of rank 1 (the result of `tensor.sum()`)
+        None => TensorType {
+            dim: 1,
+            shape: Some(vec![1]),
+            ..tensor
+        },
+        Some(dims) if keepdims => TensorType {
+            shape: tensor.shape.map(|shape| {
+                shape
+                    .iter()
+                    .enumerate()
+                    .map(|(dim, &size)| if dims.contains(&dim) { 1 } else { size })
+                    .collect()
+            }),
+            ..tensor
         },
-        None => false,
+        Some(dims) => TensorType {
+            dim: tensor.dim - dims.len(),
+            shape: tensor.shape.map(|shape| {
+                shape
+                    .iter()
+                    .enumerate()
+                    .filter(|(dim, _)| !dims.contains(dim))
+                    .map(|(_, &size)| size)
+                    .collect()
+            }),
+            ..tensor
+        },
+    });
+}
+
+/// Infers the shape of a Transpose node by permuting the shape of the input.
+fn transpose_update_outputs(node: &mut Node) {
+    let tensor = match node.inputs[0].clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("Only tensor input is valid"),
     };
 
-    if dim_only {
-        node.outputs[0].ty = ArgType::Tensor(tensor);
-    } else {
-        // NOTE: ReduceMean w/o keepdims reduces to a scalar value, but Burn doesn't have
-        // 0-dim tensor so we can't track or perform other ops on that value if we call
-        // `.into_scalar()` on the result of `tensor.max()`
-        // node.outputs[0].ty = ArgType::Scalar(tensor.elem_type);
-        // Instead, we return a tensor of rank 1 (the result of `tensor.max()`)
-        node.outputs[0].ty = ArgType::Tensor(TensorType { dim: 1, ..tensor });
-    }
+    let perm = transpose_config(node);
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        shape: tensor
+            .shape
+            .map(|shape| perm.iter().map(|&axis| shape[axis]).collect()),
+        ..tensor
+    });
 }
 
 fn argmax_update_outputs(node: &mut Node) {
@@ -386,31 +717,12 @@ fn argmax_update_outputs(node: &mut Node) {
     });
 }
 
-/// Update the output tensor dimension
+/// Update the output tensor dimension, and its shape when the input shape is known
 fn squeeze_update_output(node: &mut Node) {
-    let axes = if node.inputs.len() == 2 {
-        match &node.inputs[1].value {
-            Some(value) => match value {
-                Data::Int64s(axes) => Some(axes.clone()),
-                _ => panic!("Squeeze: invalid input types"),
-            },
-            None => None,
-        }
-    } else {
-        node.attrs.get("axes").cloned().map(|v| v.into_i64s())
-    };
-
-    if axes.is_none() {
-        panic!("Squeeze must specify an axis");
-    } else if axes.as_ref().unwrap().len() > 1 {
-        panic!(
-            "Squeeze must specify only 1 axis, found {:?}",
-            axes.as_ref().unwrap().len()
-        );
-    }
+    let axes = squeeze_config(node);
 
-    let input_dim = match &node.inputs[0].ty {
-        ArgType::Tensor(tensor) => tensor.dim,
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
         _ => panic!("Squeeze: invalid input type"),
     };
 
@@ -420,33 +732,59 @@ fn squeeze_update_output(node: &mut Node) {
     };
 
     node.outputs[0].ty = ArgType::Tensor(TensorType {
-        dim: input_dim - 1,
-        shape: None, // shape is tracked and calculated at runtime
+        dim: tensor.dim - axes.len(),
+        shape: tensor.shape.map(|shape| {
+            shape
+                .into_iter()
+                .enumerate()
+                .filter(|(axis, _)| !axes.contains(&(*axis as i64)))
+                .map(|(_, size)| size)
+                .collect()
+        }),
         elem_type: output_elem,
     });
 }
 
-/// Update the output tensor dimension based on the "axes" attribute or the second input
-fn unsqueeze_update_output(node: &mut Node) {
-    let axes = if node.inputs.len() == 2 {
-        match &node.inputs[1].value {
-            Some(value) => match value {
-                Data::Int64s(axes) => Some(axes.clone()),
-                _ => panic!("Unsqueeze: invalid input types"),
-            },
-            None => None,
-        }
-    } else {
-        node.attrs.get("axes").cloned().map(|v| v.into_i64s())
+/// Update the output tensor dimension based on the "axes" attribute or the second input, and
+/// its shape when the input shape is known
+/// The unique values and the optional indices and counts are vectors sized at runtime.
+fn unique_update_outputs(node: &mut Node) {
+    let elem_type = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.elem_type.clone(),
+        _ => panic!("Unique: only tensor input is valid"),
     };
 
-    if axes.is_none() {
+    for (index, output) in node.outputs.iter_mut().enumerate() {
+        let elem_type = match index {
+            0 => elem_type.clone(),
+            _ => ElementType::Int64,
+        };
+
+        output.ty = ArgType::Tensor(TensorType {
+            elem_type,
+            dim: 1,
+            shape: None, // shape is calculated at runtime
+        });
+    }
+}
+
+fn unsqueeze_update_output(node: &mut Node) {
+    // Axes computed at runtime are handled by remapping the node to a reshape
+    if !node.attrs.contains_key("axes")
+        && node
+            .inputs
+            .get(1)
+            .and_then(|axes| axes.value.as_ref())
+            .is_none()
+    {
         return;
     }
 
-    let input_dim = match &node.inputs[0].ty {
-        ArgType::Tensor(tensor) => tensor.dim,
-        ArgType::Scalar(_) => 0, // treat scalar as 0-dim tensor
+    let axes = unsqueeze_config(node);
+
+    let (input_dim, input_shape) = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => (tensor.dim, tensor.shape.clone()),
+        ArgType::Scalar(_) => (0, Some(Vec::new())), // treat scalar as 0-dim tensor
         _ => panic!("Unsqueeze: invalid input type"),
     };
 
@@ -456,13 +794,18 @@ fn unsqueeze_update_output(node: &mut Node) {
         _ => panic!("Unsqueeze: invalid output type"),
     };
 
-    if let Some(axes) = axes {
-        node.outputs[0].ty = ArgType::Tensor(TensorType {
-            dim: input_dim + axes.len(),
-            shape: None, // shape is tracked and calculated at runtime
-            elem_type: output_elem,
-        });
-    }
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        dim: input_dim + axes.len(),
+        shape: input_shape.map(|shape| {
+            // The axes are sorted, so each dimension is inserted at its final position
+            let mut shape = shape;
+            for &axis in axes.iter() {
+                shape.insert(axis as usize, 1);
+            }
+            shape
+        }),
+        elem_type: output_elem,
+    });
 }
 
 fn same_as_input(node: &mut Node) {
@@ -507,36 +850,96 @@ fn expand_update_outputs(node: &mut Node) {
         panic!("Expand: invalid number of inputs");
     };
 
+    let input = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("Expand: invalid input types"),
+    };
+
     let output = match &node.outputs[0].ty {
         ArgType::Tensor(tensor) => tensor.clone(),
         _ => panic!("Expand: invalid output types"),
     };
 
     if let Some(shape) = shape {
+        // The shape is only known when the input shape is fully known
+        let output_shape = input
+            .shape
+            .as_ref()
+            .filter(|input_shape| !input_shape.contains(&0))
+            .map(|input_shape| expand_shape(input_shape, &shape));
+
         node.outputs[0].ty = ArgType::Tensor(TensorType {
-            dim: shape.len(),
-            shape: None, // shape is calculated at runtime
+            dim: usize::max(input.dim, shape.len()),
+            shape: output_shape,
             ..output
         });
     }
 }
 
+/// Broadcast the input shape to the target shape of an Expand node, following numpy rules.
+///
+/// Both shapes are aligned from the last dimension. A dimension of size 1 is expanded to the size
+/// of the other shape, which means a size of 1 in the target shape keeps the size of the input.
+fn expand_shape(input_shape: &[usize], shape: &[i64]) -> Vec<usize> {
+    let rank = usize::max(input_shape.len(), shape.len());
+
+    (0..rank)
+        .map(|axis| {
+            let input_size = (axis + input_shape.len())
+                .checked_sub(rank)
+                .map_or(1, |index| input_shape[index]);
+            let size = (axis + shape.len())
+                .checked_sub(rank)
+                .map_or(1, |index| shape[index]);
+
+            if size < 1 {
+                panic!("Expand: invalid size {size} at dimension {axis} of the shape {shape:?}");
+            }
+
+            match size as usize {
+                1 => input_size,
+                size if input_size == 1 || input_size == size => size,
+                size => panic!(
+                    "Expand: the input shape {input_shape:?} can't be broadcast to {shape:?}, \
+                    dimension {axis} of size {input_size} can't be expanded to {size}"
+                ),
+            }
+        })
+        .collect()
+}
+
 fn shape_update_outputs(node: &mut Node) {
     if node.inputs.len() != 1 {
         panic!("Shape: multiple inputs are not supported: {:?}", node);
     }
 
-    let node_input = &mut node.inputs[0];
-    if let ArgType::Tensor(_tensor) = node_input.clone().ty {
-        // Output tensor is 1D int64
-        node.outputs[0].ty = ArgType::Tensor(TensorType {
-            elem_type: ElementType::Int64,
-            dim: 1,
-            ..Default::default()
-        });
-    } else {
-        panic!("Only tensor input is valid");
+    let (start, end) = shape_config(node);
+
+    // Output tensor is 1D int64, with one element per selected dimension
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type: ElementType::Int64,
+        dim: 1,
+        shape: Some(vec![end - start]),
+    });
+}
+
+/// Infers the type of the coordinates of the non-zero elements of a NonZero node, one row per
+/// dimension of the input.
+///
+/// The number of non-zero elements depends on the values of the input, so the shape of the output
+/// is left unknown and the operators requiring a static shape reject it.
+fn nonzero_update_outputs(node: &mut Node) {
+    match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) if tensor.dim > 0 => {}
+        ArgType::Tensor(_) => panic!("NonZero: scalar tensors are not supported"),
+        _ => panic!("NonZero: only tensor input is valid"),
     }
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type: ElementType::Int64,
+        dim: 2,
+        shape: None, // shape is calculated at runtime
+    });
 }
 
 /// Infers the shape of a Flatten node and replaces the shape of the output tensor.
@@ -553,46 +956,288 @@ fn flatten_update_outputs(node: &mut Node) {
         })
         .unwrap();
 
-    let input_dim = tensor.dim;
+    let axis = flatten_config(node);
 
-    let (start_dim, end_dim) = flatten_config(node);
+    // The output is a matrix, dynamic dimensions being read as 0 make its shape unknown
+    let shape = tensor
+        .shape
+        .as_ref()
+        .filter(|shape| !shape.contains(&0))
+        .map(|shape| {
+            let (rows, cols) = shape.split_at(axis);
+            vec![rows.iter().product(), cols.iter().product()]
+        });
 
-    let collapsed_dims = end_dim - start_dim;
-    let output_dim = input_dim - collapsed_dims;
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        dim: 2,
+        shape,
+        ..tensor.clone()
+    });
+}
+
+/// Infers the shape of a global pooling node, which keeps the batch and the channels and reduces
+/// each spatial dimension to 1.
+fn global_pool_update_outputs(node: &mut Node) {
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("Global pooling: the input must be a tensor"),
+    };
+
+    let shape = tensor.shape.as_ref().map(|shape| {
+        shape
+            .iter()
+            .enumerate()
+            .map(|(axis, &size)| if axis < 2 { size } else { 1 })
+            .collect()
+    });
 
     node.outputs[0].ty = ArgType::Tensor(TensorType {
-        dim: output_dim,
+        shape,
         ..tensor.clone()
     });
 }
 
+/// Checks that the slope of a PRelu node broadcasts against the channels of the input, then
+/// updates the output like the input.
+fn prelu_update_outputs(node: &mut Node) {
+    let (input, slope) = match (&node.inputs[0].ty, &node.inputs[1].ty) {
+        (ArgType::Tensor(input), ArgType::Tensor(slope)) => (input, slope),
+        _ => panic!("PRelu: the input and the slope must be tensors"),
+    };
+
+    if let Some(slope_shape) = &slope.shape {
+        if slope_shape.len() > input.dim {
+            panic!(
+                "PRelu: the slope of shape {slope_shape:?} has a higher rank than the input ({})",
+                input.dim
+            );
+        }
+
+        // The slope is broadcast from the last dimension, only the channels can differ from 1
+        let offset = input.dim - slope_shape.len();
+        for (axis, &size) in (offset..).zip(slope_shape.iter()) {
+            if size == 1 {
+                continue;
+            }
+            if axis != 1 {
+                panic!(
+                    "PRelu: the slope of shape {slope_shape:?} must have a single value or one \
+                     value per channel"
+                );
+            }
+
+            let channels = input.shape.as_ref().map_or(0, |shape| shape[1]);
+            if channels != 0 && channels != size {
+                panic!("PRelu: the slope has {size} values for {channels} channels");
+            }
+        }
+    }
+
+    same_as_input(node);
+}
+
 /// Infers the shape of a Conv1d node and replaces the shape of the output tensor.
 fn conv1d_update_outputs(node: &mut Node) {
-    // extract the channels from the weight tensor's shape [out_channels, in_channels, ...]
-    if let ArgType::Tensor(tensor) = node.inputs[0].clone().ty {
-        node.outputs[0].ty = ArgType::Tensor(tensor);
+    let mut tensor = if let ArgType::Tensor(tensor) = node.inputs[0].clone().ty {
+        tensor
     } else {
         panic!("Only tensor input is valid");
-    }
+    };
+
+    // The padding computed from auto_pad depends on the shape of the input
+    tensor.shape = tensor.shape.clone().map(|shape| {
+        let (config, pre_pads) = conv1d_config(node);
+        let padding = match (pre_pads, &config.padding) {
+            (Some([left, right]), _) => left + right,
+            (None, PaddingConfig1d::Explicit(padding)) => 2 * padding,
+            (None, _) => 0,
+        };
+        let length = conv_output_size(
+            shape[2] + padding,
+            config.kernel_size,
+            config.stride,
+            config.dilation,
+        );
+
+        vec![shape[0], config.channels_out, length]
+    });
+
+    node.outputs[0].ty = ArgType::Tensor(tensor);
 }
 
 /// Infers the shape of a Conv2d node and replaces the shape of the output tensor.
 fn conv2d_update_outputs(node: &mut Node) {
-    // extract the channels from the weight tensor's shape [out_channels, in_channels, ...]
-    if let ArgType::Tensor(tensor) = node.inputs[0].clone().ty {
-        node.outputs[0].ty = ArgType::Tensor(tensor);
+    let mut tensor = if let ArgType::Tensor(tensor) = node.inputs[0].clone().ty {
+        tensor
     } else {
         panic!("Only tensor input is valid");
+    };
+
+    // The padding computed from auto_pad depends on the shape of the input
+    tensor.shape = tensor.shape.clone().map(|shape| {
+        let (config, pre_pads) = conv2d_config(node);
+        let padding = match (pre_pads, &config.padding) {
+            (Some([left, right, top, bottom]), _) => [top + bottom, left + right],
+            (None, PaddingConfig2d::Explicit(height, width)) => [2 * height, 2 * width],
+            (None, _) => [0, 0],
+        };
+        let spatial = |i: usize| {
+            conv_output_size(
+                shape[i + 2] + padding[i],
+                config.kernel_size[i],
+                config.stride[i],
+                config.dilation[i],
+            )
+        };
+
+        vec![shape[0], config.channels[1], spatial(0), spatial(1)]
+    });
+
+    node.outputs[0].ty = ArgType::Tensor(tensor);
+}
+
+/// Size of a spatial dimension of the output of a convolution, given the padded input size.
+fn conv_output_size(padded: usize, kernel_size: usize, stride: usize, dilation: usize) -> usize {
+    (padded - dilation * (kernel_size - 1) - 1) / stride + 1
+}
+
+fn max_pool1d_update_outputs(node: &mut Node) {
+    let mut tensor = if let ArgType::Tensor(tensor) = node.inputs[0].clone().ty {
+        tensor
+    } else {
+        panic!("MaxPool1d: only tensor input is valid");
+    };
+
+    tensor.shape = tensor.shape.clone().map(|shape| {
+        let config = max_pool1d_config(node);
+        let padding = match config.padding {
+            PaddingConfig1d::Explicit(padding) => padding,
+            _ => 0,
+        };
+        let length = pool_output_size(
+            shape[2],
+            padding,
+            config.kernel_size,
+            config.stride,
+            config.dilation,
+            config.ceil_mode,
+        );
+
+        vec![shape[0], shape[1], length]
+    });
+
+    node.outputs[0].ty = ArgType::Tensor(tensor);
+}
+
+fn max_pool2d_update_outputs(node: &mut Node) {
+    let mut tensor = if let ArgType::Tensor(tensor) = node.inputs[0].clone().ty {
+        tensor
+    } else {
+        panic!("MaxPool2d: only tensor input is valid");
+    };
+
+    // The rounding of the ceil mode changes the spatial dimensions
+    tensor.shape = tensor.shape.clone().map(|shape| {
+        let config = max_pool2d_config(node);
+        let padding = match config.padding {
+            PaddingConfig2d::Explicit(height, width) => [height, width],
+            _ => [0, 0],
+        };
+        let spatial = |i: usize| {
+            pool_output_size(
+                shape[i + 2],
+                padding[i],
+                config.kernel_size[i],
+                config.strides[i],
+                config.dilation[i],
+                config.ceil_mode,
+            )
+        };
+
+        vec![shape[0], shape[1], spatial(0), spatial(1)]
+    });
+
+    // The optional indices of the maximum values
+    if let Some(indices) = node.outputs.get_mut(1) {
+        indices.ty = ArgType::Tensor(TensorType {
+            elem_type: ElementType::Int64,
+            ..tensor.clone()
+        });
     }
+
+    node.outputs[0].ty = ArgType::Tensor(tensor);
 }
 
 /// Infers the shape of a ConvTranspose2d node and replaces the shape of the output tensor.
 fn conv_transpose2d_update_outputs(node: &mut Node) {
-    // extract the channels from the weight tensor's shape [out_channels, in_channels, ...]
-    if let ArgType::Tensor(tensor) = node.inputs[0].clone().ty {
-        node.outputs[0].ty = ArgType::Tensor(tensor);
+    let tensor = if let ArgType::Tensor(tensor) = node.inputs[0].clone().ty {
+        tensor
     } else {
         panic!("Only tensor input is valid");
+    };
+
+    // The output size of a transposed convolution differs from a regular convolution
+    let shape = tensor.shape.clone().map(|shape| {
+        let config = conv_transpose2d_config(node);
+        let spatial = |i: usize| {
+            (shape[i + 2] - 1) * config.stride[i]
+                + config.dilation[i] * (config.kernel_size[i] - 1)
+                + config.padding_out[i]
+                + 1
+                - 2 * config.padding[i]
+        };
+
+        vec![shape[0], config.channels[1], spatial(0), spatial(1)]
+    });
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType { shape, ..tensor });
+}
+
+/// The output of a Gemm node not converted into a Linear node is a [M, N] matrix.
+fn gemm_update_outputs(node: &mut Node) {
+    let (_, _, trans_a, trans_b) = gemm_config(node);
+
+    match (&node.inputs[0].ty, &node.inputs[1].ty) {
+        (ArgType::Tensor(a), ArgType::Tensor(b)) => {
+            let rows = |tensor: &TensorType, transpose: bool| match tensor.shape.as_deref() {
+                Some(&[rows, cols]) => Some(if transpose { cols } else { rows }),
+                _ => None,
+            };
+            let cols = |tensor: &TensorType, transpose: bool| rows(tensor, !transpose);
+            let shape = rows(a, trans_a)
+                .zip(cols(b, trans_b))
+                .map(|(m, n)| vec![m, n]);
+
+            node.outputs[0].ty = ArgType::Tensor(TensorType {
+                elem_type: a.elem_type.clone(),
+                dim: 2,
+                shape,
+            });
+        }
+        _ => panic!("Gemm: only tensor inputs are valid"),
+    }
+}
+
+/// The inputs of the elementwise operators are broadcast, the output having the largest rank.
+fn broadcast_update_outputs(node: &mut Node) {
+    match (&node.inputs[0].ty, &node.inputs[1].ty) {
+        (ArgType::Tensor(lhs), ArgType::Tensor(rhs)) => {
+            let shape = lhs
+                .shape
+                .as_ref()
+                .zip(rhs.shape.as_ref())
+                .map(|(lhs, rhs)| broadcast_shape(&format!("{:?}", node.node_type), &[lhs, rhs]));
+
+            node.outputs[0].ty = ArgType::Tensor(TensorType {
+                elem_type: lhs.elem_type.clone(),
+                dim: lhs.dim.max(rhs.dim),
+                shape,
+            });
+        }
+        (ArgType::Tensor(lhs), ArgType::Scalar(_)) => {
+            node.outputs[0].ty = ArgType::Tensor(lhs.clone());
+        }
+        _ => panic!("{:?}: only tensor inputs are valid", node.node_type),
     }
 }
 
@@ -600,125 +1245,165 @@ fn matmul_update_outputs(node: &mut Node) {
     // NOTE: matmul only supported for float tensors
     match (node.inputs[0].ty.clone(), node.inputs[1].ty.clone()) {
         (ArgType::Tensor(a), ArgType::Tensor(b)) => {
-            // With broadcasting support, output dim has to be computed based on the inputs
-            let mut out_dim = max(a.dim, b.dim);
-
-            // Matrix-vector or vector-matrix product
-            if (a.dim >= 2 && b.dim == 1) || (a.dim == 1 && b.dim >= 2) {
-                out_dim -= 1;
-            }
+            // Vectors are promoted to matrices, and the batch dimensions are broadcast
+            let rank = max(max(a.dim, b.dim), 2);
+            let out_dim = (rank - (a.dim == 1) as usize - (b.dim == 1) as usize).max(1);
+
+            // Dynamic dimensions are read as 0, the output shape is then unknown
+            let shape = match (a.shape, b.shape) {
+                (Some(a_shape), Some(b_shape))
+                    if !a_shape.contains(&0) && !b_shape.contains(&0) =>
+                {
+                    Some(matmul_shape(&a_shape, &b_shape))
+                }
+                _ => None,
+            };
 
             node.outputs[0].ty = ArgType::Tensor(TensorType {
                 elem_type: a.elem_type.clone(),
                 dim: out_dim,
-                shape: a.shape.clone(),
+                shape,
             });
         }
         _ => panic!("Only tensor input is valid"),
     }
 }
 
-fn range_update_outputs(node: &mut Node) {
-    if node.inputs.len() != 3 {
-        panic!("Range: expected 3 inputs, found {}", node.inputs.len());
+/// Output shape of a matmul following the numpy broadcasting rules.
+fn matmul_shape(a: &[usize], b: &[usize]) -> Vec<usize> {
+    if a.len() == 1 && b.len() == 1 {
+        return vec![1];
     }
 
-    node.outputs[0].ty = ArgType::Tensor(TensorType {
-        elem_type: ElementType::Int64,
-        dim: 1,
-        shape: None,
-    });
-}
+    // Vectors are promoted to matrices: [k] @ [.., k, n] as [1, k] and [.., m, k] @ [k] as [k, 1]
+    let a_matrix = if a.len() == 1 {
+        vec![1, a[0]]
+    } else {
+        a.to_vec()
+    };
+    let b_matrix = if b.len() == 1 {
+        vec![b[0], 1]
+    } else {
+        b.to_vec()
+    };
 
-/// Infers the shape of a ReduceMax node and replaces the shape of the output tensor.
-fn reduce_max_update_outputs(node: &mut Node) {
-    if node.inputs.len() != 1 {
-        panic!("ReduceMax: multiple inputs are not supported");
-    }
+    let (a_batch, a_matrix) = a_matrix.split_at(a_matrix.len() - 2);
+    let (b_batch, b_matrix) = b_matrix.split_at(b_matrix.len() - 2);
 
-    let node_input = &mut node.inputs[0];
-    let tensor = match node_input.clone().ty {
-        ArgType::Tensor(tensor) => tensor,
-        _ => panic!("Only tensor input is valid"),
-    };
+    if a_matrix[1] != b_matrix[0] {
+        panic!(
+            "MatMul: inner dimensions differ, got shapes {:?} and {:?}",
+            a, b
+        );
+    }
 
-    let dim_only = match node.attrs.get("axes") {
-        Some(value) => match &value {
-            AttributeValue::Int64(_) => true,
-            AttributeValue::Int64s(ints) => ints.len() == 1,
-            _ => false,
-        },
-        None => false,
-    };
+    // Broadcast the batch dimensions aligned on the right
+    let batch_rank = max(a_batch.len(), b_batch.len());
+    let mut shape = (0..batch_rank)
+        .map(|i| {
+            let a_dim = (i + a_batch.len())
+                .checked_sub(batch_rank)
+                .map(|i| a_batch[i]);
+            let b_dim = (i + b_batch.len())
+                .checked_sub(batch_rank)
+                .map(|i| b_batch[i]);
+
+            match (a_dim.unwrap_or(1), b_dim.unwrap_or(1)) {
+                (1, dim) | (dim, 1) => dim,
+                (a_dim, b_dim) if a_dim == b_dim => a_dim,
+                _ => panic!(
+                    "MatMul: batch dimensions can't be broadcast, got shapes {:?} and {:?}",
+                    a, b
+                ),
+            }
+        })
+        .collect::<Vec<_>>();
 
-    if dim_only {
-        node.outputs[0].ty = ArgType::Tensor(tensor);
-    } else {
-        // NOTE: ReduceMax w/o keepdims reduces to a scalar value, but Burn doesn't have
-        // 0-dim tensor so we can't track or perform other ops on that value if we call
-        // `.into_scalar()` on the result of `tensor.max()`
-        // node.outputs[0].ty = ArgType::Scalar(tensor.elem_type);
-        // Instead, we return a tensor of rank 1 (the result of `tensor.max()`)
-        node.outputs[0].ty = ArgType::Tensor(TensorType { dim: 1, ..tensor });
+    // The dimensions added to the vectors are removed from the output
+    if a.len() != 1 {
+        shape.push(a_matrix[0]);
     }
+    if b.len() != 1 {
+        shape.push(b_matrix[1]);
+    }
+
+    shape
 }
 
-/// Infers the shape of a ReduceSum node and replaces the shape of the output tensor.
-fn reduce_sum_update_outputs(node: &mut Node) {
-    let node_input = &mut node.inputs[0];
-    let tensor = match node_input.clone().ty {
-        ArgType::Tensor(tensor) => tensor,
-        _ => panic!("Only tensor input is valid"),
+fn range_update_outputs(node: &mut Node) {
+    // The length is only known when the three values are constants
+    let shape = match range_config(node) {
+        [Some(start), Some(limit), Some(delta)] => Some(vec![range_len(start, limit, delta)]),
+        _ => None,
     };
 
-    let dim_only = match node.attrs.get("axes") {
-        Some(value) => match &value {
-            AttributeValue::Int64(_) => true,
-            AttributeValue::Int64s(ints) => ints.len() == 1,
-            _ => false,
-        },
-        None => false,
-    };
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type: ElementType::Int64,
+        dim: 1,
+        shape,
+    });
+}
 
-    let dim_only = match node.inputs.get(1).and_then(|arg| arg.value.as_ref()) {
-        Some(value) => match &value {
-            Data::Int64(_) => true,
-            Data::Int64s(ints) => ints.len() == 1,
-            _ => false,
+fn where_update_outputs(node: &mut Node) {
+    // A scalar condition is broadcast as a tensor of rank 0
+    let condition = match &node.inputs[0].ty {
+        ArgType::Tensor(condition) => condition.clone(),
+        ArgType::Scalar(elem_type) => TensorType {
+            elem_type: elem_type.clone(),
+            dim: 0,
+            shape: Some(Vec::new()),
         },
-        None => dim_only,
+        _ => panic!("Where: only tensor condition is valid"),
     };
 
-    if dim_only {
-        node.outputs[0].ty = ArgType::Tensor(tensor);
-    } else {
-        // NOTE: ReduceSum w/o keepdims reduces to a scalar value, but Burn doesn't have
-        // 0-dim tensor so we can't track or perform other ops on that value if we call
-        // `.into_scalar()` on the result of `tensor.sum()`
-        // node.outputs[0].ty = ArgType::Scalar(tensor.elem_type);
-        // Instead, we return a tensor of rank 1 (the result of `tensor.sum()`)
-        node.outputs[0].ty = ArgType::Tensor(TensorType { dim: 1, ..tensor });
-    }
-}
+    match (node.inputs[1].ty.clone(), node.inputs[2].ty.clone()) {
+        (ArgType::Tensor(x), ArgType::Tensor(y)) => {
+            // The shape is only known when the shapes of the three inputs are fully known
+            let shapes = [&condition.shape, &x.shape, &y.shape]
+                .into_iter()
+                .map(|shape| shape.as_deref().filter(|shape| !shape.contains(&0)))
+                .collect::<Option<Vec<_>>>();
 
-fn where_update_outputs(node: &mut Node) {
-    match (
-        node.inputs[0].ty.clone(),
-        node.inputs[1].ty.clone(),
-        node.inputs[2].ty.clone(),
-    ) {
-        (ArgType::Tensor(condition), ArgType::Tensor(x), ArgType::Tensor(y)) => {
-            // With broadcasting support, output dim has to be computed based on the inputs
             node.outputs[0].ty = ArgType::Tensor(TensorType {
                 elem_type: x.elem_type.clone(),
                 dim: max(condition.dim, max(x.dim, y.dim)),
-                ..Default::default()
+                shape: shapes.map(|shapes| broadcast_shape("Where", &shapes)),
             });
         }
         _ => panic!("Only tensor input is valid"),
     }
 }
 
+/// Output shape of an element-wise operation following the numpy broadcasting rules.
+fn broadcast_shape(op: &str, shapes: &[&[usize]]) -> Vec<usize> {
+    try_broadcast_shape(shapes)
+        .unwrap_or_else(|| panic!("{op}: the shapes {shapes:?} can't be broadcast together"))
+}
+
+/// Output shape of an element-wise operation following the numpy broadcasting rules, `None` when
+/// the shapes can't be broadcast together.
+pub(crate) fn try_broadcast_shape(shapes: &[&[usize]]) -> Option<Vec<usize>> {
+    let rank = shapes.iter().map(|shape| shape.len()).max().unwrap_or(0);
+
+    (0..rank)
+        .map(|axis| {
+            // The shapes are aligned on the right
+            let mut dims = shapes.iter().map(|shape| {
+                (axis + shape.len())
+                    .checked_sub(rank)
+                    .map_or(1, |index| shape[index])
+            });
+
+            dims.try_fold(1, |size, dim| match (size, dim) {
+                (1, dim) => Some(dim),
+                (size, 1) => Some(size),
+                (size, dim) if size == dim => Some(size),
+                _ => None,
+            })
+        })
+        .collect()
+}
+
 fn gather_update_outputs(node: &mut Node) {
     if node.inputs.len() != 2 {
         panic!("Gather requires two inputs: data and indices");
@@ -729,17 +1414,23 @@ fn gather_update_outputs(node: &mut Node) {
         _ => panic!("Only tensor input is valid"),
     };
 
-    let indices_tensor = match &node.inputs[1].ty {
-        ArgType::Tensor(tensor) => tensor,
+    // A scalar index has a rank of 0
+    let indices_rank = match &node.inputs[1].ty {
+        ArgType::Tensor(tensor) => tensor.dim,
+        ArgType::Scalar(_) => 0,
         _ => panic!("Only tensor indices is valid"),
     };
 
-    if indices_tensor.dim != 1 {
+    if indices_rank > 1 {
         panic!("Gather: indices tensor rank above 1 not supported")
     }
 
     // Output of rank q+(r-1), where q is rank of indices tensor and r is rank of input
-    let output_rank = indices_tensor.dim + input_tensor.dim - 1;
+    let output_rank = indices_rank + input_tensor.dim - 1;
+
+    if output_rank == 0 {
+        panic!("Gather: a scalar index on a 1D tensor is not supported");
+    }
 
     node.outputs[0].ty = ArgType::Tensor(TensorType {
         dim: output_rank,
@@ -747,3 +1438,517 @@ fn gather_update_outputs(node: &mut Node) {
         elem_type: input_tensor.elem_type.clone(),
     });
 }
+
+/// The output has the elements of the data and the shape of the indices.
+fn gather_elements_update_outputs(node: &mut Node) {
+    let (data, indices) = match (&node.inputs[0].ty, &node.inputs[1].ty) {
+        (ArgType::Tensor(data), ArgType::Tensor(indices)) => (data, indices),
+        _ => panic!("GatherElements: only tensor data and indices are valid"),
+    };
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type: data.elem_type.clone(),
+        dim: data.dim,
+        shape: indices.shape.clone(),
+    });
+}
+
+fn gather_nd_update_outputs(node: &mut Node) {
+    let tuple_size = gather_nd_config(node);
+    let (data, indices) = match (&node.inputs[0].ty, &node.inputs[1].ty) {
+        (ArgType::Tensor(data), ArgType::Tensor(indices)) => (data, indices),
+        _ => panic!("GatherND: only tensor data and indices are valid"),
+    };
+
+    // The batch axes of the indices followed by the axes of the gathered slices
+    let dim = indices.dim - 1 + data.dim - tuple_size;
+
+    if dim == 0 {
+        panic!("GatherND: gathering a single element as a scalar is not supported");
+    }
+
+    let shape = match (&data.shape, &indices.shape) {
+        (Some(data), Some(indices)) => Some(
+            indices[..indices.len() - 1]
+                .iter()
+                .chain(&data[tuple_size..])
+                .copied()
+                .collect(),
+        ),
+        _ => None,
+    };
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type: data.elem_type.clone(),
+        dim,
+        shape,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::onnx::ir::Argument;
+
+    fn transpose_node(perm: Option<Vec<i64>>) -> Node {
+        let mut input = Argument::new("input".to_string());
+        input.ty = ArgType::Tensor(TensorType {
+            elem_type: ElementType::Float32,
+            dim: 4,
+            shape: Some(vec![2, 3, 4, 5]),
+        });
+
+        Node {
+            node_type: NodeType::Transpose,
+            name: "transpose1".to_string(),
+            inputs: vec![input],
+            outputs: vec![Argument::new("output".to_string())],
+            attrs: perm
+                .map(|perm| ("perm".to_string(), AttributeValue::Int64s(perm)))
+                .into_iter()
+                .collect(),
+            opset_version: 16,
+        }
+    }
+
+    fn axes_node(node_type: NodeType, shape: Vec<usize>, axes: Vec<i64>) -> Node {
+        let mut input = Argument::new("input".to_string());
+        input.ty = ArgType::Tensor(TensorType {
+            elem_type: ElementType::Float32,
+            dim: shape.len(),
+            shape: Some(shape),
+        });
+
+        // Axes as a constant input, as since opset 13
+        let mut axes_input = Argument::new("axes".to_string());
+        axes_input.ty = ArgType::Tensor(TensorType {
+            elem_type: ElementType::Int64,
+            dim: 1,
+            shape: Some(vec![axes.len()]),
+        });
+        axes_input.value = Some(Data::Int64s(axes));
+
+        Node {
+            node_type,
+            name: "node1".to_string(),
+            inputs: vec![input, axes_input],
+            outputs: vec![Argument::new("output".to_string())],
+            attrs: Default::default(),
+            opset_version: 13,
+        }
+    }
+
+    fn output_shape(node: &Node) -> Option<Vec<usize>> {
+        match &node.outputs[0].ty {
+            ArgType::Tensor(tensor) => tensor.shape.clone(),
+            ty => panic!("Expected a tensor output, got {ty:?}"),
+        }
+    }
+
+    fn conv2d_node(input_shape: Vec<usize>, auto_pad: &str, stride: i64) -> Node {
+        let mut input = Argument::new("input".to_string());
+        input.ty = ArgType::Tensor(TensorType {
+            elem_type: ElementType::Float32,
+            dim: 4,
+            shape: Some(input_shape),
+        });
+
+        let mut weight = Argument::new("weight".to_string());
+        weight.ty = ArgType::Tensor(TensorType {
+            elem_type: ElementType::Float32,
+            dim: 4,
+            shape: Some(vec![8, 3, 3, 3]),
+        });
+
+        Node {
+            node_type: NodeType::Conv2d,
+            name: "conv1".to_string(),
+            inputs: vec![input, weight],
+            outputs: vec![Argument::new("output".to_string())],
+            attrs: [
+                (
+                    "kernel_shape".to_string(),
+                    AttributeValue::Int64s(vec![3, 3]),
+                ),
+                (
+                    "strides".to_string(),
+                    AttributeValue::Int64s(vec![stride, stride]),
+                ),
+                (
+                    "auto_pad".to_string(),
+                    AttributeValue::String(auto_pad.to_string()),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+            opset_version: 16,
+        }
+    }
+
+    #[test]
+    fn conv2d_same_padding_keeps_the_size_divided_by_the_stride() {
+        // ceil(input / stride) for each spatial dimension, as in TensorFlow.
+        let mut node = conv2d_node(vec![1, 3, 6, 7], "SAME_UPPER", 2);
+        conv2d_update_outputs(&mut node);
+        assert_eq!(output_shape(&node), Some(vec![1, 8, 3, 4]));
+
+        let mut node = conv2d_node(vec![1, 3, 6, 7], "SAME_LOWER", 1);
+        conv2d_update_outputs(&mut node);
+        assert_eq!(output_shape(&node), Some(vec![1, 8, 6, 7]));
+
+        let mut node = conv2d_node(vec![1, 3, 6, 7], "VALID", 1);
+        conv2d_update_outputs(&mut node);
+        assert_eq!(output_shape(&node), Some(vec![1, 8, 4, 5]));
+    }
+
+    #[test]
+    fn conv2d_same_padding_puts_the_odd_padding_at_the_end_or_beginning() {
+        // A total padding of 1 in each dimension, which the conv can't apply symmetrically.
+        let node = conv2d_node(vec![1, 3, 6, 6], "SAME_UPPER", 2);
+        let (config, pre_pads) = conv2d_config(&node);
+        assert_eq!(config.padding, PaddingConfig2d::Valid);
+        assert_eq!(pre_pads, Some([0, 1, 0, 1]));
+
+        let node = conv2d_node(vec![1, 3, 6, 6], "SAME_LOWER", 2);
+        let (_, pre_pads) = conv2d_config(&node);
+        assert_eq!(pre_pads, Some([1, 0, 1, 0]));
+
+        // A total padding of 2 is applied by the conv.
+        let node = conv2d_node(vec![1, 3, 6, 6], "SAME_UPPER", 1);
+        let (config, pre_pads) = conv2d_config(&node);
+        assert_eq!(config.padding, PaddingConfig2d::Explicit(1, 1));
+        assert_eq!(pre_pads, None);
+    }
+
+    #[test]
+    fn transpose_permutes_the_shape() {
+        let mut node = transpose_node(Some(vec![0, 3, 1, 2]));
+        transpose_update_outputs(&mut node);
+        assert_eq!(output_shape(&node), Some(vec![2, 5, 3, 4]));
+
+        let mut node = transpose_node(None);
+        transpose_update_outputs(&mut node);
+        assert_eq!(output_shape(&node), Some(vec![5, 4, 3, 2]));
+    }
+
+    #[test]
+    #[should_panic(expected = "is not a permutation")]
+    fn transpose_rejects_invalid_perm() {
+        let mut node = transpose_node(Some(vec![0, 1, 1, 2]));
+        transpose_update_outputs(&mut node);
+    }
+
+    #[test]
+    fn unsqueeze_inserts_dims_in_order() {
+        let mut node = axes_node(NodeType::Unsqueeze, vec![3, 4], vec![-1, 1, 0]);
+        unsqueeze_update_output(&mut node);
+        assert_eq!(output_shape(&node), Some(vec![1, 1, 3, 4, 1]));
+    }
+
+    #[test]
+    fn squeeze_removes_dims() {
+        let mut node = axes_node(NodeType::Squeeze, vec![3, 1, 4, 1], vec![-1, 1]);
+        squeeze_update_output(&mut node);
+        assert_eq!(output_shape(&node), Some(vec![3, 4]));
+    }
+
+    fn matmul_node(lhs: Vec<usize>, rhs: Vec<usize>) -> Node {
+        let inputs = [("lhs", lhs), ("rhs", rhs)]
+            .into_iter()
+            .map(|(name, shape)| {
+                let mut input = Argument::new(name.to_string());
+                input.ty = ArgType::Tensor(TensorType {
+                    elem_type: ElementType::Float32,
+                    dim: shape.len(),
+                    shape: Some(shape),
+                });
+                input
+            })
+            .collect();
+
+        Node {
+            node_type: NodeType::MatMul,
+            name: "matmul1".to_string(),
+            inputs,
+            outputs: vec![Argument::new("output".to_string())],
+            attrs: Default::default(),
+            opset_version: 16,
+        }
+    }
+
+    fn output_dim(node: &Node) -> usize {
+        match &node.outputs[0].ty {
+            ArgType::Tensor(tensor) => tensor.dim,
+            ty => panic!("Expected a tensor output, got {ty:?}"),
+        }
+    }
+
+    #[test]
+    fn matmul_broadcasts_batch_dims() {
+        let mut node = matmul_node(vec![2, 3, 4], vec![4, 5]);
+        matmul_update_outputs(&mut node);
+        assert_eq!(output_dim(&node), 3);
+        assert_eq!(output_shape(&node), Some(vec![2, 3, 5]));
+
+        let mut node = matmul_node(vec![5, 1, 3, 4], vec![6, 4, 2]);
+        matmul_update_outputs(&mut node);
+        assert_eq!(output_dim(&node), 4);
+        assert_eq!(output_shape(&node), Some(vec![5, 6, 3, 2]));
+    }
+
+    #[test]
+    fn matmul_removes_vector_dims() {
+        let mut node = matmul_node(vec![4], vec![4, 5]);
+        matmul_update_outputs(&mut node);
+        assert_eq!(output_dim(&node), 1);
+        assert_eq!(output_shape(&node), Some(vec![5]));
+
+        let mut node = matmul_node(vec![2, 3, 4], vec![4]);
+        matmul_update_outputs(&mut node);
+        assert_eq!(output_dim(&node), 2);
+        assert_eq!(output_shape(&node), Some(vec![2, 3]));
+    }
+
+    #[test]
+    fn matmul_with_dynamic_dims_has_unknown_shape() {
+        let mut node = matmul_node(vec![0, 3, 4], vec![4, 5]);
+        matmul_update_outputs(&mut node);
+        assert_eq!(output_dim(&node), 3);
+        assert_eq!(output_shape(&node), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "inner dimensions differ")]
+    fn matmul_rejects_inner_dims_mismatch() {
+        let mut node = matmul_node(vec![2, 3], vec![4, 5]);
+        matmul_update_outputs(&mut node);
+    }
+
+    fn flatten_node(shape: Vec<usize>, axis: i64) -> Node {
+        let mut input = Argument::new("input".to_string());
+        input.ty = ArgType::Tensor(TensorType {
+            elem_type: ElementType::Float32,
+            dim: shape.len(),
+            shape: Some(shape),
+        });
+
+        Node {
+            node_type: NodeType::Flatten,
+            name: "flatten1".to_string(),
+            inputs: vec![input],
+            outputs: vec![Argument::new("output".to_string())],
+            attrs: [("axis".to_string(), AttributeValue::Int64(axis))]
+                .into_iter()
+                .collect(),
+            opset_version: 13,
+        }
+    }
+
+    #[test]
+    fn flatten_collapses_into_matrix() {
+        let mut node = flatten_node(vec![2, 3, 4, 5], 1);
+        flatten_update_outputs(&mut node);
+        assert_eq!(output_dim(&node), 2);
+        assert_eq!(output_shape(&node), Some(vec![2, 60]));
+
+        let mut node = flatten_node(vec![2, 3, 4, 5], -2);
+        flatten_update_outputs(&mut node);
+        assert_eq!(output_shape(&node), Some(vec![6, 20]));
+    }
+
+    #[test]
+    fn flatten_axis_0_makes_a_row() {
+        let mut node = flatten_node(vec![2, 3, 4, 5], 0);
+        flatten_update_outputs(&mut node);
+        assert_eq!(output_dim(&node), 2);
+        assert_eq!(output_shape(&node), Some(vec![1, 120]));
+    }
+
+    fn prelu_node(shape: Vec<usize>, slope_shape: Vec<usize>) -> Node {
+        let mut input = Argument::new("input".to_string());
+        input.ty = ArgType::Tensor(TensorType {
+            elem_type: ElementType::Float32,
+            dim: shape.len(),
+            shape: Some(shape),
+        });
+
+        let mut slope = Argument::new("slope".to_string());
+        slope.ty = ArgType::Tensor(TensorType {
+            elem_type: ElementType::Float32,
+            dim: slope_shape.len(),
+            shape: Some(slope_shape),
+        });
+
+        Node {
+            node_type: NodeType::PRelu,
+            name: "prelu1".to_string(),
+            inputs: vec![input, slope],
+            outputs: vec![Argument::new("output".to_string())],
+            attrs: Default::default(),
+            opset_version: 16,
+        }
+    }
+
+    #[test]
+    fn prelu_slope_broadcasts_against_channels() {
+        for slope_shape in [vec![1], vec![3, 1, 1], vec![1, 3, 1, 1]] {
+            let mut node = prelu_node(vec![2, 3, 4, 5], slope_shape);
+            prelu_update_outputs(&mut node);
+
+            assert_eq!(output_shape(&node), Some(vec![2, 3, 4, 5]));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "single value or one value per channel")]
+    fn prelu_rejects_slope_of_other_dims() {
+        // Broadcast against the last dimension, not the channels
+        let mut node = prelu_node(vec![2, 3, 4, 5], vec![5]);
+        prelu_update_outputs(&mut node);
+    }
+
+    #[test]
+    #[should_panic(expected = "4 values for 3 channels")]
+    fn prelu_rejects_slope_of_other_channels() {
+        let mut node = prelu_node(vec![2, 3, 4, 5], vec![4, 1, 1]);
+        prelu_update_outputs(&mut node);
+    }
+
+    #[test]
+    fn expand_broadcasts_dims_of_size_one() {
+        let mut node = axes_node(NodeType::Expand, vec![1, 3, 1], vec![2, 3, 4]);
+        expand_update_outputs(&mut node);
+
+        assert_eq!(output_shape(&node), Some(vec![2, 3, 4]));
+    }
+
+    #[test]
+    fn expand_keeps_input_size_for_target_of_one() {
+        // The target may also have less dimensions than the input, or more
+        let mut node = axes_node(NodeType::Expand, vec![2, 3, 1], vec![1, 4]);
+        expand_update_outputs(&mut node);
+        assert_eq!(output_shape(&node), Some(vec![2, 3, 4]));
+
+        let mut node = axes_node(NodeType::Expand, vec![3, 1], vec![2, 1, 1]);
+        expand_update_outputs(&mut node);
+        assert_eq!(output_shape(&node), Some(vec![2, 3, 1]));
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension 1 of size 3 can't be expanded to 4")]
+    fn expand_rejects_incompatible_dims() {
+        let mut node = axes_node(NodeType::Expand, vec![1, 3, 1], vec![2, 4, 4]);
+        expand_update_outputs(&mut node);
+    }
+
+    fn where_node(condition: ArgType, x: Vec<usize>, y: Vec<usize>) -> Node {
+        let mut inputs = vec![Argument::new("condition".to_string())];
+        inputs[0].ty = condition;
+
+        for (name, shape) in [("x", x), ("y", y)] {
+            let mut input = Argument::new(name.to_string());
+            input.ty = ArgType::Tensor(TensorType {
+                elem_type: ElementType::Float32,
+                dim: shape.len(),
+                shape: Some(shape),
+            });
+            inputs.push(input);
+        }
+
+        Node {
+            node_type: NodeType::Where,
+            name: "where1".to_string(),
+            inputs,
+            outputs: vec![Argument::new("output".to_string())],
+            attrs: Default::default(),
+            opset_version: 16,
+        }
+    }
+
+    #[test]
+    fn where_broadcasts_the_three_inputs() {
+        let condition = ArgType::Tensor(TensorType {
+            elem_type: ElementType::Bool,
+            dim: 3,
+            shape: Some(vec![4, 1, 1]),
+        });
+        let mut node = where_node(condition, vec![2, 1], vec![3]);
+        where_update_outputs(&mut node);
+
+        assert_eq!(output_shape(&node), Some(vec![4, 2, 3]));
+    }
+
+    #[test]
+    fn where_broadcasts_scalar_condition() {
+        let mut node = where_node(ArgType::Scalar(ElementType::Bool), vec![2, 3], vec![1]);
+        where_update_outputs(&mut node);
+
+        assert_eq!(output_shape(&node), Some(vec![2, 3]));
+    }
+
+    #[test]
+    #[should_panic(expected = "can't be broadcast together")]
+    fn where_rejects_incompatible_shapes() {
+        let mut node = where_node(ArgType::Scalar(ElementType::Bool), vec![2, 3], vec![2]);
+        where_update_outputs(&mut node);
+    }
+
+    fn range_node(values: [Option<i64>; 3]) -> Node {
+        let inputs = ["start", "limit", "delta"]
+            .into_iter()
+            .zip(values)
+            .map(|(name, value)| {
+                let mut input = Argument::new(name.to_string());
+                input.ty = ArgType::Scalar(ElementType::Int64);
+                input.value = value.map(Data::Int64);
+                input
+            })
+            .collect();
+
+        Node {
+            node_type: NodeType::Range,
+            name: "range1".to_string(),
+            inputs,
+            outputs: vec![Argument::new("output".to_string())],
+            attrs: Default::default(),
+            opset_version: 16,
+        }
+    }
+
+    #[test]
+    fn range_length_is_rounded_up() {
+        for (values, len) in [
+            ([0, 10, 2], 5),
+            ([0, 9, 2], 5),
+            ([10, 0, -3], 4),
+            ([-10, -2, 3], 3),
+            ([0, 10, -1], 0),
+            ([10, 0, 1], 0),
+        ] {
+            let mut node = range_node(values.map(Some));
+            range_update_outputs(&mut node);
+
+            assert_eq!(output_shape(&node), Some(vec![len]), "range {values:?}");
+        }
+    }
+
+    #[test]
+    fn range_length_is_unknown_for_runtime_values() {
+        let mut node = range_node([Some(0), None, Some(1)]);
+        range_update_outputs(&mut node);
+
+        assert_eq!(output_shape(&node), None);
+    }
+
+    #[test]
+    fn global_pool_reduces_spatial_dims_to_one() {
+        for node_type in [NodeType::GlobalAveragePool, NodeType::GlobalMaxPool] {
+            // An input of shape [2, 3, 4, 5]
+            let mut node = transpose_node(None);
+            node.node_type = node_type;
+            global_pool_update_outputs(&mut node);
+
+            assert_eq!(output_shape(&node), Some(vec![2, 3, 1, 1]));
+        }
+    }
+}
@@ -0,0 +1,496 @@
+use std::{fs::File, io::Write, path::Path};
+
+use burn::{
+    module::Module,
+    nn::{
+        conv::{Conv2d, Conv2dConfig},
+        pool::{AvgPool2dConfig, MaxPool2dConfig},
+        BatchNorm, BatchNormConfig, Linear, PaddingConfig2d,
+    },
+    tensor::{backend::Backend, ops::conv::calculate_conv_padding, Tensor},
+};
+use protobuf::Message;
+
+use super::{
+    ir::{
+        ArgType, Argument, AttributeValue, Attributes, Data, ElementType, Node, NodeType,
+        OnnxGraph, TensorType,
+    },
+    op_configuration::pool_output_size,
+    proto_conversion::convert_graph,
+};
+
+/// Version of the default ONNX operator set imported by the exported models.
+const OPSET_VERSION: usize = 16;
+
+/// Exporter of a sequence of Burn layers to an ONNX model, e.g. to run a model trained with Burn
+/// in another runtime.
+///
+/// Burn modules don't describe their forward pass, so the layers are added in the order they are
+/// applied, each one taking the output of the previous one. The weights are read from the
+/// modules, and the hyperparameters that modules don't expose from their configs. The output
+/// shape of each layer is computed from the input shape, which must be fully known.
+///
+/// # Example
+///
+/// ```ignore
+/// OnnxExporter::new([1, 784])
+///     .linear(&model.linear1)
+///     .relu()
+///     .linear(&model.linear2)
+///     .save("mlp.onnx")?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct OnnxExporter {
+    input: Argument,
+    output: Argument,
+    nodes: Vec<Node>,
+}
+
+impl OnnxExporter {
+    /// Create an exporter of a model with a single float input of the given shape.
+    pub fn new<const D: usize>(input_shape: [usize; D]) -> Self {
+        let input = float_argument("input", input_shape.to_vec(), None);
+
+        Self {
+            output: input.clone(),
+            input,
+            nodes: Vec::new(),
+        }
+    }
+
+    /// Add a [Linear] layer, exported as a `Gemm` node with transposed weights.
+    ///
+    /// # Panics
+    ///
+    /// If the input isn't a matrix, since `Gemm` only multiplies matrices.
+    pub fn linear<B: Backend>(self, linear: &Linear<B>) -> Self {
+        let shape = self.output_shape();
+        if shape.len() != 2 {
+            panic!("Linear: only inputs of rank 2 can be exported, got shape {shape:?}");
+        }
+
+        // Burn stores the weights as [d_input, d_output], Gemm with transB as [d_output, d_input]
+        let weight = linear.weight.val().transpose();
+        let [d_output, _] = weight.dims();
+        let name = self.node_name("linear");
+
+        let mut inputs = vec![float_initializer(format!("{name}.weight"), weight)];
+        if let Some(bias) = &linear.bias {
+            inputs.push(float_initializer(format!("{name}.bias"), bias.val()));
+        }
+
+        let attrs = [
+            ("alpha", AttributeValue::Float32(1.0)),
+            ("beta", AttributeValue::Float32(1.0)),
+            ("transB", AttributeValue::Int64(1)),
+        ];
+
+        self.node(
+            NodeType::Gemm,
+            name,
+            inputs,
+            attrs,
+            vec![shape[0], d_output],
+        )
+    }
+
+    /// Add a [Conv2d] layer, whose hyperparameters are read from its config.
+    pub fn conv2d<B: Backend>(self, config: &Conv2dConfig, conv: &Conv2d<B>) -> Self {
+        let shape = self.output_shape();
+        let padding = padding_2d(&config.padding, &shape, &config.kernel_size, &config.stride);
+        let size = |i: usize| {
+            pool_output_size(
+                shape[i + 2],
+                padding[i],
+                config.kernel_size[i],
+                config.stride[i],
+                config.dilation[i],
+                false,
+            )
+        };
+        let output_shape = vec![shape[0], config.channels[1], size(0), size(1)];
+        let name = self.node_name("conv2d");
+
+        let mut inputs = vec![float_initializer(
+            format!("{name}.weight"),
+            conv.weight.val(),
+        )];
+        if let Some(bias) = &conv.bias {
+            inputs.push(float_initializer(format!("{name}.bias"), bias.val()));
+        }
+
+        let attrs = [
+            ("kernel_shape", ints(&config.kernel_size)),
+            ("strides", ints(&config.stride)),
+            ("pads", pads(padding)),
+            ("dilations", ints(&config.dilation)),
+            ("group", AttributeValue::Int64(config.groups as i64)),
+        ];
+
+        self.node(NodeType::Conv, name, inputs, attrs, output_shape)
+    }
+
+    /// Add a [BatchNorm] layer, whose epsilon is read from its config.
+    pub fn batch_norm<B: Backend, const D: usize>(
+        self,
+        config: &BatchNormConfig,
+        norm: &BatchNorm<B, D>,
+    ) -> Self {
+        let shape = self.output_shape();
+        let name = self.node_name("batch_norm");
+        let record = norm.clone().into_record();
+
+        let inputs = vec![
+            float_initializer(format!("{name}.gamma"), record.gamma.val()),
+            float_initializer(format!("{name}.beta"), record.beta.val()),
+            float_initializer(format!("{name}.running_mean"), record.running_mean.val()),
+            float_initializer(format!("{name}.running_var"), record.running_var.val()),
+        ];
+
+        // ONNX weights the running statistics by their momentum instead of the batch statistics
+        let attrs = [
+            ("epsilon", AttributeValue::Float32(config.epsilon as f32)),
+            (
+                "momentum",
+                AttributeValue::Float32(1.0 - config.momentum as f32),
+            ),
+        ];
+
+        self.node(NodeType::BatchNormalization, name, inputs, attrs, shape)
+    }
+
+    /// Add a 2D max pooling layer.
+    pub fn max_pool2d(self, config: &MaxPool2dConfig) -> Self {
+        let shape = self.output_shape();
+        let padding = padding_2d(
+            &config.padding,
+            &shape,
+            &config.kernel_size,
+            &config.strides,
+        );
+        let size = |i: usize| {
+            pool_output_size(
+                shape[i + 2],
+                padding[i],
+                config.kernel_size[i],
+                config.strides[i],
+                config.dilation[i],
+                config.ceil_mode,
+            )
+        };
+        let output_shape = vec![shape[0], shape[1], size(0), size(1)];
+        let name = self.node_name("max_pool2d");
+
+        let attrs = [
+            ("kernel_shape", ints(&config.kernel_size)),
+            ("strides", ints(&config.strides)),
+            ("pads", pads(padding)),
+            ("dilations", ints(&config.dilation)),
+            ("ceil_mode", AttributeValue::Int64(config.ceil_mode as i64)),
+        ];
+
+        self.node(NodeType::MaxPool, name, vec![], attrs, output_shape)
+    }
+
+    /// Add a 2D average pooling layer.
+    pub fn avg_pool2d(self, config: &AvgPool2dConfig) -> Self {
+        let shape = self.output_shape();
+        let padding = padding_2d(
+            &config.padding,
+            &shape,
+            &config.kernel_size,
+            &config.strides,
+        );
+        let size = |i: usize| {
+            pool_output_size(
+                shape[i + 2],
+                padding[i],
+                config.kernel_size[i],
+                config.strides[i],
+                1,
+                false,
+            )
+        };
+        let output_shape = vec![shape[0], shape[1], size(0), size(1)];
+        let name = self.node_name("avg_pool2d");
+
+        let attrs = [
+            ("kernel_shape", ints(&config.kernel_size)),
+            ("strides", ints(&config.strides)),
+            ("pads", pads(padding)),
+            (
+                "count_include_pad",
+                AttributeValue::Int64(config.count_include_pad as i64),
+            ),
+        ];
+
+        self.node(NodeType::AveragePool, name, vec![], attrs, output_shape)
+    }
+
+    /// Flatten the dimensions after the batch dimension, e.g. between convolutional and linear
+    /// layers.
+    pub fn flatten(self) -> Self {
+        let shape = self.output_shape();
+        let output_shape = vec![shape[0], shape[1..].iter().product()];
+        let name = self.node_name("flatten");
+
+        let attrs = [("axis", AttributeValue::Int64(1))];
+
+        self.node(NodeType::Flatten, name, vec![], attrs, output_shape)
+    }
+
+    /// Add a [ReLU](burn::tensor::activation::relu) activation.
+    pub fn relu(self) -> Self {
+        self.activation(NodeType::Relu, "relu", [])
+    }
+
+    /// Add a [leaky ReLU](burn::tensor::activation::leaky_relu) activation.
+    pub fn leaky_relu(self, negative_slope: f64) -> Self {
+        let attrs = [("alpha", AttributeValue::Float32(negative_slope as f32))];
+
+        self.activation(NodeType::LeakyRelu, "leaky_relu", attrs)
+    }
+
+    /// Add a [sigmoid](burn::tensor::activation::sigmoid) activation.
+    pub fn sigmoid(self) -> Self {
+        self.activation(NodeType::Sigmoid, "sigmoid", [])
+    }
+
+    /// Add a [tanh](burn::tensor::activation::tanh) activation.
+    pub fn tanh(self) -> Self {
+        self.activation(NodeType::Tanh, "tanh", [])
+    }
+
+    /// The graph of the exported model, with the weights as the values of the node inputs.
+    pub fn into_graph(self) -> OnnxGraph {
+        let mut output = self.output;
+        output.name = "output".to_string();
+
+        let mut nodes = self.nodes;
+        if let Some(last) = nodes.last_mut() {
+            last.outputs[0].name.clone_from(&output.name);
+        }
+
+        OnnxGraph {
+            nodes,
+            inputs: vec![self.input],
+            outputs: vec![output],
+        }
+    }
+
+    /// Write the model to the given writer, e.g. a file or a network stream.
+    pub fn write_to<W: Write>(self, writer: &mut W) -> std::io::Result<()> {
+        let model = convert_graph(&self.into_graph(), OPSET_VERSION);
+
+        model.write_to_writer(writer).map_err(std::io::Error::other)
+    }
+
+    /// Save the model to the given ONNX file.
+    pub fn save<P: AsRef<Path>>(self, path: P) -> std::io::Result<()> {
+        let mut file = std::io::BufWriter::new(File::create(path)?);
+        self.write_to(&mut file)?;
+
+        file.flush()
+    }
+
+    fn activation<const N: usize>(
+        self,
+        node_type: NodeType,
+        name: &str,
+        attrs: [(&str, AttributeValue); N],
+    ) -> Self {
+        let shape = self.output_shape();
+        let name = self.node_name(name);
+
+        self.node(node_type, name, vec![], attrs, shape)
+    }
+
+    /// Add a node applied to the current output, followed by the given initializers.
+    fn node<const N: usize>(
+        mut self,
+        node_type: NodeType,
+        name: String,
+        initializers: Vec<Argument>,
+        attrs: [(&str, AttributeValue); N],
+        output_shape: Vec<usize>,
+    ) -> Self {
+        let output = float_argument(&format!("{name}_out"), output_shape, None);
+        let inputs = [self.output.clone()]
+            .into_iter()
+            .chain(initializers)
+            .collect();
+        let attrs: Attributes = attrs
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect();
+
+        self.nodes.push(Node {
+            node_type,
+            name,
+            inputs,
+            outputs: vec![output.clone()],
+            attrs,
+            opset_version: OPSET_VERSION,
+        });
+        self.output = output;
+
+        self
+    }
+
+    fn node_name(&self, prefix: &str) -> String {
+        format!("{prefix}{}", self.nodes.len() + 1)
+    }
+
+    fn output_shape(&self) -> Vec<usize> {
+        match &self.output.ty {
+            ArgType::Tensor(tensor) => tensor.shape.clone().unwrap(),
+            _ => unreachable!("The layers only produce tensors"),
+        }
+    }
+}
+
+fn float_argument(name: &str, shape: Vec<usize>, value: Option<Data>) -> Argument {
+    let passed = value.is_none();
+
+    Argument {
+        name: name.to_string(),
+        ty: ArgType::Tensor(TensorType {
+            elem_type: ElementType::Float32,
+            dim: shape.len(),
+            shape: Some(shape),
+        }),
+        value,
+        passed,
+    }
+}
+
+fn float_initializer<B: Backend, const D: usize>(name: String, tensor: Tensor<B, D>) -> Argument {
+    let data = tensor.into_data().convert::<f32>();
+
+    float_argument(
+        &name,
+        data.shape.dims.to_vec(),
+        Some(Data::Float32s(data.value)),
+    )
+}
+
+fn ints(values: &[usize]) -> AttributeValue {
+    AttributeValue::Int64s(values.iter().map(|value| *value as i64).collect())
+}
+
+/// The ONNX `pads` of a symmetric 2D padding, i.e. `[top, left, bottom, right]`.
+fn pads([height, width]: [usize; 2]) -> AttributeValue {
+    ints(&[height, width, height, width])
+}
+
+/// The padding of each spatial dimension, computed for the input shape with
+/// [Same](PaddingConfig2d::Same) as by Burn.
+fn padding_2d(
+    padding: &PaddingConfig2d,
+    shape: &[usize],
+    kernel_size: &[usize; 2],
+    stride: &[usize; 2],
+) -> [usize; 2] {
+    match padding {
+        PaddingConfig2d::Valid => [0, 0],
+        PaddingConfig2d::Explicit(height, width) => [*height, *width],
+        PaddingConfig2d::Same => [0, 1]
+            .map(|i| calculate_conv_padding(kernel_size[i], stride[i], shape[i + 2], shape[i + 2])),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::onnx::parse_onnx;
+    use burn::{backend::NdArray, nn::LinearConfig, tensor::Data as TensorData};
+
+    type TestBackend = NdArray<f32>;
+
+    fn reimport(exporter: OnnxExporter, name: &str) -> OnnxGraph {
+        let path = std::env::temp_dir().join(format!("burn_import_export_{name}.onnx"));
+        exporter.save(&path).unwrap();
+
+        parse_onnx(&path)
+    }
+
+    fn input_value(node: &Node, index: usize) -> Vec<f32> {
+        match &node.inputs[index].value {
+            Some(Data::Float32s(values)) => values.clone(),
+            value => panic!("Expected float values, got {value:?}"),
+        }
+    }
+
+    fn output_shape(graph: &OnnxGraph) -> Option<Vec<usize>> {
+        match &graph.outputs[0].ty {
+            ArgType::Tensor(tensor) => tensor.shape.clone(),
+            ty => panic!("Expected a tensor output, got {ty:?}"),
+        }
+    }
+
+    #[test]
+    fn exported_mlp_is_reimported() {
+        let device = Default::default();
+        let linear1 = LinearConfig::new(4, 8).init::<TestBackend>(&device);
+        let linear2 = LinearConfig::new(8, 2).init::<TestBackend>(&device);
+
+        let exporter = OnnxExporter::new([3, 4])
+            .linear(&linear1)
+            .relu()
+            .linear(&linear2);
+        let graph = reimport(exporter, "mlp");
+
+        let node_types = graph.nodes.iter().map(|node| node.node_type.clone());
+        assert!(node_types.eq([NodeType::Linear, NodeType::Relu, NodeType::Linear]));
+
+        // The weights are imported back as [d_input, d_output]
+        let weight = TensorData::new(input_value(&graph.nodes[0], 1), [4, 8].into());
+        weight.assert_approx_eq(&linear1.weight.val().into_data(), 6);
+        let bias = TensorData::new(input_value(&graph.nodes[2], 2), [2].into());
+        bias.assert_approx_eq(&linear2.bias.unwrap().val().into_data(), 6);
+
+        assert_eq!(output_shape(&graph), Some(vec![3, 2]));
+    }
+
+    #[test]
+    fn exported_cnn_is_reimported() {
+        let device = Default::default();
+        let conv_config = Conv2dConfig::new([1, 4], [3, 3]).with_padding(PaddingConfig2d::Same);
+        let conv = conv_config.init::<TestBackend>(&device);
+        let norm_config = BatchNormConfig::new(4);
+        let norm = norm_config.init::<TestBackend, 2>(&device);
+        let pool_config = MaxPool2dConfig::new([2, 2]).with_strides([2, 2]);
+        let linear = LinearConfig::new(4 * 4 * 4, 10).init::<TestBackend>(&device);
+
+        let exporter = OnnxExporter::new([2, 1, 8, 8])
+            .conv2d(&conv_config, &conv)
+            .batch_norm(&norm_config, &norm)
+            .relu()
+            .max_pool2d(&pool_config)
+            .flatten()
+            .linear(&linear);
+        let graph = reimport(exporter, "cnn");
+
+        // The batch norm is folded into the convolution when imported
+        let node_types = graph.nodes.iter().map(|node| node.node_type.clone());
+        assert!(node_types.eq([
+            NodeType::Conv2d,
+            NodeType::Relu,
+            NodeType::MaxPool2d,
+            NodeType::Flatten,
+            NodeType::Linear,
+        ]));
+
+        // A freshly initialized batch norm only scales by 1 / sqrt(1 + epsilon)
+        let weight = TensorData::new(input_value(&graph.nodes[0], 1), [4, 1, 3, 3].into());
+        weight.assert_approx_eq(&conv.weight.val().into_data(), 3);
+        let weight = TensorData::new(input_value(&graph.nodes[4], 1), [64, 10].into());
+        weight.assert_approx_eq(&linear.weight.val().into_data(), 6);
+
+        assert_eq!(
+            graph.nodes[0].attrs["pads"].clone().into_i64s(),
+            vec![1, 1, 1, 1]
+        );
+    }
+}
@@ -7,8 +7,9 @@ use std::{
 use crate::onnx::{node_remap::remap_node_type, proto_conversion::convert_node_proto};
 
 use super::{
-    coalesce::coalesce,
-    ir::{Data, OnnxGraph, TensorType},
+    coalesce::{coalesce, fold_batch_norms},
+    constant_folding::fold_constant,
+    ir::{AttributeValue, Data, OnnxGraph, TensorType},
     protos::{ModelProto, NodeProto, TensorProto, ValueInfoProto},
 };
 
@@ -17,17 +18,29 @@ use super::ir::{ArgType, Argument, Node, NodeType};
 
 use protobuf::Message;
 
-const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 10] = [
+const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 22] = [
     NodeType::BatchNormalization,
     NodeType::Clip,
     NodeType::Conv1d,
     NodeType::Conv2d,
+    NodeType::CumSum,
     NodeType::Dropout,
     NodeType::Expand,
+    NodeType::Gather,
     NodeType::Reshape,
+    NodeType::Resize,
     NodeType::Unsqueeze,
+    NodeType::ReduceMax,
+    NodeType::ReduceMean,
+    NodeType::ReduceMin,
     NodeType::ReduceSum,
     NodeType::Squeeze,
+    NodeType::Slice,
+    NodeType::Split,
+    NodeType::Pad,
+    NodeType::PRelu,
+    NodeType::Range,
+    NodeType::Trilu,
 ];
 
 #[derive(Debug)]
@@ -151,8 +164,11 @@ impl OnnxGraphIO {
                 arg.name = proto_str;
                 arg
             }
-            Some(IOEntry::Out(_)) => {
-                panic!("graph output {} can't be a Node input", &proto_str)
+            // A graph output can also be used by the next nodes
+            Some(IOEntry::Out(i)) => {
+                let mut arg = self.outputs[*i].clone();
+                arg.name = proto_str;
+                arg
             }
         }
     }
@@ -216,9 +232,8 @@ impl OnnxGraphIO {
     }
 
     /// Get the updated name of a Node Input, which should be
-    /// either a graph input or a node output.
+    /// either a graph input, a graph output or a node output.
     /// Will return None if the it isn't a graph input or node output(like an initializer)
-    /// Will panic if it's a graph output
     fn get_new_name(&mut self, old_name: &str) -> Option<String> {
         match self.old_io_names.get(old_name) {
             Some(IOEntry::In(i)) => {
@@ -235,12 +250,7 @@ impl OnnxGraphIO {
                     Some(self.inputs[*i].name.clone())
                 }
             }
-            Some(IOEntry::Out(_)) => {
-                panic!(
-                    "you just tried to get an updated name on a graph output: {}",
-                    old_name
-                )
-            }
+            Some(IOEntry::Out(i)) => Some(self.outputs[*i].name.clone()),
             Some(IOEntry::Node(i)) => Some(self.node_out[*i].name.clone()),
             None => None,
         }
@@ -261,6 +271,8 @@ pub(crate) struct OnnxGraphBuilder {
     constants_types: HashSet<NodeType>,
     /// Map from identity node output names to indices of identity nodes
     identity_idx: HashMap<String, usize>,
+    /// Indices of the constant nodes used by folded nodes, removed if no other node uses them
+    folded_constants: HashSet<usize>,
 }
 
 impl OnnxGraphBuilder {
@@ -273,18 +285,21 @@ impl OnnxGraphBuilder {
             &model_proto.graph.initializer,
         );
 
+        let opset_version = default_opset_version(model_proto);
+
         self.nodes = Vec::with_capacity(model_proto.graph.node.len());
         let mut and_idx = 0;
         let mut node_iter = model_proto.graph.node.iter().peekable();
 
         while let Some(node_proto) = node_iter.next() {
-            let mut node = convert_node_proto(node_proto, &graph_io);
+            let mut node = convert_node_proto(node_proto, &graph_io, opset_version);
 
             remap_node_type(&mut node);
 
             coalesce(&mut node, &mut node_iter, &graph_io);
             self.handle_node_renaming(&mut node);
             self.handle_identity(&mut node, and_idx);
+            self.fold_constants(&mut node, &graph_io);
             self.check_constants(&mut node, and_idx, &mut graph_io);
             self.handle_unsqueeze(&mut node, &graph_io);
 
@@ -296,6 +311,11 @@ impl OnnxGraphBuilder {
             and_idx += 1;
         }
 
+        let folded_batch_norms =
+            fold_batch_norms(&mut self.nodes, &graph_io, &self.nodes_to_remove);
+        self.nodes_to_remove.extend(folded_batch_norms);
+        self.remove_folded_constants(&graph_io);
+
         let mut i = 0;
         self.nodes.retain(|_x| {
             let res = !self.nodes_to_remove.contains(&i);
@@ -335,30 +355,110 @@ impl OnnxGraphBuilder {
             self.constants_map.insert(node.outputs[0].name.clone(), i);
         } else if self.constants_types.contains(&node.node_type) {
             log::debug!("checking node {} for constants", &node.name);
-            for input in node.inputs.iter_mut().skip(1) {
+            // The first input is the data, except for Range whose inputs are all parameters
+            let skip = usize::from(node.node_type != NodeType::Range);
+            for input in node.inputs.iter_mut().skip(skip) {
                 log::debug!("checking input {:?} for const", input);
                 if let Some(const_idx) = self.constants_map.get(&input.name) {
-                    let constant = &self.nodes[*const_idx];
                     log::debug!(
                         "input {} matched constant node {}",
                         &input.name,
-                        &constant.name
+                        &self.nodes[*const_idx].name
                     );
-                    if !constant.inputs.is_empty() && constant.inputs[0].value.is_some() {
-                        // The value comes from Identity inputs
-                        input.value.clone_from(&constant.inputs[0].value);
-                        input.ty = constant.inputs[0].ty.clone();
-                    } else {
-                        let arg = convert_constant_value(constant);
-                        input.value = arg.value;
-                        input.ty = arg.ty;
-                    }
+                    let arg = self.constant_value(*const_idx);
+                    input.value = arg.value;
+                    input.ty = arg.ty;
                     self.nodes_to_remove.insert(*const_idx);
                 }
             }
         }
     }
 
+    /// Get the value of a constant node, or of an identity node with a constant input
+    fn constant_value(&self, const_idx: usize) -> Argument {
+        let constant = &self.nodes[const_idx];
+
+        if !constant.inputs.is_empty() && constant.inputs[0].value.is_some() {
+            // The value comes from Identity inputs
+            constant.inputs[0].clone()
+        } else {
+            convert_constant_value(constant)
+        }
+    }
+
+    /// Replace the node by a constant node when all its inputs are constants, e.g. the nodes
+    /// computing static shapes. Needs to be called before constant lifting so the folded value
+    /// can be lifted into the next nodes.
+    ///
+    /// The folding is conservative: nodes using a graph input or another node output are left
+    /// alone, as well as the graph outputs and the node types which can't be evaluated.
+    fn fold_constants(&mut self, node: &mut Node, graph_io: &OnnxGraphIO) {
+        if node.node_type == NodeType::Constant || node.node_type == NodeType::Identity {
+            return;
+        }
+
+        // The graph outputs are kept as computed values
+        let is_graph_output = node.outputs.iter().any(|output| {
+            matches!(
+                graph_io.old_io_names.get(&output.name),
+                Some(IOEntry::Out(_))
+            )
+        });
+        if is_graph_output {
+            return;
+        }
+
+        let mut inputs = Vec::with_capacity(node.inputs.len());
+        let mut constants = Vec::new();
+
+        for input in node.inputs.iter() {
+            if let Some(const_idx) = self.constants_map.get(&input.name) {
+                inputs.push(Some(self.constant_value(*const_idx)));
+                constants.push(*const_idx);
+            } else if input.value.is_some() && !input.passed {
+                // The value comes from an initializer
+                inputs.push(Some(input.clone()));
+            } else if input.name.is_empty() {
+                // Omitted optional input
+                inputs.push(None);
+            } else {
+                return;
+            }
+        }
+
+        if let Some(value) = fold_constant(node, &inputs) {
+            log::debug!("folding node {} into a constant", &node.name);
+
+            node.node_type = NodeType::Constant;
+            node.inputs.clear();
+            node.outputs.truncate(1);
+            node.attrs = HashMap::from([("value".to_string(), AttributeValue::Tensor(value))]);
+
+            self.folded_constants.extend(constants);
+        }
+    }
+
+    /// Remove the constant nodes that were only used by folded nodes
+    fn remove_folded_constants(&mut self, graph_io: &OnnxGraphIO) {
+        let used_names: HashSet<&str> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !self.nodes_to_remove.contains(i))
+            .flat_map(|(_, node)| node.inputs.iter().map(|input| input.name.as_str()))
+            .chain(graph_io.outputs.iter().map(|output| output.name.as_str()))
+            .collect();
+
+        let unused: Vec<usize> = self
+            .folded_constants
+            .iter()
+            .filter(|&&i| !used_names.contains(self.nodes[i].outputs[0].name.as_str()))
+            .copied()
+            .collect();
+
+        self.nodes_to_remove.extend(unused);
+    }
+
     /// Check if the unsqueeze node has a rhs value (rhs is constant) and if not remap it to a reshape
     /// Needs to be called after node renaming to ensure that the rhs name is correct
     /// Needs to be called after constant lifting to ensure that the rhs value exists
@@ -450,6 +550,16 @@ pub fn parse_onnx(onnx_path: &Path) -> OnnxGraph {
     }
 }
 
+/// Get the version of the default ONNX operator set imported by the model.
+fn default_opset_version(model_proto: &ModelProto) -> usize {
+    model_proto
+        .opset_import
+        .iter()
+        .find(|opset| opset.domain.is_empty() || opset.domain == "ai.onnx")
+        .map(|opset| opset.version as usize)
+        .expect("The model doesn't import the default ONNX operator set")
+}
+
 /// Remap the unsqueeze node to a reshape node, Should only be called after
 /// node renaming has been done. avoids marking rhs as passed so that it can be
 /// properly deleted if nothing else uses it
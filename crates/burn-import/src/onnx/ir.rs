@@ -202,6 +202,10 @@ pub struct Node {
 
     /// The attributes of the node.
     pub attrs: Attributes,
+
+    /// The version of the default ONNX operator set imported by the model, which defines the
+    /// semantics of the node.
+    pub opset_version: usize,
 }
 
 // Required by topological sort
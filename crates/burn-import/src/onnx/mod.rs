@@ -1,5 +1,7 @@
 mod coalesce;
+mod constant_folding;
 mod dim_inference;
+mod export;
 mod from_onnx;
 mod ir;
 mod node_remap;
@@ -10,5 +12,6 @@ mod to_burn;
 
 pub use to_burn::*;
 
+pub use export::OnnxExporter;
 pub use from_onnx::parse_onnx;
 pub use ir::OnnxGraph;
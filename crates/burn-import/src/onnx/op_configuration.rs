@@ -1,14 +1,30 @@
 use burn::nn::{
     conv::{Conv1dConfig, Conv2dConfig, ConvTranspose2dConfig},
+    gru::GruConfig,
     pool::{AvgPool1dConfig, AvgPool2dConfig, MaxPool1dConfig, MaxPool2dConfig},
-    BatchNormConfig, DropoutConfig, LayerNormConfig, LinearConfig, PaddingConfig1d,
-    PaddingConfig2d,
+    BatchNormConfig, DropoutConfig, GroupNormConfig, LayerNormConfig, LinearConfig, LstmConfig,
+    PaddingConfig1d, PaddingConfig2d,
 };
 
-use super::ir::{ArgType, AttributeValue, Data, Node};
+use super::ir::{ArgType, Argument, AttributeValue, Data, ElementType, Node, TensorType};
+use crate::burn::node::{
+    binary::BitShiftDirection,
+    depth_to_space::DepthToSpaceMode,
+    einsum::EinsumOp,
+    gru::GruDirection,
+    lstm::LstmDirection,
+    pad::PadMode,
+    resize::{ResizeMode, ResizeSize},
+    scatter_elements::ScatterReduction,
+    slice::is_empty_range,
+};
 
 /// Create a Conv1dConfig from the attributes of the node
-pub fn conv1d_config(curr: &Node) -> Conv1dConfig {
+///
+/// The padding computed from `auto_pad` can be asymmetric, which the config can't represent. It
+/// is then returned as `(left, right)` to be applied by a Pad node before the convolution, and
+/// the config has no padding.
+pub fn conv1d_config(curr: &Node) -> (Conv1dConfig, Option<[usize; 2]>) {
     let mut kernel_shape = Vec::new(); // TODO default inferred from weight tensor per spec
     let mut strides = vec![1];
     let mut pads = vec![0, 0];
@@ -41,18 +57,36 @@ pub fn conv1d_config(curr: &Node) -> Conv1dConfig {
         }
     }
 
-    let padding = padding_config_1d(&pads);
+    if let Some(auto_pads) = conv_auto_pads(curr, &kernel_shape, &strides, &dilations) {
+        pads = auto_pads;
+    }
 
-    Conv1dConfig::new(channels_in, channels_out, kernel_shape[0] as usize)
+    let asymmetric = pads[0] != pads[1] && pads.iter().all(|&pad| pad >= 0);
+    let (padding, pre_pads) = if !asymmetric {
+        (padding_config_1d(&pads), None)
+    } else {
+        (
+            PaddingConfig1d::Valid,
+            Some([pads[0] as usize, pads[1] as usize]),
+        )
+    };
+
+    let config = Conv1dConfig::new(channels_in, channels_out, kernel_shape[0] as usize)
         .with_stride(strides[0] as usize)
         .with_dilation(dilations[0] as usize)
         .with_groups(group as usize)
         .with_bias(bias)
-        .with_padding(padding)
+        .with_padding(padding);
+
+    (config, pre_pads)
 }
 
 /// Create a Conv2dConfig from the attributes of the node
-pub fn conv2d_config(curr: &Node) -> Conv2dConfig {
+///
+/// The padding computed from `auto_pad` can be asymmetric, which the config can't represent. It
+/// is then returned as `(left, right, top, bottom)`, as in [PadNode](crate::burn::node::pad::PadNode),
+/// to be applied before the convolution, and the config has no padding.
+pub fn conv2d_config(curr: &Node) -> (Conv2dConfig, Option<[usize; 4]>) {
     let mut kernel_shape = Vec::new(); // TODO default inferred from weight tensor per spec
     let mut strides = vec![1, 1];
     let mut pads = vec![0, 0, 0, 0];
@@ -83,9 +117,21 @@ pub fn conv2d_config(curr: &Node) -> Conv2dConfig {
         }
     }
 
-    let padding = padding_config(&pads);
+    if let Some(auto_pads) = conv_auto_pads(curr, &kernel_shape, &strides, &dilations) {
+        pads = auto_pads;
+    }
 
-    Conv2dConfig::new(
+    // The pads are [top, left, bottom, right]
+    let asymmetric = (pads[0] != pads[2] || pads[1] != pads[3]) && pads.iter().all(|&pad| pad >= 0);
+    let (padding, pre_pads) = if !asymmetric {
+        (padding_config(&pads), None)
+    } else {
+        let [top, left, bottom, right] =
+            [pads[0], pads[1], pads[2], pads[3]].map(|pad| pad as usize);
+        (PaddingConfig2d::Valid, Some([left, right, top, bottom]))
+    };
+
+    let config = Conv2dConfig::new(
         channels,
         [kernel_shape[0] as usize, kernel_shape[1] as usize],
     )
@@ -93,7 +139,63 @@ pub fn conv2d_config(curr: &Node) -> Conv2dConfig {
     .with_dilation([dilations[0] as usize, dilations[1] as usize])
     .with_groups(group as usize)
     .with_bias(bias)
-    .with_padding(padding)
+    .with_padding(padding);
+
+    (config, pre_pads)
+}
+
+/// Compute the `pads` of a convolution from its `auto_pad` attribute and the spatial shape of its
+/// input, or `None` when the pads are explicit (`NOTSET`).
+///
+/// `SAME_UPPER` and `SAME_LOWER` pad the input so that each spatial dimension of the output has
+/// `ceil(input / stride)` elements, the odd padding going at the end or at the beginning
+/// respectively, and `VALID` doesn't pad.
+fn conv_auto_pads(
+    curr: &Node,
+    kernel_shape: &[i64],
+    strides: &[i64],
+    dilations: &[i64],
+) -> Option<Vec<i64>> {
+    let auto_pad = curr.attrs.get("auto_pad")?.clone().into_string();
+    let spatial = kernel_shape.len();
+
+    match auto_pad.as_str() {
+        "NOTSET" => None,
+        "VALID" => Some(vec![0; 2 * spatial]),
+        "SAME_UPPER" | "SAME_LOWER" => {
+            let shape = match &curr.inputs[0].ty {
+                ArgType::Tensor(tensor) => tensor.shape.clone(),
+                _ => None,
+            }
+            .unwrap_or_else(|| {
+                panic!(
+                    "{}: auto_pad {auto_pad} requires the shape of the input",
+                    curr.name
+                )
+            });
+            let input_shape = &shape[shape.len() - spatial..];
+
+            let (begins, ends): (Vec<i64>, Vec<i64>) = (0..spatial)
+                .map(|i| {
+                    let input = input_shape[i] as i64;
+                    let output = (input + strides[i] - 1) / strides[i];
+                    let total =
+                        ((output - 1) * strides[i] + (kernel_shape[i] - 1) * dilations[i] + 1
+                            - input)
+                            .max(0);
+
+                    if auto_pad == "SAME_UPPER" {
+                        (total / 2, total - total / 2)
+                    } else {
+                        (total - total / 2, total / 2)
+                    }
+                })
+                .unzip();
+
+            Some([begins, ends].concat())
+        }
+        _ => panic!("{}: auto_pad {auto_pad} is not supported", curr.name),
+    }
 }
 
 /// Create a MaxPool2dConfig from the attributes of the node
@@ -102,6 +204,7 @@ pub fn max_pool1d_config(curr: &Node) -> MaxPool1dConfig {
     let mut stride = vec![1];
     let mut pads = vec![0, 0];
     let mut dilation = vec![1];
+    let mut ceil_mode = 0;
 
     for (key, value) in curr.attrs.iter() {
         match key.as_str() {
@@ -109,6 +212,7 @@ pub fn max_pool1d_config(curr: &Node) -> MaxPool1dConfig {
             "strides" => stride = value.clone().into_i64s(),
             "pads" => pads = value.clone().into_i64s(),
             "dilations" => dilation = value.clone().into_i64s(),
+            "ceil_mode" => ceil_mode = value.clone().into_i64(),
             _ => {}
         }
     }
@@ -121,6 +225,7 @@ pub fn max_pool1d_config(curr: &Node) -> MaxPool1dConfig {
         .with_stride(stride[0] as usize)
         .with_padding(padding)
         .with_dilation(dilation[0] as usize)
+        .with_ceil_mode(ceil_mode == 1)
 }
 
 /// Create a MaxPool2dConfig from the attributes of the node
@@ -129,6 +234,8 @@ pub fn max_pool2d_config(curr: &Node) -> MaxPool2dConfig {
     let mut strides = vec![1, 1];
     let mut pads = vec![0, 0, 0, 0];
     let mut dilations = vec![1, 1];
+    let mut ceil_mode = 0;
+    let mut storage_order = 0;
 
     for (key, value) in curr.attrs.iter() {
         match key.as_str() {
@@ -136,16 +243,23 @@ pub fn max_pool2d_config(curr: &Node) -> MaxPool2dConfig {
             "strides" => strides = value.clone().into_i64s(),
             "pads" => pads = value.clone().into_i64s(),
             "dilations" => dilations = value.clone().into_i64s(),
+            "ceil_mode" => ceil_mode = value.clone().into_i64(),
+            "storage_order" => storage_order = value.clone().into_i64(),
             _ => {}
         }
     }
 
+    if storage_order != 0 && curr.outputs.len() > 1 {
+        panic!("MaxPool2d: only row major indices are supported (storage_order=0)");
+    }
+
     let padding = padding_config(&pads);
 
     MaxPool2dConfig::new([kernel_shape[0] as usize, kernel_shape[1] as usize])
         .with_strides([strides[0] as usize, strides[1] as usize])
         .with_padding(padding)
         .with_dilation([dilations[0] as usize, dilations[1] as usize])
+        .with_ceil_mode(ceil_mode == 1)
 }
 pub fn conv_transpose2d_config(curr: &Node) -> ConvTranspose2dConfig {
     let mut attrs = curr.attrs.clone();
@@ -160,22 +274,33 @@ pub fn conv_transpose2d_config(curr: &Node) -> ConvTranspose2dConfig {
     let pads = attrs
         .remove("pads")
         .map(AttributeValue::into_i64s)
-        .unwrap_or_else(|| vec![0, 0]);
+        .unwrap_or_else(|| vec![0, 0, 0, 0]);
     let dilations = attrs
         .remove("dilations")
         .map(AttributeValue::into_i64s)
         .unwrap_or_else(|| vec![1, 1]);
+    let output_padding = attrs
+        .remove("output_padding")
+        .map(AttributeValue::into_i64s)
+        .unwrap_or_else(|| vec![0, 0]);
     let group = attrs
         .remove("group")
         .map(AttributeValue::into_i64)
-        .unwrap_or(1);
+        .unwrap_or(1) as usize;
+
+    if let Some(auto_pad) = attrs.remove("auto_pad") {
+        let auto_pad = auto_pad.into_string();
+        if auto_pad != "NOTSET" {
+            panic!("ConvTranspose2d: auto_pad {auto_pad} is not supported");
+        }
+    }
 
     // Trick with remove + empty check is simplest way to not forget some attribute for runtime:
     if !attrs.is_empty() {
         panic!("Not all attributes are used: {attrs:?}");
     }
 
-    // extract the channels from the weight tensor's shape [out_channels, in_channels, ...]
+    // extract the channels from the weight tensor's shape [in_channels, out_channels / group, ...]
     let weight = if let ArgType::Tensor(ref weight) = curr.inputs[1].ty {
         weight
     } else {
@@ -185,19 +310,38 @@ pub fn conv_transpose2d_config(curr: &Node) -> ConvTranspose2dConfig {
     // check if the bias is present
     let bias = curr.inputs.len() == 3;
 
-    // the channels are inverted in the weight tensor
     let shape = weight.shape.clone().unwrap();
-    let channels: [usize; 2] = [shape[1], shape[0]];
+    let channels: [usize; 2] = [shape[0], shape[1] * group];
 
-    ConvTranspose2dConfig::new(
-        channels,
-        [kernel_shape[0] as usize, kernel_shape[1] as usize],
-    )
-    .with_stride([stride[0] as usize, stride[1] as usize])
-    .with_padding([pads[0] as usize, pads[1] as usize])
-    .with_dilation([dilations[0] as usize, dilations[1] as usize])
-    .with_groups(group as usize)
-    .with_bias(bias)
+    // the kernel shape is inferred from the weight tensor when absent
+    let kernel_size = match kernel_shape.as_slice() {
+        [] => [shape[2], shape[3]],
+        kernel_shape => [kernel_shape[0] as usize, kernel_shape[1] as usize],
+    };
+
+    if pads.iter().any(|&pad| pad < 0) {
+        panic!("ConvTranspose2d: negative pads are not supported, got {pads:?}");
+    }
+
+    // Burn only pads symmetrically, but the output padding is added at the end of each spatial
+    // dimension, so a larger padding at the beginning is a symmetric padding with more output
+    // padding.
+    let [top, left, bottom, right] = [pads[0], pads[1], pads[2], pads[3]].map(|pad| pad as usize);
+
+    if bottom > top || right > left {
+        panic!("ConvTranspose2d: larger pads at the end than at the beginning are not supported, got {pads:?}");
+    }
+
+    ConvTranspose2dConfig::new(channels, kernel_size)
+        .with_stride([stride[0] as usize, stride[1] as usize])
+        .with_padding([top, left])
+        .with_padding_out([
+            output_padding[0] as usize + top - bottom,
+            output_padding[1] as usize + left - right,
+        ])
+        .with_dilation([dilations[0] as usize, dilations[1] as usize])
+        .with_groups(group)
+        .with_bias(bias)
 }
 
 pub fn avg_pool1d_config(curr: &Node) -> AvgPool1dConfig {
@@ -262,9 +406,52 @@ pub fn avg_pool2d_config(curr: &Node) -> AvgPool2dConfig {
         .with_count_include_pad(count_include_pad == 1)
 }
 
+/// Read the start, limit and delta of a Range node, `None` for the values only known at runtime.
+pub fn range_config(node: &Node) -> [Option<i64>; 3] {
+    if node.inputs.len() != 3 {
+        panic!("Range: expected 3 inputs, found {}", node.inputs.len());
+    }
+
+    [0, 1, 2].map(|index| match &node.inputs[index].value {
+        Some(Data::Int64(value)) => Some(*value),
+        Some(Data::Int32(value)) => Some(*value as i64),
+        Some(Data::Int64s(values)) if values.len() == 1 => Some(values[0]),
+        Some(Data::Int32s(values)) if values.len() == 1 => Some(values[0] as i64),
+        Some(value) => panic!("Range: only integer values are supported, got {value:?}"),
+        None => None,
+    })
+}
+
+/// Number of values of a range, `max(ceil((limit - start) / delta), 0)` as in the ONNX spec.
+pub fn range_len(start: i64, limit: i64, delta: i64) -> usize {
+    if delta == 0 {
+        panic!("Range: delta can't be zero");
+    }
+
+    let (diff, delta) = (limit as i128 - start as i128, delta as i128);
+    // The division rounds toward zero, the length is rounded up
+    let len = if diff % delta != 0 && (diff % delta > 0) == (delta > 0) {
+        diff / delta + 1
+    } else {
+        diff / delta
+    };
+
+    len.max(0) as usize
+}
+
+/// Read the shape of an Expand node as the broadcast arguments of `Tensor::expand`.
+///
+/// The shape is aligned with the input from the last dimension. Following numpy rules, a size of 1
+/// keeps the size of the input, which is -1 for `expand`, and so are the leading input dimensions
+/// missing from the shape.
 pub fn expand_config(node: &Node) -> Vec<i64> {
+    let input_dim = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.dim,
+        _ => panic!("Expand: only tensor input is valid"),
+    };
+
     let input_value = &node.inputs[1].value;
-    match &node.inputs[1].ty {
+    let shape = match &node.inputs[1].ty {
         ArgType::Tensor(tensor) => {
             assert_eq!(tensor.dim, 1, "Expand: shape tensor must be 1D");
             if let Some(Data::Int64s(shape)) = input_value.as_ref() {
@@ -274,13 +461,29 @@ pub fn expand_config(node: &Node) -> Vec<i64> {
             }
         }
         _ => panic!("Only tensor input is valid for shape"),
-    }
+    };
+
+    let rank = usize::max(input_dim, shape.len());
+    let missing = rank - shape.len();
+
+    (0..rank)
+        .map(|axis| {
+            // The output dimensions before the input ones must be given
+            let has_input_dim = axis >= rank - input_dim;
+
+            match axis.checked_sub(missing).map(|index| shape[index]) {
+                None => -1,
+                Some(1) if has_input_dim => -1,
+                Some(size) => size,
+            }
+        })
+        .collect()
 }
 
-/// Create a FlattenConfig from the attributes of the node
-pub fn flatten_config(curr: &Node) -> (usize, usize) {
-    // the begin dimension is the first dimension (Default: 1 per ONNX spec)
-    let mut start_dim: i64 = 1;
+/// Read the axis of a Flatten node, normalized in [0, rank]
+pub fn flatten_config(curr: &Node) -> usize {
+    // the dimensions before the axis are collapsed into the rows (Default: 1 per ONNX spec)
+    let mut axis: i64 = 1;
 
     // check if the node has only one input
     if curr.inputs.len() != 1 {
@@ -296,31 +499,23 @@ pub fn flatten_config(curr: &Node) -> (usize, usize) {
         _ => panic!("Only tensor input is valid"),
     };
 
-    // check if the input tensor has at least 2 dimensions
-    if tensor.dim < 2 {
-        panic!(
-            "Flatten: input tensor must have at least 2 dimensions (got {:?})",
-            tensor.dim
-        );
-    }
-
-    // the end dimension is the last dimension
-    let end_dim = tensor.dim - 1;
-
     // extract the attributes
     for (key, value) in curr.attrs.iter() {
-        match key.as_str() {
-            "axis" => start_dim = value.clone().into_i64(),
-            _ => {}
+        if key.as_str() == "axis" {
+            axis = value.clone().into_i64();
         }
     }
 
-    // if beg_dim is negative, it is counted from the end
-    if start_dim < 0 {
-        start_dim += tensor.dim as i64;
+    // the axis is in [-rank, rank], negative values being counted from the end
+    let rank = tensor.dim as i64;
+    if !(-rank..=rank).contains(&axis) {
+        panic!("Flatten: axis {axis} is out of range for a tensor of rank {rank}");
+    }
+    if axis < 0 {
+        axis += rank;
     }
 
-    (start_dim as usize, end_dim)
+    axis as usize
 }
 
 /// Create a GatherConfig from the attributes of the node
@@ -355,6 +550,123 @@ pub fn gather_config(curr: &Node) -> usize {
     dim as usize
 }
 
+/// Get the length of the index tuples of a GatherND node, i.e. the size of the last axis of its
+/// indices, which must be known to infer the rank of the output.
+pub fn gather_nd_config(node: &Node) -> usize {
+    if node.inputs.len() != 2 {
+        panic!("{}: data and indices must be present", node.name);
+    }
+
+    let batch_dims = node
+        .attrs
+        .get("batch_dims")
+        .map(|batch_dims| batch_dims.clone().into_i64())
+        .unwrap_or(0);
+
+    if batch_dims != 0 {
+        panic!(
+            "{}: batch_dims={batch_dims} is not supported, only 0 is",
+            node.name
+        );
+    }
+
+    let rank = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.dim,
+        _ => panic!("{}: only tensor data is valid", node.name),
+    };
+    let tuple_size = match &node.inputs[1].ty {
+        ArgType::Tensor(TensorType {
+            shape: Some(shape), ..
+        }) => *shape.last().unwrap(),
+        ArgType::Tensor(_) => panic!(
+            "{}: the size of the last axis of the indices must be known",
+            node.name
+        ),
+        _ => panic!("{}: only tensor indices are valid", node.name),
+    };
+
+    if !(1..=rank).contains(&tuple_size) {
+        panic!(
+            "{}: index tuples of length {tuple_size} are invalid for data of rank {rank}",
+            node.name
+        );
+    }
+
+    tuple_size
+}
+
+/// Get the axis and the reduction of a ScatterElements node
+pub fn scatter_elements_config(node: &Node) -> (usize, ScatterReduction) {
+    let rank = match &node.inputs.first().unwrap().ty {
+        ArgType::Tensor(tensor) => tensor.dim as i64,
+        _ => panic!("{}: only tensor input is valid", node.name),
+    };
+
+    // Default: 0 per ONNX spec
+    let axis = node
+        .attrs
+        .get("axis")
+        .map(|axis| axis.clone().into_i64())
+        .unwrap_or(0);
+
+    // if axis is negative, it is counted from the end
+    let axis = if axis < 0 { axis + rank } else { axis };
+
+    (axis as usize, scatter_reduction(node))
+}
+
+/// Get the reduction of a ScatterND node
+pub fn scatter_nd_config(node: &Node) -> ScatterReduction {
+    scatter_reduction(node)
+}
+
+fn scatter_reduction(node: &Node) -> ScatterReduction {
+    if node.inputs.len() != 3 {
+        panic!("{}: data, indices and updates must be present", node.name);
+    }
+
+    match node.attrs.get("reduction") {
+        None => ScatterReduction::None,
+        Some(reduction) => match reduction.clone().into_string().as_str() {
+            "none" => ScatterReduction::None,
+            "add" => ScatterReduction::Add,
+            reduction => panic!(
+                "{}: unsupported reduction {reduction}, only none and add are supported",
+                node.name
+            ),
+        },
+    }
+}
+
+/// Extract the constant indices of a Gather node, if any
+///
+/// Negative indices are counted from the end of the gathered dimension.
+pub fn gather_constant_indices(curr: &Node, dim: usize) -> Option<Vec<i64>> {
+    let indices = match curr.inputs[1].value.clone()? {
+        Data::Int64(index) => vec![index],
+        Data::Int32(index) => vec![index as i64],
+        Data::Int64s(indices) => indices,
+        Data::Int32s(indices) => indices.into_iter().map(|index| index as i64).collect(),
+        data => panic!("Gather: indices must be integers, got {data:?}"),
+    };
+
+    let dim_size = match &curr.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.shape.as_ref().map(|shape| shape[dim] as i64),
+        _ => panic!("Only tensor input is valid"),
+    };
+
+    let indices = indices
+        .into_iter()
+        .map(|index| match (index < 0, dim_size) {
+            (false, _) => index,
+            (true, Some(dim_size)) => index + dim_size,
+            (true, None) => panic!("Gather: negative indices require the input shape to be known"),
+        })
+        .collect();
+
+    Some(indices)
+}
+
 /// Create a LinearConfig from the attributes of the node
 pub fn linear_config(node: &Node) -> LinearConfig {
     if node.inputs.len() < 2 {
@@ -385,6 +697,60 @@ pub fn linear_config(node: &Node) -> LinearConfig {
     LinearConfig::new(in_size, out_size).with_bias(bias)
 }
 
+/// Read the attributes of a Gemm node: `(alpha, beta, trans_a, trans_b)`.
+pub fn gemm_config(node: &Node) -> (f32, f32, bool, bool) {
+    let mut alpha = 1.0;
+    let mut beta = 1.0;
+    let mut trans_a = false;
+    let mut trans_b = false;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "alpha" => alpha = value.clone().into_f32(),
+            "beta" => beta = value.clone().into_f32(),
+            "transA" => trans_a = value.clone().into_i64() != 0,
+            "transB" => trans_b = value.clone().into_i64() != 0,
+            _ => {}
+        }
+    }
+
+    (alpha, beta, trans_a, trans_b)
+}
+
+/// Get the direction attribute of a BitShift node.
+pub fn bit_shift_config(node: &Node) -> BitShiftDirection {
+    let mut direction = None;
+
+    for (key, value) in node.attrs.iter() {
+        if key.as_str() == "direction" {
+            direction = match value.clone().into_string().as_str() {
+                "LEFT" => Some(BitShiftDirection::Left),
+                "RIGHT" => Some(BitShiftDirection::Right),
+                direction => panic!("{}: unsupported direction {direction}", node.name),
+            }
+        }
+    }
+
+    direction.unwrap_or_else(|| panic!("{}: direction attribute is required", node.name))
+}
+
+/// Whether the remainder of a Mod node has the sign of the dividend, like the C `fmod`, instead
+/// of the sign of the divisor
+pub fn mod_config(node: &Node) -> bool {
+    // Default: 0 per ONNX spec
+    let fmod = node
+        .attrs
+        .get("fmod")
+        .map(|fmod| fmod.clone().into_i64())
+        .unwrap_or(0);
+
+    match fmod {
+        0 => false,
+        1 => true,
+        fmod => panic!("{}: fmod must be 0 or 1, got {fmod}", node.name),
+    }
+}
+
 /// Create a DropoutConfig from an attribute and state of the node
 pub fn dropout_config(node: &Node) -> DropoutConfig {
     // Opset 7 and older store probability as an attribute
@@ -414,14 +780,33 @@ pub fn dropout_config(node: &Node) -> DropoutConfig {
 }
 
 /// Create log_softmax config from the attributes of the node
-pub fn log_softmax_config(node: &Node) -> usize {
-    // the axis is the last dimension (Default: 1 per ONNX spec)
-    let mut axis: i64 = -1;
+///
+/// Returns the axis and whether the input must be coerced to 2D, as for
+/// [softmax](softmax_config).
+pub fn log_softmax_config(node: &Node) -> (usize, bool) {
+    softmax_axis_config(node, "LogSoftmax")
+}
+
+/// Create softmax config from the attributes of the node
+///
+/// Returns the axis and whether the input must be coerced to 2D, with the dimensions before the
+/// axis flattened into the first one and the others into the second one, as done before opset 13.
+pub fn softmax_config(node: &Node) -> (usize, bool) {
+    softmax_axis_config(node, "Softmax")
+}
+
+/// The axis of the softmax operators, and whether the input must be coerced to 2D.
+fn softmax_axis_config(node: &Node, op_name: &str) -> (usize, bool) {
+    // Since opset 13, the axis is the last dimension by default and the softmax is computed along
+    // it. Before, the axis is 1 by default and the input is coerced to 2D.
+    let legacy = node.opset_version < 13;
+    let mut axis: i64 = if legacy { 1 } else { -1 };
 
     // check if the node has only one input
     if node.inputs.len() != 1 {
         panic!(
-            "LogSoftmax: multiple inputs are not supported (got {:?})",
+            "{}: multiple inputs are not supported (got {:?})",
+            op_name,
             node.inputs.len()
         );
     }
@@ -445,42 +830,17 @@ pub fn log_softmax_config(node: &Node) -> usize {
         axis += tensor.dim as i64;
     }
 
-    axis as usize
-}
-
-/// Create softmax config from the attributes of the node
-pub fn softmax_config(node: &Node) -> usize {
-    // the axis is the last dimension (Default: 1 per ONNX spec)
-    let mut axis: i64 = -1;
-
-    // check if the node has only one input
-    if node.inputs.len() != 1 {
+    if axis < 0 || axis >= tensor.dim as i64 {
         panic!(
-            "Softmax: multiple inputs are not supported (got {:?})",
-            node.inputs.len()
+            "{}: axis {} is out of bounds for a tensor of rank {}",
+            op_name, axis, tensor.dim
         );
     }
 
-    // extract the shape of the input tensor
-    let tensor = match node.inputs.first().unwrap().clone().ty {
-        ArgType::Tensor(tensor) => tensor,
-        _ => panic!("Only tensor input is valid"),
-    };
-
-    // extract the attributes
-    for (key, value) in node.attrs.iter() {
-        match key.as_str() {
-            "axis" => axis = value.clone().into_i64(),
-            _ => {}
-        }
-    }
-
-    // if axis is negative, it is counted from the end
-    if axis < 0 {
-        axis += tensor.dim as i64;
-    }
+    // When the axis is the last dimension, coercing to 2D is the same as the softmax along it
+    let coerce_2d = legacy && axis as usize != tensor.dim - 1;
 
-    axis as usize
+    (axis as usize, coerce_2d)
 }
 
 /// Create argmax config from the attributes of the node
@@ -540,11 +900,23 @@ pub fn concat_config(node: &Node) -> usize {
     // the axis is the last dimension (Default: 1 per ONNX spec)
     let mut axis: i64 = 1;
 
-    // extract the shape of the input tensor
-    let tensor = match node.inputs.first().unwrap().clone().ty {
-        ArgType::Tensor(tensor) => tensor,
-        _ => panic!("Only tensor input is valid"),
-    };
+    // extract the rank of the input tensors, which must all be the same
+    let ranks: Vec<usize> = node
+        .inputs
+        .iter()
+        .map(|input| match &input.ty {
+            ArgType::Tensor(tensor) => tensor.dim,
+            _ => panic!("Concat: only tensor inputs are valid, got {:?}", input.ty),
+        })
+        .collect();
+    let rank = ranks[0];
+
+    if ranks.iter().any(|&other| other != rank) {
+        panic!(
+            "Concat: all the inputs of {} must have the same rank, got the ranks {ranks:?}",
+            node.name
+        );
+    }
 
     // extract the attributes
     for (key, value) in node.attrs.iter() {
@@ -556,7 +928,11 @@ pub fn concat_config(node: &Node) -> usize {
 
     // if axis is negative, it is counted from the end
     if axis < 0 {
-        axis += tensor.dim as i64;
+        axis += rank as i64;
+    }
+
+    if axis < 0 || axis >= rank as i64 {
+        panic!("Concat: axis {axis} is out of bounds for inputs of rank {rank}");
     }
 
     axis as usize
@@ -625,6 +1001,64 @@ pub fn layer_norm_config(node: &Node) -> (LayerNormConfig, bool) {
     )
 }
 
+/// Create a GroupNormConfig from the attributes of the node
+pub fn group_norm_config(node: &Node) -> GroupNormConfig {
+    let mut num_groups = None;
+    let mut epsilon = 1e-5;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "num_groups" => num_groups = Some(value.clone().into_i64() as usize),
+            "epsilon" => epsilon = value.clone().into_f32(),
+            _ => {}
+        }
+    }
+
+    let num_groups = num_groups.expect("GroupNorm: num_groups attribute must be present");
+
+    // The channels are known from the input shape, or from the scale of opset 21, which holds a
+    // value per channel instead of one per group.
+    let num_channels = match &node.inputs[0].ty {
+        ArgType::Tensor(TensorType {
+            shape: Some(shape), ..
+        }) => shape[1],
+        _ => norm_num_features(node, "GroupNorm"),
+    };
+
+    if num_channels % num_groups != 0 {
+        panic!(
+            "GroupNorm: the number of channels ({num_channels}) must be divisible by num_groups ({num_groups})"
+        );
+    }
+
+    GroupNormConfig::new(num_groups, num_channels).with_epsilon(epsilon as f64)
+}
+
+/// Create a GroupNormConfig with one channel per group from the attributes of an
+/// InstanceNormalization node
+pub fn instance_norm_config(node: &Node) -> GroupNormConfig {
+    let num_channels = norm_num_features(node, "InstanceNorm");
+    let mut epsilon = 1e-5;
+
+    for (key, value) in node.attrs.iter() {
+        if key.as_str() == "epsilon" {
+            epsilon = value.clone().into_f32()
+        }
+    }
+
+    GroupNormConfig::new(num_channels, num_channels).with_epsilon(epsilon as f64)
+}
+
+/// The length of the scale tensor of a normalization node.
+fn norm_num_features(node: &Node, op: &str) -> usize {
+    match &node.inputs[1].ty {
+        ArgType::Tensor(TensorType {
+            shape: Some(shape), ..
+        }) => shape[0],
+        _ => panic!("{op}: scale tensor must be present"),
+    }
+}
+
 /// Calculate the padding configuration for a 2D operations such as Convolution and Pooling.
 ///
 /// # Arguments
@@ -663,6 +1097,34 @@ fn padding_config(pads: &[i64]) -> PaddingConfig2d {
     }
 }
 
+/// Size of a spatial dimension of the output of a convolution or pooling layer, padded with
+/// `padding` on both sides.
+///
+/// With `ceil_mode`, the size is rounded up, without counting a last window starting in the right
+/// padding.
+pub fn pool_output_size(
+    size: usize,
+    padding: usize,
+    kernel: usize,
+    stride: usize,
+    dilation: usize,
+    ceil_mode: bool,
+) -> usize {
+    let span = size + 2 * padding - dilation * (kernel - 1) - 1;
+
+    if !ceil_mode {
+        return span / stride + 1;
+    }
+
+    let num_windows = span.div_ceil(stride) + 1;
+
+    if (num_windows - 1) * stride >= size + padding {
+        num_windows - 1
+    } else {
+        num_windows
+    }
+}
+
 // Create a LeakyReluConfig from the alpha attribute of the node
 pub fn leaky_relu_config(node: &Node) -> f64 {
     let mut alpha = 0.01;
@@ -677,73 +1139,158 @@ pub fn leaky_relu_config(node: &Node) -> f64 {
     alpha
 }
 
-pub fn reshape_config(node: &Node) -> Vec<i64> {
-    let mut allowzero = 0;
+/// Get the alpha and beta attributes of a HardSigmoid node.
+pub fn hard_sigmoid_config(node: &Node) -> (f64, f64) {
+    let mut alpha = 0.2;
+    let mut beta = 0.5;
 
     for (key, value) in node.attrs.iter() {
         match key.as_str() {
-            "allowzero" => allowzero = value.clone().into_i64(),
+            "alpha" => alpha = value.clone().into_f32() as f64,
+            "beta" => beta = value.clone().into_f32() as f64,
             _ => {}
         }
     }
 
-    // Burn does not support zero size shape (0 means false in ONNX)
-    // (see https://onnx.ai/onnx/operators/onnx__Reshape.html#attributes)
-    if allowzero != 0 {
-        panic!("Zero shape size is not supported");
-    }
+    (alpha, beta)
+}
 
-    // TODO: check "shape" attribute
-    if node.inputs.len() != 2 || node.inputs[1].value.is_none() {
-        panic!("Reshape: shape tensor must be present for {:?}", node);
-    }
+/// Get the block size and the mode of a DepthToSpace node.
+pub fn depth_to_space_config(node: &Node) -> (usize, DepthToSpaceMode) {
+    let mut block_size = None;
+    let mut mode = DepthToSpaceMode::Dcr;
 
-    let input_value = &node.inputs[1].value;
-    match &node.inputs[1].ty {
-        ArgType::Tensor(tensor) => {
-            assert_eq!(tensor.dim, 1, "Reshape: shape tensor must be 1D");
-
-            if let Some(Data::Int64s(shape)) = input_value.as_ref() {
-                shape.clone()
-            } else {
-                panic!("Tensor data type must be int64")
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "blocksize" => block_size = Some(value.clone().into_i64() as usize),
+            "mode" => {
+                mode = match value.clone().into_string().as_str() {
+                    "DCR" => DepthToSpaceMode::Dcr,
+                    "CRD" => DepthToSpaceMode::Crd,
+                    mode => panic!("{}: unsupported mode {mode}", node.name),
+                }
             }
+            _ => {}
         }
-        _ => panic!("Only tensor input is valid for shape"),
     }
+
+    let block_size =
+        block_size.unwrap_or_else(|| panic!("{}: blocksize attribute is required", node.name));
+
+    (block_size, mode)
 }
 
-//Note this function should only execute if the second input is a constant
-//if it wasn't and the output shape was known, unsqueeze has been remapped to reshape
-pub fn unsqueeze_config(node: &Node) -> Vec<i64> {
-    // Check if axes attribute exists
+/// Get the block size of a SpaceToDepth node.
+pub fn space_to_depth_config(node: &Node) -> usize {
+    match node.attrs.get("blocksize") {
+        Some(value) => value.clone().into_i64() as usize,
+        None => panic!("{}: blocksize attribute is required", node.name),
+    }
+}
+
+pub fn reshape_config(node: &Node) -> Vec<i64> {
+    let mut allowzero = 0;
+
     for (key, value) in node.attrs.iter() {
         match key.as_str() {
-            "axes" => return value.clone().into_i64s(),
+            "allowzero" => allowzero = value.clone().into_i64(),
             _ => {}
         }
     }
 
-    assert!(
-        !node.inputs.is_empty(),
-        "Unsqueeze: axes tensor must be present"
-    );
+    // Burn does not support zero size shape (0 means false in ONNX)
+    // (see https://onnx.ai/onnx/operators/onnx__Reshape.html#attributes)
+    if allowzero != 0 {
+        panic!("Zero shape size is not supported");
+    }
 
-    let input_value = &node.inputs[1];
+    // TODO: check "shape" attribute
+    if node.inputs.len() != 2 || node.inputs[1].value.is_none() {
+        panic!("Reshape: shape tensor must be present for {:?}", node);
+    }
 
+    let input_value = &node.inputs[1].value;
     match &node.inputs[1].ty {
         ArgType::Tensor(tensor) => {
-            assert_eq!(tensor.dim, 1, "Unsqueeze: axes tensor must be 1D");
-            if let Some(Data::Int64s(shape)) = input_value.value.as_ref() {
+            assert_eq!(tensor.dim, 1, "Reshape: shape tensor must be 1D");
+
+            if let Some(Data::Int64s(shape)) = input_value.as_ref() {
                 shape.clone()
             } else {
                 panic!("Tensor data type must be int64")
             }
         }
-        _ => panic!("Arg for unsqueeze must be tensor or scalar"),
+        _ => panic!("Only tensor input is valid for shape"),
+    }
+}
+
+//Note this function should only execute if the second input is a constant
+//if it wasn't and the output shape was known, unsqueeze has been remapped to reshape
+/// Create the sorted and non-negative axes of an Unsqueeze node, i.e. the positions of the
+/// inserted dimensions in the output.
+///
+/// The axes are an attribute before opset 13 and an input since.
+pub fn unsqueeze_config(node: &Node) -> Vec<i64> {
+    let axes = axes_attr_or_input(node, "Unsqueeze")
+        .unwrap_or_else(|| panic!("Unsqueeze: axes must be present"));
+
+    let input_dim = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.dim,
+        ArgType::Scalar(_) => 0, // treat scalar as 0-dim tensor
+        _ => panic!("Unsqueeze: invalid input type"),
+    };
+
+    // Negative axes are counted from the end of the output
+    let output_dim = input_dim + axes.len();
+    normalize_axes(axes, output_dim, "Unsqueeze")
+}
+
+/// Read the `axes` attribute of the node, or its constant second input for the newer opsets.
+fn axes_attr_or_input(node: &Node, op: &str) -> Option<Vec<i64>> {
+    if let Some(axes) = node.attrs.get("axes") {
+        return Some(axes.clone().into_i64s());
+    }
+
+    let input = node.inputs.get(1)?;
+
+    match &input.ty {
+        ArgType::Tensor(tensor) => assert_eq!(tensor.dim, 1, "{op}: axes tensor must be 1D"),
+        _ => panic!("{op}: axes must be a tensor"),
+    }
+
+    match input.value.as_ref() {
+        Some(Data::Int64s(axes)) => Some(axes.clone()),
+        Some(_) => panic!("{op}: axes tensor data type must be int64"),
+        None => panic!("{op}: axes must be a constant"),
+    }
+}
+
+/// Convert negative axes counted from the end of a tensor of rank `dim` and sort them.
+fn normalize_axes(axes: Vec<i64>, dim: usize, op: &str) -> Vec<i64> {
+    let mut axes = axes
+        .into_iter()
+        .map(|axis| {
+            let normalized = if axis < 0 { axis + dim as i64 } else { axis };
+
+            if normalized < 0 || normalized >= dim as i64 {
+                panic!("{op}: axis {axis} is out of range for rank {dim}");
+            }
+
+            normalized
+        })
+        .collect::<Vec<_>>();
+
+    axes.sort_unstable();
+
+    if axes.windows(2).any(|pair| pair[0] == pair[1]) {
+        panic!("{op}: axes {axes:?} must be unique");
     }
+
+    axes
 }
 
+/// Create the min and max values from the attributes or inputs of the node, `None` when the
+/// bound is absent
 pub fn clip_config(node: &Node) -> (Option<f64>, Option<f64>) {
     let mut min_result: Option<f64> = None;
     let mut max_result: Option<f64> = None;
@@ -763,35 +1310,31 @@ pub fn clip_config(node: &Node) -> (Option<f64>, Option<f64>) {
         }
     }
 
-    // For Clip Opset 11+ , the min and max values are inputs
-    // Get the min and max values from the input values
-    if min_result.is_none() && max_result.is_none() {
-        let min = &node.inputs[1].value;
-        let max = &node.inputs[2].value;
-
-        if min_result.is_none() && min.is_some() {
-            let min = min.clone().unwrap().into_scalar();
-            min_result = match min {
-                Data::Float16(min) => Some(f32::from(min) as f64),
-                Data::Float32(min) => Some(min as f64),
-                Data::Float64(min) => Some(min),
-                _ => panic!("Clip: only float min is supported"),
-            };
-        }
+    // For Clip Opset 11+ , the min and max values are optional inputs which must be constants
+    let input_value = |index: usize| {
+        node.inputs.get(index).and_then(|arg| match &arg.value {
+            Some(data) => Some(match data.clone().into_scalar() {
+                Data::Float16(value) => f32::from(value) as f64,
+                Data::Float32(value) => value as f64,
+                Data::Float64(value) => value,
+                Data::Int32(value) => value as f64,
+                Data::Int64(value) => value as f64,
+                data => panic!("Clip: only numeric min and max are supported, got {data:?}"),
+            }),
+            None if arg.name.is_empty() => None,
+            None => panic!(
+                "Clip: min and max must be constants, {} is a runtime input",
+                arg.name
+            ),
+        })
+    };
 
-        if max_result.is_none() && max.is_some() {
-            let max = max.clone().unwrap().into_scalar();
-            max_result = match max {
-                Data::Float16(max) => Some(f32::from(max) as f64),
-                Data::Float32(max) => Some(max as f64),
-                Data::Float64(max) => Some(max),
-                _ => panic!("Clip: only float max is supported"),
-            };
-        }
+    if min_result.is_none() {
+        min_result = input_value(1);
     }
 
-    if min_result.is_none() && max_result.is_none() {
-        panic!("Clip: min and max values must be either attributes or inputs");
+    if max_result.is_none() {
+        max_result = input_value(2);
     }
 
     (min_result, max_result)
@@ -835,9 +1378,15 @@ fn padding_config_1d(pads: &[i64]) -> PaddingConfig1d {
     }
 }
 
-pub fn reduce_max_config(node: &Node) -> Option<usize> {
+/// Create the reduced dimensions and the `keepdims` flag of a `ReduceMax`, `ReduceMean`,
+/// `ReduceMin` or `ReduceSum` node.
+///
+/// The dimensions are sorted and positive, `None` means that the input is reduced over all of its
+/// dimensions without keeping them.
+pub fn reduce_config(node: &Node) -> (Option<Vec<usize>>, bool) {
     let mut axes = Vec::new();
     let mut keepdims = 1;
+    let mut noop_with_empty_axes = 0;
 
     let tensor = match node.inputs.first().unwrap().clone().ty {
         ArgType::Tensor(tensor) => tensor,
@@ -849,214 +1398,1098 @@ pub fn reduce_max_config(node: &Node) -> Option<usize> {
         match key.as_str() {
             "axes" => axes = value.clone().into_i64s(),
             "keepdims" => keepdims = value.clone().into_i64(),
+            "noop_with_empty_axes" => noop_with_empty_axes = value.clone().into_i64(),
             _ => {}
         }
     }
 
-    if axes.len() > 1 {
-        panic!("ReduceMax: reducing on multiple dimensions is not supported")
+    // Since opset 18 (opset 13 for ReduceSum), the axes are provided as an input
+    if let Some(value) = node
+        .inputs
+        .get(1)
+        .and_then(|argument| argument.value.as_ref())
+    {
+        axes = value.clone().into_i64s();
     }
 
-    if axes.is_empty() && keepdims == 1 {
-        panic!("ReduceMax: axes must be provided with keepdims")
+    if axes.is_empty() && noop_with_empty_axes == 1 {
+        panic!(
+            "{:?}: noop_with_empty_axes is not supported",
+            node.node_type
+        )
     }
 
-    if !axes.is_empty() && keepdims == 0 {
-        // Not supported in Burn
-        panic!("ReduceMax: the reduce operation must preserve the reduced dimension")
+    let rank = tensor.dim as i64;
+    let mut dims = axes
+        .into_iter()
+        .map(|axis| {
+            // Accepted range is [-r, r-1] where r = rank(data) but Burn only supports positive dim
+            let dim = if axis < 0 { axis + rank } else { axis };
+
+            if dim < 0 || dim >= rank {
+                panic!(
+                    "{:?}: axis {} is out of bounds for a tensor of rank {}",
+                    node.node_type, axis, rank
+                )
+            }
+            dim as usize
+        })
+        .collect::<Vec<_>>();
+
+    dims.sort_unstable();
+    dims.dedup();
+
+    if dims.is_empty() {
+        dims = (0..tensor.dim).collect();
     }
 
-    if axes.is_empty() {
-        None
+    if keepdims == 0 && dims.len() == tensor.dim {
+        (None, false)
     } else {
-        let mut dim = axes[0];
+        (Some(dims), keepdims == 1)
+    }
+}
 
-        if dim < 0 {
-            // Accepted range is [-r, r-1] where r = rank(data) but Burn only supports positive dim
-            dim += tensor.dim as i64;
+/// Create the dimensions normalized by a MeanVarianceNormalization node.
+///
+/// The `axes` default to `[0, 2, 3]`, i.e. the statistics are computed per channel over the batch
+/// and the spatial dimensions of an image. Negative axes are counted from the end.
+pub fn mean_variance_normalization_config(node: &Node) -> Vec<usize> {
+    let tensor = match node.inputs.first().unwrap().clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("MeanVarianceNormalization: only tensor input is valid"),
+    };
+    let rank = tensor.dim as i64;
+
+    let axes = match node.attrs.get("axes") {
+        Some(value) => value.clone().into_i64s(),
+        None => vec![0, 2, 3],
+    };
+
+    let mut dims = axes
+        .into_iter()
+        .map(|axis| {
+            let dim = if axis < 0 { axis + rank } else { axis };
+
+            if dim < 0 || dim >= rank {
+                panic!(
+                    "MeanVarianceNormalization: axis {} is out of bounds for a tensor of rank {}",
+                    axis, rank
+                )
+            }
+            dim as usize
+        })
+        .collect::<Vec<_>>();
+
+    dims.sort_unstable();
+    dims.dedup();
+
+    dims
+}
+
+/// Create the range `[start, end)` of the dimensions selected by a Shape node.
+///
+/// Since opset 15, the `start` and `end` attributes select a slice of the shape. Negative indices
+/// are counted from the end and both indices are clamped to the rank, an empty range being
+/// selected when `start` isn't before `end`.
+pub fn shape_config(curr: &Node) -> (usize, usize) {
+    if curr.inputs.len() != 1 {
+        panic!(
+            "Shape: multiple inputs are not supported (got {:?})",
+            curr.inputs.len()
+        );
+    }
+
+    // Extract the shape of the input tensor
+    let tensor = match curr.inputs.first().unwrap().clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("Only tensor input is valid"),
+    };
+    let rank = tensor.dim as i64;
+
+    // Default: all axes up to the last one (included)
+    let mut start_dim: i64 = 0;
+    let mut end_dim: i64 = rank;
+
+    // Extract the attributes
+    for (key, value) in curr.attrs.iter() {
+        match key.as_str() {
+            "start" => start_dim = value.clone().into_i64(),
+            "end" => end_dim = value.clone().into_i64(),
+            _ => {}
         }
-        Some(dim as usize)
     }
+
+    // If dim is negative, it is counted from the end
+    let clamp = |dim: i64| {
+        let dim = if dim < 0 { dim + rank } else { dim };
+        dim.clamp(0, rank) as usize
+    };
+    let start_dim = clamp(start_dim);
+    let end_dim = clamp(end_dim).max(start_dim);
+
+    (start_dim, end_dim)
 }
 
-pub fn reduce_mean_config(node: &Node) -> Option<usize> {
-    let mut axes = Vec::new();
-    let mut keepdims = 1;
+/// Create the permutation of the dimensions of a Transpose node.
+///
+/// The dimensions are reversed when the `perm` attribute is absent.
+pub fn transpose_config(curr: &Node) -> Vec<usize> {
+    if curr.inputs.len() != 1 {
+        panic!(
+            "Transpose: multiple inputs are not supported (got {:?})",
+            curr.inputs.len()
+        );
+    }
 
-    let tensor = match node.inputs.first().unwrap().clone().ty {
+    // Extract the shape of the input tensor
+    let tensor = match curr.inputs.first().unwrap().clone().ty {
         ArgType::Tensor(tensor) => tensor,
         _ => panic!("Only tensor input is valid"),
     };
 
-    // Extract the attributes
+    // Default: reverse the dimensions
+    let perm = match curr.attrs.get("perm") {
+        Some(perm) => perm.clone().into_i64s(),
+        None => return (0..tensor.dim).rev().collect(),
+    };
+
+    let mut seen = vec![false; tensor.dim];
+    let valid = perm.len() == tensor.dim
+        && perm.iter().all(|&axis| {
+            let in_range = axis >= 0 && (axis as usize) < tensor.dim;
+            in_range && !std::mem::replace(&mut seen[axis as usize], true)
+        });
+
+    if !valid {
+        panic!(
+            "Transpose: perm {:?} is not a permutation of the {} dimensions of the input",
+            perm, tensor.dim
+        );
+    }
+
+    perm.into_iter().map(|axis| axis as usize).collect()
+}
+
+/// Create the sorted and non-negative axes of a Squeeze node.
+///
+/// The axes are an attribute before opset 13 and an input since. Without axes, all the
+/// dimensions of size 1 are squeezed, which requires the shape of the input to be known.
+pub fn squeeze_config(curr: &Node) -> Vec<i64> {
+    let tensor = match curr.inputs.first().unwrap().clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("Only tensor input is valid"),
+    };
+
+    let axes = match axes_attr_or_input(curr, "Squeeze") {
+        Some(axes) => axes,
+        None => match &tensor.shape {
+            Some(shape) => shape
+                .iter()
+                .enumerate()
+                .filter(|(_, &size)| size == 1)
+                .map(|(axis, _)| axis as i64)
+                .collect(),
+            None => panic!("Squeeze: the input shape must be known to squeeze without axes"),
+        },
+    };
+
+    normalize_axes(axes, tensor.dim, "Squeeze")
+}
+
+/// Create the resize mode and output size from the attributes and inputs of the node
+pub fn resize_config(node: &Node) -> (ResizeMode, ResizeSize) {
+    let mut mode = ResizeMode::Nearest;
+    let mut coordinate_transformation_mode = "half_pixel".to_string();
+
     for (key, value) in node.attrs.iter() {
         match key.as_str() {
-            "axes" => axes = value.clone().into_i64s(),
-            "keepdims" => keepdims = value.clone().into_i64(),
+            "mode" => {
+                mode = match value.clone().into_string().as_str() {
+                    "nearest" => ResizeMode::Nearest,
+                    "linear" => ResizeMode::Linear,
+                    mode => panic!("Resize: unsupported mode {mode}"),
+                }
+            }
+            "coordinate_transformation_mode" => {
+                coordinate_transformation_mode = value.clone().into_string()
+            }
             _ => {}
         }
     }
 
-    if axes.len() > 1 {
-        panic!("ReduceMean: reducing on multiple dimensions is not supported")
+    if !matches!(
+        coordinate_transformation_mode.as_str(),
+        "half_pixel" | "asymmetric"
+    ) {
+        panic!(
+            "Resize: unsupported coordinate transformation mode {coordinate_transformation_mode}"
+        );
     }
 
-    if axes.is_empty() && keepdims == 1 {
-        panic!("ReduceMean: axes must be provided with keepdims")
+    // Burn's nearest interpolation uses asymmetric coordinates, and its bilinear interpolation
+    // aligns the corners.
+    if mode != ResizeMode::Nearest || coordinate_transformation_mode != "asymmetric" {
+        log::warn!(
+            "Resize: {coordinate_transformation_mode} coordinates are approximated by Burn's {mode:?} interpolation"
+        );
     }
 
-    if !axes.is_empty() && keepdims == 0 {
-        // Not supported in Burn
-        panic!("ReduceMean: the reduce operation must preserve the reduced dimension")
+    match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => {
+            assert_eq!(tensor.dim, 4, "Resize: only 4D input tensors are supported")
+        }
+        _ => panic!("Resize: only tensor input is valid"),
     }
 
-    if axes.is_empty() {
-        None
-    } else {
-        let mut dim = axes[0];
+    // Opset 10 has the inputs [X, scales], later opsets have [X, roi, scales, sizes] where the roi
+    // is only used by the `tf_crop_and_resize` coordinate transformation mode.
+    let (scales, sizes) = match node.inputs.len() {
+        2 => (node.inputs.get(1), None),
+        _ => (node.inputs.get(2), node.inputs.get(3)),
+    };
 
-        if dim < 0 {
-            // Accepted range is [-r, r-1] where r = rank(data) but Burn only supports positive dim
-            dim += tensor.dim as i64;
+    // Optional inputs are omitted with an empty name or given as an empty initializer.
+    let is_provided = |arg: &&Argument| match &arg.value {
+        Some(Data::Float32s(values)) => !values.is_empty(),
+        Some(Data::Int64s(values)) => !values.is_empty(),
+        Some(_) => true,
+        None => !arg.name.is_empty(),
+    };
+
+    if let Some(sizes) = sizes.filter(is_provided) {
+        let size = match &sizes.value {
+            Some(Data::Int64s(sizes)) => {
+                assert_eq!(sizes.len(), 4, "Resize: sizes must have 4 values");
+                ResizeSize::Sizes([sizes[2] as usize, sizes[3] as usize])
+            }
+            Some(data) => panic!("Resize: sizes must be int64, got {data:?}"),
+            None => ResizeSize::RuntimeSizes(sizes.to_tensor_type()),
+        };
+
+        return (mode, size);
+    }
+
+    if let Some(scales) = scales.filter(is_provided) {
+        let size = match &scales.value {
+            Some(Data::Float32s(scales)) => {
+                assert_eq!(scales.len(), 4, "Resize: scales must have 4 values");
+                assert!(
+                    scales[0] == 1.0 && scales[1] == 1.0,
+                    "Resize: only the spatial dimensions can be scaled"
+                );
+                ResizeSize::Scales([scales[2], scales[3]])
+            }
+            Some(data) => panic!("Resize: scales must be float32, got {data:?}"),
+            None => ResizeSize::RuntimeScales(scales.to_tensor_type()),
+        };
+
+        return (mode, size);
+    }
+
+    panic!("Resize: either scales or sizes must be provided")
+}
+
+/// Create a LstmConfig and the direction from the attributes of the node
+pub fn lstm_config(node: &Node) -> (LstmConfig, LstmDirection) {
+    let mut hidden_size = None;
+    let mut direction = LstmDirection::Forward;
+    let mut activations = None;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "hidden_size" => hidden_size = Some(value.clone().into_i64() as usize),
+            "direction" => {
+                direction = match value.clone().into_string().as_str() {
+                    "forward" => LstmDirection::Forward,
+                    "reverse" => LstmDirection::Reverse,
+                    "bidirectional" => LstmDirection::Bidirectional,
+                    direction => panic!("LSTM: unsupported direction {direction}"),
+                }
+            }
+            "activations" => activations = Some(value.clone().into_strings()),
+            "clip" => panic!("LSTM: cell clipping is not supported"),
+            "input_forget" if value.clone().into_i64() != 0 => {
+                panic!("LSTM: coupled input and forget gates are not supported")
+            }
+            "layout" if value.clone().into_i64() != 0 => {
+                panic!("LSTM: only the [seq_length, batch_size, input_size] layout is supported")
+            }
+            _ => {}
         }
-        Some(dim as usize)
     }
+
+    let hidden_size = hidden_size.expect("LSTM: hidden_size attribute is required");
+
+    // Burn's LSTM uses the default sigmoid, tanh and tanh activations for each direction.
+    if let Some(activations) = activations {
+        let supported = activations.chunks(3).all(|chunk| {
+            chunk
+                .iter()
+                .map(String::as_str)
+                .eq(["Sigmoid", "Tanh", "Tanh"])
+        });
+
+        if !supported {
+            panic!("LSTM: unsupported activations {activations:?}");
+        }
+    }
+
+    // The optional inputs are omitted with an empty name.
+    let is_provided = |index: usize| {
+        node.inputs
+            .get(index)
+            .map(|arg| !arg.name.is_empty() || arg.value.is_some())
+            .unwrap_or(false)
+    };
+
+    if is_provided(4) {
+        panic!(
+            "LSTM: sequence_lens input is not supported, all sequences must have the same length"
+        );
+    }
+
+    if is_provided(7) {
+        panic!("LSTM: peephole weights are not supported");
+    }
+
+    // The weight has the shape [num_directions, 4 * hidden_size, input_size]
+    let weight = match &node.inputs[1].ty {
+        ArgType::Tensor(weight) => weight,
+        _ => panic!("LSTM: weight tensor must be present"),
+    };
+    let d_input = weight
+        .shape
+        .clone()
+        .expect("LSTM: weight shape is required")[2];
+
+    let bias = node.inputs.len() > 3 && node.inputs[3].value.is_some();
+
+    (LstmConfig::new(d_input, hidden_size, bias), direction)
 }
 
-pub fn reduce_sum_config(node: &Node) -> Option<usize> {
-    let mut axes = Vec::new();
-    let mut keepdims = 1;
+/// Create the Einsum operation from the equation of the node
+///
+/// Only the permutations of a single input and the matrix products of two inputs, with the
+/// same leading batch dimensions, are supported.
+pub fn einsum_config(node: &Node) -> EinsumOp {
+    let equation: String = node
+        .attrs
+        .get("equation")
+        .expect("Einsum: equation attribute is required")
+        .clone()
+        .into_string()
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    let unsupported = || -> ! { panic!("Einsum: unsupported equation {equation}") };
 
-    let tensor = match node.inputs.first().unwrap().clone().ty {
-        ArgType::Tensor(tensor) => tensor,
-        _ => panic!("Only tensor input is valid"),
+    let ranks: Vec<usize> = node
+        .inputs
+        .iter()
+        .map(|input| match &input.ty {
+            ArgType::Tensor(tensor) => tensor.dim,
+            _ => panic!("Einsum: only tensor inputs are valid"),
+        })
+        .collect();
+
+    let (inputs, output) = match equation.split_once("->") {
+        Some((inputs, output)) => (inputs, Some(output)),
+        None => (equation.as_str(), None),
     };
+    let inputs: Vec<&str> = inputs.split(',').collect();
+
+    if inputs.len() != ranks.len() {
+        panic!(
+            "Einsum: the equation {equation} has {} operands, but the node has {} inputs",
+            inputs.len(),
+            ranks.len()
+        );
+    }
+
+    // The dimensions of the ellipsis are labeled with digits, aligned to the right
+    let ellipsis_len = |subscripts: &str, rank: usize| match subscripts.contains("...") {
+        true => rank + 3 - subscripts.len(),
+        false => 0,
+    };
+    let labels = |subscripts: &str, ellipsis_len: usize| -> Vec<char> {
+        let ellipsis = (0..ellipsis_len as u32)
+            .rev()
+            .map(|index| char::from_digit(index, 10).unwrap());
+
+        match subscripts.split_once("...") {
+            Some((before, after)) => before
+                .chars()
+                .chain(ellipsis)
+                .chain(after.chars())
+                .collect(),
+            None => subscripts.chars().collect(),
+        }
+    };
+
+    let max_ellipsis_len = inputs
+        .iter()
+        .zip(&ranks)
+        .map(|(subscripts, &rank)| ellipsis_len(subscripts, rank))
+        .max()
+        .unwrap_or(0);
+    let inputs: Vec<Vec<char>> = inputs
+        .iter()
+        .zip(&ranks)
+        .map(|(subscripts, &rank)| labels(subscripts, ellipsis_len(subscripts, rank)))
+        .collect();
+
+    let output = match output {
+        Some(output) => labels(output, max_ellipsis_len),
+        // In implicit mode, the output has the ellipsis dimensions followed by the labels
+        // appearing once, in alphabetical order
+        None => {
+            let all_labels: Vec<char> = inputs.iter().flatten().copied().collect();
+            let mut once: Vec<char> = all_labels
+                .iter()
+                .filter(|label| {
+                    label.is_alphabetic() && all_labels.iter().filter(|l| l == label).count() == 1
+                })
+                .copied()
+                .collect();
+            once.sort();
+
+            labels("...", max_ellipsis_len)
+                .into_iter()
+                .chain(once)
+                .collect()
+        }
+    };
+
+    let has_duplicates =
+        |labels: &[char]| (0..labels.len()).any(|index| labels[..index].contains(&labels[index]));
+
+    if inputs.iter().any(|labels| has_duplicates(labels)) || has_duplicates(&output) {
+        // Diagonals and traces
+        unsupported();
+    }
+
+    match &inputs[..] {
+        [input] => {
+            if input.len() != output.len() {
+                // Reductions
+                unsupported();
+            }
+
+            let perm = output
+                .iter()
+                .map(|label| {
+                    input
+                        .iter()
+                        .position(|l| l == label)
+                        .unwrap_or_else(|| unsupported())
+                })
+                .collect();
+
+            EinsumOp::Permute(perm)
+        }
+        [lhs, rhs] => {
+            let batch: Vec<char> = output
+                .iter()
+                .filter(|label| lhs.contains(label) && rhs.contains(label))
+                .copied()
+                .collect();
+            let num_batch = batch.len();
+
+            let is_matrix =
+                |labels: &[char]| labels.len() == num_batch + 2 && labels[..num_batch] == batch[..];
+
+            if !is_matrix(lhs) || !is_matrix(rhs) || !is_matrix(&output) {
+                unsupported();
+            }
+
+            // The free dimension of each operand is kept in the output while the other one is
+            // contracted, the matrix product expects `[free, contracted]` for the lhs and
+            // `[contracted, free]` for the rhs
+            let matrix_dims = |labels: &[char]| {
+                let (first, second) = (labels[num_batch], labels[num_batch + 1]);
+
+                match (output.contains(&first), output.contains(&second)) {
+                    (true, false) => (first, second, true),
+                    (false, true) => (second, first, false),
+                    _ => unsupported(),
+                }
+            };
+            let (lhs_free, lhs_contracted, lhs_free_first) = matrix_dims(lhs);
+            let (rhs_free, rhs_contracted, rhs_free_first) = matrix_dims(rhs);
+
+            if lhs_contracted != rhs_contracted {
+                unsupported();
+            }
+
+            let transpose_output = match &output[num_batch..] {
+                [i, j] if *i == lhs_free && *j == rhs_free => false,
+                [j, i] if *i == lhs_free && *j == rhs_free => true,
+                _ => unsupported(),
+            };
+
+            EinsumOp::Matmul {
+                transpose_lhs: !lhs_free_first,
+                transpose_rhs: rhs_free_first,
+                transpose_output,
+            }
+        }
+        _ => unsupported(),
+    }
+}
+
+/// Create a GruConfig and the direction from the attributes of the node
+pub fn gru_config(node: &Node) -> (GruConfig, GruDirection) {
+    let mut hidden_size = None;
+    let mut direction = GruDirection::Forward;
+    let mut linear_before_reset = false;
 
-    // Extract the attributes
     for (key, value) in node.attrs.iter() {
         match key.as_str() {
-            "keepdims" => keepdims = value.clone().into_i64(),
-            "axes" => axes = value.clone().into_i64s(),
-            // TODO: handle noop_with_empty_axes
+            "hidden_size" => hidden_size = Some(value.clone().into_i64() as usize),
+            "direction" => {
+                direction = match value.clone().into_string().as_str() {
+                    "forward" => GruDirection::Forward,
+                    "reverse" => GruDirection::Reverse,
+                    "bidirectional" => panic!("GRU: bidirectional direction is not supported"),
+                    direction => panic!("GRU: unsupported direction {direction}"),
+                }
+            }
+            "linear_before_reset" => linear_before_reset = value.clone().into_i64() != 0,
+            // Burn's GRU uses the default sigmoid and tanh activations.
+            "activations" => {
+                let activations = value.clone().into_strings();
+
+                if !activations
+                    .iter()
+                    .map(String::as_str)
+                    .eq(["Sigmoid", "Tanh"])
+                {
+                    panic!("GRU: unsupported activations {activations:?}");
+                }
+            }
+            "clip" => panic!("GRU: cell clipping is not supported"),
+            "layout" if value.clone().into_i64() != 0 => {
+                panic!("GRU: only the [seq_length, batch_size, input_size] layout is supported")
+            }
             _ => {}
         }
     }
 
-    // TODO: Handle case where axes are passed in. Will require its own ReduceSumNode instead of a UnaryNode.
-    if let Some(value) = node
+    let hidden_size = hidden_size.expect("GRU: hidden_size attribute is required");
+
+    // The optional inputs are omitted with an empty name.
+    let sequence_lens_provided = node
         .inputs
-        .get(1)
-        .and_then(|argument| argument.value.as_ref())
-    {
-        axes = value.clone().into_i64s();
+        .get(4)
+        .map(|arg| !arg.name.is_empty() || arg.value.is_some())
+        .unwrap_or(false);
+
+    if sequence_lens_provided {
+        panic!(
+            "GRU: sequence_lens input is not supported, all sequences must have the same length"
+        );
     }
 
-    if axes.len() > 1 {
-        panic!("ReduceMean: reducing on multiple dimensions is not supported")
+    // The weight has the shape [num_directions, 3 * hidden_size, input_size]
+    let weight = match &node.inputs[1].ty {
+        ArgType::Tensor(weight) => weight,
+        _ => panic!("GRU: weight tensor must be present"),
+    };
+    let d_input = weight.shape.clone().expect("GRU: weight shape is required")[2];
+
+    let bias = node.inputs.len() > 3 && node.inputs[3].value.is_some();
+
+    let config = GruConfig::new(d_input, hidden_size, bias).with_reset_after(linear_before_reset);
+
+    (config, direction)
+}
+
+/// Create the `(start, end)` ranges of the leading dimensions from the attributes or inputs of
+/// the node
+pub fn slice_config(node: &Node) -> Vec<Option<(i64, i64)>> {
+    let rank = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.dim,
+        _ => panic!("Slice: only tensor input is valid"),
+    };
+
+    // Before opset 10, the starts, ends and axes are attributes
+    let mut starts = node.attrs.get("starts").map(|v| v.clone().into_i64s());
+    let mut ends = node.attrs.get("ends").map(|v| v.clone().into_i64s());
+    let mut axes = node.attrs.get("axes").map(|v| v.clone().into_i64s());
+    let mut steps = None;
+
+    // Since opset 10, they are inputs which must be constants
+    let input_values = |index: usize| {
+        node.inputs.get(index).and_then(|arg| match &arg.value {
+            Some(Data::Int64s(values)) => Some(values.clone()),
+            Some(Data::Int32s(values)) => Some(values.iter().map(|&v| v as i64).collect()),
+            Some(data) => panic!("Slice: indices must be integers, got {data:?}"),
+            None if arg.name.is_empty() => None,
+            None => panic!(
+                "Slice: indices must be constants, {} is a runtime input",
+                arg.name
+            ),
+        })
+    };
+
+    if node.inputs.len() > 1 {
+        starts = input_values(1);
+        ends = input_values(2);
+        axes = input_values(3);
+        steps = input_values(4);
     }
 
-    if axes.is_empty() && keepdims == 1 {
-        panic!("ReduceMean: axes must be provided with keepdims")
+    let starts = starts.expect("Slice: starts must be present");
+    let ends = ends.expect("Slice: ends must be present");
+    let axes = axes.unwrap_or_else(|| (0..starts.len() as i64).collect());
+
+    assert_eq!(
+        starts.len(),
+        ends.len(),
+        "Slice: starts and ends must have the same length"
+    );
+    assert_eq!(
+        starts.len(),
+        axes.len(),
+        "Slice: starts and axes must have the same length"
+    );
+
+    if let Some(steps) = steps {
+        if steps.iter().any(|&step| step != 1) {
+            panic!("Slice: only a step of 1 is supported, got {steps:?}");
+        }
     }
 
-    if !axes.is_empty() && keepdims == 0 {
-        // Not supported in Burn
-        panic!("ReduceMean: the reduce operation must preserve the reduced dimension")
+    let mut ranges = Vec::new();
+
+    for ((start, end), axis) in starts.into_iter().zip(ends).zip(axes) {
+        // Negative axes are counted from the last dimension
+        let axis = if axis < 0 { axis + rank as i64 } else { axis } as usize;
+
+        if axis >= rank {
+            panic!("Slice: axis {axis} is out of bounds for rank {rank}");
+        }
+
+        // Burn tensors can't be empty
+        if is_empty_range(start, end) {
+            panic!(
+                "Slice: the range {start}..{end} of axis {axis} is empty, which isn't supported"
+            );
+        }
+
+        if ranges.len() <= axis {
+            ranges.resize(axis + 1, None);
+        }
+        ranges[axis] = Some((start, end));
     }
 
-    if axes.is_empty() {
-        None
-    } else {
-        let mut dim = axes[0];
+    ranges
+}
 
-        if dim < 0 {
-            // Accepted range is [-r, r-1] where r = rank(data) but Burn only supports positive dim
-            dim += tensor.dim as i64;
+/// Create the axis and the output sizes from the attributes or inputs of the node
+///
+/// The sizes are `None` when the input is split in chunks of equal size, the last chunk being
+/// smaller when the dimension isn't divisible by the number of outputs.
+pub fn split_config(node: &Node) -> (usize, Option<Vec<usize>>) {
+    let rank = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.dim,
+        _ => panic!("Split: only tensor input is valid"),
+    };
+
+    let mut axis: i64 = 0;
+    // Before opset 13, the sizes are an attribute
+    let mut split = None;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "axis" => axis = value.clone().into_i64(),
+            "split" => split = Some(value.clone().into_i64s()),
+            // The `num_outputs` attribute of opset 18 is the number of outputs of the node
+            _ => {}
         }
-        Some(dim as usize)
     }
+
+    // Since opset 13, the sizes are an optional input which must be a constant
+    if let Some(arg) = node.inputs.get(1) {
+        split = match &arg.value {
+            Some(Data::Int64s(values)) => Some(values.clone()),
+            Some(data) => panic!("Split: split must be a tensor of int64, got {data:?}"),
+            None if arg.name.is_empty() => split,
+            None => panic!(
+                "Split: split must be a constant, {} is a runtime input",
+                arg.name
+            ),
+        };
+    }
+
+    // Negative axis is counted from the last dimension
+    if axis < 0 {
+        axis += rank as i64;
+    }
+    let axis = axis as usize;
+
+    if axis >= rank {
+        panic!("Split: axis {axis} is out of bounds for rank {rank}");
+    }
+
+    let sizes = split.map(|split| {
+        if split.len() != node.outputs.len() {
+            panic!(
+                "Split: {} sizes given for {} outputs",
+                split.len(),
+                node.outputs.len()
+            );
+        }
+
+        split
+            .into_iter()
+            .map(|size| {
+                usize::try_from(size)
+                    .unwrap_or_else(|_| panic!("Split: sizes must be positive, got {size}"))
+            })
+            .collect()
+    });
+
+    (axis, sizes)
 }
 
-pub fn shape_config(curr: &Node) -> (usize, usize) {
-    if curr.inputs.len() != 1 {
-        panic!(
-            "Shape: multiple inputs are not supported (got {:?})",
-            curr.inputs.len()
-        );
+/// Get the number of values `k`, the axis and whether the largest values are selected from a
+/// TopK node.
+///
+/// The values are always sorted, which is allowed when `sorted=0` since the order is then
+/// unspecified.
+pub fn top_k_config(node: &Node) -> (usize, usize, bool) {
+    let rank = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.dim,
+        _ => panic!("TopK: only tensor input is valid"),
+    };
+
+    let mut axis: i64 = -1;
+    let mut largest = true;
+    // Before opset 10, k is an attribute
+    let mut k = None;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "axis" => axis = value.clone().into_i64(),
+            "k" => k = Some(value.clone().into_i64()),
+            "largest" => largest = value.clone().into_i64() != 0,
+            _ => {}
+        }
     }
 
-    // Extract the shape of the input tensor
-    let tensor = match curr.inputs.first().unwrap().clone().ty {
-        ArgType::Tensor(tensor) => tensor,
-        _ => panic!("Only tensor input is valid"),
+    // Since opset 10, k is an input which must be a constant
+    if let Some(arg) = node.inputs.get(1) {
+        k = match &arg.value {
+            Some(Data::Int64s(values)) if values.len() == 1 => Some(values[0]),
+            Some(Data::Int64(value)) => Some(*value),
+            Some(data) => panic!("TopK: k must be a single int64, got {data:?}"),
+            None => panic!(
+                "TopK: k must be a constant, {} is a runtime input",
+                arg.name
+            ),
+        };
+    }
+
+    let k = match k {
+        Some(k) => {
+            usize::try_from(k).unwrap_or_else(|_| panic!("TopK: k must be positive, got {k}"))
+        }
+        None => panic!("TopK: k is required"),
     };
 
-    // Default: all axes up to the last one (included)
-    let mut start_dim: i64 = 0;
-    let mut end_dim: i64 = tensor.dim as i64;
+    // Negative axis is counted from the last dimension
+    if axis < 0 {
+        axis += rank as i64;
+    }
+    let axis = axis as usize;
 
-    // Extract the attributes
-    for (key, value) in curr.attrs.iter() {
+    if axis >= rank {
+        panic!("TopK: axis {axis} is out of bounds for rank {rank}");
+    }
+
+    (k, axis, largest)
+}
+
+/// Get the axis and whether the sum is exclusive and reversed from a CumSum node.
+///
+/// The axis is an input which must be a constant.
+pub fn cum_sum_config(node: &Node) -> (usize, bool, bool) {
+    let rank = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.dim,
+        _ => panic!("CumSum: only tensor input is valid"),
+    };
+
+    let mut exclusive = false;
+    let mut reverse = false;
+
+    for (key, value) in node.attrs.iter() {
         match key.as_str() {
-            "start" => start_dim = value.clone().into_i64(),
-            "end" => end_dim = value.clone().into_i64(),
+            "exclusive" => exclusive = value.clone().into_i64() != 0,
+            "reverse" => reverse = value.clone().into_i64() != 0,
             _ => {}
         }
     }
 
-    // If dim is negative, it is counted from the end
-    if start_dim < 0 {
-        start_dim += tensor.dim as i64;
+    let arg = node
+        .inputs
+        .get(1)
+        .unwrap_or_else(|| panic!("CumSum: axis is required"));
+    let mut axis = match &arg.value {
+        Some(Data::Int64s(values)) if values.len() == 1 => values[0],
+        Some(Data::Int32s(values)) if values.len() == 1 => values[0] as i64,
+        Some(Data::Int64(value)) => *value,
+        Some(Data::Int32(value)) => *value as i64,
+        Some(data) => panic!("CumSum: axis must be a single integer, got {data:?}"),
+        None => panic!(
+            "CumSum: axis must be a constant, {} is a runtime input",
+            arg.name
+        ),
+    };
+
+    // Negative axis is counted from the last dimension
+    if axis < 0 {
+        axis += rank as i64;
     }
-    if end_dim < 0 {
-        end_dim += tensor.dim as i64;
+    let axis = axis as usize;
+
+    if axis >= rank {
+        panic!("CumSum: axis {axis} is out of bounds for rank {rank}");
     }
 
-    (start_dim as usize, end_dim as usize)
+    (axis, exclusive, reverse)
 }
 
-pub fn transpose_config(curr: &Node) -> Vec<i64> {
-    if curr.inputs.len() != 1 {
-        panic!(
-            "Transpose: multiple inputs are not supported (got {:?})",
-            curr.inputs.len()
-        );
+/// Get whether the upper triangular part of a Trilu node is kept, and its diagonal offset.
+///
+/// The offset is read from the optional `k` input, which must be a constant.
+pub fn trilu_config(node: &Node) -> (bool, i64) {
+    match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) if tensor.dim >= 2 => {}
+        ArgType::Tensor(tensor) => panic!(
+            "Trilu: input must have at least 2 dimensions, got {}",
+            tensor.dim
+        ),
+        _ => panic!("Trilu: only tensor input is valid"),
     }
 
-    // Extract the shape of the input tensor
-    let tensor = match curr.inputs.first().unwrap().clone().ty {
-        ArgType::Tensor(tensor) => tensor,
-        _ => panic!("Only tensor input is valid"),
+    let upper = node
+        .attrs
+        .get("upper")
+        .map(|value| value.clone().into_i64() != 0)
+        .unwrap_or(true);
+
+    // An omitted optional input has an empty name, unlike the initializers which have a value
+    let diagonal = match node
+        .inputs
+        .get(1)
+        .filter(|arg| !arg.name.is_empty() || arg.value.is_some())
+    {
+        Some(arg) => match &arg.value {
+            Some(Data::Int64s(values)) if values.len() == 1 => values[0],
+            Some(Data::Int32s(values)) if values.len() == 1 => values[0] as i64,
+            Some(Data::Int64(value)) => *value,
+            Some(Data::Int32(value)) => *value as i64,
+            Some(data) => panic!("Trilu: k must be a single integer, got {data:?}"),
+            None => panic!(
+                "Trilu: k must be a constant, {} is a runtime input",
+                arg.name
+            ),
+        },
+        None => 0,
     };
 
-    // Default: reverse the dimensions
-    let mut perm = (0..tensor.dim as i64).rev().collect::<Vec<i64>>();
+    (upper, diagonal)
+}
+
+/// Get whether the unique values of a Unique node are sorted.
+///
+/// Only the unique values of the flattened input are supported, i.e. without the `axis`
+/// attribute.
+pub fn unique_config(node: &Node) -> bool {
+    let mut sorted = true;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "axis" => panic!("Unique: the axis attribute is not supported"),
+            "sorted" => sorted = value.clone().into_i64() != 0,
+            _ => {}
+        }
+    }
+
+    sorted
+}
+
+/// Create the `(depth, [off, on], axis)` configuration of a OneHot node.
+///
+/// The depth and the values are inputs which must be constants, and the axis is counted in the
+/// output, i.e. an axis equal to the rank of the indices appends the new dimension.
+pub fn one_hot_config(node: &Node) -> (usize, [f64; 2], usize) {
+    if node.inputs.len() != 3 {
+        panic!("OneHot: indices, depth and values must be present");
+    }
+
+    let rank = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor)
+            if matches!(tensor.elem_type, ElementType::Int32 | ElementType::Int64) =>
+        {
+            tensor.dim
+        }
+        ty => panic!("OneHot: the indices must be an int tensor, got {ty:?}"),
+    };
 
-    if let Some(axes) = curr.attrs.get("perm") {
-        perm = axes.clone().into_i64s();
+    let depth = match &node.inputs[1].value {
+        Some(Data::Int64(depth)) => *depth as f64,
+        Some(Data::Int32(depth)) => *depth as f64,
+        Some(Data::Float32(depth)) => *depth as f64,
+        Some(Data::Float64(depth)) => *depth,
+        Some(Data::Int64s(depth)) if depth.len() == 1 => depth[0] as f64,
+        Some(Data::Int32s(depth)) if depth.len() == 1 => depth[0] as f64,
+        Some(Data::Float32s(depth)) if depth.len() == 1 => depth[0] as f64,
+        Some(data) => panic!("OneHot: depth must be a single number, got {data:?}"),
+        None => panic!(
+            "OneHot: depth must be a constant, {} is a runtime input",
+            node.inputs[1].name
+        ),
+    };
+    // A float depth is rounded down per ONNX spec
+    let depth = match depth.floor() {
+        depth if depth >= 1.0 => depth as usize,
+        _ => panic!("OneHot: depth must be positive, got {depth}"),
+    };
+
+    let values = match &node.inputs[2].value {
+        Some(Data::Float32s(values)) if values.len() == 2 => [values[0] as f64, values[1] as f64],
+        Some(Data::Float64s(values)) if values.len() == 2 => [values[0], values[1]],
+        Some(Data::Int64s(values)) if values.len() == 2 => [values[0] as f64, values[1] as f64],
+        Some(Data::Int32s(values)) if values.len() == 2 => [values[0] as f64, values[1] as f64],
+        Some(data) => panic!("OneHot: values must be the off and on values, got {data:?}"),
+        None => panic!(
+            "OneHot: values must be a constant, {} is a runtime input",
+            node.inputs[2].name
+        ),
+    };
+
+    // Default: -1 per ONNX spec
+    let axis = node
+        .attrs
+        .get("axis")
+        .map(|axis| axis.clone().into_i64())
+        .unwrap_or(-1);
+
+    // if axis is negative, it is counted from the end of the output
+    let normalized = if axis < 0 {
+        axis + rank as i64 + 1
+    } else {
+        axis
+    };
+
+    if normalized < 0 || normalized > rank as i64 {
+        panic!(
+            "OneHot: axis {axis} is out of range for an output of rank {}",
+            rank + 1
+        );
     }
 
-    perm
+    (depth, values, normalized as usize)
 }
 
-pub fn squeeze_config(curr: &Node) -> Vec<i64> {
-    let axes = curr
+/// Create the `(left, right, top, bottom)` padding and the mode from the attributes or inputs of
+/// the node
+pub fn pad_config(node: &Node) -> ([usize; 4], PadMode) {
+    let rank = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.dim,
+        _ => panic!("Pad: only tensor input is valid"),
+    };
+
+    if rank < 2 {
+        panic!("Pad: only tensors with a rank of at least 2 are supported, got rank {rank}");
+    }
+
+    // Before opset 11, the pads and the value are attributes
+    let mut pads = node.attrs.get("pads").map(|v| v.clone().into_i64s());
+    let mut value = node.attrs.get("value").map(|v| v.clone().into_f32() as f64);
+    let mode = node
         .attrs
-        .iter()
-        .filter_map(|(key, value)| {
-            if key == "axes" {
-                Some(value.clone().into_i64s())
-            } else {
-                None
-            }
+        .get("mode")
+        .map(|v| v.clone().into_string())
+        .unwrap_or_else(|| "constant".to_string());
+
+    // Since opset 11, they are inputs which must be constants
+    let input_value = |index: usize| {
+        node.inputs.get(index).and_then(|arg| match &arg.value {
+            Some(data) => Some(data.clone()),
+            None if arg.name.is_empty() => None,
+            None => panic!(
+                "Pad: inputs must be constants, {} is a runtime input",
+                arg.name
+            ),
         })
-        .next()
-        .unwrap_or_else(Vec::new);
+    };
 
-    match curr.inputs.first().unwrap().clone().ty {
-        ArgType::Tensor(tensor) => tensor,
-        _ => panic!("Only tensor input is valid"),
+    if let Some(data) = input_value(1) {
+        pads = Some(data.into_i64s());
+    }
+    if let Some(data) = input_value(2) {
+        value = Some(match data.into_scalar() {
+            Data::Float16(value) => f32::from(value) as f64,
+            Data::Float32(value) => value as f64,
+            Data::Float64(value) => value,
+            Data::Int32(value) => value as f64,
+            Data::Int64(value) => value as f64,
+            data => panic!("Pad: only numeric constant values are supported, got {data:?}"),
+        });
+    }
+    // Since opset 18, the pads can be given for a subset of the axes
+    let axes = input_value(3)
+        .map(|data| data.into_i64s())
+        .unwrap_or_else(|| (0..rank as i64).collect());
+
+    let pads = pads.expect("Pad: pads must be present");
+
+    if pads.len() != axes.len() * 2 {
+        panic!(
+            "Pad: expected {} pads for {} axes, got {}",
+            axes.len() * 2,
+            axes.len(),
+            pads.len()
+        );
+    }
+
+    if pads.iter().any(|&pad| pad < 0) {
+        panic!("Pad: negative pads (cropping) are not supported, got {pads:?}");
+    }
+
+    // The pads list the beginning of all the axes, then their end
+    let (begins, ends) = pads.split_at(axes.len());
+    let mut padding = [0; 4];
+
+    for ((&begin, &end), &axis) in begins.iter().zip(ends).zip(axes.iter()) {
+        // Negative axes are counted from the last dimension
+        let axis = if axis < 0 { axis + rank as i64 } else { axis } as usize;
+
+        if axis >= rank {
+            panic!("Pad: axis {axis} is out of bounds for rank {rank}");
+        }
+
+        let (begin, end) = (begin as usize, end as usize);
+
+        // Burn pads the last two dimensions with `(left, right, top, bottom)`
+        match rank - axis {
+            1 => (padding[0], padding[1]) = (begin, end),
+            2 => (padding[2], padding[3]) = (begin, end),
+            _ if begin == 0 && end == 0 => {}
+            _ => panic!("Pad: only the last two dimensions can be padded, got pads {pads:?}"),
+        }
+    }
+
+    let mode = match mode.as_str() {
+        "constant" => PadMode::Constant(value.unwrap_or(0.0)),
+        "reflect" => PadMode::Reflect,
+        "edge" => PadMode::Edge,
+        mode => panic!("Pad: {mode} mode is not supported"),
     };
 
-    axes
+    (padding, mode)
 }
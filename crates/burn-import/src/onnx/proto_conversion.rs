@@ -5,15 +5,19 @@ use crate::onnx::ir::TensorType;
 use super::from_onnx::OnnxGraphIO;
 use super::ir::Dim;
 use super::ir::{
-    ArgType, Argument, AttributeValue, Attributes, Data, ElementType, Node, NodeType, Tensor,
+    ArgType, Argument, AttributeValue, Attributes, Data, ElementType, Node, NodeType, OnnxGraph,
+    Tensor,
 };
 use super::protos::{
-    attribute_proto::AttributeType, tensor_proto::DataType, tensor_shape_proto::dimension::Value,
-    type_proto, AttributeProto, NodeProto, TensorProto, TensorShapeProto, ValueInfoProto,
+    attribute_proto::AttributeType, tensor_proto::DataType, tensor_shape_proto,
+    tensor_shape_proto::dimension::Value, type_proto, AttributeProto, GraphProto, ModelProto,
+    NodeProto, OperatorSetIdProto, SparseTensorProto, TensorProto, TensorShapeProto, TypeProto,
+    ValueInfoProto,
 };
 
 use bytemuck::cast_slice;
-use protobuf::Enum;
+use half::f16;
+use protobuf::{Enum, MessageField};
 
 /// Error type for parsing ONNX model
 #[derive(Debug)]
@@ -35,6 +39,27 @@ impl TryFrom<TensorProto> for Tensor {
                     Data::Float32s(tensor.float_data)
                 },
             ),
+            DataType::FLOAT16 => (
+                ElementType::Float16,
+                // The values are stored as their bits
+                if !tensor.raw_data.is_empty() {
+                    Data::Float16s(
+                        tensor
+                            .raw_data
+                            .chunks_exact(2)
+                            .map(|bits| f16::from_le_bytes([bits[0], bits[1]]))
+                            .collect(),
+                    )
+                } else {
+                    Data::Float16s(
+                        tensor
+                            .int32_data
+                            .iter()
+                            .map(|bits| f16::from_bits(*bits as u16))
+                            .collect(),
+                    )
+                },
+            ),
             DataType::INT16 => {
                 // TODO : Add support for int16 by converting to int32
                 todo!("Add support for int16");
@@ -86,6 +111,72 @@ impl TryFrom<TensorProto> for Tensor {
     }
 }
 
+/// Convert a sparse tensor to a dense tensor, the missing values being zeros
+impl TryFrom<SparseTensorProto> for Tensor {
+    type Error = ParseError;
+    fn try_from(sparse: SparseTensorProto) -> Result<Tensor, Self::Error> {
+        let values = Tensor::try_from(sparse.values.unwrap())?;
+        let indices = Tensor::try_from(sparse.indices.unwrap())?;
+        let shape = convert_shape(sparse.dims);
+        let numel = shape.iter().product::<usize>();
+
+        let index_values = match indices.data {
+            Some(Data::Int64s(values)) => values,
+            _ => panic!("Sparse tensor indices must be int64"),
+        };
+
+        // The indices are either linearized, or tuples of the indices along each dimension
+        let positions: Vec<usize> = match indices.shape.as_deref() {
+            Some([_]) => index_values.iter().map(|index| *index as usize).collect(),
+            Some([_, rank]) if *rank == shape.len() => index_values
+                .chunks(*rank)
+                .map(|index| {
+                    index
+                        .iter()
+                        .zip(&shape)
+                        .fold(0, |position, (index, dim)| position * dim + *index as usize)
+                })
+                .collect(),
+            indices_shape => panic!(
+                "Invalid shape of the sparse tensor indices: {:?}",
+                indices_shape
+            ),
+        };
+
+        if let Some(position) = positions.iter().find(|position| **position >= numel) {
+            panic!(
+                "Sparse tensor index {} is out of bounds for the shape {:?}",
+                position, shape
+            );
+        }
+
+        fn densify<T: Copy + Default>(values: Vec<T>, positions: &[usize], numel: usize) -> Vec<T> {
+            let mut dense = vec![T::default(); numel];
+            for (value, position) in values.into_iter().zip(positions) {
+                dense[*position] = value;
+            }
+            dense
+        }
+
+        let data = match values.data.unwrap() {
+            Data::Float16s(values) => Data::Float16s(densify(values, &positions, numel)),
+            Data::Float32s(values) => Data::Float32s(densify(values, &positions, numel)),
+            Data::Float64s(values) => Data::Float64s(densify(values, &positions, numel)),
+            Data::Int32s(values) => Data::Int32s(densify(values, &positions, numel)),
+            Data::Int64s(values) => Data::Int64s(densify(values, &positions, numel)),
+            Data::Bools(values) => Data::Bools(densify(values, &positions, numel)),
+            _ => return Err(ParseError::VariantNotFound),
+        };
+
+        Ok(Tensor {
+            elem_type: values.elem_type,
+            dim: shape.len(),
+            shape: Some(shape),
+            data: Some(data),
+        })
+    }
+}
+
 impl TryFrom<TensorShapeProto> for Vec<usize> {
     type Error = ParseError;
     fn try_from(shape: TensorShapeProto) -> Result<Vec<usize>, Self::Error> {
@@ -161,7 +252,10 @@ impl TryFrom<AttributeProto> for AttributeValue {
             }
             // AttributeType::GRAPHS => AttributeValue::Graphs(attr.graphs),
             // AttributeType::SPARSE_TENSORS => AttributeValue::SparseTensors(attr.sparse_tensors),
-            // AttributeType::SPARSE_TENSOR => AttributeValue::SparseTensor(attr.sparse_tensor),
+            // Sparse tensors are densified, e.g. the value of Constant nodes
+            AttributeType::SPARSE_TENSOR => {
+                AttributeValue::Tensor(Tensor::try_from(attr.sparse_tensor.unwrap())?)
+            }
             _ => {
                 return Err(ParseError::VariantNotFound);
             }
@@ -180,7 +274,7 @@ pub fn convert_vec_attrs_proto(attrs: Vec<AttributeProto>) -> Attributes {
     result
 }
 
-pub fn convert_node_proto(node: &NodeProto, graph_io: &OnnxGraphIO) -> Node {
+pub fn convert_node_proto(node: &NodeProto, graph_io: &OnnxGraphIO, opset_version: usize) -> Node {
     let name = node.name.clone();
 
     log::debug!("Converting ONNX node with type {:?}", node.op_type.as_str());
@@ -204,6 +298,7 @@ pub fn convert_node_proto(node: &NodeProto, graph_io: &OnnxGraphIO) -> Node {
         inputs,
         outputs,
         attrs,
+        opset_version,
     }
 }
 
@@ -266,3 +361,189 @@ impl TryFrom<ValueInfoProto> for Argument {
         })
     }
 }
+
+/// Get the ONNX data type of an element type.
+fn data_type(elem_type: &ElementType) -> DataType {
+    match elem_type {
+        ElementType::Float32 => DataType::FLOAT,
+        ElementType::Float64 => DataType::DOUBLE,
+        ElementType::Float16 => DataType::FLOAT16,
+        ElementType::Int32 => DataType::INT32,
+        ElementType::Int64 => DataType::INT64,
+        ElementType::Bool => DataType::BOOL,
+        ElementType::String => DataType::STRING,
+    }
+}
+
+/// Convert an argument with a value into an initializer
+impl From<&Argument> for TensorProto {
+    fn from(arg: &Argument) -> TensorProto {
+        let mut tensor = TensorProto::new();
+        tensor.name = arg.name.clone();
+
+        if let ArgType::Tensor(tensor_type) = &arg.ty {
+            tensor.dims = tensor_type
+                .shape
+                .iter()
+                .flatten()
+                .map(|dim| *dim as i64)
+                .collect();
+        }
+
+        match &arg.value {
+            Some(Data::Float32s(values)) => {
+                tensor.data_type = DataType::FLOAT.value();
+                tensor.float_data.clone_from(values);
+            }
+            Some(Data::Float64s(values)) => {
+                tensor.data_type = DataType::DOUBLE.value();
+                tensor.double_data.clone_from(values);
+            }
+            Some(Data::Int32s(values)) => {
+                tensor.data_type = DataType::INT32.value();
+                tensor.int32_data.clone_from(values);
+            }
+            Some(Data::Int64s(values)) => {
+                tensor.data_type = DataType::INT64.value();
+                tensor.int64_data.clone_from(values);
+            }
+            value => panic!("Unsupported initializer value for {}: {value:?}", arg.name),
+        }
+
+        tensor
+    }
+}
+
+/// Convert a tensor argument into its value info
+impl From<&Argument> for ValueInfoProto {
+    fn from(arg: &Argument) -> ValueInfoProto {
+        let tensor_type = match &arg.ty {
+            ArgType::Tensor(tensor_type) => tensor_type,
+            ty => panic!("Only tensor arguments are supported, got {ty:?}"),
+        };
+
+        let mut shape = TensorShapeProto::new();
+        shape.dim = tensor_type
+            .shape
+            .iter()
+            .flatten()
+            .map(|dim| {
+                let mut dimension = tensor_shape_proto::Dimension::new();
+                dimension.set_dim_value(*dim as i64);
+                dimension
+            })
+            .collect();
+
+        let mut tensor = type_proto::Tensor::new();
+        tensor.elem_type = data_type(&tensor_type.elem_type).value();
+        tensor.shape = MessageField::some(shape);
+
+        let mut ty = TypeProto::new();
+        ty.set_tensor_type(tensor);
+
+        let mut value_info = ValueInfoProto::new();
+        value_info.name = arg.name.clone();
+        value_info.type_ = MessageField::some(ty);
+
+        value_info
+    }
+}
+
+/// Convert an attribute into its AttributeProto
+fn convert_attr(name: &str, value: &AttributeValue) -> AttributeProto {
+    let mut attr = AttributeProto::new();
+    attr.name = name.to_string();
+
+    let attr_type = match value {
+        AttributeValue::Float32(value) => {
+            attr.f = *value;
+            AttributeType::FLOAT
+        }
+        AttributeValue::Int64(value) => {
+            attr.i = *value;
+            AttributeType::INT
+        }
+        AttributeValue::String(value) => {
+            attr.s = value.as_bytes().to_vec();
+            AttributeType::STRING
+        }
+        AttributeValue::Float32s(values) => {
+            attr.floats.clone_from(values);
+            AttributeType::FLOATS
+        }
+        AttributeValue::Int64s(values) => {
+            attr.ints.clone_from(values);
+            AttributeType::INTS
+        }
+        AttributeValue::Strings(values) => {
+            attr.strings = values
+                .iter()
+                .map(|value| value.as_bytes().to_vec())
+                .collect();
+            AttributeType::STRINGS
+        }
+        value => panic!("Unsupported attribute {name}: {value:?}"),
+    };
+    attr.type_ = attr_type.into();
+
+    attr
+}
+
+/// Convert a node into its NodeProto, mapping the node types specific to Burn back to their
+/// ONNX operator
+pub fn convert_node(node: &Node) -> NodeProto {
+    let op_type = match node.node_type {
+        NodeType::Conv1d | NodeType::Conv2d => "Conv".to_string(),
+        NodeType::ConvTranspose1d | NodeType::ConvTranspose2d => "ConvTranspose".to_string(),
+        NodeType::MaxPool1d | NodeType::MaxPool2d => "MaxPool".to_string(),
+        NodeType::AveragePool1d | NodeType::AveragePool2d => "AveragePool".to_string(),
+        NodeType::Linear => panic!("Linear nodes must be exported as Gemm or MatMul nodes"),
+        ref node_type => node_type.to_string(),
+    };
+
+    let mut node_proto = NodeProto::new();
+    node_proto.name = node.name.clone();
+    node_proto.op_type = op_type;
+    node_proto.input = node.inputs.iter().map(|arg| arg.name.clone()).collect();
+    node_proto.output = node.outputs.iter().map(|arg| arg.name.clone()).collect();
+
+    // Sorted for a deterministic output
+    let mut attrs = node.attrs.iter().collect::<Vec<_>>();
+    attrs.sort_by_key(|(name, _)| *name);
+    node_proto.attribute = attrs
+        .into_iter()
+        .map(|(name, value)| convert_attr(name, value))
+        .collect();
+
+    node_proto
+}
+
+/// Convert a graph into a ModelProto importing the given version of the default operator set.
+///
+/// The inputs of the nodes with a value are written as initializers.
+pub fn convert_graph(graph: &OnnxGraph, opset_version: usize) -> ModelProto {
+    let mut graph_proto = GraphProto::new();
+    graph_proto.name = "main_graph".to_string();
+    graph_proto.node = graph.nodes.iter().map(convert_node).collect();
+    graph_proto.initializer = graph
+        .nodes
+        .iter()
+        .flat_map(|node| node.inputs.iter())
+        .filter(|arg| arg.value.is_some())
+        .map(TensorProto::from)
+        .collect();
+    graph_proto.input = graph.inputs.iter().map(ValueInfoProto::from).collect();
+    graph_proto.output = graph.outputs.iter().map(ValueInfoProto::from).collect();
+
+    let mut opset = OperatorSetIdProto::new();
+    opset.version = opset_version as i64;
+
+    let mut model = ModelProto::new();
+    model.ir_version = 8;
+    model.producer_name = "burn".to_string();
+    model.producer_version = env!("CARGO_PKG_VERSION").to_string();
+    model.graph = MessageField::some(graph_proto);
+    model.opset_import = vec![opset];
+
+    model
+}
@@ -5,11 +5,12 @@ use std::{
 };
 
 use burn::{
-    nn::PReluConfig,
+    nn::GroupNormConfig,
     record::{FullPrecisionSettings, HalfPrecisionSettings, PrecisionSettings},
-    tensor::{DataSerialize, Element},
+    tensor::{DataSerialize, Distribution, Element},
 };
-use log::warn;
+use proc_macro2::TokenStream;
+use rand::{rngs::StdRng, SeedableRng};
 
 use crate::{
     burn::{
@@ -19,32 +20,52 @@ use crate::{
             avg_pool1d::AvgPool1dNode,
             avg_pool2d::AvgPool2dNode,
             batch_norm::BatchNormNode,
-            binary::BinaryNode,
+            binary::{BinaryNode, BitShiftDirection},
             clip::ClipNode,
             concat::ConcatNode,
             constant::{ConstantNode, ConstantValue, TensorValue},
             conv1d::Conv1dNode,
             conv2d::Conv2dNode,
             conv_transpose_2d::ConvTranspose2dNode,
+            cum_sum::CumSumNode,
+            depth_to_space::DepthToSpaceNode,
             dropout::DropoutNode,
+            einsum::EinsumNode,
             expand::ExpandNode,
-            gather::GatherNode,
+            gather::{GatherIndices, GatherNode},
             gather_elements::GatherElementsNode,
+            gather_nd::GatherNDNode,
+            gemm::GemmNode,
             global_avg_pool::GlobalAvgPoolNode,
+            global_max_pool::GlobalMaxPoolNode,
+            group_norm::GroupNormNode,
+            gru::{GruGatesData, GruNode},
             layer_norm::LayerNormNode,
             linear::LinearNode,
+            lstm::{LstmGateData, LstmGatesData, LstmNode},
             mask_where::WhereNode,
             matmul::MatmulNode,
             max_pool1d::MaxPool1dNode,
             max_pool2d::MaxPool2dNode,
-            prelu::PReluNode,
+            one_hot::OneHotNode,
+            pad::{PadMode, PadNode},
+            prelu::{PReluNode, PReluSlope},
             random_normal::RandomNormalNode,
             random_uniform::RandomUniformNode,
-            range::RangeNode,
+            range::{RangeNode, RangeValue},
             reshape::ReshapeNode,
+            resize::ResizeNode,
+            scatter_elements::ScatterElementsNode,
+            scatter_nd::ScatterNDNode,
+            slice::SliceNode,
+            space_to_depth::SpaceToDepthNode,
+            split::SplitNode,
             squeeze::SqueezeNode,
             sum::SumNode,
+            top_k::TopKNode,
+            trilu::TriluNode,
             unary::UnaryNode,
+            unique::UniqueNode,
             unsqueeze::UnsqueezeNode,
         },
         ScalarKind, ScalarType, TensorKind, TensorType, Type,
@@ -79,6 +100,7 @@ pub struct ModelGen {
     half_precision: bool,
     record_type: RecordType,
     embed_states: bool,
+    dropout_modules: bool,
 }
 
 impl ModelGen {
@@ -112,15 +134,21 @@ impl ModelGen {
     /// Run code generation.
     ///
     /// This function is intended to be called from `build.rs` script.
+    ///
+    /// # Panics
+    ///
+    /// If a model can't be generated, failing the build with the error.
     pub fn run_from_script(&self) {
-        self.run(true);
+        self.run(true).unwrap_or_else(|err| panic!("{err}"));
     }
 
     /// Run code generation.
     ///
     /// This function is intended to be called from CLI.
-    pub fn run_from_cli(&self) {
-        self.run(false);
+    ///
+    /// Returns an error if a model can't be generated.
+    pub fn run_from_cli(&self) -> Result<(), Box<dyn std::error::Error>> {
+        self.run(false)
     }
 
     /// Specify parameter precision to be saved.
@@ -148,14 +176,28 @@ impl ModelGen {
     /// # Arguments
     ///
     /// * `embed_states` - If true, states are embedded in the generated code. Otherwise, states are
-    /// saved as a separate file.
+    ///   saved as a separate file.
     pub fn embed_states(&mut self, embed_states: bool) -> &mut Self {
         self.embed_states = embed_states;
         self
     }
 
+    /// Specify whether to import Dropout nodes as Burn dropout modules.
+    ///
+    /// By default, the model is imported for inference and Dropout nodes forward their input as
+    /// is.
+    ///
+    /// # Arguments
+    ///
+    /// * `dropout_modules` - If true, Dropout nodes are imported as [Dropout](burn::nn::Dropout)
+    ///   modules with the ratio of the node. Otherwise, they are imported as identities.
+    pub fn dropout_modules(&mut self, dropout_modules: bool) -> &mut Self {
+        self.dropout_modules = dropout_modules;
+        self
+    }
+
     /// Run code generation.
-    fn run(&self, is_build_script: bool) {
+    fn run(&self, is_build_script: bool) -> Result<(), Box<dyn std::error::Error>> {
         log::info!("Starting to convert ONNX to Burn");
 
         // prepend the out_dir to the cargo_out_dir if this is a build script
@@ -172,7 +214,7 @@ impl ModelGen {
 
         log::debug!("Output directory: {:?}", out_dir);
 
-        create_dir_all(&out_dir).unwrap();
+        create_dir_all(&out_dir)?;
 
         for input in self.inputs.iter() {
             let file_name = input.file_stem().unwrap();
@@ -182,14 +224,20 @@ impl ModelGen {
             log::debug!("Input file name: {:?}", file_name);
             log::debug!("Output file: {:?}", out_file);
 
-            self.generate_model(input, out_file);
+            self.generate_model(input, out_file)?;
         }
 
         log::info!("Finished converting ONNX to Burn");
+
+        Ok(())
     }
 
     /// Generate model source code and model state.
-    fn generate_model(&self, input: &PathBuf, out_file: PathBuf) {
+    fn generate_model(
+        &self,
+        input: &PathBuf,
+        out_file: PathBuf,
+    ) -> Result<(), Box<dyn std::error::Error>> {
         log::info!("Generating model from {:?}", input);
         log::debug!("Development mode: {:?}", self.development);
         log::debug!("Output file: {:?}", out_file);
@@ -201,123 +249,138 @@ impl ModelGen {
             let debug_graph = format!("{:#?}", graph);
             let graph_file = out_file.with_extension("graph.txt");
             log::debug!("Writing debug graph file: {:?}", graph_file);
-            fs::write(graph_file, debug_graph).unwrap();
+            fs::write(graph_file, debug_graph)?;
         }
 
-        let blank_space = true;
         let top_comment = Some(format!("Generated from ONNX {input:?} by burn-import"));
 
         let code = if self.half_precision {
-            graph
-                .into_burn::<HalfPrecisionSettings>()
-                .with_record(out_file.clone(), self.record_type, self.embed_states)
-                .with_blank_space(blank_space)
-                .with_top_comment(top_comment)
-                .codegen()
+            self.codegen::<HalfPrecisionSettings>(graph, &out_file, top_comment)
         } else {
-            graph
-                .into_burn::<FullPrecisionSettings>()
-                .with_record(out_file.clone(), self.record_type, self.embed_states)
-                .with_blank_space(blank_space)
-                .with_top_comment(top_comment)
-                .codegen()
+            self.codegen::<FullPrecisionSettings>(graph, &out_file, top_comment)
         };
+        let code =
+            code.map_err(|err| format!("Unable to generate the model from {input:?}: {err}"))?;
 
         let code_str = format_tokens(code);
-        fs::write(out_file.with_extension("rs"), code_str).unwrap();
+        fs::write(out_file.with_extension("rs"), code_str)?;
 
         log::info!("Model generated");
+
+        Ok(())
+    }
+
+    /// Convert the graph with the given precision and generate its source code.
+    fn codegen<PS: PrecisionSettings + 'static>(
+        &self,
+        graph: OnnxGraph,
+        out_file: &Path,
+        top_comment: Option<String>,
+    ) -> Result<TokenStream, Box<dyn std::error::Error>> {
+        let code = graph
+            .convert::<PS>(self.dropout_modules)?
+            .with_record(out_file.to_path_buf(), self.record_type, self.embed_states)
+            .with_blank_space(true)
+            .with_top_comment(top_comment)
+            .try_codegen()?;
+
+        Ok(code)
+    }
+}
+
+/// Error returned when an [ONNX graph](OnnxGraph) contains operators that can't be imported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsupportedOpsError {
+    /// The unsupported operators, in the order they first appear in the graph, with the names of
+    /// the nodes using them.
+    pub ops: Vec<(String, Vec<String>)>,
+}
+
+impl core::fmt::Display for UnsupportedOpsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Unsupported ONNX operators:")?;
+
+        for (index, (op, nodes)) in self.ops.iter().enumerate() {
+            let separator = if index == 0 { " " } else { ", " };
+            write!(f, "{separator}{op} (used by {})", nodes.join(", "))?;
+        }
+
+        Ok(())
     }
 }
 
+impl std::error::Error for UnsupportedOpsError {}
+
 impl OnnxGraph {
     /// Converts ONNX graph to Burn graph.
     pub fn into_burn<PS: PrecisionSettings + 'static>(self) -> BurnGraph<PS> {
+        self.try_into_burn()
+            .unwrap_or_else(|err| panic!("Unable to convert the ONNX graph: {err}"))
+    }
+
+    /// Converts ONNX graph to Burn graph.
+    ///
+    /// All the nodes are checked before converting any of them, so the error lists every
+    /// unsupported operator of the graph. Dropout nodes are imported as identities, the model
+    /// being imported for inference.
+    pub fn try_into_burn<PS: PrecisionSettings + 'static>(
+        self,
+    ) -> Result<BurnGraph<PS>, UnsupportedOpsError> {
+        self.convert(false)
+    }
+
+    /// Converts ONNX graph to Burn graph, importing Dropout nodes as dropout modules or as
+    /// identities.
+    fn convert<PS: PrecisionSettings + 'static>(
+        self,
+        dropout_modules: bool,
+    ) -> Result<BurnGraph<PS>, UnsupportedOpsError> {
         let mut graph = BurnGraph::<PS>::default();
 
-        let mut unsupported_ops = vec![];
-
-        for node in self.nodes {
-            match node.node_type {
-                NodeType::Add => graph.register(Self::add_conversion(node)),
-                NodeType::ArgMax => graph.register(Self::argmax_conversion(node)),
-                NodeType::Sub => graph.register(Self::sub_conversion(node)),
-                NodeType::Mul => graph.register(Self::mul_conversion(node)),
-                NodeType::Div => graph.register(Self::div_conversion(node)),
-                NodeType::Equal => graph.register(Self::equal_conversion(node)),
-                NodeType::Erf => graph.register(Self::erf_conversion(node)),
-                NodeType::Exp => graph.register(Self::exp_conversion(node)),
-                NodeType::Expand => graph.register(Self::expand_conversion(node)),
-                NodeType::Clip => graph.register(Self::clip_conversion(node)),
-                NodeType::Cos => graph.register(Self::cos_conversion(node)),
-                NodeType::Conv1d => graph.register(Self::conv1d_conversion::<PS>(node)),
-                NodeType::Conv2d => graph.register(Self::conv2d_conversion::<PS>(node)),
-                NodeType::Max => graph.register(Self::max_conversion(node)),
-                NodeType::MaxPool1d => graph.register(Self::max_pool1d_conversion(node)),
-                NodeType::MaxPool2d => graph.register(Self::max_pool2d_conversion(node)),
-                NodeType::PRelu => graph.register(Self::prelu_conversion::<PS>(node)),
-                NodeType::AveragePool1d => graph.register(Self::avg_pool_1d_conversion(node)),
-                NodeType::AveragePool2d => graph.register(Self::avg_pool_2d_conversion(node)),
-                NodeType::MatMul => graph.register(Self::matmul_conversion(node)),
-                NodeType::Neg => graph.register(Self::neg_conversion(node)),
-                NodeType::Not => graph.register(Self::not_conversion(node)),
-                NodeType::Greater => graph.register(Self::greater_conversion(node)),
-                NodeType::GreaterOrEqual => graph.register(Self::greater_or_equal_conversion(node)),
-                NodeType::Less => graph.register(Self::less_conversion(node)),
-                NodeType::LessOrEqual => graph.register(Self::less_or_equal_conversion(node)),
-                NodeType::LayerNormalization => {
-                    graph.register(Self::layer_norm_conversion::<PS>(node))
-                }
-                NodeType::Linear => graph.register(Self::linear_conversion::<PS>(node)),
-                NodeType::BatchNormalization => {
-                    graph.register(Self::batch_norm_conversion::<PS>(node))
-                }
-                NodeType::Relu => graph.register(Self::relu_conversion(node)),
-                NodeType::Gelu => graph.register(Self::gelu_conversion(node)),
-                NodeType::Flatten => graph.register(Self::flatten_conversion(node)),
-                NodeType::Gather => graph.register(Self::gather_conversion(node)),
-                NodeType::GatherElements => graph.register(Self::gather_elements_conversion(node)),
-                NodeType::Log => graph.register(Self::log_conversion(node)),
-                NodeType::LeakyRelu => graph.register(Self::leaky_relu_conversion(node)),
-                NodeType::LogSoftmax => graph.register(Self::log_softmax_conversion(node)),
-                NodeType::Softmax => graph.register(Self::softmax_conversion(node)),
-                NodeType::Sqrt => graph.register(Self::sqrt_conversion(node)),
-                NodeType::Tanh => graph.register(Self::tanh_conversion(node)),
-                NodeType::Constant => graph.register(Self::constant_conversion::<PS>(node)),
-                NodeType::Min => graph.register(Self::min_conversion(node)),
-                NodeType::Range => graph.register(Self::range_conversion(node)),
-                NodeType::ReduceMax => graph.register(Self::reduce_max_conversion(node)),
-                NodeType::ReduceMean => graph.register(Self::reduce_mean_conversion(node)),
-                NodeType::ReduceSum => graph.register(Self::reduce_sum_conversion(node)),
-                NodeType::Reshape => graph.register(Self::reshape_conversion(node)),
-                NodeType::Reciprocal => graph.register(Self::reciprocal_conversion(node)),
-                NodeType::Shape => graph.register(Self::shape_conversion(node)),
-                NodeType::Sigmoid => graph.register(Self::sigmoid_conversion(node)),
-                NodeType::Sin => graph.register(Self::sin_conversion(node)),
-                NodeType::Sum => graph.register(Self::sum_conversion(node)),
-                NodeType::Transpose => graph.register(Self::transpose_conversion(node)),
-                NodeType::Concat => graph.register(Self::concat_conversion(node)),
-                NodeType::Cast => graph.register(Self::cast_conversion(node)),
-                NodeType::Dropout => graph.register(Self::dropout_conversion(node)),
-                NodeType::GlobalAveragePool => {
-                    graph.register(Self::global_avg_pool_conversion(node))
-                }
-                NodeType::ConvTranspose2d => {
-                    graph.register(Self::conv_transpose2d_conversion(node))
-                }
-                NodeType::Pow => graph.register(Self::pow_conversion(node)),
-                NodeType::Unsqueeze => graph.register(Self::unsqueeze_conversion(node)),
-                NodeType::Where => graph.register(Self::where_conversion(node)),
-                NodeType::Sign => graph.register(Self::sign_conversion(node)),
-                NodeType::Squeeze => graph.register(Self::squeeze_conversion(node)),
-                NodeType::RandomUniform => graph.register(Self::random_uniform_conversion(node)),
-                NodeType::RandomNormal => graph.register(Self::random_normal_conversion(node)),
-                node_type => unsupported_ops.push(node_type),
+        let conversions = self
+            .nodes
+            .iter()
+            .map(|node| Self::node_conversion::<PS>(&node.node_type, dropout_modules))
+            .collect::<Vec<_>>();
+
+        let mut unsupported_ops: Vec<(String, Vec<String>)> = vec![];
+        for (node, _) in self
+            .nodes
+            .iter()
+            .zip(conversions.iter())
+            .filter(|(_, conversion)| conversion.is_none())
+        {
+            let op = node.node_type.to_string();
+            match unsupported_ops.iter_mut().find(|(name, _)| *name == op) {
+                Some((_, nodes)) => nodes.push(node.name.clone()),
+                None => unsupported_ops.push((op, vec![node.name.clone()])),
             }
         }
 
+        // Neither the identity nor the dropout module outputs the mask of the dropped values.
+        let masks_used = self
+            .nodes
+            .iter()
+            .filter(|node| node.node_type == NodeType::Dropout && self.is_mask_used(node))
+            .map(|node| node.name.clone())
+            .collect::<Vec<_>>();
+        if !masks_used.is_empty() {
+            unsupported_ops.push(("Dropout mask output".to_string(), masks_used));
+        }
+
         if !unsupported_ops.is_empty() {
-            panic!("Unsupported ops: {:?}", unsupported_ops);
+            return Err(UnsupportedOpsError {
+                ops: unsupported_ops,
+            });
+        }
+
+        // Every node has a conversion at this point.
+        for (node, conversion) in self
+            .nodes
+            .into_iter()
+            .zip(conversions.into_iter().flatten())
+        {
+            conversion(&mut graph, node);
         }
 
         // Get input and output names
@@ -335,7 +398,249 @@ impl OnnxGraph {
         // Register inputs and outputs with the graph
         graph.register_input_output(input_names, output_names);
 
-        graph
+        Ok(graph)
+    }
+
+    /// Whether the mask output of the Dropout node is used by another node or is a graph output.
+    fn is_mask_used(&self, node: &Node) -> bool {
+        let mask = match node.outputs.get(1) {
+            Some(mask) if !mask.name.is_empty() => &mask.name,
+            _ => return false,
+        };
+
+        self.nodes
+            .iter()
+            .flat_map(|node| node.inputs.iter())
+            .chain(self.outputs.iter())
+            .any(|arg| arg.name == *mask)
+    }
+
+    /// The function registering the conversion of a node of the given type into the graph,
+    /// `None` if the node type isn't supported.
+    fn node_conversion<PS: PrecisionSettings + 'static>(
+        node_type: &NodeType,
+        dropout_modules: bool,
+    ) -> Option<fn(&mut BurnGraph<PS>, Node)> {
+        let conversion: fn(&mut BurnGraph<PS>, Node) = match node_type {
+            NodeType::Add => |graph, node| graph.register(Self::add_conversion(node)),
+            NodeType::ArgMax => |graph, node| graph.register(Self::argmax_conversion(node)),
+            NodeType::Sub => |graph, node| graph.register(Self::sub_conversion(node)),
+            NodeType::Mul => |graph, node| graph.register(Self::mul_conversion(node)),
+            NodeType::Div => |graph, node| graph.register(Self::div_conversion(node)),
+            NodeType::Equal => |graph, node| graph.register(Self::equal_conversion(node)),
+            NodeType::BitwiseAnd => |graph, node| {
+                graph.register(Self::bitwise_conversion(node, BinaryNode::bitwise_and))
+            },
+            NodeType::BitwiseOr => {
+                |graph, node| graph.register(Self::bitwise_conversion(node, BinaryNode::bitwise_or))
+            }
+            NodeType::BitwiseXor => |graph, node| {
+                graph.register(Self::bitwise_conversion(node, BinaryNode::bitwise_xor))
+            },
+            NodeType::BitShift => |graph, node| graph.register(Self::bit_shift_conversion(node)),
+            NodeType::Erf => {
+                |graph, node| graph.register(Self::unary_conversion(node, UnaryNode::erf))
+            }
+            NodeType::Exp => {
+                |graph, node| graph.register(Self::unary_conversion(node, UnaryNode::exp))
+            }
+            NodeType::Expand => |graph, node| graph.register(Self::expand_conversion(node)),
+            NodeType::Clip => |graph, node| graph.register(Self::clip_conversion(node)),
+            NodeType::Cos => |graph, node| graph.register(Self::cos_conversion(node)),
+            NodeType::Conv1d => |graph, node| {
+                let (pad, conv) = Self::conv1d_conversion::<PS>(node);
+                if let Some(pad) = pad {
+                    graph.register(pad);
+                }
+                graph.register(conv)
+            },
+            NodeType::Conv2d => |graph, node| {
+                let (pad, conv) = Self::conv2d_conversion::<PS>(node);
+                if let Some(pad) = pad {
+                    graph.register(pad);
+                }
+                graph.register(conv)
+            },
+            NodeType::Max => |graph, node| graph.register(Self::max_conversion(node)),
+            NodeType::MaxPool1d => |graph, node| graph.register(Self::max_pool1d_conversion(node)),
+            NodeType::MaxPool2d => |graph, node| graph.register(Self::max_pool2d_conversion(node)),
+            NodeType::Pad => |graph, node| graph.register(Self::pad_conversion(node)),
+            NodeType::DepthToSpace => {
+                |graph, node| graph.register(Self::depth_to_space_conversion(node))
+            }
+            NodeType::SpaceToDepth => {
+                |graph, node| graph.register(Self::space_to_depth_conversion(node))
+            }
+            NodeType::PRelu => |graph, node| graph.register(Self::prelu_conversion::<PS>(node)),
+            NodeType::AveragePool1d => {
+                |graph, node| graph.register(Self::avg_pool_1d_conversion(node))
+            }
+            NodeType::AveragePool2d => {
+                |graph, node| graph.register(Self::avg_pool_2d_conversion(node))
+            }
+            NodeType::MatMul => |graph, node| graph.register(Self::matmul_conversion(node)),
+            NodeType::Gemm => |graph, node| graph.register(Self::gemm_conversion(node)),
+            NodeType::Neg => {
+                |graph, node| graph.register(Self::unary_conversion(node, UnaryNode::neg))
+            }
+            NodeType::Not => |graph, node| graph.register(Self::not_conversion(node)),
+            NodeType::NonZero => |graph, node| graph.register(Self::nonzero_conversion(node)),
+            NodeType::Greater => |graph, node| graph.register(Self::greater_conversion(node)),
+            NodeType::GreaterOrEqual => {
+                |graph, node| graph.register(Self::greater_or_equal_conversion(node))
+            }
+            NodeType::Less => |graph, node| graph.register(Self::less_conversion(node)),
+            NodeType::LessOrEqual => {
+                |graph, node| graph.register(Self::less_or_equal_conversion(node))
+            }
+            NodeType::LayerNormalization => {
+                |graph, node| graph.register(Self::layer_norm_conversion::<PS>(node))
+            }
+            NodeType::GroupNormalization => {
+                |graph, node| graph.register(Self::group_norm_conversion::<PS>(node))
+            }
+            NodeType::InstanceNormalization => {
+                |graph, node| graph.register(Self::instance_norm_conversion::<PS>(node))
+            }
+            NodeType::Linear => |graph, node| graph.register(Self::linear_conversion::<PS>(node)),
+            NodeType::GRU => |graph, node| graph.register(Self::gru_conversion::<PS>(node)),
+            NodeType::LSTM => |graph, node| graph.register(Self::lstm_conversion::<PS>(node)),
+            NodeType::BatchNormalization => {
+                |graph, node| graph.register(Self::batch_norm_conversion::<PS>(node))
+            }
+            NodeType::Relu => |graph, node| graph.register(Self::relu_conversion(node)),
+            NodeType::Gelu => |graph, node| graph.register(Self::gelu_conversion(node)),
+            NodeType::Flatten => |graph, node| graph.register(Self::flatten_conversion(node)),
+            NodeType::Gather => |graph, node| graph.register(Self::gather_conversion(node)),
+            NodeType::GatherElements => {
+                |graph, node| graph.register(Self::gather_elements_conversion(node))
+            }
+            NodeType::GatherND => |graph, node| graph.register(Self::gather_nd_conversion(node)),
+            NodeType::Log => |graph, node| graph.register(Self::log_conversion(node)),
+            NodeType::LeakyRelu => |graph, node| graph.register(Self::leaky_relu_conversion(node)),
+            NodeType::LogSoftmax => {
+                |graph, node| graph.register(Self::log_softmax_conversion(node))
+            }
+            NodeType::MeanVarianceNormalization => {
+                |graph, node| graph.register(Self::mean_variance_normalization_conversion(node))
+            }
+            NodeType::Softmax => |graph, node| graph.register(Self::softmax_conversion(node)),
+            NodeType::Sqrt => {
+                |graph, node| graph.register(Self::unary_conversion(node, UnaryNode::sqrt))
+            }
+            NodeType::Tanh => {
+                |graph, node| graph.register(Self::unary_conversion(node, UnaryNode::tanh))
+            }
+            NodeType::Constant => {
+                |graph, node| graph.register(Self::constant_conversion::<PS>(node))
+            }
+            NodeType::Min => |graph, node| graph.register(Self::min_conversion(node)),
+            NodeType::Range => |graph, node| graph.register(Self::range_conversion(node)),
+            NodeType::ReduceMax => |graph, node| graph.register(Self::reduce_max_conversion(node)),
+            NodeType::ReduceMean => {
+                |graph, node| graph.register(Self::reduce_mean_conversion(node))
+            }
+            NodeType::ReduceMin => |graph, node| graph.register(Self::reduce_min_conversion(node)),
+            NodeType::ReduceSum => |graph, node| graph.register(Self::reduce_sum_conversion(node)),
+            NodeType::Reshape => |graph, node| graph.register(Self::reshape_conversion(node)),
+            NodeType::Resize => |graph, node| graph.register(Self::resize_conversion(node)),
+            NodeType::ScatterElements => {
+                |graph, node| graph.register(Self::scatter_elements_conversion(node))
+            }
+            NodeType::ScatterND => |graph, node| graph.register(Self::scatter_nd_conversion(node)),
+            NodeType::Reciprocal => |graph, node| graph.register(Self::reciprocal_conversion(node)),
+            NodeType::Shape => |graph, node| graph.register(Self::shape_conversion(node)),
+            NodeType::HardSigmoid => {
+                |graph, node| graph.register(Self::hard_sigmoid_conversion(node))
+            }
+            NodeType::HardSwish => |graph, node| graph.register(Self::hard_swish_conversion(node)),
+            NodeType::Sigmoid => {
+                |graph, node| graph.register(Self::unary_conversion(node, UnaryNode::sigmoid))
+            }
+            NodeType::Softplus => {
+                |graph, node| graph.register(Self::unary_conversion(node, UnaryNode::softplus))
+            }
+            NodeType::Softsign => {
+                |graph, node| graph.register(Self::unary_conversion(node, UnaryNode::softsign))
+            }
+            NodeType::Sin => |graph, node| graph.register(Self::sin_conversion(node)),
+            NodeType::Slice => |graph, node| graph.register(Self::slice_conversion(node)),
+            NodeType::Split => |graph, node| graph.register(Self::split_conversion(node)),
+            NodeType::Sum => |graph, node| graph.register(Self::sum_conversion(node)),
+            NodeType::TopK => |graph, node| graph.register(Self::top_k_conversion(node)),
+            NodeType::OneHot => |graph, node| graph.register(Self::one_hot_conversion(node)),
+            NodeType::CumSum => |graph, node| graph.register(Self::cum_sum_conversion(node)),
+            NodeType::Trilu => |graph, node| graph.register(Self::trilu_conversion(node)),
+            NodeType::Transpose => |graph, node| graph.register(Self::transpose_conversion(node)),
+            NodeType::Concat => |graph, node| graph.register(Self::concat_conversion(node)),
+            NodeType::Cast => |graph, node| graph.register(Self::cast_conversion(node)),
+            NodeType::Dropout if dropout_modules => {
+                |graph, node| graph.register(Self::dropout_conversion(node))
+            }
+            NodeType::Dropout => |graph, node| graph.register(Self::identity_conversion(node)),
+            NodeType::Einsum => |graph, node| graph.register(Self::einsum_conversion(node)),
+            NodeType::GlobalAveragePool => {
+                |graph, node| graph.register(Self::global_avg_pool_conversion(node))
+            }
+            NodeType::GlobalMaxPool => {
+                |graph, node| graph.register(Self::global_max_pool_conversion(node))
+            }
+            NodeType::ConvTranspose2d => {
+                |graph, node| graph.register(Self::conv_transpose2d_conversion(node))
+            }
+            NodeType::Pow => |graph, node| graph.register(Self::pow_conversion(node)),
+            NodeType::Mod => |graph, node| graph.register(Self::mod_conversion(node)),
+            NodeType::Unique => |graph, node| graph.register(Self::unique_conversion(node)),
+            NodeType::Unsqueeze => |graph, node| graph.register(Self::unsqueeze_conversion(node)),
+            NodeType::Where => |graph, node| graph.register(Self::where_conversion(node)),
+            NodeType::Sign => {
+                |graph, node| graph.register(Self::unary_conversion(node, UnaryNode::sign))
+            }
+            NodeType::Abs => {
+                |graph, node| graph.register(Self::unary_conversion(node, UnaryNode::abs))
+            }
+            NodeType::Floor => {
+                |graph, node| graph.register(Self::unary_conversion(node, UnaryNode::floor))
+            }
+            NodeType::Ceil => {
+                |graph, node| graph.register(Self::unary_conversion(node, UnaryNode::ceil))
+            }
+            NodeType::Round => {
+                |graph, node| graph.register(Self::unary_conversion(node, UnaryNode::round))
+            }
+            NodeType::Squeeze => |graph, node| graph.register(Self::squeeze_conversion(node)),
+            NodeType::RandomUniform => |graph, node| match random_seed(&node) {
+                Some(seed) => {
+                    let name = node.name.clone();
+                    let random = Self::random_uniform_conversion(node);
+                    let distribution = Distribution::Uniform(random.low, random.high);
+                    graph.register(Self::seeded_random_conversion::<PS>(
+                        name,
+                        random.output_ty,
+                        distribution,
+                        seed,
+                    ))
+                }
+                None => graph.register(Self::random_uniform_conversion(node)),
+            },
+            NodeType::RandomNormal => |graph, node| match random_seed(&node) {
+                Some(seed) => {
+                    let name = node.name.clone();
+                    let random = Self::random_normal_conversion(node);
+                    let distribution = Distribution::Normal(random.mean, random.scale);
+                    graph.register(Self::seeded_random_conversion::<PS>(
+                        name,
+                        random.output_ty,
+                        distribution,
+                        seed,
+                    ))
+                }
+                None => graph.register(Self::random_normal_conversion(node)),
+            },
+            _ => return None,
+        };
+
+        Some(conversion)
     }
 
     fn constant_conversion<PS: PrecisionSettings>(node: Node) -> ConstantNode<PS> {
@@ -356,7 +661,7 @@ impl OnnxGraph {
 
                     let tensor_value = match tensor.elem_type {
                         // TODO Review how double precision should be supported
-                        ElementType::Float32 | ElementType::Float64 => {
+                        ElementType::Float16 | ElementType::Float32 | ElementType::Float64 => {
                             TensorValue::Float(serialize_data::<PS::FloatElem>(
                                 attr.value.unwrap(),
                                 tensor.shape.unwrap(),
@@ -409,10 +714,6 @@ impl OnnxGraph {
             .map(|val| val.clone().into_f32() as f64)
             .unwrap_or(0.0f64);
 
-        if node.attrs.contains_key("seed") {
-            warn!("seed attribute is not supported!");
-        }
-
         RandomUniformNode::new(output_type, low, high)
     }
 
@@ -436,13 +737,41 @@ impl OnnxGraph {
             .map(|val| val.clone().into_f32() as f64)
             .unwrap_or(1.0f64);
 
-        if node.attrs.contains_key("seed") {
-            warn!("seed attribute is not supported!");
-        }
-
         RandomNormalNode::new(output_type, mean, scale)
     }
 
+    /// Generate the values of a seeded random node when importing the model, so that they're
+    /// baked into the record as a constant instead of being drawn at each forward pass.
+    fn seeded_random_conversion<PS: PrecisionSettings>(
+        name: String,
+        output_ty: TensorType,
+        distribution: Distribution,
+        seed: u64,
+    ) -> ConstantNode<PS> {
+        let shape = output_ty
+            .shape
+            .clone()
+            .expect("Random output should have a shape");
+        let num_elements = shape.iter().product();
+
+        // The values are drawn from a local generator, leaving the global seed of the backend
+        // untouched.
+        let mut rng = StdRng::seed_from_u64(seed);
+        let data =
+            burn::tensor::Data::<f32, 1>::random([num_elements].into(), distribution, &mut rng)
+                .convert::<PS::FloatElem>();
+
+        let value = TensorValue::Float(DataSerialize::new(data.value, shape.clone()));
+        let constant_ty =
+            TensorType::new(name.clone(), output_ty.dim, TensorKind::Float, Some(shape));
+
+        ConstantNode::new(
+            name,
+            ConstantValue::Tensor(constant_ty, value),
+            Type::Tensor(output_ty),
+        )
+    }
+
     fn add_conversion(node: Node) -> BinaryNode {
         let lhs = node.inputs.first().unwrap().to_type();
         let rhs = node.inputs.get(1).unwrap().to_type();
@@ -483,6 +812,43 @@ impl OnnxGraph {
         MatmulNode::new(lhs, rhs, output)
     }
 
+    fn gemm_conversion(node: Node) -> GemmNode {
+        let (alpha, beta, trans_a, trans_b) = gemm_config(&node);
+
+        // Constant weights and bias are only supported by the Linear nodes converted from Gemm
+        let input = |index: usize| {
+            node.inputs
+                .get(index)
+                .filter(|arg| !arg.name.is_empty())
+                .map(|arg| {
+                    if arg.value.is_some() {
+                        panic!(
+                            "Gemm: constant input {} is only supported as the weight or the bias \
+                            of a linear layer, without transA",
+                            arg.name
+                        );
+                    }
+
+                    let tensor = arg.to_tensor_type();
+                    if tensor.dim == 0 || tensor.dim > 2 {
+                        panic!("Gemm: input {} must be a vector or a matrix", arg.name);
+                    }
+
+                    tensor
+                })
+        };
+
+        let a = input(0).expect("Gemm: A is required");
+        let b = input(1).expect("Gemm: B is required");
+        let c = input(2);
+        if a.dim != 2 || b.dim != 2 {
+            panic!("Gemm: A and B must be matrices");
+        }
+        let output = node.outputs.first().unwrap().to_tensor_type();
+
+        GemmNode::new(a, b, c, output, alpha, beta, trans_a, trans_b)
+    }
+
     fn equal_conversion(node: Node) -> BinaryNode {
         let lhs = node.inputs.first().unwrap().to_type();
         let rhs = node.inputs.get(1).unwrap().to_type();
@@ -491,19 +857,41 @@ impl OnnxGraph {
         BinaryNode::equal(lhs, rhs, output)
     }
 
-    fn max_conversion(node: Node) -> BinaryNode {
+    fn bitwise_conversion(node: Node, function: fn(Type, Type, Type) -> BinaryNode) -> BinaryNode {
+        // The bitwise operators are only implemented for int tensors, other types would emit
+        // code calling methods that don't exist.
+        for arg in node.inputs.iter().chain(node.outputs.iter()) {
+            match arg.to_type() {
+                Type::Tensor(tensor) if tensor.kind == TensorKind::Int => {}
+                ty => panic!(
+                    "{:?}: {} must be an int tensor, found {ty:?}",
+                    node.node_type, arg.name
+                ),
+            }
+        }
+
         let lhs = node.inputs.first().unwrap().to_type();
         let rhs = node.inputs.get(1).unwrap().to_type();
         let output = node.outputs.first().unwrap().to_type();
 
-        BinaryNode::max_pair(lhs, rhs, output)
+        function(lhs, rhs, output)
     }
 
-    fn erf_conversion(node: Node) -> UnaryNode {
-        let input = node.inputs.first().unwrap().to_type();
+    fn bit_shift_conversion(node: Node) -> BinaryNode {
+        let function = match bit_shift_config(&node) {
+            BitShiftDirection::Left => BinaryNode::bitwise_left_shift,
+            BitShiftDirection::Right => BinaryNode::bitwise_right_shift,
+        };
+
+        Self::bitwise_conversion(node, function)
+    }
+
+    fn max_conversion(node: Node) -> BinaryNode {
+        let lhs = node.inputs.first().unwrap().to_type();
+        let rhs = node.inputs.get(1).unwrap().to_type();
         let output = node.outputs.first().unwrap().to_type();
 
-        UnaryNode::erf(input, output)
+        BinaryNode::max_pair(lhs, rhs, output)
     }
 
     fn leaky_relu_conversion(node: Node) -> UnaryNode {
@@ -538,18 +926,26 @@ impl OnnxGraph {
     fn flatten_conversion(node: Node) -> UnaryNode {
         let input = node.inputs.first().unwrap().to_type();
         let output = node.outputs.first().unwrap().to_type();
-        let (start_dim, end_dim) = flatten_config(&node);
+        let axis = flatten_config(&node);
 
-        UnaryNode::flatten(input, output, start_dim, end_dim)
+        UnaryNode::flatten(input, output, axis)
     }
 
     fn gather_conversion(node: Node) -> GatherNode {
         let input = node.inputs.first().unwrap().to_tensor_type();
-        let index = node.inputs.get(1).unwrap().to_tensor_type();
+        let index = node.inputs.get(1).unwrap();
         let output = node.outputs.first().unwrap().to_tensor_type();
         let dim = gather_config(&node);
 
-        GatherNode::new(input, index, output, dim)
+        let indices = match (gather_constant_indices(&node, dim), index.to_type()) {
+            (Some(indices), Type::Scalar(_)) => GatherIndices::ConstantScalar(indices[0]),
+            (Some(indices), _) => GatherIndices::Constant(indices),
+            (None, Type::Scalar(index)) => GatherIndices::Scalar(index),
+            (None, Type::Tensor(index)) => GatherIndices::Tensor(index),
+            (None, _) => panic!("Gather: unsupported indices type"),
+        };
+
+        GatherNode::new(input, indices, output, dim)
     }
 
     fn gather_elements_conversion(node: Node) -> GatherElementsNode {
@@ -561,6 +957,15 @@ impl OnnxGraph {
         GatherElementsNode::new(input, index, output, dim)
     }
 
+    fn gather_nd_conversion(node: Node) -> GatherNDNode {
+        let data = node.inputs.first().unwrap().to_tensor_type();
+        let indices = node.inputs.get(1).unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let tuple_size = gather_nd_config(&node);
+
+        GatherNDNode::new(data, indices, output, tuple_size)
+    }
+
     fn transpose_conversion(node: Node) -> UnaryNode {
         let input = node.inputs.first().unwrap().to_type();
         let output = node.outputs.first().unwrap().to_type();
@@ -584,6 +989,34 @@ impl OnnxGraph {
         ReshapeNode::new(input, output, shape)
     }
 
+    fn resize_conversion(node: Node) -> ResizeNode {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let (mode, size) = resize_config(&node);
+
+        ResizeNode::new(input, output, mode, size)
+    }
+
+    fn scatter_elements_conversion(node: Node) -> ScatterElementsNode {
+        let data = node.inputs.first().unwrap().to_tensor_type();
+        let indices = node.inputs.get(1).unwrap().to_tensor_type();
+        let updates = node.inputs.get(2).unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let (axis, reduction) = scatter_elements_config(&node);
+
+        ScatterElementsNode::new(data, indices, updates, output, axis, reduction)
+    }
+
+    fn scatter_nd_conversion(node: Node) -> ScatterNDNode {
+        let data = node.inputs.first().unwrap().to_tensor_type();
+        let indices = node.inputs.get(1).unwrap().to_tensor_type();
+        let updates = node.inputs.get(2).unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let reduction = scatter_nd_config(&node);
+
+        ScatterNDNode::new(data, indices, updates, output, reduction)
+    }
+
     fn min_conversion(node: Node) -> BinaryNode {
         let lhs = node.inputs.first().unwrap().to_type();
         let rhs = node.inputs.get(1).unwrap().to_type();
@@ -607,36 +1040,54 @@ impl OnnxGraph {
                 _ => panic!("Range node requires scalar inputs"),
             }
         }
-        let output = node.outputs.first().unwrap().to_tensor_type();
-        let start = convert_arg_to_scalar(node.inputs.first().unwrap());
-        let end = convert_arg_to_scalar(node.inputs.get(1).unwrap());
-        let step = convert_arg_to_scalar(node.inputs.get(2).unwrap());
+        // The output shape is kept for the length of constant ranges
+        let output = match node.outputs.first().unwrap().to_type() {
+            Type::Tensor(tensor) => tensor,
+            _ => panic!("Range: the output must be a tensor"),
+        };
+        let [start, end, step] = range_config(&node).map(|value| value.map(RangeValue::Constant));
+        let range_value = |value: Option<RangeValue>, index: usize| {
+            value.unwrap_or_else(|| RangeValue::Scalar(convert_arg_to_scalar(&node.inputs[index])))
+        };
 
-        RangeNode::new(start, end, step, output)
+        RangeNode::new(
+            range_value(start, 0),
+            range_value(end, 1),
+            range_value(step, 2),
+            output,
+        )
     }
 
     fn reduce_max_conversion(node: Node) -> UnaryNode {
         let input = node.inputs.first().unwrap().to_type();
         let output = node.outputs.first().unwrap().to_type();
-        let dim = reduce_max_config(&node);
+        let (dims, _keepdims) = reduce_config(&node);
 
-        UnaryNode::reduce_max(input, output, dim)
+        UnaryNode::reduce_max(input, output, dims)
     }
 
     fn reduce_mean_conversion(node: Node) -> UnaryNode {
         let input = node.inputs.first().unwrap().to_type();
         let output = node.outputs.first().unwrap().to_type();
-        let dim = reduce_mean_config(&node);
+        let (dims, _keepdims) = reduce_config(&node);
 
-        UnaryNode::reduce_mean(input, output, dim)
+        UnaryNode::reduce_mean(input, output, dims)
+    }
+
+    fn reduce_min_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+        let (dims, _keepdims) = reduce_config(&node);
+
+        UnaryNode::reduce_min(input, output, dims)
     }
 
     fn reduce_sum_conversion(node: Node) -> UnaryNode {
         let input = node.inputs.first().unwrap().to_type();
         let output = node.outputs.first().unwrap().to_type();
-        let dim = reduce_sum_config(&node);
+        let (dims, _keepdims) = reduce_config(&node);
 
-        UnaryNode::reduce_sum(input, output, dim)
+        UnaryNode::reduce_sum(input, output, dims)
     }
 
     fn shape_conversion(node: Node) -> UnaryNode {
@@ -647,6 +1098,21 @@ impl OnnxGraph {
         UnaryNode::shape(input, output, start_dim, end_dim)
     }
 
+    fn unique_conversion(node: Node) -> UniqueNode {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let values = node.outputs.first().unwrap().to_tensor_type();
+        // The optional outputs are either missing or with an empty name when they aren't used
+        let output = |index: usize| {
+            node.outputs
+                .get(index)
+                .filter(|output| !output.name.is_empty())
+                .map(Argument::to_tensor_type)
+        };
+        let sorted = unique_config(&node);
+
+        UniqueNode::new(input, values, output(1), output(2), output(3), sorted)
+    }
+
     fn unsqueeze_conversion(node: Node) -> UnsqueezeNode {
         let input = node.inputs.first().unwrap().to_type();
         let output = node.outputs.first().unwrap().to_tensor_type();
@@ -656,10 +1122,16 @@ impl OnnxGraph {
     }
 
     fn where_conversion(node: Node) -> WhereNode {
-        let condition = node.inputs.first().unwrap().to_tensor_type();
-        let x = node.inputs.get(1).unwrap().to_tensor_type();
-        let y = node.inputs.get(2).unwrap().to_tensor_type();
-        let output = node.outputs.first().unwrap().to_tensor_type();
+        // The shapes are kept to broadcast the inputs to the output shape
+        let tensor_type = |arg: &Argument| match arg.to_type() {
+            Type::Tensor(tensor) => tensor,
+            _ => panic!("Where: {} must be a tensor", arg.name),
+        };
+
+        let condition = node.inputs.first().unwrap().to_type();
+        let x = tensor_type(node.inputs.get(1).unwrap());
+        let y = tensor_type(node.inputs.get(2).unwrap());
+        let output = tensor_type(node.outputs.first().unwrap());
 
         WhereNode::new(condition, x, y, output)
     }
@@ -672,11 +1144,28 @@ impl OnnxGraph {
         ClipNode::new(input, output, min, max)
     }
 
-    fn sigmoid_conversion(node: Node) -> UnaryNode {
+    fn hard_sigmoid_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+        let (alpha, beta) = hard_sigmoid_config(&node);
+
+        UnaryNode::hard_sigmoid(input, output, alpha, beta)
+    }
+
+    /// Conversion of the elementwise operators without attributes, the output keeping the type
+    /// of the input.
+    fn unary_conversion(node: Node, function: fn(Type, Type) -> UnaryNode) -> UnaryNode {
         let input = node.inputs.first().unwrap().to_type();
         let output = node.outputs.first().unwrap().to_type();
 
-        UnaryNode::sigmoid(input, output)
+        function(input, output)
+    }
+
+    fn hard_swish_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        UnaryNode::hard_swish(input, output)
     }
 
     fn sin_conversion(node: Node) -> UnaryNode {
@@ -686,6 +1175,83 @@ impl OnnxGraph {
         UnaryNode::sin(input, output)
     }
 
+    fn slice_conversion(node: Node) -> SliceNode {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let ranges = slice_config(&node);
+
+        SliceNode::new(input, output, ranges)
+    }
+
+    fn pad_conversion(node: Node) -> PadNode {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let (pads, mode) = pad_config(&node);
+
+        PadNode::new(input, output, pads, mode)
+    }
+
+    fn depth_to_space_conversion(node: Node) -> DepthToSpaceNode {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let (block_size, mode) = depth_to_space_config(&node);
+
+        DepthToSpaceNode::new(input, output, block_size, mode)
+    }
+
+    fn space_to_depth_conversion(node: Node) -> SpaceToDepthNode {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let block_size = space_to_depth_config(&node);
+
+        SpaceToDepthNode::new(input, output, block_size)
+    }
+
+    fn top_k_conversion(node: Node) -> TopKNode {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let values = node.outputs[0].to_tensor_type();
+        let indices = node.outputs[1].to_tensor_type();
+        let (k, axis, largest) = top_k_config(&node);
+
+        TopKNode::new(input, values, indices, k, axis, largest)
+    }
+
+    fn one_hot_conversion(node: Node) -> OneHotNode {
+        let indices = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let (depth, values, axis) = one_hot_config(&node);
+
+        OneHotNode::new(indices, output, depth, values, axis)
+    }
+
+    fn cum_sum_conversion(node: Node) -> CumSumNode {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let (axis, exclusive, reverse) = cum_sum_config(&node);
+
+        CumSumNode::new(input, output, axis, exclusive, reverse)
+    }
+
+    fn trilu_conversion(node: Node) -> TriluNode {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let (upper, diagonal) = trilu_config(&node);
+
+        TriluNode::new(input, output, upper, diagonal)
+    }
+
+    fn split_conversion(node: Node) -> SplitNode {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let outputs = node
+            .outputs
+            .iter()
+            .map(|output| output.to_tensor_type())
+            .collect();
+        let (axis, sizes) = split_config(&node);
+
+        SplitNode::new(input, outputs, axis, sizes)
+    }
+
     fn sum_conversion(node: Node) -> SumNode {
         let inputs = node
             .inputs
@@ -701,37 +1267,41 @@ impl OnnxGraph {
         let input = node.inputs.first().unwrap().to_type();
         let output = node.outputs.first().unwrap().to_type();
 
+        // A division by zero follows the backend, usually giving an infinity.
+        match (&input, &output) {
+            (Type::Tensor(input), Type::Tensor(output)) if input.dim != output.dim => panic!(
+                "Reciprocal: the output rank {} differs from the input rank {}",
+                output.dim, input.dim
+            ),
+            (Type::Tensor(_), Type::Tensor(_)) | (Type::Scalar(_), Type::Scalar(_)) => (),
+            _ => panic!("Reciprocal: the input and output must both be tensors or scalars"),
+        }
+
         UnaryNode::reciprocal(input, output)
     }
 
     fn log_softmax_conversion(node: Node) -> UnaryNode {
         let input = node.inputs.first().unwrap().to_type();
         let output = node.outputs.first().unwrap().to_type();
-        let dim = log_softmax_config(&node);
+        let (dim, coerce_2d) = log_softmax_config(&node);
 
-        UnaryNode::log_softmax(input, output, dim)
+        UnaryNode::log_softmax(input, output, dim, coerce_2d)
     }
 
-    fn softmax_conversion(node: Node) -> UnaryNode {
-        let input = node.inputs.first().unwrap().to_type();
-        let output = node.outputs.first().unwrap().to_type();
-        let dim = softmax_config(&node);
-
-        UnaryNode::softmax(input, output, dim)
-    }
-
-    fn sqrt_conversion(node: Node) -> UnaryNode {
+    fn mean_variance_normalization_conversion(node: Node) -> UnaryNode {
         let input = node.inputs.first().unwrap().to_type();
         let output = node.outputs.first().unwrap().to_type();
+        let dims = mean_variance_normalization_config(&node);
 
-        UnaryNode::sqrt(input, output)
+        UnaryNode::mean_variance_normalization(input, output, dims)
     }
 
-    fn tanh_conversion(node: Node) -> UnaryNode {
+    fn softmax_conversion(node: Node) -> UnaryNode {
         let input = node.inputs.first().unwrap().to_type();
         let output = node.outputs.first().unwrap().to_type();
+        let (dim, coerce_2d) = softmax_config(&node);
 
-        UnaryNode::tanh(input, output)
+        UnaryNode::softmax(input, output, dim, coerce_2d)
     }
 
     fn argmax_conversion(node: Node) -> ArgMaxNode {
@@ -768,6 +1338,93 @@ impl OnnxGraph {
         LinearNode::new(name, input, output, weight, bias, config)
     }
 
+    fn lstm_conversion<PS: PrecisionSettings>(node: Node) -> LstmNode<PS> {
+        let name = &node.name;
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let (config, direction) = lstm_config(&node);
+
+        let weight = extract_data_serialize::<PS::FloatElem>(1, &node).expect("W is required");
+        let recurrence = extract_data_serialize::<PS::FloatElem>(2, &node).expect("R is required");
+        let bias = extract_data_serialize::<PS::FloatElem>(3, &node);
+
+        let data = (0..weight.shape[0])
+            .map(|index| {
+                lstm_gates_data(&weight, &recurrence, bias.as_ref(), index, config.d_hidden)
+            })
+            .collect();
+
+        // The initial states are runtime inputs, omitted with an empty name.
+        let state_input = |index: usize| {
+            node.inputs.get(index).and_then(|arg| {
+                if arg.value.is_some() {
+                    panic!("LSTM: constant initial states are not supported");
+                }
+
+                (!arg.name.is_empty()).then(|| arg.to_tensor_type())
+            })
+        };
+        let initial_state = match (state_input(5), state_input(6)) {
+            (Some(hidden), Some(cell)) => Some((hidden, cell)),
+            (None, None) => None,
+            _ => panic!("LSTM: initial_h and initial_c must be provided together"),
+        };
+
+        let output = |index: usize| node.outputs.get(index).map(Argument::to_tensor_type);
+
+        LstmNode::new(
+            name,
+            input,
+            initial_state,
+            output(0),
+            output(1),
+            output(2),
+            config,
+            direction,
+            data,
+        )
+    }
+
+    fn gru_conversion<PS: PrecisionSettings>(node: Node) -> GruNode<PS> {
+        let name = &node.name;
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let (config, direction) = gru_config(&node);
+
+        let weight = extract_data_serialize::<PS::FloatElem>(1, &node).expect("W is required");
+        let recurrence = extract_data_serialize::<PS::FloatElem>(2, &node).expect("R is required");
+        let bias = extract_data_serialize::<PS::FloatElem>(3, &node);
+        let data = gru_gates_data(&weight, &recurrence, bias.as_ref(), config.d_hidden);
+
+        // The initial hidden state is a runtime input, omitted with an empty name.
+        let initial_hidden = node.inputs.get(5).and_then(|arg| {
+            if arg.value.is_some() {
+                panic!("GRU: constant initial states are not supported");
+            }
+
+            (!arg.name.is_empty()).then(|| arg.to_tensor_type())
+        });
+
+        let output = |index: usize| node.outputs.get(index).map(Argument::to_tensor_type);
+
+        GruNode::new(
+            name,
+            input,
+            initial_hidden,
+            output(0),
+            output(1),
+            config,
+            direction,
+            data,
+        )
+    }
+
+    fn einsum_conversion(node: Node) -> EinsumNode {
+        let inputs = node.inputs.iter().map(Argument::to_tensor_type).collect();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let op = einsum_config(&node);
+
+        EinsumNode::new(inputs, output, op)
+    }
+
     fn dropout_conversion(node: Node) -> DropoutNode {
         let name = &node.name;
         let input = node.inputs.first().unwrap().to_tensor_type();
@@ -777,6 +1434,13 @@ impl OnnxGraph {
         DropoutNode::new(name, input, output, config)
     }
 
+    fn identity_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        UnaryNode::identity(input, output)
+    }
+
     fn batch_norm_conversion<PS: PrecisionSettings>(node: Node) -> BatchNormNode<PS> {
         let config = batch_norm_config(&node);
         let input = node.inputs.first().unwrap().to_tensor_type();
@@ -820,10 +1484,60 @@ impl OnnxGraph {
         LayerNormNode::new(name, input, output, gamma, beta, config, full_precision)
     }
 
-    fn conv1d_conversion<PS: PrecisionSettings>(node: Node) -> Conv1dNode<PS> {
+    fn group_norm_conversion<PS: PrecisionSettings>(node: Node) -> GroupNormNode<PS> {
+        let config = group_norm_config(&node);
+
+        Self::norm_conversion(node, config)
+    }
+
+    fn instance_norm_conversion<PS: PrecisionSettings>(node: Node) -> GroupNormNode<PS> {
+        let config = instance_norm_config(&node);
+
+        Self::norm_conversion(node, config)
+    }
+
+    /// Convert a group or instance normalization node, whose scale and bias are per channel, or
+    /// per group before opset 21.
+    fn norm_conversion<PS: PrecisionSettings>(
+        node: Node,
+        config: GroupNormConfig,
+    ) -> GroupNormNode<PS> {
         let input = node.inputs.first().unwrap().to_tensor_type();
         let output = node.outputs.first().unwrap().to_tensor_type();
-        let config = conv1d_config(&node);
+
+        // Scale tensor (aka gamma)
+        let gamma = extract_data_serialize::<PS::FloatElem>(1, &node).expect("Scale is required");
+        // Bias (B) tensor (aka beta)
+        let beta = extract_data_serialize::<PS::FloatElem>(2, &node).expect("Bias is required");
+
+        let (gamma, beta) = (
+            expand_per_channel(gamma, config.num_channels),
+            expand_per_channel(beta, config.num_channels),
+        );
+
+        let name = &node.name;
+
+        GroupNormNode::new(name, input, output, gamma, beta, config)
+    }
+
+    /// Convert a Conv1d node, with the Pad node applying its padding first when it's asymmetric.
+    fn conv1d_conversion<PS: PrecisionSettings>(node: Node) -> (Option<PadNode>, Conv1dNode<PS>) {
+        let mut input = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let (config, pre_pads) = conv1d_config(&node);
+
+        let pad = pre_pads.map(|[left, right]| {
+            let padded = TensorType::new_float(format!("{}_padded", node.name), input.dim);
+            let pad = PadNode::new(
+                input.clone(),
+                padded.clone(),
+                [left, right, 0, 0],
+                PadMode::Constant(0.0),
+            );
+            input = padded;
+
+            pad
+        });
 
         let bias = node.inputs.len() == 3;
         let weight = extract_data_serialize::<PS::FloatElem>(1, &node).unwrap();
@@ -833,13 +1547,24 @@ impl OnnxGraph {
         };
 
         let name = &node.name;
-        Conv1dNode::<PS>::new(name, input, output, weight, bias, config)
+        let conv = Conv1dNode::<PS>::new(name, input, output, weight, bias, config);
+
+        (pad, conv)
     }
 
-    fn conv2d_conversion<PS: PrecisionSettings>(node: Node) -> Conv2dNode<PS> {
-        let input = node.inputs.first().unwrap().to_tensor_type();
+    /// Convert a Conv2d node, with the Pad node applying its padding first when it's asymmetric.
+    fn conv2d_conversion<PS: PrecisionSettings>(node: Node) -> (Option<PadNode>, Conv2dNode<PS>) {
+        let mut input = node.inputs.first().unwrap().to_tensor_type();
         let output = node.outputs.first().unwrap().to_tensor_type();
-        let config = conv2d_config(&node);
+        let (config, pre_pads) = conv2d_config(&node);
+
+        let pad = pre_pads.map(|pads| {
+            let padded = TensorType::new_float(format!("{}_padded", node.name), input.dim);
+            let pad = PadNode::new(input.clone(), padded.clone(), pads, PadMode::Constant(0.0));
+            input = padded;
+
+            pad
+        });
 
         let bias = node.inputs.len() == 3;
         let weight = extract_data_serialize::<PS::FloatElem>(1, &node).unwrap();
@@ -849,7 +1574,9 @@ impl OnnxGraph {
         };
 
         let name = &node.name;
-        Conv2dNode::<PS>::new(name, input, output, weight, bias, config)
+        let conv = Conv2dNode::<PS>::new(name, input, output, weight, bias, config);
+
+        (pad, conv)
     }
 
     fn max_pool1d_conversion(node: Node) -> MaxPool1dNode {
@@ -857,6 +1584,10 @@ impl OnnxGraph {
         let output = node.outputs.first().unwrap().to_tensor_type();
         let config = max_pool1d_config(&node);
 
+        if node.outputs.len() > 1 {
+            panic!("MaxPool1d: the Indices output is not supported");
+        }
+
         let name = &node.name;
         MaxPool1dNode::new(name, input, output, config)
     }
@@ -864,19 +1595,27 @@ impl OnnxGraph {
     fn max_pool2d_conversion(node: Node) -> MaxPool2dNode {
         let input = node.inputs.first().unwrap().to_tensor_type();
         let output = node.outputs.first().unwrap().to_tensor_type();
+        let indices = node.outputs.get(1).map(Argument::to_tensor_type);
         let config = max_pool2d_config(&node);
 
         let name = &node.name;
-        MaxPool2dNode::new(name, input, output, config)
+        MaxPool2dNode::new(name, input, output, indices, config)
     }
 
     fn prelu_conversion<PS: PrecisionSettings>(node: Node) -> PReluNode<PS> {
         let input = node.inputs.first().unwrap().to_tensor_type();
         let output = node.outputs.first().unwrap().to_tensor_type();
-        let weight = extract_data_serialize::<PS::FloatElem>(1, &node).unwrap();
-        let config = PReluConfig::new();
+        // The slope is checked to broadcast against the channels during the shape inference, so
+        // a constant slope is stored with one value per channel whatever its rank.
+        let slope = match extract_data_serialize::<PS::FloatElem>(1, &node) {
+            Some(alpha) => {
+                let num_parameters = alpha.value.len();
+                PReluSlope::Constant(DataSerialize::new(alpha.value, vec![num_parameters]))
+            }
+            None => PReluSlope::Tensor(node.inputs[1].to_tensor_type()),
+        };
         let name = &node.name;
-        PReluNode::<PS>::new(name, input, output, weight, config)
+        PReluNode::<PS>::new(name, input, output, slope)
     }
     fn conv_transpose2d_conversion<PS: PrecisionSettings>(node: Node) -> ConvTranspose2dNode<PS> {
         let input = node.inputs.first().unwrap().to_tensor_type();
@@ -920,18 +1659,18 @@ impl OnnxGraph {
         GlobalAvgPoolNode::new(name, input, output)
     }
 
-    fn cos_conversion(node: Node) -> UnaryNode {
-        let input = node.inputs.first().unwrap().to_type();
-        let output = node.outputs.first().unwrap().to_type();
+    fn global_max_pool_conversion(node: Node) -> GlobalMaxPoolNode {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
 
-        UnaryNode::cos(input, output)
+        GlobalMaxPoolNode::new(input, output)
     }
 
-    fn exp_conversion(node: Node) -> UnaryNode {
+    fn cos_conversion(node: Node) -> UnaryNode {
         let input = node.inputs.first().unwrap().to_type();
         let output = node.outputs.first().unwrap().to_type();
 
-        UnaryNode::exp(input, output)
+        UnaryNode::cos(input, output)
     }
 
     fn expand_conversion(node: Node) -> ExpandNode {
@@ -942,16 +1681,16 @@ impl OnnxGraph {
         ExpandNode::new(input, output, shape)
     }
 
-    fn neg_conversion(node: Node) -> UnaryNode {
+    fn not_conversion(node: Node) -> UnaryNode {
         let input = node.inputs.first().unwrap().to_type();
         let output = node.outputs.first().unwrap().to_type();
-        UnaryNode::neg(input, output)
+        UnaryNode::not(input, output)
     }
 
-    fn not_conversion(node: Node) -> UnaryNode {
+    fn nonzero_conversion(node: Node) -> UnaryNode {
         let input = node.inputs.first().unwrap().to_type();
         let output = node.outputs.first().unwrap().to_type();
-        UnaryNode::not(input, output)
+        UnaryNode::nonzero(input, output)
     }
 
     fn greater_conversion(node: Node) -> BinaryNode {
@@ -1001,10 +1740,13 @@ impl OnnxGraph {
         }
     }
 
-    fn sign_conversion(node: Node) -> UnaryNode {
-        let input = node.inputs.first().unwrap().to_type();
+    fn mod_conversion(node: Node) -> BinaryNode {
+        let lhs = node.inputs.first().unwrap().to_type();
+        let rhs = node.inputs.get(1).unwrap().to_type();
         let output = node.outputs.first().unwrap().to_type();
-        UnaryNode::sign(input, output)
+        let fmod = mod_config(&node);
+
+        BinaryNode::modulo(lhs, rhs, output, fmod)
     }
 
     fn squeeze_conversion(node: Node) -> SqueezeNode {
@@ -1047,6 +1789,115 @@ fn extract_data_serialize<E: Element>(input_index: usize, node: &Node) -> Option
     }
 }
 
+/// Repeat each value of a per-group parameter for all the channels of its group.
+fn expand_per_channel<E: Element>(data: DataSerialize<E>, num_channels: usize) -> DataSerialize<E> {
+    let num_groups = data.value.len();
+
+    if num_groups == num_channels {
+        return data;
+    }
+
+    let group_size = num_channels / num_groups;
+    let value = (0..num_channels)
+        .map(|channel| data.value[channel / group_size])
+        .collect();
+
+    DataSerialize::new(value, vec![num_channels])
+}
+
+/// Extract the weights of the LSTM gates of a direction from the ONNX `W`, `R` and `B` inputs,
+/// which stack the gates in the input, output, forget and cell order.
+fn lstm_gates_data<E: Element>(
+    weight: &DataSerialize<E>,
+    recurrence: &DataSerialize<E>,
+    bias: Option<&DataSerialize<E>>,
+    direction: usize,
+    d_hidden: usize,
+) -> LstmGatesData<E> {
+    let gate = |index: usize| {
+        LstmGateData::new(
+            rnn_gate_weight(weight, direction, index, d_hidden),
+            bias.map(|bias| rnn_gate_bias(bias, direction, index, d_hidden)),
+            rnn_gate_weight(recurrence, direction, index, d_hidden),
+            // The recurrence biases follow the weight biases.
+            bias.map(|bias| rnn_gate_bias(bias, direction, index + 4, d_hidden)),
+        )
+    };
+
+    LstmGatesData::new(gate(0), gate(2), gate(1), gate(3))
+}
+
+/// Extract the weights of the GRU gates from the ONNX `W`, `R` and `B` inputs, which stack the
+/// gates in the update, reset and hidden order.
+fn gru_gates_data<E: Element>(
+    weight: &DataSerialize<E>,
+    recurrence: &DataSerialize<E>,
+    bias: Option<&DataSerialize<E>>,
+    d_hidden: usize,
+) -> GruGatesData<E> {
+    let gate = |index: usize| {
+        LstmGateData::new(
+            rnn_gate_weight(weight, 0, index, d_hidden),
+            bias.map(|bias| rnn_gate_bias(bias, 0, index, d_hidden)),
+            rnn_gate_weight(recurrence, 0, index, d_hidden),
+            // The recurrence biases follow the weight biases.
+            bias.map(|bias| rnn_gate_bias(bias, 0, index + 3, d_hidden)),
+        )
+    };
+
+    GruGatesData::new(gate(0), gate(1), gate(2))
+}
+
+/// Extract the `[d_input, d_hidden]` weight of a gate from
+/// `[num_directions, num_gates * d_hidden, d_input]` weights, transposing it to the Burn linear
+/// layout.
+fn rnn_gate_weight<E: Element>(
+    data: &DataSerialize<E>,
+    direction: usize,
+    gate: usize,
+    d_hidden: usize,
+) -> DataSerialize<E> {
+    let num_gates = data.shape[1] / d_hidden;
+    let d_input = data.shape[2];
+    let start = (direction * num_gates + gate) * d_hidden * d_input;
+    let rows = &data.value[start..start + d_hidden * d_input];
+
+    let value = (0..d_input)
+        .flat_map(|i| (0..d_hidden).map(move |h| rows[h * d_input + i]))
+        .collect();
+
+    DataSerialize::new(value, vec![d_input, d_hidden])
+}
+
+/// Extract the bias of a gate from `[num_directions, 2 * num_gates * d_hidden]` biases.
+fn rnn_gate_bias<E: Element>(
+    data: &DataSerialize<E>,
+    direction: usize,
+    gate: usize,
+    d_hidden: usize,
+) -> DataSerialize<E> {
+    let num_biases = data.shape[1] / d_hidden;
+    let start = (direction * num_biases + gate) * d_hidden;
+
+    DataSerialize::new(data.value[start..start + d_hidden].to_vec(), vec![d_hidden])
+}
+
+/// The seed of a random node, if any, whose values are generated when importing the model.
+///
+/// The seed is a float attribute, which must hold a non-negative integer.
+fn random_seed(node: &Node) -> Option<u64> {
+    let seed = node.attrs.get("seed")?.clone().into_f32();
+
+    if !(seed >= 0.0 && seed.fract() == 0.0 && seed < u64::MAX as f32) {
+        panic!(
+            "{:?}: the seed must be a non-negative integer, got {seed}",
+            node.node_type
+        );
+    }
+
+    Some(seed as u64)
+}
+
 /// Convert data to `DataSerialize`.
 fn serialize_data<E: Element>(data: Data, shape: Vec<usize>) -> DataSerialize<E> {
     match data {
@@ -1125,6 +1976,7 @@ impl From<&ElementType> for ScalarKind {
 impl From<ElementType> for TensorKind {
     fn from(elem_type: ElementType) -> Self {
         match elem_type {
+            ElementType::Float16 => TensorKind::Float,
             ElementType::Float32 => TensorKind::Float,
             ElementType::Float64 => TensorKind::Float,
             ElementType::Int32 => TensorKind::Int,
@@ -1134,3 +1986,157 @@ impl From<ElementType> for TensorKind {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::node::SerializationBackend;
+    use burn::tensor::{backend::Backend, Tensor};
+
+    fn node(node_type: NodeType, name: &str) -> Node {
+        Node {
+            node_type,
+            name: name.to_string(),
+            inputs: vec![Argument::new("input".to_string())],
+            outputs: vec![Argument::new(format!("{name}_out"))],
+            attrs: Default::default(),
+            opset_version: 16,
+        }
+    }
+
+    #[test]
+    fn err_lists_all_unsupported_ops() {
+        let graph = OnnxGraph {
+            nodes: vec![
+                node(NodeType::StringNormalizer, "stringnormalizer1"),
+                node(NodeType::Relu, "relu1"),
+                node(NodeType::TfIdfVectorizer, "tfidfvectorizer1"),
+                node(NodeType::StringNormalizer, "stringnormalizer2"),
+            ],
+            inputs: vec![Argument::new("input".to_string())],
+            outputs: vec![Argument::new("stringnormalizer2_out".to_string())],
+        };
+
+        let err = graph
+            .try_into_burn::<FullPrecisionSettings>()
+            .expect_err("The graph should contain unsupported ops");
+
+        assert_eq!(
+            err,
+            UnsupportedOpsError {
+                ops: vec![
+                    (
+                        "StringNormalizer".to_string(),
+                        vec![
+                            "stringnormalizer1".to_string(),
+                            "stringnormalizer2".to_string()
+                        ]
+                    ),
+                    (
+                        "TfIdfVectorizer".to_string(),
+                        vec!["tfidfvectorizer1".to_string()]
+                    ),
+                ]
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "Unsupported ONNX operators: StringNormalizer (used by stringnormalizer1, \
+             stringnormalizer2), TfIdfVectorizer (used by tfidfvectorizer1)"
+        );
+    }
+
+    fn seeded(seed: f32) -> Node {
+        let mut node = node(NodeType::RandomUniform, "randomuniform1");
+        node.attrs
+            .insert("seed".to_string(), ir::AttributeValue::Float32(seed));
+        node
+    }
+
+    #[test]
+    fn random_seed_is_an_integer() {
+        assert_eq!(
+            random_seed(&node(NodeType::RandomUniform, "randomuniform1")),
+            None
+        );
+        assert_eq!(random_seed(&seeded(42.0)), Some(42));
+    }
+
+    #[test]
+    #[should_panic(expected = "the seed must be a non-negative integer, got -1")]
+    fn err_when_random_seed_is_negative() {
+        random_seed(&seeded(-1.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "the seed must be a non-negative integer, got 1.5")]
+    fn err_when_random_seed_is_fractional() {
+        random_seed(&seeded(1.5));
+    }
+
+    #[test]
+    fn seeded_random_doesnt_change_the_backend_generator() {
+        let mut output = TensorType::new_float("randomuniform1_out", 1);
+        output.shape = Some(vec![4]);
+        let values = || {
+            let node = OnnxGraph::seeded_random_conversion::<FullPrecisionSettings>(
+                "randomuniform1".to_string(),
+                output.clone(),
+                Distribution::Default,
+                7,
+            );
+            match node.value {
+                ConstantValue::Tensor(_, TensorValue::Float(data)) => data.value,
+                _ => unreachable!("The seeded random values are a float tensor"),
+            }
+        };
+        let random = || {
+            Tensor::<SerializationBackend, 1>::random(
+                [4],
+                Distribution::Default,
+                &Default::default(),
+            )
+            .into_data()
+        };
+
+        SerializationBackend::seed(3);
+        let expected = random();
+
+        // The values only depend on the seed of the node, and the backend keeps drawing the
+        // values of its own seed.
+        SerializationBackend::seed(3);
+        let first = values();
+        assert_eq!(values(), first);
+        assert_eq!(random(), expected);
+    }
+
+    #[test]
+    fn err_when_dropout_mask_is_used() {
+        let mut dropout = node(NodeType::Dropout, "dropout1");
+        dropout
+            .outputs
+            .push(Argument::new("dropout1_mask".to_string()));
+        let mut not = node(NodeType::Not, "not1");
+        not.inputs = vec![Argument::new("dropout1_mask".to_string())];
+
+        let graph = OnnxGraph {
+            nodes: vec![dropout, not],
+            inputs: vec![Argument::new("input".to_string())],
+            outputs: vec![Argument::new("not1_out".to_string())],
+        };
+
+        let err = graph
+            .try_into_burn::<FullPrecisionSettings>()
+            .expect_err("The mask output can't be imported");
+
+        assert_eq!(
+            err,
+            UnsupportedOpsError {
+                ops: vec![(
+                    "Dropout mask output".to_string(),
+                    vec!["dropout1".to_string()]
+                )]
+            }
+        );
+    }
+}
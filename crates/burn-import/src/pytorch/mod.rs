@@ -1,7 +1,7 @@
-mod adapter;
+pub(crate) mod adapter;
 mod config;
 mod error;
-mod reader;
+pub(crate) mod reader;
 mod recorder;
 pub use config::config_from_file;
 pub use recorder::{LoadArgs, PyTorchFileRecorder};
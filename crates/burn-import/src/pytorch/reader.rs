@@ -145,7 +145,7 @@ where
 }
 
 /// New type struct for Candle tensors because we need to implement the `Serializable` trait for it.
-struct CandleTensor(candle_core::Tensor);
+pub(crate) struct CandleTensor(pub(crate) candle_core::Tensor);
 
 impl Deref for CandleTensor {
     type Target = candle_core::Tensor;
@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use super::{error::Error, recorder::AdapterType};
+use crate::pytorch::{adapter::PyTorchAdapter, reader::CandleTensor};
+
+use burn::{
+    record::{
+        serde::{
+            adapter::DefaultAdapter,
+            data::{remap, unflatten},
+            de::Deserializer,
+        },
+        PrecisionSettings,
+    },
+    tensor::backend::Backend,
+};
+
+use candle_core::{safetensors, Device};
+use regex::Regex;
+use serde::de::DeserializeOwned;
+
+/// Deserializes a Safetensors file.
+///
+/// # Arguments
+///
+/// * `path` - A string slice that holds the path of the file to read.
+/// * `key_remap` - A vector of tuples containing a regular expression and a replacement string.
+/// * `adapter_type` - The adapter used to convert the tensors to the Burn module layout.
+pub fn from_file<PS, D, B>(
+    path: &Path,
+    key_remap: Vec<(Regex, String)>,
+    adapter_type: AdapterType,
+    debug: bool,
+) -> Result<D, Error>
+where
+    D: DeserializeOwned,
+    PS: PrecisionSettings,
+    B: Backend,
+{
+    // Read the safetensors file and return a map of Candle tensors
+    let tensors: HashMap<String, CandleTensor> = safetensors::load(path, &Device::Cpu)?
+        .into_iter()
+        .map(|(key, tensor)| (key, CandleTensor(tensor)))
+        .collect();
+
+    // Remap the keys (replace the keys in the map with the new keys)
+    let (tensors, remapped_keys) = remap(tensors, key_remap);
+
+    // Print the remapped keys if debug is enabled
+    if debug {
+        let mut remapped_keys = remapped_keys;
+        remapped_keys.sort();
+        println!("Debug information of keys and tensor shapes:\n---");
+        for (new_key, old_key) in remapped_keys {
+            if old_key != new_key {
+                println!("Original Key: {old_key}");
+                println!("Remapped Key: {new_key}");
+            } else {
+                println!("Key: {}", new_key);
+            }
+
+            let shape = tensors[&new_key].shape();
+            let dtype = tensors[&new_key].dtype();
+            println!("Shape: {shape:?}");
+            println!("Dtype: {dtype:?}");
+            println!("---");
+        }
+    }
+
+    // Convert the map of Candle tensors to a nested value data structure
+    let nested_value = unflatten::<PS, _>(tensors)?;
+
+    // Deserialize the nested value into a record type
+    let value = match adapter_type {
+        AdapterType::PyTorch => D::deserialize(Deserializer::<PyTorchAdapter<PS, B>>::new(
+            nested_value,
+            true,
+        ))?,
+        AdapterType::NoAdapter => {
+            D::deserialize(Deserializer::<DefaultAdapter>::new(nested_value, true))?
+        }
+    };
+
+    Ok(value)
+}
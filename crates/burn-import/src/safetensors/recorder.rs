@@ -0,0 +1,195 @@
+use core::marker::PhantomData;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use burn::{
+    record::{BurnMetadata, BurnRecord, PrecisionSettings, Record, Recorder, RecorderError},
+    tensor::backend::Backend,
+};
+
+use regex::Regex;
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::{reader::from_file, writer::to_file};
+
+/// A recorder that loads and saves Safetensors files (`.safetensors`) into Burn modules.
+///
+/// Tensors are stored under flattened dotted names (e.g. `encoder.layer.0.weight`), matching the
+/// names of a PyTorch `state_dict`.
+///
+/// LoadArgs can be used to remap keys, select the adapter or the file path.
+/// See [LoadArgs](struct.LoadArgs.html) for more information.
+///
+/// # Notes
+///
+/// Saved files keep the Burn layout of the tensors (e.g. linear weights are not transposed), so
+/// they should be loaded back with [AdapterType::NoAdapter].
+#[derive(new, Debug, Default, Clone)]
+pub struct SafetensorsFileRecorder<PS: PrecisionSettings> {
+    _settings: PhantomData<PS>,
+}
+
+impl<PS: PrecisionSettings, B: Backend> Recorder<B> for SafetensorsFileRecorder<PS> {
+    type Settings = PS;
+    type RecordArgs = PathBuf;
+    type RecordOutput = ();
+    type LoadArgs = LoadArgs;
+
+    fn record_with_tags<R: Record<B>>(
+        &self,
+        record: R,
+        _tags: BTreeMap<String, String>,
+        file: Self::RecordArgs,
+    ) -> Result<(), RecorderError> {
+        // Safetensors files only contain tensors, so the record metadata, including its tags,
+        // isn't saved.
+        Recorder::<B>::save_item(self, record.into_item::<PS>(), file)
+    }
+
+    fn save_item<I: Serialize>(
+        &self,
+        item: I,
+        mut file: Self::RecordArgs,
+    ) -> Result<(), RecorderError> {
+        file.set_extension("safetensors");
+
+        if let Some(parent) = file.parent() {
+            std::fs::create_dir_all(parent).ok();
+        }
+
+        to_file::<PS, _>(item, &file).map_err(|err| RecorderError::Unknown(err.to_string()))
+    }
+
+    fn load_item<I: DeserializeOwned>(&self, args: Self::LoadArgs) -> Result<I, RecorderError> {
+        let item =
+            from_file::<PS, I, B>(&args.file, args.key_remap, args.adapter_type, args.debug)?;
+        Ok(item)
+    }
+
+    fn load_metadata(&self, _args: Self::LoadArgs) -> Result<BurnMetadata, RecorderError> {
+        // The metadata isn't saved, so it's the one of the recorder, without tags.
+        Ok(BurnRecord::<(), B>::new::<Self>(()).metadata)
+    }
+
+    fn load<R: Record<B>>(
+        &self,
+        args: Self::LoadArgs,
+        device: &B::Device,
+    ) -> Result<R, RecorderError> {
+        let item: R::Item<Self::Settings> = Recorder::<B>::load_item(self, args)?;
+        Ok(R::from_item(item, device))
+    }
+}
+
+/// The adapter used to convert the tensors of a Safetensors file to the Burn module layout.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AdapterType {
+    /// The tensors are stored with the PyTorch layout, e.g. files from HuggingFace.
+    #[default]
+    PyTorch,
+
+    /// The tensors are stored with the Burn layout, e.g. files saved by [SafetensorsFileRecorder].
+    NoAdapter,
+}
+
+/// Arguments for loading a Safetensors file.
+///
+/// # Fields
+///
+/// * `file` - The path to the file to load.
+/// * `key_remap` - A vector of tuples containing a regular expression and a replacement string.
+///   See [regex::Regex::replace](https://docs.rs/regex/latest/regex/struct.Regex.html#method.replace)
+///   for more information.
+/// * `adapter_type` - The adapter used to convert the tensors to the Burn module layout.
+///
+/// # Examples
+///
+/// ```text
+/// use burn_import::safetensors::{LoadArgs, SafetensorsFileRecorder};
+/// use burn::record::FullPrecisionSettings;
+/// use burn::record::Recorder;
+///
+/// let args = LoadArgs::new("tests/safetensors/linear.safetensors".into())
+///    .with_key_remap("conv\\.(.*)", "$1"); // // Remove "conv" prefix, e.g. "conv.conv1" -> "conv1"
+///
+/// let record = SafetensorsFileRecorder::<FullPrecisionSettings>::default()
+///   .load(args)
+///   .expect("Should decode state successfully");
+/// ```
+#[derive(Debug, Clone)]
+pub struct LoadArgs {
+    /// The path to the file to load.
+    pub file: PathBuf,
+
+    /// A list of key remappings.
+    pub key_remap: Vec<(Regex, String)>,
+
+    /// The adapter used to convert the tensors to the Burn module layout.
+    pub adapter_type: AdapterType,
+
+    /// Whether to print debug information.
+    pub debug: bool,
+}
+
+impl LoadArgs {
+    /// Creates a new `LoadArgs` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The path to the file to load.
+    pub fn new(file: PathBuf) -> Self {
+        Self {
+            file,
+            key_remap: Vec::new(),
+            adapter_type: AdapterType::default(),
+            debug: false,
+        }
+    }
+
+    /// Sets key remapping.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The Regex pattern to be replaced.
+    /// * `replacement` - The pattern to replace with.
+    ///
+    /// See [Regex](https://docs.rs/regex/1.5.4/regex/#syntax) for the pattern syntax and
+    /// [Replacement](https://docs.rs/regex/latest/regex/struct.Regex.html#method.replace) for the
+    /// replacement syntax.
+    pub fn with_key_remap(mut self, pattern: &str, replacement: &str) -> Self {
+        let regex = Regex::new(pattern).expect("Valid regex");
+
+        self.key_remap.push((regex, replacement.into()));
+        self
+    }
+
+    /// Sets the adapter used to convert the tensors to the Burn module layout.
+    pub fn with_adapter_type(mut self, adapter_type: AdapterType) -> Self {
+        self.adapter_type = adapter_type;
+        self
+    }
+
+    /// Sets printing debug information on.
+    pub fn with_debug_print(mut self) -> Self {
+        self.debug = true;
+        self
+    }
+}
+
+impl From<PathBuf> for LoadArgs {
+    fn from(val: PathBuf) -> Self {
+        LoadArgs::new(val)
+    }
+}
+
+impl From<String> for LoadArgs {
+    fn from(val: String) -> Self {
+        LoadArgs::new(val.into())
+    }
+}
+
+impl From<&str> for LoadArgs {
+    fn from(val: &str) -> Self {
+        LoadArgs::new(val.into())
+    }
+}
@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use super::error::Error;
+
+use burn::{
+    record::{
+        serde::{data::NestedValue, ser::Serializer},
+        PrecisionSettings,
+    },
+    tensor::{DType, Element},
+};
+
+use candle_core::{safetensors, Device, Tensor as CandleTensor, WithDType};
+use half::{bf16, f16};
+use serde::Serialize;
+
+/// Serializes an item into a Safetensors file.
+///
+/// Each tensor of the item is stored under its flattened dotted name, e.g. `encoder.layer.0.weight`,
+/// which matches the naming used by PyTorch's `state_dict`.
+///
+/// # Arguments
+///
+/// * `item` - The item to serialize.
+/// * `path` - The path of the file to write.
+pub fn to_file<PS, I>(item: I, path: &Path) -> Result<(), Error>
+where
+    PS: PrecisionSettings,
+    I: Serialize,
+{
+    let nested_value = item.serialize(Serializer::new())?;
+
+    let mut tensors = HashMap::new();
+    flatten::<PS>(nested_value, "", &mut tensors)?;
+
+    safetensors::save(&tensors, path)?;
+
+    Ok(())
+}
+
+/// Flattens a nested value into a map of Candle tensors keyed by their dotted names.
+///
+/// Values which aren't tensors, such as constants and empty optional fields, are skipped.
+fn flatten<PS: PrecisionSettings>(
+    value: NestedValue,
+    prefix: &str,
+    tensors: &mut HashMap<String, CandleTensor>,
+) -> Result<(), Error> {
+    match value {
+        NestedValue::Map(mut map) => {
+            // Params are serialized as `{ id, param: { value, shape } }`.
+            if map.contains_key("id") {
+                if let Some(NestedValue::Map(param)) = map.remove("param") {
+                    tensors.insert(prefix.to_string(), to_candle_tensor::<PS>(param)?);
+                    return Ok(());
+                }
+            }
+
+            for (key, value) in map {
+                flatten::<PS>(value, &join(prefix, &key), tensors)?;
+            }
+        }
+        NestedValue::Vec(vec) => {
+            for (index, value) in vec.into_iter().enumerate() {
+                flatten::<PS>(value, &join(prefix, &index.to_string()), tensors)?;
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+fn join(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_string()
+    } else {
+        format!("{prefix}.{key}")
+    }
+}
+
+/// Converts the serialized tensor data into a Candle tensor.
+fn to_candle_tensor<PS: PrecisionSettings>(
+    mut data: HashMap<String, NestedValue>,
+) -> Result<CandleTensor, Error> {
+    let shape: Vec<usize> = match data.remove("shape") {
+        Some(NestedValue::Vec(dims)) => dims
+            .into_iter()
+            .map(|dim| dim.as_u64().map(|dim| dim as usize))
+            .collect::<Option<_>>()
+            .ok_or_else(|| Error::Other("Invalid tensor shape".into()))?,
+        _ => return Err(Error::Other("Missing tensor shape".into())),
+    };
+    let value = data
+        .remove("value")
+        .ok_or_else(|| Error::Other("Missing tensor value".into()))?;
+
+    match value {
+        NestedValue::F32s(values) => new_tensor(values, shape),
+        // Half precision elements are serialized with their bit representation.
+        NestedValue::U16s(values) => match PS::FloatElem::dtype() {
+            DType::BF16 => new_tensor(values.into_iter().map(bf16::from_bits).collect(), shape),
+            _ => new_tensor(values.into_iter().map(f16::from_bits).collect(), shape),
+        },
+        NestedValue::Vec(values) => match values.first() {
+            Some(NestedValue::F64(_)) => new_tensor(collect(values, NestedValue::as_f64)?, shape),
+            Some(NestedValue::Bool(_)) => new_tensor(
+                collect(values, |value| value.as_bool().map(u8::from))?,
+                shape,
+            ),
+            Some(_) => new_tensor(collect(values, NestedValue::as_i64)?, shape),
+            None => new_tensor(Vec::<f32>::new(), shape),
+        },
+        _ => Err(Error::Other(format!("Unsupported tensor value: {value:?}"))),
+    }
+}
+
+fn collect<T>(
+    values: Vec<NestedValue>,
+    convert: impl Fn(NestedValue) -> Option<T>,
+) -> Result<Vec<T>, Error> {
+    values
+        .into_iter()
+        .map(convert)
+        .collect::<Option<_>>()
+        .ok_or_else(|| Error::Other("Tensor values have mixed types".into()))
+}
+
+fn new_tensor<T: WithDType>(values: Vec<T>, shape: Vec<usize>) -> Result<CandleTensor, Error> {
+    Ok(CandleTensor::from_vec(values, shape, &Device::Cpu)?)
+}
@@ -365,6 +365,11 @@ impl TraceBuilder {
                         &mut local_tensor_ids_input,
                         &mut local_tensor_ids_output,
                     ),
+                    Operator::BitwiseOr(op) => mark_binary(
+                        op,
+                        &mut local_tensor_ids_input,
+                        &mut local_tensor_ids_output,
+                    ),
                     Operator::BitwiseXor(op) => mark_binary(
                         op,
                         &mut local_tensor_ids_input,
@@ -353,6 +353,41 @@ where
         permute(tensor, axes)
     }
 
+    fn int_bitwise_and<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        numeric::bitwise_and(lhs, rhs)
+    }
+
+    fn int_bitwise_or<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        numeric::bitwise_or(lhs, rhs)
+    }
+
+    fn int_bitwise_xor<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        numeric::bitwise_xor(lhs, rhs)
+    }
+
+    fn int_bitwise_left_shift<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        numeric::bitwise_left_shift(lhs, rhs)
+    }
+
+    fn int_bitwise_right_shift<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        numeric::bitwise_right_shift(lhs, rhs)
+    }
+
     fn int_expand<const D1: usize, const D2: usize>(
         tensor: IntTensor<Self, D1>,
         shape: Shape<D2>,
@@ -1,6 +1,7 @@
 use crate::{binary, JitRuntime};
 use crate::{element::JitElement, tensor::JitTensor, unary};
 use burn_compute::client::ComputeClient;
+use burn_cube::cpa;
 use burn_cube::ir::{BinaryOperator, Elem, Operator, Scope, UnaryOperator, Variable};
 use burn_cube::Runtime;
 use burn_tensor::{ElementConversion, Shape};
@@ -242,3 +243,99 @@ pub fn pow<R: JitRuntime, E: JitElement, const D: usize>(
         elem: E
     )
 }
+
+pub fn bitwise_and<R: JitRuntime, E: JitElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+) -> JitTensor<R, E, D> {
+    binary!(
+        operation: |scope: &mut Scope, elem: Elem, position: Variable| Operator::BitwiseAnd(BinaryOperator {
+            lhs: scope.read_array(0, elem, position),
+            rhs: scope.read_array(1, elem, position),
+            out: scope.create_local(elem),
+        }),
+        runtime: R,
+        input: lhs; rhs,
+        elem: E
+    )
+}
+
+pub fn bitwise_or<R: JitRuntime, E: JitElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+) -> JitTensor<R, E, D> {
+    binary!(
+        operation: |scope: &mut Scope, elem: Elem, position: Variable| Operator::BitwiseOr(BinaryOperator {
+            lhs: scope.read_array(0, elem, position),
+            rhs: scope.read_array(1, elem, position),
+            out: scope.create_local(elem),
+        }),
+        runtime: R,
+        input: lhs; rhs,
+        elem: E
+    )
+}
+
+pub fn bitwise_xor<R: JitRuntime, E: JitElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+) -> JitTensor<R, E, D> {
+    binary!(
+        operation: |scope: &mut Scope, elem: Elem, position: Variable| Operator::BitwiseXor(BinaryOperator {
+            lhs: scope.read_array(0, elem, position),
+            rhs: scope.read_array(1, elem, position),
+            out: scope.create_local(elem),
+        }),
+        runtime: R,
+        input: lhs; rhs,
+        elem: E
+    )
+}
+
+pub fn bitwise_left_shift<R: JitRuntime, E: JitElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+) -> JitTensor<R, E, D> {
+    binary!(
+        operation: |scope: &mut Scope, elem: Elem, position: Variable| {
+            let lhs = scope.read_array(0, elem, position);
+            let rhs = scope.read_array(1, elem, position);
+            // The number of bits to shift by is unsigned in some languages, e.g. WGSL.
+            let bits = scope.create_local(Elem::UInt);
+            cpa!(scope, bits = rhs);
+
+            Operator::ShiftLeft(BinaryOperator {
+                lhs,
+                rhs: bits,
+                out: scope.create_local(elem),
+            })
+        },
+        runtime: R,
+        input: lhs; rhs,
+        elem: E
+    )
+}
+
+pub fn bitwise_right_shift<R: JitRuntime, E: JitElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+) -> JitTensor<R, E, D> {
+    binary!(
+        operation: |scope: &mut Scope, elem: Elem, position: Variable| {
+            let lhs = scope.read_array(0, elem, position);
+            let rhs = scope.read_array(1, elem, position);
+            // The number of bits to shift by is unsigned in some languages, e.g. WGSL.
+            let bits = scope.create_local(Elem::UInt);
+            cpa!(scope, bits = rhs);
+
+            Operator::ShiftRight(BinaryOperator {
+                lhs,
+                rhs: bits,
+                out: scope.create_local(elem),
+            })
+        },
+        runtime: R,
+        input: lhs; rhs,
+        elem: E
+    )
+}
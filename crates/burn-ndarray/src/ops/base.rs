@@ -544,12 +544,24 @@ where
         rhs: NdArrayTensor<OtherE, D>,
         var_name: impl FnMut(&E, &OtherE) -> E,
     ) -> NdArrayTensor<E, D> {
-        NdArrayTensor::new(
-            Zip::from(lhs.array.view())
-                .and(rhs.array.view())
-                .map_collect(var_name)
-                .into_shared(),
-        )
+        // The dimensions of size one are broadcast, like the other binary operations.
+        let shape = lhs
+            .array
+            .shape()
+            .iter()
+            .zip(rhs.array.shape())
+            .map(|(lhs, rhs)| *lhs.max(rhs))
+            .collect::<Vec<_>>();
+        let lhs = lhs
+            .array
+            .broadcast(shape.as_slice())
+            .expect("The shapes should be broadcastable");
+        let rhs = rhs
+            .array
+            .broadcast(shape.as_slice())
+            .expect("The shapes should be broadcastable");
+
+        NdArrayTensor::new(Zip::from(lhs).and(rhs).map_collect(var_name).into_shared())
     }
 
     pub(crate) fn elementwise_op_scalar<const D: usize>(
@@ -431,6 +431,41 @@ impl<E: FloatNdArrayElement> IntTensorOps<Self> for NdArray<E> {
         NdArrayMathOps::elementwise_op(lhs, rhs, |a: &i64, b: &i64| a.pow(*b as u32))
     }
 
+    fn int_bitwise_and<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayTensor::new((lhs.array & rhs.array).into_shared())
+    }
+
+    fn int_bitwise_or<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayTensor::new((lhs.array | rhs.array).into_shared())
+    }
+
+    fn int_bitwise_xor<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayTensor::new((lhs.array ^ rhs.array).into_shared())
+    }
+
+    fn int_bitwise_left_shift<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayTensor::new((lhs.array << rhs.array).into_shared())
+    }
+
+    fn int_bitwise_right_shift<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayTensor::new((lhs.array >> rhs.array).into_shared())
+    }
+
     fn int_powf<const D: usize>(
         lhs: NdArrayTensor<i64, D>,
         rhs: NdArrayTensor<E, D>,
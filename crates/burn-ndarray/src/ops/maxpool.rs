@@ -121,7 +121,7 @@ pub(crate) fn max_pool2d_with_indices<E: FloatNdArrayElement>(
                                 let ih = ih as i64 - padding_height as i64;
                                 let iw = iw as i64 - padding_width as i64;
 
-                                index = ih * x_height as i64 + iw;
+                                index = ih * x_width as i64 + iw;
                             }
                         }
                     }
@@ -494,6 +494,71 @@ impl<E: TchElement> IntTensorOps<Self> for LibTorch<E> {
         TchOps::sign(tensor)
     }
 
+    fn int_bitwise_and<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.f_bitwise_and_tensor_(rhs).unwrap(),
+            |lhs, rhs| rhs.f_bitwise_and_tensor_(lhs).unwrap(),
+            |lhs, rhs| lhs.f_bitwise_and_tensor(rhs).unwrap(),
+        )
+    }
+
+    fn int_bitwise_or<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.f_bitwise_or_tensor_(rhs).unwrap(),
+            |lhs, rhs| rhs.f_bitwise_or_tensor_(lhs).unwrap(),
+            |lhs, rhs| lhs.f_bitwise_or_tensor(rhs).unwrap(),
+        )
+    }
+
+    fn int_bitwise_xor<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.f_bitwise_xor_tensor_(rhs).unwrap(),
+            |lhs, rhs| rhs.f_bitwise_xor_tensor_(lhs).unwrap(),
+            |lhs, rhs| lhs.f_bitwise_xor_tensor(rhs).unwrap(),
+        )
+    }
+
+    fn int_bitwise_left_shift<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.f_bitwise_left_shift_(rhs).unwrap(),
+            |lhs, rhs| lhs.f_bitwise_left_shift(rhs).unwrap(),
+            |lhs, rhs| lhs.f_bitwise_left_shift(rhs).unwrap(),
+        )
+    }
+
+    fn int_bitwise_right_shift<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.f_bitwise_right_shift_(rhs).unwrap(),
+            |lhs, rhs| lhs.f_bitwise_right_shift(rhs).unwrap(),
+            |lhs, rhs| lhs.f_bitwise_right_shift(rhs).unwrap(),
+        )
+    }
+
     fn int_expand<const D1: usize, const D2: usize>(
         tensor: burn_tensor::ops::IntTensor<Self, D1>,
         shape: Shape<D2>,
@@ -68,6 +68,22 @@ where
         Self::new(B::float_recip(self.primitive))
     }
 
+    /// Applies element wise floor operation.
+    pub fn floor(self) -> Self {
+        Self::new(B::float_floor(self.primitive))
+    }
+
+    /// Applies element wise ceil operation.
+    pub fn ceil(self) -> Self {
+        Self::new(B::float_ceil(self.primitive))
+    }
+
+    /// Applies element wise round operation, halfway cases being rounded to the nearest even
+    /// integer.
+    pub fn round(self) -> Self {
+        Self::new(B::float_round(self.primitive))
+    }
+
     /// Applies element wise root square operation.
     pub fn sqrt(self) -> Self {
         Self::new(B::float_sqrt(self.primitive))
@@ -106,6 +122,15 @@ where
         Self::from_data(floats.into().convert(), device)
     }
 
+    /// Create a tensor from values quantized to `i8` with a symmetric scale, the values of the
+    /// tensor being approximated by `data * scale`.
+    ///
+    /// Backends storing quantized tensors natively keep the quantized values, the others
+    /// dequantize them, see [float_from_quantized](crate::ops::FloatTensorOps::float_from_quantized).
+    pub fn from_quantized(data: Data<i8, D>, scale: f32, device: &B::Device) -> Self {
+        Self::new(B::float_from_quantized(data, scale, device))
+    }
+
     /// Returns a new tensor with the same shape and device as the current tensor and the data
     /// casted to Integer.
     ///
@@ -1,9 +1,9 @@
-use crate::{backend::Backend, Data, Float, Int, Shape, Tensor};
+use crate::{backend::Backend, check, check::TensorCheck, Data, Float, Int, Shape, Tensor};
 
 use core::ops::Range;
 
 #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
-use crate::{argsort, check, check::TensorCheck, sort, sort_with_indices};
+use crate::{argsort, sort, sort_with_indices};
 
 impl<B> Tensor<B, 1, Int>
 where
@@ -101,6 +101,54 @@ where
         Tensor::new(B::int_cartesian_grid::<S, D, D2>(shape, device))
     }
 
+    /// Applies element wise bitwise and operation on non-negative integers.
+    ///
+    /// `y = x2 & x1`
+    pub fn bitwise_and(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("BitwiseAnd", &self, &other));
+        Self::new(B::int_bitwise_and(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise bitwise or operation on non-negative integers.
+    ///
+    /// `y = x2 | x1`
+    pub fn bitwise_or(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("BitwiseOr", &self, &other));
+        Self::new(B::int_bitwise_or(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise bitwise xor operation on non-negative integers.
+    ///
+    /// `y = x2 ^ x1`
+    pub fn bitwise_xor(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("BitwiseXor", &self, &other));
+        Self::new(B::int_bitwise_xor(self.primitive, other.primitive))
+    }
+
+    /// Shifts the bits of non-negative integers to the left by the elements of `other`.
+    ///
+    /// `y = x2 << x1`
+    pub fn bitwise_left_shift(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew(
+            "BitwiseLeftShift",
+            &self,
+            &other
+        ));
+        Self::new(B::int_bitwise_left_shift(self.primitive, other.primitive))
+    }
+
+    /// Shifts the bits of non-negative integers to the right by the elements of `other`.
+    ///
+    /// `y = x2 >> x1`
+    pub fn bitwise_right_shift(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew(
+            "BitwiseRightShift",
+            &self,
+            &other
+        ));
+        Self::new(B::int_bitwise_right_shift(self.primitive, other.primitive))
+    }
+
     /// Sort the elements by value in ascending order along a given dimension.
     ///
     /// This sort is unstable (i.e., may reorder equal elements).
@@ -1228,6 +1228,125 @@ pub trait IntTensorOps<B: Backend> {
         result
     }
 
+    /// Element-wise bitwise and of two non-negative int tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The bitwise and of the elements of `lhs` and `rhs`.
+    ///
+    /// # Notes
+    ///
+    /// The default implementation combines the bits one at a time with arithmetic operations,
+    /// launching seven to nine element-wise operations for each bit of the int element, e.g. more
+    /// than four hundred for `i64`. Backends supporting bitwise operations should override it.
+    fn int_bitwise_and<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        bitwise_with_arithmetic::<B, D>(lhs, rhs, B::int_mul)
+    }
+
+    /// Element-wise bitwise or of two non-negative int tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The bitwise or of the elements of `lhs` and `rhs`.
+    ///
+    /// # Notes
+    ///
+    /// The default implementation combines the bits one at a time with arithmetic operations,
+    /// launching seven to nine element-wise operations for each bit of the int element, e.g. more
+    /// than four hundred for `i64`. Backends supporting bitwise operations should override it.
+    fn int_bitwise_or<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        bitwise_with_arithmetic::<B, D>(lhs, rhs, |lhs, rhs| {
+            B::int_sub(B::int_add(lhs.clone(), rhs.clone()), B::int_mul(lhs, rhs))
+        })
+    }
+
+    /// Element-wise bitwise xor of two non-negative int tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The bitwise xor of the elements of `lhs` and `rhs`.
+    ///
+    /// # Notes
+    ///
+    /// The default implementation combines the bits one at a time with arithmetic operations,
+    /// launching seven to nine element-wise operations for each bit of the int element, e.g. more
+    /// than four hundred for `i64`. Backends supporting bitwise operations should override it.
+    fn int_bitwise_xor<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        bitwise_with_arithmetic::<B, D>(lhs, rhs, |lhs, rhs| {
+            B::int_remainder_scalar(B::int_add(lhs, rhs), 2.elem())
+        })
+    }
+
+    /// Element-wise left shift of the bits of a non-negative int tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The tensor to shift.
+    /// * `rhs` - The number of bits to shift each element by.
+    ///
+    /// # Returns
+    ///
+    /// The elements of `lhs` shifted to the left by the elements of `rhs`.
+    ///
+    /// # Notes
+    ///
+    /// The default implementation multiplies `lhs` by the powers of two of `rhs`, computed with five
+    /// element-wise operations for each bit a shift can have, e.g. about thirty operations for
+    /// `i64`. Backends supporting bitwise operations should override it.
+    fn int_bitwise_left_shift<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        B::int_mul(lhs, pow2::<B, D>(rhs))
+    }
+
+    /// Element-wise right shift of the bits of a non-negative int tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The tensor to shift.
+    /// * `rhs` - The number of bits to shift each element by.
+    ///
+    /// # Returns
+    ///
+    /// The elements of `lhs` shifted to the right by the elements of `rhs`.
+    ///
+    /// # Notes
+    ///
+    /// The default implementation divides `lhs` by the powers of two of `rhs`, computed with five
+    /// element-wise operations for each bit a shift can have, e.g. about thirty operations for
+    /// `i64`. Backends supporting bitwise operations should override it.
+    fn int_bitwise_right_shift<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        B::int_div(lhs, pow2::<B, D>(rhs))
+    }
+
     /// Broadcasts the int `tensor` to the given `shape`.
     fn int_expand<const D1: usize, const D2: usize>(
         tensor: IntTensor<B, D1>,
@@ -1301,3 +1420,57 @@ pub trait IntTensorOps<B: Backend> {
         argsort::<B, D, Int>(tensor, dim, descending)
     }
 }
+
+/// Number of bits of the non-negative values of the int element of the backend.
+fn value_bits<B: Backend>() -> usize {
+    core::mem::size_of::<IntElem<B>>() * 8 - 1
+}
+
+/// Combine the bits of two non-negative int tensors one at a time, the function receiving the
+/// bits of both tensors as zeros and ones and returning the resulting bits.
+fn bitwise_with_arithmetic<B: Backend, const D: usize>(
+    lhs: IntTensor<B, D>,
+    rhs: IntTensor<B, D>,
+    combine: impl Fn(IntTensor<B, D>, IntTensor<B, D>) -> IntTensor<B, D>,
+) -> IntTensor<B, D> {
+    // Zeros with the broadcast shape of both tensors
+    let mut result = B::int_mul_scalar(B::int_add(lhs.clone(), rhs.clone()), 0.elem());
+    let mut lhs = lhs;
+    let mut rhs = rhs;
+
+    for bit in 0..value_bits::<B>() {
+        let lhs_bit = B::int_remainder_scalar(lhs.clone(), 2.elem());
+        let rhs_bit = B::int_remainder_scalar(rhs.clone(), 2.elem());
+        let value = B::int_mul_scalar(combine(lhs_bit, rhs_bit), (1i64 << bit).elem());
+
+        result = B::int_add(result, value);
+        lhs = B::int_div_scalar(lhs, 2.elem());
+        rhs = B::int_div_scalar(rhs, 2.elem());
+    }
+
+    result
+}
+
+/// Two raised to the power of the elements of a non-negative int tensor, computed exactly as
+/// the product of the powers of two selected by the bits of the exponents.
+fn pow2<B: Backend, const D: usize>(exponent: IntTensor<B, D>) -> IntTensor<B, D> {
+    let mut result = B::int_add_scalar(B::int_mul_scalar(exponent.clone(), 0.elem()), 1.elem());
+    let mut exponent = exponent;
+    let mut bit = 0;
+
+    // Exponents of at least the number of bits overflow anyway
+    while (1 << bit) < value_bits::<B>() {
+        let selected = B::int_remainder_scalar(exponent.clone(), 2.elem());
+        // 1 when the bit isn't set, 2^(2^bit) otherwise
+        let factor = B::int_add_scalar(
+            B::int_mul_scalar(selected, ((1i64 << (1 << bit)) - 1).elem()),
+            1.elem(),
+        );
+
+        result = B::int_mul(result, factor);
+        exponent = B::int_div_scalar(exponent, 2.elem());
+        bit += 1;
+    }
+
+    result
+}
@@ -30,6 +30,35 @@ pub trait FloatTensorOps<B: Backend> {
         device: &Device<B>,
     ) -> FloatTensor<B, D>;
 
+    /// Creates a new tensor from values quantized to `i8` with a symmetric per-tensor scale.
+    ///
+    /// Backends supporting quantized storage can keep the quantized values and their scale
+    /// instead of dequantizing them. By default, the values are dequantized to the float element
+    /// of the backend.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The quantized values.
+    /// * `scale` - The scale of the quantized values.
+    /// * `device` - The device to create the tensor on.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the values `data * scale`.
+    fn float_from_quantized<const D: usize>(
+        data: Data<i8, D>,
+        scale: f32,
+        device: &Device<B>,
+    ) -> FloatTensor<B, D> {
+        let value = data
+            .value
+            .into_iter()
+            .map(|value| (value as f32 * scale).elem())
+            .collect();
+
+        B::float_from_data(Data::new(value, data.shape), device)
+    }
+
     /// Creates a new tensor with random values.
     ///
     /// # Arguments
@@ -1373,6 +1402,62 @@ pub trait FloatTensorOps<B: Backend> {
         result
     }
 
+    /// Returns the largest integers less than or equal to the elements of the float `tensor`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to round down.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` containing the rounded down elements.
+    fn float_floor<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        // The remainder takes the sign of the divisor, so it is the fractional part towards
+        // negative infinity.
+        let fraction = B::float_remainder_scalar(tensor.clone(), 1.0f32.elem());
+
+        B::float_sub(tensor, fraction)
+    }
+
+    /// Returns the smallest integers greater than or equal to the elements of the float `tensor`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to round up.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` containing the rounded up elements.
+    fn float_ceil<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        B::float_neg(B::float_floor(B::float_neg(tensor)))
+    }
+
+    /// Returns the elements of the float `tensor` rounded to the nearest integers, with halfway
+    /// cases rounded to the nearest even integer.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to round.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` containing the rounded elements.
+    fn float_round<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        let floor = B::float_floor(tensor.clone());
+        let fraction = B::float_sub(tensor, floor.clone());
+        let zeros = B::float_zeros(B::float_shape(&floor), &B::float_device(&floor));
+
+        let above_half = B::float_greater_elem(fraction.clone(), 0.5f32.elem());
+        let halfway = B::float_equal_elem(fraction, 0.5f32.elem());
+        // Halfway cases are rounded up when the floor is odd.
+        let odd = B::float_remainder_scalar(floor.clone(), 2.0f32.elem());
+
+        let increment = B::float_mask_fill(zeros, above_half, 1.0f32.elem());
+        let increment = B::float_mask_where(increment, halfway, odd);
+
+        B::float_add(floor, increment)
+    }
+
     /// Broadcasts the float `tensor` to the given `shape`.
     fn float_expand<const D1: usize, const D2: usize>(
         tensor: FloatTensor<B, D1>,
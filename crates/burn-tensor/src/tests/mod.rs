@@ -43,6 +43,7 @@ macro_rules! testgen_all {
         burn_tensor::testgen_arange!();
         burn_tensor::testgen_arange_step!();
         burn_tensor::testgen_arg!();
+        burn_tensor::testgen_bitwise!();
         burn_tensor::testgen_cast!();
         burn_tensor::testgen_cat!();
         burn_tensor::testgen_chunk!();
@@ -72,6 +73,7 @@ macro_rules! testgen_all {
         burn_tensor::testgen_random!();
         burn_tensor::testgen_recip!();
         burn_tensor::testgen_repeat!();
+        burn_tensor::testgen_round!();
         burn_tensor::testgen_reshape!();
         burn_tensor::testgen_select!();
         burn_tensor::testgen_sin!();
@@ -321,4 +321,16 @@ mod tests {
         y.to_data().assert_approx_eq(&output.into_data(), 3);
         assert_eq!(indices.value, output_indices.into_data().value);
     }
+
+    #[test]
+    fn test_max_pool2d_with_indices_rectangular() {
+        let x = TestTensor::from([[[[1.0, 5.0, 2.0], [4.0, 3.0, 6.0]]]]);
+        let indices = Data::<IntElem, 4>::from([[[[1, 5]]]]);
+        let y = TestTensor::from([[[[5.0, 6.0]]]]);
+
+        let (output, output_indices) = max_pool2d_with_indices(x, [2, 2], [1, 1], [0, 0], [1, 1]);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+        assert_eq!(indices.value, output_indices.into_data().value);
+    }
 }
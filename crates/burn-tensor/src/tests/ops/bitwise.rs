@@ -0,0 +1,53 @@
+#[burn_tensor_testgen::testgen(bitwise)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    fn operands() -> (Tensor<TestBackend, 2, Int>, Tensor<TestBackend, 2, Int>) {
+        let device = Default::default();
+        let lhs = Tensor::from_data(Data::from([[0, 5, 12], [7, 255, 1024]]), &device);
+        let rhs = Tensor::from_data(Data::from([[3, 3, 10], [8, 15, 1023]]), &device);
+
+        (lhs, rhs)
+    }
+
+    #[test]
+    fn should_support_bitwise_and_ops() {
+        let (lhs, rhs) = operands();
+
+        let data_actual = lhs.bitwise_and(rhs).into_data();
+
+        assert_eq!(data_actual, Data::from([[0, 1, 8], [0, 15, 0]]));
+    }
+
+    #[test]
+    fn should_support_bitwise_or_ops() {
+        let (lhs, rhs) = operands();
+
+        let data_actual = lhs.bitwise_or(rhs).into_data();
+
+        assert_eq!(data_actual, Data::from([[3, 7, 14], [15, 255, 2047]]));
+    }
+
+    #[test]
+    fn should_support_bitwise_xor_ops() {
+        let (lhs, rhs) = operands();
+
+        let data_actual = lhs.bitwise_xor(rhs).into_data();
+
+        assert_eq!(data_actual, Data::from([[3, 6, 6], [15, 240, 2047]]));
+    }
+
+    #[test]
+    fn should_support_bitwise_shift_ops() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::from_data(Data::from([1, 3, 16, 255]), &device);
+        let shift = Tensor::from_data(Data::from([0, 2, 3, 4]), &device);
+
+        let left = tensor.clone().bitwise_left_shift(shift.clone()).into_data();
+        let right = tensor.bitwise_right_shift(shift).into_data();
+
+        assert_eq!(left, Data::from([1, 12, 128, 4080]));
+        assert_eq!(right, Data::from([1, 0, 2, 15]));
+    }
+}
@@ -7,6 +7,7 @@ mod arange;
 mod arange_step;
 mod arg;
 mod argwhere_nonzero;
+mod bitwise;
 mod bool;
 mod cartesian_grid;
 mod cast;
@@ -44,6 +45,7 @@ mod random;
 mod recip;
 mod remainder;
 mod repeat;
+mod round;
 mod reshape;
 mod select;
 mod sign;
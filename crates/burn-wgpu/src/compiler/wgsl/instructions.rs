@@ -198,6 +198,11 @@ pub enum Instruction {
         rhs: Variable,
         out: Variable,
     },
+    BitwiseOr {
+        lhs: Variable,
+        rhs: Variable,
+        out: Variable,
+    },
     BitwiseXor {
         lhs: Variable,
         rhs: Variable,
@@ -472,6 +477,9 @@ for (var {i}: u32 = {start}; {i} < {end}; {i}++) {{
             Instruction::BitwiseAnd { lhs, rhs, out } => {
                 f.write_fmt(format_args!("{out} = {lhs} & {rhs};\n"))
             }
+            Instruction::BitwiseOr { lhs, rhs, out } => {
+                f.write_fmt(format_args!("{out} = {lhs} | {rhs};\n"))
+            }
             Instruction::BitwiseXor { lhs, rhs, out } => {
                 f.write_fmt(format_args!("{out} = {lhs} ^ {rhs};\n"))
             }